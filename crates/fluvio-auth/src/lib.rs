@@ -1,8 +1,10 @@
 mod policy;
 mod error;
+mod tenant;
 
 pub mod root;
 pub mod x509;
 
 pub use policy::*;
 pub use error::AuthError;
+pub use tenant::*;
@@ -4,9 +4,10 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
 use fluvio_controlplane_metadata::extended::ObjectType;
+use fluvio_controlplane_metadata::tenant::{is_tenant_scoped, split_tenant};
 use fluvio_socket::FluvioSocket;
 
-use super::AuthError;
+use super::{AuthError, Tenant};
 
 #[derive(Debug, Clone, PartialEq, Hash, Eq, Deserialize, Serialize)]
 pub enum TypeAction {
@@ -35,6 +36,38 @@ pub trait AuthContext: Debug + Send + Sync + 'static {
         action: InstanceAction,
         key: &str,
     ) -> Result<bool, AuthError>;
+
+    /// tenant of the principal, only objects of the tenant can be accessed.
+    /// none if principal doesn't belong to any tenant
+    fn tenant(&self) -> Option<&Tenant> {
+        None
+    }
+
+    /// check if object with key belongs to tenant of the principal.
+    /// objects which are not scoped by tenant are shared by all tenants
+    fn allow_tenant_object(&self, ty: &ObjectType, key: &str) -> bool {
+        match self.tenant() {
+            Some(tenant) if is_tenant_scoped(ty) => tenant.owns(key),
+            _ => true,
+        }
+    }
+
+    /// check if tenant exists
+    fn has_tenant(&self, _name: &str) -> bool {
+        false
+    }
+
+    /// check if new object can be named `key`. Principal of tenant can only name objects
+    /// of the tenant, other principals can only qualify names with existing tenant
+    fn allow_tenant_name(&self, ty: &ObjectType, key: &str) -> bool {
+        if self.tenant().is_some() {
+            return self.allow_tenant_object(ty, key);
+        }
+        match split_tenant(key) {
+            (Some(tenant), _) if is_tenant_scoped(ty) => self.has_tenant(tenant),
+            _ => true,
+        }
+    }
 }
 
 #[async_trait]
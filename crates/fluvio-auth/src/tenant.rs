@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fs::read;
+use std::io::{Error as IoError, ErrorKind};
+use std::path::PathBuf;
+
+use tracing::debug;
+use serde::{Deserialize, Serialize};
+
+use fluvio_controlplane_metadata::tenant::{is_owned_by, TENANT_SEPARATOR};
+
+/// limits of resources used by tenant, unlimited if not set
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TenantLimits {
+    /// max number of partitions of all topics of tenant
+    pub max_partitions: Option<u32>,
+    /// max bytes of storage reserved by partitions of tenant, including replicas
+    pub max_storage: Option<u64>,
+    /// max bytes per second produced by tenant to each SPU
+    pub max_throughput: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TenantConfig {
+    /// principals which belong to tenant
+    pub principals: Vec<String>,
+    pub limits: TenantLimits,
+}
+
+/// Tenant of authenticated principal
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tenant {
+    pub name: String,
+    pub limits: TenantLimits,
+}
+
+impl Tenant {
+    pub fn new(name: impl Into<String>, limits: TenantLimits) -> Self {
+        Self {
+            name: name.into(),
+            limits,
+        }
+    }
+
+    /// check if object with key `name` belongs to tenant
+    pub fn owns(&self, name: &str) -> bool {
+        is_owned_by(name, &self.name)
+    }
+}
+
+/// Tenants of cluster by tenant name
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TenantPolicy(pub HashMap<String, TenantConfig>);
+
+impl From<HashMap<String, TenantConfig>> for TenantPolicy {
+    fn from(map: HashMap<String, TenantConfig>) -> Self {
+        Self(map)
+    }
+}
+
+impl TryFrom<PathBuf> for TenantPolicy {
+    type Error = IoError;
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        debug!("reading tenant policy: {:#?}", path);
+        let file = read(path)?;
+        let policy: TenantPolicy = serde_json::from_slice(&file)?;
+        policy.validate()?;
+        Ok(policy)
+    }
+}
+
+impl TenantPolicy {
+    /// tenant names can't contain separator, and principal can only belong to single tenant
+    pub fn validate(&self) -> Result<(), IoError> {
+        let mut owners: HashMap<&str, &str> = HashMap::new();
+        for (name, config) in self.0.iter() {
+            if name.is_empty() || name.contains(TENANT_SEPARATOR) {
+                return Err(IoError::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid tenant name: '{name}'"),
+                ));
+            }
+            for principal in config.principals.iter() {
+                if let Some(owner) = owners.insert(principal, name) {
+                    return Err(IoError::new(
+                        ErrorKind::InvalidData,
+                        format!("principal '{principal}' belongs to tenants '{owner}' and '{name}'"),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// tenant which principal belongs to
+    pub fn tenant_of(&self, principal: &str) -> Option<Tenant> {
+        self.0
+            .iter()
+            .find(|(_, config)| config.principals.iter().any(|p| p == principal))
+            .map(|(name, config)| Tenant::new(name, config.limits.clone()))
+    }
+
+    /// limits of tenant
+    pub fn limits(&self, tenant: &str) -> Option<&TenantLimits> {
+        self.0.get(tenant).map(|config| &config.limits)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn policy() -> TenantPolicy {
+        let mut tenants = HashMap::new();
+        tenants.insert(
+            "team-a".to_owned(),
+            TenantConfig {
+                principals: vec!["alice".to_owned(), "bob".to_owned()],
+                limits: TenantLimits {
+                    max_partitions: Some(10),
+                    ..Default::default()
+                },
+            },
+        );
+        tenants.insert(
+            "team-b".to_owned(),
+            TenantConfig {
+                principals: vec!["carol".to_owned()],
+                limits: TenantLimits::default(),
+            },
+        );
+        tenants.into()
+    }
+
+    #[test]
+    fn test_tenant_of_principal() {
+        let policy = policy();
+        policy.validate().expect("valid");
+
+        let tenant = policy.tenant_of("bob").expect("tenant");
+        assert_eq!(tenant.name, "team-a");
+        assert_eq!(tenant.limits.max_partitions, Some(10));
+        assert!(tenant.owns("team-a.orders"));
+        assert!(!tenant.owns("team-b.orders"));
+        assert!(!tenant.owns("orders"));
+
+        assert_eq!(policy.tenant_of("carol").expect("tenant").name, "team-b");
+        assert!(policy.tenant_of("root").is_none());
+    }
+
+    #[test]
+    fn test_invalid_policy() {
+        let mut policy = policy();
+        policy
+            .0
+            .get_mut("team-b")
+            .expect("team-b")
+            .principals
+            .push("alice".to_owned());
+        assert!(policy.validate().is_err());
+
+        let mut policy = TenantPolicy::default();
+        policy
+            .0
+            .insert("team.a".to_owned(), TenantConfig::default());
+        assert!(policy.validate().is_err());
+    }
+
+    #[test]
+    fn test_policy_deserialization() {
+        let policy: TenantPolicy = serde_json::from_str(
+            r#"{"team-a": {"principals": ["alice"], "limits": {"max_storage": 1024}}, "team-b": {}}"#,
+        )
+        .expect("parse");
+        assert_eq!(
+            policy.limits("team-a").and_then(|limits| limits.max_storage),
+            Some(1024)
+        );
+        assert_eq!(policy.limits("team-b"), Some(&TenantLimits::default()));
+        assert!(policy.limits("team-c").is_none());
+    }
+}
@@ -13,7 +13,7 @@ use flv_tls_proxy::authenticator::Authenticator;
 
 use super::request::AuthRequest;
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 struct ScopeBindings(HashMap<String, Vec<String>>);
 
 impl ScopeBindings {
@@ -48,6 +48,13 @@ impl X509Authenticator {
         }
     }
 
+    /// authenticator which only passes principal of client, without any scopes
+    pub fn without_scopes() -> Self {
+        Self {
+            scope_bindings: ScopeBindings::default(),
+        }
+    }
+
     async fn send_authorization_request(
         tcp_stream: &TcpStream,
         authorization_request: AuthRequest,
@@ -8,7 +8,7 @@ use fluvio_cli::{Root, HelpOpt};
 use fluvio_future::task::run_block_on;
 
 fn main() -> Result<()> {
-    fluvio_future::subscriber::init_tracer(None);
+    let _telemetry = fluvio_telemetry::init_tracer("fluvio");
 
     print_help_hack()?;
     let root: Root = Root::parse();
@@ -0,0 +1,78 @@
+//!
+//! # Export manifests
+//!
+//! CLI tree to write the objects of a cluster as manifests for `fluvio apply`
+//!
+use std::fmt::Debug;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use clap::Parser;
+use anyhow::Result;
+
+use fluvio::Fluvio;
+
+use crate::CliError;
+use crate::client::cmd::ClientCmd;
+use crate::common::output::Terminal;
+
+use super::manifest::Manifest;
+
+/// Export cluster objects as YAML manifests
+///
+/// The manifests can be applied to this or another cluster with `fluvio apply`.
+/// System topics are not exported.
+#[derive(Debug, Parser)]
+pub struct ExportOpt {
+    /// Directory where one manifest per object is written, it must not exist or be empty.
+    /// Manifests are printed to stdout when omitted.
+    #[arg(short, long, value_name = "dir")]
+    output: Option<PathBuf>,
+}
+
+#[async_trait]
+impl ClientCmd for ExportOpt {
+    async fn process_client<O: Terminal + Debug + Send + Sync>(
+        self,
+        _out: Arc<O>,
+        fluvio: &Fluvio,
+    ) -> Result<()> {
+        let admin = fluvio.admin().await;
+        let manifests = Manifest::list_all(&admin).await?;
+
+        let Some(output) = self.output else {
+            let documents = manifests
+                .iter()
+                .map(serde_yaml::to_string)
+                .collect::<Result<Vec<_>, _>>()?;
+            print!("{}", documents.join("---\n"));
+            return Ok(());
+        };
+
+        if output.exists() {
+            if output.read_dir()?.next().is_some() {
+                return Err(CliError::InvalidArg(format!(
+                    "output directory {} is not empty",
+                    output.display()
+                ))
+                .into());
+            }
+        } else {
+            fs::create_dir_all(&output)?;
+        }
+        for manifest in &manifests {
+            fs::write(
+                output.join(manifest.file_name()),
+                serde_yaml::to_string(manifest)?,
+            )?;
+        }
+        println!(
+            "exported {} objects to {}",
+            manifests.len(),
+            output.display()
+        );
+        Ok(())
+    }
+}
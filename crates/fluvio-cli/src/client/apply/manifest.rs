@@ -0,0 +1,275 @@
+//!
+//! # Manifests
+//!
+//! Declarative description of cluster objects stored as YAML documents.
+//! A file may hold several documents separated by `---`.
+//!
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use fluvio::FluvioAdmin;
+use fluvio::metadata::customspu::{CustomSpuKey, CustomSpuSpec};
+use fluvio::metadata::smartmodule::SmartModuleSpec;
+use fluvio::metadata::spg::SpuGroupSpec;
+use fluvio::metadata::tableformat::TableFormatSpec;
+use fluvio::metadata::topic::TopicSpec;
+use fluvio_sc_schema::mirror::MirrorSpec;
+
+/// Named object of the given spec
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Object<S> {
+    pub name: String,
+    pub spec: S,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum Manifest {
+    CustomSpu(Object<CustomSpuSpec>),
+    SpuGroup(Object<SpuGroupSpec>),
+    SmartModule(Object<SmartModuleSpec>),
+    TableFormat(Object<TableFormatSpec>),
+    Mirror(Object<MirrorSpec>),
+    Topic(Object<TopicSpec>),
+}
+
+impl Manifest {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::CustomSpu(_) => "custom-spu",
+            Self::SpuGroup(_) => "spu-group",
+            Self::SmartModule(_) => "smart-module",
+            Self::TableFormat(_) => "table-format",
+            Self::Mirror(_) => "mirror",
+            Self::Topic(_) => "topic",
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Self::CustomSpu(object) => &object.name,
+            Self::SpuGroup(object) => &object.name,
+            Self::SmartModule(object) => &object.name,
+            Self::TableFormat(object) => &object.name,
+            Self::Mirror(object) => &object.name,
+            Self::Topic(object) => &object.name,
+        }
+    }
+
+    /// Objects are created in this order and deleted in reverse,
+    /// so that SPUs exist before topics and smartmodules before topics using them.
+    pub fn rank(&self) -> u8 {
+        match self {
+            Self::CustomSpu(_) => 0,
+            Self::SpuGroup(_) => 1,
+            Self::SmartModule(_) => 2,
+            Self::TableFormat(_) => 3,
+            Self::Mirror(_) => 4,
+            Self::Topic(_) => 5,
+        }
+    }
+
+    /// file name used when exporting to a directory
+    pub fn file_name(&self) -> String {
+        format!("{}-{}.yaml", self.kind(), self.name())
+    }
+
+    pub async fn create(self, admin: &FluvioAdmin, dry_run: bool) -> Result<()> {
+        match self {
+            Self::CustomSpu(object) => admin.create(object.name, dry_run, object.spec).await,
+            Self::SpuGroup(object) => admin.create(object.name, dry_run, object.spec).await,
+            Self::SmartModule(object) => admin.create(object.name, dry_run, object.spec).await,
+            Self::TableFormat(object) => admin.create(object.name, dry_run, object.spec).await,
+            Self::Mirror(object) => admin.create(object.name, dry_run, object.spec).await,
+            Self::Topic(object) => admin.create(object.name, dry_run, object.spec).await,
+        }
+    }
+
+    pub async fn delete(&self, admin: &FluvioAdmin) -> Result<()> {
+        let name = self.name().to_owned();
+        match self {
+            Self::CustomSpu(_) => {
+                admin
+                    .delete::<CustomSpuSpec>(CustomSpuKey::Name(name))
+                    .await
+            }
+            Self::SpuGroup(_) => admin.delete::<SpuGroupSpec>(name).await,
+            Self::SmartModule(_) => admin.delete::<SmartModuleSpec>(name).await,
+            Self::TableFormat(_) => admin.delete::<TableFormatSpec>(name).await,
+            Self::Mirror(_) => admin.delete::<MirrorSpec>(name).await,
+            Self::Topic(_) => admin.delete::<TopicSpec>(name).await,
+        }
+    }
+
+    pub async fn exists(&self, admin: &FluvioAdmin) -> Result<bool> {
+        let filter = vec![self.name().to_owned()];
+        let found = match self {
+            Self::CustomSpu(_) => !admin.list::<CustomSpuSpec, _>(filter).await?.is_empty(),
+            Self::SpuGroup(_) => !admin.list::<SpuGroupSpec, _>(filter).await?.is_empty(),
+            Self::SmartModule(_) => !admin.list::<SmartModuleSpec, _>(filter).await?.is_empty(),
+            Self::TableFormat(_) => !admin.list::<TableFormatSpec, _>(filter).await?.is_empty(),
+            Self::Mirror(_) => !admin.list::<MirrorSpec, _>(filter).await?.is_empty(),
+            Self::Topic(_) => !admin.list::<TopicSpec, _>(filter).await?.is_empty(),
+        };
+        Ok(found)
+    }
+
+    /// read all objects in the cluster which can be managed with manifests.
+    /// System topics are owned by the cluster and are left out.
+    pub async fn list_all(admin: &FluvioAdmin) -> Result<Vec<Self>> {
+        let mut manifests = vec![];
+        for spu in admin.all::<CustomSpuSpec>().await? {
+            manifests.push(Self::CustomSpu(Object {
+                name: spu.name,
+                spec: spu.spec,
+            }));
+        }
+        for spg in admin.all::<SpuGroupSpec>().await? {
+            manifests.push(Self::SpuGroup(Object {
+                name: spg.name,
+                spec: spg.spec,
+            }));
+        }
+        for sm in admin.all::<SmartModuleSpec>().await? {
+            manifests.push(Self::SmartModule(Object {
+                name: sm.name,
+                spec: sm.spec,
+            }));
+        }
+        for tf in admin.all::<TableFormatSpec>().await? {
+            manifests.push(Self::TableFormat(Object {
+                name: tf.name,
+                spec: tf.spec,
+            }));
+        }
+        for mirror in admin.all::<MirrorSpec>().await? {
+            manifests.push(Self::Mirror(Object {
+                name: mirror.name,
+                spec: mirror.spec,
+            }));
+        }
+        for topic in admin.all::<TopicSpec>().await? {
+            if topic.spec.is_system() {
+                continue;
+            }
+            manifests.push(Self::Topic(Object {
+                name: topic.name,
+                spec: topic.spec,
+            }));
+        }
+        Ok(manifests)
+    }
+}
+
+/// parse all documents of a YAML string
+pub fn parse_manifests(content: &str) -> Result<Vec<Manifest>> {
+    let mut manifests = vec![];
+    for document in serde_yaml::Deserializer::from_str(content) {
+        let value = serde_yaml::Value::deserialize(document)?;
+        // skip empty documents, e.g. after a trailing `---`
+        if value.is_null() {
+            continue;
+        }
+        manifests.push(serde_yaml::from_value(value)?);
+    }
+    Ok(manifests)
+}
+
+/// load manifests from files and directories.
+/// Only `.yaml` and `.yml` files are read from a directory, in name order.
+pub fn load_manifests(paths: &[PathBuf]) -> Result<Vec<Manifest>> {
+    let mut manifests: Vec<Manifest> = vec![];
+    for path in paths {
+        for file in manifest_files(path)? {
+            let content = fs::read_to_string(&file)
+                .with_context(|| format!("reading manifest {}", file.display()))?;
+            let parsed = parse_manifests(&content)
+                .with_context(|| format!("parsing manifest {}", file.display()))?;
+            manifests.extend(parsed);
+        }
+    }
+
+    let mut seen = HashSet::new();
+    for manifest in &manifests {
+        if !seen.insert((manifest.kind(), manifest.name())) {
+            return Err(anyhow!(
+                "{} \"{}\" is defined more than once",
+                manifest.kind(),
+                manifest.name()
+            ));
+        }
+    }
+    Ok(manifests)
+}
+
+fn manifest_files(path: &Path) -> Result<Vec<PathBuf>> {
+    if !path.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+    let mut files = vec![];
+    for entry in fs::read_dir(path)? {
+        let file = entry?.path();
+        let is_yaml = file
+            .extension()
+            .map(|ext| ext == "yaml" || ext == "yml")
+            .unwrap_or(false);
+        if file.is_file() && is_yaml {
+            files.push(file);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_parse_multiple_documents() {
+        let content = r#"
+kind: topic
+name: orders
+spec:
+  replicas:
+    computed:
+      partitions: 2
+      replicationFactor: 1
+---
+kind: table-format
+name: orders-table
+spec:
+  name: orders-table
+---
+"#;
+        let manifests = parse_manifests(content).expect("parse");
+        assert_eq!(manifests.len(), 2);
+        assert_eq!(manifests[0].kind(), "topic");
+        assert_eq!(manifests[0].name(), "orders");
+        let Manifest::Topic(topic) = &manifests[0] else {
+            panic!("expected topic");
+        };
+        assert_eq!(topic.spec.partitions(), 2);
+        assert_eq!(manifests[1].kind(), "table-format");
+    }
+
+    #[test]
+    fn test_manifest_roundtrip() {
+        let manifest = Manifest::Topic(Object {
+            name: "orders".to_string(),
+            spec: TopicSpec::new_computed(3, 1, None),
+        });
+        let yaml = serde_yaml::to_string(&manifest).expect("serialize");
+        assert!(yaml.starts_with("kind: topic"));
+        assert_eq!(
+            parse_manifests(&yaml).expect("parse"),
+            vec![manifest.clone()]
+        );
+        assert_eq!(manifest.file_name(), "topic-orders.yaml");
+    }
+}
@@ -0,0 +1,182 @@
+//!
+//! # Apply manifests
+//!
+//! Converge cluster objects to YAML manifests, and export them back
+//!
+mod export;
+mod manifest;
+mod plan;
+
+pub use cmd::ApplyOpt;
+pub use export::ExportOpt;
+
+mod cmd {
+
+    use std::fmt::Debug;
+    use std::io::Read;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    use async_trait::async_trait;
+    use clap::Parser;
+    use anyhow::{anyhow, Result};
+
+    use fluvio::{Fluvio, FluvioAdmin};
+    use fluvio::metadata::topic::{AddPartition, TopicSpec, UpdateTopicAction};
+    use fluvio_future::timer::sleep;
+
+    use crate::CliError;
+    use crate::client::cmd::ClientCmd;
+    use crate::common::output::Terminal;
+
+    use super::manifest::{load_manifests, Manifest};
+    use super::plan::{Action, Change, Plan};
+
+    const DELETE_TIMEOUT: Duration = Duration::from_secs(30);
+    const DELETE_CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+    /// Create, update and delete objects to match YAML manifests
+    ///
+    /// Each manifest document has a `kind`, a `name` and a `spec`. Supported kinds are
+    /// topic, smart-module, table-format, spu-group, custom-spu and mirror.
+    /// Manifests of the current objects can be generated with `fluvio export`.
+    #[derive(Debug, Parser)]
+    pub struct ApplyOpt {
+        /// Manifest files or directories containing `.yaml` files
+        #[arg(short = 'f', long = "file", value_name = "path", required = true)]
+        files: Vec<PathBuf>,
+
+        /// Delete objects which are not in the manifests.
+        /// Only kinds present in the manifests are pruned.
+        #[arg(long)]
+        prune: bool,
+
+        /// Show the plan without changing the cluster, new objects are validated by the SC
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Allow deleting and creating again topics which can't be updated in place.
+        /// Records of replaced topics are lost.
+        #[arg(long)]
+        allow_topic_replace: bool,
+
+        /// Skip confirmation of replacing objects
+        #[arg(short, long)]
+        force: bool,
+    }
+
+    #[async_trait]
+    impl ClientCmd for ApplyOpt {
+        async fn process_client<O: Terminal + Debug + Send + Sync>(
+            self,
+            _out: Arc<O>,
+            fluvio: &Fluvio,
+        ) -> Result<()> {
+            let desired = load_manifests(&self.files)?;
+            let admin = fluvio.admin().await;
+            let live = Manifest::list_all(&admin).await?;
+            let plan = Plan::new(desired, live, self.prune);
+
+            if plan.is_empty() {
+                println!("no changes, {} objects up to date", plan.unchanged);
+                return Ok(());
+            }
+            println!("{plan}");
+
+            if !self.allow_topic_replace {
+                if let Some(change) = plan.changes.iter().find(|c| {
+                    c.action == Action::Replace && matches!(c.manifest, Manifest::Topic(_))
+                }) {
+                    return Err(CliError::InvalidArg(format!(
+                        "topic \"{}\" must be replaced, use --allow-topic-replace",
+                        change.manifest.name()
+                    ))
+                    .into());
+                }
+            }
+
+            if self.dry_run {
+                for change in plan.changes {
+                    if change.action == Action::Create {
+                        change.manifest.create(&admin, true).await?;
+                    }
+                }
+                println!("dry run, no changes made");
+                return Ok(());
+            }
+
+            let replaced = plan.count(|a| *a == Action::Replace);
+            if replaced > 0 && !self.force && !user_confirms_replace(replaced) {
+                println!("Aborted");
+                return Ok(());
+            }
+
+            for change in plan.changes {
+                apply_change(&admin, change).await?;
+            }
+            Ok(())
+        }
+    }
+
+    async fn apply_change(admin: &FluvioAdmin, change: Change) -> Result<()> {
+        let kind = change.manifest.kind();
+        let name = change.manifest.name().to_owned();
+        match change.action {
+            Action::Create => {
+                change.manifest.create(admin, false).await?;
+                println!("{kind} \"{name}\" created");
+            }
+            Action::AddPartitions(count) => {
+                let action = UpdateTopicAction::AddPartition(AddPartition { count });
+                admin.update::<TopicSpec>(name.clone(), action).await?;
+                println!("{kind} \"{name}\" updated");
+            }
+            Action::UpdateConfig(config) => {
+                let action = UpdateTopicAction::UpdateConfig(config);
+                admin.update::<TopicSpec>(name.clone(), action).await?;
+                println!("{kind} \"{name}\" updated");
+            }
+            Action::Replace => {
+                change.manifest.delete(admin).await?;
+                wait_for_deletion(admin, &change.manifest).await?;
+                change.manifest.create(admin, false).await?;
+                println!("{kind} \"{name}\" replaced");
+            }
+            Action::Delete => {
+                change.manifest.delete(admin).await?;
+                println!("{kind} \"{name}\" deleted");
+            }
+        }
+        Ok(())
+    }
+
+    fn user_confirms_replace(count: usize) -> bool {
+        println!(
+            "{count} object(s) will be deleted and created again, records of replaced topics are lost.\nAre you sure you want to proceed? (y/n)"
+        );
+        char::from(
+            std::io::stdin()
+                .bytes()
+                .next()
+                .and_then(|b| b.ok())
+                .unwrap_or_default(),
+        ) == 'y'
+    }
+
+    /// deletion of some objects completes asynchronously, they must be gone before creating again
+    async fn wait_for_deletion(admin: &FluvioAdmin, manifest: &Manifest) -> Result<()> {
+        let started = Instant::now();
+        while manifest.exists(admin).await? {
+            if started.elapsed() > DELETE_TIMEOUT {
+                return Err(anyhow!(
+                    "{} \"{}\" was not deleted in time",
+                    manifest.kind(),
+                    manifest.name()
+                ));
+            }
+            sleep(DELETE_CHECK_INTERVAL).await;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,344 @@
+//!
+//! # Plan
+//!
+//! Changes needed to converge the cluster to the manifests
+//!
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use fluvio::metadata::topic::{ReplicaSpec, TopicSpec, UpdateTopicConfig};
+use fluvio_types::PartitionCount;
+
+use super::manifest::Manifest;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    Create,
+    /// topics with computed replicas can grow in place
+    AddPartitions(PartitionCount),
+    /// topic config is updated in place
+    UpdateConfig(UpdateTopicConfig),
+    /// delete and create again, spec can't be updated in place
+    Replace,
+    Delete,
+}
+
+/// Change to a single object, manifest is the desired object or the live object to delete
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub action: Action,
+    pub manifest: Manifest,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = self.manifest.kind();
+        let name = self.manifest.name();
+        match &self.action {
+            Action::Create => write!(f, "  + {kind} \"{name}\" will be created"),
+            Action::AddPartitions(count) => write!(
+                f,
+                "  ~ {kind} \"{name}\" will be updated, {count} partitions added"
+            ),
+            Action::UpdateConfig(_) => {
+                write!(f, "  ~ {kind} \"{name}\" will be updated, config changed")
+            }
+            Action::Replace => write!(f, "-/+ {kind} \"{name}\" will be replaced"),
+            Action::Delete => write!(f, "  - {kind} \"{name}\" will be deleted"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Plan {
+    /// changes in order of execution, deletions first
+    pub changes: Vec<Change>,
+    pub unchanged: usize,
+}
+
+impl Plan {
+    /// Compare manifests with live objects.
+    ///
+    /// With `prune`, live objects missing from the manifests are deleted, but only
+    /// for kinds which appear in the manifests.
+    pub fn new(desired: Vec<Manifest>, live: Vec<Manifest>, prune: bool) -> Self {
+        let managed_kinds: HashSet<&str> = desired.iter().map(|m| m.kind()).collect();
+        let desired_keys: HashSet<(&str, &str)> =
+            desired.iter().map(|m| (m.kind(), m.name())).collect();
+
+        let mut deletions: Vec<Change> = if prune {
+            live.iter()
+                .filter(|m| managed_kinds.contains(m.kind()))
+                .filter(|m| !desired_keys.contains(&(m.kind(), m.name())))
+                .map(|m| Change {
+                    action: Action::Delete,
+                    manifest: m.clone(),
+                })
+                .collect()
+        } else {
+            vec![]
+        };
+        deletions.sort_by(|a, b| {
+            (b.manifest.rank(), a.manifest.name()).cmp(&(a.manifest.rank(), b.manifest.name()))
+        });
+
+        let live: HashMap<(&str, &str), &Manifest> =
+            live.iter().map(|m| ((m.kind(), m.name()), m)).collect();
+        let mut unchanged = 0;
+        let mut updates = vec![];
+        for manifest in desired.iter() {
+            let action = match live.get(&(manifest.kind(), manifest.name())) {
+                None => Action::Create,
+                Some(current) if *current == manifest => {
+                    unchanged += 1;
+                    continue;
+                }
+                Some(current) => match (current, manifest) {
+                    (Manifest::Topic(current), Manifest::Topic(desired)) => {
+                        if let Some(count) = added_partitions(&current.spec, &desired.spec) {
+                            Action::AddPartitions(count)
+                        } else if let Some(config) = config_update(&current.spec, &desired.spec) {
+                            Action::UpdateConfig(config)
+                        } else {
+                            Action::Replace
+                        }
+                    }
+                    _ => Action::Replace,
+                },
+            };
+            updates.push(Change {
+                action,
+                manifest: manifest.clone(),
+            });
+        }
+        updates.sort_by(|a, b| {
+            (a.manifest.rank(), a.manifest.name()).cmp(&(b.manifest.rank(), b.manifest.name()))
+        });
+
+        deletions.extend(updates);
+        Self {
+            changes: deletions,
+            unchanged,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn count(&self, matches: impl Fn(&Action) -> bool) -> usize {
+        self.changes.iter().filter(|c| matches(&c.action)).count()
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        write!(
+            f,
+            "Plan: {} to create, {} to update, {} to replace, {} to delete, {} unchanged",
+            self.count(|a| *a == Action::Create),
+            self.count(|a| matches!(a, Action::AddPartitions(_) | Action::UpdateConfig(_))),
+            self.count(|a| *a == Action::Replace),
+            self.count(|a| *a == Action::Delete),
+            self.unchanged
+        )
+    }
+}
+
+/// number of partitions to add if that is the only difference between the specs
+fn added_partitions(current: &TopicSpec, desired: &TopicSpec) -> Option<PartitionCount> {
+    let (ReplicaSpec::Computed(current_param), ReplicaSpec::Computed(desired_param)) =
+        (current.replicas(), desired.replicas())
+    else {
+        return None;
+    };
+    if desired_param.partitions <= current_param.partitions {
+        return None;
+    }
+
+    let mut grown = current.clone();
+    let mut param = current_param.clone();
+    param.partitions = desired_param.partitions;
+    grown.set_replicas(ReplicaSpec::Computed(param));
+    if grown == *desired {
+        Some(desired_param.partitions - current_param.partitions)
+    } else {
+        None
+    }
+}
+
+/// config update if config is the only difference between the specs
+fn config_update(current: &TopicSpec, desired: &TopicSpec) -> Option<UpdateTopicConfig> {
+    let update = UpdateTopicConfig {
+        cleanup_policy: desired.get_clean_policy().cloned(),
+        storage: desired.get_storage().cloned(),
+        compression_type: Some(desired.get_compression_type().clone()),
+        deduplication: desired.get_deduplication().cloned(),
+        remove_deduplication: desired.get_deduplication().is_none(),
+    };
+
+    // config can't be unset by update, those topics are still replaced
+    let mut updated = current.clone();
+    update.apply_to(&mut updated);
+    if updated == *desired {
+        Some(update)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use fluvio::metadata::tableformat::TableFormatSpec;
+    use fluvio::metadata::topic::{CleanupPolicy, CompressionAlgorithm, SegmentBasedPolicy};
+
+    use super::super::manifest::Object;
+    use super::*;
+
+    fn topic(name: &str, partitions: PartitionCount, replication: u32) -> Manifest {
+        Manifest::Topic(Object {
+            name: name.to_string(),
+            spec: TopicSpec::new_computed(partitions, replication, None),
+        })
+    }
+
+    fn topic_with(
+        name: &str,
+        partitions: PartitionCount,
+        update: impl Fn(&mut TopicSpec),
+    ) -> Manifest {
+        let mut spec = TopicSpec::new_computed(partitions, 1, None);
+        update(&mut spec);
+        Manifest::Topic(Object {
+            name: name.to_string(),
+            spec,
+        })
+    }
+
+    fn table_format(name: &str) -> Manifest {
+        Manifest::TableFormat(Object {
+            name: name.to_string(),
+            spec: TableFormatSpec {
+                name: name.to_string(),
+                ..Default::default()
+            },
+        })
+    }
+
+    fn actions(plan: &Plan) -> Vec<(Action, String)> {
+        plan.changes
+            .iter()
+            .map(|c| (c.action.clone(), c.manifest.name().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_plan_create_update_replace() {
+        let desired = vec![
+            topic("same", 1, 1),
+            topic("grow", 3, 1),
+            topic("replicas", 1, 2),
+            topic("new", 1, 1),
+        ];
+        let live = vec![
+            topic("same", 1, 1),
+            topic("grow", 1, 1),
+            topic("replicas", 1, 1),
+            topic("old", 1, 1),
+        ];
+
+        let plan = Plan::new(desired, live, false);
+        assert_eq!(plan.unchanged, 1);
+        assert_eq!(
+            actions(&plan),
+            vec![
+                (Action::AddPartitions(2), "grow".to_string()),
+                (Action::Create, "new".to_string()),
+                (Action::Replace, "replicas".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_update_config() {
+        let gzip = |spec: &mut TopicSpec| spec.set_compression_type(CompressionAlgorithm::Gzip);
+        let retention = |spec: &mut TopicSpec| {
+            spec.set_cleanup_policy(CleanupPolicy::Segment(SegmentBasedPolicy {
+                time_in_seconds: 600,
+            }))
+        };
+        let desired = vec![
+            topic_with("compressed", 1, gzip),
+            topic_with("no-retention", 1, |_| {}),
+            topic_with("grow-compressed", 2, gzip),
+        ];
+        let live = vec![
+            topic("compressed", 1, 1),
+            topic_with("no-retention", 1, retention),
+            topic("grow-compressed", 1, 1),
+        ];
+
+        let plan = Plan::new(desired, live, false);
+        assert_eq!(
+            actions(&plan),
+            vec![
+                (
+                    Action::UpdateConfig(UpdateTopicConfig {
+                        compression_type: Some(CompressionAlgorithm::Gzip),
+                        remove_deduplication: true,
+                        ..Default::default()
+                    }),
+                    "compressed".to_string()
+                ),
+                (Action::Replace, "grow-compressed".to_string()),
+                (Action::Replace, "no-retention".to_string()),
+            ]
+        );
+        assert_eq!(
+            plan.to_string().lines().last(),
+            Some("Plan: 0 to create, 1 to update, 2 to replace, 0 to delete, 0 unchanged")
+        );
+    }
+
+    #[test]
+    fn test_plan_prune_only_managed_kinds() {
+        let desired = vec![topic("keep", 1, 1)];
+        let live = vec![
+            topic("keep", 1, 1),
+            topic("old", 1, 1),
+            table_format("not-managed"),
+        ];
+
+        let plan = Plan::new(desired.clone(), live.clone(), true);
+        assert_eq!(actions(&plan), vec![(Action::Delete, "old".to_string())]);
+
+        let plan = Plan::new(desired, live, false);
+        assert!(plan.is_empty());
+    }
+
+    #[test]
+    fn test_plan_deletes_in_reverse_order() {
+        let desired = vec![topic("keep", 1, 1), table_format("keep")];
+        let live = vec![table_format("old-format"), topic("old-topic", 1, 1)];
+
+        let plan = Plan::new(desired, live, true);
+        assert_eq!(
+            actions(&plan),
+            vec![
+                (Action::Delete, "old-topic".to_string()),
+                (Action::Delete, "old-format".to_string()),
+                (Action::Create, "keep".to_string()),
+                (Action::Create, "keep".to_string()),
+            ]
+        );
+        assert_eq!(plan.changes[2].manifest.kind(), "table-format");
+        assert_eq!(
+            plan.to_string().lines().last(),
+            Some("Plan: 2 to create, 0 to update, 0 to replace, 2 to delete, 0 unchanged")
+        );
+    }
+}
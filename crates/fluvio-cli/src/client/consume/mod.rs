@@ -77,9 +77,13 @@ mod cmd {
     /// '-d' flag to exit after consuming all available messages.
     #[derive(Debug, Parser)]
     pub struct ConsumeOpt {
-        /// Topic name
-        #[arg(value_name = "topic")]
-        pub topic: String,
+        /// Topic name, records of several topics are merged
+        #[arg(value_name = "topic", required_unless_present = "topic_pattern")]
+        pub topics: Vec<String>,
+
+        /// Consume from all topics whose name matches the regex, including topics created later
+        #[arg(long, value_name = "regex", conflicts_with_all = &["partition", "mirror"])]
+        pub topic_pattern: Option<String>,
 
         /// Partition id
         #[arg(short = 'p', long, value_name = "integer")]
@@ -108,7 +112,7 @@ mod cmd {
         /// Provide a template string to print records with a custom format.
         /// See --help for details.
         ///
-        /// Template strings may include the variables {{key}}, {{value}}, {{offset}}, {{partition}} and {{time}}
+        /// Template strings may include the variables {{key}}, {{value}}, {{offset}}, {{partition}}, {{topic}} and {{time}}
         /// which will have each record's contents substituted in their place.
         /// Note that timestamp is displayed using RFC3339, is always UTC and ignores system timezone.
         ///
@@ -234,7 +238,7 @@ mod cmd {
         #[instrument(
             skip(self, fluvio),
             name = "Consume",
-            fields(topic = %self.topic, partition = ?self.partition),
+            fields(topic = ?self.topics, partition = ?self.partition),
         )]
         async fn process_client<O: Terminal + Debug + Send + Sync>(
             self,
@@ -290,6 +294,10 @@ mod cmd {
             }
         }
 
+        fn is_multi_topic(&self) -> bool {
+            self.topics.len() > 1 || self.topic_pattern.is_some()
+        }
+
         fn smart_module_ctx(&self) -> SmartModuleContextData {
             if let Some(agg_initial) = &self.aggregate_initial {
                 SmartModuleContextData::Aggregate {
@@ -310,7 +318,17 @@ mod cmd {
             let offset = self.calculate_offset()?;
 
             let mut builder = ConsumerConfigExt::builder();
-            builder.topic(&self.topic);
+            match (self.topics.as_slice(), &self.topic_pattern) {
+                ([topic], None) => {
+                    builder.topic(topic);
+                }
+                (topics, pattern) => {
+                    builder.topics(topics);
+                    if let Some(pattern) = pattern {
+                        builder.topic_pattern(pattern);
+                    }
+                }
+            }
             builder.offset_start(offset);
             for partition in &self.partition {
                 builder.partition(*partition);
@@ -651,12 +669,21 @@ mod cmd {
                         "value": value,
                         "offset": record.offset(),
                         "partition": record.partition(),
+                        "topic": record.topic().unwrap_or_default(),
                         "time": timestamp_rfc3339,
                     });
                     templates.render(USER_TEMPLATE, &object).ok()
                 }
             };
 
+            // show origin of records merged from several topics, templates print it explicitly
+            let formatted_value = match (formatted_value, record.topic()) {
+                (Some(value), Some(topic)) if self.is_multi_topic() && templates.is_none() => {
+                    Some(format!("{topic}: {value}"))
+                }
+                (value, _) => value,
+            };
+
             // If the consume type is table, we don't want to accidentally print a newline
             if self.output != Some(ConsumeOutputType::full_table) {
                 match formatted_value {
@@ -685,7 +712,15 @@ mod cmd {
         }
 
         fn format_status_string(&self) -> String {
-            let prefix = format!("Consuming records from '{}'", self.topic);
+            let mut sources: Vec<String> = self
+                .topics
+                .iter()
+                .map(|topic| format!("'{topic}'"))
+                .collect();
+            if let Some(pattern) = &self.topic_pattern {
+                sources.push(format!("topics matching '{pattern}'"));
+            }
+            let prefix = format!("Consuming records from {}", sources.join(", "));
             let starting_description = if self.beginning {
                 " starting from the beginning of log".to_string()
             } else if let Some(offset) = self.head {
@@ -788,7 +823,8 @@ mod cmd {
 
         fn get_opt() -> ConsumeOpt {
             ConsumeOpt {
-                topic: "TOPIC_NAME".to_string(),
+                topics: vec!["TOPIC_NAME".to_string()],
+                topic_pattern: Default::default(),
                 partition: Default::default(),
                 mirror: Default::default(),
                 all_partitions: Default::default(),
@@ -878,6 +914,15 @@ mod cmd {
                 "Consuming records from 'TOPIC_NAME' until offset 2 (inclusive)",
                 opt.format_status_string(),
             );
+
+            // several topics
+            let mut opt = get_opt();
+            opt.topics.push("OTHER".to_string());
+            opt.topic_pattern = Some("events-.*".to_string());
+            assert_eq!(
+                "Consuming records from 'TOPIC_NAME', 'OTHER', topics matching 'events-.*'",
+                opt.format_status_string(),
+            );
         }
 
         #[test]
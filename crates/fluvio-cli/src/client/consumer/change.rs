@@ -0,0 +1,116 @@
+use anyhow::Result;
+use serde::Serialize;
+
+use fluvio::Fluvio;
+use fluvio_types::PartitionId;
+
+use crate::common::output::{OutputType, Terminal};
+
+/// Planned change of a stored consumer offset
+#[derive(Debug, Serialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ConsumerOffsetChange {
+    pub consumer_id: String,
+    pub topic: String,
+    pub partition: PartitionId,
+    /// stored offset before the change, if any
+    pub current: Option<i64>,
+    pub new: i64,
+}
+
+/// Store the new offsets, unless it is a dry run, and print the changes
+pub async fn apply_changes<O>(
+    out: std::sync::Arc<O>,
+    fluvio: &Fluvio,
+    changes: Vec<ConsumerOffsetChange>,
+    dry_run: bool,
+    output_type: OutputType,
+) -> Result<()>
+where
+    O: Terminal,
+{
+    if !dry_run {
+        for change in &changes {
+            fluvio
+                .set_consumer_offset(
+                    change.consumer_id.clone(),
+                    (change.topic.clone(), change.partition),
+                    change.new,
+                )
+                .await?;
+        }
+    }
+
+    let is_table = output_type.is_table();
+    display::format_response_output(out.clone(), changes, output_type)?;
+    if dry_run && is_table {
+        out.println("dry run, consumer offsets not changed");
+    }
+    Ok(())
+}
+
+mod display {
+
+    use comfy_table::{Row, Cell};
+    use serde::Serialize;
+
+    use crate::common::t_println;
+    use crate::common::output::{OutputType, OutputError, Terminal, TableOutputHandler};
+
+    use super::ConsumerOffsetChange;
+
+    #[derive(Serialize)]
+    struct ListChanges(Vec<ConsumerOffsetChange>);
+
+    pub fn format_response_output<O>(
+        out: std::sync::Arc<O>,
+        changes: Vec<ConsumerOffsetChange>,
+        output_type: OutputType,
+    ) -> Result<(), OutputError>
+    where
+        O: Terminal,
+    {
+        if !changes.is_empty() {
+            out.render_list(&ListChanges(changes), output_type)?;
+        } else {
+            t_println!(out, "No consumers found");
+        }
+
+        Ok(())
+    }
+
+    impl TableOutputHandler for ListChanges {
+        fn header(&self) -> Row {
+            Row::from([
+                "CONSUMER",
+                "TOPIC",
+                "PARTITION",
+                "CURRENT OFFSET",
+                "NEW OFFSET",
+            ])
+        }
+
+        fn errors(&self) -> Vec<String> {
+            vec![]
+        }
+
+        fn content(&self) -> Vec<Row> {
+            let mut list = self.0.clone();
+            list.sort();
+            list.into_iter()
+                .map(|change| {
+                    let current = change
+                        .current
+                        .map(|offset| offset.to_string())
+                        .unwrap_or_else(|| "-".to_string());
+                    Row::from([
+                        Cell::new(change.consumer_id),
+                        Cell::new(change.topic),
+                        Cell::new(change.partition),
+                        Cell::new(current),
+                        Cell::new(change.new),
+                    ])
+                })
+                .collect()
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use clap::Parser;
+use anyhow::Result;
+
+use fluvio::Fluvio;
+
+use crate::common::output::Terminal;
+use crate::common::OutputFormat;
+
+use super::change::{ConsumerOffsetChange, apply_changes};
+
+/// Option for Copying Consumer Offsets
+#[derive(Debug, Parser)]
+pub struct CopyConsumerOpt {
+    #[clap(flatten)]
+    output: OutputFormat,
+
+    /// Consumer to copy the offsets from
+    from: String,
+
+    /// Consumer to copy the offsets to, existing offsets are overwritten
+    to: String,
+
+    /// Copy only offsets on this topic
+    #[arg(short, long)]
+    topic: Option<String>,
+
+    /// Show the new offsets without changing them
+    #[arg(long)]
+    dry_run: bool,
+}
+
+impl CopyConsumerOpt {
+    pub async fn process<O>(self, out: std::sync::Arc<O>, fluvio: &Fluvio) -> Result<()>
+    where
+        O: Terminal,
+    {
+        let stored = fluvio.consumer_offsets().await?;
+        let changes = stored
+            .iter()
+            .filter(|c| c.consumer_id == self.from)
+            .filter(|c| self.topic.as_ref().map_or(true, |t| c.topic.eq(t)))
+            .map(|source| {
+                let current = stored
+                    .iter()
+                    .find(|c| {
+                        c.consumer_id == self.to
+                            && c.topic == source.topic
+                            && c.partition == source.partition
+                    })
+                    .map(|c| c.offset);
+                ConsumerOffsetChange {
+                    consumer_id: self.to.clone(),
+                    topic: source.topic.clone(),
+                    partition: source.partition,
+                    current,
+                    new: source.offset,
+                }
+            })
+            .collect();
+
+        apply_changes(out, fluvio, changes, self.dry_run, self.output.format).await
+    }
+}
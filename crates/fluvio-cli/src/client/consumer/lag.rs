@@ -0,0 +1,250 @@
+use std::time::{Duration, SystemTime};
+
+use clap::Parser;
+use anyhow::Result;
+use humantime::parse_duration;
+
+use fluvio::Fluvio;
+use fluvio::consumer::ConsumerLagFilter;
+use fluvio_future::timer::sleep;
+
+use crate::common::output::Terminal;
+use crate::common::OutputFormat;
+
+/// Option for showing lag of Consumers
+#[derive(Debug, Parser)]
+pub struct LagConsumerOpt {
+    #[clap(flatten)]
+    output: OutputFormat,
+
+    /// Only show the lag of this consumer
+    consumer: Option<String>,
+
+    /// Only show the lag on this topic
+    #[arg(short, long)]
+    topic: Option<String>,
+
+    /// Keep refreshing the lag
+    #[arg(short, long)]
+    watch: bool,
+
+    /// Time between refreshes in watch mode
+    /// Ex: '500ms', '10s'
+    #[arg(long, value_parser=parse_duration, default_value = "5s", requires = "watch")]
+    interval: Duration,
+
+    /// Consumers that didn't commit an offset within this time are reported as stale
+    /// Ex: '30m', '1h'
+    #[arg(long, value_parser=parse_duration, default_value = "1h")]
+    stale_after: Duration,
+
+    /// Only show stale consumers
+    #[arg(long)]
+    stale: bool,
+}
+
+impl LagConsumerOpt {
+    pub async fn process<O>(self, out: std::sync::Arc<O>, fluvio: &Fluvio) -> Result<()>
+    where
+        O: Terminal,
+    {
+        let filter = ConsumerLagFilter {
+            consumer_id: self.consumer.clone(),
+            topic: self.topic.clone(),
+            stale_after: self.stale.then_some(self.stale_after),
+        };
+        loop {
+            let now = SystemTime::now();
+            let lags = fluvio
+                .consumer_lags_with_filter(&filter)
+                .await?
+                .into_iter()
+                .map(|lag| display::ConsumerLagStatus::new(lag, self.stale_after, now))
+                .collect();
+
+            display::format_response_output(out.clone(), lags, self.output.format.clone())?;
+
+            if !self.watch {
+                return Ok(());
+            }
+            sleep(self.interval).await;
+            out.println("");
+        }
+    }
+}
+
+mod display {
+
+    use std::time::{Duration, SystemTime};
+
+    use comfy_table::{Row, Cell};
+    use serde::Serialize;
+
+    use fluvio::consumer::ConsumerLag;
+
+    use crate::common::t_println;
+    use crate::common::output::{OutputType, OutputError, Terminal, TableOutputHandler};
+
+    #[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Status {
+        /// offset was committed within the stale TTL
+        Active,
+        /// no offset was committed within the stale TTL, and there are no records to consume
+        Stale,
+        /// no offset was committed within the stale TTL, but there are records to consume
+        Stuck,
+    }
+
+    impl Status {
+        pub fn is_stale(&self) -> bool {
+            !matches!(self, Self::Active)
+        }
+    }
+
+    impl std::fmt::Display for Status {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let status = match self {
+                Self::Active => "active",
+                Self::Stale => "stale",
+                Self::Stuck => "stuck",
+            };
+            write!(f, "{status}")
+        }
+    }
+
+    #[derive(Debug, Serialize, Clone)]
+    pub struct ConsumerLagStatus {
+        #[serde(flatten)]
+        pub lag: ConsumerLag,
+        pub status: Status,
+    }
+
+    impl ConsumerLagStatus {
+        pub fn new(lag: ConsumerLag, stale_after: Duration, now: SystemTime) -> Self {
+            let status = if lag.is_stuck(stale_after, now) {
+                Status::Stuck
+            } else if lag.is_stale(stale_after, now) {
+                Status::Stale
+            } else {
+                Status::Active
+            };
+            Self { lag, status }
+        }
+    }
+
+    #[derive(Serialize)]
+    struct ListConsumerLags(Vec<ConsumerLagStatus>);
+
+    pub fn format_response_output<O>(
+        out: std::sync::Arc<O>,
+        lags: Vec<ConsumerLagStatus>,
+        output_type: OutputType,
+    ) -> Result<(), OutputError>
+    where
+        O: Terminal,
+    {
+        if !lags.is_empty() {
+            out.render_list(&ListConsumerLags(lags), output_type)?;
+        } else {
+            t_println!(out, "No consumers found");
+        }
+
+        Ok(())
+    }
+
+    impl TableOutputHandler for ListConsumerLags {
+        fn header(&self) -> Row {
+            Row::from([
+                "CONSUMER",
+                "TOPIC",
+                "PARTITION",
+                "OFFSET",
+                "HW",
+                "LAG",
+                "TIME LAG",
+                "LAST SEEN",
+                "STATUS",
+            ])
+        }
+
+        fn errors(&self) -> Vec<String> {
+            vec![]
+        }
+
+        fn content(&self) -> Vec<Row> {
+            let now = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            let mut list = self.0.clone();
+            list.sort_by(|a, b| a.lag.cmp(&b.lag));
+            list.into_iter()
+                .map(|ConsumerLagStatus { lag, status }| {
+                    let time_lag = match lag.time_lag_ms {
+                        Some(ms) => {
+                            humantime::format_duration(Duration::from_millis(ms)).to_string()
+                        }
+                        None => "-".to_string(),
+                    };
+                    let last_seen = humantime::Duration::from(Duration::from_secs(
+                        now.saturating_sub(lag.modified_time),
+                    ));
+                    Row::from([
+                        Cell::new(lag.consumer_id),
+                        Cell::new(lag.topic),
+                        Cell::new(lag.partition),
+                        Cell::new(lag.offset),
+                        Cell::new(lag.high_watermark),
+                        Cell::new(lag.lag),
+                        Cell::new(time_lag),
+                        Cell::new(last_seen),
+                        Cell::new(status),
+                    ])
+                })
+                .collect()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use fluvio::consumer::ConsumerOffset;
+        use fluvio::metadata::partition::ReplicaStatus;
+
+        use super::*;
+
+        #[test]
+        fn test_consumer_lag_status() {
+            let now = SystemTime::UNIX_EPOCH + Duration::from_secs(10_000);
+            let consumer = |modified_time| ConsumerOffset {
+                consumer_id: "c1".to_string(),
+                topic: "t1".to_string(),
+                partition: 0,
+                offset: 4,
+                modified_time,
+            };
+            let stale_after = Duration::from_secs(3600);
+
+            let active = ConsumerLag::new(consumer(9_000), &ReplicaStatus::new(5001, 10, 10));
+            assert_eq!(
+                ConsumerLagStatus::new(active, stale_after, now).status,
+                Status::Active
+            );
+
+            let stuck = ConsumerLag::new(consumer(1_000), &ReplicaStatus::new(5001, 10, 10));
+            assert_eq!(
+                ConsumerLagStatus::new(stuck, stale_after, now).status,
+                Status::Stuck
+            );
+
+            let stale = ConsumerLag::new(consumer(1_000), &ReplicaStatus::new(5001, 5, 5));
+            let stale = ConsumerLagStatus::new(stale, stale_after, now);
+            assert_eq!(stale.status, Status::Stale);
+            assert!(stale.status.is_stale());
+
+            let json = serde_json::to_value(&stale).expect("json");
+            assert_eq!(json["status"], "stale");
+            assert_eq!(json["lag"], 0);
+        }
+    }
+}
@@ -1,5 +1,9 @@
 mod list;
 mod delete;
+mod lag;
+mod reset;
+mod copy;
+mod change;
 
 pub use cmd::ConsumerCmd;
 
@@ -20,6 +24,9 @@ mod cmd {
 
     use super::delete::DeleteConsumerOpt;
     use super::list::ListConsumerOpt;
+    use super::lag::LagConsumerOpt;
+    use super::reset::ResetConsumerOpt;
+    use super::copy::CopyConsumerOpt;
 
     #[derive(Debug, Parser)]
     #[command(name = "consumer", about = "Consumer operations")]
@@ -36,6 +43,24 @@ mod cmd {
             help_template = crate::common::COMMAND_TEMPLATE,
         )]
         Delete(DeleteConsumerOpt),
+        /// Show how far behind the end of partitions the Consumers are
+        #[command(
+            name = "lag",
+            help_template = crate::common::COMMAND_TEMPLATE,
+        )]
+        Lag(LagConsumerOpt),
+        /// Set the Consumer Offset to the beginning, the end, an offset or a time
+        #[command(
+            name = "reset",
+            help_template = crate::common::COMMAND_TEMPLATE,
+        )]
+        Reset(ResetConsumerOpt),
+        /// Copy the Consumer Offsets to another Consumer
+        #[command(
+            name = "copy",
+            help_template = crate::common::COMMAND_TEMPLATE,
+        )]
+        Copy(CopyConsumerOpt),
     }
 
     #[async_trait]
@@ -52,6 +77,15 @@ mod cmd {
                 Self::Delete(delete) => {
                     delete.process(out, fluvio).await?;
                 }
+                Self::Lag(lag) => {
+                    lag.process(out, fluvio).await?;
+                }
+                Self::Reset(reset) => {
+                    reset.process(out, fluvio).await?;
+                }
+                Self::Copy(copy) => {
+                    copy.process(out, fluvio).await?;
+                }
             }
 
             Ok(())
@@ -0,0 +1,170 @@
+use std::time::UNIX_EPOCH;
+
+use clap::{Args, Parser};
+use anyhow::{Result, anyhow};
+
+use fluvio::Fluvio;
+use fluvio::consumer::ConsumerOffsetReset;
+use fluvio::metadata::topic::TopicSpec;
+use fluvio_types::{PartitionId, Timestamp};
+
+use crate::common::output::Terminal;
+use crate::common::OutputFormat;
+
+use super::change::{ConsumerOffsetChange, apply_changes};
+
+/// Option for Resetting Consumer Offsets
+#[derive(Debug, Parser)]
+pub struct ResetConsumerOpt {
+    #[clap(flatten)]
+    output: OutputFormat,
+
+    consumer: String,
+
+    #[arg(short, long)]
+    topic: String,
+
+    /// Reset only this partition, all partitions of the topic by default
+    #[arg(short, long)]
+    partition: Option<PartitionId>,
+
+    #[clap(flatten)]
+    position: ResetPosition,
+
+    /// Show the new offsets without changing them
+    #[arg(long)]
+    dry_run: bool,
+}
+
+#[derive(Debug, Args)]
+#[group(required = true, multiple = false)]
+struct ResetPosition {
+    /// Consume from the first record of the partition
+    #[arg(long)]
+    earliest: bool,
+
+    /// Consume only records produced from now on
+    #[arg(long)]
+    latest: bool,
+
+    /// Consume from the record at this offset
+    #[arg(long, value_name = "OFFSET")]
+    offset: Option<i64>,
+
+    /// Consume records produced at or after this time, e.g. '2024-01-01T00:00:00Z'
+    #[arg(long, value_name = "TIME", value_parser = parse_timestamp)]
+    timestamp: Option<Timestamp>,
+
+    /// Move the consumer by a number of records, backwards if negative
+    #[arg(long, value_name = "RECORDS", allow_negative_numbers = true)]
+    shift: Option<i64>,
+}
+
+impl ResetPosition {
+    fn to_reset(&self) -> ConsumerOffsetReset {
+        if self.earliest {
+            ConsumerOffsetReset::Earliest
+        } else if self.latest {
+            ConsumerOffsetReset::Latest
+        } else if let Some(offset) = self.offset {
+            ConsumerOffsetReset::Absolute(offset)
+        } else if let Some(timestamp) = self.timestamp {
+            ConsumerOffsetReset::Timestamp(timestamp)
+        } else {
+            ConsumerOffsetReset::Shift(self.shift.unwrap_or_default())
+        }
+    }
+}
+
+impl ResetConsumerOpt {
+    pub async fn process<O>(self, out: std::sync::Arc<O>, fluvio: &Fluvio) -> Result<()>
+    where
+        O: Terminal,
+    {
+        let partitions = match self.partition {
+            Some(partition) => vec![partition],
+            None => {
+                let admin = fluvio.admin().await;
+                let topic = admin
+                    .list::<TopicSpec, _>(vec![self.topic.clone()])
+                    .await?
+                    .into_iter()
+                    .find(|topic| topic.name == self.topic)
+                    .ok_or_else(|| anyhow!("topic \"{}\" not found", self.topic))?;
+                (0..topic.spec.partitions()).collect()
+            }
+        };
+
+        let reset = self.position.to_reset();
+        let stored = fluvio.consumer_offsets().await?;
+        let mut changes = Vec::with_capacity(partitions.len());
+        for partition in partitions {
+            let current = stored
+                .iter()
+                .find(|c| {
+                    c.consumer_id == self.consumer
+                        && c.topic == self.topic
+                        && c.partition == partition
+                })
+                .map(|c| c.offset);
+            let new = fluvio
+                .resolve_consumer_offset((self.topic.clone(), partition), reset, current)
+                .await?;
+            changes.push(ConsumerOffsetChange {
+                consumer_id: self.consumer.clone(),
+                topic: self.topic.clone(),
+                partition,
+                current,
+                new,
+            });
+        }
+
+        apply_changes(out, fluvio, changes, self.dry_run, self.output.format).await
+    }
+}
+
+fn parse_timestamp(value: &str) -> Result<Timestamp> {
+    let time = humantime::parse_rfc3339_weak(value)?;
+    let millis = time.duration_since(UNIX_EPOCH)?.as_millis();
+    Ok(millis as Timestamp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reset_position() {
+        let opt = ResetConsumerOpt::try_parse_from(["reset", "c1", "-t", "t1", "--shift", "-5"])
+            .expect("parse");
+        assert_eq!(opt.position.to_reset(), ConsumerOffsetReset::Shift(-5));
+        assert!(!opt.dry_run);
+
+        let opt = ResetConsumerOpt::try_parse_from([
+            "reset",
+            "c1",
+            "-t",
+            "t1",
+            "--timestamp",
+            "1970-01-01T00:00:01Z",
+            "--dry-run",
+        ])
+        .expect("parse");
+        assert_eq!(
+            opt.position.to_reset(),
+            ConsumerOffsetReset::Timestamp(1000)
+        );
+        assert!(opt.dry_run);
+
+        assert!(ResetConsumerOpt::try_parse_from(["reset", "c1", "-t", "t1"]).is_err());
+        assert!(ResetConsumerOpt::try_parse_from([
+            "reset",
+            "c1",
+            "-t",
+            "t1",
+            "--earliest",
+            "--latest"
+        ])
+        .is_err());
+    }
+}
@@ -9,6 +9,7 @@ mod smartmodule_invocation;
 mod consumer;
 mod remote;
 mod home;
+mod apply;
 
 pub use metadata::client_metadata;
 pub use cmd::FluvioCmd;
@@ -58,6 +59,7 @@ mod cmd {
     use super::partition::PartitionCmd;
     use super::tableformat::TableFormatCmd;
     use super::hub::HubCmd;
+    use super::apply::{ApplyOpt, ExportOpt};
 
     #[async_trait]
     pub trait ClientCmd: Sized {
@@ -152,6 +154,17 @@ mod cmd {
         /// Commands to interact with the home cluster
         #[command(subcommand, name = "home")]
         Home(Box<HomeCmd>),
+
+        /// Create, update and delete objects to match YAML manifests
+        ///
+        /// Computes the difference between the manifests and the cluster,
+        /// shows the plan and applies it.
+        #[command(name = "apply")]
+        Apply(ApplyOpt),
+
+        /// Export cluster objects as YAML manifests for `fluvio apply`
+        #[command(name = "export")]
+        Export(ExportOpt),
     }
 
     impl FluvioCmd {
@@ -192,6 +205,12 @@ mod cmd {
                 Self::Home(home) => {
                     home.process(out, target).await?;
                 }
+                Self::Apply(apply) => {
+                    apply.process(out, target).await?;
+                }
+                Self::Export(export) => {
+                    export.process(out, target).await?;
+                }
             }
 
             Ok(())
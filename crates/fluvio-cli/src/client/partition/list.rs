@@ -105,6 +105,7 @@ mod display {
                 "HW",
                 "LEO",
                 "LRS",
+                "ISR",
                 "FOLLOWER OFFSETS",
             ])
         }
@@ -146,13 +147,18 @@ mod display {
                         Cell::new(spec.leader.to_string()),
                         Cell::new(spec.mirror_string()),
                         Cell::new(format!("{:?}", spec.followers())),
-                        Cell::new(format!("{:?}", status.resolution)),
+                        Cell::new(match status.corrupted_offset {
+                            Some(offset) => {
+                                format!("{:?} (corrupted at {offset})", status.resolution)
+                            }
+                            None => format!("{:?}", status.resolution),
+                        }),
                         Cell::new(printable_size),
                         Cell::new(format!("{:?}", status.base_offset)),
                         Cell::new(status.leader.hw.to_string()),
                         Cell::new(status.leader.leo.to_string()),
-                        Cell::new(status.leader.leo.to_string()),
                         Cell::new(status.lrs().to_string()),
+                        Cell::new(status.in_sync_replicas.to_string()),
                         Cell::new(format!("{:?}", status.replicas)),
                     ])
                 })
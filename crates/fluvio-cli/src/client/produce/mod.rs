@@ -116,6 +116,10 @@ mod cmd {
         #[arg(long, default_value = "at-least-once")]
         pub delivery_semantic: DeliverySemantic,
 
+        /// Wait for the partition leader to sync records to disk before acknowledging them
+        #[arg(long)]
+        pub wait_for_fsync: bool,
+
         /// Name of the smartmodule
         #[arg(
             long,
@@ -234,6 +238,7 @@ mod cmd {
 
             let config = config_builder
                 .delivery_semantic(self.delivery_semantic)
+                .wait_for_fsync(self.wait_for_fsync)
                 .build()
                 .map_err(FluvioError::from)?;
 
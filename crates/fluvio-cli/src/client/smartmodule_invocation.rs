@@ -4,9 +4,9 @@ use std::io::Read;
 
 use fluvio::{
     SmartModuleInvocation, SmartModuleInvocationWasm, SmartModuleKind, SmartModuleContextData,
-    SmartModuleExtraParams,
+    SmartModuleExtraParams, SmartModuleErrorPolicy,
 };
-use fluvio_smartengine::transformation::TransformationConfig;
+use fluvio_smartengine::transformation::{ErrorPolicy, TransformationConfig};
 
 use flate2::bufread::GzEncoder;
 use flate2::Compression;
@@ -23,6 +23,7 @@ pub(crate) fn create_smartmodule(
         wasm: SmartModuleInvocationWasm::Predefined(name.to_string()),
         kind: SmartModuleKind::Generic(ctx),
         params: params.into(),
+        ..Default::default()
     }
 }
 
@@ -42,6 +43,7 @@ pub(crate) fn create_smartmodule_from_path(
         wasm: SmartModuleInvocationWasm::AdHoc(buffer),
         kind: SmartModuleKind::Generic(ctx),
         params: params.into(),
+        ..Default::default()
     })
 }
 
@@ -62,6 +64,15 @@ pub(crate) fn create_smartmodule_list(
                     .collect::<std::collections::BTreeMap<String, String>>(),
                 t.lookback.map(Into::into),
             ),
+            on_error: error_policy(t.on_error),
         })
         .collect())
 }
+
+fn error_policy(policy: ErrorPolicy) -> SmartModuleErrorPolicy {
+    match policy {
+        ErrorPolicy::Fail => SmartModuleErrorPolicy::Fail,
+        ErrorPolicy::Skip => SmartModuleErrorPolicy::Skip,
+        ErrorPolicy::DeadLetter(topic) => SmartModuleErrorPolicy::DeadLetter(topic),
+    }
+}
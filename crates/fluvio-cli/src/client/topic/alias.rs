@@ -0,0 +1,65 @@
+//!
+//! # Topic Aliases
+//!
+//! CLI tree to add and remove aliases of a topic.
+//!
+use clap::Parser;
+use anyhow::Result;
+
+use fluvio_sc_schema::topic::{TopicAlias, TopicSpec, UpdateTopicAction};
+use fluvio::Fluvio;
+
+/// Option for adding an alias to a Topic
+#[derive(Debug, Parser)]
+pub struct AddAliasOpt {
+    /// Topic name
+    topic: String,
+    /// Alias to add, it can be used instead of the topic name to produce and consume
+    alias: String,
+}
+
+impl AddAliasOpt {
+    pub async fn process(self, fluvio: &Fluvio) -> Result<()> {
+        let admin = fluvio.admin().await;
+
+        let action = UpdateTopicAction::AddAlias(TopicAlias {
+            name: self.alias.clone(),
+        });
+        admin
+            .update::<TopicSpec>(self.topic.clone(), action)
+            .await?;
+
+        println!("added alias \"{}\" to topic \"{}\"", self.alias, self.topic);
+
+        Ok(())
+    }
+}
+
+/// Option for removing an alias of a Topic
+#[derive(Debug, Parser)]
+pub struct RemoveAliasOpt {
+    /// Topic name
+    topic: String,
+    /// Alias to remove
+    alias: String,
+}
+
+impl RemoveAliasOpt {
+    pub async fn process(self, fluvio: &Fluvio) -> Result<()> {
+        let admin = fluvio.admin().await;
+
+        let action = UpdateTopicAction::RemoveAlias(TopicAlias {
+            name: self.alias.clone(),
+        });
+        admin
+            .update::<TopicSpec>(self.topic.clone(), action)
+            .await?;
+
+        println!(
+            "removed alias \"{}\" from topic \"{}\"",
+            self.alias, self.topic
+        );
+
+        Ok(())
+    }
+}
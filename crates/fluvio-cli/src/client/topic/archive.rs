@@ -0,0 +1,262 @@
+//!
+//! # Topic archive
+//!
+//! Format shared by `fluvio topic export` and `fluvio topic import`.
+//!
+//! An archive is a directory with a `manifest.json` describing the topic and one
+//! `partition-<n>.batches` file per partition. Batch files hold the exported records
+//! as a sequence of batches in the Fluvio batch encoding.
+//!
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use fluvio::Compression;
+use fluvio::metadata::topic::TopicSpec;
+use fluvio_protocol::{Decoder, Encoder};
+use fluvio_protocol::record::{Batch, Offset, RawRecords, Record, BATCH_PREAMBLE_SIZE};
+use fluvio_types::{PartitionId, Timestamp};
+
+/// Version of the archive layout
+pub const ARCHIVE_VERSION: u32 = 1;
+
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Records of a partition are grouped in batches of this size before being written
+pub const ARCHIVE_BATCH_MAX_BYTES: usize = 1_048_576;
+
+/// Describes the content of an archive
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveManifest {
+    pub version: u32,
+    pub topic: String,
+    /// spec of the exported topic, including compression and deduplication config
+    pub spec: TopicSpec,
+    /// compression used for batches in the archive
+    pub compression: Compression,
+    /// time of export in milliseconds since Unix epoch
+    pub created_at: Timestamp,
+    pub partitions: Vec<ArchivePartition>,
+}
+
+/// Records exported from a single partition
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivePartition {
+    pub partition: PartitionId,
+    pub file: String,
+    /// offset of first exported record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_offset: Option<Offset>,
+    /// offset of last exported record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_offset: Option<Offset>,
+    pub records: u64,
+    pub batches: u64,
+}
+
+impl ArchiveManifest {
+    pub fn read_from(archive_dir: &Path) -> Result<Self> {
+        let path = archive_dir.join(MANIFEST_FILE);
+        let file = File::open(&path)
+            .map_err(|err| anyhow!("unable to open manifest {}: {err}", path.display()))?;
+        let manifest: Self = serde_json::from_reader(BufReader::new(file))?;
+        if manifest.version > ARCHIVE_VERSION {
+            return Err(anyhow!(
+                "archive version {} is not supported, max supported version is {ARCHIVE_VERSION}",
+                manifest.version
+            ));
+        }
+        Ok(manifest)
+    }
+
+    pub fn write_to(&self, archive_dir: &Path) -> Result<()> {
+        let file = File::create(archive_dir.join(MANIFEST_FILE))?;
+        serde_json::to_writer_pretty(BufWriter::new(file), self)?;
+        Ok(())
+    }
+
+    pub fn total_records(&self) -> u64 {
+        self.partitions.iter().map(|p| p.records).sum()
+    }
+}
+
+pub fn partition_file_name(partition: PartitionId) -> String {
+    format!("partition-{partition}.batches")
+}
+
+/// Groups records of a partition in batches and writes them to the partition file
+pub struct BatchFileWriter {
+    writer: BufWriter<File>,
+    compression: Compression,
+    batch: Batch,
+    batch_size: usize,
+    summary: ArchivePartition,
+}
+
+impl BatchFileWriter {
+    pub fn create(
+        archive_dir: &Path,
+        partition: PartitionId,
+        compression: Compression,
+    ) -> Result<Self> {
+        let file_name = partition_file_name(partition);
+        let file = File::create(archive_dir.join(&file_name))?;
+        Ok(Self {
+            writer: BufWriter::new(file),
+            compression,
+            batch: Batch::default(),
+            batch_size: 0,
+            summary: ArchivePartition {
+                partition,
+                file: file_name,
+                ..Default::default()
+            },
+        })
+    }
+
+    /// Append record with its original offset and timestamp
+    pub fn push(&mut self, offset: Offset, timestamp: Timestamp, mut record: Record) -> Result<()> {
+        // offset delta of record must fit in the batch header
+        if !self.batch.records().is_empty()
+            && offset - self.batch.get_base_offset() > i32::MAX as Offset
+        {
+            self.flush_batch()?;
+        }
+        if self.batch.records().is_empty() {
+            self.batch.set_base_offset(offset);
+            let header = self.batch.get_mut_header();
+            header.first_timestamp = timestamp;
+            header.max_time_stamp = timestamp;
+        }
+
+        // keep offset gaps of the source partition, such as from compaction
+        let offset_delta = offset - self.batch.get_base_offset();
+        let header = self.batch.get_mut_header();
+        record
+            .get_mut_header()
+            .set_timestamp_delta(timestamp - header.first_timestamp);
+        record.get_mut_header().set_offset_delta(offset_delta);
+        header.max_time_stamp = header.max_time_stamp.max(timestamp);
+
+        self.batch_size += record.write_size(0);
+        self.batch.mut_records().push(record);
+        self.batch.set_offset_delta(offset_delta as i32);
+
+        self.summary.start_offset.get_or_insert(offset);
+        self.summary.end_offset = Some(offset);
+        self.summary.records += 1;
+
+        if self.batch_size >= ARCHIVE_BATCH_MAX_BYTES {
+            self.flush_batch()?;
+        }
+        Ok(())
+    }
+
+    fn flush_batch(&mut self) -> Result<()> {
+        if self.batch.records().is_empty() {
+            return Ok(());
+        }
+        let mut batch = std::mem::take(&mut self.batch);
+        batch.get_mut_header().set_compression(self.compression);
+        let batch: Batch<RawRecords> = batch.try_into()?;
+
+        let mut buf = Vec::with_capacity(batch.write_size(0));
+        batch.encode(&mut buf, 0)?;
+        self.writer.write_all(&buf)?;
+
+        self.batch_size = 0;
+        self.summary.batches += 1;
+        Ok(())
+    }
+
+    /// Write pending batch and return summary of the exported partition
+    pub fn finish(mut self) -> Result<ArchivePartition> {
+        self.flush_batch()?;
+        self.writer.flush()?;
+        Ok(self.summary)
+    }
+}
+
+/// Reads batches from a partition file
+pub struct BatchFileReader {
+    reader: BufReader<File>,
+}
+
+impl BatchFileReader {
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let file = File::open(&path)
+            .map_err(|err| anyhow!("unable to open batch file {}: {err}", path.display()))?;
+        Ok(Self {
+            reader: BufReader::new(file),
+        })
+    }
+
+    /// Read next batch, `None` at the end of the file
+    pub fn next_batch(&mut self) -> Result<Option<Batch>> {
+        let mut buf = vec![0u8; BATCH_PREAMBLE_SIZE];
+        match self.reader.read_exact(&mut buf) {
+            Ok(()) => {}
+            Err(err) if err.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(err) => return Err(err.into()),
+        }
+
+        let mut batch_len: i32 = 0;
+        batch_len.decode(&mut &buf[std::mem::size_of::<Offset>()..], 0)?;
+        if batch_len < 0 {
+            return Err(anyhow!("invalid batch length: {batch_len}"));
+        }
+        buf.resize(BATCH_PREAMBLE_SIZE + batch_len as usize, 0);
+        self.reader.read_exact(&mut buf[BATCH_PREAMBLE_SIZE..])?;
+
+        let mut batch: Batch<RawRecords> = Batch::default();
+        batch.decode(&mut &buf[..], 0)?;
+        Ok(Some(batch.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+
+    use super::*;
+
+    #[test]
+    fn test_batch_file_roundtrip() {
+        let archive_dir = temp_dir().join("topic_archive_roundtrip");
+        let _ = std::fs::remove_dir_all(&archive_dir);
+        std::fs::create_dir_all(&archive_dir).expect("create dir");
+
+        let mut writer =
+            BatchFileWriter::create(&archive_dir, 1, Compression::None).expect("create");
+        writer
+            .push(10, 1000, Record::new_key_value("k1", "v1"))
+            .expect("push");
+        writer.push(13, 1500, Record::new("v2")).expect("push");
+        let summary = writer.finish().expect("finish");
+        assert_eq!(summary.start_offset, Some(10));
+        assert_eq!(summary.end_offset, Some(13));
+        assert_eq!(summary.records, 2);
+        assert_eq!(summary.batches, 1);
+
+        let mut reader = BatchFileReader::open(archive_dir.join(summary.file)).expect("open");
+        let batch = reader.next_batch().expect("read").expect("batch");
+        assert_eq!(batch.get_base_offset(), 10);
+        assert_eq!(batch.get_last_offset(), 13);
+        assert_eq!(batch.records()[1].get_header().offset_delta(), 3);
+        assert!(reader.next_batch().expect("read").is_none());
+
+        let records: Vec<_> = batch.into_consumer_records_iter(1).collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].key(), Some("k1".as_bytes()));
+        assert_eq!(records[0].timestamp(), 1000);
+        assert_eq!(records[1].key(), None);
+        assert_eq!(records[1].value(), "v2".as_bytes());
+        assert_eq!(records[1].timestamp(), 1500);
+    }
+}
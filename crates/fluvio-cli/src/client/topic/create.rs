@@ -18,6 +18,7 @@ use fluvio::metadata::topic::ReplicaSpec;
 use fluvio::metadata::topic::SegmentBasedPolicy;
 use fluvio::metadata::topic::TopicStorageConfig;
 use fluvio::metadata::topic::CompressionAlgorithm;
+use fluvio::metadata::topic::FlushPolicy;
 
 use fluvio_controlplane_metadata::topic::config::TopicConfig;
 use fluvio_sc_schema::shared::validate_resource_name;
@@ -71,6 +72,11 @@ pub struct CreateTopicOpt {
     )]
     replication: i16,
 
+    /// Replicas, including leader, which must be in sync with leader
+    /// for read committed producers to write. Can't be greater than replication factor
+    #[arg(long, value_name = "integer", group = "config-arg")]
+    min_in_sync_replicas: Option<u16>,
+
     /// Ignore racks while computing replica assignment
     #[arg(
         short = 'i',
@@ -117,6 +123,11 @@ pub struct CreateTopicOpt {
     )]
     mirror: bool,
 
+    /// Alias of the Topic, which can be used instead of its name to produce and consume.
+    /// Can be specified multiple times
+    #[arg(long = "alias", value_name = "name", group = "config-arg")]
+    aliases: Vec<String>,
+
     /// Validates configuration, does not provision
     #[arg(short = 'd', long)]
     dry_run: bool,
@@ -220,7 +231,10 @@ impl CreateTopicOpt {
             topic_spec.set_compression_type(compression_type);
         }
 
-        if self.setting.segment_size.is_some() || self.setting.max_partition_size.is_some() {
+        if self.setting.segment_size.is_some()
+            || self.setting.max_partition_size.is_some()
+            || self.setting.flush_policy.is_some()
+        {
             let mut storage = TopicStorageConfig::default();
 
             if let Some(segment_size) = self.setting.segment_size {
@@ -231,9 +245,14 @@ impl CreateTopicOpt {
                 storage.max_partition_size = Some(max_partition_size.as_u64());
             }
 
+            storage.flush_policy = self.setting.flush_policy;
+
             topic_spec.set_storage(storage);
         }
 
+        topic_spec.set_aliases(self.aliases);
+        topic_spec.set_min_in_sync_replicas(self.min_in_sync_replicas);
+
         Ok((topic_name, topic_spec))
     }
 }
@@ -268,6 +287,11 @@ pub struct TopicConfigOpt {
     /// Ex: `2048`, '2 Ki', '10 MiB', `1 GB`
     #[arg(long, value_name = "bytes")]
     max_partition_size: Option<bytesize::ByteSize>,
+
+    /// When written records are synced to disk
+    /// Ex: 'os' (default), 'every-write', 'write-count:100', 'interval:500'
+    #[arg(long, value_name = "policy")]
+    flush_policy: Option<FlushPolicy>,
 }
 
 /// module to load partitions maps from file
@@ -121,6 +121,9 @@ mod display {
 
             key_values.push(("Name".to_owned(), Some(self.0.name.clone())));
             key_values.push(("Type".to_owned(), Some(spec.type_label().to_string())));
+            if !spec.aliases().is_empty() {
+                key_values.push(("Aliases".to_owned(), Some(spec.aliases().join(", "))));
+            }
             match spec.replicas() {
                 ReplicaSpec::Computed(param) => {
                     key_values.push((
@@ -147,6 +150,13 @@ mod display {
                 ReplicaSpec::Mirror(_config) => {}
             }
 
+            if let Some(min_in_sync) = spec.get_min_in_sync_replicas() {
+                key_values.push((
+                    "Min In-Sync Replicas".to_owned(),
+                    Some(min_in_sync.to_string()),
+                ));
+            }
+
             if let Some(dedup) = spec.get_deduplication() {
                 key_values.push((
                     "Deduplication Filter".to_owned(),
@@ -0,0 +1,194 @@
+//!
+//! # Export Topic
+//!
+//! CLI tree to export the records of a topic into an archive
+//!
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use clap::Parser;
+use anyhow::{anyhow, Result};
+use futures::StreamExt;
+use tracing::debug;
+
+use fluvio::{Fluvio, Offset};
+use fluvio::Compression;
+use fluvio::consumer::ConsumerConfigExt;
+use fluvio::metadata::topic::TopicSpec;
+use fluvio_types::{PartitionId, Timestamp};
+
+use crate::CliError;
+
+use super::archive::{ArchiveManifest, ArchivePartition, BatchFileWriter, ARCHIVE_VERSION};
+
+/// Export the records of a Topic into an archive directory
+///
+/// The archive contains the topic spec and the records of every partition,
+/// and can be loaded into the same or another cluster with `fluvio topic import`.
+#[derive(Debug, Parser)]
+pub struct ExportTopicOpt {
+    /// The name of the Topic to export
+    #[arg(value_name = "name")]
+    topic: String,
+
+    /// Directory where the archive is written, it must not exist or be empty
+    #[arg(short, long, value_name = "dir")]
+    output: PathBuf,
+
+    /// Partitions to export, all partitions by default
+    #[arg(short = 'p', long, value_name = "integer")]
+    partition: Vec<PartitionId>,
+
+    /// Export records starting from this offset in each partition
+    #[arg(short = 'B', long, value_name = "integer")]
+    start: Option<i64>,
+
+    /// Export records up to this offset in each partition (inclusive)
+    #[arg(short = 'E', long, value_name = "integer")]
+    end: Option<i64>,
+
+    /// Export records with timestamp at or after this time (RFC 3339)
+    #[arg(long, value_name = "time", value_parser = parse_timestamp)]
+    since: Option<Timestamp>,
+
+    /// Export records with timestamp before this time (RFC 3339)
+    #[arg(long, value_name = "time", value_parser = parse_timestamp)]
+    until: Option<Timestamp>,
+
+    /// Compression applied to batches in the archive
+    #[arg(long, value_name = "none|gzip|snappy|lz4|zstd", default_value = "none")]
+    compression: Compression,
+}
+
+impl ExportTopicOpt {
+    pub async fn process(self, fluvio: &Fluvio) -> Result<()> {
+        if let (Some(start), Some(end)) = (self.start, self.end) {
+            if end < start {
+                return Err(CliError::InvalidArg(
+                    "end offset must be greater than or equal to start offset".to_string(),
+                )
+                .into());
+            }
+        }
+
+        let admin = fluvio.admin().await;
+        let topic = admin
+            .list::<TopicSpec, _>(vec![self.topic.clone()])
+            .await?
+            .into_iter()
+            .find(|t| t.name == self.topic)
+            .ok_or_else(|| anyhow!("topic \"{}\" not found", self.topic))?;
+
+        let partition_count = topic.spec.partitions();
+        let partitions: Vec<PartitionId> = if self.partition.is_empty() {
+            (0..partition_count).collect()
+        } else {
+            self.partition.clone()
+        };
+        if let Some(partition) = partitions.iter().find(|p| **p >= partition_count) {
+            return Err(CliError::InvalidArg(format!(
+                "partition {partition} doesn't exist, topic has {partition_count} partitions"
+            ))
+            .into());
+        }
+
+        self.prepare_output_dir()?;
+
+        let mut manifest = ArchiveManifest {
+            version: ARCHIVE_VERSION,
+            topic: self.topic.clone(),
+            spec: topic.spec,
+            compression: self.compression,
+            created_at: now_millis(),
+            partitions: vec![],
+        };
+
+        for partition in partitions {
+            let summary = self.export_partition(fluvio, partition).await?;
+            println!(
+                "exported {} records from partition {partition}",
+                summary.records
+            );
+            manifest.partitions.push(summary);
+        }
+
+        manifest.write_to(&self.output)?;
+        println!(
+            "topic \"{}\" exported to {} ({} records)",
+            self.topic,
+            self.output.display(),
+            manifest.total_records()
+        );
+
+        Ok(())
+    }
+
+    fn prepare_output_dir(&self) -> Result<()> {
+        if self.output.exists() {
+            if self.output.read_dir()?.next().is_some() {
+                return Err(CliError::InvalidArg(format!(
+                    "output directory {} is not empty",
+                    self.output.display()
+                ))
+                .into());
+            }
+        } else {
+            std::fs::create_dir_all(&self.output)?;
+        }
+        Ok(())
+    }
+
+    async fn export_partition(
+        &self,
+        fluvio: &Fluvio,
+        partition: PartitionId,
+    ) -> Result<ArchivePartition> {
+        let offset_start = match self.start {
+            Some(start) => Offset::absolute(start)?,
+            None => Offset::beginning(),
+        };
+
+        let mut builder = ConsumerConfigExt::builder();
+        builder.topic(&self.topic);
+        builder.partition(partition);
+        builder.offset_start(offset_start);
+        builder.disable_continuous(true);
+        let config = builder.build()?;
+        debug!(?config, "exporting partition");
+
+        let mut writer = BatchFileWriter::create(&self.output, partition, self.compression)?;
+        let mut stream = fluvio.consumer_with_config(config).await?;
+
+        while let Some(record) = stream.next().await {
+            let record = record?;
+            let offset = record.offset();
+            if matches!(self.end, Some(end) if offset > end) {
+                break;
+            }
+
+            let timestamp = record.timestamp();
+            if matches!(self.since, Some(since) if timestamp < since)
+                || matches!(self.until, Some(until) if timestamp >= until)
+            {
+                continue;
+            }
+
+            writer.push(offset, timestamp, record.into_inner())?;
+        }
+
+        writer.finish()
+    }
+}
+
+fn parse_timestamp(value: &str) -> Result<Timestamp> {
+    let time = humantime::parse_rfc3339_weak(value)?;
+    let millis = time.duration_since(UNIX_EPOCH)?.as_millis();
+    Ok(millis as Timestamp)
+}
+
+fn now_millis() -> Timestamp {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as Timestamp)
+        .unwrap_or_default()
+}
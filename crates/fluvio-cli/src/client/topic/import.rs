@@ -0,0 +1,228 @@
+//!
+//! # Import Topic
+//!
+//! CLI tree to load an archive created by `fluvio topic export` into a topic
+//!
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+use anyhow::{anyhow, Result};
+use futures::future::join_all;
+use tracing::debug;
+
+use fluvio::{
+    Fluvio, FluvioAdmin, Partitioner, PartitionerConfig, RecordKey, TopicProducerConfigBuilder,
+    TopicProducerPool,
+};
+use fluvio::metadata::topic::{ReplicaSpec, TopicResolution, TopicSpec};
+use fluvio_future::timer::sleep;
+use fluvio_protocol::record::NO_TIMESTAMP;
+use fluvio_types::{PartitionCount, PartitionId};
+
+use crate::CliError;
+
+use super::archive::{ArchiveManifest, ArchivePartition, BatchFileReader};
+
+const TOPIC_PROVISION_TIMEOUT: Duration = Duration::from_secs(30);
+const TOPIC_PROVISION_CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Import records from an archive created by `fluvio topic export`
+///
+/// Unless `--existing` is used, the topic is created with the spec stored in the archive.
+/// Keys, values and timestamps of the records are preserved.
+#[derive(Debug, Parser)]
+pub struct ImportTopicOpt {
+    /// Archive directory
+    #[arg(value_name = "dir")]
+    archive: PathBuf,
+
+    /// Import into a topic with this name instead of the exported topic name
+    #[arg(short, long, value_name = "name")]
+    topic: Option<String>,
+
+    /// Import into an existing topic instead of creating it
+    #[arg(long)]
+    existing: bool,
+
+    /// Send records of each exported partition to the partition with the same id.
+    /// Otherwise records are distributed using the default partitioner.
+    #[arg(long)]
+    preserve_partitions: bool,
+}
+
+impl ImportTopicOpt {
+    pub async fn process(self, fluvio: &Fluvio) -> Result<()> {
+        let manifest = ArchiveManifest::read_from(&self.archive)?;
+        let topic = self.topic.clone().unwrap_or_else(|| manifest.topic.clone());
+        let admin = fluvio.admin().await;
+
+        if !self.existing {
+            let spec = import_spec(manifest.spec.clone())?;
+            debug!("creating topic: {} spec: {:#?}", topic, spec);
+            admin.create(topic.clone(), false, spec).await?;
+            println!("topic \"{topic}\" created");
+        }
+        let partition_count = wait_for_topic(&admin, &topic).await?;
+
+        if self.preserve_partitions {
+            if let Some(partition) = manifest
+                .partitions
+                .iter()
+                .find(|p| p.partition >= partition_count)
+            {
+                return Err(CliError::InvalidArg(format!(
+                    "archive contains partition {} but topic \"{topic}\" has {} partitions",
+                    partition.partition, partition_count
+                ))
+                .into());
+            }
+        }
+
+        let shared_producer = if self.preserve_partitions {
+            None
+        } else {
+            Some(fluvio.topic_producer(&topic).await?)
+        };
+
+        for partition in &manifest.partitions {
+            let records = match &shared_producer {
+                Some(producer) => self.import_partition(producer, partition).await?,
+                None => {
+                    let config = TopicProducerConfigBuilder::default()
+                        .partitioner(Box::new(FixedPartitioner(partition.partition)))
+                        .build()?;
+                    let producer = fluvio.topic_producer_with_config(&topic, config).await?;
+                    self.import_partition(&producer, partition).await?
+                }
+            };
+            println!(
+                "imported {records} records from partition {}",
+                partition.partition
+            );
+        }
+
+        println!(
+            "archive {} imported into topic \"{topic}\" ({} records)",
+            self.archive.display(),
+            manifest.total_records()
+        );
+        Ok(())
+    }
+
+    async fn import_partition(
+        &self,
+        producer: &TopicProducerPool,
+        partition: &ArchivePartition,
+    ) -> Result<u64> {
+        let mut reader = BatchFileReader::open(self.archive.join(&partition.file))?;
+        let mut records = 0;
+        let mut produce_outputs = vec![];
+
+        while let Some(batch) = reader.next_batch()? {
+            for record in batch.into_consumer_records_iter(partition.partition) {
+                let timestamp = record.timestamp();
+                let inner = record.into_inner();
+                let key = RecordKey::from_option(inner.key);
+                let produce_output = if timestamp == NO_TIMESTAMP {
+                    producer.send(key, inner.value).await?
+                } else {
+                    producer
+                        .send_with_timestamp(key, inner.value, timestamp)
+                        .await?
+                };
+                produce_outputs.push(produce_output);
+                records += 1;
+            }
+        }
+        producer.flush().await?;
+
+        // ensure all records were stored
+        join_all(
+            produce_outputs
+                .into_iter()
+                .map(|produce_output| produce_output.wait()),
+        )
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| {
+            anyhow!(
+                "failed to import records of partition {}: {err}",
+                partition.partition
+            )
+        })?;
+
+        if records != partition.records {
+            return Err(anyhow!(
+                "partition {} archive file contains {records} records, manifest expects {}",
+                partition.partition,
+                partition.records
+            ));
+        }
+        Ok(records)
+    }
+}
+
+/// Spec used to create the imported topic.
+///
+/// Replica assignments refer to SPUs of the exporting cluster, so they are
+/// replaced with a computed assignment with the same partitions and replication.
+fn import_spec(mut spec: TopicSpec) -> Result<TopicSpec> {
+    match spec.replicas() {
+        ReplicaSpec::Computed(_) => {}
+        ReplicaSpec::Assigned(_) => {
+            let replicas = ReplicaSpec::new_computed(
+                spec.partitions(),
+                spec.replication_factor().unwrap_or(1),
+                None,
+            );
+            spec.set_replicas(replicas);
+        }
+        ReplicaSpec::Mirror(_) => {
+            return Err(CliError::InvalidArg(
+                "mirror topics can't be created from an archive, use --existing".to_string(),
+            )
+            .into());
+        }
+    }
+    Ok(spec)
+}
+
+/// wait until all partitions of the topic are provisioned, return number of partitions
+async fn wait_for_topic(admin: &FluvioAdmin, topic: &str) -> Result<PartitionCount> {
+    let started = Instant::now();
+    loop {
+        let metadata = admin
+            .list::<TopicSpec, _>(vec![topic.to_string()])
+            .await?
+            .into_iter()
+            .find(|t| t.name == topic)
+            .ok_or_else(|| anyhow!("topic \"{topic}\" not found"))?;
+
+        if metadata.status.resolution == TopicResolution::Provisioned {
+            return Ok(metadata.spec.partitions());
+        }
+        if started.elapsed() > TOPIC_PROVISION_TIMEOUT {
+            return Err(anyhow!(
+                "topic \"{topic}\" was not provisioned, status: {}",
+                metadata.status.resolution
+            ));
+        }
+        sleep(TOPIC_PROVISION_CHECK_INTERVAL).await;
+    }
+}
+
+/// Sends every record to the same partition
+struct FixedPartitioner(PartitionId);
+
+impl Partitioner for FixedPartitioner {
+    fn partition(
+        &self,
+        _config: &PartitionerConfig,
+        _key: Option<&[u8]>,
+        _value: &[u8],
+    ) -> PartitionId {
+        self.0
+    }
+}
@@ -0,0 +1,112 @@
+//!
+//! # Topic Limits CLI
+//!
+//! CLI tree and processing to list cluster limits of Topics and their usage
+//!
+
+use std::sync::Arc;
+
+use clap::Parser;
+use tracing::debug;
+use anyhow::Result;
+
+use fluvio::Fluvio;
+
+use crate::common::output::Terminal;
+use crate::common::OutputFormat;
+
+// -----------------------------------
+// CLI Options
+// -----------------------------------
+
+#[derive(Debug, Parser)]
+pub struct TopicLimitsOpt {
+    /// Output
+    #[clap(flatten)]
+    output: OutputFormat,
+}
+
+impl TopicLimitsOpt {
+    pub async fn process<O: Terminal>(self, out: Arc<O>, fluvio: &Fluvio) -> Result<()> {
+        let output_type = self.output.format;
+        debug!("topic limits {:#?} ", output_type);
+        let admin = fluvio.admin().await;
+
+        let response = admin.topic_limits().await?;
+        display::format_response_output(out, response.usage, output_type)?;
+        Ok(())
+    }
+}
+
+mod display {
+
+    use comfy_table::{Row, Cell};
+    use serde::Serialize;
+
+    use fluvio_sc_schema::limits::LimitUsage;
+
+    use crate::common::output::{OutputType, TableOutputHandler, Terminal, OutputError};
+    use crate::common::t_println;
+
+    #[derive(Serialize)]
+    struct ListLimits(Vec<LimitUsage>);
+
+    /// Process server based on output type
+    pub fn format_response_output<O>(
+        out: std::sync::Arc<O>,
+        usage: Vec<LimitUsage>,
+        output_type: OutputType,
+    ) -> Result<(), OutputError>
+    where
+        O: Terminal,
+    {
+        if !usage.is_empty() {
+            let table_list = ListLimits(usage);
+            out.render_list(&table_list, output_type)
+        } else {
+            t_println!(out, "No topics found");
+            Ok(())
+        }
+    }
+
+    // -----------------------------------
+    // Output Handlers
+    // -----------------------------------
+    impl TableOutputHandler for ListLimits {
+        /// table header implementation
+        fn header(&self) -> Row {
+            Row::from(["LIMIT", "HIGHEST", "USED", "MAX", "STATUS"])
+        }
+
+        /// return errors in string format
+        fn errors(&self) -> Vec<String> {
+            vec![]
+        }
+
+        /// table content implementation
+        fn content(&self) -> Vec<Row> {
+            self.0
+                .iter()
+                .map(|usage| -> Row {
+                    let status = match usage.max {
+                        None => "unlimited",
+                        Some(_) if usage.is_exceeded() => "exceeded",
+                        Some(_) => "ok",
+                    };
+                    Row::from([
+                        Cell::new(usage.limit.to_string()),
+                        Cell::new(&usage.scope),
+                        Cell::new(usage.limit.display_value(usage.used)),
+                        Cell::new(
+                            usage
+                                .max
+                                .map(|max| usage.limit.display_value(max))
+                                .unwrap_or_else(|| "-".to_owned()),
+                        ),
+                        Cell::new(status),
+                    ])
+                })
+                .collect()
+        }
+    }
+}
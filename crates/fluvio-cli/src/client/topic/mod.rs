@@ -4,6 +4,13 @@ mod describe;
 mod list;
 mod add_partition;
 mod add_mirror;
+mod archive;
+mod export;
+mod import;
+mod update;
+mod alias;
+mod rename;
+mod limits;
 
 pub use cmd::TopicCmd;
 
@@ -24,11 +31,17 @@ mod cmd {
     use crate::common::FluvioExtensionMetadata;
 
     use super::add_mirror::AddMirrorOpt;
+    use super::alias::{AddAliasOpt, RemoveAliasOpt};
     use super::add_partition::AddPartitionOpt;
     use super::create::CreateTopicOpt;
     use super::delete::DeleteTopicOpt;
     use super::describe::DescribeTopicsOpt;
+    use super::export::ExportTopicOpt;
+    use super::import::ImportTopicOpt;
+    use super::limits::TopicLimitsOpt;
     use super::list::ListTopicsOpt;
+    use super::rename::RenameTopicOpt;
+    use super::update::UpdateTopicOpt;
 
     #[derive(Debug, Parser)]
     #[command(name = "topic", about = "Topic operations")]
@@ -61,6 +74,13 @@ mod cmd {
         )]
         List(ListTopicsOpt),
 
+        /// List cluster limits of Topics and their current usage
+        #[command(
+            name = "limits",
+            help_template = COMMAND_TEMPLATE,
+        )]
+        Limits(TopicLimitsOpt),
+
         /// Add a new Partition to a Topic
         #[command(
             name = "add-partition",
@@ -74,6 +94,48 @@ mod cmd {
             help_template = COMMAND_TEMPLATE,
         )]
         AddMirror(AddMirrorOpt),
+
+        /// Change the configuration of a Topic
+        #[command(
+            name = "update",
+            help_template = COMMAND_TEMPLATE,
+        )]
+        Update(UpdateTopicOpt),
+
+        /// Rename a Topic, keeping its records and consumer offsets
+        #[command(
+            name = "rename",
+            help_template = COMMAND_TEMPLATE,
+        )]
+        Rename(RenameTopicOpt),
+
+        /// Add an alias to a Topic
+        #[command(
+            name = "add-alias",
+            help_template = COMMAND_TEMPLATE,
+        )]
+        AddAlias(AddAliasOpt),
+
+        /// Remove an alias of a Topic
+        #[command(
+            name = "remove-alias",
+            help_template = COMMAND_TEMPLATE,
+        )]
+        RemoveAlias(RemoveAliasOpt),
+
+        /// Export the records of a Topic into an archive
+        #[command(
+            name = "export",
+            help_template = COMMAND_TEMPLATE,
+        )]
+        Export(ExportTopicOpt),
+
+        /// Create a Topic and load records from an archive
+        #[command(
+            name = "import",
+            help_template = COMMAND_TEMPLATE,
+        )]
+        Import(ImportTopicOpt),
     }
 
     #[async_trait]
@@ -96,12 +158,33 @@ mod cmd {
                 Self::List(list) => {
                     list.process(out, fluvio).await?;
                 }
+                Self::Limits(limits) => {
+                    limits.process(out, fluvio).await?;
+                }
                 Self::AddPartition(add_partition) => {
                     add_partition.process(fluvio).await?;
                 }
                 Self::AddMirror(add_mirror) => {
                     add_mirror.process(fluvio).await?;
                 }
+                Self::Update(update) => {
+                    update.process(fluvio).await?;
+                }
+                Self::Rename(rename) => {
+                    rename.process(fluvio).await?;
+                }
+                Self::AddAlias(add_alias) => {
+                    add_alias.process(fluvio).await?;
+                }
+                Self::RemoveAlias(remove_alias) => {
+                    remove_alias.process(fluvio).await?;
+                }
+                Self::Export(export) => {
+                    export.process(fluvio).await?;
+                }
+                Self::Import(import) => {
+                    import.process(fluvio).await?;
+                }
             }
 
             Ok(())
@@ -0,0 +1,79 @@
+//!
+//! # Rename Topic
+//!
+//! CLI tree to rename a topic, keeping its records and consumer offsets.
+//!
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+use anyhow::Result;
+
+use fluvio_future::timer::sleep;
+use fluvio_sc_schema::topic::{RenameTopic, TopicResolution, TopicSpec, UpdateTopicAction};
+use fluvio::{Fluvio, FluvioAdmin};
+
+const RENAME_TIMEOUT: Duration = Duration::from_secs(60);
+const RENAME_CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Option for renaming a Topic
+///
+/// Partitions and consumer offsets of the Topic are moved to the new name.
+/// Producers and consumers of the Topic must be restarted, unless the old name is kept as alias.
+#[derive(Debug, Parser)]
+pub struct RenameTopicOpt {
+    /// Topic name
+    topic: String,
+    /// New name of the Topic
+    new_name: String,
+    /// Keep the current name as alias of the Topic
+    #[arg(long)]
+    keep_alias: bool,
+}
+
+impl RenameTopicOpt {
+    pub async fn process(self, fluvio: &Fluvio) -> Result<()> {
+        let admin = fluvio.admin().await;
+
+        let action = UpdateTopicAction::Rename(RenameTopic {
+            new_name: self.new_name.clone(),
+            keep_alias: self.keep_alias,
+        });
+        admin
+            .update::<TopicSpec>(self.topic.clone(), action)
+            .await?;
+
+        if self.wait_for_rename(&admin).await? {
+            println!("topic \"{}\" renamed to \"{}\"", self.topic, self.new_name);
+        } else {
+            println!(
+                "topic \"{}\" is being renamed to \"{}\", partitions are still moving",
+                self.topic, self.new_name
+            );
+        }
+
+        Ok(())
+    }
+
+    /// rename is finished by the cluster once partitions are moved,
+    /// return false if it's still in progress after timeout
+    async fn wait_for_rename(&self, admin: &FluvioAdmin) -> Result<bool> {
+        let started = Instant::now();
+        while started.elapsed() < RENAME_TIMEOUT {
+            // new topic is created by the cluster, old topic is deleted once rename is finished
+            let topics = admin
+                .list::<TopicSpec, _>(vec![self.topic.clone(), self.new_name.clone()])
+                .await?;
+            let renamed = !topics.iter().any(|topic| topic.name == self.topic)
+                && topics.iter().any(|topic| {
+                    topic.name == self.new_name
+                        && topic.status.resolution == TopicResolution::Provisioned
+                        && topic.status.renamed_from.is_none()
+                });
+            if renamed {
+                return Ok(true);
+            }
+            sleep(RENAME_CHECK_INTERVAL).await;
+        }
+        Ok(false)
+    }
+}
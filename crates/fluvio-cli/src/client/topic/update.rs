@@ -0,0 +1,170 @@
+//!
+//! # Update Topic config
+//!
+//! CLI tree to change the configuration of an existing topic
+//!
+use std::time::Duration;
+
+use clap::Parser;
+use humantime::parse_duration;
+use anyhow::Result;
+
+use fluvio::Fluvio;
+use fluvio::metadata::topic::{
+    Bounds, CompressionAlgorithm, Deduplication, Filter, FlushPolicy, TopicSpec,
+    TopicStorageConfig, Transform, UpdateTopicAction, UpdateTopicConfig,
+};
+
+use crate::CliError;
+
+/// Change the configuration of an existing Topic
+///
+/// Only the given settings are changed. SPUs apply new settings to running
+/// partitions, segment size and flush policy take effect from the next segment.
+#[derive(Debug, Parser)]
+pub struct UpdateTopicOpt {
+    /// Topic name
+    #[arg(value_name = "name")]
+    topic: String,
+
+    /// Retention time (round to seconds)
+    /// Ex: '1h', '2d 10s', '7 days'
+    #[arg(long, value_name = "time", value_parser = parse_duration)]
+    retention_time: Option<Duration>,
+
+    /// Segment size (by default measured in bytes)
+    /// Ex: `2048`, '2 Ki', '10 MiB', `1 GB`
+    #[arg(long, value_name = "bytes")]
+    segment_size: Option<bytesize::ByteSize>,
+
+    /// Max partition size (by default measured in bytes)
+    /// Ex: `2048`, '2 Ki', '10 MiB', `1 GB`
+    #[arg(long, value_name = "bytes")]
+    max_partition_size: Option<bytesize::ByteSize>,
+
+    /// When written records are synced to disk
+    /// Ex: 'os', 'every-write', 'write-count:100', 'interval:500'
+    #[arg(long, value_name = "policy")]
+    flush_policy: Option<FlushPolicy>,
+
+    /// Compression configuration for topic
+    #[arg(long, value_name = "compression")]
+    compression_type: Option<CompressionAlgorithm>,
+
+    /// SmartModule used to deduplicate records, Ex: 'infinyon/dedup-filter@0.0.2'
+    #[arg(long, value_name = "smartmodule", requires = "dedup_count")]
+    dedup_filter: Option<String>,
+
+    /// Number of records to keep for deduplication
+    #[arg(long, value_name = "count", requires = "dedup_filter")]
+    dedup_count: Option<u64>,
+
+    /// Max age of records kept for deduplication
+    #[arg(long, value_name = "time", value_parser = parse_duration, requires = "dedup_filter")]
+    dedup_age: Option<Duration>,
+
+    /// Remove deduplication of the topic
+    #[arg(long, conflicts_with = "dedup_filter")]
+    no_dedup: bool,
+}
+
+impl UpdateTopicOpt {
+    pub async fn process(self, fluvio: &Fluvio) -> Result<()> {
+        let topic = self.topic.clone();
+        let config = self.config();
+        if config.is_empty() {
+            return Err(CliError::InvalidArg("no topic setting to update".to_string()).into());
+        }
+
+        let admin = fluvio.admin().await;
+        admin
+            .update::<TopicSpec>(topic.clone(), UpdateTopicAction::UpdateConfig(config))
+            .await?;
+        println!("topic \"{topic}\" updated");
+        Ok(())
+    }
+
+    fn config(self) -> UpdateTopicConfig {
+        let storage = if self.segment_size.is_some()
+            || self.max_partition_size.is_some()
+            || self.flush_policy.is_some()
+        {
+            Some(TopicStorageConfig {
+                segment_size: self.segment_size.map(|size| size.as_u64() as u32),
+                max_partition_size: self.max_partition_size.map(|size| size.as_u64()),
+                flush_policy: self.flush_policy,
+            })
+        } else {
+            None
+        };
+
+        let deduplication = self.dedup_filter.map(|uses| Deduplication {
+            bounds: Bounds {
+                count: self.dedup_count.unwrap_or_default(),
+                age: self.dedup_age,
+            },
+            filter: Filter {
+                transform: Transform {
+                    uses,
+                    ..Default::default()
+                },
+            },
+        });
+
+        let config = UpdateTopicConfig {
+            storage,
+            compression_type: self.compression_type,
+            deduplication,
+            remove_deduplication: self.no_dedup,
+            ..Default::default()
+        };
+        match self.retention_time {
+            Some(retention) => config.with_retention_secs(retention.as_secs() as u32),
+            None => config,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use fluvio::metadata::topic::{CleanupPolicy, SegmentBasedPolicy};
+
+    use super::*;
+
+    #[test]
+    fn test_update_topic_config_from_args() {
+        let opt = UpdateTopicOpt::parse_from([
+            "update",
+            "topic1",
+            "--retention-time",
+            "1h",
+            "--segment-size",
+            "2 Ki",
+            "--compression-type",
+            "lz4",
+        ]);
+        let config = opt.config();
+        assert_eq!(
+            config.cleanup_policy,
+            Some(CleanupPolicy::Segment(SegmentBasedPolicy {
+                time_in_seconds: 3600
+            }))
+        );
+        assert_eq!(config.storage.unwrap().segment_size, Some(2048));
+        assert_eq!(config.compression_type, Some(CompressionAlgorithm::Lz4));
+        assert!(config.deduplication.is_none());
+
+        let opt = UpdateTopicOpt::parse_from(["update", "topic1"]);
+        assert!(opt.config().is_empty());
+
+        assert!(UpdateTopicOpt::try_parse_from([
+            "update",
+            "topic1",
+            "--dedup-filter",
+            "infinyon/dedup-filter@0.0.2",
+        ])
+        .is_err());
+    }
+}
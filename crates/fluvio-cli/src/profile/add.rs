@@ -9,7 +9,7 @@ pub struct ManualAddOpt {
     /// Name of profile to add
     profile_name: String,
 
-    /// address of cluster, e.g. 127.0.0.1:9003
+    /// address of cluster, e.g. 127.0.0.1:9003, instances of replicated SC are separated by comma
     cluster_address: String,
 
     /// Installation type of cluster, e.g. local, local-k8, k8
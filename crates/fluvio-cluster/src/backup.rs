@@ -0,0 +1,476 @@
+//!
+//! # Metadata backup
+//!
+//! Snapshot of every object stored by the SC, independent of the metadata backend.
+//! A snapshot taken from a local cluster can be restored into Kubernetes and vice versa.
+//!
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use fluvio_controlplane_metadata::core::{MetadataContext, MetadataItem, Spec};
+use fluvio_controlplane_metadata::store::k8::{K8ExtendedSpec, K8MetaItem};
+use fluvio_controlplane_metadata::store::{MetadataStoreObject, NameSpace};
+use fluvio_sc_schema::mirror::MirrorSpec;
+use fluvio_sc_schema::partition::PartitionSpec;
+use fluvio_sc_schema::smartmodule::SmartModuleSpec;
+use fluvio_sc_schema::spg::SpuGroupSpec;
+use fluvio_sc_schema::spu::SpuSpec;
+use fluvio_sc_schema::tableformat::TableFormatSpec;
+use fluvio_sc_schema::topic::{TopicLimits, TopicSpec};
+use fluvio_stream_dispatcher::metadata::MetadataClient;
+use fluvio_stream_dispatcher::metadata::local::LocalMetadataItem;
+use fluvio_types::SpuId;
+use fluvio_types::defaults::SPU_LOG_DIR_PREFIX;
+
+/// Snapshot format version, incremented on incompatible changes
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Metadata of a backend which can be used for backup and restore
+pub trait SnapshotMetadata: MetadataItem {
+    /// metadata of a new object without owner
+    fn for_key(key: &str, namespace: &NameSpace) -> Self;
+
+    /// key of the owner object
+    fn owner_key(&self) -> Option<String>;
+}
+
+impl SnapshotMetadata for LocalMetadataItem {
+    fn for_key(key: &str, _namespace: &NameSpace) -> Self {
+        LocalMetadataItem::new(key)
+    }
+
+    fn owner_key(&self) -> Option<String> {
+        self.owner().map(|owner| owner.uid().to_owned())
+    }
+}
+
+impl SnapshotMetadata for K8MetaItem {
+    fn for_key(key: &str, namespace: &NameSpace) -> Self {
+        K8MetaItem::new(key.to_owned(), namespace.to_string())
+    }
+
+    fn owner_key(&self) -> Option<String> {
+        self.owner().map(|owner| owner.name.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(bound = "S: Spec")]
+pub struct SnapshotObject<S: Spec> {
+    pub key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    pub spec: S,
+    pub status: S::Status,
+}
+
+/// All SC objects at a point in time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetadataSnapshot {
+    pub version: u32,
+    /// milliseconds since epoch
+    pub created_at: u64,
+    pub platform_version: String,
+    pub spu_groups: Vec<SnapshotObject<SpuGroupSpec>>,
+    pub spus: Vec<SnapshotObject<SpuSpec>>,
+    pub topics: Vec<SnapshotObject<TopicSpec>>,
+    pub partitions: Vec<SnapshotObject<PartitionSpec>>,
+    pub smartmodules: Vec<SnapshotObject<SmartModuleSpec>>,
+    pub tableformats: Vec<SnapshotObject<TableFormatSpec>>,
+    pub mirrors: Vec<SnapshotObject<MirrorSpec>>,
+    /// not present in snapshots taken before limits were stored as metadata
+    #[serde(default)]
+    pub topic_limits: Vec<SnapshotObject<TopicLimits>>,
+}
+
+impl MetadataSnapshot {
+    /// read all objects from the metadata backend
+    pub async fn take<M, C>(
+        client: &C,
+        namespace: &NameSpace,
+        platform_version: String,
+    ) -> Result<Self>
+    where
+        M: SnapshotMetadata,
+        C: MetadataClient<M>,
+    {
+        let created_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+
+        Ok(Self {
+            version: SNAPSHOT_VERSION,
+            created_at,
+            platform_version,
+            spu_groups: read_objects(client, namespace).await?,
+            spus: read_objects(client, namespace).await?,
+            topics: read_objects(client, namespace).await?,
+            partitions: read_objects(client, namespace).await?,
+            smartmodules: read_objects(client, namespace).await?,
+            tableformats: read_objects(client, namespace).await?,
+            mirrors: read_objects(client, namespace).await?,
+            topic_limits: read_objects(client, namespace).await?,
+        })
+    }
+
+    /// Write objects to the metadata backend.
+    ///
+    /// Owners are restored before the objects they own. Statuses are restored as well,
+    /// except for SPUs whose status is reported again when they connect to the SC.
+    pub async fn restore<M, C>(&self, client: &C, namespace: &NameSpace) -> Result<()>
+    where
+        M: SnapshotMetadata,
+        C: MetadataClient<M>,
+    {
+        restore_objects(client, namespace, &self.spu_groups, true).await?;
+        restore_objects(client, namespace, &self.spus, false).await?;
+        restore_objects(client, namespace, &self.topics, true).await?;
+        restore_objects(client, namespace, &self.partitions, true).await?;
+        restore_objects(client, namespace, &self.smartmodules, true).await?;
+        restore_objects(client, namespace, &self.tableformats, true).await?;
+        restore_objects(client, namespace, &self.mirrors, true).await?;
+        restore_objects(client, namespace, &self.topic_limits, true).await?;
+        Ok(())
+    }
+
+    pub fn write_to(&self, path: &Path) -> Result<()> {
+        let content = serde_json::to_vec_pretty(self)?;
+        fs::write(path, content)
+            .with_context(|| format!("writing snapshot to {}", path.display()))?;
+        Ok(())
+    }
+
+    pub fn read_from(path: &Path) -> Result<Self> {
+        let content =
+            fs::read(path).with_context(|| format!("reading snapshot {}", path.display()))?;
+        // check version first, later versions may not be parsable as this one
+        let header: SnapshotHeader = serde_json::from_slice(&content)?;
+        if header.version != SNAPSHOT_VERSION {
+            return Err(anyhow!(
+                "unsupported snapshot version {}, expected {SNAPSHOT_VERSION}",
+                header.version
+            ));
+        }
+        serde_json::from_slice(&content)
+            .with_context(|| format!("parsing snapshot {}", path.display()))
+    }
+
+    /// Compare partitions with replica directories of SPUs in `data_dir`.
+    ///
+    /// Only SPUs which have a log directory under `data_dir` are checked.
+    pub fn check_replicas(&self, data_dir: &Path) -> Result<ReplicaCheck> {
+        let mut check = ReplicaCheck::default();
+        let mut expected: HashMap<SpuId, BTreeSet<String>> = HashMap::new();
+        for partition in &self.partitions {
+            let mut replicas = partition.spec.replicas.clone();
+            if !replicas.contains(&partition.spec.leader) {
+                replicas.push(partition.spec.leader);
+            }
+            for spu in replicas {
+                expected
+                    .entry(spu)
+                    .or_default()
+                    .insert(partition.key.clone());
+            }
+        }
+
+        for (spu, replicas) in expected.iter() {
+            let spu_dir = spu_log_dir(data_dir, *spu);
+            if !spu_dir.is_dir() {
+                debug!(spu, dir = %spu_dir.display(), "spu log dir not found, skipping");
+                continue;
+            }
+            check.checked_spus.insert(*spu);
+            for replica in replicas {
+                if !spu_dir.join(replica).is_dir() {
+                    check.missing.push((*spu, replica.clone()));
+                }
+            }
+        }
+
+        for entry in fs::read_dir(data_dir)
+            .with_context(|| format!("reading data directory {}", data_dir.display()))?
+        {
+            let entry = entry?;
+            let Some(spu) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_prefix(SPU_LOG_DIR_PREFIX))
+                .and_then(|id| SpuId::from_str(id).ok())
+            else {
+                continue;
+            };
+            for replica_dir in fs::read_dir(entry.path())? {
+                let replica_dir = replica_dir?;
+                if !replica_dir.file_type()?.is_dir() {
+                    continue;
+                }
+                let name = replica_dir.file_name().to_string_lossy().to_string();
+                let known = expected
+                    .get(&spu)
+                    .map(|replicas| replicas.contains(&name))
+                    .unwrap_or(false);
+                if !known {
+                    check.orphaned.push(replica_dir.path());
+                }
+            }
+        }
+
+        check.missing.sort();
+        check.orphaned.sort();
+        Ok(check)
+    }
+}
+
+#[derive(Deserialize)]
+struct SnapshotHeader {
+    version: u32,
+}
+
+/// Result of comparing a snapshot with replica directories
+#[derive(Debug, Default)]
+pub struct ReplicaCheck {
+    pub checked_spus: BTreeSet<SpuId>,
+    /// replicas in the snapshot without directory
+    pub missing: Vec<(SpuId, String)>,
+    /// replica directories not in the snapshot, their records would not be reachable
+    pub orphaned: Vec<PathBuf>,
+}
+
+impl ReplicaCheck {
+    pub fn is_valid(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+fn spu_log_dir(data_dir: &Path, spu: SpuId) -> PathBuf {
+    data_dir.join(format!("{SPU_LOG_DIR_PREFIX}{spu}"))
+}
+
+async fn read_objects<S, M, C>(client: &C, namespace: &NameSpace) -> Result<Vec<SnapshotObject<S>>>
+where
+    S: K8ExtendedSpec,
+    M: SnapshotMetadata,
+    C: MetadataClient<M>,
+{
+    let mut objects: Vec<SnapshotObject<S>> = client
+        .retrieve_items::<S>(namespace)
+        .await
+        .with_context(|| format!("reading {}", S::LABEL))?
+        .items
+        .into_iter()
+        .map(|item| {
+            let owner = item.ctx().item().owner_key();
+            let (key, spec, status, _) = item.parts();
+            SnapshotObject {
+                key: key.to_string(),
+                owner,
+                spec,
+                status,
+            }
+        })
+        .collect();
+    objects.sort_by(|a, b| a.key.cmp(&b.key));
+    debug!(label = S::LABEL, count = objects.len(), "read objects");
+    Ok(objects)
+}
+
+async fn restore_objects<S, M, C>(
+    client: &C,
+    namespace: &NameSpace,
+    objects: &[SnapshotObject<S>],
+    with_status: bool,
+) -> Result<()>
+where
+    S: K8ExtendedSpec,
+    <S as Spec>::Owner: K8ExtendedSpec,
+    M: SnapshotMetadata,
+    C: MetadataClient<M>,
+{
+    if objects.is_empty() {
+        return Ok(());
+    }
+
+    let owners: HashMap<String, MetadataContext<M>> =
+        if objects.iter().any(|object| object.owner.is_some()) {
+            client
+                .retrieve_items::<S::Owner>(namespace)
+                .await?
+                .items
+                .into_iter()
+                .map(|owner| (owner.key().to_string(), owner.ctx().clone()))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+    for object in objects {
+        let ctx = match &object.owner {
+            Some(owner) => owners
+                .get(owner)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "owner \"{owner}\" of {} \"{}\" was not restored",
+                        S::LABEL,
+                        object.key
+                    )
+                })?
+                .create_child(),
+            None => MetadataContext::new(M::for_key(&object.key, namespace)),
+        };
+        let key = S::IndexKey::from_str(&object.key)
+            .map_err(|_| anyhow!("invalid {} key: {}", S::LABEL, object.key))?;
+        let mut value = MetadataStoreObject::new_with_context(key, object.spec.clone(), ctx);
+        value.set_status(object.status.clone());
+        client
+            .apply(value)
+            .await
+            .with_context(|| format!("restoring {} \"{}\"", S::LABEL, object.key))?;
+    }
+
+    if with_status {
+        // some backends ignore status on apply
+        let statuses: HashMap<&str, &S::Status> = objects
+            .iter()
+            .map(|object| (object.key.as_str(), &object.status))
+            .collect();
+        for item in client.retrieve_items::<S>(namespace).await?.items {
+            let key = item.key().to_string();
+            if let Some(status) = statuses.get(key.as_str()) {
+                if item.status() != *status {
+                    client
+                        .update_status::<S>(item.ctx().item().clone(), (*status).clone(), namespace)
+                        .await?;
+                }
+            }
+        }
+    }
+    debug!(label = S::LABEL, count = objects.len(), "restored objects");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use fluvio_stream_dispatcher::metadata::local::LocalMetadataStorage;
+    use fluvio_sc_schema::topic::TopicStatus;
+    use fluvio_sc_schema::partition::{PartitionStatus, ReplicaKey};
+
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).expect("dir");
+        dir
+    }
+
+    async fn populate(client: &LocalMetadataStorage) {
+        let mut topic = MetadataStoreObject::<TopicSpec, LocalMetadataItem>::new_with_context(
+            "orders",
+            TopicSpec::new_computed(1, 1, None),
+            MetadataContext::new(LocalMetadataItem::new("orders")),
+        );
+        topic.set_status(TopicStatus {
+            reason: "provisioned".to_string(),
+            ..Default::default()
+        });
+        client.apply(topic).await.expect("topic");
+
+        let topic = client
+            .retrieve_items::<TopicSpec>(&NameSpace::All)
+            .await
+            .expect("topics")
+            .items
+            .remove(0);
+        let partition = MetadataStoreObject::<PartitionSpec, LocalMetadataItem>::new_with_context(
+            "orders-0".parse::<ReplicaKey>().expect("key"),
+            PartitionSpec::new(5001, vec![5001]),
+            topic.ctx().create_child(),
+        );
+        client.apply(partition).await.expect("partition");
+    }
+
+    #[fluvio_future::test]
+    async fn test_snapshot_restore_local() {
+        let source = LocalMetadataStorage::new(temp_dir("backup-source"));
+        populate(&source).await;
+
+        let snapshot = MetadataSnapshot::take(&source, &NameSpace::All, "0.1.0".to_string())
+            .await
+            .expect("snapshot");
+        assert_eq!(snapshot.topics.len(), 1);
+        assert_eq!(snapshot.partitions.len(), 1);
+        assert_eq!(snapshot.partitions[0].owner.as_deref(), Some("orders"));
+
+        let file = temp_dir("backup-file").join("snapshot.json");
+        snapshot.write_to(&file).expect("write");
+        let snapshot = MetadataSnapshot::read_from(&file).expect("read");
+
+        let target = LocalMetadataStorage::new(temp_dir("backup-target"));
+        snapshot
+            .restore(&target, &NameSpace::All)
+            .await
+            .expect("restore");
+
+        let restored = MetadataSnapshot::take(&target, &NameSpace::All, "0.1.0".to_string())
+            .await
+            .expect("snapshot");
+        assert_eq!(restored.topics, snapshot.topics);
+        assert_eq!(restored.partitions, snapshot.partitions);
+        assert_eq!(restored.topics[0].status.reason, "provisioned");
+        assert_eq!(restored.partitions[0].status, PartitionStatus::default());
+    }
+
+    #[test]
+    fn test_check_replicas() {
+        let data_dir = temp_dir("backup-replicas");
+        fs::create_dir_all(data_dir.join("spu-logs-5001/orders-0")).expect("dir");
+        fs::create_dir_all(data_dir.join("spu-logs-5001/deleted-0")).expect("dir");
+
+        let snapshot = MetadataSnapshot {
+            version: SNAPSHOT_VERSION,
+            created_at: 0,
+            platform_version: "0.1.0".to_string(),
+            spu_groups: vec![],
+            spus: vec![],
+            topics: vec![],
+            partitions: vec![
+                SnapshotObject {
+                    key: "orders-0".to_string(),
+                    owner: Some("orders".to_string()),
+                    spec: PartitionSpec::new(5001, vec![5001]),
+                    status: Default::default(),
+                },
+                SnapshotObject {
+                    key: "orders-1".to_string(),
+                    owner: Some("orders".to_string()),
+                    spec: PartitionSpec::new(5001, vec![5001, 5002]),
+                    status: Default::default(),
+                },
+            ],
+            smartmodules: vec![],
+            tableformats: vec![],
+            mirrors: vec![],
+            topic_limits: vec![],
+        };
+
+        let check = snapshot.check_replicas(&data_dir).expect("check");
+        // spu 5002 has no directory on this host
+        assert_eq!(check.checked_spus, BTreeSet::from([5001]));
+        assert_eq!(check.missing, vec![(5001, "orders-1".to_string())]);
+        assert_eq!(
+            check.orphaned,
+            vec![data_dir.join("spu-logs-5001/deleted-0")]
+        );
+        assert!(!check.is_valid());
+    }
+}
@@ -0,0 +1,259 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use colored::Colorize;
+use semver::Version;
+use tracing::debug;
+
+use fluvio_extension_common::installation::InstallationType;
+use fluvio_sc_schema::store::NameSpace;
+use fluvio_sc_schema::topic::TopicSpec;
+use fluvio_stream_dispatcher::metadata::MetadataClient;
+use fluvio_stream_dispatcher::metadata::local::LocalMetadataStorage;
+use k8_client::SharedK8Client;
+
+use crate::DEFAULT_NAMESPACE;
+use crate::backup::{MetadataSnapshot, SnapshotMetadata};
+use crate::cli::get_installation_type;
+use crate::cli::shutdown::ShutdownOpt;
+use crate::start::local::{DEFAULT_DATA_DIR, DEFAULT_METADATA_SUB_DIR};
+
+/// Options selecting the metadata backend of the current cluster
+#[derive(Debug, Parser)]
+pub struct MetadataBackendOpt {
+    /// Kubernetes namespace of the cluster
+    #[arg(long, value_name = "Kubernetes namespace", default_value = DEFAULT_NAMESPACE)]
+    namespace: String,
+
+    /// Metadata directory of a local cluster, defaults to the local cluster data directory
+    #[arg(long, value_name = "dir")]
+    metadata_dir: Option<PathBuf>,
+}
+
+enum MetadataBackend {
+    Local(LocalMetadataStorage),
+    K8(SharedK8Client, NameSpace),
+}
+
+impl MetadataBackendOpt {
+    fn backend(&self, installation_type: &InstallationType) -> Result<MetadataBackend> {
+        match installation_type {
+            InstallationType::Local | InstallationType::ReadOnly => {
+                let path = match &self.metadata_dir {
+                    Some(path) => path.clone(),
+                    None => DEFAULT_DATA_DIR
+                        .as_ref()
+                        .ok_or(anyhow!("Data path not set"))?
+                        .join(DEFAULT_METADATA_SUB_DIR),
+                };
+                debug!(path = %path.display(), "using local metadata");
+                Ok(MetadataBackend::Local(LocalMetadataStorage::new(path)))
+            }
+            InstallationType::K8 | InstallationType::LocalK8 => Ok(MetadataBackend::K8(
+                k8_client::load_and_share()?,
+                NameSpace::Named(self.namespace.clone()),
+            )),
+            other => bail!("backup is not supported for {other} installation type"),
+        }
+    }
+}
+
+/// Save all SC metadata into a snapshot file
+///
+/// The snapshot contains topics, partitions, SPUs, SPU groups, SmartModules,
+/// table formats and mirrors, with their status. It can be loaded with `fluvio cluster restore`.
+#[derive(Debug, Parser)]
+pub struct BackupOpt {
+    /// Snapshot file to write
+    #[arg(short, long, value_name = "file")]
+    output: PathBuf,
+
+    #[command(flatten)]
+    backend: MetadataBackendOpt,
+}
+
+impl BackupOpt {
+    pub async fn process(self, platform_version: Version) -> Result<()> {
+        let (installation_type, _) = get_installation_type()?;
+        debug!(?installation_type);
+
+        let platform_version = platform_version.to_string();
+        let snapshot = match self.backend.backend(&installation_type)? {
+            MetadataBackend::Local(client) => {
+                MetadataSnapshot::take(&client, &NameSpace::All, platform_version).await?
+            }
+            MetadataBackend::K8(client, namespace) => {
+                MetadataSnapshot::take(client.as_ref(), &namespace, platform_version).await?
+            }
+        };
+        snapshot.write_to(&self.output)?;
+
+        println!(
+            "✅ {}",
+            format!(
+                "Saved {} topics, {} partitions, {} SmartModules to {}",
+                snapshot.topics.len(),
+                snapshot.partitions.len(),
+                snapshot.smartmodules.len(),
+                self.output.display()
+            )
+            .bold()
+        );
+        Ok(())
+    }
+}
+
+/// Restore SC metadata from a snapshot created by `fluvio cluster backup`
+///
+/// Partitions are checked against replica directories of the SPUs before they are restored,
+/// so that their records can be served again. A local cluster is shut down before restore.
+/// The SC of a Kubernetes cluster must be stopped while restoring.
+#[derive(Debug, Parser)]
+pub struct RestoreOpt {
+    /// Snapshot file
+    #[arg(value_name = "file")]
+    snapshot: PathBuf,
+
+    #[command(flatten)]
+    backend: MetadataBackendOpt,
+
+    /// Directory containing SPU replica directories (`spu-logs-<id>`).
+    /// Defaults to the local cluster data directory.
+    #[arg(long, value_name = "dir")]
+    data_dir: Option<PathBuf>,
+
+    /// Restore without checking SPU replica directories
+    #[arg(long)]
+    skip_replica_check: bool,
+
+    /// Do not prompt for confirmation, and restore into a cluster which already has topics
+    #[arg(short, long)]
+    force: bool,
+}
+
+impl RestoreOpt {
+    pub async fn process(self) -> Result<()> {
+        let (installation_type, _) = get_installation_type()?;
+        debug!(?installation_type);
+
+        let snapshot = MetadataSnapshot::read_from(&self.snapshot)?;
+        println!(
+            "📝 {}",
+            format!(
+                "Snapshot of platform version {} with {} topics, {} partitions, {} SmartModules",
+                snapshot.platform_version,
+                snapshot.topics.len(),
+                snapshot.partitions.len(),
+                snapshot.smartmodules.len(),
+            )
+            .bold()
+        );
+
+        if self.skip_replica_check {
+            println!("⚠️  Skipping replica check");
+        } else {
+            self.check_replicas(&snapshot, &installation_type)?;
+        }
+
+        let backend = self.backend.backend(&installation_type)?;
+        let topics = match &backend {
+            MetadataBackend::Local(client) => count_topics(client, &NameSpace::All).await?,
+            MetadataBackend::K8(client, namespace) => {
+                count_topics(client.as_ref(), namespace).await?
+            }
+        };
+        if topics > 0 && !self.force {
+            bail!("cluster already has {topics} topics, use --force to restore over them");
+        }
+
+        if !self.force {
+            let prompt = dialoguer::Confirm::new()
+                .with_prompt("Restore metadata of the current cluster from the snapshot?")
+                .interact()?;
+            if !prompt {
+                println!("Restore cancelled");
+                return Ok(());
+            }
+        }
+
+        let local = matches!(
+            installation_type,
+            InstallationType::Local | InstallationType::LocalK8 | InstallationType::ReadOnly
+        );
+        if local {
+            ShutdownOpt.process().await?;
+        }
+
+        match &backend {
+            MetadataBackend::Local(client) => snapshot.restore(client, &NameSpace::All).await?,
+            MetadataBackend::K8(client, namespace) => {
+                snapshot.restore(client.as_ref(), namespace).await?
+            }
+        }
+
+        println!("🎉 {}", "Restored cluster metadata".bold());
+        if local {
+            println!(
+                "Run: {} to start the cluster again",
+                "fluvio cluster resume".bold()
+            );
+        }
+        Ok(())
+    }
+
+    fn check_replicas(
+        &self,
+        snapshot: &MetadataSnapshot,
+        installation_type: &InstallationType,
+    ) -> Result<()> {
+        let data_dir = match (&self.data_dir, installation_type) {
+            (Some(data_dir), _) => data_dir.clone(),
+            (None, InstallationType::K8) => bail!(
+                "replica directories of SPUs running in Kubernetes can't be checked, \
+                use --data-dir with a copy of SPU volumes or --skip-replica-check"
+            ),
+            (None, _) => DEFAULT_DATA_DIR
+                .clone()
+                .ok_or(anyhow!("Data path not set"))?,
+        };
+
+        let check = snapshot.check_replicas(&data_dir)?;
+        if check.checked_spus.is_empty() && !snapshot.partitions.is_empty() {
+            bail!("no SPU replica directory found in {}", data_dir.display());
+        }
+        for orphan in &check.orphaned {
+            println!(
+                "⚠️  Replica directory {} is not in the snapshot, its records will not be reachable",
+                orphan.display()
+            );
+        }
+        if !check.is_valid() {
+            for (spu, replica) in &check.missing {
+                println!("❌ Replica {replica} not found on SPU {spu}");
+            }
+            bail!(
+                "{} replicas of the snapshot are missing in {}",
+                check.missing.len(),
+                data_dir.display()
+            );
+        }
+        println!(
+            "✅ {}",
+            format!("Checked replicas of SPUs {:?}", check.checked_spus).bold()
+        );
+        Ok(())
+    }
+}
+
+async fn count_topics<M, C>(client: &C, namespace: &NameSpace) -> Result<usize>
+where
+    M: SnapshotMetadata,
+    C: MetadataClient<M>,
+{
+    Ok(client
+        .retrieve_items::<TopicSpec>(namespace)
+        .await?
+        .items
+        .len())
+}
@@ -19,6 +19,7 @@ mod diagnostics;
 mod status;
 mod shutdown;
 mod upgrade;
+mod backup;
 
 use start::StartOpt;
 use resume::ResumeOpt;
@@ -30,6 +31,7 @@ use diagnostics::DiagnosticsOpt;
 use status::StatusOpt;
 use shutdown::ShutdownOpt;
 use upgrade::UpgradeOpt;
+use backup::{BackupOpt, RestoreOpt};
 
 pub use self::error::ClusterCliError;
 
@@ -94,6 +96,14 @@ pub enum ClusterCmd {
     /// Shutdown cluster processes without deleting data
     #[command(name = "shutdown")]
     Shutdown(ShutdownOpt),
+
+    /// Save SC metadata into a snapshot file
+    #[command(name = "backup")]
+    Backup(BackupOpt),
+
+    /// Restore SC metadata from a snapshot file
+    #[command(name = "restore")]
+    Restore(RestoreOpt),
 }
 
 impl ClusterCmd {
@@ -169,6 +179,12 @@ impl ClusterCmd {
             Self::Shutdown(opt) => {
                 opt.process().await?;
             }
+            Self::Backup(opt) => {
+                opt.process(platform_version).await?;
+            }
+            Self::Restore(opt) => {
+                opt.process().await?;
+            }
         }
 
         Ok(())
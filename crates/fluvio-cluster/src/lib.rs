@@ -33,6 +33,7 @@ mod render;
 mod delete;
 mod error;
 mod progress;
+mod backup;
 pub mod runtime;
 
 /// extensions
@@ -47,6 +48,8 @@ pub use helm::HelmError;
 pub use check::{ClusterChecker, CheckStatus, CheckStatuses, CheckResult, CheckResults};
 pub use check::{RecoverableCheck, UnrecoverableCheckStatus, CheckSuggestion};
 pub use delete::*;
+pub use backup::{MetadataSnapshot, SnapshotMetadata, SnapshotObject, ReplicaCheck};
+pub use backup::SNAPSHOT_VERSION;
 pub use fluvio::config as fluvio_config;
 pub use fluvio_extension_common::installation::InstallationType;
 
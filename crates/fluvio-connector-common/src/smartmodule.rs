@@ -1,4 +1,8 @@
-use fluvio::{FluvioConfig, SmartModuleInvocation, SmartModuleKind, SmartModuleExtraParams};
+use fluvio::{
+    FluvioConfig, SmartModuleInvocation, SmartModuleKind, SmartModuleExtraParams,
+    SmartModuleErrorPolicy,
+};
+use fluvio_smartengine::transformation::ErrorPolicy;
 
 use crate::{config::ConnectorConfig, Result};
 
@@ -52,6 +56,13 @@ pub fn smartmodule_vec_from_config(config: &ConnectorConfig) -> Option<Vec<Smart
                         .collect::<std::collections::BTreeMap<String, String>>(),
                     s.lookback.map(Into::into),
                 ),
+                on_error: match &s.on_error {
+                    ErrorPolicy::Fail => SmartModuleErrorPolicy::Fail,
+                    ErrorPolicy::Skip => SmartModuleErrorPolicy::Skip,
+                    ErrorPolicy::DeadLetter(topic) => {
+                        SmartModuleErrorPolicy::DeadLetter(topic.clone())
+                    }
+                },
             })
             .collect(),
     )
@@ -719,6 +719,7 @@ mod tests {
                     ),
                     ("param".to_string(), "param_value".into()),
                 ]),
+                ..Default::default()
             }],
         });
 
@@ -748,6 +749,7 @@ mod tests {
                         max_size: Some(bytesize::ByteSize(1000)),
                         replication: Some(2),
                         ignore_rack_assignment: Some(true),
+                        min_in_sync_replicas: None,
                         maps: None,
                     },
                     retention: RetentionConfig {
@@ -770,6 +772,7 @@ mod tests {
                             },
                         },
                     }),
+                    storage: None,
                 },
                 version: "0.1.0".to_string(),
                 producer: Some(ProducerParameters {
@@ -801,6 +804,7 @@ mod tests {
                     ),
                     ("param".to_string(), "param_value".into()),
                 ]),
+                ..Default::default()
             }],
         });
 
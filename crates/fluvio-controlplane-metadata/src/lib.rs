@@ -7,9 +7,13 @@ pub mod tableformat;
 pub mod message;
 pub mod mirror;
 pub mod mirroring;
+pub mod tenant;
 
 pub use fluvio_stream_model::core;
 
+/// version of metadata objects, used by every API which encodes them
+pub const METADATA_VERSION: i16 = 21;
+
 pub mod store {
     pub use fluvio_stream_model::store::*;
 }
@@ -34,6 +38,7 @@ pub mod extended {
         TableFormat,
         DerivedStream,
         Mirror,
+        TopicLimits,
     }
 
     pub trait SpecExt: Spec {
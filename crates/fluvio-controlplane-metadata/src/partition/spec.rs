@@ -37,6 +37,25 @@ pub struct PartitionSpec {
     #[cfg_attr(feature = "use_serde", serde(default))]
     #[fluvio(min_version = 14)]
     pub mirror: Option<PartitionMirrorConfig>,
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    #[fluvio(min_version = 20)]
+    pub aliases: Vec<String>,
+    /// topic name of the partition before it was renamed, until SPUs have moved its replica
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    #[fluvio(min_version = 20)]
+    pub renamed_from: Option<String>,
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    #[fluvio(min_version = 21)]
+    pub min_in_sync_replicas: Option<u16>,
 }
 
 impl PartitionSpec {
@@ -65,9 +84,36 @@ impl PartitionSpec {
             compression_type: topic.get_compression_type().clone(),
             deduplication: topic.get_deduplication().cloned(),
             system: topic.is_system(),
+            aliases: topic.aliases().to_vec(),
+            renamed_from: None,
+            min_in_sync_replicas: topic.get_min_in_sync_replicas(),
         }
     }
 
+    /// update config of partition from topic spec, return true if it was changed
+    pub fn update_config_from_topic(&mut self, topic: &TopicSpec) -> bool {
+        let cleanup_policy = topic.get_clean_policy().cloned();
+        let storage = topic.get_storage().cloned();
+        let compression_type = topic.get_compression_type().clone();
+        let deduplication = topic.get_deduplication().cloned();
+        if self.cleanup_policy == cleanup_policy
+            && self.storage == storage
+            && self.compression_type == compression_type
+            && self.deduplication == deduplication
+            && self.aliases == topic.aliases()
+            && self.min_in_sync_replicas == topic.get_min_in_sync_replicas()
+        {
+            return false;
+        }
+        self.cleanup_policy = cleanup_policy;
+        self.storage = storage;
+        self.compression_type = compression_type;
+        self.deduplication = deduplication;
+        self.aliases = topic.aliases().to_vec();
+        self.min_in_sync_replicas = topic.get_min_in_sync_replicas();
+        true
+    }
+
     pub fn has_spu(&self, spu: &SpuId) -> bool {
         self.replicas.contains(spu)
     }
@@ -41,6 +41,17 @@ pub struct PartitionStatus {
     #[cfg_attr(feature = "use_serde", serde(default))]
     #[fluvio(min_version = 16)]
     pub base_offset: i64,
+    /// offset of corrupted batch reported by leader
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    #[fluvio(min_version = 18)]
+    pub corrupted_offset: Option<Offset>,
+    /// replicas in sync with leader, including leader, as seen by leader
+    #[cfg_attr(feature = "use_serde", serde(default))]
+    #[fluvio(min_version = 21)]
+    pub in_sync_replicas: u32,
 }
 
 impl Default for PartitionStatus {
@@ -53,6 +64,8 @@ impl Default for PartitionStatus {
             replicas: Default::default(),
             is_being_deleted: Default::default(),
             base_offset: Default::default(),
+            corrupted_offset: Default::default(),
+            in_sync_replicas: Default::default(),
         }
     }
 }
@@ -110,6 +123,11 @@ impl PartitionStatus {
         }
     }
 
+    /// leader found corrupted batch and is fenced
+    pub fn is_corrupted(&self) -> bool {
+        self.corrupted_offset.is_some()
+    }
+
     pub fn is_online(&self) -> bool {
         self.resolution == PartitionResolution::Online
     }
@@ -0,0 +1,83 @@
+//!
+//! # Tenant Names
+//!
+//! Objects of a tenant are named `<tenant>.<name>`, so names are unique per tenant
+//! while every object still has a cluster wide key.
+//!
+use crate::extended::ObjectType;
+
+/// separator between tenant and object name
+pub const TENANT_SEPARATOR: char = '.';
+
+/// name of object `name` owned by `tenant`
+pub fn qualified_name(tenant: &str, name: &str) -> String {
+    format!("{tenant}{TENANT_SEPARATOR}{name}")
+}
+
+/// name of object `name` in `tenant`. Names which are already qualified are unchanged,
+/// as well as names of SmartModule packages, which are shared by the cluster
+pub fn qualify(tenant: &str, name: &str) -> String {
+    if name.contains([TENANT_SEPARATOR, '/', '@']) {
+        name.to_owned()
+    } else {
+        qualified_name(tenant, name)
+    }
+}
+
+/// tenant and object name of qualified name, tenant is none for cluster wide names
+pub fn split_tenant(name: &str) -> (Option<&str>, &str) {
+    match name.split_once(TENANT_SEPARATOR) {
+        Some((tenant, name)) => (Some(tenant), name),
+        None => (None, name),
+    }
+}
+
+/// check if object with key `name` is owned by `tenant`.
+/// partition keys start with topic name, so they are owned by tenant of the topic
+pub fn is_owned_by(name: &str, tenant: &str) -> bool {
+    name.len() > tenant.len() + 1
+        && name.starts_with(tenant)
+        && name[tenant.len()..].starts_with(TENANT_SEPARATOR)
+}
+
+/// object types which names are scoped per tenant
+pub fn is_tenant_scoped(ty: &ObjectType) -> bool {
+    matches!(
+        ty,
+        ObjectType::Topic
+            | ObjectType::Partition
+            | ObjectType::SmartModule
+            | ObjectType::TableFormat
+    )
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_qualified_name() {
+        let name = qualified_name("team-a", "orders");
+        assert_eq!(name, "team-a.orders");
+        assert_eq!(split_tenant(&name), (Some("team-a"), "orders"));
+        assert_eq!(split_tenant("orders"), (None, "orders"));
+
+        assert_eq!(qualify("team-a", "orders"), "team-a.orders");
+        assert_eq!(qualify("team-a", "team-b.orders"), "team-b.orders");
+        assert_eq!(
+            qualify("team-a", "infinyon/jolt@0.4.1"),
+            "infinyon/jolt@0.4.1"
+        );
+    }
+
+    #[test]
+    fn test_is_owned_by() {
+        assert!(is_owned_by("team-a.orders", "team-a"));
+        assert!(is_owned_by("team-a.orders-0", "team-a"));
+        assert!(!is_owned_by("team-a.orders", "team"));
+        assert!(!is_owned_by("team-ab.orders", "team-a"));
+        assert!(!is_owned_by("team-a.", "team-a"));
+        assert!(!is_owned_by("orders", "team-a"));
+    }
+}
@@ -5,6 +5,7 @@ use fluvio_types::{ReplicationFactor, TopicName, PartitionCount, IgnoreRackAssig
 
 use crate::topic::{
     ReplicaSpec, TopicReplicaParam, SegmentBasedPolicy, CleanupPolicy, TopicStorageConfig,
+    FlushPolicy,
 };
 
 use super::{TopicSpec, PartitionMap, CompressionAlgorithm, deduplication::Deduplication};
@@ -45,6 +46,13 @@ pub struct TopicConfig {
         serde(default, skip_serializing_if = "Option::is_none")
     )]
     pub deduplication: Option<Deduplication>,
+
+    #[builder(default)]
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub storage: Option<StorageConfig>,
 }
 
 #[derive(Debug, Default, Builder, Clone, PartialEq, Eq)]
@@ -88,6 +96,14 @@ pub struct PartitionConfig {
     )]
     pub ignore_rack_assignment: Option<IgnoreRackAssignment>,
 
+    /// replicas which must be in sync to accept read committed writes
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(skip_serializing_if = "Option::is_none", default)
+    )]
+    #[builder(default)]
+    pub min_in_sync_replicas: Option<u16>,
+
     #[cfg_attr(
         feature = "use_serde",
         serde(skip_serializing_if = "Option::is_none", default)
@@ -130,6 +146,20 @@ pub struct CompressionConfig {
     pub type_: CompressionAlgorithm,
 }
 
+#[derive(Debug, Default, Builder, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub struct StorageConfig {
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(skip_serializing_if = "Option::is_none", default)
+    )]
+    pub flush_policy: Option<FlushPolicy>,
+}
+
 impl TopicConfig {
     #[cfg(feature = "use_serde")]
     pub fn from_file<P: AsRef<std::path::Path>>(path: P) -> anyhow::Result<Self> {
@@ -153,6 +183,7 @@ impl Default for PartitionConfig {
             replication: Some(DEFAULT_REPLICATION_FACTOR),
             ignore_rack_assignment: Some(DEFAULT_IGNORE_RACK_ASSIGMENT),
             max_size: Default::default(),
+            min_in_sync_replicas: Default::default(),
             maps: Default::default(),
         }
     }
@@ -162,6 +193,7 @@ impl From<TopicConfig> for TopicSpec {
     fn from(config: TopicConfig) -> Self {
         let segment_size = config.retention.segment_size.map(|s| s.as_u64() as u32);
         let max_partition_size = config.partition.max_size.map(|s| s.as_u64());
+        let flush_policy = config.storage.and_then(|s| s.flush_policy);
 
         let replica_spec = match config.partition.maps {
             Some(maps) => ReplicaSpec::Assigned(maps.into()),
@@ -186,11 +218,13 @@ impl From<TopicConfig> for TopicSpec {
 
         topic_spec.set_compression_type(config.compression.type_);
         topic_spec.set_deduplication(config.deduplication);
+        topic_spec.set_min_in_sync_replicas(config.partition.min_in_sync_replicas);
 
-        if segment_size.is_some() || max_partition_size.is_some() {
+        if segment_size.is_some() || max_partition_size.is_some() || flush_policy.is_some() {
             topic_spec.set_storage(TopicStorageConfig {
                 segment_size,
                 max_partition_size,
+                flush_policy,
             });
         }
 
@@ -220,6 +254,7 @@ partition:
   max-size: 1.0 KB
   replication: 2
   ignore-rack-assignment: true
+  min-in-sync-replicas: 2
   maps:
   - id: 1
     replicas:
@@ -237,6 +272,8 @@ deduplication:
   filter:
     transform:
       uses: infinyon/dedup-filter@0.1.0
+storage:
+  flush-policy: every-write
 "#;
 
         //when
@@ -331,8 +368,10 @@ compression:
         test_spec.set_storage(TopicStorageConfig {
             segment_size: Some(2000),
             max_partition_size: Some(1000),
+            flush_policy: Some(FlushPolicy::EveryWrite),
         });
         test_spec.set_deduplication(Some(test_deduplication()));
+        test_spec.set_min_in_sync_replicas(Some(2));
 
         assert_eq!(spec, test_spec);
     }
@@ -348,6 +387,7 @@ compression:
                 max_size: Some(bytesize::ByteSize(1000)),
                 replication: Some(2),
                 ignore_rack_assignment: Some(true),
+                min_in_sync_replicas: Some(2),
                 maps: Some(vec![PartitionMap {
                     id: 1,
                     replicas: vec![1, 2],
@@ -362,6 +402,9 @@ compression:
                 type_: CompressionAlgorithm::Lz4,
             },
             deduplication: Some(test_deduplication()),
+            storage: Some(StorageConfig {
+                flush_policy: Some(FlushPolicy::EveryWrite),
+            }),
         }
     }
 
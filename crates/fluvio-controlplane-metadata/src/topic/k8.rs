@@ -1,7 +1,8 @@
-use crate::k8_types::{Crd, GROUP, CrdNames, Spec, Status, DefaultHeader};
+use crate::k8_types::{Crd, GROUP, V1, CrdNames, Spec, Status, DefaultHeader};
 
 use super::TopicStatus;
 use super::TopicSpec;
+use super::{TopicLimits, TopicLimitsStatus};
 
 const TOPIC_V2_API: Crd = Crd {
     group: GROUP,
@@ -13,8 +14,29 @@ const TOPIC_V2_API: Crd = Crd {
     },
 };
 
+const TOPIC_LIMITS_API: Crd = Crd {
+    group: GROUP,
+    version: V1,
+    names: CrdNames {
+        kind: "TopicLimits",
+        plural: "topiclimits",
+        singular: "topiclimit",
+    },
+};
+
 impl Status for TopicStatus {}
 
+impl Status for TopicLimitsStatus {}
+
+impl Spec for TopicLimits {
+    type Status = TopicLimitsStatus;
+    type Header = DefaultHeader;
+
+    fn metadata() -> &'static Crd {
+        &TOPIC_LIMITS_API
+    }
+}
+
 impl Spec for TopicSpec {
     type Status = TopicStatus;
     type Header = DefaultHeader;
@@ -33,10 +55,10 @@ mod test_spec {
 
     use crate::{
         partition::HomePartitionConfig,
-        topic::{MirrorConfig, ReplicaSpec},
+        topic::{MirrorConfig, ReplicaSpec, TOPIC_LIMITS_NAME},
     };
 
-    use super::TopicSpec;
+    use super::{TopicSpec, TopicLimits};
 
     type K8TopicSpec = K8Obj<TopicSpec>;
 
@@ -72,4 +94,17 @@ mod test_spec {
             ))
         );
     }
+
+    #[test]
+    fn read_k8_topic_limits_json() {
+        let reader: BufReader<File> =
+            BufReader::new(File::open("tests/k8_topic_limits_v1.json").expect("spec"));
+        let limits: K8Obj<TopicLimits> =
+            serde_json::from_reader(reader).expect("failed to parse topic limits");
+        assert_eq!(limits.metadata.name, TOPIC_LIMITS_NAME);
+        assert_eq!(limits.spec.max_partitions_per_topic, Some(100));
+        assert_eq!(limits.spec.max_partitions_per_spu, Some(500));
+        assert_eq!(limits.spec.max_replication_factor, None);
+        assert_eq!(limits.spec.allowed_compression.len(), 2);
+    }
 }
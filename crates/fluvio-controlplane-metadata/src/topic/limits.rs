@@ -0,0 +1,295 @@
+//!
+//! # Topic Limits
+//!
+//! Cluster wide limits of topics. Limits are stored as a single metadata object and enforced by the SC
+//! when a topic is created or updated, topics which existed before limits were configured are not changed.
+//!
+use std::fmt;
+use std::time::Duration;
+
+use fluvio_protocol::{Encoder, Decoder};
+use fluvio_types::defaults::SPU_PARTITION_MAX_BYTES;
+use fluvio_types::{PartitionCount, ReplicationFactor};
+
+use super::{CompressionAlgorithm, ReplicaSpec, TopicSpec};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Encoder, Decoder)]
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct TopicLimits {
+    pub max_partitions_per_topic: Option<PartitionCount>,
+    /// replicas hosted by single SPU, leaders and followers
+    pub max_partitions_per_spu: Option<u32>,
+    pub max_replication_factor: Option<ReplicationFactor>,
+    /// compression types topics can use, any type is allowed if empty
+    pub allowed_compression: Vec<CompressionAlgorithm>,
+    /// topics must have retention time no longer than this
+    #[cfg_attr(feature = "use_serde", serde(with = "humantime_serde"))]
+    pub max_retention: Option<Duration>,
+    /// topics must have max partition size no larger than this
+    pub max_partition_size: Option<u64>,
+}
+
+/// name of the metadata object which holds limits of the cluster
+pub const TOPIC_LIMITS_NAME: &str = "topic-limits";
+
+/// limits have no status, they are applied as soon as they are stored
+#[derive(Debug, Default, Clone, PartialEq, Eq, Encoder, Decoder)]
+#[cfg_attr(feature = "use_serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TopicLimitsStatus {}
+
+impl fmt::Display for TopicLimitsStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "applied")
+    }
+}
+
+impl TopicLimits {
+    pub fn is_unlimited(&self) -> bool {
+        self == &Self::default()
+    }
+
+    pub fn allows_compression(&self, compression: &CompressionAlgorithm) -> bool {
+        self.allowed_compression.is_empty() || self.allowed_compression.contains(compression)
+    }
+
+    /// check limits which apply to topic spec alone, return description of violated limit
+    pub fn check_topic(&self, spec: &TopicSpec) -> Result<(), String> {
+        let replicas = spec.replicas();
+
+        if let Some(max) = self.max_partitions_per_topic {
+            let partitions = replicas.partitions();
+            if partitions > max {
+                return Err(format!(
+                    "topic has {partitions} partitions, at most {max} partitions per topic are allowed"
+                ));
+            }
+        }
+
+        if let (Some(max), Some(replication)) =
+            (self.max_replication_factor, replicas.replication_factor())
+        {
+            if replication > max {
+                return Err(format!(
+                    "replication factor {replication} exceeds max replication factor {max}"
+                ));
+            }
+        }
+
+        // mirror topics are written by remote clusters
+        if let ReplicaSpec::Mirror(_) = replicas {
+            return Ok(());
+        }
+
+        let compression = spec.get_compression_type();
+        if !self.allows_compression(compression) {
+            let allowed: Vec<String> = self
+                .allowed_compression
+                .iter()
+                .map(|compression| compression.to_string())
+                .collect();
+            return Err(format!(
+                "compression type '{compression}' is not allowed, use one of: {}",
+                allowed.join(", ")
+            ));
+        }
+
+        if let Some(max) = self.max_retention {
+            let retention = spec.retention_secs() as u64;
+            if retention > max.as_secs() {
+                return Err(format!(
+                    "retention time {} exceeds max retention time {}, set a shorter retention time",
+                    display_secs(retention),
+                    display_secs(max.as_secs())
+                ));
+            }
+        }
+
+        if let Some(max) = self.max_partition_size {
+            let size = partition_size(spec);
+            if size > max {
+                return Err(format!(
+                    "max partition size {size} bytes exceeds limit of {max} bytes, set a smaller max partition size"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// max size of partition of topic, SPU default is used if not set
+pub fn partition_size(spec: &TopicSpec) -> u64 {
+    spec.get_storage()
+        .and_then(|storage| storage.max_partition_size)
+        .unwrap_or(SPU_PARTITION_MAX_BYTES)
+}
+
+/// Topic limit which usage is reported
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Encoder, Decoder)]
+#[cfg_attr(feature = "use_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TopicLimit {
+    #[default]
+    #[fluvio(tag = 0)]
+    PartitionsPerTopic,
+    #[fluvio(tag = 1)]
+    PartitionsPerSpu,
+    #[fluvio(tag = 2)]
+    ReplicationFactor,
+    /// topics with compression type which is not allowed
+    #[fluvio(tag = 3)]
+    Compression,
+    #[fluvio(tag = 4)]
+    Retention,
+    #[fluvio(tag = 5)]
+    PartitionSize,
+}
+
+impl TopicLimit {
+    /// value of this limit as text
+    pub fn display_value(&self, value: u64) -> String {
+        match self {
+            Self::Retention => display_secs(value),
+            Self::PartitionSize => bytesize::ByteSize(value).to_string(),
+            _ => value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for TopicLimit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::PartitionsPerTopic => write!(f, "partitions per topic"),
+            Self::PartitionsPerSpu => write!(f, "partitions per spu"),
+            Self::ReplicationFactor => write!(f, "replication factor"),
+            Self::Compression => write!(f, "disallowed compression"),
+            Self::Retention => write!(f, "retention"),
+            Self::PartitionSize => write!(f, "partition size"),
+        }
+    }
+}
+
+/// Highest usage of limit in the cluster
+#[derive(Debug, Default, Clone, PartialEq, Eq, Encoder, Decoder)]
+#[cfg_attr(feature = "use_serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LimitUsage {
+    pub limit: TopicLimit,
+    /// topic or SPU with the highest usage
+    pub scope: String,
+    pub used: u64,
+    /// not set if limit is not configured
+    pub max: Option<u64>,
+}
+
+impl LimitUsage {
+    pub fn is_exceeded(&self) -> bool {
+        self.max.map(|max| self.used > max).unwrap_or(false)
+    }
+}
+
+fn display_secs(secs: u64) -> String {
+    const UNITS: [(u64, &str); 3] = [(24 * 3600, "d"), (3600, "h"), (60, "m")];
+    UNITS
+        .iter()
+        .find(|(unit, _)| secs > 0 && secs % unit == 0)
+        .map(|(unit, suffix)| format!("{}{suffix}", secs / unit))
+        .unwrap_or_else(|| format!("{secs}s"))
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::topic::{CleanupPolicy, SegmentBasedPolicy, TopicStorageConfig};
+
+    use super::*;
+
+    #[test]
+    fn test_check_topic() {
+        let limits = TopicLimits {
+            max_partitions_per_topic: Some(10),
+            max_replication_factor: Some(3),
+            allowed_compression: vec![CompressionAlgorithm::Lz4, CompressionAlgorithm::Zstd],
+            max_retention: Some(Duration::from_secs(24 * 3600)),
+            max_partition_size: Some(1024 * 1024 * 1024),
+            ..Default::default()
+        };
+
+        let mut spec = TopicSpec::new_computed(10, 3, None);
+        spec.set_compression_type(CompressionAlgorithm::Lz4);
+        spec.set_cleanup_policy(CleanupPolicy::Segment(SegmentBasedPolicy {
+            time_in_seconds: 3600,
+        }));
+        spec.set_storage(TopicStorageConfig {
+            max_partition_size: Some(1024 * 1024),
+            ..Default::default()
+        });
+        assert!(limits.check_topic(&spec).is_ok());
+
+        let too_many = TopicSpec::new_computed(11, 1, None);
+        assert!(limits
+            .check_topic(&too_many)
+            .unwrap_err()
+            .contains("11 partitions"));
+
+        let mut gzip = spec.clone();
+        gzip.set_compression_type(CompressionAlgorithm::Gzip);
+        assert_eq!(
+            limits.check_topic(&gzip).unwrap_err(),
+            "compression type 'gzip' is not allowed, use one of: lz4, zstd"
+        );
+
+        // default retention of SPU is used, if topic has no retention
+        let mut default_retention = TopicSpec::new_computed(1, 1, None);
+        default_retention.set_compression_type(CompressionAlgorithm::Zstd);
+        assert_eq!(
+            limits.check_topic(&default_retention).unwrap_err(),
+            "retention time 7d exceeds max retention time 1d, set a shorter retention time"
+        );
+
+        let mut unbounded = spec;
+        unbounded.set_storage(TopicStorageConfig::default());
+        assert!(limits
+            .check_topic(&unbounded)
+            .unwrap_err()
+            .contains("max partition size"));
+    }
+
+    #[test]
+    fn test_unlimited() {
+        let limits = TopicLimits::default();
+        assert!(limits.is_unlimited());
+        assert!(limits
+            .check_topic(&TopicSpec::new_computed(10000, 5, None))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_display_value() {
+        assert_eq!(TopicLimit::Retention.display_value(7 * 24 * 3600), "7d");
+        assert_eq!(TopicLimit::Retention.display_value(90), "90s");
+        assert_eq!(TopicLimit::PartitionsPerSpu.display_value(12), "12");
+    }
+
+    #[cfg(feature = "use_serde")]
+    #[test]
+    fn test_limits_deserialization() {
+        let limits: TopicLimits = serde_json::from_str(
+            r#"{
+                "max_partitions_per_topic": 100,
+                "allowed_compression": ["Lz4"],
+                "max_retention": "7days"
+            }"#,
+        )
+        .expect("deserialize");
+        assert_eq!(limits.max_partitions_per_topic, Some(100));
+        assert_eq!(limits.allowed_compression, vec![CompressionAlgorithm::Lz4]);
+        assert_eq!(
+            limits.max_retention,
+            Some(Duration::from_secs(7 * 24 * 3600))
+        );
+        assert_eq!(limits.max_partitions_per_spu, None);
+    }
+}
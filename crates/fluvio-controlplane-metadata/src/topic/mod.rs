@@ -2,9 +2,11 @@ mod spec;
 mod status;
 mod deduplication;
 mod update;
+mod limits;
 pub mod config;
 
 pub use self::update::*;
+pub use self::limits::*;
 pub use self::spec::*;
 pub use self::status::*;
 pub use self::deduplication::*;
@@ -40,6 +42,19 @@ mod metadata {
 
     impl Status for TopicStatus {}
 
+    impl Spec for TopicLimits {
+        const LABEL: &'static str = "TopicLimits";
+        type IndexKey = String;
+        type Status = TopicLimitsStatus;
+        type Owner = Self;
+    }
+
+    impl SpecExt for TopicLimits {
+        const OBJECT_TYPE: ObjectType = ObjectType::TopicLimits;
+    }
+
+    impl Status for TopicLimitsStatus {}
+
     #[cfg(feature = "k8")]
     mod extended {
 
@@ -50,7 +65,7 @@ mod metadata {
         use crate::k8_types::K8Obj;
         use crate::store::k8::default_convert_from_k8;
 
-        use super::TopicSpec;
+        use super::{TopicSpec, TopicLimits};
 
         impl K8ExtendedSpec for TopicSpec {
             type K8Spec = Self;
@@ -73,5 +88,25 @@ mod metadata {
                 self
             }
         }
+
+        impl K8ExtendedSpec for TopicLimits {
+            type K8Spec = Self;
+
+            fn convert_from_k8(
+                k8_obj: K8Obj<Self::K8Spec>,
+                multi_namespace_context: bool,
+            ) -> Result<MetadataStoreObject<Self, K8MetaItem>, K8ConvertError<Self::K8Spec>>
+            {
+                default_convert_from_k8(k8_obj, multi_namespace_context)
+            }
+
+            fn convert_status_from_k8(status: Self::Status) -> Self::Status {
+                status
+            }
+
+            fn into_k8(self) -> Self::K8Spec {
+                self
+            }
+        }
     }
 }
@@ -39,6 +39,19 @@ pub struct TopicSpec {
     #[cfg_attr(feature = "use_serde", serde(default))]
     #[fluvio(min_version = 13)]
     system: bool,
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    #[fluvio(min_version = 20)]
+    aliases: Vec<String>,
+    /// replicas, including leader, which must be in sync to accept read committed writes
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    #[fluvio(min_version = 21)]
+    min_in_sync_replicas: Option<u16>,
 }
 
 impl From<ReplicaSpec> for TopicSpec {
@@ -147,6 +160,27 @@ impl TopicSpec {
         self.system = system;
     }
 
+    /// other names which resolve to this topic
+    pub fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    pub fn set_aliases(&mut self, aliases: Vec<String>) {
+        self.aliases = aliases;
+    }
+
+    pub fn has_alias(&self, name: &str) -> bool {
+        self.aliases.iter().any(|alias| alias == name)
+    }
+
+    pub fn get_min_in_sync_replicas(&self) -> Option<u16> {
+        self.min_in_sync_replicas
+    }
+
+    pub fn set_min_in_sync_replicas(&mut self, min_in_sync_replicas: Option<u16>) {
+        self.min_in_sync_replicas = min_in_sync_replicas;
+    }
+
     /// get retention secs that can be displayed
     pub fn retention_secs(&self) -> u32 {
         self.get_clean_policy()
@@ -156,6 +190,19 @@ impl TopicSpec {
 
     /// validate configuration, return string with errors
     pub fn validate_config(&self) -> Option<String> {
+        if let Some(min_in_sync) = self.min_in_sync_replicas {
+            if min_in_sync == 0 {
+                return Some("min_in_sync_replicas must be at least 1".to_owned());
+            }
+            if let Some(replication) = self.replicas.replication_factor() {
+                if min_in_sync as ReplicationFactor > replication {
+                    return Some(format!(
+                        "min_in_sync_replicas {min_in_sync} is greater than replication factor {replication}"
+                    ));
+                }
+            }
+        }
+
         if let Some(policy) = self.get_clean_policy() {
             if policy.retention_secs() < STORAGE_RETENTION_SECONDS_MIN {
                 return Some(format!(
@@ -834,6 +881,75 @@ impl SegmentBasedPolicy {
 pub struct TopicStorageConfig {
     pub segment_size: Option<u32>,       // segment size
     pub max_partition_size: Option<u64>, // max partition size
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    #[fluvio(min_version = 19)]
+    pub flush_policy: Option<FlushPolicy>, // when written records are synced to disk
+}
+
+/// Controls when the SPU syncs written records of a partition to disk
+#[derive(Decoder, Default, Encoder, Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum FlushPolicy {
+    /// leave flushing to the operating system
+    #[default]
+    #[fluvio(tag = 0)]
+    Os,
+    /// sync after every written batch
+    #[fluvio(tag = 1)]
+    EveryWrite,
+    /// sync after given number of written batches
+    #[fluvio(tag = 2)]
+    WriteCount(u32),
+    /// sync on write if given number of milliseconds passed since last sync
+    #[fluvio(tag = 3)]
+    Interval(u32),
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid flush policy, expected: os, every-write, write-count:<n> or interval:<ms>")]
+pub struct InvalidFlushPolicy;
+
+impl std::str::FromStr for FlushPolicy {
+    type Err = InvalidFlushPolicy;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.to_lowercase();
+        let (name, value) = match s.split_once(':') {
+            Some((name, value)) => (name, Some(value)),
+            None => (s.as_str(), None),
+        };
+        let parse_value = || {
+            value
+                .and_then(|value| value.trim().parse::<u32>().ok())
+                .filter(|value| *value > 0)
+                .ok_or(InvalidFlushPolicy)
+        };
+        match name {
+            "os" if value.is_none() => Ok(FlushPolicy::Os),
+            "every-write" if value.is_none() => Ok(FlushPolicy::EveryWrite),
+            "write-count" => Ok(FlushPolicy::WriteCount(parse_value()?)),
+            "interval" => Ok(FlushPolicy::Interval(parse_value()?)),
+            _ => Err(InvalidFlushPolicy),
+        }
+    }
+}
+
+impl std::fmt::Display for FlushPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Self::Os => write!(f, "os"),
+            Self::EveryWrite => write!(f, "every-write"),
+            Self::WriteCount(count) => write!(f, "write-count:{count}"),
+            Self::Interval(msec) => write!(f, "interval:{msec}"),
+        }
+    }
 }
 
 #[derive(Decoder, Default, Encoder, Debug, Clone, Eq, PartialEq)]
@@ -1106,6 +1222,75 @@ mod test {
         assert!(topic_spec_decoded.deduplication.is_none());
     }
 
+    #[test]
+    fn test_flush_policy_from_str() {
+        use std::str::FromStr;
+
+        for policy in [
+            FlushPolicy::Os,
+            FlushPolicy::EveryWrite,
+            FlushPolicy::WriteCount(100),
+            FlushPolicy::Interval(500),
+        ] {
+            assert_eq!(FlushPolicy::from_str(&policy.to_string()).unwrap(), policy);
+        }
+        assert!(FlushPolicy::from_str("os:1").is_err());
+        assert!(FlushPolicy::from_str("write-count").is_err());
+        assert!(FlushPolicy::from_str("interval:0").is_err());
+        assert!(FlushPolicy::from_str("always").is_err());
+    }
+
+    #[test]
+    fn test_topic_with_flush_policy_prev_version_compatibility() {
+        //given
+        let prev_version = 18;
+        let mut topic_spec: TopicSpec = ReplicaSpec::Computed((2, 3, true).into()).into();
+        topic_spec.set_storage(TopicStorageConfig {
+            segment_size: Some(1000),
+            flush_policy: Some(FlushPolicy::EveryWrite),
+            ..Default::default()
+        });
+
+        //when
+        let mut dest = vec![];
+        topic_spec.encode(&mut dest, prev_version).expect("encoded");
+        let mut topic_spec_decoded = TopicSpec::default();
+        topic_spec_decoded
+            .decode(&mut Cursor::new(&dest), prev_version)
+            .expect("decoded");
+
+        //then
+        let storage = topic_spec_decoded.get_storage().expect("storage");
+        assert_eq!(storage.segment_size, Some(1000));
+        assert!(storage.flush_policy.is_none());
+    }
+
+    #[test]
+    fn test_min_in_sync_replicas() {
+        let mut topic_spec = TopicSpec::new_computed(2, 3, None);
+        topic_spec.set_min_in_sync_replicas(Some(2));
+        assert!(topic_spec.validate_config().is_none());
+
+        topic_spec.set_min_in_sync_replicas(Some(0));
+        assert!(topic_spec.validate_config().is_some());
+
+        topic_spec.set_min_in_sync_replicas(Some(4));
+        assert_eq!(
+            topic_spec.validate_config().expect("error"),
+            "min_in_sync_replicas 4 is greater than replication factor 3"
+        );
+
+        // previous version doesn't have min in-sync replicas
+        topic_spec.set_min_in_sync_replicas(Some(2));
+        let mut dest = vec![];
+        topic_spec.encode(&mut dest, 20).expect("encoded");
+        let mut topic_spec_decoded = TopicSpec::default();
+        topic_spec_decoded
+            .decode(&mut Cursor::new(&dest), 20)
+            .expect("decoded");
+        assert_eq!(topic_spec_decoded.get_min_in_sync_replicas(), None);
+    }
+
     #[test]
     fn test_partition_map_str() {
         // Test multiple
@@ -32,6 +32,32 @@ pub struct TopicStatus {
     #[fluvio(min_version = 14)]
     pub mirror_map: MirrorMap,
     pub reason: String,
+    /// previous name of the topic, until partitions of renamed topic are moved
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    #[fluvio(min_version = 20)]
+    pub renamed_from: Option<String>,
+    /// requested rename of the topic, until topic with the new name is created
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    #[fluvio(min_version = 20)]
+    pub renaming_to: Option<TopicRenameTarget>,
+}
+
+/// New name and aliases of topic being renamed
+#[derive(Decoder, Default, Encoder, Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct TopicRenameTarget {
+    pub name: String,
+    pub aliases: Vec<String>,
 }
 
 impl fmt::Display for TopicStatus {
@@ -122,6 +148,8 @@ impl ::std::default::Default for TopicStatus {
             replica_map: BTreeMap::new(),
             reason: "".to_owned(),
             mirror_map: BTreeMap::new(),
+            renamed_from: None,
+            renaming_to: None,
         }
     }
 }
@@ -149,6 +177,8 @@ impl TopicStatus {
             replica_map: create_replica_map(replica_map),
             reason: reason.into(),
             mirror_map: BTreeMap::new(),
+            renamed_from: None,
+            renaming_to: None,
         }
     }
 
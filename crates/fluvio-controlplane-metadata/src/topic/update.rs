@@ -1,5 +1,10 @@
 use fluvio_protocol::{Decoder, Encoder};
 
+use super::{
+    CleanupPolicy, CompressionAlgorithm, Deduplication, SegmentBasedPolicy, TopicSpec,
+    TopicStorageConfig,
+};
+
 #[derive(Debug, Default, Encoder, Decoder, Clone)]
 pub struct AddPartition {
     pub count: u32,
@@ -10,12 +15,92 @@ pub struct AddMirror {
     pub remote_cluster: String,
 }
 
+/// Changes to the configuration of an existing topic.
+/// Fields which are not set are left unchanged.
+#[derive(Debug, Default, Encoder, Decoder, Clone, Eq, PartialEq)]
+pub struct UpdateTopicConfig {
+    pub cleanup_policy: Option<CleanupPolicy>,
+    /// set fields replace the ones of the current storage config
+    pub storage: Option<TopicStorageConfig>,
+    pub compression_type: Option<CompressionAlgorithm>,
+    pub deduplication: Option<Deduplication>,
+    /// remove deduplication of the topic, takes precedence over `deduplication`
+    pub remove_deduplication: bool,
+}
+
+impl UpdateTopicConfig {
+    pub fn with_retention_secs(mut self, time_in_seconds: u32) -> Self {
+        self.cleanup_policy = Some(CleanupPolicy::Segment(SegmentBasedPolicy {
+            time_in_seconds,
+        }));
+        self
+    }
+
+    /// true if no change is requested
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// apply changes to topic spec
+    pub fn apply_to(&self, spec: &mut TopicSpec) {
+        if let Some(policy) = &self.cleanup_policy {
+            spec.set_cleanup_policy(policy.clone());
+        }
+
+        if let Some(update) = &self.storage {
+            let mut storage = spec.get_storage().cloned().unwrap_or_default();
+            if update.segment_size.is_some() {
+                storage.segment_size = update.segment_size;
+            }
+            if update.max_partition_size.is_some() {
+                storage.max_partition_size = update.max_partition_size;
+            }
+            if update.flush_policy.is_some() {
+                storage.flush_policy = update.flush_policy;
+            }
+            spec.set_storage(storage);
+        }
+
+        if let Some(compression_type) = &self.compression_type {
+            spec.set_compression_type(compression_type.clone());
+        }
+
+        if self.remove_deduplication {
+            spec.set_deduplication(None);
+        } else if let Some(deduplication) = &self.deduplication {
+            spec.set_deduplication(Some(deduplication.clone()));
+        }
+    }
+}
+
+/// Alias of a topic, which can be used instead of its name to produce and consume
+#[derive(Debug, Default, Encoder, Decoder, Clone)]
+pub struct TopicAlias {
+    pub name: String,
+}
+
+/// Rename topic, its partitions and consumer offsets are moved to the new name
+#[derive(Debug, Default, Encoder, Decoder, Clone)]
+pub struct RenameTopic {
+    pub new_name: String,
+    /// keep current name as alias of the renamed topic
+    pub keep_alias: bool,
+}
+
 #[derive(Debug, Encoder, Decoder, Clone)]
 pub enum UpdateTopicAction {
     #[fluvio(tag = 0)]
     AddPartition(AddPartition),
     #[fluvio(tag = 1)]
     AddMirror(AddMirror),
+    #[fluvio(tag = 2)]
+    UpdateConfig(UpdateTopicConfig),
+    #[fluvio(tag = 3)]
+    AddAlias(TopicAlias),
+    #[fluvio(tag = 4)]
+    RemoveAlias(TopicAlias),
+    #[fluvio(tag = 5)]
+    Rename(RenameTopic),
 }
 
 impl Default for UpdateTopicAction {
@@ -23,3 +108,69 @@ impl Default for UpdateTopicAction {
         Self::AddPartition(AddPartition::default())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::topic::{FlushPolicy, ReplicaSpec, TopicReplicaParam};
+
+    use super::*;
+
+    fn computed_topic() -> TopicSpec {
+        ReplicaSpec::Computed(TopicReplicaParam::new(1, 1, false)).into()
+    }
+
+    #[test]
+    fn test_update_config_merges_storage() {
+        let mut spec = computed_topic();
+        spec.set_storage(TopicStorageConfig {
+            segment_size: Some(2000),
+            max_partition_size: Some(10000),
+            flush_policy: Some(FlushPolicy::EveryWrite),
+        });
+
+        let update = UpdateTopicConfig {
+            storage: Some(TopicStorageConfig {
+                max_partition_size: Some(20000),
+                ..Default::default()
+            }),
+            compression_type: Some(CompressionAlgorithm::Gzip),
+            ..Default::default()
+        }
+        .with_retention_secs(600);
+        update.apply_to(&mut spec);
+
+        let storage = spec.get_storage().expect("storage");
+        assert_eq!(storage.segment_size, Some(2000));
+        assert_eq!(storage.max_partition_size, Some(20000));
+        assert_eq!(storage.flush_policy, Some(FlushPolicy::EveryWrite));
+        assert_eq!(spec.get_compression_type(), &CompressionAlgorithm::Gzip);
+        assert_eq!(
+            spec.get_clean_policy(),
+            Some(&CleanupPolicy::Segment(SegmentBasedPolicy {
+                time_in_seconds: 600
+            }))
+        );
+    }
+
+    #[test]
+    fn test_update_config_deduplication() {
+        let mut spec = computed_topic();
+        let update = UpdateTopicConfig {
+            deduplication: Some(Deduplication::default()),
+            ..Default::default()
+        };
+        update.apply_to(&mut spec);
+        assert!(spec.get_deduplication().is_some());
+
+        UpdateTopicConfig::default().apply_to(&mut spec);
+        assert!(spec.get_deduplication().is_some());
+
+        let update = UpdateTopicConfig {
+            remove_deduplication: true,
+            ..Default::default()
+        };
+        update.apply_to(&mut spec);
+        assert!(spec.get_deduplication().is_none());
+        assert!(UpdateTopicConfig::default().is_empty());
+    }
+}
@@ -25,6 +25,11 @@ pub struct Replica {
     pub storage: Option<TopicStorageConfig>,
     pub compression_type: CompressionAlgorithm,
     pub deduplication: Option<Deduplication>,
+    pub aliases: Vec<String>,
+    /// topic of the replica before it was renamed, its storage must be moved
+    pub renamed_from: Option<String>,
+    /// replicas which must be in sync to accept read committed writes, SPU default if not set
+    pub min_in_sync_replicas: Option<u16>,
 }
 
 impl Replica {
@@ -46,6 +51,20 @@ impl Replica {
             ..Default::default()
         }
     }
+
+    /// true if name is an alias of the topic of this replica
+    pub fn has_alias(&self, name: &str) -> bool {
+        self.aliases.iter().any(|alias| alias == name)
+    }
+
+    /// true if topic config of replicas differs
+    pub fn is_config_changed(&self, other: &Self) -> bool {
+        self.cleanup_policy != other.cleanup_policy
+            || self.storage != other.storage
+            || self.compression_type != other.compression_type
+            || self.deduplication != other.deduplication
+            || self.min_in_sync_replicas != other.min_in_sync_replicas
+    }
 }
 
 impl<C> From<PartitionMetadata<C>> for Replica
@@ -69,6 +88,9 @@ where
             storage: spec.storage,
             compression_type: spec.compression_type,
             deduplication: spec.deduplication,
+            aliases: spec.aliases,
+            renamed_from: spec.renamed_from,
+            min_in_sync_replicas: spec.min_in_sync_replicas,
         }
     }
 }
@@ -14,6 +14,7 @@ use crate::sc_api::update_mirror::UpdateMirrorStatRequest;
 use super::register_spu::RegisterSpuRequest;
 use super::update_lrs::UpdateLrsRequest;
 use super::remove::ReplicaRemovedRequest;
+use super::shutdown_spu::ShutdownSpuRequest;
 
 /// API call from Spu to SC
 
@@ -27,6 +28,7 @@ pub enum InternalScKey {
     UpdateLrs = 2001,
     ReplicaRemoved = 2002,
     UpdateMirror = 2003,
+    ShutdownSpu = 2004,
 }
 
 /// Request made to Spu from Sc
@@ -40,6 +42,8 @@ pub enum InternalScRequest {
     ReplicaRemovedRequest(RequestMessage<ReplicaRemovedRequest>),
     #[fluvio(tag = 3)]
     UpdateMirrorStatRequest(RequestMessage<UpdateMirrorStatRequest>),
+    #[fluvio(tag = 4)]
+    ShutdownSpuRequest(RequestMessage<ShutdownSpuRequest>),
 }
 
 impl Default for InternalScRequest {
@@ -70,6 +74,9 @@ impl ApiMessage for InternalScRequest {
             InternalScKey::UpdateMirror => {
                 api_decode!(InternalScRequest, UpdateMirrorStatRequest, src, header)
             }
+            InternalScKey::ShutdownSpu => {
+                api_decode!(InternalScRequest, ShutdownSpuRequest, src, header)
+            }
         }
     }
 }
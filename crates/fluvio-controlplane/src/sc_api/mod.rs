@@ -1,5 +1,6 @@
 pub mod api;
 pub mod register_spu;
 pub mod remove;
+pub mod shutdown_spu;
 pub mod update_lrs;
 pub mod update_mirror;
@@ -0,0 +1,44 @@
+//!
+//! # Shutdown SPU
+//!
+//! SPU sends Shutdown message to the SC before it stops. SC moves leadership of partitions
+//! led by this SPU to in-sync followers. SC doesn't respond, SPU learns about new leaders
+//! from replica updates.
+//!
+use std::fmt;
+
+use fluvio_protocol::api::Request;
+use fluvio_protocol::Decoder;
+use fluvio_protocol::Encoder;
+use fluvio_types::SpuId;
+
+use super::api::InternalScKey;
+
+#[derive(Decoder, Encoder, Debug, Default, Clone)]
+pub struct ShutdownSpuRequest {
+    spu: SpuId,
+}
+
+impl ShutdownSpuRequest {
+    pub fn new(spu: SpuId) -> Self {
+        Self { spu }
+    }
+
+    pub fn spu(&self) -> SpuId {
+        self.spu
+    }
+}
+
+impl fmt::Display for ShutdownSpuRequest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "shutdown spu {}", self.spu)
+    }
+}
+
+impl Request for ShutdownSpuRequest {
+    const API_KEY: u16 = InternalScKey::ShutdownSpu as u16;
+    type Response = ShutdownSpuResponse;
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct ShutdownSpuResponse {}
@@ -44,7 +44,7 @@ impl fmt::Display for UpdateLrsRequest {
 impl Request for UpdateLrsRequest {
     const API_KEY: u16 = InternalScKey::UpdateLrs as u16;
     type Response = UpdateLrsResponse;
-    const DEFAULT_API_VERSION: i16 = 1;
+    const DEFAULT_API_VERSION: i16 = 3;
 }
 
 #[derive(Decoder, Encoder, Debug, Default, Clone)]
@@ -65,6 +65,12 @@ pub struct LrsRequest {
     pub size: i64,
     #[fluvio(min_version = 1)]
     pub base_offset: i64,
+    /// offset of corrupted batch found by leader, replica is fenced from it
+    #[fluvio(min_version = 2)]
+    pub corrupted_offset: Option<i64>,
+    /// replicas in sync with leader, including leader
+    #[fluvio(min_version = 3)]
+    pub in_sync_replicas: u32,
 }
 
 impl PartialEq for LrsRequest {
@@ -102,6 +108,8 @@ impl LrsRequest {
             replicas,
             size,
             base_offset,
+            corrupted_offset: None,
+            in_sync_replicas: 0,
         }
     }
 }
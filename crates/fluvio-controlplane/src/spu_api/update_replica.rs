@@ -1,6 +1,7 @@
 use fluvio_protocol::api::Request;
 use fluvio_protocol::Decoder;
 use fluvio_protocol::Encoder;
+use fluvio_controlplane_metadata::METADATA_VERSION;
 
 use crate::replica::Replica;
 use crate::requests::ControlPlaneRequest;
@@ -12,6 +13,7 @@ pub type UpdateReplicaRequest = ControlPlaneRequest<Replica>;
 impl Request for UpdateReplicaRequest {
     const API_KEY: u16 = InternalSpuApi::UpdateReplica as u16;
     type Response = UpdateReplicaResponse;
+    const DEFAULT_API_VERSION: i16 = METADATA_VERSION; // align with public api to encode topic config
 }
 
 #[derive(Decoder, Encoder, Default, Debug)]
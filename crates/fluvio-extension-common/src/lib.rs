@@ -125,6 +125,11 @@ pub mod target {
 
         #[arg(short = 'P', long, value_name = "profile")]
         pub profile: Option<String>,
+
+        /// Tenant which plain names of topics, SmartModules and table formats belong to,
+        /// overrides tenant of profile
+        #[arg(long, value_name = "tenant")]
+        pub tenant: Option<String>,
     }
 
     impl ClusterTarget {
@@ -135,7 +140,16 @@ pub mod target {
         }
 
         /// try to create sc config
-        pub fn load(self) -> Result<FluvioConfig> {
+        pub fn load(mut self) -> Result<FluvioConfig> {
+            let tenant = self.tenant.take();
+            let config = self.load_cluster()?;
+            Ok(match tenant {
+                Some(tenant) => config.with_tenant(tenant),
+                None => config,
+            })
+        }
+
+        fn load_cluster(self) -> Result<FluvioConfig> {
             let tls = self.tls.try_into()?;
 
             use fluvio::config::TlsPolicy::*;
@@ -0,0 +1,10 @@
+use clap::Parser;
+use fluvio_http_gateway::GatewayOpt;
+
+fn main() -> anyhow::Result<()> {
+    let opt = GatewayOpt::parse();
+
+    fluvio_future::subscriber::init_tracer(None);
+
+    opt.process()
+}
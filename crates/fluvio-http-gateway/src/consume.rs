@@ -0,0 +1,344 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use base64::Engine;
+use futures_util::io::{AsyncWrite, AsyncWriteExt};
+use futures_util::StreamExt;
+use serde::Serialize;
+use tokio::select;
+use tracing::{debug, instrument};
+
+use fluvio::consumer::{
+    ConsumerConfigExt, ConsumerConfigExtBuilder, ConsumerStream, OffsetManagementStrategy, Record,
+};
+use fluvio::{Offset, SmartModuleInvocation, SmartModuleInvocationWasm, SmartModuleKind};
+use fluvio_future::timer::sleep;
+use fluvio_service::http::{write_stream_head, HttpRequest, HttpResponse, CONTENT_TYPE_EVENT_STREAM};
+
+use crate::error::{from_fluvio, GatewayError};
+use crate::server::SharedGatewayContext;
+
+const DEFAULT_COUNT: usize = 100;
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+/// once records are found, wait this long for more records before responding
+const LINGER: Duration = Duration::from_millis(100);
+const SSE_KEEP_ALIVE: Duration = Duration::from_secs(15);
+const SMARTMODULE_PARAM_PREFIX: &str = "param.";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    #[default]
+    Utf8,
+    Base64,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonRecord {
+    partition: u32,
+    offset: i64,
+    timestamp: i64,
+    key: Option<String>,
+    value: String,
+}
+
+impl JsonRecord {
+    fn new(record: &Record, encoding: Encoding) -> Self {
+        let encode = |bytes: &[u8]| match encoding {
+            Encoding::Utf8 => String::from_utf8_lossy(bytes).into_owned(),
+            Encoding::Base64 => base64::engine::general_purpose::STANDARD.encode(bytes),
+        };
+        Self {
+            partition: record.partition(),
+            offset: record.offset(),
+            timestamp: record.timestamp(),
+            key: record.key().map(encode),
+            value: encode(record.value()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ConsumeResponse {
+    records: Vec<JsonRecord>,
+}
+
+/// Consumer options from query parameters:
+///
+/// * `offset`: `beginning`, `end` (default) or absolute offset, ignored if consumer has a stored offset
+/// * `partition`: consume a single partition, all partitions by default
+/// * `consumer`: consumer id, offsets of returned records are committed
+/// * `smartmodule`: name of SmartModule applied to records, `param.<name>` are passed to it
+/// * `encoding`: `utf8` (default) or `base64` for keys and values
+#[derive(Debug)]
+struct ConsumeOptions {
+    config: ConsumerConfigExt,
+    encoding: Encoding,
+    committed: bool,
+}
+
+impl ConsumeOptions {
+    fn from_request(
+        topic: &str,
+        request: &HttpRequest,
+        strategy: OffsetManagementStrategy,
+    ) -> Result<Self, GatewayError> {
+        let mut builder = ConsumerConfigExtBuilder::default();
+        builder
+            .topic(topic)
+            .offset_start(parse_offset(request.query("offset"))?);
+
+        if let Some(partition) = request.query_parse("partition")? {
+            builder.partition(partition);
+        }
+
+        let committed = match request.query("consumer") {
+            Some(consumer) => {
+                builder.offset_consumer(consumer).offset_strategy(strategy);
+                true
+            }
+            None => false,
+        };
+
+        if let Some(smartmodule) = request.query("smartmodule") {
+            let params: BTreeMap<String, String> = request
+                .query_with_prefix(SMARTMODULE_PARAM_PREFIX)
+                .map(|(name, value)| (name.to_owned(), value.to_owned()))
+                .collect();
+            builder.smartmodule(vec![SmartModuleInvocation {
+                wasm: SmartModuleInvocationWasm::Predefined(smartmodule.to_owned()),
+                kind: SmartModuleKind::Generic(Default::default()),
+                params: params.into(),
+                ..Default::default()
+            }]);
+        }
+
+        let encoding = match request.query("encoding") {
+            None | Some("utf8") => Encoding::Utf8,
+            Some("base64") => Encoding::Base64,
+            Some(other) => {
+                return Err(GatewayError::BadRequest(format!(
+                    "unsupported encoding: {other}"
+                )))
+            }
+        };
+
+        Ok(Self {
+            config: builder
+                .build()
+                .map_err(|err| GatewayError::BadRequest(err.to_string()))?,
+            encoding,
+            committed,
+        })
+    }
+}
+
+fn parse_offset(offset: Option<&str>) -> Result<Offset, GatewayError> {
+    match offset {
+        None | Some("end") => Ok(Offset::end()),
+        Some("beginning") => Ok(Offset::beginning()),
+        Some(value) => value
+            .parse::<i64>()
+            .ok()
+            .and_then(|index| Offset::absolute(index).ok())
+            .ok_or_else(|| GatewayError::BadRequest(format!("invalid offset: {value}"))),
+    }
+}
+
+/// `GET /topics/{topic}/records`
+///
+/// Long poll: waits up to `timeout_ms` for records and returns at most `count` of them.
+/// With a consumer id, offsets of returned records are committed before responding.
+#[instrument(skip(ctx, request))]
+pub async fn handle_consume(
+    ctx: &SharedGatewayContext,
+    topic: &str,
+    request: &HttpRequest,
+) -> Result<HttpResponse, GatewayError> {
+    let options = ConsumeOptions::from_request(topic, request, OffsetManagementStrategy::Manual)?;
+    let count = request
+        .query_parse::<usize>("count")?
+        .unwrap_or(DEFAULT_COUNT);
+    let timeout = request
+        .query_parse::<u64>("timeout_ms")?
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_TIMEOUT);
+
+    let mut stream = ctx
+        .fluvio()
+        .consumer_with_config(options.config)
+        .await
+        .map_err(from_fluvio)?;
+
+    let deadline = Instant::now() + timeout;
+    let mut records = vec![];
+    while records.len() < count {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let wait = if records.is_empty() {
+            remaining
+        } else {
+            remaining.min(LINGER)
+        };
+        select! {
+            next = stream.next() => match next {
+                Some(Ok(record)) => records.push(JsonRecord::new(&record, options.encoding)),
+                Some(Err(err)) => return Err(GatewayError::Fluvio(err.into())),
+                None => break,
+            },
+            _ = sleep(wait) => break,
+        }
+    }
+    debug!(records = records.len(), "consumed");
+
+    if options.committed && !records.is_empty() {
+        stream
+            .offset_commit()
+            .map_err(|err| GatewayError::Fluvio(err.into()))?;
+        stream
+            .offset_flush()
+            .await
+            .map_err(|err| GatewayError::Fluvio(err.into()))?;
+    }
+
+    Ok(HttpResponse::json(200, &ConsumeResponse { records }))
+}
+
+/// `GET /topics/{topic}/stream` or `GET /topics/{topic}/records` accepting `text/event-stream`
+///
+/// Streams records as Server-Sent Events until the client disconnects.
+/// With a consumer id, offsets are committed periodically and when the client disconnects.
+#[instrument(skip(ctx, request, writer))]
+pub async fn handle_stream<W: AsyncWrite + Unpin>(
+    ctx: &SharedGatewayContext,
+    topic: &str,
+    request: &HttpRequest,
+    writer: &mut W,
+) -> Result<(), GatewayError> {
+    let options = ConsumeOptions::from_request(topic, request, OffsetManagementStrategy::Auto)?;
+    let mut stream = ctx
+        .fluvio()
+        .consumer_with_config(options.config)
+        .await
+        .map_err(from_fluvio)?;
+
+    write_stream_head(writer, CONTENT_TYPE_EVENT_STREAM).await?;
+    loop {
+        let event = select! {
+            next = stream.next() => match next {
+                Some(Ok(record)) => {
+                    let data = serde_json::to_string(&JsonRecord::new(&record, options.encoding))
+                        .map_err(|err| GatewayError::Fluvio(err.into()))?;
+                    format!(
+                        "id: {}-{}\nevent: record\ndata: {data}\n\n",
+                        record.partition(),
+                        record.offset()
+                    )
+                }
+                Some(Err(err)) => format!("event: error\ndata: {err}\n\n"),
+                None => break,
+            },
+            // comment line, detects closed connections when there are no records
+            _ = sleep(SSE_KEEP_ALIVE) => ":\n\n".to_owned(),
+        };
+        let written = async {
+            writer.write_all(event.as_bytes()).await?;
+            writer.flush().await
+        };
+        if let Err(err) = written.await {
+            debug!(%err, "client disconnected");
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// `GET /consumers`
+pub async fn handle_list_consumers(
+    ctx: &SharedGatewayContext,
+) -> Result<HttpResponse, GatewayError> {
+    let consumers = ctx.fluvio().consumer_offsets().await.map_err(from_fluvio)?;
+    Ok(HttpResponse::json(200, &consumers))
+}
+
+/// `DELETE /consumers/{consumer}/topics/{topic}/partitions/{partition}`
+pub async fn handle_delete_consumer(
+    ctx: &SharedGatewayContext,
+    consumer: &str,
+    topic: &str,
+    partition: &str,
+) -> Result<HttpResponse, GatewayError> {
+    let partition: u32 = partition
+        .parse()
+        .map_err(|_| GatewayError::BadRequest(format!("invalid partition: {partition}")))?;
+    ctx.fluvio()
+        .delete_consumer_offset(consumer, (topic.to_owned(), partition))
+        .await
+        .map_err(from_fluvio)?;
+    Ok(HttpResponse::json(200, &serde_json::json!({})))
+}
+
+#[cfg(test)]
+mod test {
+
+    use fluvio_future::task::run_block_on;
+
+    use super::*;
+
+    fn get(target: &str) -> HttpRequest {
+        let head = format!("GET {target} HTTP/1.1\r\n\r\n");
+        run_block_on(HttpRequest::read(&mut head.as_bytes(), 0))
+            .expect("valid request")
+            .expect("complete request")
+    }
+
+    #[test]
+    fn test_parse_offset() {
+        assert_eq!(parse_offset(None).expect("end"), Offset::end());
+        assert_eq!(
+            parse_offset(Some("beginning")).expect("beginning"),
+            Offset::beginning()
+        );
+        assert_eq!(
+            parse_offset(Some("10")).expect("absolute"),
+            Offset::absolute(10).expect("offset")
+        );
+        assert!(parse_offset(Some("-1")).is_err());
+        assert!(parse_offset(Some("latest")).is_err());
+    }
+
+    #[test]
+    fn test_consume_options() {
+        let request = get("/topics/hello/records");
+        let options =
+            ConsumeOptions::from_request("hello", &request, OffsetManagementStrategy::Manual)
+                .expect("options");
+        assert!(!options.committed);
+        assert!(options.config.offset_consumer.is_none());
+        assert!(options.config.smartmodule.is_empty());
+        assert_eq!(options.encoding, Encoding::Utf8);
+
+        let request = get(
+            "/topics/hello/records?consumer=c1&smartmodule=infinyon/regex-filter@0.1.0&param.regex=%5Ea&encoding=base64",
+        );
+        let options =
+            ConsumeOptions::from_request("hello", &request, OffsetManagementStrategy::Manual)
+                .expect("options");
+        assert!(options.committed);
+        assert_eq!(options.config.offset_consumer.as_deref(), Some("c1"));
+        assert_eq!(options.encoding, Encoding::Base64);
+        let smartmodule = &options.config.smartmodule[0];
+        assert!(matches!(
+            &smartmodule.wasm,
+            SmartModuleInvocationWasm::Predefined(name) if name == "infinyon/regex-filter@0.1.0"
+        ));
+        assert_eq!(
+            smartmodule.params.get("regex").map(String::as_str),
+            Some("^a")
+        );
+
+        let request = get("/topics/hello/records?encoding=hex");
+        assert!(
+            ConsumeOptions::from_request("hello", &request, OffsetManagementStrategy::Manual)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use std::io::Error as IoError;
+
+use serde::Serialize;
+
+use fluvio_service::http::{HttpError, HttpResponse};
+
+#[derive(thiserror::Error, Debug)]
+pub enum GatewayError {
+    #[error(transparent)]
+    Io(#[from] IoError),
+    #[error("malformed request: {0}")]
+    BadRequest(String),
+    #[error("not found: {0}")]
+    NotFound(String),
+    #[error("method not allowed")]
+    MethodNotAllowed,
+    #[error("request body exceeds {0} bytes")]
+    PayloadTooLarge(usize),
+    #[error("request body must have content length")]
+    LengthRequired,
+    #[error("topic not found: {0}")]
+    TopicNotFound(String),
+    #[error(transparent)]
+    Fluvio(#[from] anyhow::Error),
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+impl GatewayError {
+    pub fn status(&self) -> u16 {
+        match self {
+            Self::BadRequest(_) => 400,
+            Self::NotFound(_) | Self::TopicNotFound(_) => 404,
+            Self::MethodNotAllowed => 405,
+            Self::LengthRequired => 411,
+            Self::PayloadTooLarge(_) => 413,
+            Self::Io(_) | Self::Fluvio(_) => 500,
+        }
+    }
+
+    pub fn into_response(self) -> HttpResponse {
+        HttpResponse::json(
+            self.status(),
+            &ErrorBody {
+                error: self.to_string(),
+            },
+        )
+    }
+}
+
+impl From<HttpError> for GatewayError {
+    fn from(err: HttpError) -> Self {
+        match err {
+            HttpError::Io(err) => Self::Io(err),
+            HttpError::BadRequest(message) => Self::BadRequest(message),
+            HttpError::PayloadTooLarge(max) => Self::PayloadTooLarge(max),
+            HttpError::LengthRequired => Self::LengthRequired,
+        }
+    }
+}
+
+impl From<fluvio::FluvioError> for GatewayError {
+    fn from(err: fluvio::FluvioError) -> Self {
+        match err {
+            fluvio::FluvioError::TopicNotFound(topic) => Self::TopicNotFound(topic),
+            other => Self::Fluvio(other.into()),
+        }
+    }
+}
+
+/// Fluvio client returns `anyhow::Error`, recover missing topics to answer with 404
+pub(crate) fn from_fluvio(err: anyhow::Error) -> GatewayError {
+    match err.downcast::<fluvio::FluvioError>() {
+        Ok(err) => err.into(),
+        Err(err) => GatewayError::Fluvio(err),
+    }
+}
@@ -0,0 +1,70 @@
+//! HTTP gateway to Fluvio for clients that can't use the Fluvio protocol.
+//!
+//! * `POST /topics/{topic}/records`: produce JSON records or a binary record
+//! * `GET /topics/{topic}/records`: consume with long poll
+//! * `GET /topics/{topic}/stream`: consume as Server-Sent Events
+//! * `GET /consumers`: list consumer offsets
+//! * `DELETE /consumers/{consumer}/topics/{topic}/partitions/{partition}`: delete consumer offset
+//!
+//! WebSocket is not supported.
+
+mod consume;
+mod error;
+mod produce;
+mod server;
+
+pub use error::GatewayError;
+pub use server::{GatewayContext, HttpGateway};
+
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use tracing::info;
+
+use fluvio::config::ConfigFile;
+use fluvio::{Fluvio, FluvioConfig};
+use fluvio_future::task::run_block_on;
+
+const DEFAULT_MAX_BODY_SIZE: usize = 16 * 1024 * 1024;
+
+#[derive(Debug, Parser)]
+#[command(name = "fluvio-http-gateway", about = "HTTP gateway for Fluvio")]
+pub struct GatewayOpt {
+    /// Address to listen for HTTP requests, only local clients can connect by default.
+    /// Gateway has no authentication, put it behind a TLS proxy before exposing it
+    #[arg(
+        long,
+        value_name = "host:port",
+        default_value = "127.0.0.1:8080",
+        env = "FLV_HTTP_GATEWAY_ADDR"
+    )]
+    pub bind: String,
+
+    /// Maximum size of produce request body in bytes
+    #[arg(long, value_name = "integer", default_value_t = DEFAULT_MAX_BODY_SIZE)]
+    pub max_body_size: usize,
+
+    /// Profile of the cluster, current profile if not set
+    #[arg(long, value_name = "name")]
+    pub profile: Option<String>,
+}
+
+impl GatewayOpt {
+    pub fn process(self) -> Result<()> {
+        let config = match &self.profile {
+            Some(profile) => ConfigFile::load_default_or_new()?
+                .config()
+                .cluster_with_profile(profile)
+                .cloned()
+                .ok_or_else(|| anyhow!("profile not found: {profile}"))?,
+            None => FluvioConfig::load()?,
+        };
+
+        run_block_on(async move {
+            let fluvio = Fluvio::connect_with_config(&config).await?;
+            info!(endpoint = %config.endpoint, "connected to cluster");
+            HttpGateway::new(self.bind, GatewayContext::new(fluvio, self.max_body_size))
+                .run()
+                .await
+        })
+    }
+}
@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::{debug, instrument};
+
+use fluvio::RecordKey;
+use fluvio_service::http::{HttpRequest, HttpResponse, CONTENT_TYPE_JSON};
+
+use crate::error::{from_fluvio, GatewayError};
+use crate::server::SharedGatewayContext;
+
+/// Record sent as JSON, string values are stored as is and any other value as JSON text
+#[derive(Debug, Deserialize)]
+struct JsonRecord {
+    #[serde(default)]
+    key: Option<String>,
+    value: Value,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum JsonRecords {
+    One(JsonRecord),
+    Many(Vec<JsonRecord>),
+}
+
+#[derive(Debug, Serialize)]
+struct ProducedRecord {
+    partition: u32,
+    offset: i64,
+}
+
+#[derive(Debug, Serialize)]
+struct ProduceResponse {
+    records: Vec<ProducedRecord>,
+}
+
+/// `POST /topics/{topic}/records`
+///
+/// JSON bodies contain one record or an array of records, any other content type is sent as
+/// a single binary record with the key taken from the `key` query parameter.
+#[instrument(skip(ctx, request))]
+pub async fn handle_produce(
+    ctx: &SharedGatewayContext,
+    topic: &str,
+    request: HttpRequest,
+) -> Result<HttpResponse, GatewayError> {
+    let records = if request
+        .header("content-type")
+        .is_some_and(|content_type| content_type.starts_with(CONTENT_TYPE_JSON))
+    {
+        parse_json_records(&request.body)?
+    } else {
+        vec![(request.query("key").map(str::to_owned), request.body)]
+    };
+    debug!(records = records.len(), "producing");
+
+    let producer = ctx.producer(topic).await?;
+    let result = async {
+        let mut outputs = Vec::with_capacity(records.len());
+        for (key, value) in records {
+            let key = match key {
+                Some(key) => RecordKey::from(key),
+                None => RecordKey::NULL,
+            };
+            outputs.push(producer.send(key, value).await?);
+        }
+        producer.flush().await?;
+
+        let mut produced = Vec::with_capacity(outputs.len());
+        for output in outputs {
+            let metadata = output.wait().await?;
+            produced.push(ProducedRecord {
+                partition: metadata.partition_id(),
+                offset: metadata.offset(),
+            });
+        }
+        Ok::<_, anyhow::Error>(produced)
+    }
+    .await;
+
+    match result {
+        Ok(records) => Ok(HttpResponse::json(200, &ProduceResponse { records })),
+        Err(err) => {
+            // producer can't be used after an error, next request creates a new one
+            ctx.remove_producer(topic).await;
+            Err(from_fluvio(err))
+        }
+    }
+}
+
+fn parse_json_records(body: &[u8]) -> Result<Vec<(Option<String>, Vec<u8>)>, GatewayError> {
+    let records = match serde_json::from_slice(body)
+        .map_err(|err| GatewayError::BadRequest(err.to_string()))?
+    {
+        JsonRecords::One(record) => vec![record],
+        JsonRecords::Many(records) => records,
+    };
+    Ok(records
+        .into_iter()
+        .map(|record| {
+            let value = match record.value {
+                Value::String(value) => value.into_bytes(),
+                other => other.to_string().into_bytes(),
+            };
+            (record.key, value)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_parse_json_records() {
+        let records = parse_json_records(br#"{"key":"a","value":"hello"}"#).expect("one");
+        assert_eq!(records, vec![(Some("a".to_owned()), b"hello".to_vec())]);
+
+        let records =
+            parse_json_records(br#"[{"value":{"count":1}},{"key":"b","value":2}]"#).expect("many");
+        assert_eq!(
+            records,
+            vec![
+                (None, br#"{"count":1}"#.to_vec()),
+                (Some("b".to_owned()), b"2".to_vec())
+            ]
+        );
+
+        assert!(parse_json_records(br#"{"key":"a"}"#).is_err());
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_lock::RwLock;
+use futures_util::StreamExt;
+use tracing::{debug, error, info, instrument};
+
+use fluvio::{Fluvio, TopicProducerPool};
+use fluvio_future::net::{TcpListener, TcpStream};
+use fluvio_future::task::spawn;
+use fluvio_service::http::{HttpRequest, HttpResponse, CONTENT_TYPE_EVENT_STREAM};
+
+use crate::consume::{handle_consume, handle_delete_consumer, handle_list_consumers, handle_stream};
+use crate::error::{from_fluvio, GatewayError};
+use crate::produce::handle_produce;
+
+pub type SharedGatewayContext = Arc<GatewayContext>;
+
+/// State shared by all connections, producers are created on first use and reused
+pub struct GatewayContext {
+    fluvio: Fluvio,
+    producers: RwLock<HashMap<String, Arc<TopicProducerPool>>>,
+    max_body_size: usize,
+}
+
+impl GatewayContext {
+    pub fn new(fluvio: Fluvio, max_body_size: usize) -> Self {
+        Self {
+            fluvio,
+            producers: RwLock::new(HashMap::new()),
+            max_body_size,
+        }
+    }
+
+    pub fn fluvio(&self) -> &Fluvio {
+        &self.fluvio
+    }
+
+    pub async fn producer(&self, topic: &str) -> Result<Arc<TopicProducerPool>, GatewayError> {
+        if let Some(producer) = self.producers.read().await.get(topic) {
+            return Ok(producer.clone());
+        }
+
+        let mut producers = self.producers.write().await;
+        if let Some(producer) = producers.get(topic) {
+            return Ok(producer.clone());
+        }
+        debug!(topic, "creating producer");
+        let producer = Arc::new(
+            self.fluvio
+                .topic_producer(topic)
+                .await
+                .map_err(from_fluvio)?,
+        );
+        producers.insert(topic.to_owned(), producer.clone());
+        Ok(producer)
+    }
+
+    pub async fn remove_producer(&self, topic: &str) {
+        self.producers.write().await.remove(topic);
+    }
+}
+
+pub struct HttpGateway {
+    addr: String,
+    ctx: SharedGatewayContext,
+}
+
+impl HttpGateway {
+    pub fn new(addr: impl Into<String>, ctx: GatewayContext) -> Self {
+        Self {
+            addr: addr.into(),
+            ctx: Arc::new(ctx),
+        }
+    }
+
+    pub async fn run(self) -> Result<()> {
+        let listener = TcpListener::bind(&self.addr).await?;
+        info!(addr = %self.addr, "HTTP gateway listening");
+
+        let mut incoming = listener.incoming();
+        while let Some(stream) = incoming.next().await {
+            match stream {
+                Ok(stream) => {
+                    spawn(handle_connection(stream, self.ctx.clone()));
+                }
+                Err(err) => {
+                    error!("error accepting connection: {}", err);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[instrument(skip(stream, ctx))]
+async fn handle_connection(mut stream: TcpStream, ctx: SharedGatewayContext) {
+    let response = match HttpRequest::read(&mut stream, ctx.max_body_size).await {
+        Ok(Some(request)) => {
+            debug!(method = %request.method, path = %request.path, "request");
+            match route(&ctx, request, &mut stream).await {
+                Ok(Some(response)) => response,
+                // response was streamed
+                Ok(None) => return,
+                Err(err) => {
+                    debug!(%err, "request failed");
+                    err.into_response()
+                }
+            }
+        }
+        Ok(None) => return,
+        Err(err) => GatewayError::from(err).into_response(),
+    };
+
+    if let Err(err) = response.write_to(&mut stream).await {
+        debug!(%err, "error writing response");
+    }
+}
+
+/// dispatch request, returns `None` if response was already written to the stream
+async fn route(
+    ctx: &SharedGatewayContext,
+    request: HttpRequest,
+    stream: &mut TcpStream,
+) -> Result<Option<HttpResponse>, GatewayError> {
+    let method = request.method.clone();
+    let segments: Vec<String> = request.segments().into_iter().map(String::from).collect();
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+    let response = match (method.as_str(), segments.as_slice()) {
+        ("GET", ["health"]) => HttpResponse::json(200, &serde_json::json!({ "status": "ok" })),
+        ("POST", ["topics", topic, "records"]) => handle_produce(ctx, topic, request).await?,
+        ("GET", ["topics", topic, "records"]) if request.accepts(CONTENT_TYPE_EVENT_STREAM) => {
+            handle_stream(ctx, topic, &request, stream).await?;
+            return Ok(None);
+        }
+        ("GET", ["topics", topic, "records"]) => handle_consume(ctx, topic, &request).await?,
+        ("GET", ["topics", topic, "stream"]) => {
+            handle_stream(ctx, topic, &request, stream).await?;
+            return Ok(None);
+        }
+        ("GET", ["consumers"]) => handle_list_consumers(ctx).await?,
+        ("DELETE", ["consumers", consumer, "topics", topic, "partitions", partition]) => {
+            handle_delete_consumer(ctx, consumer, topic, partition).await?
+        }
+        (_, ["health"]) | (_, ["topics", _, "records" | "stream"]) | (_, ["consumers", ..]) => {
+            return Err(GatewayError::MethodNotAllowed)
+        }
+        _ => return Err(GatewayError::NotFound(request.path)),
+    };
+    Ok(Some(response))
+}
@@ -1,8 +1,10 @@
 mod request;
 mod response;
+mod trace;
 
 pub use self::response::*;
 pub use self::request::*;
+pub use self::trace::TraceContext;
 
 pub const MAX_BYTES: i32 = 52428800;
 
@@ -29,7 +31,12 @@ mod common {
     use bytes::Buf;
     use tracing::{debug, trace};
 
-    use crate::{Encoder, Decoder};
+    use crate::{Encoder, Decoder, Version};
+
+    use super::TraceContext;
+
+    /// set in encoded api key when trace context follows client id
+    const TRACE_CONTEXT_FLAG: u16 = 0x8000;
 
     const fn max(a: i16, b: i16) -> i16 {
         if a > b {
@@ -45,6 +52,8 @@ mod common {
         const DEFAULT_API_VERSION: i16 = 0;
         const MIN_API_VERSION: i16 = max(Self::DEFAULT_API_VERSION - 1, 0); // by default, only suport last version
         const MAX_API_VERSION: i16 = Self::DEFAULT_API_VERSION;
+        /// first version where server accepts trace context in header, never sent if None
+        const TRACE_CONTEXT_API_VERSION: Option<i16> = None;
 
         type Response: Encoder + Decoder + Debug;
     }
@@ -93,12 +102,68 @@ mod common {
 
     pub trait ApiKey: Sized + Encoder + Decoder + TryFrom<u16> {}
 
-    #[derive(Debug, Encoder, Decoder, Default, Clone)]
+    /// Header is always decoded with version 0, so trace context can't be gated by version.
+    /// Instead, its presence is flagged by the top bit of api key which no api uses.
+    #[derive(Debug, Default, Clone)]
     pub struct RequestHeader {
         api_key: u16,
         api_version: i16,
         correlation_id: i32,
         client_id: String,
+        trace_context: Option<TraceContext>,
+    }
+
+    impl Encoder for RequestHeader {
+        fn write_size(&self, version: Version) -> usize {
+            self.api_key.write_size(version)
+                + self.api_version.write_size(version)
+                + self.correlation_id.write_size(version)
+                + self.client_id.write_size(version)
+                + self
+                    .trace_context
+                    .as_ref()
+                    .map(|context| context.write_size(version))
+                    .unwrap_or_default()
+        }
+
+        fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), IoError>
+        where
+            T: bytes::BufMut,
+        {
+            let api_key = if self.trace_context.is_some() {
+                self.api_key | TRACE_CONTEXT_FLAG
+            } else {
+                self.api_key
+            };
+            api_key.encode(dest, version)?;
+            self.api_version.encode(dest, version)?;
+            self.correlation_id.encode(dest, version)?;
+            self.client_id.encode(dest, version)?;
+            if let Some(context) = &self.trace_context {
+                context.encode(dest, version)?;
+            }
+            Ok(())
+        }
+    }
+
+    impl Decoder for RequestHeader {
+        fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), IoError>
+        where
+            T: Buf,
+        {
+            let mut api_key: u16 = 0;
+            api_key.decode(src, version)?;
+            self.api_key = api_key & !TRACE_CONTEXT_FLAG;
+            self.api_version.decode(src, version)?;
+            self.correlation_id.decode(src, version)?;
+            self.client_id.decode(src, version)?;
+            self.trace_context = if api_key & TRACE_CONTEXT_FLAG != 0 {
+                Some(TraceContext::decode_from(src, version)?)
+            } else {
+                None
+            };
+            Ok(())
+        }
     }
 
     impl fmt::Display for RequestHeader {
@@ -120,6 +185,7 @@ mod common {
                 correlation_id: 1,
 
                 client_id: client_id.into(),
+                trace_context: None,
             }
         }
 
@@ -156,6 +222,15 @@ mod common {
             self.client_id = client_id.into();
             self
         }
+
+        pub fn trace_context(&self) -> Option<&TraceContext> {
+            self.trace_context.as_ref()
+        }
+
+        pub fn set_trace_context(&mut self, context: Option<TraceContext>) -> &mut Self {
+            self.trace_context = context;
+            self
+        }
     }
 
     impl From<&RequestHeader> for i32 {
@@ -189,6 +189,7 @@ mod test {
     use std::convert::TryInto;
     use bytes::{Buf, BufMut};
     use crate::api::ApiMessage;
+    use crate::api::TraceContext;
 
     #[repr(u16)]
     #[derive(Eq, PartialEq, Debug, Clone, Copy, Encoder, Decoder)]
@@ -272,6 +273,29 @@ mod test {
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn test_header_trace_context() -> Result<(), IoError> {
+        let context =
+            TraceContext::new(0x4bf92f3577b34da6a3ce929d0e0e4736, 0x00f067aa0ba902b7, true);
+        let mut req_header = RequestHeader::new_with_client(
+            TestApiEnum::ApiVersion as u16,
+            String::from("consumer-1"),
+        );
+        req_header.set_trace_context(Some(context));
+
+        let mut result = vec![];
+        req_header.encode(&mut result, 0)?;
+        assert_eq!(result.len(), req_header.write_size(0));
+        assert_eq!(&result[0..2], &[0x80, 0x12]);
+
+        let header: RequestHeader = RequestHeader::decode_from(&mut Cursor::new(&result), 0)?;
+        assert_eq!(header.api_key(), TestApiEnum::ApiVersion as u16);
+        assert_eq!(header.client_id(), "consumer-1");
+        assert_eq!(header.trace_context(), Some(&context));
+
+        Ok(())
+    }
+
     pub enum TestApiRequest {
         ApiVersionRequest(RequestMessage<ApiVersionRequest>),
     }
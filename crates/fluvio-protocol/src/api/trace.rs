@@ -0,0 +1,123 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Encoder, Decoder};
+
+const TRACEPARENT_VERSION: &str = "00";
+const FLAG_SAMPLED: u8 = 0x01;
+
+/// Distributed trace context carried in request header.
+/// Follows W3C Trace Context, textual form is the `traceparent` header value.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Encoder, Decoder)]
+pub struct TraceContext {
+    trace_id_high: u64,
+    trace_id_low: u64,
+    span_id: u64,
+    flags: u8,
+}
+
+impl TraceContext {
+    pub fn new(trace_id: u128, span_id: u64, sampled: bool) -> Self {
+        Self {
+            trace_id_high: (trace_id >> 64) as u64,
+            trace_id_low: trace_id as u64,
+            span_id,
+            flags: if sampled { FLAG_SAMPLED } else { 0 },
+        }
+    }
+
+    pub fn trace_id(&self) -> u128 {
+        ((self.trace_id_high as u128) << 64) | self.trace_id_low as u128
+    }
+
+    pub fn span_id(&self) -> u64 {
+        self.span_id
+    }
+
+    pub fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    pub fn is_sampled(&self) -> bool {
+        self.flags & FLAG_SAMPLED != 0
+    }
+
+    /// all zero trace or span id is invalid per W3C spec
+    pub fn is_valid(&self) -> bool {
+        self.trace_id() != 0 && self.span_id != 0
+    }
+
+    /// same trace with different parent span
+    pub fn with_span_id(&self, span_id: u64) -> Self {
+        Self { span_id, ..*self }
+    }
+}
+
+impl fmt::Display for TraceContext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{TRACEPARENT_VERSION}-{:032x}-{:016x}-{:02x}",
+            self.trace_id(),
+            self.span_id,
+            self.flags
+        )
+    }
+}
+
+impl FromStr for TraceContext {
+    type Err = String;
+
+    /// parse `traceparent` value: `version-trace_id-span_id-flags`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.trim().split('-').collect();
+        let [version, trace_id, span_id, flags] = parts[..] else {
+            return Err(format!("invalid traceparent: {s}"));
+        };
+        if version.len() != 2 || trace_id.len() != 32 || span_id.len() != 16 || flags.len() != 2 {
+            return Err(format!("invalid traceparent: {s}"));
+        }
+        let hex_err = |_| format!("invalid traceparent: {s}");
+        let context = Self {
+            flags: u8::from_str_radix(flags, 16).map_err(hex_err)?,
+            ..Self::new(
+                u128::from_str_radix(trace_id, 16).map_err(hex_err)?,
+                u64::from_str_radix(span_id, 16).map_err(hex_err)?,
+                false,
+            )
+        };
+        if context.is_valid() {
+            Ok(context)
+        } else {
+            Err(format!("invalid traceparent: {s}"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_traceparent_round_trip() {
+        let context =
+            TraceContext::new(0x4bf92f3577b34da6a3ce929d0e0e4736, 0x00f067aa0ba902b7, true);
+        let traceparent = context.to_string();
+        assert_eq!(
+            traceparent,
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+
+        let parsed: TraceContext = traceparent.parse().expect("parse");
+        assert_eq!(parsed, context);
+        assert!(parsed.is_sampled());
+
+        assert!("00-00000000000000000000000000000000-00f067aa0ba902b7-01"
+            .parse::<TraceContext>()
+            .is_err());
+        assert!("00-4bf92f3577b34da6a3ce929d0e0e4736-01"
+            .parse::<TraceContext>()
+            .is_err());
+    }
+}
@@ -103,6 +103,9 @@ pub enum ErrorCode {
     #[fluvio(tag = 2008)]
     #[error("the topic has invalid replica type")]
     TopicInvalidReplicaType,
+    #[fluvio(tag = 2009)]
+    #[error("topic violates cluster limits: {0}")]
+    TopicLimitViolation(String),
 
     // Partition errors
     #[fluvio(tag = 3000)]
@@ -122,6 +125,9 @@ pub enum ErrorCode {
     #[fluvio(tag = 3004)]
     #[error("the offset management is disabled for the stream")]
     OffsetManagementDisabled,
+    #[fluvio(tag = 3005)]
+    #[error("{in_sync} replicas are in sync, at least {min} in-sync replicas are required")]
+    NotEnoughInSyncReplicas { in_sync: u16, min: u16 },
 
     // Managed Connector Errors
     #[fluvio(tag = 5000)]
@@ -166,6 +172,9 @@ pub enum ErrorCode {
         "SmartModule memory limit exceeded: requested {requested} bytes, max allowed {max} bytes"
     )]
     SmartModuleMemoryLimitExceeded { requested: u64, max: u64 },
+    #[fluvio(tag = 6009)]
+    #[error("SmartModule dead-letter error policy is supported only on produce, consumer can skip records")]
+    SmartModuleDeadLetterNotSupported,
 
     // TableFormat Errors
     #[fluvio(tag = 7000)]
@@ -228,6 +237,11 @@ pub enum ErrorCode {
     #[fluvio(tag = 12002)]
     #[error("system {kind} '{name}' can only be updated forcibly")]
     SystemSpecUpdatingAttempt { kind: String, name: String },
+
+    // Tenants
+    #[fluvio(tag = 13001)]
+    #[error("tenant '{tenant}' limit exceeded: {limit}")]
+    TenantLimitExceeded { tenant: String, limit: String },
 }
 
 impl ErrorCode {
@@ -315,6 +329,7 @@ mod test {
         assert_tag!(ErrorCode::TopicPendingInitialization, 2003, 0);
         assert_tag!(ErrorCode::TopicInvalidConfiguration, 2004, 0);
         assert_tag!(ErrorCode::TopicNotProvisioned, 2005, 0);
+        assert_tag!(ErrorCode::TopicLimitViolation("limit".to_owned()), 2009, 0);
 
         // Partition errors
         assert_tag!(ErrorCode::PartitionPendingInitialization, 3000, 0);
@@ -322,6 +337,21 @@ mod test {
 
         // Stream Fetch error
         assert_tag!(ErrorCode::FetchSessionNotFoud, 3002, 0);
+        assert_tag!(
+            ErrorCode::NotEnoughInSyncReplicas { in_sync: 1, min: 2 },
+            3005,
+            0
+        );
+
+        // Tenant errors
+        assert_tag!(
+            ErrorCode::TenantLimitExceeded {
+                tenant: "team-a".to_owned(),
+                limit: "partitions".to_owned()
+            },
+            13001,
+            0
+        );
     }
 
     #[test]
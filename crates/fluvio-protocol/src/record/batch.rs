@@ -298,6 +298,7 @@ impl Batch {
                 offset: base_offset + relative as Offset,
                 timestamp_base: first_timestamp,
                 record,
+                topic: None,
             })
     }
 }
@@ -408,6 +409,20 @@ where
         self.header.partition_leader_epoch.encode(dest, version)?;
         self.header.magic.encode(dest, version)?;
 
+        let out = self.crc_content(version)?;
+        let crc = crc32c::crc32c(&out);
+        crc.encode(dest, version)?;
+        dest.put_slice(&out);
+        Ok(())
+    }
+}
+
+impl<R> Batch<R>
+where
+    R: BatchRecords,
+{
+    /// bytes covered by crc, from attributes to the end of records
+    fn crc_content(&self, version: Version) -> Result<Vec<u8>, Error> {
         let mut out: Vec<u8> = Vec::new();
         let buf = &mut out;
         self.header.attributes.encode(buf, version)?;
@@ -421,11 +436,17 @@ where
             self.schema_id.encode(buf, version)?;
         }
         self.records.encode(buf, version)?;
+        Ok(out)
+    }
 
-        let crc = crc32c::crc32c(&out);
-        crc.encode(dest, version)?;
-        dest.put_slice(&out);
-        Ok(())
+    /// compute CRC32C of the batch content
+    pub fn compute_crc(&self) -> Result<u32, Error> {
+        Ok(crc32c::crc32c(&self.crc_content(0)?))
+    }
+
+    /// check if crc in the header matches batch content
+    pub fn validate_crc(&self) -> Result<bool, Error> {
+        Ok(self.compute_crc()? == self.header.crc)
     }
 }
 
@@ -579,6 +600,24 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_batch_validate_crc() -> Result<(), IoError> {
+        let mut batch = Batch::<MemoryRecords>::default();
+        batch.add_record(Record::new("test"));
+        let bytes = batch.as_bytes(0)?;
+
+        let decoded = Batch::<RawRecords>::decode_from(&mut Cursor::new(bytes.clone()), 0)?;
+        assert!(decoded.validate_crc()?);
+
+        // flip last byte of the record value
+        let mut corrupted = bytes.to_vec();
+        let last = corrupted.len() - 1;
+        corrupted[last] ^= 0xFF;
+        let decoded = Batch::<RawRecords>::decode_from(&mut Cursor::new(corrupted), 0)?;
+        assert!(!decoded.validate_crc()?);
+        Ok(())
+    }
+
     /*  raw batch encoded
 
     0000   02 00 00 00 45 00 00 c7 00 00 40 00 40 06 00 00
@@ -6,6 +6,7 @@ use std::io::Error;
 use std::io::ErrorKind;
 use std::ops::Deref;
 use std::str::Utf8Error;
+use std::sync::Arc;
 
 use bytes::Bytes;
 use bytes::BytesMut;
@@ -413,12 +414,89 @@ impl RecordHeader {
     }
 }
 
+/// Header of record, key and optional value as in Kafka records
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RecordHeaderEntry {
+    pub key: String,
+    pub value: Option<Bytes>,
+}
+
+impl RecordHeaderEntry {
+    pub fn new(key: impl Into<String>, value: Option<Bytes>) -> Self {
+        Self {
+            key: key.into(),
+            value,
+        }
+    }
+}
+
+impl Encoder for RecordHeaderEntry {
+    fn write_size(&self, _version: Version) -> usize {
+        let key_len = self.key.len() as i64;
+        let value_len = self.value.as_ref().map_or(-1, |value| value.len() as i64);
+        key_len.var_write_size()
+            + self.key.len()
+            + value_len.var_write_size()
+            + value_len.max(0) as usize
+    }
+
+    fn encode<T>(&self, dest: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        (self.key.len() as i64).encode_varint(dest)?;
+        dest.put_slice(self.key.as_bytes());
+        match &self.value {
+            Some(value) => {
+                (value.len() as i64).encode_varint(dest)?;
+                dest.put_slice(value);
+            }
+            None => (-1_i64).encode_varint(dest)?,
+        }
+        Ok(())
+    }
+}
+
+impl Decoder for RecordHeaderEntry {
+    fn decode<T>(&mut self, src: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        let key = decode_header_bytes(src)?
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "null record header key"))?;
+        self.key = String::from_utf8(key.to_vec())
+            .map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+        self.value = decode_header_bytes(src)?;
+        Ok(())
+    }
+}
+
+fn decode_header_bytes<T: Buf>(src: &mut T) -> Result<Option<Bytes>, Error> {
+    let mut len: i64 = 0;
+    len.decode_varint(src)?;
+    if len < 0 {
+        return Ok(None);
+    }
+    if (src.remaining() as i64) < len {
+        return Err(Error::new(
+            ErrorKind::UnexpectedEof,
+            "not enough for record header",
+        ));
+    }
+    Ok(Some(src.copy_to_bytes(len as usize)))
+}
+
+/// Record is encoded as in Kafka record batches (magic 2), except key and value
+/// are encoded by `B`.
+///
+/// Headers are only set by Kafka producers. Clients and SmartModules built with
+/// versions of this crate before headers were decoded can't read records with headers.
 #[derive(Default, Clone)]
 pub struct Record<B = RecordData> {
     pub preamble: RecordHeader,
     pub key: Option<B>,
     pub value: B,
-    pub headers: i64,
+    pub headers: Vec<RecordHeaderEntry>,
 }
 
 impl<B: Default> Record<B> {
@@ -513,7 +591,12 @@ where
         let inner_size = self.preamble.write_size(version)
             + self.key.write_size(version)
             + self.value.write_size(version)
-            + self.headers.var_write_size();
+            + (self.headers.len() as i64).var_write_size()
+            + self
+                .headers
+                .iter()
+                .map(|header| header.write_size(version))
+                .sum::<usize>();
         let len: i64 = inner_size as i64;
         len.var_write_size() + inner_size
     }
@@ -526,7 +609,10 @@ where
         self.preamble.encode(&mut out, version)?;
         self.key.encode(&mut out, version)?;
         self.value.encode(&mut out, version)?;
-        self.headers.encode_varint(&mut out)?;
+        (self.headers.len() as i64).encode_varint(&mut out)?;
+        for header in &self.headers {
+            header.encode(&mut out, version)?;
+        }
         let len: i64 = out.len() as i64;
         trace!("record encode as {} bytes", len);
         len.encode_varint(dest)?;
@@ -555,11 +641,26 @@ where
                 "not enough for record",
             ));
         }
+        let start = src.remaining();
         self.preamble.decode(src, version)?;
         trace!("offset delta: {}", self.preamble.offset_delta);
         self.key.decode(src, version)?;
         self.value.decode(src, version)?;
-        self.headers.decode_varint(src)?;
+
+        let mut headers: i64 = 0;
+        headers.decode_varint(src)?;
+        self.headers.clear();
+        for _ in 0..headers {
+            let mut header = RecordHeaderEntry::default();
+            header.decode(src, version)?;
+            self.headers.push(header);
+        }
+
+        // skip fields this version doesn't know about
+        let read = (start - src.remaining()) as i64;
+        if read < len {
+            src.advance((len - read) as usize);
+        }
 
         Ok(())
     }
@@ -575,6 +676,8 @@ pub struct ConsumerRecord {
     pub record: Record<RecordData>,
     /// Timestamp base of batch in which the records is present
     pub(crate) timestamp_base: Timestamp,
+    /// The topic where this Record is stored, set by consumers
+    pub(crate) topic: Option<Arc<str>>,
 }
 
 impl ConsumerRecord {
@@ -588,6 +691,17 @@ impl ConsumerRecord {
         self.partition
     }
 
+    /// The topic where this Record is stored, if known.
+    pub fn topic(&self) -> Option<&str> {
+        self.topic.as_deref()
+    }
+
+    /// Sets the topic where this Record is stored.
+    pub fn with_topic(mut self, topic: Arc<str>) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+
     /// Returns the inner representation of the Record
     pub fn into_inner(self) -> Record<RecordData> {
         self.record
@@ -728,6 +842,33 @@ mod test {
         assert_eq!(record.value.as_ref(), decoded.value.as_ref());
     }
 
+    #[test]
+    fn test_record_headers_encoding() {
+        let mut record = Record::new_key_value("key", "value");
+        record.headers = vec![
+            RecordHeaderEntry::new("trace", Some(Bytes::from_static(b"abc"))),
+            RecordHeaderEntry::new("empty", None),
+        ];
+
+        let mut encoded = Vec::new();
+        record.encode(&mut encoded, 0).unwrap();
+        assert_eq!(record.write_size(0), encoded.len());
+
+        // bytes after headers within record length are skipped
+        let mut extended = encoded.clone();
+        extended[0] += 4; // zigzag encoded length
+        extended.push(0x7);
+        extended.push(0x0);
+        extended.extend_from_slice(&encoded);
+
+        let mut src = Cursor::new(extended);
+        let decoded = Record::<RecordData>::decode_from(&mut src, 0).unwrap();
+        assert_eq!(decoded.headers, record.headers);
+        assert_eq!(decoded.value.as_ref(), b"value");
+        let next = Record::<RecordData>::decode_from(&mut src, 0).unwrap();
+        assert_eq!(next.headers, record.headers);
+    }
+
     // Test Specification:
     //
     // A record was encoded and written to a file, using the following code:
@@ -775,6 +916,7 @@ mod test {
             offset: 0,
             partition: 0,
             record: Default::default(),
+            topic: None,
         };
 
         assert_eq!(record.timestamp(), NO_TIMESTAMP);
@@ -783,6 +925,7 @@ mod test {
             offset: 0,
             partition: 0,
             record: Default::default(),
+            topic: None,
         };
         assert_eq!(record.timestamp(), NO_TIMESTAMP);
     }
@@ -794,6 +937,7 @@ mod test {
             offset: 0,
             partition: 0,
             record: Default::default(),
+            topic: None,
         };
 
         assert_eq!(record.timestamp(), 1_000_000_000);
@@ -804,6 +948,7 @@ mod test {
             record: memory_record,
             offset: 0,
             partition: 0,
+            topic: None,
         };
         assert_eq!(record.timestamp(), 1_000_000_800);
     }
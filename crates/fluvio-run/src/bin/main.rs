@@ -4,7 +4,7 @@ use fluvio_run::RunCmd;
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cmd: RunCmd = RunCmd::parse();
 
-    fluvio_future::subscriber::init_tracer(None);
+    let _telemetry = fluvio_telemetry::init_tracer(cmd.service_name());
 
     cmd.process()?;
     Ok(())
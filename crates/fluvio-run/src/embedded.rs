@@ -0,0 +1,386 @@
+//!
+//! # Embedded Fluvio
+//!
+//! SC and SPUs running inside of application process, on async runtime of application.
+//! Servers listen on ephemeral ports of loopback interface. Metadata and logs are stored
+//! in temporary directory, unless data directory is set.
+//!
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use tempfile::TempDir;
+use tracing::{debug, info, instrument};
+
+use fluvio::{Fluvio, FluvioConfig};
+use fluvio_future::net::TcpListener;
+use fluvio_future::task::run_block_on;
+use fluvio_future::timer::sleep;
+use fluvio_sc::config::ScConfig;
+use fluvio_sc::stores::spu::{Endpoint, IngressAddr, IngressPort, SpuSpec, SpuType};
+use fluvio_service::ServiceTasks;
+use fluvio_spu::SpuConfig;
+use fluvio_types::SpuId;
+use fluvio_types::event::StickyEvent;
+
+const LOCALHOST: &str = "127.0.0.1";
+const BASE_SPU: SpuId = 5001;
+const METADATA_DIR: &str = "metadata";
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Configuration of embedded cluster
+#[derive(Debug, Clone)]
+pub struct EmbeddedConfig {
+    spus: u16,
+    data_dir: Option<PathBuf>,
+    timeout: Duration,
+}
+
+impl Default for EmbeddedConfig {
+    fn default() -> Self {
+        Self {
+            spus: 1,
+            data_dir: None,
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+impl EmbeddedConfig {
+    /// number of SPUs, at least one SPU is started
+    pub fn with_spus(mut self, spus: u16) -> Self {
+        self.spus = spus.max(1);
+        self
+    }
+
+    /// directory for metadata and logs, which is kept when cluster is dropped.
+    /// cluster started again with same directory has same topics and records
+    pub fn with_data_dir(mut self, data_dir: impl Into<PathBuf>) -> Self {
+        self.data_dir = Some(data_dir.into());
+        self
+    }
+
+    /// how long to wait for SC and SPUs to become ready
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Fluvio cluster running in this process.
+///
+/// Dropping cluster stops servers of SC and SPUs, waits until their controllers are stopped,
+/// then removes temporary data directory.
+pub struct EmbeddedCluster {
+    fluvio: Fluvio,
+    sc_addr: String,
+    spus: Vec<SpuId>,
+    data_dir: PathBuf,
+    servers: Vec<Arc<StickyEvent>>,
+    tasks: Vec<Arc<ServiceTasks>>,
+    _temp_dir: Option<TempDir>,
+}
+
+impl EmbeddedCluster {
+    /// start SC and SPUs on current runtime and connect to SC
+    #[instrument(skip(config))]
+    pub async fn start(config: EmbeddedConfig) -> Result<Self> {
+        let (data_dir, temp_dir) = match config.data_dir {
+            Some(data_dir) => {
+                std::fs::create_dir_all(&data_dir)?;
+                (data_dir, None)
+            }
+            None => {
+                let temp_dir = tempfile::Builder::new().prefix("fluvio-").tempdir()?;
+                (temp_dir.path().to_path_buf(), Some(temp_dir))
+            }
+        };
+        let deadline = Instant::now() + config.timeout;
+
+        let (sc_public, sc_addr) = bind_local().await?;
+        let (sc_private, sc_private_addr) = bind_local().await?;
+        let sc_config = ScConfig {
+            public_endpoint: sc_addr.clone(),
+            private_endpoint: sc_private_addr.clone(),
+            ..Default::default()
+        };
+        info!(%sc_addr, data_dir = %data_dir.display(), "starting embedded sc");
+        let (ctx, mut servers) = fluvio_sc::start::start_local(
+            sc_config,
+            &data_dir.join(METADATA_DIR),
+            sc_public,
+            sc_private,
+        )
+        .await;
+        let mut tasks = vec![ctx.tasks().clone()];
+
+        let mut spus = vec![];
+        for index in 0..config.spus {
+            let id = BASE_SPU + index as SpuId;
+            let (public_listener, public_endpoint) = bind_local().await?;
+            let (private_listener, private_endpoint) = bind_local().await?;
+            let mut spu_config = SpuConfig {
+                id,
+                public_endpoint,
+                private_endpoint,
+                sc_endpoint: sc_private_addr.clone(),
+                ..Default::default()
+            };
+            spu_config.log.base_dir = data_dir.clone();
+
+            // spec is updated if spu was registered by previous run on different ports
+            let spec = spu_spec(&spu_config)?;
+            debug!(?spec, "registering embedded spu");
+            ctx.spus()
+                .create_spec(format!("embedded-spu-{id}"), spec)
+                .await?;
+
+            info!(id, endpoint = %spu_config.public_endpoint, "starting embedded spu");
+            let (spu_tasks, spu_servers) =
+                fluvio_spu::start_services(spu_config, public_listener, private_listener);
+            servers.extend(spu_servers);
+            tasks.push(spu_tasks);
+            spus.push(id);
+        }
+
+        // spu is online once it is connected to sc
+        loop {
+            let online = ctx
+                .spus()
+                .store()
+                .read()
+                .await
+                .values()
+                .filter(|spu| spus.contains(&spu.spec.id) && spu.status.is_online())
+                .count();
+            if online == spus.len() {
+                break;
+            }
+            if Instant::now() > deadline {
+                return Err(anyhow!(
+                    "{online} of {} embedded spus online, timed out after {:?}",
+                    spus.len(),
+                    config.timeout
+                ));
+            }
+            sleep(POLL_INTERVAL).await;
+        }
+
+        let fluvio_config = FluvioConfig::new(sc_addr.clone());
+        let fluvio = loop {
+            match Fluvio::connect_with_config(&fluvio_config).await {
+                Ok(fluvio) => break fluvio,
+                Err(err) if Instant::now() > deadline => return Err(err),
+                Err(err) => {
+                    debug!(%err, "embedded sc not ready");
+                    sleep(POLL_INTERVAL).await;
+                }
+            }
+        };
+
+        info!(%sc_addr, spus = spus.len(), "embedded cluster started");
+        Ok(Self {
+            fluvio,
+            sc_addr,
+            spus,
+            data_dir,
+            servers,
+            tasks,
+            _temp_dir: temp_dir,
+        })
+    }
+
+    /// client connected to this cluster
+    pub fn fluvio(&self) -> &Fluvio {
+        &self.fluvio
+    }
+
+    /// public endpoint of SC, other clients can connect to
+    pub fn sc_addr(&self) -> &str {
+        &self.sc_addr
+    }
+
+    pub fn spus(&self) -> &[SpuId] {
+        &self.spus
+    }
+
+    pub fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+}
+
+impl Drop for EmbeddedCluster {
+    /// stop servers and controllers before data directory is removed
+    fn drop(&mut self) {
+        debug!(sc_addr = %self.sc_addr, "stopping embedded cluster");
+        for server in &self.servers {
+            server.notify();
+        }
+        // spus are stopped before sc, so they don't reconnect to it
+        let tasks = std::mem::take(&mut self.tasks);
+        run_block_on(async move {
+            for tasks in tasks.iter().rev() {
+                tasks.stop().await;
+            }
+        });
+    }
+}
+
+/// listener on ephemeral port of loopback interface and its address
+async fn bind_local() -> Result<(TcpListener, String)> {
+    let listener = TcpListener::bind(local_addr(0)).await?;
+    let addr = listener.local_addr()?.to_string();
+    Ok((listener, addr))
+}
+
+fn local_addr(port: u16) -> String {
+    format!("{LOCALHOST}:{port}")
+}
+
+fn port_of(addr: &str) -> Result<u16> {
+    addr.rsplit(':')
+        .next()
+        .and_then(|port| port.parse().ok())
+        .ok_or_else(|| anyhow!("invalid address: {addr}"))
+}
+
+/// spec of custom spu under which SPU is registered in SC
+fn spu_spec(config: &SpuConfig) -> Result<SpuSpec> {
+    Ok(SpuSpec {
+        id: config.id,
+        spu_type: SpuType::Custom,
+        public_endpoint: IngressPort {
+            port: port_of(&config.public_endpoint)?,
+            ingress: vec![IngressAddr::from_host(LOCALHOST.to_owned())],
+            ..Default::default()
+        },
+        private_endpoint: Endpoint {
+            port: port_of(&config.private_endpoint)?,
+            host: LOCALHOST.to_owned(),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod test {
+
+    use futures_util::StreamExt;
+
+    use fluvio::{Offset, RecordKey};
+    use fluvio::consumer::ConsumerConfigExtBuilder;
+    use fluvio::metadata::topic::TopicSpec;
+    use fluvio_future::net::TcpStream;
+
+    use super::*;
+
+    #[test]
+    fn test_spu_spec() {
+        let config = SpuConfig {
+            id: 5002,
+            public_endpoint: local_addr(9010),
+            private_endpoint: local_addr(9011),
+            ..Default::default()
+        };
+        let spec = spu_spec(&config).expect("spec");
+        assert!(spec.is_custom());
+        assert_eq!(spec.public_endpoint.port, 9010);
+        assert_eq!(spec.private_endpoint.port, 9011);
+        assert_eq!(spec.private_endpoint.host, LOCALHOST);
+        assert!(port_of("localhost").is_err());
+    }
+
+    #[fluvio_future::test]
+    async fn test_embedded_cluster() {
+        let cluster = EmbeddedCluster::start(EmbeddedConfig::default().with_spus(2))
+            .await
+            .expect("start");
+        assert_eq!(cluster.spus(), &[5001, 5002]);
+
+        let fluvio = cluster.fluvio();
+        fluvio
+            .admin()
+            .await
+            .create(
+                "embedded".to_owned(),
+                false,
+                TopicSpec::new_computed(1, 2, None),
+            )
+            .await
+            .expect("create topic");
+
+        let producer = fluvio.topic_producer("embedded").await.expect("producer");
+        producer.send(RecordKey::NULL, "hello").await.expect("send");
+        producer.flush().await.expect("flush");
+
+        let mut stream = fluvio
+            .consumer_with_config(
+                ConsumerConfigExtBuilder::default()
+                    .topic("embedded".to_owned())
+                    .offset_start(Offset::beginning())
+                    .build()
+                    .expect("config"),
+            )
+            .await
+            .expect("stream");
+        let record = stream.next().await.expect("record").expect("no error");
+        assert_eq!(record.value(), b"hello");
+
+        let data_dir = cluster.data_dir().to_path_buf();
+        assert!(data_dir.join(METADATA_DIR).exists());
+        drop(cluster);
+        assert!(!data_dir.exists());
+    }
+
+    #[fluvio_future::test]
+    async fn test_embedded_cluster_restart() {
+        let data_dir = tempfile::tempdir().expect("data dir");
+        let topic = "embedded-restart";
+
+        let cluster =
+            EmbeddedCluster::start(EmbeddedConfig::default().with_data_dir(data_dir.path()))
+                .await
+                .expect("start");
+        let sc_addr = cluster.sc_addr().to_owned();
+        let fluvio = cluster.fluvio();
+        fluvio
+            .admin()
+            .await
+            .create(topic.to_owned(), false, TopicSpec::new_computed(1, 1, None))
+            .await
+            .expect("create topic");
+        let producer = fluvio.topic_producer(topic).await.expect("producer");
+        producer
+            .send(RecordKey::NULL, "before restart")
+            .await
+            .expect("send");
+        producer.flush().await.expect("flush");
+        drop(producer);
+        drop(cluster);
+
+        // servers are stopped, data is kept
+        sleep(POLL_INTERVAL).await;
+        assert!(TcpStream::connect(&sc_addr).await.is_err());
+        assert!(data_dir.path().join(METADATA_DIR).exists());
+
+        let cluster =
+            EmbeddedCluster::start(EmbeddedConfig::default().with_data_dir(data_dir.path()))
+                .await
+                .expect("restart");
+        let mut stream = cluster
+            .fluvio()
+            .consumer_with_config(
+                ConsumerConfigExtBuilder::default()
+                    .topic(topic.to_owned())
+                    .offset_start(Offset::beginning())
+                    .build()
+                    .expect("config"),
+            )
+            .await
+            .expect("stream");
+        let record = stream.next().await.expect("record").expect("no error");
+        assert_eq!(record.value(), b"before restart");
+    }
+}
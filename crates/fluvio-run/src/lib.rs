@@ -1,6 +1,7 @@
 use clap::Parser;
 
 mod error;
+pub mod embedded;
 
 pub use error::RunnerError;
 use error::Result;
@@ -29,13 +30,22 @@ pub enum RunCmd {
 }
 
 impl RunCmd {
+    /// name of service reported in traces
+    pub fn service_name(&self) -> &'static str {
+        match self {
+            Self::SPU(_) => "fluvio-spu",
+            Self::SC(_) => "fluvio-sc",
+            _ => "fluvio-run",
+        }
+    }
+
     pub fn process(self) -> Result<()> {
         match self {
             Self::SPU(opt) => {
                 fluvio_spu::main_loop(opt);
             }
             Self::SC(opt) => {
-                fluvio_sc::start::main_loop(opt);
+                fluvio_sc::start::main_loop(opt)?;
             }
             Self::Metadata(meta) => {
                 meta.process()?;
@@ -10,6 +10,7 @@ pub mod shared;
 pub mod tableformat;
 pub mod mirror;
 pub mod mirroring;
+pub mod limits;
 
 pub mod remote_file;
 
@@ -29,6 +30,7 @@ pub mod errors {
 pub use fluvio_controlplane_metadata::core;
 pub use fluvio_controlplane_metadata::store;
 pub use fluvio_controlplane_metadata::message;
+pub use fluvio_controlplane_metadata::tenant;
 pub use error::ApiError;
 mod error {
 
@@ -122,15 +124,30 @@ mod admin {
     }
 
     /// Not every Admin Object can be created directly
-    pub trait CreatableAdminSpec: ClassicCreatableAdminSpec + Spec + Encoder + Decoder {}
+    pub trait CreatableAdminSpec: ClassicCreatableAdminSpec + Spec + Encoder + Decoder {
+        /// name of object created by principal of tenant
+        fn qualify_name(name: String, _tenant: &str) -> String {
+            name
+        }
+    }
 
     pub trait DeletableAdminSpec: Spec + Encoder + Decoder {
         type DeleteKey: Encoder + Decoder + Debug + Default;
+
+        /// key of object deleted by principal of tenant
+        fn qualify_delete_key(key: Self::DeleteKey, _tenant: &str) -> Self::DeleteKey {
+            key
+        }
     }
 
     pub trait UpdatableAdminSpec: Spec + Encoder + Decoder {
         type UpdateKey: Encoder + Decoder + Debug + Default;
         type UpdateAction: Encoder + Decoder + Debug + Default + Clone;
+
+        /// key of object updated by principal of tenant
+        fn qualify_update_key(key: Self::UpdateKey, _tenant: &str) -> Self::UpdateKey {
+            key
+        }
     }
 
     /// try to encode type object into dynamic type which can be downcast later
@@ -0,0 +1,29 @@
+//!
+//! # Topic Limits API
+//!
+//! Cluster limits of topics and their current usage.
+//!
+use fluvio_protocol::{Encoder, Decoder};
+use fluvio_protocol::api::Request;
+
+pub use fluvio_controlplane_metadata::topic::{LimitUsage, TopicLimit, TopicLimits};
+
+use crate::AdminPublicApiKey;
+use crate::objects::COMMON_VERSION;
+
+/// Request limits of topics, with usage of topics visible to the principal
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct TopicLimitsRequest;
+
+impl Request for TopicLimitsRequest {
+    const API_KEY: u16 = AdminPublicApiKey::TopicLimits as u16;
+    const MIN_API_VERSION: i16 = COMMON_VERSION;
+    const DEFAULT_API_VERSION: i16 = COMMON_VERSION;
+    type Response = TopicLimitsResponse;
+}
+
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq, Eq)]
+pub struct TopicLimitsResponse {
+    pub limits: TopicLimits,
+    pub usage: Vec<LimitUsage>,
+}
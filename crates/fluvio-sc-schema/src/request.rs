@@ -17,6 +17,7 @@ use fluvio_protocol::core::Decoder;
 use fluvio_protocol::link::versions::ApiVersionsRequest;
 
 use crate::mirroring::ObjectMirroringRequest;
+use crate::limits::TopicLimitsRequest;
 use crate::AdminPublicApiKey;
 use crate::objects::{
     ObjectApiCreateRequest, ObjectApiDeleteRequest, ObjectApiListRequest, ObjectApiUpdateRequest,
@@ -33,6 +34,7 @@ pub enum AdminPublicDecodedRequest {
     WatchRequest(RequestMessage<ObjectApiWatchRequest>),
     MirroringRequest(RequestMessage<ObjectMirroringRequest>),
     UpdateRequest(RequestMessage<ObjectApiUpdateRequest>),
+    TopicLimitsRequest(RequestMessage<TopicLimitsRequest>),
 }
 
 impl Default for AdminPublicDecodedRequest {
@@ -93,6 +95,10 @@ impl ApiMessage for AdminPublicDecodedRequest {
                 header,
                 ObjectApiUpdateRequest::decode_from(src, version)?,
             ))),
+            AdminPublicApiKey::TopicLimits => Ok(Self::TopicLimitsRequest(RequestMessage::new(
+                header,
+                TopicLimitsRequest::decode_from(src, version)?,
+            ))),
         }
     }
 }
@@ -41,9 +41,21 @@ pub fn validate_resource_name(name: &str) -> Result {
     Err(ValidateResourceNameError::InvalidCharacterEncountered)
 }
 
+/// Checks if the name of tenant scoped resource is valid.
+/// Name is either a plain resource name or `<tenant>.<name>`, both parts must be valid.
+pub fn validate_qualified_resource_name(name: &str) -> Result {
+    match crate::tenant::split_tenant(name) {
+        (Some(tenant), name) => {
+            validate_resource_name(tenant)?;
+            validate_resource_name(name)
+        }
+        (None, name) => validate_resource_name(name),
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::validate_resource_name;
+    use super::{validate_resource_name, validate_qualified_resource_name};
 
     #[test]
     fn validates_name_length() {
@@ -105,4 +117,15 @@ mod test {
     fn reject_topics_that_start_with_hyphen() {
         assert!(validate_resource_name("-helloworld").is_err());
     }
+
+    #[test]
+    fn validates_qualified_names() {
+        assert!(validate_qualified_resource_name("hello-world").is_ok());
+        assert!(validate_qualified_resource_name("team-a.hello-world").is_ok());
+        assert!(validate_qualified_resource_name("team-a.").is_err());
+        assert!(validate_qualified_resource_name(".hello-world").is_err());
+        assert!(validate_qualified_resource_name("team-a.b.c").is_err());
+        assert!(validate_qualified_resource_name("team_a.hello").is_err());
+        assert!(validate_resource_name("team-a.hello-world").is_err());
+    }
 }
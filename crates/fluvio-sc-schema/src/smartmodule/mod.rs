@@ -7,6 +7,7 @@ mod convert {
     use fluvio_controlplane_metadata::smartmodule::{SmartModuleWasmSummary, SmartModuleWasm};
 
     use crate::{AdminSpec, CreatableAdminSpec, DeletableAdminSpec, UpdatableAdminSpec};
+    use crate::tenant::qualify;
     use super::SmartModuleSpec;
 
     impl AdminSpec for SmartModuleSpec {
@@ -21,10 +22,18 @@ mod convert {
         }
     }
 
-    impl CreatableAdminSpec for SmartModuleSpec {}
+    impl CreatableAdminSpec for SmartModuleSpec {
+        fn qualify_name(name: String, tenant: &str) -> String {
+            qualify(tenant, &name)
+        }
+    }
 
     impl DeletableAdminSpec for SmartModuleSpec {
         type DeleteKey = String;
+
+        fn qualify_delete_key(key: Self::DeleteKey, tenant: &str) -> Self::DeleteKey {
+            qualify(tenant, &key)
+        }
     }
 
     impl UpdatableAdminSpec for SmartModuleSpec {
@@ -5,14 +5,23 @@ mod convert {
     use crate::{CreatableAdminSpec, DeletableAdminSpec, UpdatableAdminSpec};
 
     use crate::AdminSpec;
+    use crate::tenant::qualify;
     use super::TableFormatSpec;
 
     impl AdminSpec for TableFormatSpec {}
 
-    impl CreatableAdminSpec for TableFormatSpec {}
+    impl CreatableAdminSpec for TableFormatSpec {
+        fn qualify_name(name: String, tenant: &str) -> String {
+            qualify(tenant, &name)
+        }
+    }
 
     impl DeletableAdminSpec for TableFormatSpec {
         type DeleteKey = String;
+
+        fn qualify_delete_key(key: Self::DeleteKey, tenant: &str) -> Self::DeleteKey {
+            qualify(tenant, &key)
+        }
     }
 
     impl UpdatableAdminSpec for TableFormatSpec {
@@ -20,18 +20,32 @@ mod convert {
     use crate::AdminSpec;
     use crate::UpdatableAdminSpec;
 
+    use crate::tenant::qualify;
+
     use super::TopicSpec;
 
     impl AdminSpec for TopicSpec {}
 
-    impl CreatableAdminSpec for TopicSpec {}
+    impl CreatableAdminSpec for TopicSpec {
+        fn qualify_name(name: String, tenant: &str) -> String {
+            qualify(tenant, &name)
+        }
+    }
 
     impl DeletableAdminSpec for TopicSpec {
         type DeleteKey = String;
+
+        fn qualify_delete_key(key: Self::DeleteKey, tenant: &str) -> Self::DeleteKey {
+            qualify(tenant, &key)
+        }
     }
 
     impl UpdatableAdminSpec for TopicSpec {
         type UpdateKey = String;
         type UpdateAction = UpdateTopicAction;
+
+        fn qualify_update_key(key: Self::UpdateKey, tenant: &str) -> Self::UpdateKey {
+            qualify(tenant, &key)
+        }
     }
 }
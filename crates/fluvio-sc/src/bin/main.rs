@@ -6,9 +6,9 @@ use clap::Parser;
 use fluvio_sc::cli::ScOpt;
 use fluvio_sc::start::main_loop;
 
-fn main() {
-    fluvio_future::subscriber::init_tracer(None);
+fn main() -> anyhow::Result<()> {
+    let _telemetry = fluvio_telemetry::init_tracer("fluvio-sc");
 
     let opt = ScOpt::parse();
-    main_loop(opt);
+    main_loop(opt)
 }
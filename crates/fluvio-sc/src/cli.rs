@@ -21,11 +21,16 @@ use clap::Parser;
 
 use fluvio_types::print_cli_err;
 use fluvio_types::defaults::TLS_SERVER_SECRET_NAME;
+use fluvio_types::defaults::SC_RAFT_PORT;
 use fluvio_future::openssl::TlsAcceptor;
 use fluvio_future::openssl::SslVerifyMode;
 
+use fluvio_auth::TenantPolicy;
+use fluvio_controlplane_metadata::topic::TopicLimits;
+
 use crate::services::auth::basic::BasicRbacPolicy;
 use crate::config::ScConfig;
+use crate::raft::{NodeId, RaftConfig};
 
 type Config = (ScConfig, Option<BasicRbacPolicy>);
 
@@ -65,9 +70,63 @@ pub struct ScOpt {
     )]
     auth_policy: Option<PathBuf>,
 
+    /// Tenants of principals and their limits, used with authorization policy
+    #[arg(long = "tenant-policy", value_name = "tenant policy path", env)]
+    tenant_policy: Option<PathBuf>,
+
+    /// Cluster limits of topics, replaces limits stored in metadata on start
+    #[arg(long = "topic-limits", value_name = "topic limits path", env)]
+    topic_limits: Option<PathBuf>,
+
     /// only allow white list of controllers
     #[arg(long)]
     white_list: Vec<String>,
+
+    /// Http server serving metrics in OpenMetrics format, disabled if not set
+    #[arg(long, value_name = "host:port", env = "FLV_METRICS_ADDR")]
+    metrics_addr: Option<String>,
+
+    #[command(flatten)]
+    raft: RaftOpt,
+}
+
+/// replication of local metadata among several SC instances
+#[derive(Debug, Args)]
+pub struct RaftOpt {
+    /// id of this SC instance, enables replication of local metadata
+    #[arg(long, requires = "local", requires = "raft_peers")]
+    raft_id: Option<NodeId>,
+
+    /// other SC instance as <id>=<host:port>, can be repeated
+    #[arg(
+        long = "raft-peer",
+        value_name = "id=host:port",
+        value_parser = parse_raft_peer,
+        requires = "raft_id"
+    )]
+    raft_peers: Vec<(NodeId, String)>,
+
+    /// Address for replication requests from other SC instances
+    #[arg(long, requires = "raft_id")]
+    bind_raft: Option<String>,
+}
+
+/// topic limits in json format
+fn read_topic_limits(path: &Path) -> Result<TopicLimits> {
+    debug!(?path, "reading topic limits");
+    let file = std::fs::read(path)?;
+    let limits = serde_json::from_slice(&file)
+        .map_err(|err| anyhow!("invalid topic limits {}: {err}", path.display()))?;
+    info!(?limits, "topic limits");
+    Ok(limits)
+}
+
+fn parse_raft_peer(value: &str) -> Result<(NodeId, String)> {
+    let (id, addr) = value
+        .split_once('=')
+        .ok_or_else(|| anyhow!("expected <id>=<host:port>, got: {value}"))?;
+    let id = id.parse().map_err(|_| anyhow!("invalid raft id: {id}"))?;
+    Ok((id, addr.to_owned()))
 }
 
 #[derive(Debug, Args)]
@@ -89,6 +148,7 @@ pub struct ScOptRunMode {
 #[derive(Debug)]
 pub enum RunMode<'a> {
     Local(&'a Path),
+    Replicated(&'a Path),
     ReadOnly(&'a Path),
     K8s,
 }
@@ -100,6 +160,9 @@ impl ScOpt {
             &self.run_mode.read_only,
             self.run_mode.k8,
         ) {
+            (Some(metadata), None, false) if self.raft.raft_id.is_some() => {
+                RunMode::Replicated(metadata)
+            }
             (Some(metadata), None, false) => RunMode::Local(metadata),
             (None, Some(path), false) => RunMode::ReadOnly(path),
             (None, None, true) => RunMode::K8s,
@@ -107,6 +170,18 @@ impl ScOpt {
         }
     }
 
+    /// replication of metadata stored at path, raft state is kept in its sub directory
+    pub fn raft_config(&self, metadata: &Path) -> Option<RaftConfig> {
+        let id = self.raft.raft_id?;
+        let bind = self
+            .raft
+            .bind_raft
+            .clone()
+            .unwrap_or_else(|| format!("0.0.0.0:{SC_RAFT_PORT}"));
+        let peers = self.raft.raft_peers.iter().cloned().collect();
+        Some(RaftConfig::new(id, bind, peers, metadata.join("raft")))
+    }
+
     /// as sc configuration, 2nd part of tls configuration(proxy addr, tls config)
     /// 3rd part is path to read only metadata config
     #[allow(clippy::wrong_self_convention)]
@@ -127,8 +202,14 @@ impl ScOpt {
         }
 
         config.x509_auth_scopes = self.x509_auth_scopes;
+        config.tenant_policy = self.tenant_policy.map(TenantPolicy::try_from).transpose()?;
+        config.topic_limits = self
+            .topic_limits
+            .map(|path| read_topic_limits(&path))
+            .transpose()?;
         config.white_list = self.white_list.into_iter().collect();
         config.read_only_metadata = self.run_mode.read_only.is_some();
+        config.metrics_endpoint = self.metrics_addr;
 
         // Set Configuration Authorization Policy
 
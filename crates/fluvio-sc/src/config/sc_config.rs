@@ -8,6 +8,8 @@ use std::{io::Error as IoError, path::PathBuf};
 
 use fluvio_types::defaults::SC_PUBLIC_PORT;
 use fluvio_types::defaults::SC_PRIVATE_PORT;
+use fluvio_auth::TenantPolicy;
+use fluvio_controlplane_metadata::topic::TopicLimits;
 
 pub const DEFAULT_NAMESPACE: &str = "default";
 
@@ -28,7 +30,13 @@ pub struct ScConfig {
     pub private_endpoint: String,
     pub namespace: String,
     pub x509_auth_scopes: Option<PathBuf>,
+    /// tenants of authenticated principals, requires authorization policy
+    pub tenant_policy: Option<TenantPolicy>,
+    /// cluster limits of topics, stored as metadata at start replacing stored limits
+    pub topic_limits: Option<TopicLimits>,
     pub white_list: HashSet<String>,
+    /// optional http listener serving OpenMetrics
+    pub metrics_endpoint: Option<String>,
 }
 
 impl ::std::default::Default for ScConfig {
@@ -39,7 +47,10 @@ impl ::std::default::Default for ScConfig {
             private_endpoint: format!("0.0.0.0:{SC_PRIVATE_PORT}"),
             namespace: DEFAULT_NAMESPACE.to_owned(),
             x509_auth_scopes: None,
+            tenant_policy: None,
+            topic_limits: None,
             white_list: HashSet::new(),
+            metrics_endpoint: None,
         }
     }
 }
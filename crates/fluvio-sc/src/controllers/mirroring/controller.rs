@@ -1,3 +1,4 @@
+use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 
 use tracing::{debug, error, info, instrument};
@@ -9,7 +10,11 @@ use adaptive_backoff::prelude::{
 use fluvio::config::TlsPolicy;
 use fluvio_socket::{AsyncResponse, ClientConfig, MultiplexerSocket, StreamSocket};
 use futures_util::StreamExt;
-use fluvio_future::{net::DomainConnector, task::spawn, timer::sleep};
+use fluvio_future::{
+    net::DomainConnector,
+    task::{spawn, JoinHandle},
+    timer::sleep,
+};
 use fluvio_sc_schema::{
     core::MetadataItem,
     mirror::{ConnectionStatus, Home, MirrorPairStatus, MirrorSpec, MirrorStatus, MirrorType},
@@ -23,6 +28,7 @@ use fluvio_controlplane_metadata::mirroring::{
 };
 
 use crate::core::SharedContext;
+use crate::core::metrics::ScMetrics;
 
 const MIRRORING_CONTROLLER_INTERVAL: u64 = 1;
 
@@ -31,17 +37,19 @@ const MIRRORING_CONTROLLER_INTERVAL: u64 = 1;
 pub struct RemoteMirrorController<C: MetadataItem> {
     mirrors: StoreContext<MirrorSpec, C>,
     topics: StoreContext<TopicSpec, C>,
+    metrics: Arc<ScMetrics>,
 }
 
 impl<C: MetadataItem> RemoteMirrorController<C> {
-    pub fn start(ctx: SharedContext<C>) {
+    pub fn start(ctx: SharedContext<C>) -> JoinHandle<()> {
         let controller = Self {
             mirrors: ctx.mirrors().clone(),
             topics: ctx.topics().clone(),
+            metrics: ctx.metrics().clone(),
         };
 
         info!("starting mirroring controller");
-        spawn(controller.dispatch_loop());
+        spawn(controller.dispatch_loop())
     }
 
     #[instrument(skip(self), name = "MirroringControllerLoop")]
@@ -88,6 +96,8 @@ impl<C: MetadataItem> RemoteMirrorController<C> {
                             for topic in response.topics.iter() {
                                 self.sync_topic(&home, topic).await?;
                             }
+                            self.metrics.mirroring().reconciled();
+                            self.metrics.mirroring().add_actions(response.topics.len());
 
                             info!("synced topics from home");
                             self.update_status(MirrorPairStatus::Succesful).await?;
@@ -2,16 +2,18 @@
 //! # Partition Controller
 //!
 
+use std::sync::Arc;
 use std::time::Duration;
 
 use fluvio_controlplane_metadata::store::ChangeListener;
 use fluvio_future::timer::sleep;
 use tracing::{debug, trace, info, error, instrument};
 
-use fluvio_future::task::spawn;
+use fluvio_future::task::{spawn, JoinHandle};
 use fluvio_controlplane_metadata::core::MetadataItem;
 use fluvio_controlplane_metadata::store::k8::K8MetaItem;
 
+use crate::core::metrics::ScMetrics;
 use crate::stores::StoreContext;
 use crate::stores::partition::PartitionSpec;
 use crate::stores::spu::SpuSpec;
@@ -24,20 +26,26 @@ pub struct PartitionController<C: MetadataItem = K8MetaItem> {
     partitions: StoreContext<PartitionSpec, C>,
     spus: StoreContext<SpuSpec, C>,
     reducer: PartitionReducer<C>,
+    metrics: Arc<ScMetrics>,
 }
 
 impl<C> PartitionController<C>
 where
     C: MetadataItem + 'static,
 {
-    pub fn start(partitions: StoreContext<PartitionSpec, C>, spus: StoreContext<SpuSpec, C>) {
+    pub fn start(
+        partitions: StoreContext<PartitionSpec, C>,
+        spus: StoreContext<SpuSpec, C>,
+        metrics: Arc<ScMetrics>,
+    ) -> JoinHandle<()> {
         let controller = Self {
             reducer: PartitionReducer::new(partitions.store().clone(), spus.store().clone()),
             partitions,
             spus,
+            metrics,
         };
 
-        spawn(controller.dispatch_loop());
+        spawn(controller.dispatch_loop())
     }
 }
 
@@ -72,6 +80,7 @@ where
         loop {
             self.sync_spu_changes(&mut spu_status_listener).await;
             self.sync_partition_changes(&mut partition_listener).await;
+            self.metrics.partition().reconciled();
 
             trace!("waiting for events");
 
@@ -110,6 +119,7 @@ where
         let actions = self.reducer.process_partition_update(updates).await;
 
         debug!("generated partition actions: {}", actions.len());
+        self.metrics.partition().add_actions(actions.len());
         for action in actions.into_iter() {
             self.partitions.send_action(action).await;
         }
@@ -144,6 +154,7 @@ where
             .await;
 
         debug!("there were election actions: {}", actions.len());
+        self.metrics.partition().add_actions(actions.len());
         for action in actions.into_iter() {
             self.partitions.send_action(action).await;
         }
@@ -2,6 +2,7 @@ mod controller;
 mod reducer;
 
 pub use self::controller::*;
+pub(crate) use self::reducer::PartitionReducer;
 pub use common::*;
 
 mod common {
@@ -6,14 +6,16 @@
 use std::sync::Arc;
 
 use fluvio_controlplane::PartitionMetadata;
-use tracing::{debug, info, instrument};
+use tracing::{debug, info, instrument, warn};
 
 use fluvio_controlplane_metadata::store::k8::K8MetaItem;
 use fluvio_controlplane_metadata::core::MetadataItem;
+use fluvio_controlplane_metadata::partition::ReplicaKey;
+use fluvio_types::SpuId;
 
 use crate::stores::partition::{
-    PartitionSpec, PartitionResolution, PartitionLocalStore, SimplePolicy, PartitonStatusExtension,
-    ElectionPolicy,
+    PartitionSpec, PartitionResolution, PartitionLocalStore, SimplePolicy, InSyncPolicy,
+    PartitonStatusExtension, ElectionPolicy,
 };
 use crate::stores::actions::WSAction;
 use crate::stores::spu::{SpuLocalStorePolicy, SpuLocalStore, SpuMetadata};
@@ -200,6 +202,87 @@ impl<C: MetadataItem> PartitionReducer<C> {
         }
     }
 
+    /// move leadership of partitions led by spu which is shutting down.
+    /// only in-sync followers are elected, other partitions stay with spu until it goes offline
+    #[instrument(skip(self))]
+    pub async fn handoff_election(&self, leaving_spu: SpuId) -> Vec<PartitionWSAction<C>> {
+        let mut actions = vec![];
+        let mut spu_status = self.spu_store.online_status().await;
+        spu_status.remove(&leaving_spu);
+
+        let policy = InSyncPolicy::new();
+
+        for partition_kv_epoch in self.partition_store.read().await.values() {
+            let partition_kv = partition_kv_epoch.inner();
+            if partition_kv.spec.leader != leaving_spu {
+                continue;
+            }
+            if let Some(candidate_leader) =
+                partition_kv.status.candidate_leader(&spu_status, &policy)
+            {
+                let mut part_kv_change = partition_kv.clone();
+                part_kv_change.spec.leader = candidate_leader;
+                actions.push(PartitionWSAction::UpdateSpec((
+                    part_kv_change.key_owned(),
+                    part_kv_change.spec,
+                )));
+                info!(
+                    partition = %partition_kv.key(),
+                    candidate_leader,
+                    "handing off leadership",
+                );
+            } else {
+                debug!(
+                    partition = %partition_kv.key(),
+                    "no in-sync follower to hand off leadership",
+                );
+            }
+        }
+        actions
+    }
+
+    /// move leadership of partition whose leader reported corrupted batch to in-sync follower.
+    /// old leader becomes follower, discards corrupted records and re-fetches them from new leader.
+    /// without in-sync follower, leadership stays and leader keeps fencing reads at corrupted offset,
+    /// since electing lagging follower would lose committed records
+    #[instrument(skip(self))]
+    pub async fn corruption_election(
+        &self,
+        replica: &ReplicaKey,
+        corrupted_leader: SpuId,
+    ) -> Option<PartitionWSAction<C>> {
+        let mut spu_status = self.spu_store.online_status().await;
+        spu_status.remove(&corrupted_leader);
+
+        let read_guard = self.partition_store.read().await;
+        let partition_kv = read_guard.get(replica)?.inner();
+        if partition_kv.spec.leader != corrupted_leader {
+            debug!("leadership already moved");
+            return None;
+        }
+        match partition_kv
+            .status
+            .candidate_leader(&spu_status, &InSyncPolicy::new())
+        {
+            Some(candidate_leader) => {
+                let mut part_kv_change = partition_kv.clone();
+                part_kv_change.spec.leader = candidate_leader;
+                info!(
+                    candidate_leader,
+                    "moving leadership away from corrupted leader",
+                );
+                Some(PartitionWSAction::UpdateSpec((
+                    part_kv_change.key_owned(),
+                    part_kv_change.spec,
+                )))
+            }
+            None => {
+                warn!("no in-sync follower, reads stay fenced at corrupted offset");
+                None
+            }
+        }
+    }
+
     /// perform election when spu become online
     #[instrument(skip(self, online_spu, actions))]
     async fn force_election_spu_on(
@@ -69,6 +69,8 @@ pub(crate) struct PartitionScheduler<'a, C: MetadataItem> {
     spus: &'a SpuLocalStore<C>,
     partitions: &'a PartitionLocalStore<C>,
     scheduling_groups: ReplicaSchedulingGroups,
+    max_replicas_per_spu: Option<u32>,
+    limit_reached: bool,
 }
 
 impl<'a, C> PartitionScheduler<'a, C>
@@ -84,9 +86,36 @@ where
             spus,
             partitions,
             scheduling_groups,
+            max_replicas_per_spu: None,
+            limit_reached: false,
         }
     }
 
+    /// don't schedule replicas to spus which already host `max` replicas
+    pub(crate) fn with_max_replicas_per_spu(mut self, max: Option<u32>) -> Self {
+        self.max_replicas_per_spu = max;
+        self
+    }
+
+    pub(crate) fn max_replicas_per_spu(&self) -> Option<u32> {
+        self.max_replicas_per_spu
+    }
+
+    /// true if last scheduling failed only because spus were at replica limit
+    pub(crate) fn limit_reached(&self) -> bool {
+        self.limit_reached
+    }
+
+    fn is_spu_full(&self, spu: SpuId) -> bool {
+        self.max_replicas_per_spu.is_some_and(|max| {
+            self.scheduling_groups
+                .get(&spu)
+                .map(|group| group.replicas())
+                .unwrap_or_default()
+                >= max
+        })
+    }
+
     pub(crate) fn spus(&self) -> &'a SpuLocalStore<C> {
         self.spus
     }
@@ -98,7 +127,7 @@ where
     /// Generate replica map for a specific topic
     #[instrument(level = "debug")]
     pub async fn generate_replica_map_for_topic(
-        &'a mut self,
+        &mut self,
         param: &TopicReplicaParam,
         actual_replica_map: Option<&ReplicaPartitionMap>,
     ) -> ReplicaPartitionMap {
@@ -125,6 +154,7 @@ where
         online_spus.sort_unstable();
 
         trace!(?online_spus, "online");
+        self.limit_reached = false;
         let mut partition_map = BTreeMap::new();
         for p_idx in 0..param.partitions {
             let mut reserved_spus: Vec<i32> = vec![]; // spu reserved
@@ -140,15 +170,21 @@ where
             }
 
             for r_idx in 0..param.replication_factor {
+                let weight = if r_idx == 0 {
+                    SpuWeightSelection::Leader
+                } else {
+                    SpuWeightSelection::Follower
+                };
+                let available_spus: Vec<SpuId> = online_spus
+                    .iter()
+                    .copied()
+                    .filter(|spu| !self.is_spu_full(*spu))
+                    .collect();
                 // for each replica, they must be on different spu, anti-affinity
                 if let Some(spu) = self.scheduling_groups.find_suitable_spu(
-                    &online_spus,
+                    &available_spus,
                     &reserved_spus,
-                    if r_idx == 0 {
-                        SpuWeightSelection::Leader
-                    } else {
-                        SpuWeightSelection::Follower
-                    },
+                    weight,
                 ) {
                     trace!(spu, "found spu");
                     reserved_spus.push(spu);
@@ -158,7 +194,11 @@ where
                         self.scheduling_groups.increase_followers(spu);
                     }
                 } else {
-                    trace!("no suitable spu found");
+                    self.limit_reached = self
+                        .scheduling_groups
+                        .find_suitable_spu(&online_spus, &reserved_spus, weight)
+                        .is_some();
+                    trace!(limit_reached = self.limit_reached, "no suitable spu found");
                     return BTreeMap::new().into();
                 }
             }
@@ -317,4 +357,41 @@ pub mod replica_map_test {
 
         assert_eq!(actual, expect);
     }
+
+    #[fluvio_future::test]
+    async fn generate_replica_max_replicas_per_spu() {
+        let spus = DefaultSpuStore::quick(vec![(0, true, None), (1, true, None)]);
+        let partitions =
+            DefaultPartitionStore::bulk_load(vec![(("t1", 0), vec![0]), (("t1", 1), vec![0])]);
+
+        let param = TopicReplicaParam {
+            partitions: 2,
+            replication_factor: 1,
+            ignore_rack_assignment: false,
+        };
+        let mut scheduler = PartitionScheduler::init(&spus, &partitions)
+            .await
+            .with_max_replicas_per_spu(Some(2));
+        // spu 0 is full
+        let expected: ReplicaPartitionMap = vec![(0, vec![1]), (1, vec![1])].into();
+        assert_eq!(
+            scheduler
+                .generate_partitions_without_rack(&param, None)
+                .await,
+            expected
+        );
+        assert!(!scheduler.limit_reached());
+
+        // both spus are full now
+        let param = TopicReplicaParam {
+            partitions: 1,
+            replication_factor: 1,
+            ignore_rack_assignment: false,
+        };
+        assert!(scheduler
+            .generate_partitions_without_rack(&param, None)
+            .await
+            .is_empty());
+        assert!(scheduler.limit_reached());
+    }
 }
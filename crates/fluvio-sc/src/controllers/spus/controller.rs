@@ -1,6 +1,7 @@
 //!
 //! # Spu Controller
 
+use std::sync::Arc;
 use std::time::Duration;
 use std::io::Error as IoError;
 
@@ -8,9 +9,10 @@ use fluvio_future::timer::sleep;
 use fluvio_stream_model::core::MetadataItem;
 use tracing::{debug, info, error, trace, instrument};
 
-use fluvio_future::task::spawn;
+use fluvio_future::task::{spawn, JoinHandle};
 
 use crate::core::SharedContext;
+use crate::core::metrics::ScMetrics;
 use crate::stores::StoreContext;
 use crate::stores::spu::*;
 
@@ -19,19 +21,21 @@ use crate::stores::spu::*;
 pub struct SpuController<C: MetadataItem> {
     spus: StoreContext<SpuSpec, C>,
     health_check: SharedHealthCheck,
+    metrics: Arc<ScMetrics>,
     counter: u64, // how many time we have been sync
 }
 
 impl<C: MetadataItem + 'static> SpuController<C> {
-    pub fn start(ctx: SharedContext<C>) {
+    pub fn start(ctx: SharedContext<C>) -> JoinHandle<()> {
         let controller = Self {
             spus: ctx.spus().clone(),
             health_check: ctx.health().clone(),
+            metrics: ctx.metrics().clone(),
             counter: 0,
         };
 
         info!("starting spu controller");
-        spawn(controller.dispatch_loop());
+        spawn(controller.dispatch_loop())
     }
 
     #[instrument(skip(self), name = "SpuControllerLoop")]
@@ -119,6 +123,9 @@ impl<C: MetadataItem + 'static> SpuController<C> {
 
         drop(health_read);
 
+        self.metrics.spu().reconciled();
+        self.metrics.spu().add_actions(changes.len());
+
         for updated_spu in changes.into_iter() {
             let key = updated_spu.key;
             let status = updated_spu.status;
@@ -5,6 +5,7 @@
 
 use std::cmp::min;
 use std::ops::Add;
+use std::sync::Arc;
 
 use fluvio_controlplane_metadata::spu::SpuSpec;
 use fluvio_controlplane_metadata::topic::CleanupPolicy;
@@ -17,10 +18,11 @@ use fluvio_stream_model::store::k8::K8MetaItem;
 use fluvio_types::defaults::{STORAGE_RETENTION_SECONDS, CONSUMER_STORAGE_TOPIC};
 use tracing::{info, instrument, trace, debug};
 
-use fluvio_future::task::spawn;
+use fluvio_future::task::{spawn, JoinHandle};
 
 use crate::core::SharedContext;
-use crate::stores::topic::TopicSpec;
+use crate::core::metrics::ScMetrics;
+use crate::stores::topic::{TopicSpec, TopicLimits};
 use crate::stores::partition::PartitionSpec;
 use crate::stores::StoreContext;
 
@@ -36,7 +38,9 @@ pub struct TopicController<C: MetadataItem = K8MetaItem> {
     spus: StoreContext<SpuSpec, C>,
     topics: StoreContext<TopicSpec, C>,
     partitions: StoreContext<PartitionSpec, C>,
+    limits: StoreContext<TopicLimits, C>,
     reducer: TopicReducer<C>,
+    metrics: Arc<ScMetrics>,
 }
 
 impl<C> TopicController<C>
@@ -45,7 +49,7 @@ where
     C::UId: Send + Sync,
 {
     /// streaming coordinator controller constructor
-    pub fn start(ctx: SharedContext<C>) {
+    pub fn start(ctx: SharedContext<C>) -> JoinHandle<()> {
         let topics = ctx.topics().clone();
         let partitions = ctx.partitions().clone();
         let spus = ctx.spus().clone();
@@ -55,13 +59,16 @@ where
                 topics.store().clone(),
                 ctx.spus().store().clone(),
                 partitions.store().clone(),
+                ctx.topic_limits().store().clone(),
             ),
             topics,
             partitions,
             spus,
+            limits: ctx.topic_limits().clone(),
+            metrics: ctx.metrics().clone(),
         };
 
-        spawn(controller.dispatch_loop());
+        spawn(controller.dispatch_loop())
     }
 }
 
@@ -77,10 +84,13 @@ impl<C: MetadataItem> TopicController<C> {
 
         let mut topics_listener = self.topics.change_listener();
         let mut spus_listener = self.spus.change_listener();
+        let mut limits_listener = self.limits.change_listener();
 
         loop {
             self.sync_topics(&mut topics_listener).await;
             self.sync_spus(&mut spus_listener).await;
+            self.sync_limits(&mut limits_listener).await;
+            self.metrics.topic().reconciled();
 
             select! {
 
@@ -94,6 +104,9 @@ impl<C: MetadataItem> TopicController<C> {
                 _ = spus_listener.listen() => {
                     debug!("detected spu changes");
                 }
+                _ = limits_listener.listen() => {
+                    debug!("detected limits changes");
+                }
             }
         }
     }
@@ -140,7 +153,27 @@ impl<C: MetadataItem> TopicController<C> {
             return;
         };
 
-        let actions = self.reducer.process_spu_update().await;
+        let actions = self.reducer.process_all_topics().await;
+
+        self.handle_actions(actions).await;
+    }
+
+    /// topics rejected by previous limits may be valid now
+    #[instrument(skip(self, listener))]
+    async fn sync_limits(&mut self, listener: &mut ChangeListener<TopicLimits, C>) {
+        if !listener.has_change() {
+            debug!("no change");
+            return;
+        }
+
+        let changes = listener.sync_changes().await;
+
+        if changes.is_empty() {
+            debug!("no limits changes");
+            return;
+        }
+
+        let actions = self.reducer.process_all_topics().await;
 
         self.handle_actions(actions).await;
     }
@@ -154,6 +187,9 @@ impl<C: MetadataItem> TopicController<C> {
                 actions.topics.len(),
                 actions.partitions.len()
             );
+            self.metrics
+                .topic()
+                .add_actions(actions.topics.len() + actions.partitions.len());
             for action in actions.topics.into_iter() {
                 self.topics.send_action(action).await;
             }
@@ -175,12 +211,12 @@ where
     C: MetadataItem + 'static,
     C::UId: Send + Sync,
 {
-    pub fn start(ctx: SharedContext<C>) {
+    pub fn start(ctx: SharedContext<C>) -> JoinHandle<()> {
         let topics = ctx.topics().clone();
 
         let controller = Self { topics };
 
-        spawn(controller.dispatch_loop());
+        spawn(controller.dispatch_loop())
     }
 
     #[instrument(name = "SystemTopicController", skip(self))]
@@ -222,6 +258,7 @@ where
             spec.set_storage(TopicStorageConfig {
                 segment_size: Some(OFFSET_TOPIC_SEGMENT_SIZE),
                 max_partition_size: Some(OFFSET_TOPIC_PARTITION_SIZE),
+                ..Default::default()
             });
             self.topics
                 .send_action(WSAction::UpdateSpec((
@@ -2,3 +2,4 @@ mod actions;
 mod reducer;
 pub(crate) mod controller;
 pub(crate) mod policy;
+pub(crate) mod rename;
@@ -4,7 +4,7 @@ use tracing::{debug, instrument};
 
 use fluvio_controlplane_metadata::partition::PartitionMirrorConfig;
 use fluvio_controlplane_metadata::partition::RemotePartitionConfig;
-use fluvio_types::PartitionId;
+use fluvio_types::{PartitionId, SpuId};
 
 use fluvio_controlplane::PartitionMetadata;
 use fluvio_controlplane_metadata::topic::MirrorConfig;
@@ -12,6 +12,7 @@ use fluvio_controlplane_metadata::topic::MirrorMap;
 use fluvio_controlplane_metadata::topic::ReplicaSpec;
 use fluvio_controlplane_metadata::topic::TopicReplicaParam;
 use fluvio_controlplane_metadata::topic::TopicResolution;
+use fluvio_controlplane_metadata::topic::TopicLimits;
 
 use fluvio_types::ReplicaMap;
 use fluvio_controlplane_metadata::topic::PartitionMaps;
@@ -20,6 +21,7 @@ use fluvio_stream_model::core::MetadataItem;
 
 use crate::controllers::scheduler::PartitionScheduler;
 use crate::controllers::scheduler::ReplicaPartitionMap;
+use crate::stores::partition::PartitionLocalStore;
 use crate::stores::spu::SpuLocalStore;
 use crate::stores::spu::SpuLocalStorePolicy;
 use crate::stores::topic::TopicMd;
@@ -102,6 +104,55 @@ pub(crate) async fn update_replica_map_for_assigned_topic<C: MetadataItem>(
     }
 }
 
+///
+/// Check that assigned replicas don't exceed replicas limit of spus
+///  * replicas of other topics are counted as well
+///
+pub(crate) async fn validate_assigned_spu_replicas<C: MetadataItem>(
+    topic: &str,
+    partition_maps: &PartitionMaps,
+    partition_store: &PartitionLocalStore<C>,
+    max: u32,
+) -> Option<String> {
+    let mut replicas: BTreeMap<SpuId, u32> = BTreeMap::new();
+    for partition in partition_store.read().await.values() {
+        if partition.key.topic == topic {
+            continue;
+        }
+        for spu in partition.spec.replicas.iter() {
+            *replicas.entry(*spu).or_default() += 1;
+        }
+    }
+    for map in partition_maps.maps() {
+        for spu in map.replicas.iter() {
+            *replicas.entry(*spu).or_default() += 1;
+        }
+    }
+
+    let assigned = partition_maps.unique_spus_in_partition_map();
+    replicas
+        .into_iter()
+        .find(|(spu, count)| assigned.contains(spu) && *count > max)
+        .map(|(spu, count)| {
+            format!("spu {spu} would host {count} partition replicas, at most {max} replicas per spu are allowed")
+        })
+}
+
+/// next state of topic which couldn't be scheduled
+fn insufficient_resources<C: MetadataItem>(scheduler: &PartitionScheduler<C>) -> TopicNextState<C> {
+    let reason = match scheduler.max_replicas_per_spu() {
+        Some(max) if scheduler.limit_reached() => {
+            format!("spus are at limit of {max} replicas per spu")
+        }
+        _ => String::new(),
+    };
+    TopicNextState {
+        resolution: TopicResolution::InsufficientResources,
+        reason,
+        ..Default::default()
+    }
+}
+
 /// values for next state
 #[derive(Default, Debug)]
 pub(crate) struct TopicNextState<C: MetadataItem> {
@@ -181,7 +232,19 @@ impl<C: MetadataItem> TopicNextState<C> {
     pub async fn compute_next_state<'a>(
         topic: &'a TopicMetadata<C>,
         scheduler: &'a mut PartitionScheduler<'a, C>,
+        limits: &TopicLimits,
     ) -> TopicNextState<C> {
+        // limits are checked only for new or invalid topics, existing topics are not changed
+        if matches!(
+            topic.status.resolution,
+            TopicResolution::Init | TopicResolution::InvalidConfig
+        ) {
+            if let Err(violation) = limits.check_topic(topic.spec()) {
+                debug!(topic = %topic.key(), %violation, "topic exceeds limits");
+                return TopicStatus::next_resolution_invalid_config(violation).into();
+            }
+        }
+
         match topic.spec().replicas() {
             // Computed Topic
             ReplicaSpec::Computed(ref param) => match topic.status.resolution {
@@ -206,10 +269,7 @@ impl<C: MetadataItem> TopicNextState<C> {
                             ..Default::default()
                         }
                     } else {
-                        TopicNextState {
-                            resolution: TopicResolution::InsufficientResources,
-                            ..Default::default()
-                        }
+                        insufficient_resources(scheduler)
                     }
                 }
                 _ => {
@@ -234,6 +294,18 @@ impl<C: MetadataItem> TopicNextState<C> {
                     validate_assigned_topic_parameters(partition_map)
                 }
                 TopicResolution::Pending | TopicResolution::InsufficientResources => {
+                    if let Some(max) = limits.max_partitions_per_spu {
+                        if let Some(violation) = validate_assigned_spu_replicas(
+                            topic.key(),
+                            partition_map,
+                            scheduler.partitions(),
+                            max,
+                        )
+                        .await
+                        {
+                            return TopicStatus::next_resolution_invalid_config(violation).into();
+                        }
+                    }
                     let mut next_state =
                         update_replica_map_for_assigned_topic(partition_map, scheduler.spus())
                             .await;
@@ -319,10 +391,7 @@ impl<C: MetadataItem> TopicNextState<C> {
                             ..Default::default()
                         }
                     } else {
-                        TopicNextState {
-                            resolution: TopicResolution::InsufficientResources,
-                            ..Default::default()
-                        }
+                        insufficient_resources(scheduler)
                     }
                 }
                 _ => {
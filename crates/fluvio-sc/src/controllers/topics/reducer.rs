@@ -10,6 +10,7 @@
 //!     Inconsistent, // use change spec parameters, which is not supported
 //!     InvalidConfig, // invalid configuration parameters provided
 //!
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use fluvio_stream_dispatcher::actions::WSAction;
@@ -43,6 +44,7 @@ pub struct TopicReducer<C: MetadataItem = K8MetaItem> {
     topic_store: Arc<TopicLocalStore<C>>,
     spu_store: Arc<SpuLocalStore<C>>,
     partition_store: Arc<PartitionLocalStore<C>>,
+    limits_store: Arc<TopicLimitsLocalStore<C>>,
 }
 
 impl<C: MetadataItem> TopicReducer<C> {
@@ -50,15 +52,16 @@ impl<C: MetadataItem> TopicReducer<C> {
         topic_store: impl Into<Arc<TopicLocalStore<C>>>,
         spu_store: impl Into<Arc<SpuLocalStore<C>>>,
         partition_store: impl Into<Arc<PartitionLocalStore<C>>>,
+        limits_store: impl Into<Arc<TopicLimitsLocalStore<C>>>,
     ) -> Self {
         Self {
             topic_store: topic_store.into(),
             spu_store: spu_store.into(),
             partition_store: partition_store.into(),
+            limits_store: limits_store.into(),
         }
     }
 
-    #[allow(unused)]
     fn topic_store(&self) -> &TopicLocalStore<C> {
         &self.topic_store
     }
@@ -76,19 +79,30 @@ impl<C: MetadataItem> TopicReducer<C> {
 
         let mut actions = TopicActions::default();
 
+        let rename_targets = rename_targets(
+            self.topic_store()
+                .read()
+                .await
+                .values()
+                .map(|topic| topic.inner()),
+        );
         for topic in topic_updates {
-            self.update_actions_next_state(&topic, &mut actions).await;
+            self.update_actions_next_state(&topic, &rename_targets, &mut actions)
+                .await;
         }
 
         actions
     }
 
-    pub async fn process_spu_update(&self) -> TopicActions<C> {
+    /// re-evaluate all topics, when spus or limits change
+    pub async fn process_all_topics(&self) -> TopicActions<C> {
         let mut actions = TopicActions::default();
 
         let topics = self.topic_store().read().await;
+        let rename_targets = rename_targets(topics.values().map(|topic| topic.inner()));
         for topic in topics.values() {
-            self.update_actions_next_state(topic, &mut actions).await;
+            self.update_actions_next_state(topic, &rename_targets, &mut actions)
+                .await;
         }
 
         actions
@@ -98,10 +112,11 @@ impl<C: MetadataItem> TopicReducer<C> {
     /// Compute next state for topic
     /// if state is different, apply actions
     ///
-    #[instrument(skip(self, actions))]
+    #[instrument(skip(self, rename_targets, actions))]
     async fn update_actions_next_state(
         &self,
         topic: &TopicMetadata<C>,
+        rename_targets: &HashSet<String>,
         actions: &mut TopicActions<C>,
     ) {
         // wait for partition store to be initially loaded
@@ -145,6 +160,13 @@ impl<C: MetadataItem> TopicReducer<C> {
             return;
         }
 
+        // replica map of renamed topic is set by rename controller
+        if topic.status.resolution == TopicResolution::Init && rename_targets.contains(topic.key())
+        {
+            debug!(topic = %topic.key(), "waiting for rename of topic");
+            return;
+        }
+
         if topic.status().is_resolution_provisioned()
             && topic.spec().replicas().partitions() > topic.status().replica_map.len() as u32
         {
@@ -161,9 +183,15 @@ impl<C: MetadataItem> TopicReducer<C> {
             return;
         }
 
-        let mut scheduler =
-            PartitionScheduler::init(self.spu_store(), self.partition_store()).await;
-        let next_state = TopicNextState::compute_next_state(topic, &mut scheduler).await;
+        let limits = self
+            .limits_store
+            .spec(TOPIC_LIMITS_NAME)
+            .await
+            .unwrap_or_default();
+        let mut scheduler = PartitionScheduler::init(self.spu_store(), self.partition_store())
+            .await
+            .with_max_replicas_per_spu(limits.max_partitions_per_spu);
+        let next_state = TopicNextState::compute_next_state(topic, &mut scheduler, &limits).await;
 
         debug!(topic = %topic.key(), ?next_state, "topic and next");
         let mut updated_topic = topic.clone();
@@ -197,11 +225,22 @@ impl<C: MetadataItem> TopicReducer<C> {
     }
 }
 
+/// names of topics which are being created by rename
+fn rename_targets<'a, C: MetadataItem + 'a>(
+    topics: impl Iterator<Item = &'a TopicMetadata<C>>,
+) -> HashSet<String> {
+    topics
+        .filter_map(|topic| topic.status.renaming_to.as_ref())
+        .map(|target| target.name.clone())
+        .collect()
+}
+
 #[cfg(test)]
 mod test2 {
 
-    use fluvio_controlplane_metadata::topic::{TopicResolution, TopicStatus};
+    use fluvio_controlplane_metadata::topic::{TopicRenameTarget, TopicResolution, TopicStatus};
     use fluvio_controlplane_metadata::topic::PENDING_REASON;
+    use fluvio_stream_model::store::MetadataStoreObject;
 
     use super::*;
 
@@ -215,6 +254,7 @@ mod test2 {
             TopicLocalStore::new_shared(),
             SpuLocalStore::new_shared(),
             partition_store.clone(),
+            TopicLimitsLocalStore::new_shared(),
         );
         let topic_requests = vec![
             TopicAdminMd::with_spec("topic1", (1, 1).into()),
@@ -237,4 +277,84 @@ mod test2 {
         ];
         assert_eq!(actions.topics, expected_actions);
     }
+
+    // topic created by rename is left to rename controller
+    #[fluvio_future::test]
+    async fn test_topic_reducer_init_rename_target() {
+        let topic_store = TopicLocalStore::new_shared();
+        let partition_store = PartitionLocalStore::new_shared();
+        let topic_reducer = TopicReducer::new(
+            topic_store.clone(),
+            SpuLocalStore::new_shared(),
+            partition_store.clone(),
+            TopicLimitsLocalStore::new_shared(),
+        );
+        let mut status = TopicStatus::new(TopicResolution::Provisioned, vec![vec![5001]], "");
+        status.renaming_to = Some(TopicRenameTarget {
+            name: "topic2".to_owned(),
+            aliases: vec![],
+        });
+        topic_store
+            .sync_all(vec![TopicAdminMd::new("topic1", (1, 1).into(), status)])
+            .await;
+        partition_store.sync_all(vec![]).await;
+
+        let topic_requests = vec![
+            TopicAdminMd::with_spec("topic2", (1, 1).into()),
+            TopicAdminMd::with_spec("topic3", (1, 1).into()),
+        ];
+        let actions = topic_reducer.process_requests(topic_requests).await;
+
+        let expected_actions: Vec<TopicWSAction> = vec![TopicWSAction::UpdateStatus((
+            "topic3".into(),
+            TopicStatus::new(TopicResolution::Pending, vec![], PENDING_REASON),
+        ))];
+        assert_eq!(actions.topics, expected_actions);
+    }
+
+    // topics exceeding limits are rejected, even if they are not created through public api
+    #[fluvio_future::test]
+    async fn test_topic_reducer_init_exceeds_limits() {
+        let partition_store = PartitionLocalStore::new_shared();
+        let limits_store = TopicLimitsLocalStore::new_shared();
+        let topic_reducer = TopicReducer::new(
+            TopicLocalStore::new_shared(),
+            SpuLocalStore::new_shared(),
+            partition_store.clone(),
+            limits_store.clone(),
+        );
+        let limits = TopicLimits {
+            max_partitions_per_topic: Some(1),
+            ..Default::default()
+        };
+        limits_store
+            .sync_all(vec![MetadataStoreObject::with_spec(
+                TOPIC_LIMITS_NAME,
+                limits,
+            )])
+            .await;
+        partition_store.sync_all(vec![]).await;
+
+        let topic_requests = vec![
+            TopicAdminMd::with_spec("topic1", (1, 1).into()),
+            TopicAdminMd::with_spec("topic2", (2, 1).into()),
+        ];
+        let actions = topic_reducer.process_requests(topic_requests).await;
+
+        let expected_actions: Vec<TopicWSAction> = vec![
+            TopicWSAction::UpdateStatus((
+                "topic1".into(),
+                TopicStatus::new(TopicResolution::Pending, vec![], PENDING_REASON),
+            )),
+            TopicWSAction::UpdateStatus((
+                "topic2".into(),
+                TopicStatus::new(
+                    TopicResolution::InvalidConfig,
+                    vec![],
+                    "topic has 2 partitions, at most 1 partitions per topic are allowed",
+                ),
+            )),
+        ];
+        assert_eq!(actions.topics, expected_actions);
+    }
 }
@@ -0,0 +1,306 @@
+//!
+//! # Topic Rename Controller
+//!
+//! Carry out rename of topics.
+//! Topic requested to be renamed has the new name in its status. Controller creates a topic
+//! with the new name and takes over replica assignment of the old topic, so partitions of the
+//! new topic are created as renamed. If the new name is taken meanwhile, the request is dropped.
+//! Renamed topic keeps the old name in its status until SPUs have moved all of its partitions.
+//! Then the old topic is deleted and rename marks are cleared.
+//! Marks are part of metadata, so rename interrupted by restart of SC is resumed.
+//!
+use std::io::Error as IoError;
+use std::time::Duration;
+
+use tracing::{debug, error, info, instrument};
+
+use fluvio_controlplane_metadata::partition::PartitionStatus;
+use fluvio_future::task::{spawn, JoinHandle};
+use fluvio_protocol::record::ReplicaKey;
+use fluvio_stream_model::core::MetadataItem;
+use fluvio_stream_model::store::k8::K8MetaItem;
+use fluvio_types::{PartitionId, ReplicaMap, SpuId};
+
+use crate::core::SharedContext;
+use crate::stores::topic::{TopicRenameTarget, TopicResolution, TopicSpec};
+use crate::stores::partition::PartitionSpec;
+use crate::stores::StoreContext;
+
+/// interval to check renames, in addition to metadata changes
+const RENAME_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+pub struct TopicRenameController<C: MetadataItem = K8MetaItem> {
+    topics: StoreContext<TopicSpec, C>,
+    partitions: StoreContext<PartitionSpec, C>,
+}
+
+impl<C> TopicRenameController<C>
+where
+    C: MetadataItem + 'static,
+    C::UId: Send + Sync,
+{
+    pub fn start(ctx: SharedContext<C>) -> JoinHandle<()> {
+        let controller = Self {
+            topics: ctx.topics().clone(),
+            partitions: ctx.partitions().clone(),
+        };
+
+        spawn(controller.dispatch_loop())
+    }
+}
+
+impl<C: MetadataItem> TopicRenameController<C> {
+    #[instrument(name = "TopicRenameController", skip(self))]
+    async fn dispatch_loop(self) {
+        use tokio::select;
+        use fluvio_future::timer::sleep;
+
+        debug!("starting rename dispatch loop");
+
+        let mut topics_listener = self.topics.change_listener();
+        let mut partitions_listener = self.partitions.change_listener();
+
+        loop {
+            topics_listener.load_last();
+            partitions_listener.load_last();
+
+            self.reconcile().await;
+
+            select! {
+                _ = sleep(RENAME_CHECK_INTERVAL) => {
+                    debug!("timer expired");
+                },
+                _ = topics_listener.listen() => {
+                    debug!("detected topic changes");
+                },
+                _ = partitions_listener.listen() => {
+                    debug!("detected partition changes");
+                }
+            }
+        }
+    }
+
+    async fn reconcile(&self) {
+        let requested: Vec<_> = self
+            .topics
+            .store()
+            .read()
+            .await
+            .values()
+            .filter(|topic| !topic.ctx().item().is_being_deleted())
+            .filter_map(|topic| {
+                topic
+                    .status
+                    .renaming_to
+                    .clone()
+                    .map(|target| (topic.key_owned(), target))
+            })
+            .collect();
+
+        for (old_name, target) in requested {
+            if let Err(err) = self.start_rename(&old_name, &target).await {
+                error!(%old_name, new_name = %target.name, %err, "unable to start topic rename");
+            }
+        }
+
+        let renamed: Vec<_> = self
+            .topics
+            .store()
+            .read()
+            .await
+            .values()
+            .filter_map(|topic| {
+                topic
+                    .status
+                    .renamed_from
+                    .clone()
+                    .map(|old_name| (topic.key_owned(), old_name))
+            })
+            .collect();
+
+        for (new_name, old_name) in renamed {
+            if let Err(err) = self.finish_rename(&new_name, &old_name).await {
+                error!(%old_name, %new_name, %err, "unable to finish topic rename");
+            }
+        }
+    }
+
+    /// create topic with the new name, then hand over replica assignment to it.
+    /// Each step is a single metadata update, which is repeated until its result is observed.
+    #[instrument(skip(self))]
+    async fn start_rename(
+        &self,
+        old_name: &str,
+        target: &TopicRenameTarget,
+    ) -> Result<(), IoError> {
+        let Some(topic) = self.topics.store().value(old_name).await else {
+            return Ok(());
+        };
+        let new_name = &target.name;
+
+        match self.topics.store().value(new_name).await {
+            None => {
+                let mut spec = topic.spec.clone();
+                spec.set_aliases(target.aliases.clone());
+                self.topics.create_spec(new_name.clone(), spec).await?;
+                debug!("new topic created");
+            }
+            Some(new_topic) if new_topic.status.renamed_from.as_deref() == Some(old_name) => {
+                debug!("rename already started");
+            }
+            Some(new_topic)
+                if new_topic.status.resolution == TopicResolution::Init
+                    && new_topic.status.renamed_from.is_none() =>
+            {
+                // keep current leaders, partitions are created with first replica as leader
+                let leaders: Vec<_> = self
+                    .partitions
+                    .store()
+                    .read()
+                    .await
+                    .values()
+                    .filter(|partition| partition.key.topic == old_name)
+                    .map(|partition| (partition.key.partition, partition.spec.leader))
+                    .collect();
+
+                let mut status = topic.status.clone();
+                status.replica_map = replica_map_with_leaders(status.replica_map, leaders);
+                status.renaming_to = None;
+                status.renamed_from = Some(old_name.to_owned());
+                self.topics.update_status(new_name.clone(), status).await?;
+                info!("topic rename started");
+            }
+            Some(_) => {
+                error!("topic with the new name already exists, dropping rename request");
+                let mut status = topic.status.clone();
+                status.renaming_to = None;
+                self.topics
+                    .update_status(old_name.to_owned(), status)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// delete old topic and clear marks once all partitions of the renamed topic are online
+    #[instrument(skip(self))]
+    async fn finish_rename(&self, new_name: &str, old_name: &str) -> Result<(), IoError> {
+        let Some(topic) = self.topics.store().value(new_name).await else {
+            return Ok(());
+        };
+        let partition_count = topic.status.replica_map.len();
+
+        let partitions: Vec<_> = self
+            .partitions
+            .store()
+            .read()
+            .await
+            .values()
+            .filter(|partition| partition.key.topic == new_name)
+            .map(|partition| partition.inner().clone())
+            .collect();
+
+        if !is_rename_complete(
+            partition_count,
+            partitions.iter().map(|partition| &partition.status),
+        ) {
+            debug!("partitions of renamed topic are not online yet");
+            return Ok(());
+        }
+
+        if self.topics.store().contains_key(old_name).await {
+            self.topics.delete(old_name.to_owned()).await?;
+            debug!("old topic deleted");
+        }
+
+        for partition in partitions {
+            if partition.spec.renamed_from.is_none() {
+                continue;
+            }
+            let mut spec = partition.spec;
+            spec.renamed_from = None;
+            self.partitions
+                .create_spec(ReplicaKey::new(new_name, partition.key.partition), spec)
+                .await?;
+        }
+
+        let mut status = topic.status.clone();
+        status.renamed_from = None;
+        self.topics
+            .update_status(new_name.to_owned(), status)
+            .await?;
+
+        info!("topic renamed");
+        Ok(())
+    }
+}
+
+/// rename is complete when every partition of the renamed topic is online
+fn is_rename_complete<'a>(
+    partition_count: usize,
+    statuses: impl Iterator<Item = &'a PartitionStatus>,
+) -> bool {
+    let mut online = 0;
+    for status in statuses {
+        if !status.is_online() {
+            return false;
+        }
+        online += 1;
+    }
+    online >= partition_count
+}
+
+/// move current leader of each partition to the front of its replicas
+fn replica_map_with_leaders(
+    mut replica_map: ReplicaMap,
+    leaders: impl IntoIterator<Item = (PartitionId, SpuId)>,
+) -> ReplicaMap {
+    for (partition, leader) in leaders {
+        let Some(replicas) = replica_map.get_mut(&partition) else {
+            continue;
+        };
+        if let Some(position) = replicas.iter().position(|replica| *replica == leader) {
+            replicas[..=position].rotate_right(1);
+        }
+    }
+    replica_map
+}
+
+#[cfg(test)]
+mod test {
+    use fluvio_controlplane_metadata::partition::PartitionResolution;
+
+    use super::*;
+
+    fn status(resolution: PartitionResolution) -> PartitionStatus {
+        PartitionStatus {
+            resolution,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_rename_complete() {
+        let online = status(PartitionResolution::Online);
+        let offline = status(PartitionResolution::Offline);
+
+        assert!(is_rename_complete(2, [&online, &online].into_iter()));
+        assert!(!is_rename_complete(2, [&online, &offline].into_iter()));
+        // partitions not created yet
+        assert!(!is_rename_complete(2, [&online].into_iter()));
+        assert!(!is_rename_complete(1, std::iter::empty()));
+    }
+
+    #[test]
+    fn test_replica_map_with_leaders() {
+        let replica_map: ReplicaMap =
+            [(0, vec![5001, 5002, 5003]), (1, vec![5002, 5003, 5001])].into();
+
+        let replica_map = replica_map_with_leaders(replica_map, [(0, 5003), (1, 5002), (2, 5001)]);
+
+        assert_eq!(replica_map[&0], vec![5003, 5001, 5002]);
+        assert_eq!(replica_map[&1], vec![5002, 5003, 5001]);
+        assert_eq!(replica_map.len(), 2);
+    }
+}
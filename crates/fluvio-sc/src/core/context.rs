@@ -6,9 +6,12 @@
 use std::sync::Arc;
 
 use fluvio_sc_schema::mirror::MirrorSpec;
+use fluvio_controlplane_metadata::topic::{TopicLimits, TOPIC_LIMITS_NAME};
 use fluvio_stream_model::core::MetadataItem;
+use fluvio_service::ServiceTasks;
 
 use crate::config::ScConfig;
+use crate::core::metrics::ScMetrics;
 use crate::stores::spu::*;
 use crate::stores::partition::*;
 use crate::stores::topic::*;
@@ -31,7 +34,10 @@ pub struct Context<C: MetadataItem> {
     smartmodules: StoreContext<SmartModuleSpec, C>,
     tableformats: StoreContext<TableFormatSpec, C>,
     mirrors: StoreContext<MirrorSpec, C>,
+    topic_limits: StoreContext<TopicLimits, C>,
     health: SharedHealthCheck,
+    metrics: Arc<ScMetrics>,
+    tasks: Arc<ServiceTasks>,
     config: ScConfig,
 }
 
@@ -54,7 +60,10 @@ impl<C: MetadataItem> Context<C> {
             smartmodules: StoreContext::new(),
             tableformats: StoreContext::new(),
             mirrors: StoreContext::new(),
+            topic_limits: StoreContext::new(),
             health: HealthCheck::shared(),
+            metrics: Arc::new(ScMetrics::default()),
+            tasks: Arc::new(ServiceTasks::default()),
             config,
         }
     }
@@ -90,11 +99,34 @@ impl<C: MetadataItem> Context<C> {
         &self.mirrors
     }
 
+    pub fn topic_limits(&self) -> &StoreContext<TopicLimits, C> {
+        &self.topic_limits
+    }
+
+    /// limits of topics in the cluster, unlimited if limits are not stored
+    pub async fn current_topic_limits(&self) -> TopicLimits {
+        self.topic_limits
+            .store()
+            .spec(TOPIC_LIMITS_NAME)
+            .await
+            .unwrap_or_default()
+    }
+
     /// spu health channel
     pub fn health(&self) -> &SharedHealthCheck {
         &self.health
     }
 
+    /// controller metrics
+    pub fn metrics(&self) -> &Arc<ScMetrics> {
+        &self.metrics
+    }
+
+    /// dispatchers and controllers, which are stopped with SC
+    pub fn tasks(&self) -> &Arc<ServiceTasks> {
+        &self.tasks
+    }
+
     /// reference to config
     pub fn config(&self) -> &ScConfig {
         &self.config
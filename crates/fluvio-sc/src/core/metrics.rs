@@ -0,0 +1,90 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Activity of SC controllers
+#[derive(Debug, Default)]
+pub struct ScMetrics {
+    spu: ControllerMetrics,
+    topic: ControllerMetrics,
+    partition: ControllerMetrics,
+    mirroring: ControllerMetrics,
+}
+
+impl ScMetrics {
+    pub fn spu(&self) -> &ControllerMetrics {
+        &self.spu
+    }
+
+    pub fn topic(&self) -> &ControllerMetrics {
+        &self.topic
+    }
+
+    pub fn partition(&self) -> &ControllerMetrics {
+        &self.partition
+    }
+
+    pub fn mirroring(&self) -> &ControllerMetrics {
+        &self.mirroring
+    }
+
+    /// metrics of all controllers by controller name
+    pub fn controllers(&self) -> [(&'static str, &ControllerMetrics); 4] {
+        [
+            ("spu", &self.spu),
+            ("topic", &self.topic),
+            ("partition", &self.partition),
+            ("mirroring", &self.mirroring),
+        ]
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ControllerMetrics {
+    reconciles: AtomicU64,
+    actions: AtomicU64,
+}
+
+impl ControllerMetrics {
+    /// record reconcile cycle
+    pub fn reconciled(&self) {
+        self.reconciles.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// record actions generated by reconciliation
+    pub fn add_actions(&self, actions: usize) {
+        self.actions.fetch_add(actions as u64, Ordering::SeqCst);
+    }
+
+    pub fn reconciles(&self) -> u64 {
+        self.reconciles.load(Ordering::SeqCst)
+    }
+
+    pub fn actions(&self) -> u64 {
+        self.actions.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_controller_metrics() {
+        let metrics = ScMetrics::default();
+        metrics.topic().reconciled();
+        metrics.topic().reconciled();
+        metrics.topic().add_actions(3);
+
+        assert_eq!(metrics.topic().reconciles(), 2);
+        assert_eq!(metrics.topic().actions(), 3);
+        assert_eq!(metrics.spu().reconciles(), 0);
+        assert_eq!(
+            metrics
+                .controllers()
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>(),
+            vec!["spu", "topic", "partition", "mirroring"]
+        );
+    }
+}
@@ -1,2 +1,3 @@
 mod context;
+pub mod metrics;
 pub use self::context::*;
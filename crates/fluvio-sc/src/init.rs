@@ -6,9 +6,16 @@
 //!
 use std::sync::Arc;
 
+use tracing::{error, info};
+
+use fluvio_auth::root::RootAuthorization;
+use fluvio_future::net::TcpListener;
+use fluvio_future::task::spawn;
 use fluvio_sc_schema::mirror::MirrorSpec;
+use fluvio_controlplane_metadata::topic::TOPIC_LIMITS_NAME;
 use fluvio_stream_dispatcher::metadata::{SharedClient, MetadataClient};
 use fluvio_stream_model::core::MetadataItem;
+use fluvio_types::event::StickyEvent;
 
 use crate::controllers::mirroring::controller::RemoteMirrorController;
 use crate::core::Context;
@@ -16,8 +23,10 @@ use crate::core::SharedContext;
 use crate::controllers::partitions::PartitionController;
 use crate::controllers::spus::SpuController;
 use crate::controllers::topics::controller::{TopicController, SystemTopicController};
+use crate::controllers::topics::rename::TopicRenameController;
 use crate::config::ScConfig;
-use crate::services::start_internal_server;
+use crate::services::{start_internal_server, start_metrics_server, start_public_server};
+use crate::services::auth::AuthGlobalContext;
 use crate::dispatcher::dispatcher::MetadataDispatcher;
 use crate::services::auth::basic::BasicRbacPolicy;
 
@@ -25,6 +34,64 @@ pub async fn start_main_loop<C, M>(
     sc_config_policy: (ScConfig, Option<BasicRbacPolicy>),
     metadata_client: SharedClient<C>,
 ) -> crate::core::SharedContext<M>
+where
+    C: MetadataClient<M> + 'static,
+    M: MetadataItem,
+    M::UId: Send + Sync,
+{
+    let (sc_config, auth_policy) = sc_config_policy;
+    let ctx = start_dispatchers(sc_config, metadata_client);
+    start_leader_services(ctx.clone());
+    start_public_services(ctx.clone(), auth_policy);
+    ctx
+}
+
+/// start main loop of SC embedded in other process.
+/// Servers run on listeners bound by caller with root authorization, returned events stop them
+pub async fn start_embedded_main_loop<C, M>(
+    sc_config: ScConfig,
+    metadata_client: SharedClient<C>,
+    public_listener: TcpListener,
+    private_listener: TcpListener,
+) -> (crate::core::SharedContext<M>, Vec<Arc<StickyEvent>>)
+where
+    C: MetadataClient<M> + 'static,
+    M: MetadataItem,
+    M::UId: Send + Sync,
+{
+    let ctx = start_dispatchers(sc_config, metadata_client);
+    start_controllers(ctx.clone());
+    let servers = vec![
+        start_internal_server(ctx.clone(), Some(private_listener)),
+        start_public_server(
+            AuthGlobalContext::new(ctx.clone(), Arc::new(RootAuthorization::new())),
+            Some(public_listener),
+        ),
+    ];
+    (ctx, servers)
+}
+
+/// start main loop of replicated SC, only services which don't modify metadata are started.
+/// Rest is started by `start_leader_services` once this SC becomes leader
+pub async fn start_replicated_main_loop<C, M>(
+    sc_config_policy: (ScConfig, Option<BasicRbacPolicy>),
+    metadata_client: SharedClient<C>,
+) -> crate::core::SharedContext<M>
+where
+    C: MetadataClient<M> + 'static,
+    M: MetadataItem,
+    M::UId: Send + Sync,
+{
+    let (sc_config, auth_policy) = sc_config_policy;
+    let ctx = start_dispatchers(sc_config, metadata_client);
+    start_public_services(ctx.clone(), auth_policy);
+    ctx
+}
+
+fn start_dispatchers<C, M>(
+    sc_config: ScConfig,
+    metadata_client: SharedClient<C>,
+) -> crate::core::SharedContext<M>
 where
     C: MetadataClient<M> + 'static,
     M: MetadataItem,
@@ -36,94 +103,164 @@ where
     use crate::stores::spg::SpuGroupSpec;
     use crate::stores::tableformat::TableFormatSpec;
     use crate::stores::smartmodule::SmartModuleSpec;
-
-    let (sc_config, auth_policy) = sc_config_policy;
+    use crate::stores::topic::TopicLimits;
 
     let namespace = sc_config.namespace.clone();
     let ctx = Context::shared_metadata(sc_config);
+    let tasks = ctx.tasks();
 
-    MetadataDispatcher::<SpuSpec, C, M>::start(
+    tasks.track(MetadataDispatcher::<SpuSpec, C, M>::start(
         namespace.clone(),
         metadata_client.clone(),
         ctx.spus().clone(),
-    );
+    ));
 
-    MetadataDispatcher::<TopicSpec, C, M>::start(
+    tasks.track(MetadataDispatcher::<TopicSpec, C, M>::start(
         namespace.clone(),
         metadata_client.clone(),
         ctx.topics().clone(),
-    );
+    ));
 
-    MetadataDispatcher::<PartitionSpec, C, M>::start(
+    tasks.track(MetadataDispatcher::<PartitionSpec, C, M>::start(
         namespace.clone(),
         metadata_client.clone(),
         ctx.partitions().clone(),
-    );
+    ));
 
-    MetadataDispatcher::<SpuGroupSpec, C, M>::start(
+    tasks.track(MetadataDispatcher::<SpuGroupSpec, C, M>::start(
         namespace.clone(),
         metadata_client.clone(),
         ctx.spgs().clone(),
-    );
+    ));
 
-    MetadataDispatcher::<TableFormatSpec, C, M>::start(
+    tasks.track(MetadataDispatcher::<TableFormatSpec, C, M>::start(
         namespace.clone(),
         metadata_client.clone(),
         ctx.tableformats().clone(),
-    );
+    ));
 
-    MetadataDispatcher::<SmartModuleSpec, C, M>::start(
+    tasks.track(MetadataDispatcher::<SmartModuleSpec, C, M>::start(
         namespace.clone(),
         metadata_client.clone(),
         ctx.smartmodules().clone(),
-    );
+    ));
 
-    MetadataDispatcher::<MirrorSpec, C, M>::start(
+    tasks.track(MetadataDispatcher::<MirrorSpec, C, M>::start(
         namespace.clone(),
         metadata_client.clone(),
         ctx.mirrors().clone(),
-    );
+    ));
 
-    start_main_loop_services(ctx, auth_policy).await
+    tasks.track(MetadataDispatcher::<TopicLimits, C, M>::start(
+        namespace.clone(),
+        metadata_client.clone(),
+        ctx.topic_limits().clone(),
+    ));
+
+    ctx
 }
 
-/// start the main loop
-async fn start_main_loop_services<C>(
-    ctx: Arc<Context<C>>,
-    auth_policy: Option<BasicRbacPolicy>,
-) -> SharedContext<C>
+/// start controllers and services which modify metadata
+pub fn start_leader_services<C>(ctx: SharedContext<C>)
+where
+    C: MetadataItem + 'static,
+    C::UId: Send + Sync,
+{
+    start_controllers(ctx.clone());
+    whitelist!(
+        ctx.config(),
+        "internal",
+        start_internal_server(ctx.clone(), None)
+    );
+}
+
+fn start_controllers<C>(ctx: SharedContext<C>)
 where
     C: MetadataItem + 'static,
     C::UId: Send + Sync,
 {
     let config = ctx.config();
 
-    whitelist!(config, "spu", SpuController::start(ctx.clone()));
-    whitelist!(config, "topic", TopicController::start(ctx.clone()));
-    whitelist!(config, "topic", SystemTopicController::start(ctx.clone()));
+    let tasks = ctx.tasks();
+
+    store_topic_limits(ctx.clone());
     whitelist!(
         config,
-        "partition",
-        PartitionController::start(ctx.partitions().clone(), ctx.spus().clone())
+        "spu",
+        tasks.track(SpuController::start(ctx.clone()))
     );
-
-    whitelist!(config, "internal", start_internal_server(ctx.clone()));
     whitelist!(
         config,
-        "public",
-        pub_server::start(ctx.clone(), auth_policy)
+        "topic",
+        tasks.track(TopicController::start(ctx.clone()))
+    );
+    whitelist!(
+        config,
+        "topic",
+        tasks.track(SystemTopicController::start(ctx.clone()))
+    );
+    whitelist!(
+        config,
+        "topic",
+        tasks.track(TopicRenameController::start(ctx.clone()))
+    );
+    whitelist!(
+        config,
+        "partition",
+        tasks.track(PartitionController::start(
+            ctx.partitions().clone(),
+            ctx.spus().clone(),
+            ctx.metrics().clone()
+        ))
     );
     whitelist!(
         config,
         "mirroring",
-        RemoteMirrorController::start(ctx.clone())
+        tasks.track(RemoteMirrorController::start(ctx.clone()))
+    );
+}
+
+/// store limits from config, they replace limits which were stored before
+fn store_topic_limits<C>(ctx: SharedContext<C>)
+where
+    C: MetadataItem + 'static,
+    C::UId: Send + Sync,
+{
+    let Some(limits) = ctx.config().topic_limits.clone() else {
+        return;
+    };
+    let topic_limits = ctx.topic_limits().clone();
+    ctx.tasks().track(spawn(async move {
+        info!(?limits, "storing topic limits");
+        if let Err(err) = topic_limits
+            .create_spec(TOPIC_LIMITS_NAME.to_owned(), limits)
+            .await
+        {
+            error!(%err, "failed to store topic limits");
+        }
+    }));
+}
+
+/// start services serving clients
+fn start_public_services<C>(ctx: Arc<Context<C>>, auth_policy: Option<BasicRbacPolicy>)
+where
+    C: MetadataItem + 'static,
+    C::UId: Send + Sync,
+{
+    let config = ctx.config();
+
+    whitelist!(
+        config,
+        "public",
+        pub_server::start(ctx.clone(), auth_policy)
     );
+    whitelist!(config, "metrics", start_metrics_server(ctx.clone()));
 
     mod pub_server {
 
         use std::sync::Arc;
         use fluvio_auth::root::RootAuthorization;
-        use tracing::info;
+        use tracing::{info, warn};
 
         use crate::services::start_public_server;
         use crate::core::SharedContext;
@@ -137,28 +274,32 @@ where
             C: MetadataItem + 'static,
             C::UId: Send + Sync,
         {
+            if auth_policy_option.is_none() && ctx.config().tenant_policy.is_some() {
+                warn!("tenant policy is ignored without authorization policy");
+            }
+
             if let Some(policy) = auth_policy_option {
                 info!("using basic authorization");
-                start_public_server(AuthGlobalContext::new(
-                    ctx,
-                    Arc::new(BasicAuthorization::new(policy)),
-                ));
+                let mut authorization = BasicAuthorization::new(policy);
+                if let Some(tenants) = ctx.config().tenant_policy.clone() {
+                    info!(tenants = tenants.0.len(), "using tenants");
+                    authorization = authorization.with_tenants(tenants);
+                }
+                start_public_server(AuthGlobalContext::new(ctx, Arc::new(authorization)), None);
             } else if ctx.config().read_only_metadata {
                 info!("using read-only authorization");
 
-                start_public_server(AuthGlobalContext::new(
-                    ctx,
-                    Arc::new(ReadOnlyAuthorization::new()),
-                ));
+                start_public_server(
+                    AuthGlobalContext::new(ctx, Arc::new(ReadOnlyAuthorization::new())),
+                    None,
+                );
             } else {
                 info!("using root authorization");
-                start_public_server(AuthGlobalContext::new(
-                    ctx,
-                    Arc::new(RootAuthorization::new()),
-                ));
+                start_public_server(
+                    AuthGlobalContext::new(ctx, Arc::new(RootAuthorization::new())),
+                    None,
+                );
             }
         }
     }
-
-    ctx
 }
@@ -4,6 +4,7 @@ pub mod k8;
 pub mod cli;
 pub mod core;
 pub mod start;
+pub mod raft;
 
 pub mod stores;
 mod init;
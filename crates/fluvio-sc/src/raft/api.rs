@@ -0,0 +1,173 @@
+//!
+//! # Raft API
+//!
+//! Messages exchanged between SC instances of a replicated cluster.
+//!
+use std::io::Error as IoError;
+use std::convert::TryInto;
+
+use fluvio_protocol::api::api_decode;
+use fluvio_protocol::api::ApiMessage;
+use fluvio_protocol::api::Request;
+use fluvio_protocol::api::RequestHeader;
+use fluvio_protocol::api::RequestMessage;
+use fluvio_protocol::bytes::Buf;
+use fluvio_protocol::derive::Encoder;
+use fluvio_protocol::derive::Decoder;
+
+pub type NodeId = u64;
+
+#[repr(u16)]
+#[derive(Eq, PartialEq, Debug, Encoder, Decoder, Clone, Copy)]
+#[fluvio(encode_discriminant)]
+#[derive(Default)]
+pub enum RaftApiKey {
+    #[default]
+    RequestVote = 4000,
+    AppendEntries = 4001,
+    Propose = 4002,
+    InstallSnapshot = 4003,
+}
+
+/// Request made from one SC to another
+#[derive(Debug, Encoder)]
+pub enum RaftRequest {
+    #[fluvio(tag = 0)]
+    RequestVoteRequest(RequestMessage<RequestVoteRequest>),
+    #[fluvio(tag = 1)]
+    AppendEntriesRequest(RequestMessage<AppendEntriesRequest>),
+    #[fluvio(tag = 2)]
+    ProposeRequest(RequestMessage<ProposeRequest>),
+    #[fluvio(tag = 3)]
+    InstallSnapshotRequest(RequestMessage<InstallSnapshotRequest>),
+}
+
+impl Default for RaftRequest {
+    fn default() -> Self {
+        Self::RequestVoteRequest(RequestMessage::default())
+    }
+}
+
+impl ApiMessage for RaftRequest {
+    type ApiKey = RaftApiKey;
+
+    fn decode_with_header<T>(src: &mut T, header: RequestHeader) -> Result<Self, IoError>
+    where
+        Self: Default + Sized,
+        Self::ApiKey: Sized,
+        T: Buf,
+    {
+        match header.api_key().try_into()? {
+            RaftApiKey::RequestVote => {
+                api_decode!(RaftRequest, RequestVoteRequest, src, header)
+            }
+            RaftApiKey::AppendEntries => {
+                api_decode!(RaftRequest, AppendEntriesRequest, src, header)
+            }
+            RaftApiKey::Propose => {
+                api_decode!(RaftRequest, ProposeRequest, src, header)
+            }
+            RaftApiKey::InstallSnapshot => {
+                api_decode!(RaftRequest, InstallSnapshotRequest, src, header)
+            }
+        }
+    }
+}
+
+/// Replicated command, empty command is a no-op appended by a new leader
+#[derive(Decoder, Encoder, Debug, Default, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    pub term: u64,
+    pub index: u64,
+    pub command: Vec<u8>,
+}
+
+/// State machine including all entries up to `index`, replaces the compacted log prefix
+#[derive(Decoder, Encoder, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub index: u64,
+    pub term: u64,
+    pub data: Vec<u8>,
+}
+
+/// Candidate asks for a vote
+#[derive(Decoder, Encoder, Debug, Default)]
+pub struct RequestVoteRequest {
+    pub term: u64,
+    pub candidate_id: NodeId,
+    pub last_log_index: u64,
+    pub last_log_term: u64,
+}
+
+impl Request for RequestVoteRequest {
+    const API_KEY: u16 = RaftApiKey::RequestVote as u16;
+    type Response = RequestVoteResponse;
+}
+
+#[derive(Decoder, Encoder, Debug, Default)]
+pub struct RequestVoteResponse {
+    pub term: u64,
+    pub vote_granted: bool,
+}
+
+/// Leader replicates entries, without entries it is a heartbeat
+#[derive(Decoder, Encoder, Debug, Default)]
+pub struct AppendEntriesRequest {
+    pub term: u64,
+    pub leader_id: NodeId,
+    pub prev_log_index: u64,
+    pub prev_log_term: u64,
+    pub entries: Vec<LogEntry>,
+    pub leader_commit: u64,
+}
+
+impl Request for AppendEntriesRequest {
+    const API_KEY: u16 = RaftApiKey::AppendEntries as u16;
+    type Response = AppendEntriesResponse;
+}
+
+#[derive(Decoder, Encoder, Debug, Default)]
+pub struct AppendEntriesResponse {
+    pub term: u64,
+    pub success: bool,
+    /// last index known to match the leader, used to rewind on rejection
+    pub last_log_index: u64,
+}
+
+/// Follower forwards a write to the leader
+#[derive(Decoder, Encoder, Debug, Default)]
+pub struct ProposeRequest {
+    pub command: Vec<u8>,
+}
+
+impl Request for ProposeRequest {
+    const API_KEY: u16 = RaftApiKey::Propose as u16;
+    type Response = ProposeResponse;
+}
+
+#[derive(Decoder, Encoder, Debug, Default)]
+pub struct ProposeResponse {
+    pub term: u64,
+    pub index: u64,
+    pub error: Option<String>,
+    /// leader known to the node which rejected the proposal
+    pub leader_id: Option<NodeId>,
+}
+
+/// Leader sends its snapshot to a follower which is behind the compacted log
+#[derive(Decoder, Encoder, Debug, Default)]
+pub struct InstallSnapshotRequest {
+    pub term: u64,
+    pub leader_id: NodeId,
+    pub snapshot: Snapshot,
+}
+
+impl Request for InstallSnapshotRequest {
+    const API_KEY: u16 = RaftApiKey::InstallSnapshot as u16;
+    type Response = InstallSnapshotResponse;
+}
+
+#[derive(Decoder, Encoder, Debug, Default)]
+pub struct InstallSnapshotResponse {
+    pub term: u64,
+}
@@ -0,0 +1,392 @@
+//!
+//! # Raft persistent state
+//!
+//! Log entries are appended to a single file. Once enough entries are applied, the prefix
+//! of the log is replaced by a snapshot of the state machine and the file is rewritten.
+//! Files are written on blocking threads so that the executor keeps running.
+//!
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Serialize, Deserialize};
+use tracing::{info, warn};
+
+use fluvio_future::task::spawn_blocking;
+use fluvio_protocol::{Encoder, Decoder};
+
+use super::api::{LogEntry, NodeId, Snapshot};
+
+const LOG_FILE: &str = "raft.log";
+const STATE_FILE: &str = "state.json";
+const SNAPSHOT_FILE: &str = "snapshot.bin";
+
+/// State which must survive restart
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HardState {
+    pub current_term: u64,
+    pub voted_for: Option<NodeId>,
+    /// last entry applied to metadata storage
+    pub last_applied: u64,
+}
+
+impl HardState {
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(STATE_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read(&path)?;
+        serde_json::from_slice(&content)
+            .with_context(|| format!("loading raft state from {}", path.display()))
+    }
+
+    pub async fn flush(&self, dir: &Path) -> Result<()> {
+        let content = serde_json::to_vec(self)?;
+        let dir = dir.to_path_buf();
+        spawn_blocking(move || write_atomic(&dir.join(STATE_FILE), &content)).await
+    }
+}
+
+/// Log entries after the snapshot, first entry of a new log has index 1
+#[derive(Debug)]
+pub struct RaftLog {
+    dir: PathBuf,
+    /// last entry included in snapshot
+    snapshot_index: u64,
+    snapshot_term: u64,
+    entries: Vec<LogEntry>,
+}
+
+impl RaftLog {
+    pub fn load(dir: &Path) -> Result<Self> {
+        fs::create_dir_all(dir)?;
+        let (snapshot_index, snapshot_term) = match read_snapshot(dir)? {
+            Some(snapshot) => (snapshot.index, snapshot.term),
+            None => (0, 0),
+        };
+
+        let path = dir.join(LOG_FILE);
+        let mut entries: Vec<LogEntry> = vec![];
+        let mut dirty = false;
+        if path.exists() {
+            let content = fs::read(&path)?;
+            let mut src = content.as_slice();
+            while !src.is_empty() {
+                match LogEntry::decode_from(&mut src, 0) {
+                    // compacted before the log was rewritten
+                    Ok(entry) if entry.index <= snapshot_index => dirty = true,
+                    Ok(entry) if entry.index == snapshot_index + entries.len() as u64 + 1 => {
+                        entries.push(entry)
+                    }
+                    _ => {
+                        // partially written entry from a crash, it was never acknowledged
+                        warn!(path = %path.display(), "ignoring corrupted tail of raft log");
+                        dirty = true;
+                        break;
+                    }
+                }
+            }
+        }
+        let log = Self {
+            dir: dir.to_path_buf(),
+            snapshot_index,
+            snapshot_term,
+            entries,
+        };
+        if dirty {
+            write_atomic(&log.log_path(), &log.encode_entries()?)?;
+        }
+        Ok(log)
+    }
+
+    pub fn last_index(&self) -> u64 {
+        self.snapshot_index + self.entries.len() as u64
+    }
+
+    pub fn last_term(&self) -> u64 {
+        self.entries
+            .last()
+            .map(|entry| entry.term)
+            .unwrap_or(self.snapshot_term)
+    }
+
+    pub fn snapshot_index(&self) -> u64 {
+        self.snapshot_index
+    }
+
+    /// term of entry at index, 0 for the empty prefix
+    pub fn term_at(&self, index: u64) -> Option<u64> {
+        if index == self.snapshot_index {
+            Some(self.snapshot_term)
+        } else {
+            self.get(index).map(|entry| entry.term)
+        }
+    }
+
+    /// entry at index, None if it is compacted or not in the log
+    pub fn get(&self, index: u64) -> Option<&LogEntry> {
+        index
+            .checked_sub(self.snapshot_index + 1)
+            .and_then(|offset| self.entries.get(offset as usize))
+    }
+
+    /// up to `max` entries starting at index, which must be after the snapshot
+    pub fn entries_from(&self, index: u64, max: usize) -> Vec<LogEntry> {
+        let start = index.saturating_sub(self.snapshot_index + 1) as usize;
+        self.entries.iter().skip(start).take(max).cloned().collect()
+    }
+
+    /// append new entry with next index
+    pub async fn append(&mut self, term: u64, command: Vec<u8>) -> Result<u64> {
+        let entry = LogEntry {
+            term,
+            index: self.last_index() + 1,
+            command,
+        };
+        self.write(std::slice::from_ref(&entry)).await?;
+        self.entries.push(entry);
+        Ok(self.last_index())
+    }
+
+    /// store entries from leader, conflicting entries are removed
+    pub async fn merge(&mut self, entries: Vec<LogEntry>) -> Result<()> {
+        let mut new_entries = vec![];
+        for entry in entries {
+            if entry.index <= self.snapshot_index {
+                // committed entries are part of snapshot
+                continue;
+            }
+            match self.term_at(entry.index) {
+                Some(term) if term == entry.term => continue,
+                Some(_) => self.truncate(entry.index).await?,
+                None => {}
+            }
+            new_entries.push(entry);
+        }
+        if !new_entries.is_empty() {
+            self.write(&new_entries).await?;
+            self.entries.extend(new_entries);
+        }
+        Ok(())
+    }
+
+    /// replace applied entries up to index with snapshot of state machine
+    pub async fn compact(&mut self, index: u64, data: Vec<u8>) -> Result<()> {
+        let Some(term) = self.term_at(index) else {
+            return Ok(());
+        };
+        if index <= self.snapshot_index {
+            return Ok(());
+        }
+        let snapshot = Snapshot { index, term, data };
+        self.write_snapshot(&snapshot).await?;
+        self.entries.drain(..(index - self.snapshot_index) as usize);
+        self.snapshot_index = index;
+        self.snapshot_term = term;
+        self.rewrite().await?;
+        info!(index, term, "compacted raft log");
+        Ok(())
+    }
+
+    /// store snapshot from leader, entries after snapshot are kept if they match it
+    pub async fn install_snapshot(&mut self, snapshot: &Snapshot) -> Result<()> {
+        self.write_snapshot(snapshot).await?;
+        if self.term_at(snapshot.index) == Some(snapshot.term) {
+            let compacted = snapshot.index.saturating_sub(self.snapshot_index) as usize;
+            self.entries.drain(..compacted.min(self.entries.len()));
+        } else {
+            self.entries.clear();
+        }
+        self.snapshot_index = snapshot.index;
+        self.snapshot_term = snapshot.term;
+        self.rewrite().await
+    }
+
+    /// snapshot which replaced the log prefix
+    pub async fn snapshot(&self) -> Result<Option<Snapshot>> {
+        let dir = self.dir.clone();
+        spawn_blocking(move || read_snapshot(&dir)).await
+    }
+
+    /// remove entries starting at index
+    async fn truncate(&mut self, index: u64) -> Result<()> {
+        let retained = index.saturating_sub(self.snapshot_index + 1) as usize;
+        self.entries.truncate(retained);
+        self.rewrite().await
+    }
+
+    async fn write(&self, entries: &[LogEntry]) -> Result<()> {
+        let mut buf = vec![];
+        for entry in entries {
+            entry.encode(&mut buf, 0)?;
+        }
+        let path = self.log_path();
+        spawn_blocking(move || {
+            let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+            file.write_all(&buf)?;
+            file.sync_data()?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn rewrite(&self) -> Result<()> {
+        let buf = self.encode_entries()?;
+        let path = self.log_path();
+        spawn_blocking(move || write_atomic(&path, &buf)).await
+    }
+
+    async fn write_snapshot(&self, snapshot: &Snapshot) -> Result<()> {
+        let mut buf = vec![];
+        snapshot.encode(&mut buf, 0)?;
+        let path = self.dir.join(SNAPSHOT_FILE);
+        spawn_blocking(move || write_atomic(&path, &buf)).await
+    }
+
+    fn encode_entries(&self) -> Result<Vec<u8>> {
+        let mut buf = vec![];
+        for entry in &self.entries {
+            entry.encode(&mut buf, 0)?;
+        }
+        Ok(buf)
+    }
+
+    fn log_path(&self) -> PathBuf {
+        self.dir.join(LOG_FILE)
+    }
+}
+
+fn read_snapshot(dir: &Path) -> Result<Option<Snapshot>> {
+    let path = dir.join(SNAPSHOT_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read(&path)?;
+    let snapshot = Snapshot::decode_from(&mut content.as_slice(), 0)
+        .with_context(|| format!("loading raft snapshot from {}", path.display()))?;
+    Ok(Some(snapshot))
+}
+
+/// replace file content, either old or new content is seen after crash
+fn write_atomic(path: &Path, content: &[u8]) -> Result<()> {
+    let tmp = path.with_extension("tmp");
+    let mut file = File::create(&tmp)?;
+    file.write_all(content)?;
+    file.sync_all()?;
+    fs::rename(tmp, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn entry(term: u64, index: u64) -> LogEntry {
+        LogEntry {
+            term,
+            index,
+            command: format!("{term}-{index}").into_bytes(),
+        }
+    }
+
+    #[fluvio_future::test]
+    async fn test_log_append_and_reload() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let dir = temp_dir.path().to_path_buf();
+
+        let mut log = RaftLog::load(&dir).expect("load");
+        assert_eq!(log.last_index(), 0);
+        assert_eq!(log.term_at(0), Some(0));
+        assert_eq!(log.append(1, b"a".to_vec()).await.expect("append"), 1);
+        assert_eq!(log.append(2, b"b".to_vec()).await.expect("append"), 2);
+
+        let log = RaftLog::load(&dir).expect("reload");
+        assert_eq!(log.last_index(), 2);
+        assert_eq!(log.last_term(), 2);
+        assert_eq!(log.get(1).expect("entry").command, b"a".to_vec());
+        assert_eq!(log.entries_from(2, 10).len(), 1);
+        assert!(log.term_at(3).is_none());
+    }
+
+    #[fluvio_future::test]
+    async fn test_log_merge_truncates_conflicts() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let dir = temp_dir.path().to_path_buf();
+
+        let mut log = RaftLog::load(&dir).expect("load");
+        log.merge(vec![entry(1, 1), entry(1, 2), entry(1, 3)])
+            .await
+            .expect("merge");
+        // entries already in the log are kept, conflicting suffix is replaced
+        log.merge(vec![entry(1, 2), entry(2, 3)])
+            .await
+            .expect("merge");
+        assert_eq!(log.last_index(), 3);
+        assert_eq!(log.term_at(3), Some(2));
+
+        let log = RaftLog::load(&dir).expect("reload");
+        assert_eq!(
+            log.entries_from(1, 10),
+            vec![entry(1, 1), entry(1, 2), entry(2, 3)]
+        );
+    }
+
+    #[fluvio_future::test]
+    async fn test_log_compaction() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let dir = temp_dir.path().to_path_buf();
+
+        let mut log = RaftLog::load(&dir).expect("load");
+        log.merge(vec![entry(1, 1), entry(1, 2), entry(2, 3)])
+            .await
+            .expect("merge");
+        log.compact(2, b"state".to_vec()).await.expect("compact");
+        assert_eq!(log.snapshot_index(), 2);
+        assert_eq!(log.last_index(), 3);
+        assert_eq!(log.term_at(2), Some(1));
+        assert!(log.get(1).is_none());
+        assert_eq!(log.entries_from(3, 10), vec![entry(2, 3)]);
+
+        let mut log = RaftLog::load(&dir).expect("reload");
+        assert_eq!(log.snapshot_index(), 2);
+        assert_eq!(log.entries_from(3, 10), vec![entry(2, 3)]);
+        assert_eq!(log.append(2, b"d".to_vec()).await.expect("append"), 4);
+        let snapshot = log.snapshot().await.expect("read").expect("snapshot");
+        assert_eq!((snapshot.index, snapshot.term), (2, 1));
+        assert_eq!(snapshot.data, b"state".to_vec());
+
+        // snapshot from leader which doesn't match the log replaces it
+        log.install_snapshot(&Snapshot {
+            index: 5,
+            term: 3,
+            data: b"leader".to_vec(),
+        })
+        .await
+        .expect("install");
+        assert_eq!(log.last_index(), 5);
+        assert_eq!(log.last_term(), 3);
+        let log = RaftLog::load(&dir).expect("reload");
+        assert_eq!(log.last_index(), 5);
+        assert!(log.entries_from(6, 10).is_empty());
+    }
+
+    #[fluvio_future::test]
+    async fn test_hard_state_flush() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let dir = temp_dir.path().to_path_buf();
+
+        assert_eq!(HardState::load(&dir).expect("load"), HardState::default());
+        let state = HardState {
+            current_term: 3,
+            voted_for: Some(2),
+            last_applied: 7,
+        };
+        state.flush(&dir).await.expect("flush");
+        assert_eq!(HardState::load(&dir).expect("load"), state);
+    }
+}
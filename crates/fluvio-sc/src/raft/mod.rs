@@ -0,0 +1,70 @@
+//!
+//! # Replicated SC
+//!
+//! Several SC instances running in local mode replicate metadata with raft.
+//! Only the leader runs controllers and accepts SPU connections. Every instance serves
+//! the public API, writes received by followers are forwarded to the leader.
+//! A leader which loses leadership exits, to be restarted by its supervisor as follower.
+//!
+//! The raft log is kept on disk next to metadata. Applied entries are compacted into a
+//! snapshot of metadata, which is sent to followers that are behind the compacted log.
+//!
+mod api;
+mod log;
+mod node;
+mod server;
+mod storage;
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use rand::Rng;
+
+pub use api::NodeId;
+pub use node::{RaftNode, Role};
+pub use server::start_raft_server;
+pub use storage::{MetadataStateMachine, ReplicatedMetadataStorage};
+
+const DEFAULT_HEARTBEAT_INTERVAL_MS: u64 = 200;
+const DEFAULT_ELECTION_TIMEOUT_MS: u64 = 1500;
+const DEFAULT_RPC_TIMEOUT_MS: u64 = 1000;
+const DEFAULT_MAX_LOG_ENTRIES: u64 = 1000;
+
+#[derive(Debug, Clone)]
+pub struct RaftConfig {
+    pub id: NodeId,
+    /// address for requests from other SC instances
+    pub bind: String,
+    /// raft address of other SC instances
+    pub peers: BTreeMap<NodeId, String>,
+    /// directory of raft log and state
+    pub path: PathBuf,
+    pub heartbeat_interval: Duration,
+    /// minimum time without leader before starting election
+    pub election_timeout: Duration,
+    pub rpc_timeout: Duration,
+    /// applied entries kept in the log before it is compacted
+    pub max_log_entries: u64,
+}
+
+impl RaftConfig {
+    pub fn new(id: NodeId, bind: String, peers: BTreeMap<NodeId, String>, path: PathBuf) -> Self {
+        Self {
+            id,
+            bind,
+            peers,
+            path,
+            heartbeat_interval: Duration::from_millis(DEFAULT_HEARTBEAT_INTERVAL_MS),
+            election_timeout: Duration::from_millis(DEFAULT_ELECTION_TIMEOUT_MS),
+            rpc_timeout: Duration::from_millis(DEFAULT_RPC_TIMEOUT_MS),
+            max_log_entries: DEFAULT_MAX_LOG_ENTRIES,
+        }
+    }
+
+    /// randomized so that nodes rarely start election at the same time
+    fn random_election_timeout(&self) -> Duration {
+        let timeout = self.election_timeout.as_millis() as u64;
+        Duration::from_millis(rand::thread_rng().gen_range(timeout..timeout * 2))
+    }
+}
@@ -0,0 +1,1156 @@
+//!
+//! # Raft node
+//!
+//! Leader election and log replication among SC instances.
+//! Committed entries are applied in order to the state machine on every node.
+//!
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use async_lock::Mutex;
+use async_trait::async_trait;
+use tracing::{debug, error, info, instrument, warn};
+
+use fluvio_future::task::spawn;
+use fluvio_future::timer::sleep;
+use fluvio_protocol::api::{Request, RequestMessage};
+use fluvio_socket::FluvioSocket;
+use fluvio_types::event::StickyEvent;
+use fluvio_types::event::offsets::{OffsetPublisher, SharedOffsetPublisher};
+
+use super::RaftConfig;
+use super::api::{
+    AppendEntriesRequest, AppendEntriesResponse, InstallSnapshotRequest, InstallSnapshotResponse,
+    NodeId, ProposeRequest, ProposeResponse, RequestVoteRequest, RequestVoteResponse,
+};
+use super::log::{HardState, RaftLog};
+
+const MAX_ENTRIES_PER_APPEND: usize = 64;
+const MAX_RETAINED_RESULTS: usize = 1000;
+const PROPOSE_TIMEOUT: Duration = Duration::from_secs(10);
+const PROPOSE_RETRY: Duration = Duration::from_millis(100);
+
+/// Deterministic consumer of committed commands
+#[async_trait]
+pub trait StateMachine: Send + Sync {
+    async fn apply(&self, command: &[u8]) -> Result<()>;
+
+    /// apply command which may have been applied before the node stopped,
+    /// state must be the same as if it was applied once
+    async fn reapply(&self, command: &[u8]) -> Result<()>;
+
+    /// serialized state, including all applied commands
+    async fn snapshot(&self) -> Result<Vec<u8>>;
+
+    /// replace state with snapshot
+    async fn restore(&self, snapshot: &[u8]) -> Result<()>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Follower,
+    Candidate,
+    Leader,
+}
+
+struct RaftState {
+    role: Role,
+    hard: HardState,
+    log: RaftLog,
+    commit_index: u64,
+    leader_id: Option<NodeId>,
+    election_deadline: Instant,
+    votes: HashSet<NodeId>,
+    next_index: HashMap<NodeId, u64>,
+    match_index: HashMap<NodeId, u64>,
+    /// index of the no-op appended by this node when elected
+    leader_start: u64,
+}
+
+/// Request replicating log to a follower
+enum Replication {
+    Entries(AppendEntriesRequest),
+    Snapshot(InstallSnapshotRequest),
+}
+
+struct Peer {
+    addr: String,
+    socket: Mutex<Option<FluvioSocket>>,
+}
+
+impl Peer {
+    fn new(addr: String) -> Self {
+        Self {
+            addr,
+            socket: Mutex::new(None),
+        }
+    }
+
+    async fn send<R: Request>(&self, request: R, timeout: Duration) -> Result<R::Response> {
+        use tokio::select;
+
+        let request = RequestMessage::new_request(request);
+        let send = async {
+            let mut socket = self.socket.lock().await;
+            let mut connected = match socket.take() {
+                Some(connected) => connected,
+                None => FluvioSocket::connect(&self.addr).await?,
+            };
+            // connection is reused only if request succeeded
+            let response = connected.send(&request).await?;
+            *socket = Some(connected);
+            Ok::<_, anyhow::Error>(response.response)
+        };
+
+        select! {
+            result = send => result,
+            _ = sleep(timeout) => {
+                // response may still arrive later, start over with new connection
+                *self.socket.lock().await = None;
+                Err(anyhow!("request to {} timed out", self.addr))
+            }
+        }
+    }
+}
+
+pub struct RaftNode {
+    config: RaftConfig,
+    state: Mutex<RaftState>,
+    state_machine: Arc<dyn StateMachine>,
+    /// held while state machine is changed, taken before `state`
+    applying: Mutex<()>,
+    peers: HashMap<NodeId, Peer>,
+    /// last log index, wakes up replication
+    log_index: SharedOffsetPublisher,
+    commit_index: SharedOffsetPublisher,
+    applied_index: SharedOffsetPublisher,
+    /// term while this node is leader and has applied its log, 0 otherwise
+    leader_term: SharedOffsetPublisher,
+    /// apply results by index, with the term of applied entry
+    results: Mutex<BTreeMap<u64, (u64, Result<(), String>)>>,
+    shutdown: Arc<StickyEvent>,
+}
+
+impl RaftNode {
+    /// load persistent state and start election, replication and apply loops
+    pub fn start(config: RaftConfig, state_machine: Arc<dyn StateMachine>) -> Result<Arc<Self>> {
+        let hard = HardState::load(&config.path)?;
+        let log = RaftLog::load(&config.path)?;
+        info!(
+            id = config.id,
+            term = hard.current_term,
+            snapshot_index = log.snapshot_index(),
+            last_index = log.last_index(),
+            last_applied = hard.last_applied,
+            "starting raft node"
+        );
+
+        let peers = config
+            .peers
+            .iter()
+            .map(|(id, addr)| (*id, Peer::new(addr.clone())))
+            .collect();
+        // entries in snapshot were committed
+        let commit_index = log.snapshot_index();
+        let node = Arc::new(Self {
+            log_index: OffsetPublisher::shared(log.last_index() as i64),
+            commit_index: OffsetPublisher::shared(commit_index as i64),
+            applied_index: OffsetPublisher::shared(hard.last_applied as i64),
+            leader_term: OffsetPublisher::shared(0),
+            results: Mutex::new(BTreeMap::new()),
+            state: Mutex::new(RaftState {
+                role: Role::Follower,
+                hard,
+                log,
+                commit_index,
+                leader_id: None,
+                election_deadline: Instant::now() + config.random_election_timeout(),
+                votes: HashSet::new(),
+                next_index: HashMap::new(),
+                match_index: HashMap::new(),
+                leader_start: 0,
+            }),
+            config,
+            state_machine,
+            applying: Mutex::new(()),
+            peers,
+            shutdown: StickyEvent::shared(),
+        });
+
+        spawn(node.clone().election_loop());
+        spawn(node.clone().apply_loop());
+        for peer in node.peers.keys() {
+            spawn(node.clone().replicate_loop(*peer));
+        }
+
+        Ok(node)
+    }
+
+    pub fn config(&self) -> &RaftConfig {
+        &self.config
+    }
+
+    pub fn id(&self) -> NodeId {
+        self.config.id
+    }
+
+    pub async fn role(&self) -> Role {
+        self.state.lock().await.role
+    }
+
+    pub async fn leader_id(&self) -> Option<NodeId> {
+        self.state.lock().await.leader_id
+    }
+
+    /// stop election, replication and apply loops, node no longer answers peers
+    pub fn stop(&self) {
+        info!(id = self.id(), "stopping raft node");
+        self.shutdown.notify();
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.shutdown.is_set()
+    }
+
+    /// wait until this node is leader and has applied all entries of previous leaders,
+    /// returns term of leadership
+    pub async fn wait_for_leadership(&self) -> u64 {
+        let mut listener = self.leader_term.change_listener();
+        loop {
+            let term = self.leader_term.current_value();
+            if term > 0 {
+                return term as u64;
+            }
+            listener.listen().await;
+        }
+    }
+
+    /// wait until this node is no longer leader of term
+    pub async fn wait_for_leadership_loss(&self, term: u64) {
+        let mut listener = self.leader_term.change_listener();
+        while self.leader_term.current_value() == term as i64 {
+            listener.listen().await;
+        }
+    }
+
+    /// replicate command through leader, return once it has been applied to local state machine
+    pub async fn propose(&self, command: Vec<u8>) -> Result<()> {
+        let deadline = Instant::now() + PROPOSE_TIMEOUT;
+        let (term, index) = loop {
+            let response = self.propose_local(command.clone()).await?;
+            if response.error.is_none() {
+                break (response.term, response.index);
+            }
+            if let Some(leader) = response.leader_id.and_then(|id| self.peers.get(&id)) {
+                let request = ProposeRequest {
+                    command: command.clone(),
+                };
+                match leader.send(request, self.config.rpc_timeout).await {
+                    Ok(response) if response.error.is_none() => {
+                        break (response.term, response.index);
+                    }
+                    Ok(response) => debug!(error = ?response.error, "proposal rejected"),
+                    Err(err) => debug!(%err, "unable to forward proposal to leader"),
+                }
+            }
+            if Instant::now() >= deadline {
+                return Err(anyhow!("no SC leader available to accept metadata change"));
+            }
+            sleep(PROPOSE_RETRY).await;
+        };
+
+        self.wait_for_result(term, index, deadline).await
+    }
+
+    /// append command if this node is leader, otherwise reply with known leader
+    pub async fn propose_local(&self, command: Vec<u8>) -> Result<ProposeResponse> {
+        let mut state = self.state.lock().await;
+        if state.role != Role::Leader || self.is_stopped() {
+            return Ok(ProposeResponse {
+                error: Some("not a leader".to_string()),
+                leader_id: state.leader_id.filter(|leader| *leader != self.id()),
+                ..Default::default()
+            });
+        }
+        let term = state.hard.current_term;
+        let index = state.log.append(term, command).await?;
+        self.log_index.update(index as i64);
+        self.advance_commit(&mut state);
+        Ok(ProposeResponse {
+            term,
+            index,
+            error: None,
+            leader_id: Some(self.id()),
+        })
+    }
+
+    async fn wait_for_result(&self, term: u64, index: u64, deadline: Instant) -> Result<()> {
+        use tokio::select;
+
+        let mut listener = self.applied_index.change_listener();
+        while (self.applied_index.current_value() as u64) < index {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            select! {
+                _ = listener.listen() => {},
+                _ = sleep(remaining) => {
+                    return Err(anyhow!("timed out waiting for metadata change to be committed"));
+                }
+            }
+        }
+
+        let results = self.results.lock().await;
+        match results.get(&index) {
+            Some((applied_term, result)) if *applied_term == term => {
+                result.clone().map_err(|err| anyhow!(err))
+            }
+            Some(_) => Err(anyhow!("metadata change was discarded by SC leader change")),
+            None => Err(anyhow!("result of metadata change is no longer available")),
+        }
+    }
+
+    pub async fn handle_request_vote(&self, request: &RequestVoteRequest) -> RequestVoteResponse {
+        let mut state = self.state.lock().await;
+        if request.term > state.hard.current_term {
+            if let Err(err) = self.become_follower(&mut state, request.term, None).await {
+                error!(%err, "unable to persist raft state, denying vote");
+                return RequestVoteResponse {
+                    term: state.hard.current_term,
+                    vote_granted: false,
+                };
+            }
+        }
+
+        let up_to_date = (request.last_log_term, request.last_log_index)
+            >= (state.log.last_term(), state.log.last_index());
+        let vote_granted = request.term == state.hard.current_term
+            && state
+                .hard
+                .voted_for
+                .map_or(true, |voted| voted == request.candidate_id)
+            && up_to_date;
+
+        if vote_granted {
+            let hard = HardState {
+                voted_for: Some(request.candidate_id),
+                ..state.hard.clone()
+            };
+            if let Err(err) = self.flush_hard_state(&mut state, hard).await {
+                error!(%err, "unable to persist raft state, denying vote");
+                return RequestVoteResponse {
+                    term: state.hard.current_term,
+                    vote_granted: false,
+                };
+            }
+            state.election_deadline = Instant::now() + self.config.random_election_timeout();
+        }
+        debug!(
+            candidate = request.candidate_id,
+            term = request.term,
+            vote_granted,
+            "vote requested"
+        );
+
+        RequestVoteResponse {
+            term: state.hard.current_term,
+            vote_granted,
+        }
+    }
+
+    pub async fn handle_append_entries(
+        &self,
+        request: AppendEntriesRequest,
+    ) -> AppendEntriesResponse {
+        let mut state = self.state.lock().await;
+        let current_term = state.hard.current_term;
+        if request.term < current_term {
+            return AppendEntriesResponse {
+                term: current_term,
+                success: false,
+                last_log_index: state.log.last_index(),
+            };
+        }
+        if request.term > current_term || state.role != Role::Follower {
+            if let Err(err) = self
+                .become_follower(&mut state, request.term, Some(request.leader_id))
+                .await
+            {
+                error!(%err, "unable to persist raft state, rejecting entries");
+                return AppendEntriesResponse {
+                    term: current_term,
+                    success: false,
+                    last_log_index: state.log.last_index(),
+                };
+            }
+        }
+        state.leader_id = Some(request.leader_id);
+        state.election_deadline = Instant::now() + self.config.random_election_timeout();
+
+        let rejected = AppendEntriesResponse {
+            term: request.term,
+            success: false,
+            last_log_index: state.log.last_index(),
+        };
+        // entries up to the snapshot are committed, so they match the leader
+        if request.prev_log_index > state.log.snapshot_index() {
+            match state.log.term_at(request.prev_log_index) {
+                None => return rejected,
+                Some(term) if term != request.prev_log_term => {
+                    return AppendEntriesResponse {
+                        last_log_index: request.prev_log_index - 1,
+                        ..rejected
+                    };
+                }
+                Some(_) => {}
+            }
+        }
+
+        let last_new_index = request.prev_log_index + request.entries.len() as u64;
+        if let Err(err) = state.log.merge(request.entries).await {
+            error!(%err, "unable to store raft log entries");
+            return rejected;
+        }
+        self.log_index.update(state.log.last_index() as i64);
+
+        // entries beyond `last_new_index` may not match leader, commit index never moves back
+        let commit_index = request.leader_commit.min(last_new_index);
+        if commit_index > state.commit_index {
+            state.commit_index = commit_index;
+            self.commit_index.update(commit_index as i64);
+        }
+
+        AppendEntriesResponse {
+            term: request.term,
+            success: true,
+            last_log_index: last_new_index,
+        }
+    }
+
+    pub async fn handle_install_snapshot(
+        &self,
+        request: InstallSnapshotRequest,
+    ) -> InstallSnapshotResponse {
+        let _applying = self.applying.lock().await;
+        let mut state = self.state.lock().await;
+        let current_term = state.hard.current_term;
+        if request.term < current_term {
+            return InstallSnapshotResponse { term: current_term };
+        }
+        if request.term > current_term || state.role != Role::Follower {
+            if let Err(err) = self
+                .become_follower(&mut state, request.term, Some(request.leader_id))
+                .await
+            {
+                error!(%err, "unable to persist raft state, rejecting snapshot");
+                return InstallSnapshotResponse { term: current_term };
+            }
+        }
+        state.leader_id = Some(request.leader_id);
+        state.election_deadline = Instant::now() + self.config.random_election_timeout();
+
+        let response = InstallSnapshotResponse { term: request.term };
+        let snapshot = request.snapshot;
+        if snapshot.index <= state.hard.last_applied {
+            return response;
+        }
+        info!(index = snapshot.index, "installing snapshot from leader");
+        if let Err(err) = state.log.install_snapshot(&snapshot).await {
+            error!(%err, "unable to store raft snapshot");
+            return response;
+        }
+        self.log_index.update(state.log.last_index() as i64);
+        if snapshot.index > state.commit_index {
+            state.commit_index = snapshot.index;
+            self.commit_index.update(snapshot.index as i64);
+        }
+        // on failure, apply loop restores the stored snapshot
+        if let Err(err) = self.state_machine.restore(&snapshot.data).await {
+            error!(%err, "unable to restore state machine from snapshot");
+            return response;
+        }
+        let hard = HardState {
+            last_applied: snapshot.index,
+            ..state.hard.clone()
+        };
+        if let Err(err) = self.flush_hard_state(&mut state, hard).await {
+            error!(%err, "unable to persist raft state");
+            return response;
+        }
+        self.applied_index.update(snapshot.index as i64);
+        response
+    }
+
+    fn majority(&self) -> usize {
+        (self.peers.len() + 1) / 2 + 1
+    }
+
+    /// hard state takes effect only once it is stored,
+    /// losing vote or term may elect two leaders
+    async fn flush_hard_state(&self, state: &mut RaftState, hard: HardState) -> Result<()> {
+        hard.flush(&self.config.path).await?;
+        state.hard = hard;
+        Ok(())
+    }
+
+    /// step down even if new term can't be stored, node stays in old term then
+    async fn become_follower(
+        &self,
+        state: &mut RaftState,
+        term: u64,
+        leader_id: Option<NodeId>,
+    ) -> Result<()> {
+        if state.role != Role::Follower {
+            info!(id = self.id(), term, "became follower");
+        }
+        state.role = Role::Follower;
+        state.leader_id = leader_id;
+        state.votes.clear();
+        self.leader_term.update(0);
+        if term > state.hard.current_term {
+            let hard = HardState {
+                current_term: term,
+                voted_for: None,
+                ..state.hard.clone()
+            };
+            self.flush_hard_state(state, hard).await?;
+        }
+        Ok(())
+    }
+
+    /// step down on response from node with higher term
+    async fn step_down(&self, state: &mut RaftState, term: u64) {
+        if let Err(err) = self.become_follower(state, term, None).await {
+            error!(%err, "unable to persist raft state");
+        }
+    }
+
+    async fn become_leader(&self, state: &mut RaftState) {
+        let term = state.hard.current_term;
+        info!(id = self.id(), term, "became leader");
+        state.role = Role::Leader;
+        state.leader_id = Some(self.id());
+        let next_index = state.log.last_index() + 1;
+        state.next_index = self.peers.keys().map(|id| (*id, next_index)).collect();
+        state.match_index = self.peers.keys().map(|id| (*id, 0)).collect();
+
+        // entries of previous terms are committed with the no-op
+        match state.log.append(term, vec![]).await {
+            Ok(index) => {
+                state.leader_start = index;
+                self.log_index.update(index as i64);
+                self.advance_commit(state);
+            }
+            Err(err) => {
+                error!(%err, "unable to append to raft log");
+                self.step_down(state, term).await;
+            }
+        }
+    }
+
+    /// commit highest entry of current term stored on majority of nodes
+    fn advance_commit(&self, state: &mut RaftState) {
+        let term = state.hard.current_term;
+        let majority = self.majority();
+        for index in (state.commit_index + 1..=state.log.last_index()).rev() {
+            if state.log.term_at(index) != Some(term) {
+                break;
+            }
+            let replicated = 1 + state
+                .match_index
+                .values()
+                .filter(|matched| **matched >= index)
+                .count();
+            if replicated >= majority {
+                state.commit_index = index;
+                self.commit_index.update(index as i64);
+                break;
+            }
+        }
+    }
+
+    async fn election_loop(self: Arc<Self>) {
+        use tokio::select;
+
+        let tick = self.config.heartbeat_interval / 2;
+        loop {
+            select! {
+                _ = sleep(tick) => {},
+                _ = self.shutdown.listen_pinned() => return,
+            }
+            let election = {
+                let mut state = self.state.lock().await;
+                if state.role == Role::Leader || Instant::now() < state.election_deadline {
+                    continue;
+                }
+                let hard = HardState {
+                    current_term: state.hard.current_term + 1,
+                    voted_for: Some(self.id()),
+                    ..state.hard.clone()
+                };
+                if let Err(err) = self.flush_hard_state(&mut state, hard).await {
+                    error!(%err, "unable to persist raft state, postponing election");
+                    state.election_deadline =
+                        Instant::now() + self.config.random_election_timeout();
+                    continue;
+                }
+                state.role = Role::Candidate;
+                state.leader_id = None;
+                state.votes = HashSet::from([self.id()]);
+                state.election_deadline = Instant::now() + self.config.random_election_timeout();
+                info!(
+                    id = self.id(),
+                    term = state.hard.current_term,
+                    "starting election"
+                );
+
+                if state.votes.len() >= self.majority() {
+                    self.become_leader(&mut state).await;
+                    continue;
+                }
+                (
+                    state.hard.current_term,
+                    state.log.last_index(),
+                    state.log.last_term(),
+                )
+            };
+
+            let (term, last_log_index, last_log_term) = election;
+            for peer in self.peers.keys() {
+                let request = RequestVoteRequest {
+                    term,
+                    candidate_id: self.id(),
+                    last_log_index,
+                    last_log_term,
+                };
+                spawn(self.clone().request_vote(*peer, request));
+            }
+        }
+    }
+
+    async fn request_vote(self: Arc<Self>, peer_id: NodeId, request: RequestVoteRequest) {
+        let Some(peer) = self.peers.get(&peer_id) else {
+            return;
+        };
+        let term = request.term;
+        let response = match peer.send(request, self.config.rpc_timeout).await {
+            Ok(response) => response,
+            Err(err) => {
+                debug!(peer_id, %err, "vote request failed");
+                return;
+            }
+        };
+
+        let mut state = self.state.lock().await;
+        if self.is_stopped() {
+            return;
+        }
+        if response.term > state.hard.current_term {
+            self.step_down(&mut state, response.term).await;
+            return;
+        }
+        if state.role != Role::Candidate || state.hard.current_term != term {
+            return;
+        }
+        if response.vote_granted {
+            state.votes.insert(peer_id);
+            if state.votes.len() >= self.majority() {
+                self.become_leader(&mut state).await;
+            }
+        }
+    }
+
+    /// next request to bring follower up to date, snapshot if it is behind the compacted log
+    async fn next_replication(&self, peer_id: NodeId) -> Result<Option<Replication>> {
+        let state = self.state.lock().await;
+        if state.role != Role::Leader {
+            return Ok(None);
+        }
+        let next_index = state.next_index.get(&peer_id).copied().unwrap_or(1);
+        if next_index <= state.log.snapshot_index() {
+            let Some(snapshot) = state.log.snapshot().await? else {
+                return Err(anyhow!("raft snapshot is missing"));
+            };
+            return Ok(Some(Replication::Snapshot(InstallSnapshotRequest {
+                term: state.hard.current_term,
+                leader_id: self.id(),
+                snapshot,
+            })));
+        }
+        let prev_log_index = next_index - 1;
+        Ok(Some(Replication::Entries(AppendEntriesRequest {
+            term: state.hard.current_term,
+            leader_id: self.id(),
+            prev_log_index,
+            prev_log_term: state.log.term_at(prev_log_index).unwrap_or_default(),
+            entries: state.log.entries_from(next_index, MAX_ENTRIES_PER_APPEND),
+            leader_commit: state.commit_index,
+        })))
+    }
+
+    #[instrument(skip(self))]
+    async fn replicate_loop(self: Arc<Self>, peer_id: NodeId) {
+        use tokio::select;
+
+        let Some(peer) = self.peers.get(&peer_id) else {
+            return;
+        };
+        let mut log_listener = self.log_index.change_listener();
+        while !self.is_stopped() {
+            match self.next_replication(peer_id).await {
+                Ok(Some(Replication::Entries(request))) => {
+                    let term = request.term;
+                    let prev_log_index = request.prev_log_index;
+                    let sent = request.entries.len() as u64;
+                    match peer.send(request, self.config.rpc_timeout).await {
+                        Ok(response) => {
+                            let pending = self
+                                .handle_append_response(
+                                    peer_id,
+                                    term,
+                                    prev_log_index,
+                                    sent,
+                                    response,
+                                )
+                                .await;
+                            if pending {
+                                continue;
+                            }
+                        }
+                        Err(err) => debug!(%err, "append entries failed"),
+                    }
+                }
+                Ok(Some(Replication::Snapshot(request))) => {
+                    let term = request.term;
+                    let index = request.snapshot.index;
+                    info!(peer_id, index, "sending snapshot to follower");
+                    match peer.send(request, self.config.rpc_timeout).await {
+                        Ok(response) => {
+                            self.handle_snapshot_response(peer_id, term, index, response)
+                                .await;
+                        }
+                        Err(err) => debug!(%err, "install snapshot failed"),
+                    }
+                }
+                Ok(None) => {}
+                Err(err) => error!(%err, "unable to prepare replication"),
+            }
+
+            select! {
+                _ = sleep(self.config.heartbeat_interval) => {},
+                _ = log_listener.listen() => {},
+                _ = self.shutdown.listen_pinned() => {}
+            }
+        }
+    }
+
+    /// update replication progress of peer, returns true if there are more entries to send
+    async fn handle_append_response(
+        &self,
+        peer_id: NodeId,
+        term: u64,
+        prev_log_index: u64,
+        sent: u64,
+        response: AppendEntriesResponse,
+    ) -> bool {
+        let mut state = self.state.lock().await;
+        if response.term > state.hard.current_term {
+            self.step_down(&mut state, response.term).await;
+            return false;
+        }
+        if state.role != Role::Leader || state.hard.current_term != term {
+            return false;
+        }
+        if response.success {
+            let matched = prev_log_index + sent;
+            let match_index = state.match_index.entry(peer_id).or_default();
+            *match_index = (*match_index).max(matched);
+            state.next_index.insert(peer_id, matched + 1);
+            self.advance_commit(&mut state);
+            matched < state.log.last_index()
+        } else {
+            let next_index = (response.last_log_index + 1).min(prev_log_index).max(1);
+            warn!(peer_id, next_index, "follower log diverged, rewinding");
+            state.next_index.insert(peer_id, next_index);
+            true
+        }
+    }
+
+    async fn handle_snapshot_response(
+        &self,
+        peer_id: NodeId,
+        term: u64,
+        index: u64,
+        response: InstallSnapshotResponse,
+    ) {
+        let mut state = self.state.lock().await;
+        if response.term > state.hard.current_term {
+            self.step_down(&mut state, response.term).await;
+            return;
+        }
+        if state.role != Role::Leader || state.hard.current_term != term {
+            return;
+        }
+        let match_index = state.match_index.entry(peer_id).or_default();
+        *match_index = (*match_index).max(index);
+        state.next_index.insert(peer_id, index + 1);
+        self.advance_commit(&mut state);
+    }
+
+    async fn apply_loop(self: Arc<Self>) {
+        use tokio::select;
+
+        // entry which may have been applied before the node stopped,
+        // state machine changes and `last_applied` are not written atomically
+        let recovered = self.applied_index.current_value() as u64 + 1;
+        let mut listener = self.commit_index.change_listener();
+        while !self.is_stopped() {
+            if self.applied_index.current_value() >= self.commit_index.current_value() {
+                select! {
+                    _ = listener.listen() => {},
+                    _ = self.shutdown.listen_pinned() => {}
+                }
+                continue;
+            }
+
+            let _applying = self.applying.lock().await;
+            // snapshot from leader may have been installed meanwhile
+            let index = self.applied_index.current_value() as u64 + 1;
+            if index > self.commit_index.current_value() as u64 {
+                continue;
+            }
+            let (entry, snapshot_index) = {
+                let state = self.state.lock().await;
+                (state.log.get(index).cloned(), state.log.snapshot_index())
+            };
+            let Some(entry) = entry else {
+                if index <= snapshot_index {
+                    if let Err(err) = self.restore_snapshot().await {
+                        error!(%err, "unable to restore state machine from snapshot");
+                        sleep(self.config.heartbeat_interval).await;
+                    }
+                } else {
+                    error!(index, "committed entry is missing from raft log");
+                    sleep(self.config.heartbeat_interval).await;
+                }
+                continue;
+            };
+            let result = if entry.command.is_empty() {
+                Ok(())
+            } else if index == recovered {
+                self.state_machine.reapply(&entry.command).await
+            } else {
+                self.state_machine.apply(&entry.command).await
+            };
+            if let Err(err) = &result {
+                debug!(index, %err, "command rejected by state machine");
+            }
+
+            {
+                let mut results = self.results.lock().await;
+                results.insert(index, (entry.term, result.map_err(|err| err.to_string())));
+                while results.len() > MAX_RETAINED_RESULTS {
+                    results.pop_first();
+                }
+            }
+
+            // entry is already in state machine, it must not be applied again after restart
+            let mut state = loop {
+                let mut state = self.state.lock().await;
+                let hard = HardState {
+                    last_applied: index,
+                    ..state.hard.clone()
+                };
+                match self.flush_hard_state(&mut state, hard).await {
+                    Ok(()) => break state,
+                    Err(err) => error!(index, %err, "unable to persist applied index, retrying"),
+                }
+                drop(state);
+                if self.is_stopped() {
+                    return;
+                }
+                sleep(self.config.heartbeat_interval).await;
+            };
+            self.applied_index.update(index as i64);
+            if state.role == Role::Leader && index >= state.leader_start {
+                let term = state.hard.current_term as i64;
+                if self.leader_term.current_value() != term {
+                    info!(id = self.id(), term, "leader is ready");
+                    self.leader_term.update(term);
+                }
+            }
+
+            if index - state.log.snapshot_index() >= self.config.max_log_entries {
+                match self.state_machine.snapshot().await {
+                    Ok(data) => {
+                        if let Err(err) = state.log.compact(index, data).await {
+                            error!(%err, "unable to compact raft log");
+                        }
+                    }
+                    Err(err) => error!(%err, "unable to take snapshot of state machine"),
+                }
+            }
+        }
+    }
+
+    /// bring state machine to stored snapshot, when the node stopped while installing it
+    async fn restore_snapshot(&self) -> Result<()> {
+        let mut state = self.state.lock().await;
+        let Some(snapshot) = state.log.snapshot().await? else {
+            return Err(anyhow!("raft snapshot is missing"));
+        };
+        info!(
+            index = snapshot.index,
+            "restoring state machine from snapshot"
+        );
+        self.state_machine.restore(&snapshot.data).await?;
+        let hard = HardState {
+            last_applied: snapshot.index,
+            ..state.hard.clone()
+        };
+        self.flush_hard_state(&mut state, hard).await?;
+        self.applied_index.update(snapshot.index as i64);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::net::TcpListener;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use super::super::server::start_raft_server;
+
+    /// records applied commands
+    #[derive(Default)]
+    struct TestStateMachine {
+        applied: Mutex<Vec<Vec<u8>>>,
+    }
+
+    #[async_trait]
+    impl StateMachine for TestStateMachine {
+        async fn apply(&self, command: &[u8]) -> Result<()> {
+            if command == b"reject" {
+                return Err(anyhow!("rejected"));
+            }
+            self.applied.lock().await.push(command.to_vec());
+            Ok(())
+        }
+
+        async fn reapply(&self, command: &[u8]) -> Result<()> {
+            let applied = self.applied.lock().await.last().cloned();
+            if applied.as_deref() == Some(command) {
+                return Ok(());
+            }
+            self.apply(command).await
+        }
+
+        async fn snapshot(&self) -> Result<Vec<u8>> {
+            Ok(serde_json::to_vec(&*self.applied.lock().await)?)
+        }
+
+        async fn restore(&self, snapshot: &[u8]) -> Result<()> {
+            *self.applied.lock().await = serde_json::from_slice(snapshot)?;
+            Ok(())
+        }
+    }
+
+    struct TestNode {
+        node: Arc<RaftNode>,
+        state_machine: Arc<TestStateMachine>,
+        server: Arc<StickyEvent>,
+    }
+
+    impl TestNode {
+        fn start(config: RaftConfig) -> Self {
+            let state_machine = Arc::new(TestStateMachine::default());
+            let node = RaftNode::start(config, state_machine.clone()).expect("start");
+            let server = start_raft_server(node.clone());
+            Self {
+                node,
+                state_machine,
+                server,
+            }
+        }
+
+        /// simulate crash, peers can no longer reach the node
+        fn kill(&self) {
+            self.node.stop();
+            self.server.notify();
+        }
+
+        async fn wait_for_applied(&self, expected: &[&[u8]]) {
+            let deadline = Instant::now() + WAIT_TIMEOUT;
+            loop {
+                let applied = self.state_machine.applied.lock().await.clone();
+                if applied
+                    .iter()
+                    .map(Vec::as_slice)
+                    .eq(expected.iter().copied())
+                {
+                    break;
+                }
+                assert!(
+                    Instant::now() < deadline,
+                    "node {} applied {applied:?}",
+                    self.node.id()
+                );
+                sleep(Duration::from_millis(20)).await;
+            }
+        }
+    }
+
+    const WAIT_TIMEOUT: Duration = Duration::from_secs(30);
+
+    /// addresses on ports picked by OS
+    fn test_addrs(count: NodeId) -> BTreeMap<NodeId, String> {
+        (1..=count)
+            .map(|id| {
+                let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+                let addr = listener.local_addr().expect("addr");
+                (id, addr.to_string())
+            })
+            .collect()
+    }
+
+    fn test_config(dir: &TempDir, id: NodeId, addrs: &BTreeMap<NodeId, String>) -> RaftConfig {
+        let path = dir.path().join(format!("node-{id}"));
+        let peers = addrs
+            .iter()
+            .filter(|(peer, _)| **peer != id)
+            .map(|(peer, addr)| (*peer, addr.clone()))
+            .collect();
+        let mut config = RaftConfig::new(id, addrs[&id].clone(), peers, path);
+        config.heartbeat_interval = Duration::from_millis(50);
+        config.election_timeout = Duration::from_millis(300);
+        config
+    }
+
+    /// wait until one of running nodes takes over
+    async fn wait_for_leader(nodes: &[TestNode]) -> NodeId {
+        let deadline = Instant::now() + WAIT_TIMEOUT;
+        loop {
+            assert!(Instant::now() < deadline, "no leader elected");
+            for test_node in nodes {
+                let node = &test_node.node;
+                if !node.is_stopped() && node.role().await == Role::Leader {
+                    return node.id();
+                }
+            }
+            sleep(Duration::from_millis(50)).await;
+        }
+    }
+
+    #[fluvio_future::test]
+    async fn test_single_node_elects_itself() {
+        let dir = TempDir::new().expect("temp dir");
+        let addrs = BTreeMap::from([(1, "127.0.0.1:0".to_string())]);
+        let state_machine = Arc::new(TestStateMachine::default());
+        let node =
+            RaftNode::start(test_config(&dir, 1, &addrs), state_machine.clone()).expect("start");
+
+        let term = node.wait_for_leadership().await;
+        assert_eq!(term, 1);
+        node.propose(b"a".to_vec()).await.expect("propose");
+        assert!(node.propose(b"reject".to_vec()).await.is_err());
+        assert_eq!(*state_machine.applied.lock().await, vec![b"a".to_vec()]);
+    }
+
+    #[fluvio_future::test]
+    async fn test_cluster_replicates_through_leader() {
+        let dir = TempDir::new().expect("temp dir");
+        let addrs = test_addrs(3);
+        let nodes: Vec<TestNode> = addrs
+            .keys()
+            .map(|id| TestNode::start(test_config(&dir, *id, &addrs)))
+            .collect();
+
+        let leader = wait_for_leader(&nodes).await;
+
+        // followers forward proposals to leader
+        let follower = nodes
+            .iter()
+            .find(|test_node| test_node.node.id() != leader)
+            .expect("follower");
+        follower.node.propose(b"a".to_vec()).await.expect("propose");
+
+        for test_node in &nodes {
+            test_node.wait_for_applied(&[b"a"]).await;
+        }
+    }
+
+    #[fluvio_future::test]
+    async fn test_writes_succeed_after_leader_is_killed() {
+        let dir = TempDir::new().expect("temp dir");
+        let addrs = test_addrs(3);
+        let nodes: Vec<TestNode> = addrs
+            .keys()
+            .map(|id| TestNode::start(test_config(&dir, *id, &addrs)))
+            .collect();
+
+        let leader = wait_for_leader(&nodes).await;
+        let leader_node = nodes
+            .iter()
+            .find(|test_node| test_node.node.id() == leader)
+            .expect("leader");
+        leader_node
+            .node
+            .propose(b"a".to_vec())
+            .await
+            .expect("propose");
+        leader_node.kill();
+
+        let new_leader = wait_for_leader(&nodes).await;
+        assert_ne!(new_leader, leader);
+        let survivors: Vec<&TestNode> = nodes
+            .iter()
+            .filter(|test_node| test_node.node.id() != leader)
+            .collect();
+        for survivor in &survivors {
+            survivor
+                .node
+                .propose(format!("from-{}", survivor.node.id()).into_bytes())
+                .await
+                .expect("write after leader failure");
+        }
+
+        let first = format!("from-{}", survivors[0].node.id()).into_bytes();
+        let second = format!("from-{}", survivors[1].node.id()).into_bytes();
+        for survivor in &survivors {
+            survivor
+                .wait_for_applied(&[b"a", first.as_slice(), second.as_slice()])
+                .await;
+        }
+    }
+
+    #[fluvio_future::test]
+    async fn test_lagging_follower_receives_snapshot() {
+        let dir = TempDir::new().expect("temp dir");
+        let addrs = test_addrs(3);
+        let config = |id| {
+            let mut config = test_config(&dir, id, &addrs);
+            config.max_log_entries = 4;
+            config
+        };
+        let nodes: Vec<TestNode> = (1..=2).map(|id| TestNode::start(config(id))).collect();
+
+        wait_for_leader(&nodes).await;
+        let commands: Vec<Vec<u8>> = (0..10).map(|i| format!("c{i}").into_bytes()).collect();
+        for command in &commands {
+            nodes[0]
+                .node
+                .propose(command.clone())
+                .await
+                .expect("propose");
+        }
+        assert!(nodes[0].node.state.lock().await.log.snapshot_index() > 0);
+
+        // entries replicated before it started were compacted
+        let lagging = TestNode::start(config(3));
+        let expected: Vec<&[u8]> = commands.iter().map(Vec::as_slice).collect();
+        lagging.wait_for_applied(&expected).await;
+    }
+}
@@ -0,0 +1,92 @@
+//!
+//! # Raft server
+//!
+//! Serves requests from other SC instances.
+//!
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use tracing::{debug, info, instrument};
+
+use fluvio_service::{ConnectInfo, FluvioApiServer, FluvioService};
+use fluvio_socket::FluvioSocket;
+use fluvio_types::event::StickyEvent;
+
+use super::api::{RaftApiKey, RaftRequest};
+use super::node::RaftNode;
+
+#[derive(Debug)]
+pub struct RaftService;
+
+#[async_trait]
+impl FluvioService for RaftService {
+    type Context = Arc<RaftNode>;
+    type Request = RaftRequest;
+
+    #[instrument(skip(self, node))]
+    async fn respond(
+        self: Arc<Self>,
+        node: Self::Context,
+        socket: FluvioSocket,
+        _connection: ConnectInfo,
+    ) -> Result<()> {
+        let (mut sink, mut stream) = socket.split();
+        let mut api_stream = stream.api_stream::<RaftRequest, RaftApiKey>();
+
+        while let Some(request) = api_stream.next().await {
+            if node.is_stopped() {
+                break;
+            }
+            match request? {
+                RaftRequest::RequestVoteRequest(req_msg) => {
+                    let response = node.handle_request_vote(&req_msg.request).await;
+                    sink.send_response(
+                        &req_msg.new_response(response),
+                        req_msg.header.api_version(),
+                    )
+                    .await?;
+                }
+                RaftRequest::AppendEntriesRequest(mut req_msg) => {
+                    let request = std::mem::take(&mut req_msg.request);
+                    let response = node.handle_append_entries(request).await;
+                    sink.send_response(
+                        &req_msg.new_response(response),
+                        req_msg.header.api_version(),
+                    )
+                    .await?;
+                }
+                RaftRequest::InstallSnapshotRequest(mut req_msg) => {
+                    let request = std::mem::take(&mut req_msg.request);
+                    let response = node.handle_install_snapshot(request).await;
+                    sink.send_response(
+                        &req_msg.new_response(response),
+                        req_msg.header.api_version(),
+                    )
+                    .await?;
+                }
+                RaftRequest::ProposeRequest(req_msg) => {
+                    let response = node.propose_local(req_msg.request.command.clone()).await?;
+                    sink.send_response(
+                        &req_msg.new_response(response),
+                        req_msg.header.api_version(),
+                    )
+                    .await?;
+                }
+            }
+        }
+
+        debug!("raft peer disconnected");
+        Ok(())
+    }
+}
+
+/// start server accepting requests from other SC instances,
+/// returns event which stops accepting connections
+pub fn start_raft_server(node: Arc<RaftNode>) -> Arc<StickyEvent> {
+    let addr = node.config().bind.clone();
+    info!(%addr, "starting raft server");
+    let server = FluvioApiServer::new(addr, node, RaftService);
+    server.run()
+}
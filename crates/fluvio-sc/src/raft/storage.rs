@@ -0,0 +1,575 @@
+//!
+//! # Replicated metadata storage
+//!
+//! Writes are serialized into commands, replicated through raft and applied to the
+//! local metadata storage of every SC. Reads and watches are served from local storage.
+//!
+use std::collections::{BTreeMap, HashMap};
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use futures_util::stream::BoxStream;
+use serde::{Serialize, Deserialize};
+use tracing::{debug, trace};
+
+use fluvio_stream_dispatcher::metadata::MetadataClient;
+use fluvio_stream_dispatcher::metadata::local::{LocalMetadataItem, LocalMetadataStorage};
+use fluvio_stream_model::core::{MetadataContext, MetadataItem, Spec};
+use fluvio_stream_model::store::{MetadataStoreList, MetadataStoreObject, NameSpace};
+use fluvio_stream_model::store::actions::LSUpdate;
+use fluvio_stream_model::store::k8::K8ExtendedSpec;
+
+use super::node::{RaftNode, StateMachine};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct MetadataCommand {
+    label: String,
+    op: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(bound = "S: Spec")]
+enum MetadataOp<S: Spec> {
+    Apply {
+        key: String,
+        meta: LocalMetadataItem,
+        spec: S,
+        status: S::Status,
+    },
+    UpdateSpec {
+        meta: LocalMetadataItem,
+        spec: S,
+    },
+    UpdateSpecByKey {
+        key: String,
+        spec: S,
+    },
+    UpdateStatus {
+        meta: LocalMetadataItem,
+        status: S::Status,
+    },
+    Delete {
+        meta: LocalMetadataItem,
+    },
+}
+
+impl<S: Spec> MetadataOp<S> {
+    fn encode(self) -> Result<Vec<u8>> {
+        let command = MetadataCommand {
+            label: S::LABEL.to_owned(),
+            op: serde_json::to_value(self)?,
+        };
+        Ok(serde_json::to_vec(&command)?)
+    }
+}
+
+fn parse_key<S: Spec>(key: &str) -> Result<S::IndexKey> {
+    S::IndexKey::from_str(key).map_err(|_| anyhow!("failed to parse key from a string: {key}"))
+}
+
+#[async_trait]
+trait CommandApplier: Send + Sync {
+    /// apply command, when `reapply` is set command already in effect is skipped
+    async fn apply(
+        &self,
+        storage: &LocalMetadataStorage,
+        op: serde_json::Value,
+        reapply: bool,
+    ) -> Result<()>;
+
+    /// commands which recreate all stored items
+    async fn snapshot(&self, storage: &LocalMetadataStorage) -> Result<Vec<serde_json::Value>>;
+
+    /// make stored items equal to snapshot
+    async fn restore(
+        &self,
+        storage: &LocalMetadataStorage,
+        snapshot: Vec<serde_json::Value>,
+    ) -> Result<()>;
+}
+
+struct TypedApplier<S>(PhantomData<S>);
+
+impl<S> TypedApplier<S>
+where
+    S: K8ExtendedSpec,
+    <S as Spec>::Owner: K8ExtendedSpec,
+{
+    /// true if storage already has the result of the operation
+    async fn is_applied(&self, storage: &LocalMetadataStorage, op: &MetadataOp<S>) -> Result<bool> {
+        let items = storage.retrieve_items::<S>(&NameSpace::All).await?.items;
+        let find = |id: &str| items.iter().find(|item| item.ctx().item().uid() == id);
+        Ok(match op {
+            MetadataOp::Apply {
+                key,
+                meta,
+                spec,
+                status,
+            } => find(key).is_some_and(|item| {
+                item.spec() == spec
+                    && item.status() == status
+                    && item.ctx().item().owner() == meta.owner()
+            }),
+            MetadataOp::UpdateSpec { meta, spec } => {
+                find(meta.uid()).is_some_and(|item| item.spec() == spec)
+            }
+            MetadataOp::UpdateSpecByKey { key, spec } => {
+                find(key).is_some_and(|item| item.spec() == spec)
+            }
+            MetadataOp::UpdateStatus { meta, status } => {
+                find(meta.uid()).is_some_and(|item| item.status() == status)
+            }
+            MetadataOp::Delete { meta } => find(meta.uid()).is_none(),
+        })
+    }
+}
+
+#[async_trait]
+impl<S> CommandApplier for TypedApplier<S>
+where
+    S: K8ExtendedSpec,
+    <S as Spec>::Owner: K8ExtendedSpec,
+{
+    async fn apply(
+        &self,
+        storage: &LocalMetadataStorage,
+        op: serde_json::Value,
+        reapply: bool,
+    ) -> Result<()> {
+        let namespace = NameSpace::All;
+        let op = serde_json::from_value::<MetadataOp<S>>(op)?;
+        // applying again would bump revisions of items
+        if reapply && self.is_applied(storage, &op).await? {
+            debug!(label = S::LABEL, "metadata command was already applied");
+            return Ok(());
+        }
+        match op {
+            MetadataOp::Apply {
+                key,
+                meta,
+                spec,
+                status,
+            } => {
+                let mut value = MetadataStoreObject::new_with_context(
+                    parse_key::<S>(&key)?,
+                    spec,
+                    MetadataContext::new(meta),
+                );
+                value.set_status(status);
+                storage.apply(value).await
+            }
+            MetadataOp::UpdateSpec { meta, spec } => storage.update_spec(meta, spec).await,
+            MetadataOp::UpdateSpecByKey { key, spec } => {
+                storage
+                    .update_spec_by_key(parse_key::<S>(&key)?, &namespace, spec)
+                    .await
+            }
+            MetadataOp::UpdateStatus { meta, status } => storage
+                .update_status::<S>(meta, status, &namespace)
+                .await
+                .map(|_| ()),
+            MetadataOp::Delete { meta } => storage.delete_item::<S>(meta).await,
+        }
+    }
+
+    async fn snapshot(&self, storage: &LocalMetadataStorage) -> Result<Vec<serde_json::Value>> {
+        let items = storage.retrieve_items::<S>(&NameSpace::All).await?.items;
+        let mut snapshot = Vec::with_capacity(items.len());
+        for item in items {
+            let (key, spec, status, ctx) = item.parts();
+            snapshot.push(serde_json::to_value(MetadataOp::Apply {
+                key: key.to_string(),
+                meta: ctx.into_inner(),
+                spec,
+                status,
+            })?);
+        }
+        Ok(snapshot)
+    }
+
+    async fn restore(
+        &self,
+        storage: &LocalMetadataStorage,
+        snapshot: Vec<serde_json::Value>,
+    ) -> Result<()> {
+        let mut restored = HashMap::new();
+        for op in snapshot {
+            let MetadataOp::<S>::Apply {
+                key,
+                meta,
+                spec,
+                status,
+            } = serde_json::from_value(op)?
+            else {
+                return Err(anyhow!("invalid snapshot of {}", S::LABEL));
+            };
+            let mut value = MetadataStoreObject::new_with_context(
+                parse_key::<S>(&key)?,
+                spec,
+                MetadataContext::new(meta),
+            );
+            value.set_status(status);
+            restored.insert(key, value);
+        }
+
+        // items are recreated, so that revisions are the same as in snapshot
+        for item in storage.retrieve_items::<S>(&NameSpace::All).await?.items {
+            let id = item.ctx().item().uid().clone();
+            match restored.get(&id) {
+                Some(value) if *value == item => {
+                    restored.remove(&id);
+                }
+                _ => storage.delete_item::<S>(item.ctx().item().clone()).await?,
+            }
+        }
+        for value in restored.into_values() {
+            storage.apply(value).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Applies committed metadata commands to local storage
+pub struct MetadataStateMachine {
+    storage: LocalMetadataStorage,
+    appliers: HashMap<&'static str, Box<dyn CommandApplier>>,
+    /// labels in order of registration, owners are registered before owned specs
+    labels: Vec<&'static str>,
+}
+
+impl MetadataStateMachine {
+    pub fn new(storage: LocalMetadataStorage) -> Self {
+        Self {
+            storage,
+            appliers: HashMap::new(),
+            labels: vec![],
+        }
+    }
+
+    /// every replicated spec must be registered, unknown commands are rejected.
+    /// Owners must be registered before the specs they own.
+    pub fn register<S>(&mut self)
+    where
+        S: K8ExtendedSpec,
+        <S as Spec>::Owner: K8ExtendedSpec,
+    {
+        self.appliers
+            .insert(S::LABEL, Box::new(TypedApplier::<S>(PhantomData)));
+        self.labels.push(S::LABEL);
+    }
+
+    pub fn storage(&self) -> &LocalMetadataStorage {
+        &self.storage
+    }
+
+    async fn apply_command(&self, command: &[u8], reapply: bool) -> Result<()> {
+        let command: MetadataCommand = serde_json::from_slice(command)?;
+        trace!(label = command.label, "applying metadata command");
+        let applier = self
+            .appliers
+            .get(command.label.as_str())
+            .ok_or_else(|| anyhow!("unknown metadata kind: {}", command.label))?;
+        applier.apply(&self.storage, command.op, reapply).await
+    }
+}
+
+#[async_trait]
+impl StateMachine for MetadataStateMachine {
+    async fn apply(&self, command: &[u8]) -> Result<()> {
+        self.apply_command(command, false).await
+    }
+
+    async fn reapply(&self, command: &[u8]) -> Result<()> {
+        self.apply_command(command, true).await
+    }
+
+    async fn snapshot(&self) -> Result<Vec<u8>> {
+        let mut snapshot = BTreeMap::new();
+        for label in &self.labels {
+            let applier = &self.appliers[label];
+            snapshot.insert(*label, applier.snapshot(&self.storage).await?);
+        }
+        Ok(serde_json::to_vec(&snapshot)?)
+    }
+
+    async fn restore(&self, snapshot: &[u8]) -> Result<()> {
+        let mut snapshot: BTreeMap<String, Vec<serde_json::Value>> =
+            serde_json::from_slice(snapshot)?;
+        // owned items are deleted with their owners, so owners are restored first
+        for label in &self.labels {
+            let items = snapshot.remove(*label).unwrap_or_default();
+            self.appliers[label].restore(&self.storage, items).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Metadata client replicating writes among SC instances
+pub struct ReplicatedMetadataStorage {
+    node: Arc<RaftNode>,
+    state_machine: Arc<MetadataStateMachine>,
+}
+
+impl ReplicatedMetadataStorage {
+    pub fn new(node: Arc<RaftNode>, state_machine: Arc<MetadataStateMachine>) -> Self {
+        Self {
+            node,
+            state_machine,
+        }
+    }
+
+    async fn propose<S: Spec>(&self, op: MetadataOp<S>) -> Result<()> {
+        self.node.propose(op.encode()?).await
+    }
+
+    async fn retrieve_item<S>(
+        &self,
+        metadata: &LocalMetadataItem,
+    ) -> Result<MetadataStoreObject<S, LocalMetadataItem>>
+    where
+        S: K8ExtendedSpec,
+    {
+        self.state_machine
+            .storage()
+            .retrieve_items::<S>(&NameSpace::All)
+            .await?
+            .items
+            .into_iter()
+            .find(|item| item.ctx().item().uid() == metadata.uid())
+            .ok_or_else(|| anyhow!("'{}' not found", metadata.uid()))
+    }
+}
+
+#[async_trait]
+impl MetadataClient<LocalMetadataItem> for ReplicatedMetadataStorage {
+    async fn retrieve_items<S>(
+        &self,
+        namespace: &NameSpace,
+    ) -> Result<MetadataStoreList<S, LocalMetadataItem>>
+    where
+        S: K8ExtendedSpec,
+    {
+        self.state_machine.storage().retrieve_items(namespace).await
+    }
+
+    async fn delete_item<S>(&self, metadata: LocalMetadataItem) -> Result<()>
+    where
+        S: K8ExtendedSpec,
+    {
+        self.propose(MetadataOp::<S>::Delete { meta: metadata })
+            .await
+    }
+
+    async fn finalize_delete_item<S>(&self, metadata: LocalMetadataItem) -> Result<()>
+    where
+        S: K8ExtendedSpec,
+    {
+        self.delete_item::<S>(metadata).await
+    }
+
+    async fn apply<S>(&self, value: MetadataStoreObject<S, LocalMetadataItem>) -> Result<()>
+    where
+        S: K8ExtendedSpec,
+        <S as Spec>::Owner: K8ExtendedSpec,
+    {
+        let (key, spec, status, ctx) = value.parts();
+        self.propose(MetadataOp::Apply {
+            key: key.to_string(),
+            meta: ctx.into_inner(),
+            spec,
+            status,
+        })
+        .await
+    }
+
+    async fn update_spec<S>(&self, metadata: LocalMetadataItem, spec: S) -> Result<()>
+    where
+        S: K8ExtendedSpec,
+    {
+        self.propose(MetadataOp::UpdateSpec {
+            meta: metadata,
+            spec,
+        })
+        .await
+    }
+
+    async fn update_spec_by_key<S>(
+        &self,
+        key: S::IndexKey,
+        _namespace: &NameSpace,
+        spec: S,
+    ) -> Result<()>
+    where
+        S: K8ExtendedSpec,
+    {
+        self.propose(MetadataOp::UpdateSpecByKey {
+            key: key.to_string(),
+            spec,
+        })
+        .await
+    }
+
+    async fn update_status<S>(
+        &self,
+        metadata: LocalMetadataItem,
+        status: S::Status,
+        _namespace: &NameSpace,
+    ) -> Result<MetadataStoreObject<S, LocalMetadataItem>>
+    where
+        S: K8ExtendedSpec,
+    {
+        self.propose(MetadataOp::<S>::UpdateStatus {
+            meta: metadata.clone(),
+            status,
+        })
+        .await?;
+        self.retrieve_item(&metadata).await
+    }
+
+    fn watch_stream_since<S>(
+        &self,
+        namespace: &NameSpace,
+        resource_version: Option<String>,
+    ) -> BoxStream<'_, Result<Vec<LSUpdate<S, LocalMetadataItem>>>>
+    where
+        S: K8ExtendedSpec,
+    {
+        self.state_machine
+            .storage()
+            .watch_stream_since(namespace, resource_version)
+    }
+
+    async fn patch_status<S>(
+        &self,
+        metadata: LocalMetadataItem,
+        status: S::Status,
+        namespace: &NameSpace,
+    ) -> Result<MetadataStoreObject<S, LocalMetadataItem>>
+    where
+        S: K8ExtendedSpec,
+    {
+        self.update_status(metadata, status, namespace).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use tempfile::TempDir;
+
+    use crate::stores::spu::SpuSpec;
+    use crate::stores::topic::TopicSpec;
+
+    use super::*;
+
+    #[fluvio_future::test]
+    async fn test_state_machine_applies_commands() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let path = temp_dir.path().to_path_buf();
+        let mut state_machine = MetadataStateMachine::new(LocalMetadataStorage::new(&path));
+        state_machine.register::<TopicSpec>();
+
+        let apply = MetadataOp::<TopicSpec>::Apply {
+            key: "topic1".to_string(),
+            meta: LocalMetadataItem::new("topic1"),
+            spec: TopicSpec::new_computed(2, 1, None),
+            status: Default::default(),
+        };
+        state_machine
+            .apply(&apply.encode().expect("encode"))
+            .await
+            .expect("apply");
+        let items = state_machine
+            .storage()
+            .retrieve_items::<TopicSpec>(&NameSpace::All)
+            .await
+            .expect("items")
+            .items;
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].key(), "topic1");
+
+        // spec which is not registered is rejected on every node
+        let unknown = MetadataOp::<SpuSpec>::Delete {
+            meta: LocalMetadataItem::new("spu"),
+        };
+        assert!(state_machine
+            .apply(&unknown.encode().expect("encode"))
+            .await
+            .is_err());
+
+        let delete = MetadataOp::<TopicSpec>::Delete {
+            meta: items[0].ctx().item().clone(),
+        };
+        state_machine
+            .apply(&delete.encode().expect("encode"))
+            .await
+            .expect("delete");
+        assert!(state_machine
+            .storage()
+            .retrieve_items::<TopicSpec>(&NameSpace::All)
+            .await
+            .expect("items")
+            .items
+            .is_empty());
+    }
+
+    #[fluvio_future::test]
+    async fn test_state_machine_reapply_and_snapshot() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let path = temp_dir.path().to_path_buf();
+        let mut state_machine = MetadataStateMachine::new(LocalMetadataStorage::new(&path));
+        state_machine.register::<TopicSpec>();
+
+        let topic = |name: &str| {
+            MetadataOp::<TopicSpec>::Apply {
+                key: name.to_string(),
+                meta: LocalMetadataItem::new(name),
+                spec: TopicSpec::new_computed(1, 1, None),
+                status: Default::default(),
+            }
+            .encode()
+            .expect("encode")
+        };
+        let command = topic("topic1");
+        state_machine.apply(&command).await.expect("apply");
+        state_machine.apply(&command).await.expect("apply");
+        let items = state_machine
+            .storage()
+            .retrieve_items::<TopicSpec>(&NameSpace::All)
+            .await
+            .expect("items")
+            .items;
+        // applying twice bumps revision, reapply of command in effect doesn't
+        assert!(items[0]
+            .ctx()
+            .item()
+            .is_newer(&LocalMetadataItem::new("topic1")));
+        state_machine.reapply(&command).await.expect("reapply");
+        let reapplied = state_machine
+            .storage()
+            .retrieve_items::<TopicSpec>(&NameSpace::All)
+            .await
+            .expect("items")
+            .items;
+        assert_eq!(reapplied, items);
+
+        let snapshot = state_machine.snapshot().await.expect("snapshot");
+
+        let other_dir = TempDir::new().expect("temp dir");
+        let other_path = other_dir.path().to_path_buf();
+        let mut other = MetadataStateMachine::new(LocalMetadataStorage::new(&other_path));
+        other.register::<TopicSpec>();
+        other.apply(&topic("topic2")).await.expect("apply");
+        other.restore(&snapshot).await.expect("restore");
+        let restored = other
+            .storage()
+            .retrieve_items::<TopicSpec>(&NameSpace::All)
+            .await
+            .expect("items")
+            .items;
+        assert_eq!(restored, items);
+    }
+}
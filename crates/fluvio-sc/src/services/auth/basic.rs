@@ -4,21 +4,31 @@ use tracing::instrument;
 use async_trait::async_trait;
 pub use policy::BasicRbacPolicy;
 
-use fluvio_auth::{AuthContext, Authorization, TypeAction, InstanceAction, AuthError};
+use fluvio_auth::{
+    AuthContext, Authorization, TypeAction, InstanceAction, AuthError, Tenant, TenantPolicy,
+};
 use fluvio_controlplane_metadata::extended::ObjectType;
 use fluvio_auth::x509::X509Identity;
 
 #[derive(Debug, Clone)]
 pub struct BasicAuthorization {
     policy: Arc<BasicRbacPolicy>,
+    tenants: Option<Arc<TenantPolicy>>,
 }
 
 impl BasicAuthorization {
     pub fn new(policy: BasicRbacPolicy) -> Self {
         Self {
             policy: Arc::new(policy),
+            tenants: None,
         }
     }
+
+    /// scope objects of principals which belong to tenant
+    pub fn with_tenants(mut self, tenants: TenantPolicy) -> Self {
+        self.tenants = Some(Arc::new(tenants));
+        self
+    }
 }
 
 #[async_trait]
@@ -36,9 +46,15 @@ impl Authorization for BasicAuthorization {
                 tracing::error!(%err, "failed to create x509 identity");
                 err
             })?;
+        let tenant = self
+            .tenants
+            .as_ref()
+            .and_then(|tenants| tenants.tenant_of(&identity.principal));
         Ok(BasicAuthContext {
             identity,
             policy: self.policy.clone(),
+            tenants: self.tenants.clone(),
+            tenant,
         })
     }
 }
@@ -47,6 +63,8 @@ impl Authorization for BasicAuthorization {
 pub struct BasicAuthContext {
     identity: X509Identity,
     policy: Arc<BasicRbacPolicy>,
+    tenants: Option<Arc<TenantPolicy>>,
+    tenant: Option<Tenant>,
 }
 
 #[async_trait]
@@ -61,14 +79,25 @@ impl AuthContext for BasicAuthContext {
             .await
     }
 
-    /// check if specific instance of spec can be deleted
+    /// check if specific instance of spec can be deleted.
+    /// principal of tenant can only act on objects of the tenant
     async fn allow_instance_action(
         &self,
-        _ty: ObjectType,
+        ty: ObjectType,
         _action: InstanceAction,
-        _key: &str,
+        key: &str,
     ) -> Result<bool, AuthError> {
-        Ok(true)
+        Ok(self.allow_tenant_object(&ty, key))
+    }
+
+    fn tenant(&self) -> Option<&Tenant> {
+        self.tenant.as_ref()
+    }
+
+    fn has_tenant(&self, name: &str) -> bool {
+        self.tenants
+            .as_ref()
+            .is_some_and(|tenants| tenants.limits(name).is_some())
     }
 }
 
@@ -278,10 +307,13 @@ mod test {
     use std::convert::TryFrom;
     use std::collections::HashMap;
 
+    use std::sync::Arc;
+
     use fluvio_auth::x509::X509Identity;
+    use fluvio_auth::{AuthContext, InstanceAction, Tenant, TenantConfig, TenantLimits, TenantPolicy};
 
     use super::policy::*;
-    use super::ObjectType;
+    use super::{BasicAuthContext, ObjectType};
 
     #[test]
     fn test_action_urn_serialization() {
@@ -396,4 +428,62 @@ mod test {
             .await
             .expect("eval"));
     }
+
+    #[fluvio_future::test]
+    async fn test_tenant_instance_action() {
+        let auth_context = BasicAuthContext {
+            identity: X509Identity::new("alice".to_owned(), vec!["Root".to_owned()]),
+            policy: Arc::new(BasicRbacPolicy::default()),
+            tenants: None,
+            tenant: Some(Tenant::new("team-a", TenantLimits::default())),
+        };
+
+        assert!(auth_context
+            .allow_instance_action(ObjectType::Topic, InstanceAction::Delete, "team-a.orders")
+            .await
+            .expect("eval"));
+        assert!(!auth_context
+            .allow_instance_action(ObjectType::Topic, InstanceAction::Delete, "team-b.orders")
+            .await
+            .expect("eval"));
+        assert!(!auth_context
+            .allow_instance_action(ObjectType::TableFormat, InstanceAction::Delete, "orders")
+            .await
+            .expect("eval"));
+        assert!(auth_context
+            .allow_instance_action(ObjectType::SpuGroup, InstanceAction::Delete, "main")
+            .await
+            .expect("eval"));
+        assert_eq!(
+            auth_context.tenant().map(|tenant| tenant.name.as_str()),
+            Some("team-a")
+        );
+    }
+
+    #[test]
+    fn test_tenant_name() {
+        let mut tenants = HashMap::new();
+        tenants.insert("team-a".to_owned(), TenantConfig::default());
+        let tenants = Some(Arc::new(TenantPolicy::from(tenants)));
+
+        let tenant_context = BasicAuthContext {
+            identity: X509Identity::new("alice".to_owned(), vec![]),
+            policy: Arc::new(BasicRbacPolicy::default()),
+            tenants: tenants.clone(),
+            tenant: Some(Tenant::new("team-a", TenantLimits::default())),
+        };
+        assert!(tenant_context.allow_tenant_name(&ObjectType::Topic, "team-a.orders"));
+        assert!(!tenant_context.allow_tenant_name(&ObjectType::Topic, "orders"));
+
+        let admin_context = BasicAuthContext {
+            identity: X509Identity::new("admin".to_owned(), vec![]),
+            policy: Arc::new(BasicRbacPolicy::default()),
+            tenants,
+            tenant: None,
+        };
+        assert!(admin_context.allow_tenant_name(&ObjectType::Topic, "orders"));
+        assert!(admin_context.allow_tenant_name(&ObjectType::Topic, "team-a.orders"));
+        assert!(!admin_context.allow_tenant_name(&ObjectType::Topic, "team-x.orders"));
+        assert!(admin_context.allow_tenant_name(&ObjectType::SpuGroup, "team-x.main"));
+    }
 }
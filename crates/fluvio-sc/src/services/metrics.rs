@@ -0,0 +1,82 @@
+//!
+//! # OpenMetrics endpoint of SC
+//!
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tracing::info;
+
+use fluvio_service::metrics::{MetricType, MetricsServer, MetricsSource, MetricsWriter};
+use fluvio_stream_model::core::MetadataItem;
+
+use crate::core::SharedContext;
+
+/// start metrics endpoint if configured
+pub fn start_metrics_server<C>(ctx: SharedContext<C>)
+where
+    C: MetadataItem + 'static,
+{
+    if let Some(addr) = ctx.config().metrics_endpoint.clone() {
+        info!(addr, "starting metrics endpoint");
+        MetricsServer::new(addr, Arc::new(ScMetricsSource(ctx))).run();
+    }
+}
+
+struct ScMetricsSource<C: MetadataItem>(SharedContext<C>);
+
+#[async_trait]
+impl<C> MetricsSource for ScMetricsSource<C>
+where
+    C: MetadataItem + 'static,
+{
+    async fn render(&self, writer: &mut MetricsWriter) {
+        let ctx = &self.0;
+        let controllers = ctx.metrics().controllers();
+
+        writer.family(
+            "fluvio_sc_controller_reconciles",
+            MetricType::Counter,
+            "Reconcile cycles run by controller",
+        );
+        for (controller, metrics) in &controllers {
+            writer.sample(
+                "fluvio_sc_controller_reconciles_total",
+                &[("controller", *controller)],
+                metrics.reconciles(),
+            );
+        }
+
+        writer.family(
+            "fluvio_sc_controller_actions",
+            MetricType::Counter,
+            "Metadata changes generated by controller",
+        );
+        for (controller, metrics) in &controllers {
+            writer.sample(
+                "fluvio_sc_controller_actions_total",
+                &[("controller", *controller)],
+                metrics.actions(),
+            );
+        }
+
+        let spus = ctx.spus().store().clone_values().await;
+        let online = spus.iter().filter(|spu| spu.status.is_online()).count();
+        writer.family("fluvio_sc_spus", MetricType::Gauge, "Registered SPUs");
+        writer.sample("fluvio_sc_spus", &[("status", "online")], online);
+        writer.sample(
+            "fluvio_sc_spus",
+            &[("status", "offline")],
+            spus.len() - online,
+        );
+
+        writer.family("fluvio_sc_topics", MetricType::Gauge, "Topics");
+        writer.sample("fluvio_sc_topics", &[], ctx.topics().store().count().await);
+
+        writer.family("fluvio_sc_partitions", MetricType::Gauge, "Partitions");
+        writer.sample(
+            "fluvio_sc_partitions",
+            &[],
+            ctx.partitions().store().count().await,
+        );
+    }
+}
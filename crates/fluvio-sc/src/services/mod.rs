@@ -1,8 +1,10 @@
 // pub mod send_channels;
 mod public_api;
 mod private_api;
+mod metrics;
 
 pub mod auth;
 
 pub use public_api::start_public_server;
 pub use private_api::start_internal_server;
+pub use metrics::start_metrics_server;
@@ -1,6 +1,10 @@
 mod private_server;
 
+use std::sync::Arc;
+
 use fluvio_stream_model::core::MetadataItem;
+use fluvio_future::net::TcpListener;
+use fluvio_types::event::StickyEvent;
 use tracing::info;
 use tracing::instrument;
 
@@ -12,10 +16,13 @@ use crate::core::SharedContext;
 // start server
 #[instrument(
     name = "sc_private_server"
-    skip(ctx),
+    skip(ctx, listener),
     fields(address = &*ctx.config().private_endpoint)
 )]
-pub fn start_internal_server<C>(ctx: SharedContext<C>)
+pub fn start_internal_server<C>(
+    ctx: SharedContext<C>,
+    listener: Option<TcpListener>,
+) -> Arc<StickyEvent>
 where
     C: MetadataItem + 'static,
 {
@@ -23,5 +30,8 @@ where
 
     let addr = ctx.config().private_endpoint.clone();
     let server = FluvioApiServer::new(addr, ctx, ScInternalService::new());
-    server.run();
+    match listener {
+        Some(listener) => server.run_on(listener),
+        None => server.run(),
+    }
 }
@@ -12,6 +12,7 @@ use fluvio_controlplane::sc_api::api::InternalScKey;
 use fluvio_controlplane::sc_api::api::InternalScRequest;
 use fluvio_controlplane::sc_api::register_spu::RegisterSpuResponse;
 use fluvio_controlplane::sc_api::remove::ReplicaRemovedRequest;
+use fluvio_controlplane::sc_api::shutdown_spu::ShutdownSpuRequest;
 use fluvio_controlplane::sc_api::update_lrs::UpdateLrsRequest;
 use fluvio_controlplane::sc_api::update_mirror::UpdateMirrorStatRequest;
 use fluvio_controlplane::spu_api::update_mirror::MirrorMsg;
@@ -38,6 +39,7 @@ use fluvio_service::{FluvioService, wait_for_request};
 use fluvio_socket::{FluvioSocket, SocketError, FluvioSink};
 
 use crate::core::SharedContext;
+use crate::controllers::partitions::PartitionReducer;
 use crate::stores::partition::PartitonStatusExtension;
 use crate::stores::partition::{PartitionSpec, PartitionStatus, PartitionResolution};
 use crate::stores::spu::SpuLocalStorePolicy;
@@ -177,6 +179,9 @@ where
                             InternalScRequest::UpdateMirrorStatRequest(msg) => {
                                 receive_mirror_update(&context, msg.request).await;
                             },
+                            InternalScRequest::ShutdownSpuRequest(msg) => {
+                                receive_spu_shutdown(&context, spu_id, msg.request).await;
+                            },
                         }
                         // reset timer
                         health_check_timer = sleep(Duration::from_secs(HEALTH_DURATION));
@@ -227,18 +232,25 @@ where
         debug!(?requests, "received lr requests");
     }
     let mut actions = vec![];
+    let mut corrupted = vec![];
     let read_guard = ctx.partitions().store().read().await;
     for lrs_req in requests.into_iter() {
         if let Some(partition) = read_guard.get(&lrs_req.id) {
             let mut current_status = partition.inner().status().clone();
             let key = lrs_req.id.clone();
-            let new_status = PartitionStatus::new2(
+            if let Some(corrupted_offset) = lrs_req.corrupted_offset {
+                warn!(replica = %key, corrupted_offset, "leader reported corrupted batch");
+                corrupted.push((key.clone(), lrs_req.leader.spu));
+            }
+            let mut new_status = PartitionStatus::new2(
                 lrs_req.leader,
                 lrs_req.replicas,
                 lrs_req.size,
                 PartitionResolution::Online,
                 lrs_req.base_offset,
             );
+            new_status.corrupted_offset = lrs_req.corrupted_offset;
+            new_status.in_sync_replicas = lrs_req.in_sync_replicas;
             current_status.merge(new_status);
 
             actions.push(WSAction::<PartitionSpec, C>::UpdateStatus((
@@ -259,6 +271,17 @@ where
     for action in actions.into_iter() {
         ctx.partitions().send_action(action).await;
     }
+
+    if corrupted.is_empty() {
+        return;
+    }
+    let reducer =
+        PartitionReducer::new(ctx.partitions().store().clone(), ctx.spus().store().clone());
+    for (replica, leader) in corrupted {
+        if let Some(action) = reducer.corruption_election(&replica, leader).await {
+            ctx.partitions().send_action(action).await;
+        }
+    }
 }
 
 #[instrument(
@@ -293,6 +316,30 @@ where
     }
 }
 
+/// hand off leadership of partitions of spu which is shutting down
+#[instrument(skip(ctx, request))]
+async fn receive_spu_shutdown<C>(ctx: &SharedContext<C>, spu_id: SpuId, request: ShutdownSpuRequest)
+where
+    C: MetadataItem,
+{
+    if request.spu() != spu_id {
+        warn!(
+            spu = request.spu(),
+            "shutdown request for different spu, ignoring"
+        );
+        return;
+    }
+    debug!("spu is shutting down, handing off leadership");
+
+    let reducer =
+        PartitionReducer::new(ctx.partitions().store().clone(), ctx.spus().store().clone());
+    let actions = reducer.handoff_election(spu_id).await;
+    debug!(actions = actions.len(), "handoff actions");
+    for action in actions.into_iter() {
+        ctx.partitions().send_action(action).await;
+    }
+}
+
 /// send mirror update to metadata stores
 #[instrument(skip(ctx, requests))]
 async fn receive_mirror_update<C>(ctx: &SharedContext<C>, requests: UpdateMirrorStatRequest)
@@ -1,4 +1,5 @@
 use fluvio_sc_schema::mirroring::ObjectMirroringRequest;
+use fluvio_sc_schema::limits::TopicLimitsRequest;
 use tracing::{trace, instrument, debug};
 use semver::Version;
 use once_cell::sync::Lazy;
@@ -66,6 +67,12 @@ pub async fn handle_api_versions_request(
         ObjectApiUpdateRequest::MAX_API_VERSION,
     ));
 
+    response.api_keys.push(make_version_key(
+        AdminPublicApiKey::TopicLimits,
+        TopicLimitsRequest::MIN_API_VERSION,
+        TopicLimitsRequest::MAX_API_VERSION,
+    ));
+
     trace!("flv api versions response: {:#?}", response);
 
     Ok(request.new_response(response))
@@ -1,7 +1,7 @@
 use fluvio_protocol::link::ErrorCode;
 use fluvio_sc_schema::mirror::MirrorSpec;
 use fluvio_stream_model::core::MetadataItem;
-use tracing::{instrument, debug, error};
+use tracing::{instrument, debug, error, Span};
 use anyhow::Result;
 
 use fluvio_controlplane_metadata::smartmodule::SmartModuleSpec;
@@ -13,16 +13,20 @@ use fluvio_protocol::api::{RequestMessage, ResponseMessage};
 use fluvio_sc_schema::{Status, TryEncodableFrom};
 use fluvio_sc_schema::objects::{ObjectApiCreateRequest, CreateRequest};
 use fluvio_auth::AuthContext;
+use fluvio_telemetry::set_parent;
 
 use crate::services::auth::AuthServiceContext;
 
 /// Handler for create topic request
-#[instrument(skip(request, auth_context))]
+#[instrument(skip(request, auth_context), fields(otel.root = true, otel.kind = "server"))]
 pub async fn handle_create_request<AC: AuthContext, C: MetadataItem>(
     request: Box<RequestMessage<ObjectApiCreateRequest>>,
     auth_context: &AuthServiceContext<AC, C>,
 ) -> Result<ResponseMessage<Status>> {
     let (header, req) = request.get_header_request();
+    if let Some(trace_context) = header.trace_context() {
+        set_parent(&Span::current(), trace_context);
+    }
 
     debug!(?req, "create request");
     let status = if let Some(create) = req.downcast()? as Option<CreateRequest<TopicSpec>> {
@@ -8,7 +8,7 @@
 use fluvio_protocol::link::ErrorCode;
 use fluvio_sc_schema::mirror::MirrorSpec;
 use fluvio_stream_model::core::MetadataItem;
-use tracing::{instrument, trace, debug, error};
+use tracing::{instrument, trace, debug, error, Span};
 use anyhow::Result;
 
 use fluvio_controlplane_metadata::smartmodule::SmartModuleSpec;
@@ -20,16 +20,20 @@ use fluvio_protocol::api::{RequestMessage, ResponseMessage};
 use fluvio_sc_schema::{Status, TryEncodableFrom};
 use fluvio_sc_schema::objects::{ObjectApiDeleteRequest, DeleteRequest};
 use fluvio_auth::AuthContext;
+use fluvio_telemetry::set_parent;
 
 use crate::services::auth::AuthServiceContext;
 
 /// Handler for delete topic request
-#[instrument(skip(request, auth_ctx))]
+#[instrument(skip(request, auth_ctx), fields(otel.root = true, otel.kind = "server"))]
 pub async fn handle_delete_request<AC: AuthContext, C: MetadataItem>(
     request: RequestMessage<ObjectApiDeleteRequest>,
     auth_ctx: &AuthServiceContext<AC, C>,
 ) -> Result<ResponseMessage<Status>> {
     let (header, del_req) = request.get_header_request();
+    if let Some(trace_context) = header.trace_context() {
+        set_parent(&Span::current(), trace_context);
+    }
 
     debug!(?del_req, "del request");
 
@@ -0,0 +1,178 @@
+//!
+//! # Topic Limits Request
+//!
+//! Limits of topics and highest usage of each limit by topics visible to the principal.
+//!
+use tracing::{debug, instrument, trace};
+use anyhow::{anyhow, Result};
+
+use fluvio_protocol::api::{RequestMessage, ResponseMessage};
+use fluvio_sc_schema::limits::{
+    LimitUsage, TopicLimit, TopicLimitsRequest, TopicLimitsResponse, TopicLimits,
+};
+use fluvio_controlplane_metadata::extended::SpecExt;
+use fluvio_controlplane_metadata::topic::{partition_size, TopicSpec};
+use fluvio_stream_model::core::MetadataItem;
+use fluvio_auth::{AuthContext, TypeAction};
+
+use crate::services::auth::AuthServiceContext;
+
+use super::topic::spu_replicas;
+
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_topic_limits_request<AC: AuthContext, C: MetadataItem>(
+    request: RequestMessage<TopicLimitsRequest>,
+    auth_ctx: &AuthServiceContext<AC, C>,
+) -> Result<ResponseMessage<TopicLimitsResponse>> {
+    let limits = auth_ctx.global_ctx.current_topic_limits().await;
+
+    if let Ok(authorized) = auth_ctx
+        .auth
+        .allow_type_action(TopicSpec::OBJECT_TYPE, TypeAction::Read)
+        .await
+    {
+        if !authorized {
+            trace!("authorization failed");
+            return Ok(request.new_response(TopicLimitsResponse {
+                limits,
+                usage: vec![],
+            }));
+        }
+    } else {
+        return Err(anyhow!("authorization error"));
+    }
+
+    let topics: Vec<(String, TopicSpec)> = auth_ctx
+        .global_ctx
+        .topics()
+        .store()
+        .read()
+        .await
+        .values()
+        .filter(|topic| {
+            auth_ctx
+                .auth
+                .allow_tenant_object(&TopicSpec::OBJECT_TYPE, topic.key())
+        })
+        .map(|topic| (topic.key().to_owned(), topic.spec.clone()))
+        .collect();
+
+    let mut usage = topic_usage(&limits, &topics);
+
+    // SPUs are shared by all tenants
+    if auth_ctx.auth.tenant().is_none() {
+        let replicas = spu_replicas(&auth_ctx.global_ctx, |_| false).await;
+        if let Some((spu, used)) = replicas.into_iter().max_by_key(|(_, count)| *count) {
+            usage.push(LimitUsage {
+                limit: TopicLimit::PartitionsPerSpu,
+                scope: format!("spu {spu}"),
+                used,
+                max: limits.max_partitions_per_spu.map(|max| max as u64),
+            });
+        }
+    }
+
+    debug!(?usage, "topic limits usage");
+    Ok(request.new_response(TopicLimitsResponse { limits, usage }))
+}
+
+/// highest usage of limits which apply to single topic
+fn topic_usage(limits: &TopicLimits, topics: &[(String, TopicSpec)]) -> Vec<LimitUsage> {
+    let highest = |limit: TopicLimit, max: Option<u64>, value: &dyn Fn(&TopicSpec) -> u64| {
+        topics
+            .iter()
+            .map(|(name, spec)| (name, value(spec)))
+            .max_by_key(|(_, used)| *used)
+            .map(|(name, used)| LimitUsage {
+                limit,
+                scope: name.clone(),
+                used,
+                max,
+            })
+    };
+
+    let disallowed: Vec<&String> = topics
+        .iter()
+        .filter(|(_, spec)| !limits.allows_compression(spec.get_compression_type()))
+        .map(|(name, _)| name)
+        .collect();
+    let compression = LimitUsage {
+        limit: TopicLimit::Compression,
+        scope: disallowed
+            .first()
+            .map(|name| name.to_string())
+            .unwrap_or_default(),
+        used: disallowed.len() as u64,
+        max: (!limits.allowed_compression.is_empty()).then_some(0),
+    };
+
+    [
+        highest(
+            TopicLimit::PartitionsPerTopic,
+            limits.max_partitions_per_topic.map(u64::from),
+            &|spec| spec.replicas().partitions() as u64,
+        ),
+        highest(
+            TopicLimit::ReplicationFactor,
+            limits.max_replication_factor.map(u64::from),
+            &|spec| spec.replicas().replication_factor().unwrap_or_default() as u64,
+        ),
+        highest(
+            TopicLimit::Retention,
+            limits.max_retention.map(|max| max.as_secs()),
+            &|spec| spec.retention_secs() as u64,
+        ),
+        highest(
+            TopicLimit::PartitionSize,
+            limits.max_partition_size,
+            &partition_size,
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .chain(std::iter::once(compression))
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::Duration;
+
+    use fluvio_controlplane_metadata::topic::CompressionAlgorithm;
+
+    use super::*;
+
+    #[test]
+    fn test_topic_usage() {
+        let limits = TopicLimits {
+            max_partitions_per_topic: Some(3),
+            allowed_compression: vec![CompressionAlgorithm::Lz4],
+            max_retention: Some(Duration::from_secs(3600)),
+            ..Default::default()
+        };
+        let mut small = TopicSpec::new_computed(1, 1, None);
+        small.set_compression_type(CompressionAlgorithm::Lz4);
+        let large = TopicSpec::new_computed(5, 2, None);
+        let topics = vec![("small".to_owned(), small), ("large".to_owned(), large)];
+
+        let usage = topic_usage(&limits, &topics);
+
+        let partitions = &usage[0];
+        assert_eq!(partitions.limit, TopicLimit::PartitionsPerTopic);
+        assert_eq!(partitions.scope, "large");
+        assert_eq!(partitions.used, 5);
+        assert!(partitions.is_exceeded());
+
+        let replication = &usage[1];
+        assert_eq!(replication.used, 2);
+        assert_eq!(replication.max, None);
+        assert!(!replication.is_exceeded());
+
+        let compression = usage.last().expect("compression");
+        assert_eq!(compression.limit, TopicLimit::Compression);
+        assert_eq!(compression.scope, "large");
+        assert_eq!(compression.used, 1);
+        assert!(compression.is_exceeded());
+    }
+}
@@ -141,6 +141,11 @@ mod fetch {
         let reader = object_ctx.store().read().await;
         let objects: Vec<Metadata<S>> = reader
             .values()
+            .filter(|value| {
+                auth_ctx
+                    .auth
+                    .allow_tenant_object(&S::OBJECT_TYPE, value.key().as_ref())
+            })
             .filter_map(|value| {
                 if filters.filter(value.key().as_ref()) {
                     let list_obj: Metadata<S> = AdminSpec::convert_from(value);
@@ -14,14 +14,18 @@ mod tableformat;
 mod derivedstream;
 mod mirror;
 mod mirroring;
+mod limits;
 
 pub use server::start_public_server;
 
 mod server {
 
     use std::fmt::Debug;
+    use std::sync::Arc;
 
     use fluvio_stream_model::core::MetadataItem;
+    use fluvio_future::net::TcpListener;
+    use fluvio_types::event::StickyEvent;
     use tracing::debug;
 
     use fluvio_service::FluvioApiServer;
@@ -30,8 +34,11 @@ mod server {
     use crate::services::auth::AuthGlobalContext;
     use super::public_server::PublicService;
 
-    /// create public server
-    pub fn start_public_server<A, C>(ctx: AuthGlobalContext<A, C>)
+    /// create public server, on listener if it is already bound
+    pub fn start_public_server<A, C>(
+        ctx: AuthGlobalContext<A, C>,
+        listener: Option<TcpListener>,
+    ) -> Arc<StickyEvent>
     where
         A: Authorization + Sync + Send + Debug + 'static,
         C: MetadataItem + 'static,
@@ -43,6 +50,9 @@ mod server {
         let addr = ctx.global_ctx.config().public_endpoint.clone();
         debug!("starting public api service");
         let server = FluvioApiServer::new(addr, ctx, PublicService::new());
-        server.run();
+        match listener {
+            Some(listener) => server.run_on(listener),
+            None => server.run(),
+        }
     }
 }
@@ -40,6 +40,11 @@ pub async fn handle_fetch_request<AC: AuthContext, C: MetadataItem>(
         .await
         .values()
         .filter(|value| value.inner().spec().system == system)
+        .filter(|value| {
+            auth_ctx
+                .auth
+                .allow_tenant_object(&PartitionSpec::OBJECT_TYPE, &value.key().topic)
+        })
         .map(|value| value.inner().clone().into())
         .collect();
 
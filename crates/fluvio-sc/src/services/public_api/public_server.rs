@@ -115,6 +115,12 @@ where
                 shared_sink,
                 "list handler"
             ),
+            AdminPublicDecodedRequest::TopicLimitsRequest(request) => call_service!(
+                request,
+                super::limits::handle_topic_limits_request(request, &service_context),
+                shared_sink,
+                "topic limits handler"
+            ),
             AdminPublicDecodedRequest::MirroringRequest(request) =>
                 super::mirroring::handle_mirroring_request(request, service_context.clone(), shared_sink.clone(), end_event.clone())?,
             AdminPublicDecodedRequest::WatchRequest(request) =>
@@ -46,6 +46,23 @@ pub async fn handle_create_smartmodule_request<AC: AuthContext, C: MetadataItem>
         return Err(anyhow!("authorization io error"));
     }
 
+    let store_id = spec
+        .meta
+        .as_ref()
+        .map(|meta| meta.store_id())
+        .unwrap_or_else(|| name.clone());
+    if !auth_ctx
+        .auth
+        .allow_tenant_object(&SmartModuleSpec::OBJECT_TYPE, &store_id)
+    {
+        trace!(%store_id, "name outside of tenant");
+        return Ok(Status::new(
+            name,
+            ErrorCode::PermissionDenied,
+            Some(format!("'{store_id}' is not a name of tenant")),
+        ));
+    }
+
     let status = process_smartmodule_request(&auth_ctx.global_ctx, name, spec).await;
     trace!("create smartmodule response {:#?}", status);
 
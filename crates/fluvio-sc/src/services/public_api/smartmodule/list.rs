@@ -49,6 +49,7 @@ where
     let reader = object_ctx.store().read().await;
     let objects: Vec<Metadata<SmartModuleSpec>> = reader
         .values()
+        .filter(|value| auth.allow_tenant_object(&SmartModuleSpec::OBJECT_TYPE, value.key()))
         .filter_map(|value| {
             //println!("value: {:#?}", value);
             if sm_keys.is_empty()
@@ -90,7 +91,11 @@ mod test {
 
     use std::sync::Arc;
 
+    use async_trait::async_trait;
+
     use fluvio_auth::root::RootAuthContext;
+    use fluvio_auth::{AuthContext, AuthError, InstanceAction, Tenant, TenantLimits, TypeAction};
+    use fluvio_controlplane_metadata::extended::ObjectType;
     use fluvio_stream_dispatcher::store::StoreContext;
     use fluvio_stream_model::fixture::TestMeta;
     use fluvio_stream_model::store::{MetadataStoreObject, LocalStore};
@@ -171,4 +176,54 @@ mod test {
             1
         );
     }
+
+    /// context of principal which belongs to tenant
+    #[derive(Debug)]
+    struct TenantAuthContext(Tenant);
+
+    #[async_trait]
+    impl AuthContext for TenantAuthContext {
+        async fn allow_type_action(
+            &self,
+            _ty: ObjectType,
+            _action: TypeAction,
+        ) -> Result<bool, AuthError> {
+            Ok(true)
+        }
+
+        async fn allow_instance_action(
+            &self,
+            ty: ObjectType,
+            _action: InstanceAction,
+            key: &str,
+        ) -> Result<bool, AuthError> {
+            Ok(self.allow_tenant_object(&ty, key))
+        }
+
+        fn tenant(&self) -> Option<&Tenant> {
+            Some(&self.0)
+        }
+    }
+
+    #[fluvio_future::test]
+    async fn test_sm_search_tenant() {
+        let tenant_auth = TenantAuthContext(Tenant::new("team-a", TenantLimits::default()));
+
+        let test_data = vec![
+            SmartModuleTest::with_spec("sm1", SmartModuleSpec::default()),
+            SmartModuleTest::with_spec("team-a.sm1", SmartModuleSpec::default()),
+            SmartModuleTest::with_spec("team-b.sm1", SmartModuleSpec::default()),
+        ];
+
+        let local_sm_store = TestSmartModuleStore::default();
+        _ = local_sm_store.sync_all(test_data).await;
+        let sm_ctx = StoreContext::new_with_store(Arc::new(local_sm_store));
+
+        let found = fetch_smart_modules(vec![], false, &tenant_auth, &sm_ctx)
+            .await
+            .expect("search")
+            .inner();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].name, "team-a.sm1");
+    }
 }
@@ -61,6 +61,18 @@ pub async fn handle_create_tableformat_request<AC: AuthContext, C: MetadataItem>
         return Err(anyhow!("authorization io error"));
     }
 
+    if !auth_ctx
+        .auth
+        .allow_tenant_name(&TableFormatSpec::OBJECT_TYPE, &name)
+    {
+        trace!("name outside of tenant");
+        return Ok(Status::new(
+            name.clone(),
+            ErrorCode::PermissionDenied,
+            Some(format!("'{name}' is not a name of tenant")),
+        ));
+    }
+
     let status = process_tableformat_request(&auth_ctx.global_ctx, name, spec).await;
     trace!("create tableformat response {:#?}", status);
 
@@ -15,9 +15,9 @@ use tracing::{info, debug, trace, instrument};
 use anyhow::{anyhow, Result};
 
 use fluvio_protocol::link::ErrorCode;
-use fluvio_controlplane_metadata::topic::ReplicaSpec;
+use fluvio_controlplane_metadata::topic::{Deduplication, ReplicaSpec};
 use fluvio_sc_schema::objects::CreateRequest;
-use fluvio_sc_schema::shared::validate_resource_name;
+use fluvio_sc_schema::shared::validate_qualified_resource_name;
 use fluvio_sc_schema::Status;
 use fluvio_sc_schema::topic::TopicSpec;
 use fluvio_auth::{AuthContext, TypeAction};
@@ -32,6 +32,8 @@ use crate::controllers::topics::policy::{
 use crate::core::Context;
 use crate::services::auth::AuthServiceContext;
 
+use super::{check_tenant_limits, check_topic_limits};
+
 /// Handler for create topic request
 #[instrument(skip(req, auth_ctx))]
 pub(crate) async fn handle_create_topics_request<AC: AuthContext, C: MetadataItem>(
@@ -60,12 +62,37 @@ pub(crate) async fn handle_create_topics_request<AC: AuthContext, C: MetadataIte
         return Err(anyhow!("authorization io error"));
     }
 
+    // topic and its aliases must be named in tenant of principal
+    if let Some(denied) = std::iter::once(&name).chain(topic.aliases()).find(|name| {
+        !auth_ctx
+            .auth
+            .allow_tenant_name(&TopicSpec::OBJECT_TYPE, name)
+    }) {
+        trace!(%denied, "name outside of tenant");
+        return Ok(Status::new(
+            name.clone(),
+            ErrorCode::PermissionDenied,
+            Some(format!("'{denied}' is not a name of tenant")),
+        ));
+    }
+
     // validate topic request
     let mut status = validate_topic_request::<C>(&name, &topic, &auth_ctx.global_ctx).await;
     if status.is_error() {
         return Ok(status);
     }
 
+    if let Err(status) = check_topic_limits(&name, &topic, &auth_ctx.global_ctx).await {
+        return Ok(status);
+    }
+
+    if let Some(tenant) = auth_ctx.auth.tenant() {
+        if let Err(status) = check_tenant_limits(tenant, &name, &topic, &auth_ctx.global_ctx).await
+        {
+            return Ok(status);
+        }
+    }
+
     if !create.dry_run {
         status = process_topic_request(auth_ctx, name, topic).await;
     }
@@ -83,7 +110,7 @@ async fn validate_topic_request<C: MetadataItem>(
 ) -> Status {
     debug!("validating topic: {}", name);
 
-    if let Err(err) = validate_resource_name(name) {
+    if let Err(err) = validate_qualified_resource_name(name) {
         return Status::new(
             name.to_string(),
             ErrorCode::TopicInvalidName,
@@ -103,6 +130,18 @@ async fn validate_topic_request<C: MetadataItem>(
         );
     }
 
+    if let Some(topic) = topic_using_name(name, metadata).await {
+        return Status::new(
+            name.to_string(),
+            ErrorCode::TopicAlreadyExists,
+            Some(format!("'{name}' is already an alias of topic '{topic}'")),
+        );
+    }
+
+    if let Err(status) = validate_aliases(name, topic_spec.aliases(), None, metadata).await {
+        return status;
+    }
+
     // check configuration
     if let Some(error) = topic_spec.validate_config() {
         return Status::new(
@@ -114,26 +153,8 @@ async fn validate_topic_request<C: MetadataItem>(
 
     // check if deduplication filter is present
     if let Some(deduplication) = topic_spec.get_deduplication() {
-        let sm_name = deduplication.filter.transform.uses.as_str();
-        let sm_fqdn = match SmartModulePackageKey::from_qualified_name(sm_name) {
-            Ok(fqdn) => fqdn.store_id(),
-            Err(err) => {
-                return Status::new(
-                    sm_name.to_string(),
-                    ErrorCode::DeduplicationSmartModuleNameInvalid(err.to_string()),
-                    Some(err.to_string()),
-                )
-            }
-        };
-        if !metadata.smartmodules().store().contains_key(&sm_fqdn).await {
-            return Status::new(
-                sm_name.to_string(),
-                ErrorCode::DeduplicationSmartModuleNotLoaded,
-                Some(format!(
-                    "{}\nHint: try `fluvio hub download {sm_name}` and repeat this operation",
-                    ErrorCode::DeduplicationSmartModuleNotLoaded
-                )),
-            );
+        if let Err(status) = validate_deduplication(deduplication, metadata).await {
+            return status;
         }
     }
 
@@ -191,6 +212,94 @@ async fn validate_topic_request<C: MetadataItem>(
     }
 }
 
+/// Topic which uses name either as its name or as an alias,
+/// including the name and aliases it is being renamed to
+pub(crate) async fn topic_using_name<C: MetadataItem>(
+    name: &str,
+    metadata: &Context<C>,
+) -> Option<String> {
+    metadata
+        .topics()
+        .store()
+        .read()
+        .await
+        .values()
+        .find(|topic| {
+            topic.key() == name
+                || topic.spec.has_alias(name)
+                || topic.status.renaming_to.as_ref().is_some_and(|target| {
+                    target.name == name || target.aliases.iter().any(|alias| alias == name)
+                })
+        })
+        .map(|topic| topic.key().to_owned())
+}
+
+/// Validate aliases of topic `name`. Aliases must be valid names, which are not used by
+/// other topics than `owner`
+pub(crate) async fn validate_aliases<C: MetadataItem>(
+    name: &str,
+    aliases: &[String],
+    owner: Option<&str>,
+    metadata: &Context<C>,
+) -> Result<(), Status> {
+    for (idx, alias) in aliases.iter().enumerate() {
+        if let Err(err) = validate_qualified_resource_name(alias) {
+            return Err(Status::new(
+                name.to_string(),
+                ErrorCode::TopicInvalidName,
+                Some(format!("Invalid alias: '{alias}'. {err}")),
+            ));
+        }
+        if alias == name || aliases[..idx].contains(alias) {
+            return Err(Status::new(
+                name.to_string(),
+                ErrorCode::TopicInvalidName,
+                Some(format!("Duplicated alias: '{alias}'")),
+            ));
+        }
+        if let Some(topic) = topic_using_name(alias, metadata)
+            .await
+            .filter(|topic| Some(topic.as_str()) != owner)
+        {
+            return Err(Status::new(
+                name.to_string(),
+                ErrorCode::TopicAlreadyExists,
+                Some(format!("'{alias}' is already used by topic '{topic}'")),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Validate that SmartModule used by deduplication is loaded
+pub(crate) async fn validate_deduplication<C: MetadataItem>(
+    deduplication: &Deduplication,
+    metadata: &Context<C>,
+) -> Result<(), Status> {
+    let sm_name = deduplication.filter.transform.uses.as_str();
+    let sm_fqdn = match SmartModulePackageKey::from_qualified_name(sm_name) {
+        Ok(fqdn) => fqdn.store_id(),
+        Err(err) => {
+            return Err(Status::new(
+                sm_name.to_string(),
+                ErrorCode::DeduplicationSmartModuleNameInvalid(err.to_string()),
+                Some(err.to_string()),
+            ))
+        }
+    };
+    if !metadata.smartmodules().store().contains_key(&sm_fqdn).await {
+        return Err(Status::new(
+            sm_name.to_string(),
+            ErrorCode::DeduplicationSmartModuleNotLoaded,
+            Some(format!(
+                "{}\nHint: try `fluvio hub download {sm_name}` and repeat this operation",
+                ErrorCode::DeduplicationSmartModuleNotLoaded
+            )),
+        ));
+    }
+    Ok(())
+}
+
 /// create new topic and wait until all partitions are fully provisioned
 /// if any partitions are not provisioned in time, this will generate error
 async fn process_topic_request<AC: AuthContext, C: MetadataItem>(
@@ -39,6 +39,11 @@ pub async fn handle_fetch_topics_request<AC: AuthContext, C: MetadataItem>(
         .await
         .values()
         .filter(|value| value.inner().spec().is_system() == system)
+        .filter(|value| {
+            auth_ctx
+                .auth
+                .allow_tenant_object(&TopicSpec::OBJECT_TYPE, value.key())
+        })
         .filter_map(|value| {
             if filters.filter(value.key()) {
                 Some(value.inner().clone().into())
@@ -0,0 +1,156 @@
+//!
+//! # Topic Limits
+//!
+//! Topics must be within cluster limits stored in metadata. Limits are checked
+//! when a topic is created or changed, topic controller checks them again for topics created by other means.
+//!
+use std::collections::BTreeMap;
+
+use fluvio_protocol::link::ErrorCode;
+use fluvio_sc_schema::Status;
+use fluvio_controlplane_metadata::topic::{ReplicaSpec, TopicSpec};
+use fluvio_stream_model::core::MetadataItem;
+use fluvio_types::SpuId;
+
+use crate::core::Context;
+use crate::stores::spu::SpuLocalStorePolicy;
+
+/// check that topic `name` is within cluster limits, if it is created or changed to `spec`
+pub(crate) async fn check_topic_limits<C: MetadataItem>(
+    name: &str,
+    spec: &TopicSpec,
+    metadata: &Context<C>,
+) -> Result<(), Status> {
+    let limits = metadata.current_topic_limits().await;
+    if limits.is_unlimited() {
+        return Ok(());
+    }
+
+    let violation = match limits.check_topic(spec) {
+        Err(violation) => Some(violation),
+        Ok(()) => match limits.max_partitions_per_spu {
+            Some(max) => check_spu_replicas(name, spec, max, metadata).await,
+            None => None,
+        },
+    };
+
+    match violation {
+        Some(violation) => Err(Status::new(
+            name.to_owned(),
+            ErrorCode::TopicLimitViolation(violation.clone()),
+            Some(violation),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// replicas hosted by each SPU, without replicas of partitions of topics rejected by `skip`
+pub(crate) async fn spu_replicas<C: MetadataItem>(
+    metadata: &Context<C>,
+    skip: impl Fn(&str) -> bool,
+) -> BTreeMap<SpuId, u64> {
+    let mut replicas: BTreeMap<SpuId, u64> = metadata
+        .spus()
+        .store()
+        .spu_ids()
+        .await
+        .into_iter()
+        .map(|id| (id, 0))
+        .collect();
+    for partition in metadata.partitions().store().read().await.values() {
+        if skip(&partition.key.topic) {
+            continue;
+        }
+        for spu in partition.spec.replicas.iter() {
+            *replicas.entry(*spu).or_default() += 1;
+        }
+    }
+    replicas
+}
+
+/// check that no SPU hosts more than `max` replicas with partitions of topic
+async fn check_spu_replicas<C: MetadataItem>(
+    name: &str,
+    spec: &TopicSpec,
+    max: u32,
+    metadata: &Context<C>,
+) -> Option<String> {
+    let max = max as u64;
+    let violation = |spu: SpuId, count: u64| {
+        format!("spu {spu} would host {count} partition replicas, at most {max} replicas per spu are allowed")
+    };
+
+    match spec.replicas() {
+        ReplicaSpec::Computed(param) => {
+            let replicas = spu_replicas(metadata, |_| false).await;
+            let existing = metadata
+                .partitions()
+                .store()
+                .read()
+                .await
+                .values()
+                .filter(|partition| partition.key.topic == name)
+                .count() as u64;
+            let added = (param.partitions as u64)
+                .saturating_sub(existing)
+                .saturating_mul(param.replication_factor as u64);
+            if added == 0 {
+                return None;
+            }
+            let (spu, count) = max_spu_replicas(&replicas, added)?;
+            (count > max).then(|| violation(spu, count))
+        }
+        ReplicaSpec::Assigned(partition_maps) => {
+            let mut replicas = spu_replicas(metadata, |topic| topic == name).await;
+            let assigned = partition_maps.unique_spus_in_partition_map();
+            for map in partition_maps.maps() {
+                for spu in map.replicas.iter() {
+                    *replicas.entry(*spu).or_default() += 1;
+                }
+            }
+            replicas
+                .into_iter()
+                .filter(|(spu, count)| assigned.contains(spu) && *count > max)
+                .map(|(spu, count)| violation(spu, count))
+                .next()
+        }
+        // replicas of mirror topics are assigned by the remote cluster
+        ReplicaSpec::Mirror(_) => None,
+    }
+}
+
+/// SPU with most replicas after `added` replicas are assigned to the least loaded SPUs
+fn max_spu_replicas(replicas: &BTreeMap<SpuId, u64>, added: u64) -> Option<(SpuId, u64)> {
+    let (busiest, current) = replicas
+        .iter()
+        .max_by_key(|(_, count)| **count)
+        .map(|(spu, count)| (*spu, *count))?;
+    let total = replicas.values().sum::<u64>().saturating_add(added);
+    let spread = total.div_ceil(replicas.len() as u64);
+    if spread > current {
+        // least loaded SPUs are filled up to the spread
+        let least = replicas
+            .iter()
+            .min_by_key(|(_, count)| **count)
+            .map(|(spu, _)| *spu)
+            .unwrap_or(busiest);
+        Some((least, spread))
+    } else {
+        Some((busiest, current))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_max_spu_replicas() {
+        let replicas: BTreeMap<SpuId, u64> = [(5001, 4), (5002, 1), (5003, 1)].into();
+        assert_eq!(max_spu_replicas(&replicas, 3), Some((5001, 4)));
+        assert_eq!(max_spu_replicas(&replicas, 6), Some((5001, 4)));
+        assert_eq!(max_spu_replicas(&replicas, 7), Some((5002, 5)));
+        assert_eq!(max_spu_replicas(&BTreeMap::new(), 7), None);
+    }
+}
@@ -1,8 +1,12 @@
 mod create;
 mod delete;
 mod fetch;
+mod limits;
+mod tenant;
 pub mod update;
 
 pub(crate) use create::*;
 pub(crate) use delete::*;
 pub(crate) use fetch::*;
+pub(crate) use limits::*;
+pub(crate) use tenant::*;
@@ -0,0 +1,125 @@
+//!
+//! # Tenant Limits
+//!
+//! Partitions and storage reserved by all topics of tenant must be within its limits.
+//! Limits are checked when a topic of tenant is created or changed.
+//!
+use fluvio_protocol::link::ErrorCode;
+use fluvio_sc_schema::Status;
+use fluvio_controlplane_metadata::topic::TopicSpec;
+use fluvio_stream_model::core::MetadataItem;
+use fluvio_types::defaults::SPU_PARTITION_MAX_BYTES;
+use fluvio_auth::Tenant;
+
+use crate::core::Context;
+
+/// partitions and bytes of storage reserved by topic, including replicas
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TopicUsage {
+    pub partitions: u64,
+    pub storage: u64,
+}
+
+impl TopicUsage {
+    pub fn of(spec: &TopicSpec) -> Self {
+        let partitions = spec.replicas().partitions() as u64;
+        let replication = spec.replicas().replication_factor().unwrap_or(1).max(1) as u64;
+        let partition_size = spec
+            .get_storage()
+            .and_then(|storage| storage.max_partition_size)
+            .unwrap_or(SPU_PARTITION_MAX_BYTES);
+        Self {
+            partitions,
+            storage: partitions
+                .saturating_mul(replication)
+                .saturating_mul(partition_size),
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            partitions: self.partitions.saturating_add(other.partitions),
+            storage: self.storage.saturating_add(other.storage),
+        }
+    }
+}
+
+/// check that tenant is within its limits, if topic `name` is created or changed to `spec`
+pub(crate) async fn check_tenant_limits<C: MetadataItem>(
+    tenant: &Tenant,
+    name: &str,
+    spec: &TopicSpec,
+    metadata: &Context<C>,
+) -> Result<(), Status> {
+    let limits = &tenant.limits;
+    if limits.max_partitions.is_none() && limits.max_storage.is_none() {
+        return Ok(());
+    }
+
+    let usage = metadata
+        .topics()
+        .store()
+        .read()
+        .await
+        .values()
+        .filter(|topic| tenant.owns(topic.key()) && topic.key() != name)
+        .fold(TopicUsage::of(spec), |usage, topic| {
+            usage.add(TopicUsage::of(&topic.spec))
+        });
+
+    let exceeded = if let Some(max) = limits
+        .max_partitions
+        .filter(|max| usage.partitions > *max as u64)
+    {
+        Some(format!("{} partitions, max {max}", usage.partitions))
+    } else {
+        limits
+            .max_storage
+            .filter(|max| usage.storage > *max)
+            .map(|max| format!("{} bytes of storage, max {max}", usage.storage))
+    };
+
+    match exceeded {
+        Some(limit) => Err(Status::new(
+            name.to_owned(),
+            ErrorCode::TenantLimitExceeded {
+                tenant: tenant.name.clone(),
+                limit: limit.clone(),
+            },
+            Some(format!("tenant '{}' would use {limit}", tenant.name)),
+        )),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use fluvio_controlplane_metadata::topic::TopicStorageConfig;
+
+    use super::*;
+
+    #[test]
+    fn test_topic_usage() {
+        let mut spec = TopicSpec::new_computed(3, 2, None);
+        assert_eq!(
+            TopicUsage::of(&spec),
+            TopicUsage {
+                partitions: 3,
+                storage: 6 * SPU_PARTITION_MAX_BYTES
+            }
+        );
+
+        spec.set_storage(TopicStorageConfig {
+            max_partition_size: Some(1024),
+            ..Default::default()
+        });
+        assert_eq!(
+            TopicUsage::of(&spec),
+            TopicUsage {
+                partitions: 3,
+                storage: 6 * 1024
+            }
+        );
+    }
+}
@@ -16,6 +16,8 @@ use fluvio_auth::AuthContext;
 
 use crate::services::auth::AuthServiceContext;
 
+use super::super::{check_tenant_limits, check_topic_limits};
+
 /// Handler for add partition request
 #[instrument(skip(request, auth_ctx))]
 pub async fn handle_add_partition<AC: AuthContext, C: MetadataItem>(
@@ -59,6 +61,18 @@ pub async fn handle_add_partition<AC: AuthContext, C: MetadataItem>(
             }
         }
 
+        if let Err(status) = check_topic_limits(&topic_name, &spec, &auth_ctx.global_ctx).await {
+            return Ok(status);
+        }
+
+        if let Some(tenant) = auth_ctx.auth.tenant() {
+            if let Err(status) =
+                check_tenant_limits(tenant, &topic_name, &spec, &auth_ctx.global_ctx).await
+            {
+                return Ok(status);
+            }
+        }
+
         auth_ctx
             .global_ctx
             .topics()
@@ -0,0 +1,132 @@
+//!
+//! # Topic Alias Requests
+//!
+use std::io::Error;
+
+use tracing::{debug, instrument};
+
+use fluvio_protocol::link::ErrorCode;
+use fluvio_sc_schema::{topic::TopicAlias, Status};
+use fluvio_stream_model::core::{MetadataItem, Spec};
+use fluvio_controlplane_metadata::extended::SpecExt;
+use fluvio_controlplane_metadata::topic::TopicSpec;
+use fluvio_auth::AuthContext;
+
+use crate::services::auth::AuthServiceContext;
+
+use super::super::validate_aliases;
+
+/// Handler for add alias request
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_add_alias<AC: AuthContext, C: MetadataItem>(
+    topic_name: String,
+    request: TopicAlias,
+    auth_ctx: &AuthServiceContext<AC, C>,
+) -> Result<Status, Error> {
+    let mut spec = match topic_spec(&topic_name, auth_ctx).await {
+        Ok(spec) => spec,
+        Err(status) => return Ok(status),
+    };
+
+    if spec.has_alias(&request.name) {
+        debug!(%topic_name, alias = %request.name, "alias already exists");
+        return Ok(Status::new_ok(topic_name));
+    }
+
+    if !auth_ctx
+        .auth
+        .allow_tenant_name(&TopicSpec::OBJECT_TYPE, &request.name)
+    {
+        return Ok(Status::new(
+            topic_name,
+            ErrorCode::PermissionDenied,
+            Some(format!("'{}' is not a name of tenant", request.name)),
+        ));
+    }
+
+    let mut aliases = spec.aliases().to_vec();
+    aliases.push(request.name);
+    if let Err(status) = validate_aliases(
+        &topic_name,
+        &aliases,
+        Some(&topic_name),
+        &auth_ctx.global_ctx,
+    )
+    .await
+    {
+        return Ok(status);
+    }
+    spec.set_aliases(aliases);
+
+    auth_ctx
+        .global_ctx
+        .topics()
+        .create_spec(topic_name.clone(), spec)
+        .await?;
+
+    Ok(Status::new_ok(topic_name))
+}
+
+/// Handler for remove alias request
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_remove_alias<AC: AuthContext, C: MetadataItem>(
+    topic_name: String,
+    request: TopicAlias,
+    auth_ctx: &AuthServiceContext<AC, C>,
+) -> Result<Status, Error> {
+    let mut spec = match topic_spec(&topic_name, auth_ctx).await {
+        Ok(spec) => spec,
+        Err(status) => return Ok(status),
+    };
+
+    if !spec.has_alias(&request.name) {
+        return Ok(Status::new(
+            topic_name,
+            ErrorCode::TopicError,
+            Some(format!("alias '{}' not found", request.name)),
+        ));
+    }
+
+    let aliases = spec
+        .aliases()
+        .iter()
+        .filter(|alias| **alias != request.name)
+        .cloned()
+        .collect();
+    spec.set_aliases(aliases);
+
+    auth_ctx
+        .global_ctx
+        .topics()
+        .create_spec(topic_name.clone(), spec)
+        .await?;
+
+    Ok(Status::new_ok(topic_name))
+}
+
+/// spec of topic which aliases can be changed
+async fn topic_spec<AC: AuthContext, C: MetadataItem>(
+    topic_name: &str,
+    auth_ctx: &AuthServiceContext<AC, C>,
+) -> Result<TopicSpec, Status> {
+    let Some(topic) = auth_ctx.global_ctx.topics().store().value(topic_name).await else {
+        return Err(Status::new(
+            topic_name.to_owned(),
+            ErrorCode::TopicNotFound,
+            Some("not found".to_owned()),
+        ));
+    };
+
+    if topic.spec().is_system() {
+        return Err(Status::new(
+            topic_name.to_owned(),
+            ErrorCode::SystemSpecUpdatingAttempt {
+                kind: TopicSpec::LABEL.to_lowercase(),
+                name: topic_name.to_owned(),
+            },
+            None,
+        ));
+    }
+
+    Ok(topic.spec().clone())
+}
@@ -1,5 +1,8 @@
 mod add_partition;
 mod add_mirror;
+mod update_config;
+mod alias;
+mod rename;
 
 use std::io::{Error, ErrorKind};
 
@@ -49,6 +52,16 @@ pub async fn handle_topic_update_request<AC: AuthContext, C: MetadataItem>(
         UpdateTopicAction::AddMirror(req) => {
             add_mirror::handle_add_mirror(topic_name, req, auth_ctx).await?
         }
+        UpdateTopicAction::UpdateConfig(req) => {
+            update_config::handle_update_config(topic_name, req, auth_ctx).await?
+        }
+        UpdateTopicAction::AddAlias(req) => {
+            alias::handle_add_alias(topic_name, req, auth_ctx).await?
+        }
+        UpdateTopicAction::RemoveAlias(req) => {
+            alias::handle_remove_alias(topic_name, req, auth_ctx).await?
+        }
+        UpdateTopicAction::Rename(req) => rename::handle_rename(topic_name, req, auth_ctx).await?,
     };
 
     Ok(status)
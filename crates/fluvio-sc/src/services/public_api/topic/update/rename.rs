@@ -0,0 +1,143 @@
+//!
+//! # Rename Topic Request
+//!
+//! Rename is requested by a single status update of the topic.
+//! Rename controller creates a new topic with the same replica assignment,
+//! whose partitions are marked as renamed, so SPUs fence old partitions and move
+//! their storage and consumer offsets to them.
+//! Request returns once rename is requested, rename controller deletes old topic
+//! when all new partitions are online.
+//!
+use std::io::Error;
+
+use tracing::{info, instrument};
+
+use fluvio_protocol::link::ErrorCode;
+use fluvio_sc_schema::{
+    shared::validate_qualified_resource_name,
+    topic::{RenameTopic, ReplicaSpec, TopicResolution},
+    Status,
+};
+use fluvio_stream_model::core::{MetadataItem, Spec};
+use fluvio_controlplane_metadata::extended::SpecExt;
+use fluvio_controlplane_metadata::topic::{TopicRenameTarget, TopicSpec};
+use fluvio_auth::AuthContext;
+
+use crate::services::auth::AuthServiceContext;
+
+use super::super::{topic_using_name, validate_aliases};
+
+/// Handler for rename topic request
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_rename<AC: AuthContext, C: MetadataItem>(
+    topic_name: String,
+    request: RenameTopic,
+    auth_ctx: &AuthServiceContext<AC, C>,
+) -> Result<Status, Error> {
+    let ctx = &auth_ctx.global_ctx;
+    let new_name = request.new_name;
+
+    let Some(topic) = ctx.topics().store().value(&topic_name).await else {
+        return Ok(Status::new(
+            topic_name,
+            ErrorCode::TopicNotFound,
+            Some("not found".to_owned()),
+        ));
+    };
+
+    if topic.spec().is_system() {
+        return Ok(Status::new(
+            topic_name.clone(),
+            ErrorCode::SystemSpecUpdatingAttempt {
+                kind: TopicSpec::LABEL.to_lowercase(),
+                name: topic_name,
+            },
+            None,
+        ));
+    }
+
+    if let ReplicaSpec::Mirror(_) = topic.spec().replicas() {
+        return Ok(Status::new(
+            topic_name,
+            ErrorCode::TopicInvalidReplicaType,
+            Some("mirror topic can't be renamed".to_owned()),
+        ));
+    }
+
+    let rename_in_progress = ctx
+        .topics()
+        .store()
+        .read()
+        .await
+        .values()
+        .any(|other| other.status.renamed_from.as_deref() == Some(topic_name.as_str()));
+    if topic.status.resolution != TopicResolution::Provisioned
+        || topic.status.renamed_from.is_some()
+        || topic.status.renaming_to.is_some()
+        || rename_in_progress
+    {
+        return Ok(Status::new(
+            topic_name,
+            ErrorCode::TopicError,
+            Some("topic is not provisioned or is being renamed".to_owned()),
+        ));
+    }
+
+    if let Err(err) = validate_qualified_resource_name(&new_name) {
+        return Ok(Status::new(
+            topic_name,
+            ErrorCode::TopicInvalidName,
+            Some(format!("Invalid topic name: '{new_name}'. {err}")),
+        ));
+    }
+
+    if !auth_ctx
+        .auth
+        .allow_tenant_name(&TopicSpec::OBJECT_TYPE, &new_name)
+    {
+        return Ok(Status::new(
+            topic_name,
+            ErrorCode::PermissionDenied,
+            Some(format!("'{new_name}' is not a name of tenant")),
+        ));
+    }
+
+    // new name can be an alias of the renamed topic
+    if let Some(owner) = topic_using_name(&new_name, ctx)
+        .await
+        .filter(|owner| *owner != topic_name || new_name == topic_name)
+    {
+        return Ok(Status::new(
+            topic_name,
+            ErrorCode::TopicAlreadyExists,
+            Some(format!("'{new_name}' is already used by topic '{owner}'")),
+        ));
+    }
+
+    let mut aliases: Vec<String> = topic
+        .spec()
+        .aliases()
+        .iter()
+        .filter(|alias| **alias != new_name)
+        .cloned()
+        .collect();
+    if request.keep_alias {
+        aliases.push(topic_name.clone());
+    }
+    if let Err(status) = validate_aliases(&new_name, &aliases, Some(&topic_name), ctx).await {
+        return Ok(status);
+    }
+
+    // rename controller creates the new topic and finishes rename
+    let mut status = topic.status.clone();
+    status.renaming_to = Some(TopicRenameTarget {
+        name: new_name.clone(),
+        aliases,
+    });
+    ctx.topics()
+        .update_status(topic_name.clone(), status)
+        .await?;
+
+    info!(%topic_name, %new_name, "topic rename requested");
+    Ok(Status::new_ok(topic_name))
+}
@@ -0,0 +1,98 @@
+//!
+//! # Update Topic Config Request
+//!
+use std::io::Error;
+
+use tracing::{debug, instrument};
+
+use fluvio_protocol::link::ErrorCode;
+use fluvio_sc_schema::{topic::UpdateTopicConfig, Status};
+use fluvio_stream_model::core::{MetadataItem, Spec};
+use fluvio_controlplane_metadata::topic::TopicSpec;
+use fluvio_auth::AuthContext;
+
+use crate::services::auth::AuthServiceContext;
+
+use super::super::{check_tenant_limits, check_topic_limits, validate_deduplication};
+
+/// Handler for update topic config request
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_update_config<AC: AuthContext, C: MetadataItem>(
+    topic_name: String,
+    request: UpdateTopicConfig,
+    auth_ctx: &AuthServiceContext<AC, C>,
+) -> Result<Status, Error> {
+    let topic = auth_ctx
+        .global_ctx
+        .topics()
+        .store()
+        .value(&topic_name)
+        .await;
+
+    let Some(topic) = topic else {
+        // topic does not exist
+        return Ok(Status::new(
+            topic_name,
+            ErrorCode::TopicNotFound,
+            Some("not found".to_owned()),
+        ));
+    };
+
+    let mut spec = topic.spec().clone();
+
+    if spec.is_system() {
+        return Ok(Status::new(
+            topic_name.clone(),
+            ErrorCode::SystemSpecUpdatingAttempt {
+                kind: TopicSpec::LABEL.to_lowercase(),
+                name: topic_name,
+            },
+            None,
+        ));
+    };
+
+    request.apply_to(&mut spec);
+
+    if let Some(error) = spec.validate_config() {
+        return Ok(Status::new(
+            topic_name,
+            ErrorCode::TopicInvalidConfiguration,
+            Some(error),
+        ));
+    }
+
+    // only a new deduplication must be checked, SmartModule of current one may have been removed since
+    if let Some(deduplication) = spec
+        .get_deduplication()
+        .filter(|_| request.deduplication.is_some())
+    {
+        if let Err(status) = validate_deduplication(deduplication, &auth_ctx.global_ctx).await {
+            return Ok(status);
+        }
+    }
+
+    if spec == *topic.spec() {
+        debug!(%topic_name, "topic config unchanged");
+        return Ok(Status::new_ok(topic_name));
+    }
+
+    if let Err(status) = check_topic_limits(&topic_name, &spec, &auth_ctx.global_ctx).await {
+        return Ok(status);
+    }
+
+    if let Some(tenant) = auth_ctx.auth.tenant() {
+        if let Err(status) =
+            check_tenant_limits(tenant, &topic_name, &spec, &auth_ctx.global_ctx).await
+        {
+            return Ok(status);
+        }
+    }
+
+    auth_ctx
+        .global_ctx
+        .topics()
+        .create_spec(topic.key.clone(), spec)
+        .await?;
+
+    Ok(Status::new_ok(topic_name))
+}
@@ -6,7 +6,7 @@
 //!
 
 use anyhow::Result;
-use tracing::{instrument, trace, debug, error};
+use tracing::{instrument, trace, debug, error, Span};
 
 use fluvio_protocol::link::ErrorCode;
 use fluvio_stream_model::core::MetadataItem;
@@ -15,16 +15,20 @@ use fluvio_protocol::api::{RequestMessage, ResponseMessage};
 use fluvio_sc_schema::{Status, TryEncodableFrom};
 use fluvio_sc_schema::objects::{ObjectApiUpdateRequest, UpdateRequest};
 use fluvio_auth::AuthContext;
+use fluvio_telemetry::set_parent;
 
 use crate::services::auth::AuthServiceContext;
 
 /// Handler for update topic request
-#[instrument(skip(request, auth_ctx))]
+#[instrument(skip(request, auth_ctx), fields(otel.root = true, otel.kind = "server"))]
 pub async fn handle_update_request<AC: AuthContext, C: MetadataItem>(
     request: RequestMessage<ObjectApiUpdateRequest>,
     auth_ctx: &AuthServiceContext<AC, C>,
 ) -> Result<ResponseMessage<Status>> {
     let (header, del_req) = request.get_header_request();
+    if let Some(trace_context) = header.trace_context() {
+        set_parent(&Span::current(), trace_context);
+    }
 
     debug!(?del_req, "del request");
 
@@ -20,6 +20,8 @@ use fluvio_controlplane_metadata::spu::SpuSpec;
 use fluvio_controlplane_metadata::topic::TopicSpec;
 use fluvio_controlplane_metadata::smartmodule::SmartModuleSpec;
 use fluvio_controlplane_metadata::tableformat::TableFormatSpec;
+use fluvio_controlplane_metadata::tenant::is_owned_by;
+use fluvio_auth::AuthContext;
 
 use crate::services::auth::AuthServiceContext;
 use crate::stores::StoreContext;
@@ -27,7 +29,7 @@ use fluvio_controlplane_metadata::spg::SpuGroupSpec;
 
 /// handle watch request by spawning watch controller for each store
 #[instrument(skip(request, auth_ctx, sink, end_event))]
-pub fn handle_watch_request<AC, C: MetadataItem + 'static>(
+pub fn handle_watch_request<AC: AuthContext, C: MetadataItem + 'static>(
     request: RequestMessage<ObjectApiWatchRequest>,
     auth_ctx: &AuthServiceContext<AC, C>,
     sink: ExclusiveFlvSink,
@@ -36,12 +38,16 @@ pub fn handle_watch_request<AC, C: MetadataItem + 'static>(
     let (header, req) = request.get_header_request();
     debug!("handling watch header: {:#?}, request: {:#?}", header, req);
 
+    // objects of tenant scoped types are only visible to their tenant
+    let tenant = auth_ctx.auth.tenant().map(|tenant| tenant.name.clone());
+
     if (req.downcast()? as Option<WatchRequest<TopicSpec>>).is_some() {
         WatchController::<TopicSpec, C>::update(
             sink,
             end_event,
             auth_ctx.global_ctx.topics().clone(),
             header,
+            tenant.clone(),
             false,
         )
     } else if (req.downcast()? as Option<WatchRequest<SpuSpec>>).is_some() {
@@ -50,6 +56,7 @@ pub fn handle_watch_request<AC, C: MetadataItem + 'static>(
             end_event,
             auth_ctx.global_ctx.spus().clone(),
             header,
+            None,
             false,
         )
     } else if (req.downcast()? as Option<WatchRequest<SpuGroupSpec>>).is_some() {
@@ -58,6 +65,7 @@ pub fn handle_watch_request<AC, C: MetadataItem + 'static>(
             end_event,
             auth_ctx.global_ctx.spgs().clone(),
             header,
+            None,
             false,
         )
     } else if (req.downcast()? as Option<WatchRequest<PartitionSpec>>).is_some() {
@@ -66,6 +74,7 @@ pub fn handle_watch_request<AC, C: MetadataItem + 'static>(
             end_event,
             auth_ctx.global_ctx.partitions().clone(),
             header,
+            tenant.clone(),
             false,
         )
     } else if let Some(req) = req.downcast()? as Option<WatchRequest<SmartModuleSpec>> {
@@ -74,6 +83,7 @@ pub fn handle_watch_request<AC, C: MetadataItem + 'static>(
             end_event,
             auth_ctx.global_ctx.smartmodules().clone(),
             header,
+            tenant.clone(),
             req.summary,
         )
     } else if (req.downcast()? as Option<WatchRequest<TableFormatSpec>>).is_some() {
@@ -82,6 +92,7 @@ pub fn handle_watch_request<AC, C: MetadataItem + 'static>(
             end_event,
             auth_ctx.global_ctx.tableformats().clone(),
             header,
+            tenant,
             false,
         )
     } else {
@@ -97,6 +108,7 @@ struct WatchController<S: AdminSpec, C: MetadataItem> {
     response_sink: ExclusiveFlvSink,
     store: StoreContext<S, C>,
     header: RequestHeader,
+    tenant: Option<String>,
     summary: bool,
     end_event: Arc<StickyEvent>,
 }
@@ -115,6 +127,7 @@ where
         end_event: Arc<StickyEvent>,
         store: StoreContext<S, C>,
         header: RequestHeader,
+        tenant: Option<String>,
         summary: bool,
     ) {
         use fluvio_future::task::spawn;
@@ -123,6 +136,7 @@ where
             response_sink,
             store,
             header,
+            tenant,
             end_event,
             summary,
         };
@@ -166,6 +180,14 @@ where
         debug!("watch: {} is done, terminating", S::LABEL);
     }
 
+    /// object is visible if there is no tenant or it belongs to tenant
+    fn is_visible(&self, name: &str) -> bool {
+        match &self.tenant {
+            Some(tenant) => is_owned_by(name, tenant),
+            None => true,
+        }
+    }
+
     /// sync with store and send out changes to send response
     /// if can't send, then signal end and return false
     #[instrument(skip(self, listener))]
@@ -192,6 +214,7 @@ where
                 updates
                     .into_iter()
                     .map(|u| u.into())
+                    .filter(|d: &Metadata<S>| self.is_visible(&d.name))
                     .map(|d: Metadata<S>| if self.summary { d.summary() } else { d })
                     .collect(),
             )
@@ -200,12 +223,15 @@ where
             let mut changes: Vec<Message<Metadata<S>>> = updates
                 .into_iter()
                 .map(|u| u.into())
+                .filter(|d: &Metadata<S>| self.is_visible(&d.name))
                 .map(|d: Metadata<S>| if self.summary { d.summary() } else { d })
                 .map(Message::update)
                 .collect();
             let mut deletes = deletes
                 .into_iter()
-                .map(|d| Message::delete(d.into()))
+                .map(|d| d.into())
+                .filter(|d: &Metadata<S>| self.is_visible(&d.name))
+                .map(Message::delete)
                 .collect();
             changes.append(&mut deletes);
             MetadataUpdate::with_changes(epoch, changes)
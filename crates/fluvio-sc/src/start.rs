@@ -4,12 +4,16 @@ use std::{
     time::Duration,
 };
 
-use anyhow::Result;
-use tracing::info;
+use anyhow::{anyhow, Context, Result};
+use tracing::{error, info};
 
-use fluvio_future::{task::run_block_on, timer::sleep};
-use fluvio_stream_dispatcher::metadata::{SharedClient, MetadataClient, local::LocalMetadataStorage};
+use fluvio_future::{net::TcpListener, task::run_block_on, timer::sleep};
+use fluvio_stream_dispatcher::metadata::{
+    SharedClient, MetadataClient,
+    local::{LocalMetadataItem, LocalMetadataStorage},
+};
 use fluvio_stream_model::{store::k8::K8MetaItem, core::MetadataItem};
+use fluvio_types::event::StickyEvent;
 use k8_client::{K8Client, K8Config, memory::MemoryClient};
 
 use crate::{
@@ -17,9 +21,13 @@ use crate::{
     services::auth::basic::BasicRbacPolicy,
     config::ScConfig,
     config::DEFAULT_NAMESPACE,
+    core::SharedContext,
+    raft::{
+        MetadataStateMachine, RaftConfig, RaftNode, ReplicatedMetadataStorage, start_raft_server,
+    },
 };
 
-pub fn main_loop(opt: ScOpt) {
+pub fn main_loop(opt: ScOpt) -> Result<()> {
     // parse configuration (program exits on error)
     println!("CLI Option: {opt:#?}");
 
@@ -31,7 +39,16 @@ pub fn main_loop(opt: ScOpt) {
             info!(?metadata, "Running in local mode");
             let client = create_local_metadata_store(metadata);
             let ((sc_config, auth_policy), tls_option) = opt.parse_cli_or_exit();
-            local_main_loop(sc_config, client, auth_policy, tls_option)
+            local_main_loop(sc_config, client, auth_policy, tls_option);
+        }
+        RunMode::Replicated(metadata) => {
+            info!(?metadata, "Running in replicated local mode");
+            let metadata = metadata.to_path_buf();
+            let raft_config = opt
+                .raft_config(&metadata)
+                .ok_or_else(|| anyhow!("replicated mode requires --raft-id"))?;
+            let ((sc_config, auth_policy), tls_option) = opt.parse_cli_or_exit();
+            replicated_main_loop(sc_config, metadata, raft_config, auth_policy, tls_option)?;
         }
         RunMode::ReadOnly(read_only_path) => {
             let read_only_path = read_only_path.to_path_buf();
@@ -43,7 +60,7 @@ pub fn main_loop(opt: ScOpt) {
                 create_memory_client(read_only_path).await
             })
             .expect("failed to initialize metadata from read only configuration");
-            local_main_loop(sc_config, client, auth_policy, tls_option)
+            local_main_loop(sc_config, client, auth_policy, tls_option);
         }
         RunMode::K8s => {
             info!("Running with K8");
@@ -61,9 +78,10 @@ pub fn main_loop(opt: ScOpt) {
             }
 
             let client = create_k8_client(k8_config).expect("failed to create k8 client");
-            k8_main_loop(sc_config, client, auth_policy, tls_option)
+            k8_main_loop(sc_config, client, auth_policy, tls_option);
         }
     }
+    Ok(())
 }
 
 /// print out system information
@@ -139,6 +157,52 @@ fn local_main_loop<C, M>(
     });
 }
 
+/// start SC with local metadata store in current runtime, without blocking.
+/// TLS proxy is not started, clients connect to public listener directly.
+/// Servers are stopped by returned events
+pub async fn start_local(
+    sc_config: ScConfig,
+    metadata: &Path,
+    public_listener: TcpListener,
+    private_listener: TcpListener,
+) -> (SharedContext<LocalMetadataItem>, Vec<Arc<StickyEvent>>) {
+    info!(?metadata, "starting local services");
+    let client = create_local_metadata_store(metadata);
+    crate::init::start_embedded_main_loop(sc_config, client, public_listener, private_listener)
+        .await
+}
+
+fn replicated_main_loop(
+    sc_config: ScConfig,
+    metadata: PathBuf,
+    raft_config: RaftConfig,
+    auth_policy: Option<BasicRbacPolicy>,
+    tls_option: Option<(String, TlsConfig)>,
+) -> Result<()> {
+    run_block_on(async move {
+        info!("starting replicated main loop");
+
+        let (node, client) = create_replicated_metadata_store(&metadata, raft_config)
+            .context("failed to start metadata replication")?;
+        start_raft_server(node.clone());
+
+        let ctx =
+            crate::init::start_replicated_main_loop((sc_config.clone(), auth_policy), client).await;
+        proxy::start_if(sc_config, tls_option).await;
+
+        println!("Streaming Controller started successfully");
+
+        let term = node.wait_for_leadership().await;
+        info!(term, "elected as leader, starting controllers");
+        crate::init::start_leader_services(ctx);
+
+        // controllers can't be stopped, let supervisor restart this instance as follower
+        node.wait_for_leadership_loss(term).await;
+        error!(term, "lost leadership, exiting");
+        std::process::exit(1);
+    })
+}
+
 mod proxy {
     use std::process;
     use tracing::info;
@@ -206,3 +270,34 @@ fn create_k8_client(config: K8Config) -> Result<Arc<K8Client>> {
 fn create_local_metadata_store(path: &Path) -> Arc<LocalMetadataStorage> {
     Arc::new(LocalMetadataStorage::new(path))
 }
+
+fn create_replicated_metadata_store(
+    path: &Path,
+    raft_config: RaftConfig,
+) -> Result<(Arc<RaftNode>, Arc<ReplicatedMetadataStorage>)> {
+    use fluvio_sc_schema::mirror::MirrorSpec;
+
+    use crate::stores::spu::SpuSpec;
+    use crate::stores::topic::TopicSpec;
+    use crate::stores::partition::PartitionSpec;
+    use crate::stores::spg::SpuGroupSpec;
+    use crate::stores::tableformat::TableFormatSpec;
+    use crate::stores::smartmodule::SmartModuleSpec;
+    use crate::stores::topic::TopicLimits;
+
+    let mut state_machine = MetadataStateMachine::new(LocalMetadataStorage::new(path));
+    // owners first, snapshots are restored in this order
+    state_machine.register::<SpuGroupSpec>();
+    state_machine.register::<SpuSpec>();
+    state_machine.register::<TopicSpec>();
+    state_machine.register::<PartitionSpec>();
+    state_machine.register::<TableFormatSpec>();
+    state_machine.register::<SmartModuleSpec>();
+    state_machine.register::<MirrorSpec>();
+    state_machine.register::<TopicLimits>();
+    let state_machine = Arc::new(state_machine);
+
+    let node = RaftNode::start(raft_config, state_machine.clone())?;
+    let client = Arc::new(ReplicatedMetadataStorage::new(node.clone(), state_machine));
+    Ok((node, client))
+}
@@ -45,3 +45,28 @@ impl ElectionPolicy for SimplePolicy {
         }
     }
 }
+
+/// only replicas which have all committed records of leader are suitable,
+/// the most caught up is preferred. Used when leadership is handed off by live leader
+pub(crate) struct InSyncPolicy {}
+
+impl InSyncPolicy {
+    pub(crate) fn new() -> Self {
+        InSyncPolicy {}
+    }
+}
+
+impl ElectionPolicy for InSyncPolicy {
+    fn potential_leader_score(
+        &self,
+        replica_status: &ReplicaStatus,
+        leader: &ReplicaStatus,
+    ) -> ElectionScoring {
+        if replica_status.leo >= 0 && replica_status.leo >= leader.hw {
+            let lag = (leader.leo - replica_status.leo).clamp(0, u16::MAX as i64);
+            ElectionScoring::Score(lag as u16)
+        } else {
+            ElectionScoring::NotSuitable
+        }
+    }
+}
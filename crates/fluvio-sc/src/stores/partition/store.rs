@@ -182,6 +182,7 @@ impl ReplicaSchedulingGroups {
 }
 
 // used for selecting weight
+#[derive(Debug, Clone, Copy)]
 pub(crate) enum SpuWeightSelection {
     Leader,
     Follower,
@@ -203,6 +204,11 @@ impl PartitionCountSpu {
     pub(crate) fn follower_weight(&self) -> u16 {
         self.followers
     }
+
+    /// replicas hosted by spu, leaders and followers
+    pub(crate) fn replicas(&self) -> u32 {
+        self.leaders as u32 + self.followers as u32
+    }
 }
 
 #[async_trait]
@@ -391,6 +397,8 @@ impl PartitonStatusExtension for PartitionStatus {
     fn merge(&mut self, other: Self) {
         self.resolution = other.resolution;
         self.size = other.size;
+        self.corrupted_offset = other.corrupted_offset;
+        self.in_sync_replicas = other.in_sync_replicas;
         if let Some(old) = self.leader.merge(&other.leader) {
             self.replicas.push(old); // move old leader to replicas
         }
@@ -560,6 +568,34 @@ mod test {
         assert!(status.candidate_leader(&online_spu, &policy).is_none());
     }
 
+    /// only followers with all committed records of leader can take over from live leader
+    #[test]
+    fn test_candidate_spu_in_sync() {
+        use crate::stores::partition::InSyncPolicy;
+
+        let status = PartitionStatus::new(
+            (5000, 100, 110),
+            vec![
+                (5001, 100, 110).into(), // caught up with leader
+                (5002, 100, 105).into(), // has all committed records
+                (5003, 90, 95).into(),   // missing committed records
+            ],
+        );
+        let policy = InSyncPolicy::new();
+
+        let mut online_spu = HashSet::new();
+        online_spu.insert(5001);
+        online_spu.insert(5002);
+        online_spu.insert(5003);
+        assert_eq!(status.candidate_leader(&online_spu, &policy), Some(5001));
+
+        online_spu.remove(&5001);
+        assert_eq!(status.candidate_leader(&online_spu, &policy), Some(5002));
+
+        online_spu.remove(&5002);
+        assert!(status.candidate_leader(&online_spu, &policy).is_none());
+    }
+
     #[test]
     fn test_merge_initial() {
         let mut target = PartitionStatus::default();
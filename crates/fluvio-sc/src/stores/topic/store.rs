@@ -14,6 +14,7 @@ use super::*;
 
 pub type TopicMetadata<C> = MetadataStoreObject<TopicSpec, C>;
 pub type TopicLocalStore<C> = LocalStore<TopicSpec, C>;
+pub type TopicLimitsLocalStore<C> = LocalStore<TopicLimits, C>;
 pub type DefaultTopicMd = TopicMetadata<u32>;
 pub type DefaultTopicLocalStore = TopicLocalStore<u32>;
 
@@ -43,11 +44,19 @@ where
 
             let replica_key = ReplicaKey::new(self.key(), *idx);
 
-            let partition_spec = PartitionSpec::from_replicas(replicas.clone(), &self.spec, mirror);
+            let mut partition_spec =
+                PartitionSpec::from_replicas(replicas.clone(), &self.spec, mirror);
+            partition_spec
+                .renamed_from
+                .clone_from(&self.status.renamed_from);
             let store = partition_store.read().await;
             let partition = store.get(&replica_key);
             if let Some(p) = partition {
-                partitions.push(p.inner().clone());
+                let mut partition = p.inner().clone();
+                if partition.spec.update_config_from_topic(&self.spec) {
+                    debug!(?replica_key, "updating partition config from topic");
+                }
+                partitions.push(partition);
             } else {
                 debug!(?replica_key, ?partition_spec, "creating new partition");
                 partitions.push(
@@ -258,4 +267,71 @@ mod test {
         assert_eq!(partitions[1].key, ReplicaKey::new("topic-1", 1_u32));
         assert_eq!(partitions[1].spec.leader, 1);
     }
+
+    #[fluvio_future::test]
+    async fn test_partitions_from_replicas_update_config() {
+        use fluvio_controlplane_metadata::topic::{CompressionAlgorithm, TopicStorageConfig};
+
+        let partition_stored = MetadataStoreObject::<PartitionSpec, u32>::new(
+            ReplicaKey::new("topic-1", 0_u32),
+            PartitionSpec::new(0, vec![0]),
+            PartitionStatus::default(),
+        );
+
+        let mut spec: TopicSpec = (1, 1, false).into();
+        spec.set_compression_type(CompressionAlgorithm::Lz4);
+        spec.set_storage(TopicStorageConfig {
+            segment_size: Some(2000),
+            ..Default::default()
+        });
+        let status = TopicStatus::new(TopicResolution::Provisioned, vec![vec![0]], "".to_owned());
+        let topic = MetadataStoreObject::<TopicSpec, u32>::new("topic-1", spec, status);
+        let partition_store = DefaultPartitionStore::bulk_new(vec![partition_stored]);
+
+        let partitions = topic.partitions_from_replicas(&partition_store).await;
+
+        assert_eq!(partitions.len(), 1);
+        assert_eq!(partitions[0].spec.leader, 0);
+        assert_eq!(
+            partitions[0].spec.compression_type,
+            CompressionAlgorithm::Lz4
+        );
+        assert_eq!(
+            partitions[0]
+                .spec
+                .storage
+                .as_ref()
+                .and_then(|s| s.segment_size),
+            Some(2000)
+        );
+    }
+
+    #[fluvio_future::test]
+    async fn test_partitions_from_replicas_renamed() {
+        let partition_stored = MetadataStoreObject::<PartitionSpec, u32>::new(
+            ReplicaKey::new("new", 0_u32),
+            PartitionSpec::new(0, vec![0]),
+            PartitionStatus::default(),
+        );
+
+        let mut spec: TopicSpec = (2, 1, false).into();
+        spec.set_aliases(vec!["old".to_owned()]);
+        let mut status = TopicStatus::new(
+            TopicResolution::Provisioned,
+            vec![vec![0], vec![1]],
+            "".to_owned(),
+        );
+        status.renamed_from = Some("old".to_owned());
+        let topic = MetadataStoreObject::<TopicSpec, u32>::new("new", spec, status);
+        let partition_store = DefaultPartitionStore::bulk_new(vec![partition_stored]);
+
+        let partitions = topic.partitions_from_replicas(&partition_store).await;
+
+        assert_eq!(partitions.len(), 2);
+        // existing partition only gets aliases of topic
+        assert_eq!(partitions[0].spec.renamed_from, None);
+        assert_eq!(partitions[0].spec.aliases, vec!["old".to_owned()]);
+        assert_eq!(partitions[1].spec.renamed_from, Some("old".to_owned()));
+        assert_eq!(partitions[1].spec.aliases, vec!["old".to_owned()]);
+    }
 }
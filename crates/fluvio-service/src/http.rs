@@ -0,0 +1,297 @@
+//! Minimal HTTP/1.1 support, one request per connection.
+//!
+//! Shared by HTTP endpoints of Fluvio components. Request bodies must have `Content-Length`,
+//! chunked requests are rejected.
+
+use std::io::Error as IoError;
+
+use futures_util::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use serde::Serialize;
+
+const MAX_HEADERS: usize = 64;
+const MAX_HEAD_SIZE: usize = 16 * 1024;
+const READ_CHUNK_SIZE: usize = 4096;
+
+pub const CONTENT_TYPE_JSON: &str = "application/json";
+pub const CONTENT_TYPE_EVENT_STREAM: &str = "text/event-stream";
+pub const CONTENT_TYPE_TEXT: &str = "text/plain";
+
+/// Error reading request
+#[derive(thiserror::Error, Debug)]
+pub enum HttpError {
+    #[error(transparent)]
+    Io(#[from] IoError),
+    #[error("malformed request: {0}")]
+    BadRequest(String),
+    #[error("request body exceeds {0} bytes")]
+    PayloadTooLarge(usize),
+    #[error("request body must have content length")]
+    LengthRequired,
+}
+
+impl HttpError {
+    pub fn status(&self) -> u16 {
+        match self {
+            Self::BadRequest(_) => 400,
+            Self::LengthRequired => 411,
+            Self::PayloadTooLarge(_) => 413,
+            Self::Io(_) => 500,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct HttpRequest {
+    pub method: String,
+    pub path: String,
+    query: Vec<(String, String)>,
+    headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl HttpRequest {
+    /// read request from the stream, returns `None` if connection is closed before any byte is sent
+    pub async fn read<R: AsyncRead + Unpin>(
+        reader: &mut R,
+        max_body_size: usize,
+    ) -> Result<Option<Self>, HttpError> {
+        let mut buf = Vec::with_capacity(READ_CHUNK_SIZE);
+        let mut chunk = [0u8; READ_CHUNK_SIZE];
+
+        let (mut request, head_len) = loop {
+            let read = reader.read(&mut chunk).await?;
+            if read == 0 {
+                if buf.is_empty() {
+                    return Ok(None);
+                }
+                return Err(HttpError::BadRequest("incomplete request".to_owned()));
+            }
+            buf.extend_from_slice(&chunk[..read]);
+            if let Some(parsed) = Self::parse_head(&buf)? {
+                break parsed;
+            }
+            if buf.len() > MAX_HEAD_SIZE {
+                return Err(HttpError::BadRequest("request head too large".to_owned()));
+            }
+        };
+
+        if request
+            .header("transfer-encoding")
+            .is_some_and(|encoding| !encoding.eq_ignore_ascii_case("identity"))
+        {
+            return Err(HttpError::LengthRequired);
+        }
+        let content_length = match request.header("content-length") {
+            Some(value) => value
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| HttpError::BadRequest("invalid content length".to_owned()))?,
+            None => 0,
+        };
+        if content_length > max_body_size {
+            return Err(HttpError::PayloadTooLarge(max_body_size));
+        }
+
+        let mut body = buf.split_off(head_len);
+        body.truncate(content_length);
+        if body.len() < content_length {
+            let start = body.len();
+            body.resize(content_length, 0);
+            reader.read_exact(&mut body[start..]).await?;
+        }
+        request.body = body;
+        Ok(Some(request))
+    }
+
+    /// parse request line and headers, returns `None` if more bytes are needed
+    fn parse_head(buf: &[u8]) -> Result<Option<(Self, usize)>, HttpError> {
+        let mut headers = [httparse::EMPTY_HEADER; MAX_HEADERS];
+        let mut parsed = httparse::Request::new(&mut headers);
+        let head_len = match parsed.parse(buf) {
+            Ok(httparse::Status::Complete(len)) => len,
+            Ok(httparse::Status::Partial) => return Ok(None),
+            Err(err) => return Err(HttpError::BadRequest(err.to_string())),
+        };
+
+        let target = parsed.path.unwrap_or("/");
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let request = Self {
+            method: parsed.method.unwrap_or_default().to_owned(),
+            path: path.to_owned(),
+            query: url::form_urlencoded::parse(query.as_bytes())
+                .into_owned()
+                .collect(),
+            headers: parsed
+                .headers
+                .iter()
+                .map(|header| {
+                    (
+                        header.name.to_ascii_lowercase(),
+                        String::from_utf8_lossy(header.value).into_owned(),
+                    )
+                })
+                .collect(),
+            body: vec![],
+        };
+        Ok(Some((request, head_len)))
+    }
+
+    /// header value, name must be lower case
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn query(&self, name: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// query parameters with given prefix, prefix is removed from the name
+    pub fn query_with_prefix<'a>(
+        &'a self,
+        prefix: &'a str,
+    ) -> impl Iterator<Item = (&'a str, &'a str)> + 'a {
+        self.query.iter().filter_map(move |(key, value)| {
+            key.strip_prefix(prefix).map(|name| (name, value.as_str()))
+        })
+    }
+
+    /// parse query parameter, missing parameter is `None`
+    pub fn query_parse<T: std::str::FromStr>(&self, name: &str) -> Result<Option<T>, HttpError> {
+        self.query(name)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| HttpError::BadRequest(format!("invalid {name}: {value}")))
+            })
+            .transpose()
+    }
+
+    pub fn segments(&self) -> Vec<&str> {
+        self.path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect()
+    }
+
+    pub fn accepts(&self, content_type: &str) -> bool {
+        self.header("accept")
+            .is_some_and(|accept| accept.contains(content_type))
+    }
+}
+
+#[derive(Debug)]
+pub struct HttpResponse {
+    pub status: u16,
+    content_type: &'static str,
+    body: Vec<u8>,
+}
+
+impl HttpResponse {
+    pub fn new(status: u16, content_type: &'static str, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            content_type,
+            body: body.into(),
+        }
+    }
+
+    pub fn text(status: u16, body: impl Into<String>) -> Self {
+        Self::new(status, CONTENT_TYPE_TEXT, body.into())
+    }
+
+    pub fn json<T: Serialize>(status: u16, value: &T) -> Self {
+        match serde_json::to_vec(value) {
+            Ok(body) => Self::new(status, CONTENT_TYPE_JSON, body),
+            Err(err) => Self::text(500, err.to_string()),
+        }
+    }
+
+    pub async fn write_to<W: AsyncWrite + Unpin>(&self, writer: &mut W) -> std::io::Result<()> {
+        let head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            self.status,
+            reason(self.status),
+            self.content_type,
+            self.body.len()
+        );
+        writer.write_all(head.as_bytes()).await?;
+        writer.write_all(&self.body).await?;
+        writer.flush().await
+    }
+}
+
+/// start response without content length, body is written until connection is closed
+pub async fn write_stream_head<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    content_type: &str,
+) -> std::io::Result<()> {
+    let head = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: {content_type}\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n"
+    );
+    writer.write_all(head.as_bytes()).await?;
+    writer.flush().await
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        _ => "Internal Server Error",
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[fluvio_future::test]
+    async fn test_read_request() {
+        let raw = b"POST /topics/hello/records?key=a%20b&sm.regex=x HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: 5\r\n\r\nhello";
+        let request = HttpRequest::read(&mut raw.as_slice(), 1024)
+            .await
+            .expect("read")
+            .expect("request");
+
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.segments(), vec!["topics", "hello", "records"]);
+        assert_eq!(request.query("key"), Some("a b"));
+        assert_eq!(
+            request.query_with_prefix("sm.").collect::<Vec<_>>(),
+            vec![("regex", "x")]
+        );
+        assert_eq!(request.header("content-type"), Some(CONTENT_TYPE_JSON));
+        assert_eq!(request.body, b"hello");
+    }
+
+    #[fluvio_future::test]
+    async fn test_read_request_rejected() {
+        let raw = b"POST /topics/hello/records HTTP/1.1\r\nContent-Length: 5\r\n\r\nhello";
+        let err = HttpRequest::read(&mut raw.as_slice(), 4)
+            .await
+            .expect_err("too large");
+        assert_eq!(err.status(), 413);
+
+        let raw = b"POST /topics/hello/records HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n";
+        let err = HttpRequest::read(&mut raw.as_slice(), 1024)
+            .await
+            .expect_err("chunked");
+        assert_eq!(err.status(), 411);
+
+        let empty: &[u8] = b"";
+        assert!(HttpRequest::read(&mut &*empty, 1024)
+            .await
+            .expect("closed")
+            .is_none());
+    }
+}
@@ -1,10 +1,14 @@
 #[cfg(unix)]
 mod server;
+mod tasks;
+pub mod http;
+pub mod metrics;
 
 #[cfg(test)]
 pub mod test_request;
 
 pub use self::server::*;
+pub use self::tasks::ServiceTasks;
 pub use fluvio_protocol::codec::FluvioCodec;
 
 #[macro_export]
@@ -0,0 +1,236 @@
+//! Metrics endpoint serving OpenMetrics text over HTTP.
+//!
+//! Only `GET /metrics` is served, one request per connection.
+
+use std::fmt::{Display, Write};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use tracing::{debug, error, info, instrument};
+
+use fluvio_future::net::{TcpListener, TcpStream};
+use fluvio_future::task::spawn;
+
+use crate::http::{HttpError, HttpRequest, HttpResponse};
+
+pub const METRICS_PATH: &str = "/metrics";
+pub const CONTENT_TYPE_OPENMETRICS: &str =
+    "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricType {
+    Counter,
+    Gauge,
+    Summary,
+}
+
+impl MetricType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Counter => "counter",
+            Self::Gauge => "gauge",
+            Self::Summary => "summary",
+        }
+    }
+}
+
+/// Encoder for OpenMetrics text exposition format
+#[derive(Debug, Default)]
+pub struct MetricsWriter {
+    buf: String,
+}
+
+impl MetricsWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// declare metric family, must be followed by its samples
+    pub fn family(&mut self, name: &str, metric_type: MetricType, help: &str) {
+        let _ = writeln!(self.buf, "# TYPE {name} {}", metric_type.as_str());
+        let _ = writeln!(self.buf, "# HELP {name} {}", escape_help(help));
+    }
+
+    /// write sample, `name` includes the suffix, e.g. `_total` for counters
+    pub fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: impl Display) {
+        self.buf.push_str(name);
+        if !labels.is_empty() {
+            self.buf.push('{');
+            for (index, (label, label_value)) in labels.iter().enumerate() {
+                if index > 0 {
+                    self.buf.push(',');
+                }
+                let _ = write!(self.buf, "{label}=\"{}\"", escape_label(label_value));
+            }
+            self.buf.push('}');
+        }
+        let _ = writeln!(self.buf, " {value}");
+    }
+
+    /// terminate exposition
+    pub fn finish(mut self) -> String {
+        self.buf.push_str("# EOF\n");
+        self.buf
+    }
+}
+
+fn escape_help(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Source of metrics rendered on every scrape
+#[async_trait]
+pub trait MetricsSource: Send + Sync + 'static {
+    async fn render(&self, writer: &mut MetricsWriter);
+}
+
+/// HTTP listener for metrics scrapes
+pub struct MetricsServer<S> {
+    addr: String,
+    source: Arc<S>,
+}
+
+impl<S: MetricsSource> MetricsServer<S> {
+    pub fn new(addr: String, source: Arc<S>) -> Self {
+        Self { addr, source }
+    }
+
+    pub fn run(self) {
+        spawn(self.accept_incoming());
+    }
+
+    #[instrument(skip(self), fields(addr = %self.addr))]
+    async fn accept_incoming(self) {
+        let listener = match TcpListener::bind(&self.addr).await {
+            Ok(listener) => listener,
+            Err(err) => {
+                error!("Error binding metrics listener: {}", err);
+                return;
+            }
+        };
+
+        info!("metrics endpoint started");
+        let mut incoming = listener.incoming();
+        while let Some(incoming) = incoming.next().await {
+            match incoming {
+                Ok(stream) => {
+                    spawn(Self::handle_request(stream, self.source.clone()));
+                }
+                Err(err) => {
+                    error!("Error from metrics TCP stream: {:?}", err);
+                }
+            }
+        }
+        info!("metrics endpoint closed");
+    }
+
+    async fn handle_request(mut stream: TcpStream, source: Arc<S>) {
+        let response = match HttpRequest::read(&mut stream, 0).await {
+            Ok(Some(request)) if request.path != METRICS_PATH => {
+                HttpResponse::text(404, "not found\n")
+            }
+            Ok(Some(request)) if request.method != "GET" => {
+                HttpResponse::text(405, "method not allowed\n")
+            }
+            Ok(Some(_)) => {
+                let mut writer = MetricsWriter::new();
+                source.render(&mut writer).await;
+                HttpResponse::new(200, CONTENT_TYPE_OPENMETRICS, writer.finish())
+            }
+            Ok(None) => return,
+            Err(HttpError::Io(err)) => {
+                debug!(%err, "error reading metrics request");
+                return;
+            }
+            Err(err) => HttpResponse::text(err.status(), format!("{err}\n")),
+        };
+
+        if let Err(err) = response.write_to(&mut stream).await {
+            debug!(%err, "error sending metrics response");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::time::Duration;
+
+    use fluvio_future::timer::sleep;
+    use futures_util::io::{AsyncReadExt, AsyncWriteExt};
+
+    use super::*;
+
+    struct TestSource;
+
+    #[async_trait]
+    impl MetricsSource for TestSource {
+        async fn render(&self, writer: &mut MetricsWriter) {
+            writer.family("test_requests", MetricType::Counter, "Requests served");
+            writer.sample("test_requests_total", &[], 3);
+        }
+    }
+
+    #[test]
+    fn test_metrics_writer() {
+        let mut writer = MetricsWriter::new();
+        writer.family(
+            "fluvio_replica_hw",
+            MetricType::Gauge,
+            "High watermark\nof replica",
+        );
+        writer.sample(
+            "fluvio_replica_hw",
+            &[("topic", "t\"1"), ("partition", "0")],
+            10,
+        );
+        writer.sample("fluvio_replica_hw", &[], 2.5);
+
+        assert_eq!(
+            writer.finish(),
+            "# TYPE fluvio_replica_hw gauge\n\
+             # HELP fluvio_replica_hw High watermark\\nof replica\n\
+             fluvio_replica_hw{topic=\"t\\\"1\",partition=\"0\"} 10\n\
+             fluvio_replica_hw 2.5\n\
+             # EOF\n"
+        );
+    }
+
+    #[fluvio_future::test]
+    async fn test_metrics_server() {
+        let port = portpicker::pick_unused_port().expect("No free ports left");
+        let addr = format!("127.0.0.1:{port}");
+        MetricsServer::new(addr.clone(), Arc::new(TestSource)).run();
+        sleep(Duration::from_millis(100)).await;
+
+        let request = |request: &'static str| {
+            let addr = addr.clone();
+            async move {
+                let mut stream = TcpStream::connect(&addr).await.expect("connect");
+                stream.write_all(request.as_bytes()).await.expect("write");
+                let mut response = String::new();
+                stream.read_to_string(&mut response).await.expect("read");
+                response
+            }
+        };
+
+        let response = request("GET /metrics HTTP/1.1\r\nHost: test\r\n\r\n").await;
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains(CONTENT_TYPE_OPENMETRICS));
+        assert!(response.ends_with("test_requests_total 3\n# EOF\n"));
+
+        let response = request("GET /other HTTP/1.1\r\n\r\n").await;
+        assert!(response.starts_with("HTTP/1.1 404"));
+
+        let response = request("POST /metrics HTTP/1.1\r\n\r\n").await;
+        assert!(response.starts_with("HTTP/1.1 405"));
+    }
+}
@@ -87,6 +87,13 @@ where
         shutdown
     }
 
+    /// run on listener which is already bound, i.e. to ephemeral port
+    pub fn run_on(self, listener: TcpListener) -> Arc<StickyEvent> {
+        let shutdown = StickyEvent::shared();
+        spawn(self.serve(listener, shutdown.clone()));
+        shutdown
+    }
+
     #[instrument(skip(shutdown))]
     async fn accept_incoming(self, shutdown: Arc<StickyEvent>) {
         debug!("Binding TcpListener");
@@ -98,6 +105,11 @@ where
             }
         };
 
+        self.serve(listener, shutdown).await;
+    }
+
+    #[instrument(skip(listener, shutdown))]
+    async fn serve(self, listener: TcpListener, shutdown: Arc<StickyEvent>) {
         info!("Opened TcpListener, waiting for connections");
         let mut incoming = listener.incoming().take_until(shutdown.listen_pinned());
 
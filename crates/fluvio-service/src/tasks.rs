@@ -0,0 +1,97 @@
+use std::sync::Mutex;
+
+use tracing::debug;
+
+use fluvio_future::task::{spawn, JoinHandle};
+
+/// Background tasks of a service, such as controllers, which are stopped together
+#[derive(Debug)]
+pub struct ServiceTasks {
+    handles: Mutex<Option<Vec<JoinHandle<()>>>>,
+}
+
+impl Default for ServiceTasks {
+    fn default() -> Self {
+        Self {
+            handles: Mutex::new(Some(vec![])),
+        }
+    }
+}
+
+impl ServiceTasks {
+    /// task is cancelled when tasks are stopped,
+    /// task spawned after tasks are stopped is cancelled right away
+    pub fn track(&self, handle: JoinHandle<()>) {
+        let mut handles = self.handles.lock().unwrap_or_else(|err| err.into_inner());
+        match handles.as_mut() {
+            Some(handles) => handles.push(handle),
+            None => {
+                spawn(async move {
+                    handle.cancel().await;
+                });
+            }
+        }
+    }
+
+    /// cancel all tasks and wait until they are dropped
+    pub async fn stop(&self) {
+        let handles = self
+            .handles
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .take()
+            .unwrap_or_default();
+        debug!(tasks = handles.len(), "stopping tasks");
+        for handle in handles {
+            handle.cancel().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    use fluvio_future::timer::sleep;
+
+    use super::*;
+
+    /// sets flag when task is dropped
+    struct DropFlag(Arc<AtomicBool>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn endless_task(dropped: Arc<AtomicBool>) -> JoinHandle<()> {
+        spawn(async move {
+            let _flag = DropFlag(dropped);
+            loop {
+                sleep(Duration::from_millis(10)).await;
+            }
+        })
+    }
+
+    #[fluvio_future::test]
+    async fn test_stop_cancels_tasks() {
+        let tasks = ServiceTasks::default();
+        let dropped = Arc::new(AtomicBool::new(false));
+        tasks.track(endless_task(dropped.clone()));
+        sleep(Duration::from_millis(50)).await;
+        assert!(!dropped.load(Ordering::SeqCst));
+
+        tasks.stop().await;
+        assert!(dropped.load(Ordering::SeqCst));
+
+        // tracked after stop
+        let late = Arc::new(AtomicBool::new(false));
+        tasks.track(endless_task(late.clone()));
+        sleep(Duration::from_millis(100)).await;
+        assert!(late.load(Ordering::SeqCst));
+    }
+}
@@ -40,6 +40,8 @@ pub struct SmartModuleConfig {
     pub(crate) version: Option<i16>,
     #[builder(default)]
     pub(crate) lookback: Option<Lookback>,
+    #[builder(default)]
+    pub(crate) error_policy: ErrorPolicy,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -48,6 +50,19 @@ pub enum Lookback {
     Age { age: Duration, last: u64 },
 }
 
+/// What the chain does with a record that fails in the SmartModule
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// stop processing and return the error with the partial output
+    #[default]
+    Fail,
+    /// drop the record and continue with the next one
+    Skip,
+    /// drop the record and keep the error to be written to the topic.
+    /// Only producer-side chains write dead letters, SPU rejects it for consumer-side chains
+    DeadLetter(String),
+}
+
 impl SmartModuleConfigBuilder {
     /// add initial parameters
     pub fn param(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
@@ -86,6 +101,7 @@ impl From<crate::transformation::TransformationStep> for SmartModuleConfig {
                 .into(),
             version: None,
             lookback: step.lookback.map(|l| l.into()),
+            error_policy: step.on_error.into(),
         }
     }
 }
@@ -103,6 +119,17 @@ impl From<crate::transformation::Lookback> for Lookback {
     }
 }
 
+#[cfg(feature = "transformation")]
+impl From<crate::transformation::ErrorPolicy> for ErrorPolicy {
+    fn from(value: crate::transformation::ErrorPolicy) -> Self {
+        match value {
+            crate::transformation::ErrorPolicy::Fail => Self::Fail,
+            crate::transformation::ErrorPolicy::Skip => Self::Skip,
+            crate::transformation::ErrorPolicy::DeadLetter(topic) => Self::DeadLetter(topic),
+        }
+    }
+}
+
 impl From<&fluvio_smartmodule::dataplane::smartmodule::Lookback> for Lookback {
     fn from(value: &fluvio_smartmodule::dataplane::smartmodule::Lookback) -> Self {
         match value.age {
@@ -8,6 +8,7 @@ pub struct SmartModuleChainMetrics {
     records_out: AtomicU64,
     invocation_count: AtomicU64,
     fuel_used: AtomicU64,
+    records_skipped: AtomicU64,
 }
 
 impl SmartModuleChainMetrics {
@@ -24,6 +25,10 @@ impl SmartModuleChainMetrics {
         self.fuel_used.fetch_add(value, Ordering::SeqCst);
     }
 
+    pub fn add_records_skipped(&self, value: u64) {
+        self.records_skipped.fetch_add(value, Ordering::SeqCst);
+    }
+
     pub fn bytes_in(&self) -> u64 {
         self.bytes_in.load(Ordering::SeqCst)
     }
@@ -38,4 +43,8 @@ impl SmartModuleChainMetrics {
     pub fn invocation_count(&self) -> u64 {
         self.invocation_count.load(Ordering::SeqCst)
     }
+
+    pub fn records_skipped(&self) -> u64 {
+        self.records_skipped.load(Ordering::SeqCst)
+    }
 }
@@ -12,7 +12,7 @@ pub mod metrics;
 pub use error::EngineError;
 pub use config::{
     SmartModuleConfig, SmartModuleConfigBuilder, SmartModuleConfigBuilderError,
-    SmartModuleInitialData, Lookback, DEFAULT_SMARTENGINE_VERSION,
+    SmartModuleInitialData, Lookback, ErrorPolicy, DEFAULT_SMARTENGINE_VERSION,
 };
 
 pub type WasmSlice = (i32, i32, u32);
@@ -20,4 +20,6 @@ pub type WasmSlice = (i32, i32, u32);
 /// SmartEngine Version
 pub type Version = i16;
 
-pub use self::wasmtime::{SmartEngine, SmartModuleChainBuilder, SmartModuleChainInstance};
+pub use self::wasmtime::{
+    SmartEngine, SmartModuleChainBuilder, SmartModuleChainInstance, DeadLetterRecord,
+};
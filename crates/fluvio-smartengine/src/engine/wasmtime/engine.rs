@@ -1,7 +1,10 @@
 use std::fmt::{self, Debug};
 use std::future::Future;
+use std::io::Cursor;
 
 use anyhow::Result;
+use fluvio_protocol::Decoder;
+use fluvio_protocol::link::smartmodule::SmartModuleTransformRuntimeError;
 use fluvio_smartmodule::Record;
 use tracing::debug;
 use wasmtime::{Engine, Module};
@@ -9,7 +12,7 @@ use wasmtime::{Engine, Module};
 use fluvio_smartmodule::dataplane::smartmodule::{SmartModuleInput, SmartModuleOutput};
 
 use crate::SmartModuleConfig;
-use crate::engine::config::{Lookback, DEFAULT_SMARTENGINE_VERSION};
+use crate::engine::config::{ErrorPolicy, Lookback, DEFAULT_SMARTENGINE_VERSION};
 
 use super::init::SmartModuleInit;
 use super::instance::{SmartModuleInstance, SmartModuleInstanceContext};
@@ -78,7 +81,14 @@ impl SmartModuleChainBuilder {
             let init = SmartModuleInit::try_instantiate(&ctx, &mut state)?;
             let look_back = SmartModuleLookBack::try_instantiate(&ctx, &mut state)?;
             let transform = create_transform(&ctx, config.initial_data, &mut state)?;
-            let mut instance = SmartModuleInstance::new(ctx, init, look_back, transform, version);
+            let mut instance = SmartModuleInstance::new(
+                ctx,
+                init,
+                look_back,
+                transform,
+                version,
+                config.error_policy,
+            );
 
             instance.call_init(&mut state)?;
             instances.push(instance);
@@ -87,6 +97,7 @@ impl SmartModuleChainBuilder {
         Ok(SmartModuleChainInstance {
             store: state,
             instances,
+            dead_letters: Vec::new(),
         })
     }
 }
@@ -110,10 +121,20 @@ impl<T: Into<Vec<u8>>> From<(SmartModuleConfig, T)> for SmartModuleChainBuilder
     }
 }
 
+/// Record dropped by a SmartModule with dead-letter error policy
+#[derive(Debug, Clone)]
+pub struct DeadLetterRecord {
+    /// topic where the record should be sent
+    pub topic: String,
+    /// error returned by the SmartModule, it contains the offending record
+    pub error: SmartModuleTransformRuntimeError,
+}
+
 /// SmartModule Chain Instance that can be executed
 pub struct SmartModuleChainInstance {
     store: WasmState,
     instances: Vec<SmartModuleInstance>,
+    dead_letters: Vec<DeadLetterRecord>,
 }
 
 impl Debug for SmartModuleChainInstance {
@@ -150,11 +171,13 @@ impl SmartModuleChainInstance {
             for instance in instances {
                 // pass raw inputs to transform instance
                 // each raw input may result in multiple records
-                self.store.top_up_fuel();
-                let output = instance.process(next_input, &mut self.store)?;
-                let fuel_used = self.store.get_used_fuel();
-                debug!(fuel_used, "fuel used");
-                metric.add_fuel_used(fuel_used);
+                let output = Self::process_instance(
+                    instance,
+                    next_input,
+                    &mut self.store,
+                    &mut self.dead_letters,
+                    metric,
+                )?;
 
                 if let Some(ref smerr) = output.error {
                     // encountered error, we stop processing and return partial output
@@ -168,14 +191,16 @@ impl SmartModuleChainInstance {
                 }
             }
 
-            self.store.top_up_fuel();
-            let output = last.process(next_input, &mut self.store)?;
+            let output = Self::process_instance(
+                last,
+                next_input,
+                &mut self.store,
+                &mut self.dead_letters,
+                metric,
+            )?;
             if let Some(ref smerr) = output.error {
                 tracing::error!(err=?smerr);
             }
-            let fuel_used = self.store.get_used_fuel();
-            debug!(fuel_used, "fuel used");
-            metric.add_fuel_used(fuel_used);
             let records_out = output.successes.len();
             metric.add_records_out(records_out as u64);
             debug!(records_out, "sm records out");
@@ -188,6 +213,90 @@ impl SmartModuleChainInstance {
         }
     }
 
+    /// Records dropped by SmartModules with dead-letter error policy since last call
+    pub fn take_dead_letters(&mut self) -> Vec<DeadLetterRecord> {
+        std::mem::take(&mut self.dead_letters)
+    }
+
+    /// Process input with a single instance.
+    /// If the instance error policy is not `Fail`, the record that failed is dropped
+    /// and the instance is invoked again with the records after it.
+    fn process_instance(
+        instance: &mut SmartModuleInstance,
+        input: SmartModuleInput,
+        store: &mut WasmState,
+        dead_letters: &mut Vec<DeadLetterRecord>,
+        metric: &SmartModuleChainMetrics,
+    ) -> Result<SmartModuleOutput> {
+        if *instance.error_policy() == ErrorPolicy::Fail {
+            return Self::call_instance(instance, input, store, metric);
+        }
+
+        let version = instance.version();
+        let base_offset = input.base_offset();
+        let base_timestamp = input.base_timestamp();
+        let mut successes = vec![];
+        let mut next_input = input;
+
+        loop {
+            let output = Self::call_instance(instance, next_input.clone(), store, metric)?;
+            successes.extend(output.successes);
+            let Some(error) = output.error else {
+                break;
+            };
+
+            let mut records: Vec<Record> =
+                Decoder::decode_from(&mut Cursor::new(next_input.raw_bytes()), version)?;
+            let failed = records.iter().position(|record| {
+                base_offset + record.preamble.offset_delta() == error.offset
+                    && record.value == error.record_value
+            });
+            let Some(failed) = failed else {
+                // error is not caused by a record of this input, nothing can be skipped
+                return Ok(SmartModuleOutput {
+                    successes,
+                    error: Some(error),
+                });
+            };
+            let remaining = records.split_off(failed + 1);
+
+            debug!(
+                offset = error.offset,
+                "skipping record with SmartModule error"
+            );
+            metric.add_records_skipped(1);
+            if let ErrorPolicy::DeadLetter(topic) = instance.error_policy() {
+                dead_letters.push(DeadLetterRecord {
+                    topic: topic.clone(),
+                    error,
+                });
+            }
+
+            if remaining.is_empty() {
+                break;
+            }
+            next_input = SmartModuleInput::try_from_records(remaining, version)?;
+            next_input.set_base_offset(base_offset);
+            next_input.set_base_timestamp(base_timestamp);
+        }
+
+        Ok(SmartModuleOutput::new(successes))
+    }
+
+    fn call_instance(
+        instance: &mut SmartModuleInstance,
+        input: SmartModuleInput,
+        store: &mut WasmState,
+        metric: &SmartModuleChainMetrics,
+    ) -> Result<SmartModuleOutput> {
+        store.top_up_fuel();
+        let output = instance.process(input, store)?;
+        let fuel_used = store.get_used_fuel();
+        debug!(fuel_used, "fuel used");
+        metric.add_fuel_used(fuel_used);
+        Ok(output)
+    }
+
     pub async fn look_back<F, R>(
         &mut self,
         read_fn: F,
@@ -246,7 +355,7 @@ mod chaining_test {
     use fluvio_smartmodule::dataplane::smartmodule::SmartModuleInput;
 
     use crate::engine::error::EngineError;
-    use crate::engine::config::{Lookback, DEFAULT_SMARTENGINE_VERSION};
+    use crate::engine::config::{ErrorPolicy, Lookback, DEFAULT_SMARTENGINE_VERSION};
 
     use super::super::{
         SmartEngine, SmartModuleChainBuilder, SmartModuleConfig, SmartModuleInitialData,
@@ -478,6 +587,57 @@ mod chaining_test {
         assert_eq!(metrics.invocation_count(), 1);
     }
 
+    const SM_MAP_DOUBLE: &str = "fluvio_wasm_map_double";
+
+    #[ignore]
+    #[test]
+    fn test_chain_error_policy() {
+        let engine = SmartEngine::new();
+        let metrics = SmartModuleChainMetrics::default();
+
+        let mut chain_builder = SmartModuleChainBuilder::default();
+        chain_builder.add_smart_module(
+            SmartModuleConfig::builder()
+                .error_policy(ErrorPolicy::DeadLetter("dlq".to_string()))
+                .build()
+                .unwrap(),
+            read_wasm_module(SM_MAP_DOUBLE),
+        );
+        let mut chain = chain_builder
+            .initialize(&engine)
+            .expect("failed to build chain");
+
+        let input = ["1", "one", "2", "two"]
+            .into_iter()
+            .enumerate()
+            .map(|(offset_delta, value)| {
+                let mut record = Record::new(value);
+                record.preamble.set_offset_delta(offset_delta as i64);
+                record
+            })
+            .collect();
+        let output = chain
+            .process(
+                SmartModuleInput::try_from_records(input, DEFAULT_SMARTENGINE_VERSION)
+                    .expect("input"),
+                &metrics,
+            )
+            .expect("process");
+
+        assert!(output.error.is_none());
+        assert_eq!(output.successes.len(), 2);
+        assert_eq!(output.successes[0].value.as_ref(), b"2");
+        assert_eq!(output.successes[1].value.as_ref(), b"4");
+        assert_eq!(metrics.records_skipped(), 2);
+
+        let dead_letters = chain.take_dead_letters();
+        assert_eq!(dead_letters.len(), 2);
+        assert_eq!(dead_letters[0].topic, "dlq");
+        assert_eq!(dead_letters[0].error.offset, 1);
+        assert_eq!(dead_letters[1].error.offset, 3);
+        assert!(chain.take_dead_letters().is_empty());
+    }
+
     #[test]
     fn test_empty_chain() {
         //given
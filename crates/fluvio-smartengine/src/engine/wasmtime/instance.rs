@@ -12,7 +12,7 @@ use fluvio_smartmodule::dataplane::smartmodule::{
     SmartModuleExtraParams, SmartModuleInput, SmartModuleOutput, SmartModuleInitInput,
 };
 
-use crate::engine::config::Lookback;
+use crate::engine::config::{ErrorPolicy, Lookback};
 
 use super::error::EngineError;
 use super::init::SmartModuleInit;
@@ -26,6 +26,7 @@ pub(crate) struct SmartModuleInstance {
     look_back: Option<SmartModuleLookBack>,
     transform: Box<dyn DowncastableTransform>,
     version: Version,
+    error_policy: ErrorPolicy,
 }
 
 impl SmartModuleInstance {
@@ -46,6 +47,7 @@ impl SmartModuleInstance {
         look_back: Option<SmartModuleLookBack>,
         transform: Box<dyn DowncastableTransform>,
         version: Version,
+        error_policy: ErrorPolicy,
     ) -> Self {
         Self {
             ctx,
@@ -53,6 +55,7 @@ impl SmartModuleInstance {
             look_back,
             transform,
             version,
+            error_policy,
         }
     }
 
@@ -98,6 +101,10 @@ impl SmartModuleInstance {
     pub fn version(&self) -> Version {
         self.version
     }
+
+    pub(crate) fn error_policy(&self) -> &ErrorPolicy {
+        &self.error_policy
+    }
 }
 
 pub(crate) struct SmartModuleInstanceContext {
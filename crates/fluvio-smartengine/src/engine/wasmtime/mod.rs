@@ -6,6 +6,6 @@ pub(crate) mod engine;
 pub(crate) mod instance;
 pub(crate) mod look_back;
 pub(crate) mod limiter;
-pub use engine::{SmartEngine, SmartModuleChainBuilder, SmartModuleChainInstance};
+pub use engine::{SmartEngine, SmartModuleChainBuilder, SmartModuleChainInstance, DeadLetterRecord};
 
 use super::*;
@@ -54,6 +54,12 @@ pub struct TransformationStep {
     pub lookback: Option<Lookback>,
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub with: BTreeMap<String, JsonString>,
+    #[serde(
+        default,
+        rename = "on-error",
+        skip_serializing_if = "ErrorPolicy::is_fail"
+    )]
+    pub on_error: ErrorPolicy,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
@@ -65,6 +71,28 @@ pub struct Lookback {
     pub age: Option<Duration>,
 }
 
+/// What to do with a record that fails in the transformation step
+///
+/// ```yaml
+/// on-error: skip
+/// on-error:
+///   dead-letter: my-topic-dlq
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ErrorPolicy {
+    #[default]
+    Fail,
+    Skip,
+    DeadLetter(String),
+}
+
+impl ErrorPolicy {
+    pub fn is_fail(&self) -> bool {
+        matches!(self, Self::Fail)
+    }
+}
+
 impl Display for TransformationStep {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{self:?}")
@@ -202,7 +230,8 @@ mod tests {
                         with: BTreeMap::from([(
                             "spec".to_string(),
                             JsonString("[{\"operation\":\"shift\",\"spec\":{\"payload\":{\"device\":\"device\"}}},{\"operation\":\"default\",\"spec\":{\"device\":{\"type\":\"mobile\"}}}]".to_string())
-                        )])
+                        )]),
+                        ..Default::default()
                     },
                     TransformationStep {
                         uses: "infinyon/jolt@0.1.0".to_string(),
@@ -210,7 +239,8 @@ mod tests {
                         with: BTreeMap::from([(
                             "spec".to_string(),
                             JsonString("[{\"operation\":\"shift\",\"spec\":{\"payload\":{\"device\":\"device\"}}},{\"operation\":\"default\",\"spec\":{\"device\":{\"type\":\"mobile\"}}}]".to_string())
-                        )])
+                        )]),
+                        ..Default::default()
                     },
                     TransformationStep {
                         uses: "infinyon/json-sql@0.1.0".to_string(),
@@ -218,7 +248,8 @@ mod tests {
                         with: BTreeMap::from([(
                             "mapping".to_string(),
                             JsonString("{\"map-columns\":{\"device_id\":{\"json-key\":\"device.device_id\",\"value\":{\"default\":\"0\",\"required\":true,\"type\":\"int\"}},\"record\":{\"json-key\":\"$\",\"value\":{\"required\":true,\"type\":\"jsonb\"}}},\"table\":\"topic_message_demo\"}".to_string())
-                        )])
+                        )]),
+                        ..Default::default()
                     }
                 ]
             }
@@ -267,4 +298,37 @@ mod tests {
             )])
         );
     }
+
+    #[test]
+    fn test_read_error_policy() {
+        //given
+        //when
+        let config = TransformationConfig::from_file("testdata/transformation/on-error.yaml")
+            .expect("config file");
+
+        //then
+        assert_eq!(config.transforms.len(), 3);
+        assert_eq!(config.transforms[0].on_error, ErrorPolicy::Fail);
+        assert_eq!(config.transforms[1].on_error, ErrorPolicy::Skip);
+        assert_eq!(
+            config.transforms[2].on_error,
+            ErrorPolicy::DeadLetter("device-dlq".to_string())
+        );
+    }
+
+    #[test]
+    fn test_error_policy_from_json() {
+        let step = TransformationStep::try_from(
+            r#"{"uses":"infinyon/jolt@0.1.0","on-error":{"dead-letter":"dlq"}}"#,
+        )
+        .expect("step");
+        assert_eq!(step.on_error, ErrorPolicy::DeadLetter("dlq".to_string()));
+
+        let json = serde_json::to_string(&TransformationStep {
+            uses: "infinyon/jolt@0.1.0".to_string(),
+            ..Default::default()
+        })
+        .expect("json");
+        assert!(!json.contains("on-error"));
+    }
 }
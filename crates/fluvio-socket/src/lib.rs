@@ -5,6 +5,7 @@ mod socket;
 mod stream;
 mod versioned;
 mod stream_socket;
+mod trace;
 
 #[cfg(test)]
 pub mod test_request;
@@ -18,6 +19,7 @@ pub use sink::*;
 pub use stream::*;
 pub use stream_socket::*;
 pub use versioned::*;
+pub use trace::{set_trace_context_provider, TraceContextProvider};
 
 use fluvio_protocol::api::Request;
 use fluvio_protocol::api::RequestMessage;
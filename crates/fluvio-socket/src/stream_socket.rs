@@ -5,6 +5,7 @@ use crate::{
     AsyncResponse, ClientConfig, SharedMultiplexerSocket, SocketError, VersionedSerialSocket,
     Versions,
 };
+use crate::trace::trace_context_for;
 
 const DEFAULT_STREAM_QUEUE_SIZE: usize = 10;
 
@@ -51,6 +52,9 @@ impl StreamSocket {
         req_msg
             .header
             .set_client_id(self.config.client_id().to_owned());
+        req_msg
+            .header
+            .set_trace_context(trace_context_for::<R>(version));
         self.socket
             .create_stream(req_msg, DEFAULT_STREAM_QUEUE_SIZE)
             .await
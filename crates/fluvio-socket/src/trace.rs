@@ -0,0 +1,25 @@
+//! Propagation of distributed trace context in outgoing requests.
+
+use once_cell::sync::OnceCell;
+
+use fluvio_protocol::api::{Request, TraceContext};
+
+/// returns trace context of current span if it is part of trace
+pub type TraceContextProvider = fn() -> Option<TraceContext>;
+
+static TRACE_CONTEXT_PROVIDER: OnceCell<TraceContextProvider> = OnceCell::new();
+
+/// install provider used to fill trace context of outgoing requests,
+/// only first provider is kept
+pub fn set_trace_context_provider(provider: TraceContextProvider) {
+    let _ = TRACE_CONTEXT_PROVIDER.set(provider);
+}
+
+/// trace context for request if server accepts it at negotiated `version`
+pub(crate) fn trace_context_for<R: Request>(version: i16) -> Option<TraceContext> {
+    let min_version = R::TRACE_CONTEXT_API_VERSION?;
+    if version < min_version {
+        return None;
+    }
+    TRACE_CONTEXT_PROVIDER.get().and_then(|provider| provider())
+}
@@ -15,6 +15,7 @@ use fluvio_future::net::{DomainConnector, DefaultDomainConnector};
 use fluvio_future::retry::retry_if;
 
 use crate::{SocketError, FluvioSocket, SharedMultiplexerSocket, AsyncResponse};
+use crate::trace::trace_context_for;
 
 /// Frame with request and response
 pub trait SerialFrame: Display {
@@ -345,6 +346,8 @@ impl VersionedSerialSocket {
         if let Some(ver) = version {
             req_msg.get_mut_header().set_api_version(ver);
         }
+        let trace_context = trace_context_for::<R>(req_msg.header.api_version());
+        req_msg.get_mut_header().set_trace_context(trace_context);
         req_msg
     }
 }
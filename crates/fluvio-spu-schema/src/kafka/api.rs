@@ -0,0 +1,215 @@
+use std::convert::TryInto;
+use std::io::{Error as IoError, ErrorKind};
+use std::fmt;
+
+use tracing::trace;
+
+use fluvio_protocol::bytes::Buf;
+use fluvio_protocol::Encoder;
+use fluvio_protocol::api::{ApiMessage, Request};
+use fluvio_protocol::api::api_decode;
+use fluvio_protocol::api::RequestHeader;
+use fluvio_protocol::api::RequestMessage;
+
+use super::KafkaApiKey;
+use super::api_versions::KafkaApiVersionsRequest;
+use super::metadata::MetadataRequest;
+use super::produce::KafkaProduceRequest;
+use super::fetch::KafkaFetchRequest;
+use super::list_offsets::ListOffsetsRequest;
+use super::offset_commit::OffsetCommitRequest;
+use super::offset_fetch::OffsetFetchRequest;
+use super::find_coordinator::FindCoordinatorRequest;
+
+/// Request to the Kafka listener
+#[derive(Debug, Encoder)]
+pub enum KafkaRequest {
+    #[fluvio(tag = 0)]
+    ApiVersionsRequest(RequestMessage<KafkaApiVersionsRequest>),
+    #[fluvio(tag = 1)]
+    MetadataRequest(RequestMessage<MetadataRequest>),
+    #[fluvio(tag = 2)]
+    KafkaProduceRequest(RequestMessage<KafkaProduceRequest>),
+    #[fluvio(tag = 3)]
+    KafkaFetchRequest(RequestMessage<KafkaFetchRequest>),
+    #[fluvio(tag = 4)]
+    ListOffsetsRequest(RequestMessage<ListOffsetsRequest>),
+    #[fluvio(tag = 5)]
+    OffsetCommitRequest(RequestMessage<OffsetCommitRequest>),
+    #[fluvio(tag = 6)]
+    OffsetFetchRequest(RequestMessage<OffsetFetchRequest>),
+    #[fluvio(tag = 7)]
+    FindCoordinatorRequest(RequestMessage<FindCoordinatorRequest>),
+}
+
+impl fmt::Display for KafkaRequest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ApiVersionsRequest(_) => write!(f, "ApiVersionsRequest"),
+            Self::MetadataRequest(_) => write!(f, "MetadataRequest"),
+            Self::KafkaProduceRequest(_) => write!(f, "KafkaProduceRequest"),
+            Self::KafkaFetchRequest(_) => write!(f, "KafkaFetchRequest"),
+            Self::ListOffsetsRequest(_) => write!(f, "ListOffsetsRequest"),
+            Self::OffsetCommitRequest(_) => write!(f, "OffsetCommitRequest"),
+            Self::OffsetFetchRequest(_) => write!(f, "OffsetFetchRequest"),
+            Self::FindCoordinatorRequest(_) => write!(f, "FindCoordinatorRequest"),
+        }
+    }
+}
+
+impl Default for KafkaRequest {
+    fn default() -> Self {
+        Self::ApiVersionsRequest(RequestMessage::<KafkaApiVersionsRequest>::default())
+    }
+}
+
+/// reject versions outside of supported range, flexible versions can't be decoded
+fn check_version<R: Request>(header: &RequestHeader) -> Result<(), IoError> {
+    let version = header.api_version();
+    if (R::MIN_API_VERSION..=R::MAX_API_VERSION).contains(&version) {
+        Ok(())
+    } else {
+        Err(IoError::new(
+            ErrorKind::InvalidData,
+            format!(
+                "unsupported version: {} for kafka api: {}",
+                version,
+                header.api_key()
+            ),
+        ))
+    }
+}
+
+impl ApiMessage for KafkaRequest {
+    type ApiKey = KafkaApiKey;
+
+    fn decode_with_header<T>(src: &mut T, header: RequestHeader) -> Result<Self, IoError>
+    where
+        Self: Default + Sized,
+        Self::ApiKey: Sized,
+        T: Buf,
+    {
+        trace!("decoding with header: {:#?}", header);
+        match header.api_key().try_into()? {
+            // all versions are accepted, newer ones are answered with UNSUPPORTED_VERSION
+            KafkaApiKey::ApiVersions => Ok(Self::ApiVersionsRequest(RequestMessage::new(
+                header,
+                KafkaApiVersionsRequest::default(),
+            ))),
+            KafkaApiKey::Metadata => {
+                check_version::<MetadataRequest>(&header)?;
+                api_decode!(Self, MetadataRequest, src, header)
+            }
+            KafkaApiKey::Produce => {
+                check_version::<KafkaProduceRequest>(&header)?;
+                api_decode!(Self, KafkaProduceRequest, src, header)
+            }
+            KafkaApiKey::Fetch => {
+                check_version::<KafkaFetchRequest>(&header)?;
+                api_decode!(Self, KafkaFetchRequest, src, header)
+            }
+            KafkaApiKey::ListOffsets => {
+                check_version::<ListOffsetsRequest>(&header)?;
+                api_decode!(Self, ListOffsetsRequest, src, header)
+            }
+            KafkaApiKey::OffsetCommit => {
+                check_version::<OffsetCommitRequest>(&header)?;
+                api_decode!(Self, OffsetCommitRequest, src, header)
+            }
+            KafkaApiKey::OffsetFetch => {
+                check_version::<OffsetFetchRequest>(&header)?;
+                api_decode!(Self, OffsetFetchRequest, src, header)
+            }
+            KafkaApiKey::FindCoordinator => {
+                check_version::<FindCoordinatorRequest>(&header)?;
+                api_decode!(Self, FindCoordinatorRequest, src, header)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::io::Cursor;
+
+    use super::*;
+
+    /// kafka request header v1 followed by body
+    fn kafka_request(api_key: i16, api_version: i16, body: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&api_key.to_be_bytes());
+        bytes.extend_from_slice(&api_version.to_be_bytes());
+        bytes.extend_from_slice(&7_i32.to_be_bytes());
+        bytes.extend_from_slice(&(-1_i16).to_be_bytes()); // null client id
+        bytes.extend_from_slice(body);
+        bytes
+    }
+
+    #[test]
+    fn test_decode_api_versions_flexible() {
+        // v3 body uses compact strings and tagged fields, it's not decoded
+        let bytes = kafka_request(18, 3, &[0x02, b'a', 0x02, b'1', 0x00]);
+        let request = KafkaRequest::decode_from(&mut Cursor::new(bytes)).expect("decode");
+        match request {
+            KafkaRequest::ApiVersionsRequest(req) => {
+                assert_eq!(req.header.api_version(), 3);
+                assert_eq!(req.header.correlation_id(), 7);
+            }
+            _ => panic!("expected api versions request"),
+        }
+    }
+
+    #[test]
+    fn test_decode_metadata_request() {
+        let mut body = vec![];
+        body.extend_from_slice(&1_i32.to_be_bytes());
+        body.extend_from_slice(&5_i16.to_be_bytes());
+        body.extend_from_slice(b"topic");
+        body.push(0x00); // allow_auto_topic_creation
+        let bytes = kafka_request(3, 5, &body);
+        let request = KafkaRequest::decode_from(&mut Cursor::new(bytes)).expect("decode");
+        match request {
+            KafkaRequest::MetadataRequest(req) => {
+                assert_eq!(req.request.topics.len(), 1);
+                assert_eq!(req.request.topics[0].name, "topic");
+            }
+            _ => panic!("expected metadata request"),
+        }
+    }
+
+    #[test]
+    fn test_decode_unsupported_version() {
+        let bytes = kafka_request(3, 9, &[]);
+        assert!(KafkaRequest::decode_from(&mut Cursor::new(bytes)).is_err());
+    }
+
+    #[test]
+    fn test_decode_produce_request() {
+        let mut body = vec![];
+        body.extend_from_slice(&(-1_i16).to_be_bytes()); // transactional_id
+        body.extend_from_slice(&(-1_i16).to_be_bytes()); // acks
+        body.extend_from_slice(&1000_i32.to_be_bytes()); // timeout
+        body.extend_from_slice(&1_i32.to_be_bytes());
+        body.extend_from_slice(&5_i16.to_be_bytes());
+        body.extend_from_slice(b"topic");
+        body.extend_from_slice(&1_i32.to_be_bytes());
+        body.extend_from_slice(&2_i32.to_be_bytes()); // partition
+        body.extend_from_slice(&3_i32.to_be_bytes());
+        body.extend_from_slice(&[1, 2, 3]);
+        let bytes = kafka_request(0, 7, &body);
+        let request = KafkaRequest::decode_from(&mut Cursor::new(bytes)).expect("decode");
+        match request {
+            KafkaRequest::KafkaProduceRequest(req) => {
+                let produce = req.request;
+                assert!(produce.transactional_id.as_deref().is_none());
+                assert_eq!(produce.acks, -1);
+                assert_eq!(produce.timeout_ms, 1000);
+                let partition = &produce.topics[0].partitions[0];
+                assert_eq!(partition.partition_index, 2);
+                assert_eq!(partition.records.len(), 3);
+            }
+            _ => panic!("expected produce request"),
+        }
+    }
+}
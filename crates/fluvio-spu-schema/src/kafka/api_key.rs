@@ -0,0 +1,28 @@
+use fluvio_protocol::{Decoder, Encoder};
+
+// Make sure that the ApiVersions variant matches dataplane's API_VERSIONS_KEY
+static_assertions::const_assert_eq!(
+    fluvio_protocol::link::versions::VERSIONS_API_KEY,
+    KafkaApiKey::ApiVersions as u16,
+);
+
+/// Api Key for the Kafka compatible listener
+#[repr(u16)]
+#[derive(Eq, PartialEq, Debug, Encoder, Decoder, Clone, Copy)]
+#[fluvio(encode_discriminant)]
+pub enum KafkaApiKey {
+    Produce = 0,
+    Fetch = 1,
+    ListOffsets = 2,
+    Metadata = 3,
+    OffsetCommit = 8,
+    OffsetFetch = 9,
+    FindCoordinator = 10,
+    ApiVersions = 18,
+}
+
+impl Default for KafkaApiKey {
+    fn default() -> Self {
+        Self::ApiVersions
+    }
+}
@@ -0,0 +1,68 @@
+use fluvio_protocol::api::Request;
+use fluvio_protocol::{Decoder, Encoder};
+
+use super::KafkaApiKey;
+use super::metadata::MetadataRequest;
+use super::produce::KafkaProduceRequest;
+use super::fetch::KafkaFetchRequest;
+use super::list_offsets::ListOffsetsRequest;
+use super::offset_commit::OffsetCommitRequest;
+use super::offset_fetch::OffsetFetchRequest;
+use super::find_coordinator::FindCoordinatorRequest;
+
+/// Kafka ApiVersions request.
+///
+/// Versions 3 and above use the flexible encoding. Their body is not decoded,
+/// the listener answers them with `UNSUPPORTED_VERSION` in version 0 format
+/// as Kafka brokers do, and clients retry with version 2.
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct KafkaApiVersionsRequest {}
+
+impl Request for KafkaApiVersionsRequest {
+    const API_KEY: u16 = KafkaApiKey::ApiVersions as u16;
+
+    const MIN_API_VERSION: i16 = 0;
+    const MAX_API_VERSION: i16 = 2;
+    const DEFAULT_API_VERSION: i16 = 2;
+
+    type Response = KafkaApiVersionsResponse;
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct KafkaApiVersionsResponse {
+    pub error_code: i16,
+    pub api_keys: Vec<KafkaApiVersionKey>,
+    #[fluvio(min_version = 1)]
+    pub throttle_time_ms: i32,
+}
+
+#[derive(Decoder, Encoder, Default, Debug, Clone, PartialEq, Eq)]
+pub struct KafkaApiVersionKey {
+    pub api_key: i16,
+    pub min_version: i16,
+    pub max_version: i16,
+}
+
+impl KafkaApiVersionKey {
+    fn of<R: Request>() -> Self {
+        Self {
+            api_key: R::API_KEY as i16,
+            min_version: R::MIN_API_VERSION,
+            max_version: R::MAX_API_VERSION,
+        }
+    }
+}
+
+/// Api versions supported by the Kafka listener
+pub fn supported_api_versions() -> Vec<KafkaApiVersionKey> {
+    vec![
+        KafkaApiVersionKey::of::<KafkaProduceRequest>(),
+        KafkaApiVersionKey::of::<KafkaFetchRequest>(),
+        KafkaApiVersionKey::of::<ListOffsetsRequest>(),
+        KafkaApiVersionKey::of::<MetadataRequest>(),
+        KafkaApiVersionKey::of::<OffsetCommitRequest>(),
+        KafkaApiVersionKey::of::<OffsetFetchRequest>(),
+        KafkaApiVersionKey::of::<FindCoordinatorRequest>(),
+        KafkaApiVersionKey::of::<KafkaApiVersionsRequest>(),
+    ]
+}
@@ -0,0 +1,88 @@
+use fluvio_protocol::api::Request;
+use fluvio_protocol::{Decoder, Encoder};
+
+use crate::Isolation;
+
+use super::KafkaApiKey;
+use super::NullableBytes;
+
+/// Kafka Fetch request
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct KafkaFetchRequest {
+    /// Always -1 for consumers
+    pub replica_id: i32,
+    pub max_wait_ms: i32,
+    pub min_bytes: i32,
+    pub max_bytes: i32,
+    pub isolation_level: Isolation,
+    pub topics: Vec<KafkaFetchTopic>,
+}
+
+impl Request for KafkaFetchRequest {
+    const API_KEY: u16 = KafkaApiKey::Fetch as u16;
+
+    // record batch v2 with isolation level starts at version 4
+    const MIN_API_VERSION: i16 = 4;
+    const MAX_API_VERSION: i16 = 6;
+    const DEFAULT_API_VERSION: i16 = 6;
+
+    type Response = KafkaFetchResponse;
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct KafkaFetchTopic {
+    pub name: String,
+    pub partitions: Vec<KafkaFetchPartition>,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct KafkaFetchPartition {
+    pub partition_index: i32,
+    pub fetch_offset: i64,
+    #[fluvio(min_version = 5)]
+    pub log_start_offset: i64,
+    pub partition_max_bytes: i32,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct KafkaFetchResponse {
+    pub throttle_time_ms: i32,
+    pub topics: Vec<KafkaFetchTopicResponse>,
+}
+
+impl KafkaFetchResponse {
+    /// total size of records in the response
+    pub fn records_len(&self) -> usize {
+        self.topics
+            .iter()
+            .flat_map(|topic| topic.partitions.iter())
+            .map(|partition| partition.records.len())
+            .sum()
+    }
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct KafkaFetchTopicResponse {
+    pub name: String,
+    pub partitions: Vec<KafkaFetchPartitionResponse>,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct KafkaFetchPartitionResponse {
+    pub partition_index: i32,
+    pub error_code: i16,
+    pub high_watermark: i64,
+    pub last_stable_offset: i64,
+    #[fluvio(min_version = 5)]
+    pub log_start_offset: i64,
+    /// Always empty, transactions are not supported
+    pub aborted_transactions: Vec<KafkaAbortedTransaction>,
+    /// Kafka record batches v2
+    pub records: NullableBytes,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct KafkaAbortedTransaction {
+    pub producer_id: i64,
+    pub first_offset: i64,
+}
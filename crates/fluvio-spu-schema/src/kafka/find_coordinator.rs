@@ -0,0 +1,38 @@
+use fluvio_protocol::api::Request;
+use fluvio_protocol::{Decoder, Encoder};
+
+use super::KafkaApiKey;
+use super::NullableString;
+
+/// Kafka FindCoordinator request.
+///
+/// Coordinator of every group is the leader of the consumer offsets partition.
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct FindCoordinatorRequest {
+    pub key: String,
+    /// 0 for groups, 1 for transactions
+    #[fluvio(min_version = 1)]
+    pub key_type: i8,
+}
+
+impl Request for FindCoordinatorRequest {
+    const API_KEY: u16 = KafkaApiKey::FindCoordinator as u16;
+
+    const MIN_API_VERSION: i16 = 0;
+    const MAX_API_VERSION: i16 = 2;
+    const DEFAULT_API_VERSION: i16 = 2;
+
+    type Response = FindCoordinatorResponse;
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct FindCoordinatorResponse {
+    #[fluvio(min_version = 1)]
+    pub throttle_time_ms: i32,
+    pub error_code: i16,
+    #[fluvio(min_version = 1)]
+    pub error_message: NullableString,
+    pub node_id: i32,
+    pub host: String,
+    pub port: i32,
+}
@@ -0,0 +1,69 @@
+use fluvio_protocol::api::Request;
+use fluvio_protocol::{Decoder, Encoder};
+
+use crate::Isolation;
+
+use super::KafkaApiKey;
+
+/// timestamp asking for the offset of the next record to be written
+pub const LATEST_TIMESTAMP: i64 = -1;
+
+/// timestamp asking for the first available offset
+pub const EARLIEST_TIMESTAMP: i64 = -2;
+
+/// Kafka ListOffsets request
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct ListOffsetsRequest {
+    pub replica_id: i32,
+    #[fluvio(min_version = 2)]
+    pub isolation_level: Isolation,
+    pub topics: Vec<ListOffsetsTopic>,
+}
+
+impl Request for ListOffsetsRequest {
+    const API_KEY: u16 = KafkaApiKey::ListOffsets as u16;
+
+    // version 0 returns list of offsets instead of single offset
+    const MIN_API_VERSION: i16 = 1;
+    const MAX_API_VERSION: i16 = 4;
+    const DEFAULT_API_VERSION: i16 = 4;
+
+    type Response = ListOffsetsResponse;
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct ListOffsetsTopic {
+    pub name: String,
+    pub partitions: Vec<ListOffsetsPartition>,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct ListOffsetsPartition {
+    pub partition_index: i32,
+    #[fluvio(min_version = 4)]
+    pub current_leader_epoch: i32,
+    pub timestamp: i64,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct ListOffsetsResponse {
+    #[fluvio(min_version = 2)]
+    pub throttle_time_ms: i32,
+    pub topics: Vec<ListOffsetsTopicResponse>,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct ListOffsetsTopicResponse {
+    pub name: String,
+    pub partitions: Vec<ListOffsetsPartitionResponse>,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct ListOffsetsPartitionResponse {
+    pub partition_index: i32,
+    pub error_code: i16,
+    pub timestamp: i64,
+    pub offset: i64,
+    #[fluvio(min_version = 4)]
+    pub leader_epoch: i32,
+}
@@ -0,0 +1,72 @@
+use fluvio_protocol::api::Request;
+use fluvio_protocol::{Decoder, Encoder};
+
+use super::KafkaApiKey;
+use super::NullableString;
+
+/// Kafka Metadata request
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct MetadataRequest {
+    /// The topics to fetch metadata for. Empty or null returns all topics.
+    pub topics: Vec<MetadataRequestTopic>,
+
+    /// Ignored, topics are never created from the Kafka listener.
+    #[fluvio(min_version = 4)]
+    pub allow_auto_topic_creation: bool,
+}
+
+impl Request for MetadataRequest {
+    const API_KEY: u16 = KafkaApiKey::Metadata as u16;
+
+    const MIN_API_VERSION: i16 = 0;
+    const MAX_API_VERSION: i16 = 5;
+    const DEFAULT_API_VERSION: i16 = 5;
+
+    type Response = MetadataResponse;
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct MetadataRequestTopic {
+    pub name: String,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct MetadataResponse {
+    #[fluvio(min_version = 3)]
+    pub throttle_time_ms: i32,
+    pub brokers: Vec<MetadataResponseBroker>,
+    #[fluvio(min_version = 2)]
+    pub cluster_id: NullableString,
+    #[fluvio(min_version = 1)]
+    pub controller_id: i32,
+    pub topics: Vec<MetadataResponseTopic>,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct MetadataResponseBroker {
+    pub node_id: i32,
+    pub host: String,
+    pub port: i32,
+    #[fluvio(min_version = 1)]
+    pub rack: NullableString,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct MetadataResponseTopic {
+    pub error_code: i16,
+    pub name: String,
+    #[fluvio(min_version = 1)]
+    pub is_internal: bool,
+    pub partitions: Vec<MetadataResponsePartition>,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct MetadataResponsePartition {
+    pub error_code: i16,
+    pub partition_index: i32,
+    pub leader_id: i32,
+    pub replica_nodes: Vec<i32>,
+    pub isr_nodes: Vec<i32>,
+    #[fluvio(min_version = 5)]
+    pub offline_replicas: Vec<i32>,
+}
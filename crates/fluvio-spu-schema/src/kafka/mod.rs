@@ -0,0 +1,24 @@
+//!
+//! # Kafka wire protocol
+//!
+//! Subset of the Kafka protocol served by the optional Kafka listener of the SPU.
+//! Only non-flexible versions of each API are supported, which covers
+//! clients that negotiate versions through `ApiVersions`.
+//!
+mod api_key;
+mod api;
+mod types;
+
+pub mod error;
+pub mod api_versions;
+pub mod metadata;
+pub mod produce;
+pub mod fetch;
+pub mod list_offsets;
+pub mod offset_commit;
+pub mod offset_fetch;
+pub mod find_coordinator;
+
+pub use self::api_key::KafkaApiKey;
+pub use self::api::KafkaRequest;
+pub use self::types::{NullableString, NullableBytes};
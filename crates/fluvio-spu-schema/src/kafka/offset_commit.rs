@@ -0,0 +1,62 @@
+use fluvio_protocol::api::Request;
+use fluvio_protocol::{Decoder, Encoder};
+
+use super::KafkaApiKey;
+use super::NullableString;
+
+/// Kafka OffsetCommit request.
+///
+/// Group id is used as Fluvio consumer id. Group membership is not supported,
+/// so generation and member id are ignored.
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct OffsetCommitRequest {
+    pub group_id: String,
+    pub generation_id: i32,
+    pub member_id: String,
+    #[fluvio(max_version = 4)]
+    pub retention_time_ms: i64,
+    pub topics: Vec<OffsetCommitTopic>,
+}
+
+impl Request for OffsetCommitRequest {
+    const API_KEY: u16 = KafkaApiKey::OffsetCommit as u16;
+
+    const MIN_API_VERSION: i16 = 2;
+    const MAX_API_VERSION: i16 = 5;
+    const DEFAULT_API_VERSION: i16 = 5;
+
+    type Response = OffsetCommitResponse;
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct OffsetCommitTopic {
+    pub name: String,
+    pub partitions: Vec<OffsetCommitPartition>,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct OffsetCommitPartition {
+    pub partition_index: i32,
+    pub committed_offset: i64,
+    /// Not stored
+    pub committed_metadata: NullableString,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct OffsetCommitResponse {
+    #[fluvio(min_version = 3)]
+    pub throttle_time_ms: i32,
+    pub topics: Vec<OffsetCommitTopicResponse>,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct OffsetCommitTopicResponse {
+    pub name: String,
+    pub partitions: Vec<OffsetCommitPartitionResponse>,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct OffsetCommitPartitionResponse {
+    pub partition_index: i32,
+    pub error_code: i16,
+}
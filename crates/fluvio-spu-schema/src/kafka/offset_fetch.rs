@@ -0,0 +1,56 @@
+use fluvio_protocol::api::Request;
+use fluvio_protocol::{Decoder, Encoder};
+
+use super::KafkaApiKey;
+use super::NullableString;
+
+/// Kafka OffsetFetch request
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct OffsetFetchRequest {
+    pub group_id: String,
+    /// Empty or null returns all committed offsets of the group
+    pub topics: Vec<OffsetFetchTopic>,
+}
+
+impl Request for OffsetFetchRequest {
+    const API_KEY: u16 = KafkaApiKey::OffsetFetch as u16;
+
+    // version 0 reads offsets from zookeeper
+    const MIN_API_VERSION: i16 = 1;
+    const MAX_API_VERSION: i16 = 5;
+    const DEFAULT_API_VERSION: i16 = 5;
+
+    type Response = OffsetFetchResponse;
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct OffsetFetchTopic {
+    pub name: String,
+    pub partition_indexes: Vec<i32>,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct OffsetFetchResponse {
+    #[fluvio(min_version = 3)]
+    pub throttle_time_ms: i32,
+    pub topics: Vec<OffsetFetchTopicResponse>,
+    #[fluvio(min_version = 2)]
+    pub error_code: i16,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct OffsetFetchTopicResponse {
+    pub name: String,
+    pub partitions: Vec<OffsetFetchPartitionResponse>,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct OffsetFetchPartitionResponse {
+    pub partition_index: i32,
+    /// -1 if there is no committed offset
+    pub committed_offset: i64,
+    #[fluvio(min_version = 5)]
+    pub committed_leader_epoch: i32,
+    pub metadata: NullableString,
+    pub error_code: i16,
+}
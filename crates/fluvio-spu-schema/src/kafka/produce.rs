@@ -0,0 +1,66 @@
+use fluvio_protocol::api::Request;
+use fluvio_protocol::{Decoder, Encoder};
+
+use super::KafkaApiKey;
+use super::{NullableString, NullableBytes};
+
+/// Kafka Produce request.
+///
+/// Records are kept as raw Kafka record batches, they are converted to Fluvio batches by the SPU.
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct KafkaProduceRequest {
+    /// Transactions are not supported, non null values are rejected
+    pub transactional_id: NullableString,
+
+    /// 0 no response, 1 leader only, -1 all in-sync replicas
+    pub acks: i16,
+
+    pub timeout_ms: i32,
+
+    pub topics: Vec<KafkaProduceTopic>,
+}
+
+impl Request for KafkaProduceRequest {
+    const API_KEY: u16 = KafkaApiKey::Produce as u16;
+
+    // versions below 3 carry message sets v0/v1 which are not supported
+    const MIN_API_VERSION: i16 = 3;
+    const MAX_API_VERSION: i16 = 7;
+    const DEFAULT_API_VERSION: i16 = 7;
+
+    type Response = KafkaProduceResponse;
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct KafkaProduceTopic {
+    pub name: String,
+    pub partitions: Vec<KafkaProducePartition>,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct KafkaProducePartition {
+    pub partition_index: i32,
+    pub records: NullableBytes,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct KafkaProduceResponse {
+    pub topics: Vec<KafkaProduceTopicResponse>,
+    pub throttle_time_ms: i32,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct KafkaProduceTopicResponse {
+    pub name: String,
+    pub partitions: Vec<KafkaProducePartitionResponse>,
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct KafkaProducePartitionResponse {
+    pub partition_index: i32,
+    pub error_code: i16,
+    pub base_offset: i64,
+    pub log_append_time_ms: i64,
+    #[fluvio(min_version = 5)]
+    pub log_start_offset: i64,
+}
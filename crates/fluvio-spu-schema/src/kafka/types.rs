@@ -0,0 +1,193 @@
+use std::io::{Error as IoError, ErrorKind};
+
+use bytes::{Buf, BufMut, Bytes};
+
+use fluvio_protocol::{Decoder, Encoder, Version};
+
+/// Kafka `NULLABLE_STRING`, length of -1 is null.
+///
+/// `Option<String>` can't be used because Fluvio encodes options with a bool prefix.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct NullableString(pub Option<String>);
+
+impl NullableString {
+    pub fn as_deref(&self) -> Option<&str> {
+        self.0.as_deref()
+    }
+}
+
+impl From<Option<String>> for NullableString {
+    fn from(value: Option<String>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<String> for NullableString {
+    fn from(value: String) -> Self {
+        Self(Some(value))
+    }
+}
+
+impl Encoder for NullableString {
+    fn write_size(&self, version: Version) -> usize {
+        match &self.0 {
+            Some(value) => value.write_size(version),
+            None => 0_i16.write_size(version),
+        }
+    }
+
+    fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), IoError>
+    where
+        T: BufMut,
+    {
+        match &self.0 {
+            Some(value) => value.encode(dest, version),
+            None => (-1_i16).encode(dest, version),
+        }
+    }
+}
+
+impl Decoder for NullableString {
+    fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), IoError>
+    where
+        T: Buf,
+    {
+        if src.remaining() < 2 {
+            return Err(IoError::new(
+                ErrorKind::UnexpectedEof,
+                "can't read nullable string length",
+            ));
+        }
+        if src.chunk()[..2] == (-1_i16).to_be_bytes() {
+            src.advance(2);
+            self.0 = None;
+            return Ok(());
+        }
+        let mut value = String::default();
+        value.decode(src, version)?;
+        self.0 = Some(value);
+        Ok(())
+    }
+}
+
+/// Kafka `NULLABLE_BYTES`, length of -1 is null.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct NullableBytes(pub Option<Bytes>);
+
+impl NullableBytes {
+    pub fn len(&self) -> usize {
+        self.0.as_ref().map(|b| b.len()).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl From<Bytes> for NullableBytes {
+    fn from(value: Bytes) -> Self {
+        Self(Some(value))
+    }
+}
+
+impl From<Vec<u8>> for NullableBytes {
+    fn from(value: Vec<u8>) -> Self {
+        Self(Some(Bytes::from(value)))
+    }
+}
+
+impl Encoder for NullableBytes {
+    fn write_size(&self, version: Version) -> usize {
+        0_i32.write_size(version) + self.len()
+    }
+
+    fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), IoError>
+    where
+        T: BufMut,
+    {
+        match &self.0 {
+            Some(value) => {
+                (value.len() as i32).encode(dest, version)?;
+                if dest.remaining_mut() < value.len() {
+                    return Err(IoError::new(
+                        ErrorKind::UnexpectedEof,
+                        "not enough capacity for nullable bytes",
+                    ));
+                }
+                dest.put_slice(value);
+                Ok(())
+            }
+            None => (-1_i32).encode(dest, version),
+        }
+    }
+}
+
+impl Decoder for NullableBytes {
+    fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), IoError>
+    where
+        T: Buf,
+    {
+        let mut len: i32 = 0;
+        len.decode(src, version)?;
+        if len < 0 {
+            self.0 = None;
+            return Ok(());
+        }
+        let len = len as usize;
+        if src.remaining() < len {
+            return Err(IoError::new(
+                ErrorKind::UnexpectedEof,
+                format!(
+                    "expected nullable bytes len: {} but found {}",
+                    len,
+                    src.remaining()
+                ),
+            ));
+        }
+        self.0 = Some(src.copy_to_bytes(len));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_nullable_string_codec() {
+        let mut dest = vec![];
+        NullableString(None).encode(&mut dest, 0).expect("encode");
+        NullableString::from("abc".to_owned())
+            .encode(&mut dest, 0)
+            .expect("encode");
+        assert_eq!(dest, vec![0xff, 0xff, 0x00, 0x03, b'a', b'b', b'c']);
+
+        let mut src = Cursor::new(dest);
+        let null = NullableString::decode_from(&mut src, 0).expect("decode");
+        let value = NullableString::decode_from(&mut src, 0).expect("decode");
+        assert_eq!(null.as_deref(), None);
+        assert_eq!(value.as_deref(), Some("abc"));
+    }
+
+    #[test]
+    fn test_nullable_bytes_codec() {
+        let mut dest = vec![];
+        NullableBytes(None).encode(&mut dest, 0).expect("encode");
+        NullableBytes::from(vec![1, 2])
+            .encode(&mut dest, 0)
+            .expect("encode");
+        assert_eq!(
+            dest,
+            vec![0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x02, 0x01, 0x02]
+        );
+
+        let mut src = Cursor::new(dest);
+        let null = NullableBytes::decode_from(&mut src, 0).expect("decode");
+        let value = NullableBytes::decode_from(&mut src, 0).expect("decode");
+        assert!(null.0.is_none());
+        assert_eq!(value.0.as_deref(), Some([1_u8, 2].as_ref()));
+    }
+}
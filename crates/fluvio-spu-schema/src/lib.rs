@@ -4,6 +4,7 @@ pub mod server;
 pub mod client;
 pub mod fetch;
 pub mod produce;
+pub mod kafka;
 mod isolation;
 
 #[cfg(feature = "file")]
@@ -17,4 +18,7 @@ pub use fluvio_protocol::link::versions::{ApiVersions, ApiVersionsRequest, ApiVe
 pub use isolation::*;
 
 /// Default API version for all API
-pub const COMMON_VERSION: i16 = 23;
+pub const COMMON_VERSION: i16 = 28;
+
+/// first version where SPU accepts trace context in request header
+pub const TRACE_CONTEXT_API: i16 = 28;
@@ -13,7 +13,7 @@ use fluvio_protocol::api::Request;
 use fluvio_protocol::record::RecordSet;
 use fluvio_types::PartitionId;
 
-use crate::COMMON_VERSION;
+use crate::{COMMON_VERSION, TRACE_CONTEXT_API};
 use crate::isolation::Isolation;
 
 use super::ProduceResponse;
@@ -24,6 +24,7 @@ pub type DefaultPartitionRequest = PartitionProduceData<RecordSet<RawRecords>>;
 pub type DefaultTopicRequest = TopicProduceData<RecordSet<RawRecords>>;
 
 const PRODUCER_TRANSFORMATION_API_VERSION: i16 = 8;
+const PRODUCER_FSYNC_API_VERSION: i16 = 24;
 
 #[derive(FluvioDefault, Debug)]
 pub struct ProduceRequest<R> {
@@ -44,6 +45,10 @@ pub struct ProduceRequest<R> {
     #[fluvio(min_version = PRODUCER_TRANSFORMATION_API)]
     pub smartmodules: Vec<SmartModuleInvocation>,
 
+    /// Wait for the leader to sync written records to disk before responding.
+    #[fluvio(min_version = PRODUCER_FSYNC_API_VERSION)]
+    pub wait_for_fsync: bool,
+
     pub data: PhantomData<R>,
 }
 
@@ -55,6 +60,7 @@ where
 
     const MIN_API_VERSION: i16 = 0;
     const DEFAULT_API_VERSION: i16 = COMMON_VERSION;
+    const TRACE_CONTEXT_API_VERSION: Option<i16> = Some(TRACE_CONTEXT_API);
 
     type Response = ProduceResponse;
 }
@@ -92,6 +98,11 @@ where
             } else {
                 0
             }
+            + if version >= PRODUCER_FSYNC_API_VERSION {
+                self.wait_for_fsync.write_size(version)
+            } else {
+                0
+            }
     }
 
     fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), Error>
@@ -105,6 +116,9 @@ where
         if version >= PRODUCER_TRANSFORMATION_API_VERSION {
             self.smartmodules.encode(dest, version)?;
         }
+        if version >= PRODUCER_FSYNC_API_VERSION {
+            self.wait_for_fsync.encode(dest, version)?;
+        }
         Ok(())
     }
 }
@@ -124,6 +138,9 @@ where
         if version >= PRODUCER_TRANSFORMATION_API_VERSION {
             self.smartmodules.decode(src, version)?;
         }
+        if version >= PRODUCER_FSYNC_API_VERSION {
+            self.wait_for_fsync.decode(src, version)?;
+        }
         Ok(())
     }
 }
@@ -137,6 +154,7 @@ impl<R: Encoder + Decoder + Default + Debug + Clone> Clone for ProduceRequest<R>
             topics: self.topics.clone(),
             data: self.data,
             smartmodules: self.smartmodules.clone(),
+            wait_for_fsync: self.wait_for_fsync,
         }
     }
 }
@@ -306,6 +324,7 @@ mod tests {
         let request = DefaultProduceRequest {
             isolation: Isolation::ReadCommitted,
             timeout: Duration::from_millis(123456),
+            wait_for_fsync: true,
             ..Default::default()
         };
 
@@ -316,6 +335,7 @@ mod tests {
 
         assert_eq!(request.isolation, decoded.isolation);
         assert_eq!(request.timeout, decoded.timeout);
+        assert_eq!(request.wait_for_fsync, decoded.wait_for_fsync);
         Ok(())
     }
 
@@ -360,6 +380,7 @@ mod tests {
             }],
             data: Default::default(),
             smartmodules: Default::default(),
+            wait_for_fsync: true,
         };
         let version = DefaultProduceRequest::DEFAULT_API_VERSION;
 
@@ -389,6 +410,7 @@ mod tests {
                 params,
             }],
             data: std::marker::PhantomData,
+            ..Default::default()
         };
         //when
         value
@@ -458,6 +480,7 @@ mod tests {
                 params,
             }],
             data: std::marker::PhantomData,
+            ..Default::default()
         };
         //when
         value
@@ -469,7 +492,7 @@ mod tests {
             0x01, 0x00, 0x04, 0x74, 0x5f, 0x69, 0x64, 0xff, 0xff, 0x00, 0x00, 0x03, 0xe8, 0x00,
             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x04, 0xde, 0xad,
             0xbe, 0xef, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
-            0x00,
+            0x00, 0x00, 0x00,
         ];
         assert_eq!(dest, expected);
     }
@@ -491,6 +514,7 @@ mod tests {
                 params,
             }],
             data: std::marker::PhantomData,
+            ..Default::default()
         };
         //when
         value
@@ -502,7 +526,7 @@ mod tests {
             0x01, 0x00, 0x04, 0x74, 0x5f, 0x69, 0x64, 0xff, 0xff, 0x00, 0x00, 0x03, 0xe8, 0x00,
             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x04, 0xde, 0xad,
             0xbe, 0xef, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
-            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00,
         ];
         assert_eq!(dest, expected);
     }
@@ -514,7 +538,7 @@ mod tests {
             0x01, 0x00, 0x04, 0x74, 0x5f, 0x69, 0x64, 0xff, 0xff, 0x00, 0x00, 0x03, 0xe8, 0x00,
             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x04, 0xde, 0xad,
             0xbe, 0xef, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
-            0x00,
+            0x00, 0x00, 0x00,
         ];
         let mut value = DefaultProduceRequest::default();
 
@@ -552,7 +576,7 @@ mod tests {
             0x01, 0x00, 0x04, 0x74, 0x5f, 0x69, 0x64, 0xff, 0xff, 0x00, 0x00, 0x03, 0xe8, 0x00,
             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x04, 0xde, 0xad,
             0xbe, 0xef, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
-            0x00,
+            0x00, 0x00,
         ];
         let mut value = DefaultProduceRequest::default();
 
@@ -23,8 +23,10 @@ use super::fetch_offset::FetchOffsetsRequest;
 use super::stream_fetch::FileStreamFetchRequest;
 use super::consumer_offset::{
     UpdateConsumerOffsetRequest, DeleteConsumerOffsetRequest, FetchConsumerOffsetsRequest,
+    SetConsumerOffsetRequest,
 };
 use super::update_offset::UpdateOffsetsRequest;
+use super::update_stream_fetch::UpdateStreamFetchRequest;
 use super::mirror::StartMirrorRequest;
 
 #[allow(clippy::large_enum_variant)]
@@ -54,6 +56,10 @@ pub enum SpuServerRequest {
     FetchConsumerOffsetsRequest(RequestMessage<FetchConsumerOffsetsRequest>),
     #[fluvio(tag = 9)]
     StartMirrorRequest(RequestMessage<StartMirrorRequest>),
+    #[fluvio(tag = 10)]
+    UpdateStreamFetchRequest(RequestMessage<UpdateStreamFetchRequest>),
+    #[fluvio(tag = 11)]
+    SetConsumerOffsetRequest(RequestMessage<SetConsumerOffsetRequest>),
 }
 
 impl fmt::Display for SpuServerRequest {
@@ -69,6 +75,8 @@ impl fmt::Display for SpuServerRequest {
             Self::DeleteConsumerOffsetRequest(_) => write!(f, "DeleteConsumerOffsetRequest"),
             Self::FetchConsumerOffsetsRequest(_) => write!(f, "FetchConsumerOffsetsRequest"),
             Self::StartMirrorRequest(_) => write!(f, "StartMirrorRequest"),
+            Self::UpdateStreamFetchRequest(_) => write!(f, "UpdateStreamFetchRequest"),
+            Self::SetConsumerOffsetRequest(_) => write!(f, "SetConsumerOffsetRequest"),
         }
     }
 }
@@ -111,6 +119,12 @@ impl ApiMessage for SpuServerRequest {
                 api_decode!(Self, FetchConsumerOffsetsRequest, src, header)
             }
             SpuServerApiKey::StartMirror => api_decode!(Self, StartMirrorRequest, src, header),
+            SpuServerApiKey::UpdateStreamFetch => {
+                api_decode!(Self, UpdateStreamFetchRequest, src, header)
+            }
+            SpuServerApiKey::SetConsumerOffset => {
+                api_decode!(Self, SetConsumerOffsetRequest, src, header)
+            }
         }
     }
 }
@@ -23,6 +23,8 @@ pub enum SpuServerApiKey {
     UpdateConsumerOffset = 1006,
     DeleteConsumerOffset = 1007,
     FetchConsumerOffsets = 1008,
+    UpdateStreamFetch = 1009,
+    SetConsumerOffset = 1010,
 
     StartMirror = 2000,
 }
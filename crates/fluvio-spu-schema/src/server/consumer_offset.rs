@@ -62,6 +62,40 @@ pub struct DeleteConsumerOffsetResponse {
     pub error_code: ErrorCode,
 }
 
+/// Overwrite the offset of a consumer, creating it if it doesn't exist
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct SetConsumerOffsetRequest {
+    pub replica_id: ReplicaKey,
+    pub consumer_id: String,
+    /// offset of the last record consumed, the consumer continues from the next one
+    pub offset: Offset,
+}
+
+impl SetConsumerOffsetRequest {
+    pub fn new(
+        replica_id: impl Into<ReplicaKey>,
+        consumer_id: impl Into<String>,
+        offset: Offset,
+    ) -> Self {
+        Self {
+            replica_id: replica_id.into(),
+            consumer_id: consumer_id.into(),
+            offset,
+        }
+    }
+}
+
+impl Request for SetConsumerOffsetRequest {
+    const API_KEY: u16 = SpuServerApiKey::SetConsumerOffset as u16;
+    const DEFAULT_API_VERSION: i16 = COMMON_VERSION;
+    type Response = SetConsumerOffsetResponse;
+}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct SetConsumerOffsetResponse {
+    pub error_code: ErrorCode,
+}
+
 #[derive(Decoder, Encoder, Default, Debug)]
 pub struct FetchConsumerOffsetsRequest;
 
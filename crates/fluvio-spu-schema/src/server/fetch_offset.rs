@@ -9,7 +9,7 @@ use fluvio_protocol::{Encoder, Decoder};
 use fluvio_protocol::record::PartitionOffset;
 use fluvio_protocol::record::ReplicaKey;
 
-use fluvio_types::PartitionId;
+use fluvio_types::{PartitionId, Timestamp};
 
 use crate::COMMON_VERSION;
 use crate::errors::ErrorCode;
@@ -19,6 +19,9 @@ use super::SpuServerApiKey;
 // FlvFetchOffsetsRequest
 // -----------------------------------
 
+/// version with offsets of timestamps
+pub const FETCH_OFFSET_TIMESTAMP_API: i16 = 27;
+
 /// Fetch offsets
 #[derive(Decoder, Encoder, Default, Debug)]
 pub struct FetchOffsetsRequest {
@@ -43,6 +46,7 @@ impl FetchOffsetsRequest {
                 name: topic,
                 partitions: vec![FetchOffsetPartition {
                     partition_index: partition,
+                    timestamp: None,
                 }],
             }],
             consumer_id,
@@ -63,6 +67,10 @@ pub struct FetchOffsetTopic {
 pub struct FetchOffsetPartition {
     /// The partition index.
     pub partition_index: PartitionId,
+
+    /// Find the offset of records produced at or after this timestamp (ms)
+    #[fluvio(min_version = FETCH_OFFSET_TIMESTAMP_API)]
+    pub timestamp: Option<Timestamp>,
 }
 
 // -----------------------------------
@@ -113,6 +121,10 @@ pub struct FetchOffsetPartitionResponse {
 
     /// Last readable offset
     pub last_stable_offset: i64,
+
+    /// Base offset of the first batch with records produced at or after the requested timestamp
+    #[fluvio(min_version = FETCH_OFFSET_TIMESTAMP_API)]
+    pub timestamp_offset: Option<i64>,
 }
 
 impl fmt::Display for FetchOffsetPartitionResponse {
@@ -12,6 +12,8 @@ use flate2::{
 use fluvio_protocol::{Encoder, Decoder};
 use fluvio_smartmodule::dataplane::smartmodule::SmartModuleExtraParams;
 
+use super::stream_fetch::SMARTMODULE_ERROR_POLICY;
+
 /// The request payload when using a Consumer SmartModule.
 ///
 /// This includes the WASM module name as well as the invocation being used.
@@ -21,6 +23,8 @@ pub struct SmartModuleInvocation {
     pub wasm: SmartModuleInvocationWasm,
     pub kind: SmartModuleKind,
     pub params: SmartModuleExtraParams,
+    #[fluvio(min_version = SMARTMODULE_ERROR_POLICY)]
+    pub on_error: SmartModuleErrorPolicy,
 }
 
 #[derive(Clone, Encoder, Decoder)]
@@ -130,6 +134,31 @@ pub enum SmartModuleContextData {
     },
 }
 
+/// What to do with a record when the SmartModule returns an error for it
+#[derive(Debug, Clone, Encoder, Decoder, Default, PartialEq, Eq)]
+pub enum SmartModuleErrorPolicy {
+    /// Stop processing and return the error
+    #[default]
+    #[fluvio(tag = 0)]
+    Fail,
+    /// Drop the record and continue with the next one
+    #[fluvio(tag = 1)]
+    Skip,
+    /// Drop the record and write it with the error to the given topic.
+    /// Records are written when they are produced, stream fetch rejects this policy
+    #[fluvio(tag = 2)]
+    DeadLetter(String),
+}
+
+impl SmartModuleErrorPolicy {
+    pub fn dead_letter_topic(&self) -> Option<&str> {
+        match self {
+            Self::DeadLetter(topic) => Some(topic),
+            _ => None,
+        }
+    }
+}
+
 fn zip(raw: &[u8]) -> io::Result<Vec<u8>> {
     let mut encoder = GzEncoder::new(raw, Compression::default());
     let mut buffer = Vec::with_capacity(raw.len());
@@ -168,6 +197,37 @@ mod tests {
         assert!(matches!(value, SmartModuleKind::Map));
     }
 
+    #[test]
+    fn test_encode_decode_error_policy() {
+        let invocation = SmartModuleInvocation {
+            on_error: SmartModuleErrorPolicy::DeadLetter("dlq".to_owned()),
+            ..Default::default()
+        };
+
+        let mut dest = Vec::new();
+        invocation
+            .encode(&mut dest, SMARTMODULE_ERROR_POLICY)
+            .expect("should encode");
+        let decoded = SmartModuleInvocation::decode_from(
+            &mut io::Cursor::new(&dest),
+            SMARTMODULE_ERROR_POLICY,
+        )
+        .expect("should decode");
+        assert_eq!(decoded.on_error.dead_letter_topic(), Some("dlq"));
+
+        // older clients don't send the policy
+        let mut dest = Vec::new();
+        invocation
+            .encode(&mut dest, SMARTMODULE_ERROR_POLICY - 1)
+            .expect("should encode");
+        let decoded = SmartModuleInvocation::decode_from(
+            &mut io::Cursor::new(&dest),
+            SMARTMODULE_ERROR_POLICY - 1,
+        )
+        .expect("should decode");
+        assert_eq!(decoded.on_error, SmartModuleErrorPolicy::Fail);
+    }
+
     #[test]
     fn test_gzip_smartmoduleinvocationwasm() {
         let bytes = vec![0xde, 0xad, 0xbe, 0xef];
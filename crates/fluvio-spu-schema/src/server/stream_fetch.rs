@@ -16,7 +16,7 @@ use fluvio_protocol::record::RecordSet;
 use fluvio_smartmodule::dataplane::smartmodule::SmartModuleExtraParams;
 use fluvio_types::{PartitionId, defaults::FLUVIO_CLIENT_MAX_FETCH_BYTES};
 
-use crate::COMMON_VERSION;
+use crate::{COMMON_VERSION, TRACE_CONTEXT_API};
 use crate::fetch::FetchablePartitionResponse;
 use crate::isolation::Isolation;
 
@@ -54,6 +54,12 @@ pub const SMARTMODULE_TIMESTAMP: i16 = 22;
 
 pub const OFFSET_MANAGEMENT_API: i16 = 23;
 
+// version for SmartModule error policy
+pub const SMARTMODULE_ERROR_POLICY: i16 = 25;
+
+// version for pause, resume and seek of running streams
+pub const STREAM_FETCH_UPDATE_API: i16 = 26;
+
 /// Fetch records continuously
 /// Output will be send back as stream
 #[allow(deprecated)]
@@ -106,6 +112,7 @@ where
 {
     const API_KEY: u16 = SpuServerApiKey::StreamFetch as u16;
     const DEFAULT_API_VERSION: i16 = COMMON_VERSION;
+    const TRACE_CONTEXT_API_VERSION: Option<i16> = Some(TRACE_CONTEXT_API);
     type Response = StreamFetchResponse<R>;
 }
 
@@ -120,6 +127,9 @@ pub struct StreamFetchResponse<R> {
     pub topic: String,
     pub stream_id: u32,
     pub partition: FetchablePartitionResponse<R>,
+    /// incremented on every seek, responses with older epoch were sent before the seek
+    #[fluvio(min_version = STREAM_FETCH_UPDATE_API)]
+    pub epoch: u32,
 }
 
 #[cfg(feature = "file")]
@@ -154,6 +164,9 @@ mod file {
             self.topic.encode(src, version)?;
             self.stream_id.encode(src, version)?;
             self.partition.file_encode(src, data, version)?;
+            if version >= STREAM_FETCH_UPDATE_API {
+                self.epoch.encode(src, version)?;
+            }
             Ok(())
         }
     }
@@ -242,7 +255,7 @@ mod tests {
             ..Default::default()
         };
         value
-            .encode(&mut dest, DefaultStreamFetchRequest::MAX_API_VERSION - 1)
+            .encode(&mut dest, OFFSET_MANAGEMENT_API - 1)
             .expect("should encode");
         let expected = vec![
             0x00, 0x03, 0x6f, 0x6e, 0x65, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -321,10 +334,7 @@ mod tests {
         ];
         let mut value = DefaultStreamFetchRequest::default();
         value
-            .decode(
-                &mut std::io::Cursor::new(bytes),
-                DefaultStreamFetchRequest::MAX_API_VERSION - 1,
-            )
+            .decode(&mut std::io::Cursor::new(bytes), OFFSET_MANAGEMENT_API - 1)
             .unwrap();
         assert_eq!(value.topic, "one");
         assert_eq!(value.partition, 3);
@@ -0,0 +1,83 @@
+//!
+//! # Update Stream Fetch
+//!
+//! Pause, resume or reposition a running stream fetch
+//!
+
+use fluvio_protocol::api::Request;
+use fluvio_protocol::{Encoder, Decoder};
+use fluvio_protocol::record::Offset;
+use fluvio_types::Timestamp;
+
+use crate::COMMON_VERSION;
+use crate::errors::ErrorCode;
+use super::SpuServerApiKey;
+
+#[derive(Debug, Clone, PartialEq, Eq, Encoder, Decoder, Default)]
+pub enum StreamFetchAction {
+    /// Stop sending records until the stream is resumed
+    #[default]
+    #[fluvio(tag = 0)]
+    Pause,
+    /// Continue sending records from the current position
+    #[fluvio(tag = 1)]
+    Resume,
+    /// Continue from the absolute offset
+    #[fluvio(tag = 2)]
+    Seek(Offset),
+    /// Continue from the first batch containing records produced at or after the timestamp (ms)
+    #[fluvio(tag = 3)]
+    SeekTimestamp(Timestamp),
+}
+
+/// update stream fetch session on the SPU
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct UpdateStreamFetchRequest {
+    pub session_id: u32,
+    pub action: StreamFetchAction,
+}
+
+impl Request for UpdateStreamFetchRequest {
+    const API_KEY: u16 = SpuServerApiKey::UpdateStreamFetch as u16;
+    const DEFAULT_API_VERSION: i16 = COMMON_VERSION;
+    type Response = UpdateStreamFetchResponse;
+}
+
+impl UpdateStreamFetchRequest {
+    pub fn new(session_id: u32, action: StreamFetchAction) -> Self {
+        Self { session_id, action }
+    }
+}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct UpdateStreamFetchResponse {
+    pub error_code: ErrorCode,
+    /// offset the stream continues from
+    pub offset: Offset,
+    /// epoch of the stream responses sent after this update
+    pub epoch: u32,
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_encode_decode_update_stream_fetch() {
+        let request = UpdateStreamFetchRequest::new(3, StreamFetchAction::SeekTimestamp(1000));
+
+        let mut dest = Vec::new();
+        request
+            .encode(&mut dest, COMMON_VERSION)
+            .expect("should encode");
+        let decoded =
+            UpdateStreamFetchRequest::decode_from(&mut Cursor::new(&dest), COMMON_VERSION)
+                .expect("should decode");
+
+        assert_eq!(decoded.session_id, 3);
+        assert_eq!(decoded.action, StreamFetchAction::SeekTimestamp(1000));
+    }
+}
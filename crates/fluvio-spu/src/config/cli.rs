@@ -15,6 +15,8 @@ use clap::Parser;
 use fluvio_types::print_cli_err;
 use fluvio_types::SpuId;
 use fluvio_future::openssl::TlsAcceptor;
+use fluvio_storage::tiered::TieredStorageConfig;
+use fluvio_auth::TenantPolicy;
 
 use super::SpuConfig;
 
@@ -34,7 +36,15 @@ pub struct SpuOpt {
     /// Spu server for internal cluster communication
     pub bind_private: Option<String>,
 
-    /// Address of the SC Server
+    /// Spu server for Kafka compatible clients, disabled if not set
+    #[arg(long, value_name = "host:port", env = "FLV_KAFKA_ADDR")]
+    pub kafka_addr: Option<String>,
+
+    /// Http server serving metrics in OpenMetrics format, disabled if not set
+    #[arg(long, value_name = "host:port", env = "FLV_METRICS_ADDR")]
+    pub metrics_addr: Option<String>,
+
+    /// Address of the SC Server, comma separated list for replicated SC
     #[arg(long, value_name = "host:port", env = "FLV_SC_PRIVATE_HOST")]
     pub sc_addr: Option<String>,
 
@@ -66,6 +76,76 @@ pub struct SpuOpt {
     )]
     pub smart_engine_max_memory: Option<usize>,
 
+    /// Offload closed segments to object store in this directory
+    #[arg(
+        long,
+        value_name = "dir",
+        env = "FLV_TIERED_STORAGE_DIR",
+        group = "tiered_storage"
+    )]
+    pub tiered_storage_dir: Option<String>,
+
+    /// Offload closed segments to bucket of S3 compatible service.
+    /// Credentials are read from AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY
+    #[arg(
+        long,
+        value_name = "bucket",
+        env = "FLV_TIERED_STORAGE_S3_BUCKET",
+        group = "tiered_storage",
+        requires = "tiered_storage_s3_endpoint"
+    )]
+    pub tiered_storage_s3_bucket: Option<String>,
+
+    /// Url of S3 compatible service, for example https://s3.us-east-1.amazonaws.com
+    #[arg(
+        long,
+        value_name = "url",
+        env = "FLV_TIERED_STORAGE_S3_ENDPOINT",
+        requires = "tiered_storage_s3_bucket"
+    )]
+    pub tiered_storage_s3_endpoint: Option<String>,
+
+    /// Region of S3 bucket
+    #[arg(
+        long,
+        value_name = "region",
+        env = "FLV_TIERED_STORAGE_S3_REGION",
+        default_value = "us-east-1"
+    )]
+    pub tiered_storage_s3_region: String,
+
+    /// Number of closed segments per replica kept locally when tiered storage is enabled
+    #[arg(
+        long,
+        value_name = "integer",
+        env = "FLV_TIERED_LOCAL_SEGMENTS",
+        requires = "tiered_storage"
+    )]
+    pub tiered_local_segments: Option<u32>,
+
+    /// Verify checksum of batches before they are sent to consumers and followers
+    #[arg(long, env = "FLV_VERIFY_CHECKSUM_ON_FETCH")]
+    pub verify_checksum_on_fetch: bool,
+
+    /// Skip verifying checksum of all stored batches when replicas are loaded at startup.
+    /// Corrupted batches are then only detected when fetched with checksum verification
+    #[arg(long, env = "FLV_SKIP_CHECKSUM_ON_LOAD")]
+    pub skip_checksum_on_load: bool,
+
+    /// Tenants of principals and their limits. Clients are authenticated by TLS certificate,
+    /// can only access topics of their tenant and their throughput is limited per SPU
+    #[arg(
+        long,
+        value_name = "tenant policy path",
+        env = "FLV_TENANT_POLICY",
+        requires = "tls"
+    )]
+    pub tenant_policy: Option<std::path::PathBuf>,
+
+    /// Seconds to wait for leadership of partitions to move to followers on shutdown
+    #[arg(long, value_name = "seconds", env = "FLV_SHUTDOWN_TIMEOUT")]
+    pub shutdown_timeout: Option<u64>,
+
     #[clap(flatten)]
     tls: TlsConfig,
 }
@@ -141,6 +221,16 @@ impl SpuOpt {
             config.private_endpoint = private_addr;
         }
 
+        if let Some(kafka_addr) = self.kafka_addr {
+            info!("enabling kafka listener: {}", kafka_addr);
+            config.kafka_endpoint = Some(kafka_addr);
+        }
+
+        if let Some(metrics_addr) = self.metrics_addr {
+            info!("enabling metrics endpoint: {}", metrics_addr);
+            config.metrics_endpoint = Some(metrics_addr);
+        }
+
         config.peer_max_bytes = self.peer_max_bytes;
 
         if let Some(smart_engine_max_memory) = self.smart_engine_max_memory {
@@ -151,6 +241,42 @@ impl SpuOpt {
             config.smart_engine.store_max_memory = smart_engine_max_memory;
         }
 
+        let tiered_config = if let Some(tiered_storage_dir) = self.tiered_storage_dir {
+            info!("enabling tiered storage at: {}", tiered_storage_dir);
+            Some(TieredStorageConfig::local(tiered_storage_dir))
+        } else if let (Some(bucket), Some(endpoint)) = (
+            self.tiered_storage_s3_bucket,
+            self.tiered_storage_s3_endpoint,
+        ) {
+            info!(%bucket, %endpoint, "enabling tiered storage in S3 bucket");
+            Some(TieredStorageConfig::s3(
+                endpoint,
+                bucket,
+                self.tiered_storage_s3_region,
+            ))
+        } else {
+            None
+        };
+        if let Some(mut tiered_config) = tiered_config {
+            if let Some(local_segments) = self.tiered_local_segments {
+                tiered_config.local_segments = local_segments;
+            }
+            config.log.tiered_storage = Some(tiered_config);
+        }
+
+        config.log.verify_checksum_on_fetch = self.verify_checksum_on_fetch;
+        config.log.verify_checksum_on_load = !self.skip_checksum_on_load;
+
+        if let Some(tenant_policy) = self.tenant_policy {
+            info!("using tenant policy: {}", tenant_policy.display());
+            config.tenant_policy = Some(TenantPolicy::try_from(tenant_policy)?);
+        }
+
+        if let Some(shutdown_timeout) = self.shutdown_timeout {
+            info!("overriding shutdown timeout: {}s", shutdown_timeout);
+            config.shutdown_timeout = std::time::Duration::from_secs(shutdown_timeout);
+        }
+
         Ok((config, tls_port))
     }
 
@@ -10,39 +10,49 @@
 
 use std::env;
 use std::path::PathBuf;
+use std::time::Duration;
 
 // defaults values
 use fluvio_types::defaults::SPU_PUBLIC_PORT;
 use fluvio_types::defaults::SPU_PRIVATE_PORT;
 use fluvio_types::defaults::SC_PRIVATE_PORT;
 use fluvio_types::defaults::SPU_LOG_BASE_DIR;
+use fluvio_types::defaults::SPU_LOG_DIR_PREFIX;
 use fluvio_types::defaults::SPU_LOG_SIZE;
 use fluvio_types::defaults::SPU_LOG_INDEX_MAX_BYTES;
 use fluvio_types::defaults::SPU_LOG_INDEX_MAX_INTERVAL_BYTES;
 use fluvio_types::defaults::SPU_LOG_SEGMENT_MAX_BYTES;
 use fluvio_types::defaults::SPU_RETRY_SC_TIMEOUT_MS;
+use fluvio_types::defaults::SPU_SHUTDOWN_TIMEOUT_SEC;
 use fluvio_types::defaults::SPU_SMARTENGINE_STORE_MAX_BYTES;
 
 // environment variables
 
 use fluvio_types::defaults::SPU_MIN_IN_SYNC_REPLICAS;
+use fluvio_types::defaults::SPU_REPLICA_MAX_LAG_SEC;
 use fluvio_types::defaults::FLV_LOG_BASE_DIR;
 use fluvio_types::defaults::FLV_LOG_SIZE;
 use fluvio_types::SpuId;
 use fluvio_storage::config::ReplicaConfig;
+use fluvio_storage::tiered::TieredStorageConfig;
+use fluvio_auth::TenantPolicy;
 use fluvio_types::defaults::{
     STORAGE_FLUSH_IDLE_MSEC, STORAGE_FLUSH_WRITE_COUNT, STORAGE_MAX_BATCH_SIZE,
 };
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct ReplicationConfig {
+    /// used for topics which don't set min in-sync replicas
     pub min_in_sync_replicas: u16,
+    /// follower is out of sync if it has not caught up with leader within this time
+    pub max_follower_lag: Duration,
 }
 
 impl Default for ReplicationConfig {
     fn default() -> Self {
         Self {
             min_in_sync_replicas: SPU_MIN_IN_SYNC_REPLICAS,
+            max_follower_lag: Duration::from_secs(SPU_REPLICA_MAX_LAG_SEC),
         }
     }
 }
@@ -57,6 +67,9 @@ pub struct Log {
     pub flush_write_count: u32,
     pub flush_idle_msec: u32,
     pub max_batch_size: u32,
+    pub tiered_storage: Option<TieredStorageConfig>,
+    pub verify_checksum_on_fetch: bool,
+    pub verify_checksum_on_load: bool,
 }
 
 impl Default for Log {
@@ -72,6 +85,9 @@ impl Default for Log {
             flush_write_count: STORAGE_FLUSH_WRITE_COUNT,
             flush_idle_msec: STORAGE_FLUSH_IDLE_MSEC,
             max_batch_size: STORAGE_MAX_BATCH_SIZE,
+            tiered_storage: None,
+            verify_checksum_on_fetch: false,
+            verify_checksum_on_load: true,
         }
     }
 }
@@ -99,6 +115,10 @@ pub struct SpuConfig {
     // spu (local server) points
     pub public_endpoint: String,
     pub private_endpoint: String,
+    /// optional kafka compatible listener
+    pub kafka_endpoint: Option<String>,
+    /// optional http listener serving OpenMetrics
+    pub metrics_endpoint: Option<String>,
 
     // sc (remote server) endpoint
     pub sc_endpoint: String,
@@ -111,6 +131,12 @@ pub struct SpuConfig {
     pub peer_max_bytes: u32,
 
     pub smart_engine: SmartEngineConfig,
+
+    /// tenants of authenticated principals, which can only access topics of their tenant
+    pub tenant_policy: Option<TenantPolicy>,
+
+    /// max time to wait for leadership handoff on shutdown
+    pub shutdown_timeout: Duration,
 }
 
 impl Default for SpuConfig {
@@ -120,12 +146,16 @@ impl Default for SpuConfig {
             rack: None,
             public_endpoint: format!("0.0.0.0:{SPU_PUBLIC_PORT}"),
             private_endpoint: format!("0.0.0.0:{SPU_PRIVATE_PORT}"),
+            kafka_endpoint: None,
+            metrics_endpoint: None,
             sc_endpoint: format!("localhost:{SC_PRIVATE_PORT}"),
             replication: ReplicationConfig::default(),
             sc_retry_ms: SPU_RETRY_SC_TIMEOUT_MS,
             log: Log::default(),
             peer_max_bytes: fluvio_storage::FileReplica::PREFER_MAX_LEN,
             smart_engine: SmartEngineConfig::default(),
+            tenant_policy: None,
+            shutdown_timeout: Duration::from_secs(SPU_SHUTDOWN_TIMEOUT_SEC),
         }
     }
 }
@@ -158,6 +188,22 @@ impl SpuConfig {
         &self.private_endpoint
     }
 
+    pub fn kafka_socket_addr(&self) -> Option<&str> {
+        self.kafka_endpoint.as_deref()
+    }
+
+    /// port of kafka listener, advertised to kafka clients
+    pub fn kafka_port(&self) -> Option<u16> {
+        self.kafka_endpoint
+            .as_ref()
+            .and_then(|endpoint| endpoint.rsplit(':').next())
+            .and_then(|port| port.parse().ok())
+    }
+
+    pub fn metrics_socket_addr(&self) -> Option<&str> {
+        self.metrics_endpoint.as_deref()
+    }
+
     pub fn storage(&self) -> &Log {
         &self.log
     }
@@ -167,13 +213,19 @@ impl From<&SpuConfig> for ReplicaConfig {
     fn from(config: &SpuConfig) -> Self {
         let log = &config.log;
         ReplicaConfig::builder()
-            .base_dir(log.base_dir.join(format!("spu-logs-{}", config.id)))
+            .base_dir(
+                log.base_dir
+                    .join(format!("{SPU_LOG_DIR_PREFIX}{}", config.id)),
+            )
             .index_max_bytes(log.index_max_bytes)
             .index_max_interval_bytes(log.index_max_interval_bytes)
             .segment_max_bytes(log.segment_max_bytes)
             .flush_write_count(log.flush_write_count)
             .flush_idle_msec(log.flush_idle_msec)
             .max_batch_size(log.max_batch_size)
+            .tiered_storage(log.tiered_storage.clone())
+            .verify_checksum_on_fetch(log.verify_checksum_on_fetch)
+            .verify_checksum_on_load(log.verify_checksum_on_load)
             .build()
     }
 }
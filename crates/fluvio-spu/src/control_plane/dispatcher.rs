@@ -8,6 +8,7 @@ use futures_util::stream::StreamExt;
 use anyhow::{anyhow, Result};
 
 use fluvio_controlplane::sc_api::register_spu::RegisterSpuRequest;
+use fluvio_controlplane::sc_api::shutdown_spu::ShutdownSpuRequest;
 use fluvio_controlplane::sc_api::update_lrs::UpdateLrsRequest;
 use fluvio_controlplane::spu_api::api::{InternalSpuRequest, InternalSpuApi};
 use fluvio_controlplane::spu_api::update_replica::UpdateReplicaRequest;
@@ -56,7 +57,8 @@ impl ScDispatcher<FileReplica> {
 
     /// start the controller with ctx and receiver
     pub fn run(self) {
-        spawn(self.dispatch_loop());
+        let tasks = self.ctx.tasks().clone();
+        tasks.track(spawn(self.dispatch_loop()));
     }
 
     async fn dispatch_loop(mut self) {
@@ -129,14 +131,26 @@ impl ScDispatcher<FileReplica> {
 
         let mut status_timer = Timer::interval(MIN_SC_SINK_TIME);
 
+        // shutdown is repeated with status, SC elects again as followers catch up
+        let ctx = self.ctx.clone();
+        let mut shutdown_sent = false;
+
         loop {
             trace!("waiting");
 
             select! {
 
+                _ = ctx.shutdown().listen(), if !shutdown_sent => {
+                    self.send_shutdown_to_sc(&mut sink).await?;
+                    shutdown_sent = true;
+                },
+
                 _ = status_timer.next() =>  {
                     self.send_lrs_status_back_to_sc(&mut sink).await?;
                     self.send_mirror_status_back_to_sc(&mut sink).await?;
+                    if shutdown_sent {
+                        self.send_shutdown_to_sc(&mut sink).await?;
+                    }
                 },
 
                 sc_request = api_stream.next() => {
@@ -221,6 +235,18 @@ impl ScDispatcher<FileReplica> {
             .map_err(|err| anyhow!("error sending status back to sc: {}", err))
     }
 
+    /// ask sc to move leadership of our partitions to followers
+    #[instrument(skip(self))]
+    async fn send_shutdown_to_sc(&mut self, sc_sink: &mut FluvioSink) -> Result<()> {
+        let local_spu_id = self.ctx.local_spu_id();
+        debug!(local_spu_id, "sending shutdown to sc");
+        let message = RequestMessage::new_request(ShutdownSpuRequest::new(local_spu_id));
+        sc_sink
+            .send_request(&message)
+            .await
+            .map_err(|err| anyhow!("error sending shutdown to sc: {}", err))
+    }
+
     /// register local spu to sc
     #[instrument(
         skip(self),
@@ -258,31 +284,41 @@ impl ScDispatcher<FileReplica> {
     }
 
     /// connect to sc if can't connect try until we succeed
-    /// or if we received termination message
+    /// or if we received termination message.
+    /// With replicated SC, endpoint is a comma separated list and only the leader accepts connection
     async fn create_socket_to_sc(&mut self) -> FluvioSocket {
         let spu_id = self.ctx.local_spu_id();
-        let sc_endpoint = self.ctx.config().sc_endpoint().to_string();
+        let sc_endpoints: Vec<String> = self
+            .ctx
+            .config()
+            .sc_endpoint()
+            .split(',')
+            .map(|endpoint| endpoint.trim().to_string())
+            .filter(|endpoint| !endpoint.is_empty())
+            .collect();
 
         let wait_interval = self.ctx.config().sc_retry_ms;
         loop {
-            info!(
-                %sc_endpoint,
-                spu_id,
-                "trying to create socket to sc",
-
-            );
-            match FluvioSocket::connect(&sc_endpoint).await {
-                Ok(socket) => {
-                    info!(spu_id, "connected to sc for spu");
-                    self.counter.reconnect += 1;
-                    return socket;
-                }
-                Err(err) => {
-                    warn!("error connecting to sc: {}", err);
-                    info!(wait_interval, spu_id, "sleeping ms");
-                    sleep(Duration::from_millis(wait_interval as u64)).await;
+            for sc_endpoint in &sc_endpoints {
+                info!(
+                    %sc_endpoint,
+                    spu_id,
+                    "trying to create socket to sc",
+
+                );
+                match FluvioSocket::connect(sc_endpoint).await {
+                    Ok(socket) => {
+                        info!(spu_id, %sc_endpoint, "connected to sc for spu");
+                        self.counter.reconnect += 1;
+                        return socket;
+                    }
+                    Err(err) => {
+                        warn!("error connecting to sc: {}", err);
+                    }
                 }
             }
+            info!(wait_interval, spu_id, "sleeping ms");
+            sleep(Duration::from_millis(wait_interval as u64)).await;
         }
     }
 
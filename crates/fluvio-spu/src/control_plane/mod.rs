@@ -3,7 +3,9 @@
 mod dispatcher;
 mod action;
 mod message_sink;
+mod shutdown;
 
 pub use dispatcher::ScDispatcher;
+pub use shutdown::graceful_shutdown;
 
 pub use message_sink::*;
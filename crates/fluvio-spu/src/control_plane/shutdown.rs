@@ -0,0 +1,202 @@
+//!
+//! # Graceful Shutdown
+//!
+//! Before SPU exits, SC moves leadership of partitions led by this SPU to in-sync followers.
+//! Leaders stop accepting writes first, so followers can catch up while leadership is moved.
+//! SPU waits until leadership is handed off, then syncs replicas to disk.
+//! Partitions without in-sync follower are elected by SC once SPU is offline.
+//!
+use std::time::{Duration, Instant};
+
+use tracing::{debug, error, info, instrument, warn};
+
+use fluvio_future::timer::sleep;
+use fluvio_protocol::record::ReplicaKey;
+use fluvio_storage::FileReplica;
+
+use crate::core::GlobalContext;
+
+const HANDOFF_CHECK_INTERVAL: Duration = Duration::from_millis(200);
+
+/// hand off leadership and sync replicas, waits at most for configured shutdown timeout
+#[instrument(skip(ctx))]
+pub async fn graceful_shutdown(ctx: &GlobalContext<FileReplica>) {
+    let timeout = ctx.config().shutdown_timeout;
+    info!(?timeout, "shutting down, handing off leadership");
+    ctx.shutdown().notify();
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        // producers are redirected to new leader once it is elected
+        fence_leaders(ctx).await;
+        let pending = handoff_pending(ctx).await;
+        if pending.is_empty() {
+            info!("leadership handed off");
+            break;
+        }
+        if Instant::now() >= deadline {
+            warn!(?pending, "timed out waiting for leadership handoff");
+            break;
+        }
+        debug!(pending = pending.len(), "waiting for leadership handoff");
+        sleep(HANDOFF_CHECK_INTERVAL).await;
+    }
+
+    sync_replicas(ctx).await;
+}
+
+/// reject writes to leaders, including ones promoted after shutdown started
+async fn fence_leaders(ctx: &GlobalContext<FileReplica>) {
+    let leaders: Vec<_> = ctx.leaders_state().read().await.values().cloned().collect();
+    for leader in leaders {
+        if !leader.is_fenced() {
+            leader.fence().await;
+            debug!(replica = %leader.id(), "leader fenced");
+        }
+    }
+}
+
+/// leaders with follower which has all committed records, leadership of these can be moved
+async fn handoff_pending(ctx: &GlobalContext<FileReplica>) -> Vec<ReplicaKey> {
+    let leaders: Vec<_> = ctx.leaders_state().read().await.values().cloned().collect();
+    let mut pending = vec![];
+    for leader in leaders {
+        let hw = leader.hw();
+        if leader
+            .followers_info()
+            .await
+            .values()
+            .any(|follower| follower.is_valid() && follower.leo >= hw)
+        {
+            pending.push(leader.id().clone());
+        }
+    }
+    pending
+}
+
+/// write records of all replicas to disk
+async fn sync_replicas(ctx: &GlobalContext<FileReplica>) {
+    let leaders: Vec<_> = ctx.leaders_state().read().await.values().cloned().collect();
+    for leader in leaders {
+        if let Err(err) = leader.sync().await {
+            error!(replica = %leader.id(), %err, "failed to sync leader replica");
+        }
+    }
+
+    let followers: Vec<_> = ctx
+        .followers_state()
+        .read()
+        .await
+        .values()
+        .cloned()
+        .collect();
+    for follower in followers {
+        if let Err(err) = follower.sync().await {
+            error!(replica = %follower.id(), %err, "failed to sync follower replica");
+        }
+    }
+    debug!("replicas synced");
+}
+
+#[cfg(test)]
+mod test {
+    use std::env::temp_dir;
+
+    use flv_util::fixture::ensure_clean_dir;
+    use fluvio_controlplane::replica::Replica;
+    use fluvio_future::task::spawn;
+    use fluvio_protocol::fixture::create_raw_recordset;
+    use fluvio_storage::OffsetInfo;
+    use fluvio_types::SpuId;
+
+    use crate::config::SpuConfig;
+    use crate::replication::leader::LeaderReplicaState;
+    use crate::storage::ReplicaFenced;
+
+    use super::*;
+
+    const LEADER: SpuId = 5001;
+    const FOLLOWER: SpuId = 5002;
+
+    #[fluvio_future::test]
+    async fn test_produce_during_shutdown() {
+        let test_path = temp_dir().join("spu_shutdown_produce");
+        ensure_clean_dir(&test_path);
+
+        let mut spu_config = SpuConfig::default();
+        spu_config.id = LEADER;
+        spu_config.log.base_dir = test_path;
+        spu_config.shutdown_timeout = Duration::from_secs(10);
+        let ctx = GlobalContext::new_shared_context(spu_config);
+
+        let replica = Replica::new(("shutdown", 0), LEADER, vec![LEADER, FOLLOWER]);
+        let replica_id = replica.id.clone();
+        let leader = LeaderReplicaState::create(replica, ctx.config(), ctx.status_update_owned())
+            .await
+            .expect("replica")
+            .init(&ctx)
+            .await
+            .expect("init");
+        ctx.leaders_state()
+            .insert(replica_id.clone(), leader.clone())
+            .await;
+
+        // producer keeps writing until leader rejects records
+        let producer = {
+            let leader = leader.clone();
+            let ctx = ctx.clone();
+            spawn(async move {
+                loop {
+                    let mut records = create_raw_recordset(2);
+                    if let Err(err) = leader
+                        .write_record_set(&mut records, ctx.follower_notifier())
+                        .await
+                    {
+                        return err;
+                    }
+                    sleep(Duration::from_millis(1)).await;
+                }
+            })
+        };
+
+        // follower reports offsets it fetched a moment ago, SC hands off once it is suitable
+        let handoff = {
+            let leader = leader.clone();
+            let ctx = ctx.clone();
+            spawn(async move {
+                loop {
+                    let leo = leader.leo();
+                    sleep(Duration::from_millis(5)).await;
+                    let follower_pos = OffsetInfo { leo, hw: leo };
+                    leader
+                        .update_states_from_followers(
+                            FOLLOWER,
+                            follower_pos,
+                            ctx.follower_notifier(),
+                        )
+                        .await;
+                    if leader.is_fenced() && !handoff_pending(&ctx).await.is_empty() {
+                        ctx.leaders_state().remove(leader.id()).await;
+                        return leo;
+                    }
+                }
+            })
+        };
+
+        sleep(Duration::from_millis(50)).await;
+        let start = Instant::now();
+        graceful_shutdown(&ctx).await;
+        assert!(start.elapsed() < ctx.config().shutdown_timeout);
+
+        let err = producer.await;
+        assert!(err.downcast_ref::<ReplicaFenced>().is_some());
+
+        // follower has every committed record and leader doesn't take more
+        let follower_leo = handoff.await;
+        let leo = leader.leo();
+        assert!(follower_leo >= leader.hw());
+        assert!(leo > 0);
+        sleep(Duration::from_millis(20)).await;
+        assert_eq!(leader.leo(), leo);
+    }
+}
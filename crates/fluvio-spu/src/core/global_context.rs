@@ -9,7 +9,11 @@ use std::fmt::Debug;
 use tracing::{debug, error, instrument};
 
 use fluvio_types::SpuId;
+use fluvio_types::event::StickyEvent;
 use fluvio_storage::ReplicaStorage;
+use fluvio_protocol::link::ErrorCode;
+use fluvio_auth::Tenant;
+use fluvio_service::ServiceTasks;
 
 use crate::config::SpuConfig;
 use crate::control_plane::SharedMirrorStatusUpdate;
@@ -22,6 +26,8 @@ use crate::replication::leader::{
 };
 use crate::control_plane::{StatusLrsMessageSink, SharedLrsStatusUpdate};
 use crate::core::metrics::SpuMetrics;
+use crate::core::traces::ProducedTraces;
+use crate::core::tenant::TenantThrottle;
 use crate::smartengine::SmartEngine;
 
 use super::leader_client::LeaderConnections;
@@ -53,6 +59,10 @@ pub struct GlobalContext<S> {
     mirrors: SharedMirrorLocalStore,
     metrics: Arc<SpuMetrics>,
     consumer_offset: SharedConsumerOffsetStorages,
+    produced_traces: ProducedTraces,
+    tenant_throttle: TenantThrottle,
+    shutdown: Arc<StickyEvent>,
+    tasks: Arc<ServiceTasks>,
 }
 
 // -----------------------------------
@@ -87,6 +97,10 @@ where
             mirrors: MirrorLocalStore::new_shared(),
             metrics,
             consumer_offset: SharedConsumerOffsetStorages::default(),
+            produced_traces: ProducedTraces::default(),
+            tenant_throttle: TenantThrottle::default(),
+            shutdown: StickyEvent::shared(),
+            tasks: Arc::new(ServiceTasks::default()),
         }
     }
 
@@ -185,6 +199,36 @@ where
     pub(crate) fn consumer_offset(&self) -> &SharedConsumerOffsetStorages {
         &self.consumer_offset
     }
+
+    pub(crate) fn produced_traces(&self) -> &ProducedTraces {
+        &self.produced_traces
+    }
+
+    /// set once SPU starts shutting down
+    pub(crate) fn shutdown(&self) -> &StickyEvent {
+        &self.shutdown
+    }
+
+    /// dispatcher and replica controllers, which are stopped with SPU
+    pub(crate) fn tasks(&self) -> &Arc<ServiceTasks> {
+        &self.tasks
+    }
+
+    /// admit bytes produced by tenant, error if tenant exceeds its max throughput
+    pub(crate) fn admit_tenant(&self, tenant: &Tenant, bytes: u64) -> Result<(), ErrorCode> {
+        if self.tenant_throttle.admit(tenant, bytes) {
+            Ok(())
+        } else {
+            debug!(tenant = %tenant.name, bytes, "tenant throughput exceeded, rejecting");
+            Err(ErrorCode::TenantLimitExceeded {
+                tenant: tenant.name.clone(),
+                limit: format!(
+                    "max throughput {} bytes/s",
+                    tenant.limits.max_throughput.unwrap_or_default()
+                ),
+            })
+        }
+    }
 }
 
 mod file_replica {
@@ -195,7 +239,9 @@ mod file_replica {
     };
     use tracing::{trace, warn};
 
+    use fluvio_protocol::record::ReplicaKey;
     use fluvio_storage::FileReplica;
+    use fluvio_types::{PartitionId, defaults::CONSUMER_STORAGE_TOPIC};
     use flv_util::actions::Actions;
 
     use crate::core::SpecChange;
@@ -291,6 +337,15 @@ mod file_replica {
 
                 match replica_action {
                     SpecChange::Add(new_replica) => {
+                        if let Some(old_topic) = &new_replica.renamed_from {
+                            // replica is not started until it owns storage of old replica,
+                            // so rename is retried when the replica is added again
+                            if let Err(err) = self.rename_replica(&new_replica, old_topic).await {
+                                error!(replica = %new_replica.id, %err, "failed to rename replica");
+                                outputs.push(ReplicaChange::StorageError(err));
+                                continue;
+                            }
+                        }
                         if new_replica.is_being_deleted {
                             outputs.push(ReplicaChange::Remove(
                                 self.remove_leader_replica(new_replica).await,
@@ -362,13 +417,20 @@ mod file_replica {
                                             .await
                                     }
                                 }
+                            } else if !new_replica.is_config_changed(&old_replica) {
+                                debug!(replica = %new_replica.id, "no config change");
                             } else if new_replica.leader == local_id {
-                                if self.leaders_state().get(&new_replica.id).await.is_some() {
-                                } else {
-                                    error!("leader controller was not found: {}", new_replica.id);
+                                if let Err(err) = self
+                                    .leaders_state()
+                                    .update_leader_replica(self, new_replica)
+                                    .await
+                                {
+                                    outputs.push(ReplicaChange::StorageError(err));
                                 }
                             } else {
-                                self.followers_state().update_replica(new_replica).await;
+                                self.followers_state()
+                                    .update_replica(self, new_replica)
+                                    .await;
                             }
                         }
                     }
@@ -378,6 +440,59 @@ mod file_replica {
             outputs
         }
 
+        /// Take over consumer offsets and storage of replica before its topic was renamed.
+        /// Old replica is fenced against writes and stopped without removing its storage,
+        /// which is then moved to new replica.
+        /// Applied on every SPU, since consumer offsets may be stored on SPU without the replica
+        #[instrument(
+            skip(self,replica),
+            fields(
+                replica = %replica.id,
+            )
+        )]
+        async fn rename_replica(&self, replica: &Replica, old_topic: &str) -> anyhow::Result<()> {
+            let old_id = ReplicaKey::new(old_topic, replica.id.partition);
+
+            let consumers_replica_id =
+                ReplicaKey::new(CONSUMER_STORAGE_TOPIC, <PartitionId as Default>::default());
+            if let Some(ref leader) = self.leaders_state().get(&consumers_replica_id).await {
+                match self
+                    .consumer_offset()
+                    .get_or_insert(leader, self.follower_notifier())
+                    .await
+                {
+                    Ok(storage) => match storage.move_replica(&old_id, &replica.id).await {
+                        Ok(moved) => debug!(moved, "consumer offsets moved"),
+                        Err(err) => error!(%err, "failed to move consumer offsets"),
+                    },
+                    Err(err) => error!(%err, "consumer offset storage not available"),
+                }
+            }
+
+            if !replica.replicas.contains(&self.local_spu_id()) {
+                return Ok(());
+            }
+
+            // handlers may still hold the old replica, fence it before its storage is moved
+            if let Some(old_leader) = self.leaders_state().get(&old_id).await {
+                old_leader.fence().await;
+                old_leader.sync().await?;
+                old_leader.signal_topic_deleted().await;
+                self.leaders_state().remove(&old_id).await;
+            } else if let Some(old_follower) = self.followers_state().get(&old_id).await {
+                old_follower.fence().await;
+                let leader = old_follower.leader();
+                drop(old_follower);
+                self.followers_state().remove_replica(leader, &old_id).await;
+            }
+            self.metrics.partitions().remove(&old_id);
+            self.produced_traces.remove(&old_id);
+
+            let moved = FileReplica::rename(&old_id, &replica.id, &self.config().into()).await?;
+            debug!(moved, "replica storage renamed");
+            Ok(())
+        }
+
         /// reemove leader replica
         #[instrument(
             skip(self,replica),
@@ -386,6 +501,8 @@ mod file_replica {
             )
         )]
         async fn remove_leader_replica(&self, replica: Replica) -> ReplicaRemovedRequest {
+            self.metrics.partitions().remove(&replica.id);
+            self.produced_traces.remove(&replica.id);
             // try to send message to leader controller if still exists
             if let Some(previous_state) = self.leaders_state().remove(&replica.id).await {
                 previous_state.signal_topic_deleted().await;
@@ -414,6 +531,7 @@ mod file_replica {
         )]
         async fn remove_follower_replica(&self, replica: Replica) {
             debug!("removing follower replica: {}", replica);
+            self.metrics.partitions().remove(&replica.id);
             if let Some(replica_state) = self
                 .followers_state()
                 .remove_replica(replica.leader, &replica.id)
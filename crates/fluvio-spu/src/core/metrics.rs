@@ -1,9 +1,14 @@
 use std::{
-    sync::atomic::{AtomicU64, Ordering},
+    collections::BTreeMap,
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicU64, Ordering},
+    },
     ops::AddAssign,
+    time::Duration,
 };
 
-use fluvio_protocol::record::Batch;
+use fluvio_protocol::record::{Batch, ReplicaKey};
 use fluvio_spu_schema::fetch::FilePartitionResponse;
 use serde::Serialize;
 
@@ -14,6 +19,8 @@ pub(crate) struct SpuMetrics {
     inbound: Activity,
     outbound: Activity,
     smartmodule: SmartModuleChainMetrics,
+    #[serde(skip)]
+    partitions: PartitionMetrics,
 }
 
 impl SpuMetrics {
@@ -32,6 +39,10 @@ impl SpuMetrics {
     pub fn chain_metrics(&self) -> &SmartModuleChainMetrics {
         &self.smartmodule
     }
+
+    pub(crate) fn partitions(&self) -> &PartitionMetrics {
+        &self.partitions
+    }
 }
 
 #[derive(Default, Debug, Serialize)]
@@ -45,6 +56,19 @@ impl Record {
         self.records.fetch_add(records, Ordering::SeqCst);
         self.bytes.fetch_add(bytes, Ordering::SeqCst);
     }
+
+    pub(crate) fn increase_by_value(&self, value: IncreaseValue) {
+        let IncreaseValue { records, bytes } = value;
+        self.increase(records, bytes)
+    }
+
+    pub(crate) fn records(&self) -> u64 {
+        self.records.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn bytes(&self) -> u64 {
+        self.bytes.load(Ordering::SeqCst)
+    }
 }
 
 #[derive(Default, Debug, Serialize)]
@@ -53,13 +77,21 @@ pub struct Activity {
     client: Record,
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone, Copy)]
 pub(crate) struct IncreaseValue {
     records: u64,
     bytes: u64,
 }
 
 impl Activity {
+    pub(crate) fn connector(&self) -> &Record {
+        &self.connector
+    }
+
+    pub(crate) fn client(&self) -> &Record {
+        &self.client
+    }
+
     pub(crate) fn increase(&self, connector: bool, records: u64, bytes: u64) {
         if connector {
             self.connector.increase(records, bytes);
@@ -90,6 +122,90 @@ impl Activity {
     }
 }
 
+/// Activity of a single partition, on leader and follower replicas
+#[derive(Default, Debug)]
+pub(crate) struct PartitionActivity {
+    produced: Record,
+    fetched: Record,
+    follower_sync: SyncLatency,
+}
+
+impl PartitionActivity {
+    pub(crate) fn produced(&self) -> &Record {
+        &self.produced
+    }
+
+    pub(crate) fn fetched(&self) -> &Record {
+        &self.fetched
+    }
+
+    pub(crate) fn follower_sync(&self) -> &SyncLatency {
+        &self.follower_sync
+    }
+}
+
+/// Time spent by a follower to apply records sent by the leader
+#[derive(Default, Debug)]
+pub(crate) struct SyncLatency {
+    count: AtomicU64,
+    sum_micros: AtomicU64,
+}
+
+impl SyncLatency {
+    pub(crate) fn observe(&self, elapsed: Duration) {
+        self.count.fetch_add(1, Ordering::SeqCst);
+        self.sum_micros
+            .fetch_add(elapsed.as_micros() as u64, Ordering::SeqCst);
+    }
+
+    pub(crate) fn count(&self) -> u64 {
+        self.count.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn sum_seconds(&self) -> f64 {
+        self.sum_micros.load(Ordering::SeqCst) as f64 / 1_000_000.0
+    }
+}
+
+#[derive(Default, Debug)]
+pub(crate) struct PartitionMetrics(RwLock<BTreeMap<ReplicaKey, Arc<PartitionActivity>>>);
+
+impl PartitionMetrics {
+    /// activity of the partition, created on first use
+    pub(crate) fn partition(&self, replica: &ReplicaKey) -> Arc<PartitionActivity> {
+        if let Some(activity) = self
+            .0
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .get(replica)
+        {
+            return activity.clone();
+        }
+        self.0
+            .write()
+            .unwrap_or_else(|err| err.into_inner())
+            .entry(replica.clone())
+            .or_default()
+            .clone()
+    }
+
+    pub(crate) fn remove(&self, replica: &ReplicaKey) {
+        self.0
+            .write()
+            .unwrap_or_else(|err| err.into_inner())
+            .remove(replica);
+    }
+
+    pub(crate) fn snapshot(&self) -> Vec<(ReplicaKey, Arc<PartitionActivity>)> {
+        self.0
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .iter()
+            .map(|(replica, activity)| (replica.clone(), activity.clone()))
+            .collect()
+    }
+}
+
 impl IncreaseValue {
     pub(crate) fn new(records: u64, bytes: u64) -> Self {
         Self { records, bytes }
@@ -170,4 +286,30 @@ mod tests {
         assert_eq!(activity.connector.records.load(Ordering::SeqCst), 1);
         assert_eq!(activity.connector.bytes.load(Ordering::SeqCst), 123);
     }
+
+    #[test]
+    fn test_partition_metrics() {
+        let metrics = PartitionMetrics::default();
+        let replica = ReplicaKey::new("topic", 0u32);
+
+        metrics.partition(&replica).produced().increase(2, 20);
+        metrics.partition(&replica).produced().increase(1, 10);
+        metrics
+            .partition(&replica)
+            .follower_sync()
+            .observe(Duration::from_millis(1500));
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.len(), 1);
+        let (key, activity) = &snapshot[0];
+        assert_eq!(key, &replica);
+        assert_eq!(activity.produced().records(), 3);
+        assert_eq!(activity.produced().bytes(), 30);
+        assert_eq!(activity.fetched().records(), 0);
+        assert_eq!(activity.follower_sync().count(), 1);
+        assert_eq!(activity.follower_sync().sum_seconds(), 1.5);
+
+        metrics.remove(&replica);
+        assert!(metrics.snapshot().is_empty());
+    }
 }
@@ -7,6 +7,8 @@ pub mod replica;
 pub mod smartmodule;
 pub mod metrics;
 pub mod mirror;
+pub(crate) mod traces;
+pub(crate) mod tenant;
 
 pub use self::global_context::{GlobalContext, ReplicaChange};
 pub use self::store::Spec;
@@ -19,3 +19,48 @@ impl Spec for Replica {
 }
 
 pub type ReplicaStore = LocalStore<Replica>;
+
+impl ReplicaStore {
+    /// resolve replica addressed by topic alias to replica of the topic.
+    /// Replica is returned unchanged if it exists or no topic has the alias
+    pub fn resolve(&self, replica: ReplicaKey) -> ReplicaKey {
+        if self.contains_key(&replica) {
+            return replica;
+        }
+        self.read()
+            .values()
+            .find(|spec| spec.id.partition == replica.partition && spec.has_alias(&replica.topic))
+            .map(|spec| spec.id.clone())
+            .unwrap_or(replica)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_resolve_alias() {
+        let store = ReplicaStore::default();
+        let mut replica = Replica::new(("orders", 0), 5001, vec![5001]);
+        replica.aliases = vec!["purchases".to_owned()];
+        store.insert(replica);
+
+        assert_eq!(
+            store.resolve(ReplicaKey::new("purchases", 0u32)),
+            ReplicaKey::new("orders", 0u32)
+        );
+        assert_eq!(
+            store.resolve(ReplicaKey::new("orders", 0u32)),
+            ReplicaKey::new("orders", 0u32)
+        );
+        assert_eq!(
+            store.resolve(ReplicaKey::new("purchases", 1u32)),
+            ReplicaKey::new("purchases", 1u32)
+        );
+        assert_eq!(
+            store.resolve(ReplicaKey::new("unknown", 0u32)),
+            ReplicaKey::new("unknown", 0u32)
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//!
+//! # Tenant Throughput
+//!
+//! Bytes produced by each tenant to this SPU are limited by a token bucket
+//! refilled at the max throughput of tenant. Tenant is the one of authenticated
+//! principal of producer. Once tenant used up its bucket, its produce requests are
+//! rejected before records are written, until the bucket is refilled.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use fluvio_auth::Tenant;
+
+#[derive(Debug)]
+struct Bucket {
+    available: f64,
+    updated: Instant,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct TenantThrottle {
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl TenantThrottle {
+    /// admit `bytes` produced by tenant, false if tenant is over its max throughput
+    pub(crate) fn admit(&self, tenant: &Tenant, bytes: u64) -> bool {
+        self.admit_at(tenant, bytes, Instant::now())
+    }
+
+    fn admit_at(&self, tenant: &Tenant, bytes: u64, now: Instant) -> bool {
+        let Some(limit) = tenant.limits.max_throughput.filter(|max| *max > 0) else {
+            return true;
+        };
+        let limit = limit as f64;
+
+        let mut buckets = self.buckets.lock().unwrap_or_else(|err| err.into_inner());
+        let bucket = buckets.entry(tenant.name.clone()).or_insert(Bucket {
+            available: limit,
+            updated: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.available = (bucket.available + elapsed * limit).min(limit);
+        bucket.updated = now;
+
+        // batch larger than what is left is admitted, tenant pays it back before next one
+        if bucket.available <= 0.0 {
+            return false;
+        }
+        bucket.available -= bytes as f64;
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::Duration;
+
+    use fluvio_auth::TenantLimits;
+
+    use super::*;
+
+    fn tenant(name: &str, max_throughput: Option<u64>) -> Tenant {
+        Tenant::new(
+            name,
+            TenantLimits {
+                max_throughput,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_reject_over_limit() {
+        let throttle = TenantThrottle::default();
+        let team_a = tenant("team-a", Some(1000));
+        let now = Instant::now();
+
+        assert!(throttle.admit_at(&team_a, 600, now));
+        assert!(throttle.admit_at(&team_a, 600, now));
+        assert!(!throttle.admit_at(&team_a, 1, now));
+
+        // bucket is refilled over time
+        assert!(!throttle.admit_at(&team_a, 1, now + Duration::from_millis(100)));
+        assert!(throttle.admit_at(&team_a, 500, now + Duration::from_millis(300)));
+    }
+
+    #[test]
+    fn test_tenants_have_own_buckets() {
+        let throttle = TenantThrottle::default();
+        let now = Instant::now();
+
+        assert!(throttle.admit_at(&tenant("team-a", Some(1000)), 2000, now));
+        assert!(!throttle.admit_at(&tenant("team-a", Some(1000)), 1, now));
+        assert!(throttle.admit_at(&tenant("team-c", Some(1000)), 1, now));
+    }
+
+    #[test]
+    fn test_no_limit() {
+        let throttle = TenantThrottle::default();
+        let now = Instant::now();
+        assert!(throttle.admit_at(&tenant("team-b", None), 100_000, now));
+        assert!(throttle.admit_at(&tenant("team-b", None), 100_000, now));
+        assert!(throttle.admit_at(&tenant("team-b", Some(0)), 100_000, now));
+    }
+}
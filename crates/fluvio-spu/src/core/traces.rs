@@ -0,0 +1,106 @@
+//!
+//! # Produced Traces
+//!
+//! Trace context of recently produced batches, so that delivery of records
+//! to consumers can be recorded in the trace of the producer.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::RwLock;
+
+use fluvio_protocol::api::TraceContext;
+use fluvio_protocol::record::{Offset, ReplicaKey};
+
+/// number of traced batches kept per partition
+const MAX_TRACES_PER_PARTITION: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TracedBatch {
+    base_offset: Offset,
+    end_offset: Offset,
+    context: TraceContext,
+}
+
+#[derive(Default, Debug)]
+pub(crate) struct ProducedTraces(RwLock<BTreeMap<ReplicaKey, VecDeque<TracedBatch>>>);
+
+impl ProducedTraces {
+    /// remember trace of records written in `[base_offset, end_offset)`
+    pub(crate) fn record(
+        &self,
+        replica: &ReplicaKey,
+        base_offset: Offset,
+        end_offset: Offset,
+        context: TraceContext,
+    ) {
+        let mut traces = self.0.write().unwrap_or_else(|err| err.into_inner());
+        let batches = traces.entry(replica.clone()).or_default();
+        if batches.len() >= MAX_TRACES_PER_PARTITION {
+            batches.pop_front();
+        }
+        batches.push_back(TracedBatch {
+            base_offset,
+            end_offset,
+            context,
+        });
+    }
+
+    /// traces of batches overlapping `[start_offset, end_offset)`
+    pub(crate) fn lookup(
+        &self,
+        replica: &ReplicaKey,
+        start_offset: Offset,
+        end_offset: Offset,
+    ) -> Vec<(Offset, TraceContext)> {
+        let traces = self.0.read().unwrap_or_else(|err| err.into_inner());
+        let Some(batches) = traces.get(replica) else {
+            return vec![];
+        };
+        batches
+            .iter()
+            .filter(|batch| batch.base_offset < end_offset && batch.end_offset > start_offset)
+            .map(|batch| (batch.base_offset, batch.context))
+            .collect()
+    }
+
+    pub(crate) fn remove(&self, replica: &ReplicaKey) {
+        self.0
+            .write()
+            .unwrap_or_else(|err| err.into_inner())
+            .remove(replica);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_produced_traces() {
+        let traces = ProducedTraces::default();
+        let replica = ReplicaKey::new("test", 0u32);
+        let first = TraceContext::new(1, 1, true);
+        let second = TraceContext::new(2, 2, true);
+
+        traces.record(&replica, 0, 10, first);
+        traces.record(&replica, 10, 15, second);
+
+        assert_eq!(traces.lookup(&replica, 0, 5), vec![(0, first)]);
+        assert_eq!(
+            traces.lookup(&replica, 5, 12),
+            vec![(0, first), (10, second)]
+        );
+        assert!(traces.lookup(&replica, 15, 20).is_empty());
+        assert!(traces
+            .lookup(&ReplicaKey::new("other", 0u32), 0, 20)
+            .is_empty());
+
+        for offset in 0..MAX_TRACES_PER_PARTITION as Offset {
+            traces.record(&replica, 20 + offset, 21 + offset, second);
+        }
+        assert!(traces.lookup(&replica, 0, 15).is_empty());
+
+        traces.remove(&replica);
+        assert!(traces.lookup(&replica, 0, 1000).is_empty());
+    }
+}
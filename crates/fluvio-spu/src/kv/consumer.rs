@@ -67,6 +67,25 @@ impl SharedConsumerOffsetStorages {
             }
         }
     }
+
+    /// number of stored consumer offsets per storage replica
+    pub(crate) async fn sizes(&self) -> Vec<(ReplicaKey, usize)> {
+        let storages: Vec<_> = self
+            .0
+            .read()
+            .await
+            .iter()
+            .map(|(replica, storage)| (replica.clone(), storage.clone()))
+            .collect();
+        let mut sizes = Vec::with_capacity(storages.len());
+        for (replica, storage) in storages {
+            match storage.list().await {
+                Ok(entries) => sizes.push((replica, entries.len())),
+                Err(err) => trace!(%replica, %err, "unable to list consumer offsets"),
+            }
+        }
+        sizes
+    }
 }
 
 impl ConsumerOffsetStorage {
@@ -180,6 +199,26 @@ impl SharableConsumerOffsetStorage {
     pub async fn list(&self) -> Result<Vec<(ConsumerOffsetKey, ConsumerOffset)>> {
         self.0.read().await.entries().await
     }
+
+    /// move offsets of all consumers of a replica to another replica.
+    /// Offsets already stored for the target replica are kept. Return number of moved offsets
+    pub async fn move_replica(&self, from: &ReplicaKey, to: &ReplicaKey) -> Result<usize> {
+        let mut storage = self.0.write().await;
+        let moved: Vec<_> = storage
+            .entries()
+            .await?
+            .into_iter()
+            .filter(|(key, _)| &key.replica_id == from)
+            .collect();
+        for (key, offset) in moved.iter() {
+            let target = ConsumerOffsetKey::new(to.clone(), key.consumer_id.clone());
+            if storage.get(&target).await?.is_none() {
+                storage.put(target, offset.clone()).await?;
+            }
+            storage.delete(key).await?;
+        }
+        Ok(moved.len())
+    }
 }
 
 fn now_timestamp() -> TimestampSecs {
@@ -247,6 +286,71 @@ mod tests {
         //then
     }
 
+    #[fluvio_future::test]
+    async fn test_move_replica() {
+        //given
+        let leader = create_offset_replica("test_move_replica").await;
+        let notifier = FollowerNotifier::shared();
+        let storage: SharableConsumerOffsetStorage =
+            ConsumerOffsetStorage::new(leader.clone(), notifier).into();
+        let old = ReplicaKey::new("old", 0u32);
+        let new = ReplicaKey::new("new", 0u32);
+        let other = ReplicaKey::new("old", 1u32);
+        storage
+            .put(
+                ConsumerOffsetKey::new(old.clone(), "c1"),
+                ConsumerOffset::new(5),
+            )
+            .await
+            .expect("put");
+        storage
+            .put(
+                ConsumerOffsetKey::new(old.clone(), "c2"),
+                ConsumerOffset::new(7),
+            )
+            .await
+            .expect("put");
+        storage
+            .put(
+                ConsumerOffsetKey::new(new.clone(), "c2"),
+                ConsumerOffset::new(9),
+            )
+            .await
+            .expect("put");
+        storage
+            .put(
+                ConsumerOffsetKey::new(other.clone(), "c1"),
+                ConsumerOffset::new(3),
+            )
+            .await
+            .expect("put");
+
+        //when
+        let moved = storage.move_replica(&old, &new).await.expect("move");
+
+        //then
+        assert_eq!(moved, 2);
+        assert_eq!(offset_of(&storage, &new, "c1").await, Some(5));
+        assert_eq!(offset_of(&storage, &new, "c2").await, Some(9));
+        assert_eq!(offset_of(&storage, &old, "c1").await, None);
+        assert_eq!(offset_of(&storage, &old, "c2").await, None);
+        assert_eq!(offset_of(&storage, &other, "c1").await, Some(3));
+
+        leader.remove().await.expect("removed");
+    }
+
+    async fn offset_of(
+        storage: &SharableConsumerOffsetStorage,
+        replica: &ReplicaKey,
+        consumer: &str,
+    ) -> Option<i64> {
+        storage
+            .get(&ConsumerOffsetKey::new(replica.clone(), consumer))
+            .await
+            .expect("get")
+            .map(|offset| offset.offset)
+    }
+
     async fn create_offset_replica(dir: impl AsRef<Path>) -> LeaderReplicaState<FileReplica> {
         let base_dir = temp_dir().join(dir);
         ensure_clean_dir(&base_dir);
@@ -13,11 +13,11 @@ cfg_if::cfg_if! {
         mod smartengine;
         mod monitoring;
         pub(crate) mod mirroring;
-        pub use start::main_loop;
+        pub use start::{main_loop, start_services};
     }
 }
 
-pub use config::SpuOpt;
+pub use config::{SpuOpt, SpuConfig};
 
 const VERSION: &str = include_str!("../../../VERSION");
 
@@ -172,7 +172,7 @@ where
             mirror_store: ctx.mirrors_localstore_owned(),
             status_update: ctx.mirror_status_update_owned(),
         };
-        spawn(controller.dispatch_loop());
+        ctx.tasks().track(spawn(controller.dispatch_loop()));
         state
     }
 
@@ -82,11 +82,8 @@ async fn test_mirroring_new_records() {
         .generate("mirror_remote");
 
     let (remote_ctx1, remote_replica_1) = sourcd_builder_1.init_mirror_remote().await;
-    let remote_mirror1 = remote_replica_1
-        .get_replica()
-        .mirror
-        .as_ref()
-        .expect("mirror");
+    let remote_config1 = remote_replica_1.get_replica();
+    let remote_mirror1 = remote_config1.mirror.as_ref().expect("mirror");
     assert_eq!(
         remote_mirror1.remote().expect("remote"),
         &RemotePartitionConfig {
@@ -127,11 +124,8 @@ async fn test_mirroring_new_records() {
         .generate("mirror_remote");
 
     let (_remote_ctx2, remote_replica2) = sourcd_builder2.init_mirror_remote().await;
-    let remote_mirror2 = remote_replica2
-        .get_replica()
-        .mirror
-        .as_ref()
-        .expect("mirror");
+    let remote_config2 = remote_replica2.get_replica();
+    let remote_mirror2 = remote_config2.mirror.as_ref().expect("mirror");
     assert_eq!(
         remote_mirror2.remote().expect("remote"),
         &RemotePartitionConfig {
@@ -1,14 +1,22 @@
 use std::io::Error as IoError;
+use std::sync::Arc;
 
+use async_trait::async_trait;
 use futures_util::{StreamExt, AsyncWriteExt};
 use fluvio_types::defaults::SPU_MONITORING_UNIX_SOCKET;
 use fluvio_future::task::spawn;
 use fluvio_future::net::unix::UnixListener;
+use fluvio_service::metrics::{MetricType, MetricsServer, MetricsSource, MetricsWriter};
 use tracing::{error, info, debug};
 
 use crate::core::{DefaultSharedGlobalContext, metrics::SpuMetrics};
 
 pub(crate) fn init_monitoring(ctx: DefaultSharedGlobalContext) {
+    if let Some(addr) = ctx.config().metrics_socket_addr() {
+        info!(addr, "starting metrics endpoint");
+        MetricsServer::new(addr.to_owned(), Arc::new(SpuMetricsSource(ctx.clone()))).run();
+    }
+
     spawn(async move {
         if let Err(err) = start_monitoring(ctx).await {
             error!("error running monitoring: {}", err);
@@ -64,3 +72,258 @@ async fn start_monitoring(ctx: DefaultSharedGlobalContext) -> Result<(), IoError
         fluvio_future::timer::sleep(std::time::Duration::from_secs(5)).await;
     }
 }
+
+/// Renders SPU metrics for the OpenMetrics endpoint
+struct SpuMetricsSource(DefaultSharedGlobalContext);
+
+#[async_trait]
+impl MetricsSource for SpuMetricsSource {
+    async fn render(&self, writer: &mut MetricsWriter) {
+        let ctx = &self.0;
+        let metrics = ctx.metrics();
+        let spu = ctx.local_spu_id().to_string();
+        let spu = spu.as_str();
+
+        render_activity(writer, spu, &metrics);
+        render_partitions(writer, spu, &metrics);
+        render_replicas(writer, spu, ctx).await;
+        render_smartmodule(writer, spu, &metrics);
+
+        writer.family(
+            "fluvio_spu_consumer_offsets",
+            MetricType::Gauge,
+            "Number of consumer offsets stored by the replica",
+        );
+        for (replica, size) in ctx.consumer_offset().sizes().await {
+            let partition = replica.partition.to_string();
+            writer.sample(
+                "fluvio_spu_consumer_offsets",
+                &[
+                    ("spu", spu),
+                    ("topic", replica.topic.as_str()),
+                    ("partition", partition.as_str()),
+                ],
+                size,
+            );
+        }
+    }
+}
+
+fn render_activity(writer: &mut MetricsWriter, spu: &str, metrics: &SpuMetrics) {
+    let directions = [
+        ("inbound", metrics.inbound()),
+        ("outbound", metrics.outbound()),
+    ];
+    for (name, help) in [
+        ("records", "Number of records"),
+        ("bytes", "Number of bytes"),
+    ] {
+        let family = format!("fluvio_spu_{name}");
+        writer.family(
+            &family,
+            MetricType::Counter,
+            &format!("{help} produced (inbound) and consumed (outbound)"),
+        );
+        let sample = format!("{family}_total");
+        for (direction, activity) in directions {
+            for (client_type, record) in [
+                ("client", activity.client()),
+                ("connector", activity.connector()),
+            ] {
+                let value = if name == "records" {
+                    record.records()
+                } else {
+                    record.bytes()
+                };
+                writer.sample(
+                    &sample,
+                    &[
+                        ("spu", spu),
+                        ("direction", direction),
+                        ("client_type", client_type),
+                    ],
+                    value,
+                );
+            }
+        }
+    }
+}
+
+fn render_partitions(writer: &mut MetricsWriter, spu: &str, metrics: &SpuMetrics) {
+    let partitions = metrics.partitions().snapshot();
+
+    for (family, help) in [
+        (
+            "fluvio_spu_partition_produced_records",
+            "Records produced to partition",
+        ),
+        (
+            "fluvio_spu_partition_produced_bytes",
+            "Bytes produced to partition",
+        ),
+        (
+            "fluvio_spu_partition_fetched_records",
+            "Records fetched from partition",
+        ),
+        (
+            "fluvio_spu_partition_fetched_bytes",
+            "Bytes fetched from partition",
+        ),
+    ] {
+        writer.family(family, MetricType::Counter, help);
+        let sample = format!("{family}_total");
+        for (replica, activity) in &partitions {
+            let record = if family.contains("produced") {
+                activity.produced()
+            } else {
+                activity.fetched()
+            };
+            let value = if family.ends_with("records") {
+                record.records()
+            } else {
+                record.bytes()
+            };
+            let partition = replica.partition.to_string();
+            writer.sample(
+                &sample,
+                &[
+                    ("spu", spu),
+                    ("topic", replica.topic.as_str()),
+                    ("partition", partition.as_str()),
+                ],
+                value,
+            );
+        }
+    }
+
+    writer.family(
+        "fluvio_spu_follower_sync_latency_seconds",
+        MetricType::Summary,
+        "Time for follower replica to apply records received from leader",
+    );
+    for (replica, activity) in &partitions {
+        let sync = activity.follower_sync();
+        if sync.count() == 0 {
+            continue;
+        }
+        let partition = replica.partition.to_string();
+        let labels = [
+            ("spu", spu),
+            ("topic", replica.topic.as_str()),
+            ("partition", partition.as_str()),
+        ];
+        writer.sample(
+            "fluvio_spu_follower_sync_latency_seconds_count",
+            &labels,
+            sync.count(),
+        );
+        writer.sample(
+            "fluvio_spu_follower_sync_latency_seconds_sum",
+            &labels,
+            sync.sum_seconds(),
+        );
+    }
+}
+
+async fn render_replicas(writer: &mut MetricsWriter, spu: &str, ctx: &DefaultSharedGlobalContext) {
+    let leaders: Vec<_> = ctx.leaders_state().read().await.values().cloned().collect();
+    let followers: Vec<_> = ctx
+        .followers_state()
+        .read()
+        .await
+        .values()
+        .cloned()
+        .collect();
+
+    let mut offsets = vec![];
+    for leader in &leaders {
+        offsets.push((leader.id().clone(), "leader", leader.leo(), leader.hw()));
+    }
+    for follower in &followers {
+        offsets.push((
+            follower.id().clone(),
+            "follower",
+            follower.leo(),
+            follower.hw(),
+        ));
+    }
+
+    for (family, help) in [
+        ("fluvio_spu_replica_leo", "Log end offset of replica"),
+        ("fluvio_spu_replica_hw", "High watermark of replica"),
+    ] {
+        writer.family(family, MetricType::Gauge, help);
+        for (replica, role, leo, hw) in &offsets {
+            let partition = replica.partition.to_string();
+            let value = if family.ends_with("leo") { leo } else { hw };
+            writer.sample(
+                family,
+                &[
+                    ("spu", spu),
+                    ("topic", replica.topic.as_str()),
+                    ("partition", partition.as_str()),
+                    ("role", *role),
+                ],
+                value,
+            );
+        }
+    }
+
+    writer.family(
+        "fluvio_spu_replica_lag",
+        MetricType::Gauge,
+        "Records the follower replica is behind the leader",
+    );
+    for leader in &leaders {
+        let replica = leader.id();
+        let partition = replica.partition.to_string();
+        let leo = leader.leo();
+        for (follower, info) in leader.followers_info().await {
+            let follower = follower.to_string();
+            writer.sample(
+                "fluvio_spu_replica_lag",
+                &[
+                    ("spu", spu),
+                    ("topic", replica.topic.as_str()),
+                    ("partition", partition.as_str()),
+                    ("follower", follower.as_str()),
+                ],
+                (leo - info.leo.max(0)).max(0),
+            );
+        }
+    }
+}
+
+fn render_smartmodule(writer: &mut MetricsWriter, spu: &str, metrics: &SpuMetrics) {
+    let chain = metrics.chain_metrics();
+    for (family, help, value) in [
+        (
+            "fluvio_spu_smartmodule_bytes_in",
+            "Bytes passed to SmartModule chains",
+            chain.bytes_in(),
+        ),
+        (
+            "fluvio_spu_smartmodule_records_out",
+            "Records emitted by SmartModule chains",
+            chain.records_out(),
+        ),
+        (
+            "fluvio_spu_smartmodule_invocations",
+            "SmartModule chain invocations",
+            chain.invocation_count(),
+        ),
+        (
+            "fluvio_spu_smartmodule_fuel_used",
+            "Fuel consumed by SmartModule chains",
+            chain.fuel_used(),
+        ),
+        (
+            "fluvio_spu_smartmodule_records_skipped",
+            "Records skipped by SmartModule error policy",
+            chain.records_skipped(),
+        ),
+    ] {
+        writer.family(family, MetricType::Counter, help);
+        writer.sample(&format!("{family}_total"), &[("spu", spu)], value);
+    }
+}
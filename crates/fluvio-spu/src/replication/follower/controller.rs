@@ -1,4 +1,8 @@
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use tracing::{debug, error, trace, warn, instrument};
 use async_lock::RwLock;
@@ -39,13 +43,14 @@ impl FollowerGroups {
             if let Some(old_notification) = leaders.insert(leader, notification.clone()) {
                 old_notification.shutdown();
             } else {
-                FollowGroupController::run(
+                ctx.tasks().track(FollowGroupController::run(
                     leader,
                     ctx.spu_localstore_owned(),
                     ctx.followers_state_owned(),
                     notification,
                     ctx.config_owned(),
-                );
+                    ctx.metrics(),
+                ));
             }
         }
     }
@@ -81,7 +86,7 @@ mod inner {
     use futures_util::StreamExt;
     use once_cell::sync::Lazy;
 
-    use fluvio_future::task::spawn;
+    use fluvio_future::task::{spawn, JoinHandle};
     use fluvio_future::timer::sleep;
     use fluvio_socket::FluvioSocket;
     use fluvio_socket::FluvioSink;
@@ -93,6 +98,7 @@ mod inner {
     use fluvio_controlplane_metadata::spu::SpuSpec;
 
     use crate::{replication::leader::UpdateOffsetRequest, core::SharedSpuConfig};
+    use crate::core::metrics::SpuMetrics;
     use crate::services::internal::FetchStreamRequest;
     use crate::core::spus::SharedSpuLocalStore;
 
@@ -111,6 +117,7 @@ mod inner {
         states: SharedFollowersState<FileReplica>,
         config: SharedSpuConfig,
         group: Arc<GroupNotification>,
+        metrics: Arc<SpuMetrics>,
     }
 
     impl FollowGroupController {
@@ -120,15 +127,17 @@ mod inner {
             states: SharedFollowersState<FileReplica>,
             spu_ctx: Arc<GroupNotification>,
             config: SharedSpuConfig,
-        ) {
+            metrics: Arc<SpuMetrics>,
+        ) -> JoinHandle<()> {
             let controller = Self {
                 leader,
                 spus,
                 states,
                 group: spu_ctx,
                 config,
+                metrics,
             };
-            spawn(controller.dispatch_loop());
+            spawn(controller.dispatch_loop())
         }
 
         fn local_spu_id(&self) -> SpuId {
@@ -276,7 +285,14 @@ mod inner {
                     base_offset = p.records.base_offset(),
                     "update from leader");
                     if let Some(replica) = self.states.get(&replica_key).await {
-                        match replica.update_from_leader(&mut p.records, p.hw).await {
+                        let start = Instant::now();
+                        let result = replica.update_from_leader(&mut p.records, p.hw).await;
+                        self.metrics
+                            .partitions()
+                            .partition(&replica_key)
+                            .follower_sync()
+                            .observe(start.elapsed());
+                        match result {
                             Ok(changes) => {
                                 if changes {
                                     debug!("changes occur, need to send back offset");
@@ -6,7 +6,7 @@ use std::ops::{Deref, DerefMut};
 use fluvio_controlplane::replica::Replica;
 use tracing::{debug, warn, instrument};
 use async_lock::RwLock;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 
 use fluvio_protocol::record::{BatchRecords, ReplicaKey};
 use fluvio_storage::config::ReplicaConfig;
@@ -139,7 +139,17 @@ impl FollowersState<FileReplica> {
         }
     }
 
-    pub async fn update_replica(&self, _replica: Replica) {}
+    /// apply config changes of replica to its storage
+    pub async fn update_replica(&self, ctx: &FileGlobalContext, replica: Replica) {
+        if let Some(state) = self.get(&replica.id).await {
+            debug!(replica = %replica.id, "updating follower config");
+            let mut replica_config: ReplicaConfig = ctx.config().into();
+            replica_config.update_from_replica(&replica);
+            state.update_config(replica_config).await;
+        } else {
+            warn!(replica = %replica.id, "follower replica not found");
+        }
+    }
 }
 
 /// State for Follower Replica Controller
@@ -193,11 +203,13 @@ where
         );
 
         let replica_storage = SharableReplicaStorage::create(replica_key, config).await?;
-
-        Ok(Self {
+        let replica_state = Self {
             leader,
             inner: replica_storage,
-        })
+        };
+        replica_state.discard_corrupted_records().await?;
+
+        Ok(replica_state)
     }
 
     pub fn leader(&self) -> SpuId {
@@ -210,7 +222,7 @@ where
         records: &mut RecordSet<R>,
         leader_hw: Offset,
     ) -> Result<bool> {
-        let mut changes = false;
+        let mut changes = self.discard_corrupted_records().await?;
 
         if records.total_records() > 0 {
             self.write_recordsets(records).await?;
@@ -243,6 +255,24 @@ where
         Ok(changes)
     }
 
+    /// if replica is fenced, discard corrupted records so they are fetched again from leader
+    async fn discard_corrupted_records(&self) -> Result<bool> {
+        if self.corrupted_offset().await.is_none() {
+            return Ok(false);
+        }
+        match self.discard_corrupted().await? {
+            Some(leo) => {
+                warn!(
+                    replica = %self.id(),
+                    leo,
+                    "discarded corrupted records, fetching again from leader"
+                );
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
     /// try to write records
     /// ensure records has correct baseoffset
     async fn write_recordsets<R: BatchRecords>(&self, records: &mut RecordSet<R>) -> Result<bool> {
@@ -254,11 +284,23 @@ where
                 incoming_base_offset = records.base_offset(),
                 "follower leo is not same as base offset, skipping write"
             );
-            Ok(false)
-        } else {
-            self.write_record_set(records, false).await?;
-            Ok(true)
+            return Ok(false);
+        }
+
+        // records are not written if any batch was damaged, sync is failed instead of fetching again
+        for batch in &records.batches {
+            if !batch.validate_crc()? {
+                return Err(anyhow!(
+                    "batch from leader failed checksum, replica: {}, base offset: {}, crc: {}",
+                    self.id(),
+                    batch.get_base_offset(),
+                    batch.header.crc
+                ));
+            }
         }
+
+        self.write_record_set(records, false).await?;
+        Ok(true)
     }
 
     /// convert to offset request
@@ -283,6 +325,7 @@ mod follower_tests {
     use flv_util::fixture::ensure_clean_dir;
     use fluvio_types::{SpuId, PartitionId};
     use fluvio_storage::config::ReplicaConfig;
+    use fluvio_protocol::fixture::create_recordset;
 
     use super::*;
 
@@ -310,4 +353,37 @@ mod follower_tests {
         assert_eq!(follower_replica.hw(), 0);
         assert!(PathBuf::from(test_path).join("spu-5002").exists());
     }
+
+    #[fluvio_future::test]
+    async fn test_follower_rejects_corrupted_batch() {
+        let test_path = "/tmp/follower_corrupted_batch";
+        ensure_clean_dir(test_path);
+
+        let config = ReplicaConfig {
+            base_dir: PathBuf::from(test_path).join("spu-5002"),
+            ..Default::default()
+        };
+
+        let follower_replica: FollowerReplicaState<FileReplica> =
+            FollowerReplicaState::create(LEADER, TEST_REPLICA.into(), config)
+                .await
+                .expect("create");
+
+        let mut records = create_recordset(2);
+        records.batches[0].header.crc = records.batches[0].compute_crc().expect("crc");
+        assert!(follower_replica
+            .update_from_leader(&mut records, 0)
+            .await
+            .expect("update"));
+        assert_eq!(follower_replica.leo(), 2);
+
+        let mut corrupted = create_recordset(2);
+        corrupted.batches[0].base_offset = 2;
+        corrupted.batches[0].header.crc = 1;
+        assert!(follower_replica
+            .update_from_leader(&mut corrupted, 0)
+            .await
+            .is_err());
+        assert_eq!(follower_replica.leo(), 2);
+    }
 }
@@ -7,7 +7,8 @@ use tracing::{error, instrument};
 use anyhow::Result;
 
 use fluvio_controlplane_metadata::partition::{PartitionMirrorConfig, ReplicaKey};
-use fluvio_storage::{FileReplica, ReplicaStorage};
+use fluvio_storage::{FileReplica, ReplicaStorage, ReplicaStorageConfig};
+use fluvio_storage::config::ReplicaConfig;
 
 use crate::{control_plane::SharedLrsStatusUpdate, core::GlobalContext};
 use crate::config::ReplicationConfig;
@@ -53,7 +54,6 @@ impl<S> ReplicaLeadersState<S> {
         writer.remove(replica)
     }
 
-    #[allow(unused)]
     pub async fn insert(
         &self,
         replica: ReplicaKey,
@@ -75,8 +75,7 @@ where
 
         let mut replicas = Vec::new();
         for (_replica_key, state) in read.iter() {
-            let replica_config = state.get_replica();
-            replicas.push(replica_config.clone());
+            replicas.push(state.get_replica());
         }
         replicas
     }
@@ -138,6 +137,25 @@ impl ReplicaLeadersState<FileReplica> {
         }
     }
 
+    /// apply config changes of replica to running leader
+    #[instrument(
+        skip(self, ctx, replica),
+        fields(replica = %replica.id)
+    )]
+    pub async fn update_leader_replica(
+        &self,
+        ctx: &GlobalContext<FileReplica>,
+        replica: Replica,
+    ) -> Result<()> {
+        let Some(state) = self.get(&replica.id).await else {
+            error!("leader controller was not found: {}", replica.id);
+            return Ok(());
+        };
+        let mut replica_config: ReplicaConfig = ctx.config().into();
+        replica_config.update_from_replica(&replica);
+        state.update_config(replica, replica_config, ctx).await
+    }
+
     /// promote follower
     #[instrument(
         skip(self,follower,replica,status_update,ctx),
@@ -2,7 +2,8 @@ use std::{
     cmp::{min, Reverse},
     collections::{BTreeMap, HashSet, BinaryHeap},
     ops::{Deref, DerefMut},
-    sync::Arc,
+    sync::{Arc, RwLock as SyncRwLock},
+    time::Instant,
 };
 use std::iter::FromIterator;
 use std::fmt;
@@ -14,6 +15,7 @@ use tracing::instrument;
 use async_lock::RwLock;
 use anyhow::{Result, Context};
 
+use fluvio_protocol::link::ErrorCode;
 use fluvio_protocol::record::{RecordSet, Offset, ReplicaKey, RawRecords, Batch};
 use fluvio_controlplane_metadata::partition::{PartitionMirrorConfig, PartitionStatus, ReplicaStatus};
 use fluvio_storage::{FileReplica, ReplicaStorage, OffsetInfo, ReplicaStorageConfig};
@@ -46,13 +48,17 @@ pub const CLEANUP_FREQUENCY: usize = 10;
 
 #[derive(Debug)]
 pub struct LeaderReplicaState<S> {
-    replica: Replica,
+    id: ReplicaKey,
+    /// replica metadata, shared by all clones so config updates are seen by every holder
+    replica: Arc<SyncRwLock<Replica>>,
     in_sync_replica: u16,
     storage: SharableReplicaStorage<S>,
     config: ReplicationConfig,
     followers: Arc<RwLock<BTreeMap<SpuId, OffsetInfo>>>,
+    /// last time when follower had same leo as leader
+    caught_up: Arc<RwLock<BTreeMap<SpuId, Instant>>>,
     status_update: SharedLrsStatusUpdate,
-    sm_ctx: Option<SharedSmartModuleContext>,
+    sm_ctx: Arc<RwLock<Option<SharedSmartModuleContext>>>,
     consumer_offset_publishers: Arc<Mutex<Vec<WeakSharedOffsetPublisher>>>,
     mirror_controller_state: Option<SharedMirrorControllerState>,
 }
@@ -60,10 +66,12 @@ pub struct LeaderReplicaState<S> {
 impl<S> Clone for LeaderReplicaState<S> {
     fn clone(&self) -> Self {
         Self {
+            id: self.id.clone(),
             replica: self.replica.clone(),
             storage: self.storage.clone(),
             config: self.config.clone(),
             followers: self.followers.clone(),
+            caught_up: self.caught_up.clone(),
             in_sync_replica: self.in_sync_replica,
             status_update: self.status_update.clone(),
             sm_ctx: self.sm_ctx.clone(),
@@ -131,13 +139,15 @@ where
         );
 
         Uninit(Self {
-            replica,
+            id: replica.id.clone(),
+            replica: Arc::new(SyncRwLock::new(replica)),
             storage: inner,
             config,
             followers: Arc::new(RwLock::new(followers)),
+            caught_up: Arc::new(RwLock::new(BTreeMap::new())),
             in_sync_replica,
             status_update,
-            sm_ctx: None,
+            sm_ctx: Arc::new(RwLock::new(None)),
             consumer_offset_publishers: Arc::new(Mutex::new(Vec::new())),
             mirror_controller_state: None,
         })
@@ -163,17 +173,77 @@ where
 
     /// replica id
     pub fn id(&self) -> &ReplicaKey {
-        &self.replica.id
+        &self.id
     }
 
     /// leader SPU. This should be same as our local SPU
     pub fn leader(&self) -> SpuId {
-        self.replica.leader
+        self.read_replica().leader
     }
 
     /// replica metadata
-    pub fn get_replica(&self) -> &Replica {
-        &self.replica
+    pub fn get_replica(&self) -> Replica {
+        self.read_replica().clone()
+    }
+
+    fn read_replica(&self) -> std::sync::RwLockReadGuard<'_, Replica> {
+        self.replica.read().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// replicas which must be in sync to accept read committed writes.
+    /// SPU default is used if topic doesn't set it, it can't be more than replicas of partition
+    pub fn min_in_sync_replicas(&self) -> u16 {
+        let replica = self.read_replica();
+        replica
+            .min_in_sync_replicas
+            .unwrap_or(self.config.min_in_sync_replicas)
+            .min(replica.replicas.len().max(1) as u16)
+    }
+
+    /// replicas in sync with leader, including leader.
+    /// follower is in sync if it has same leo as leader or had it recently
+    pub async fn in_sync_replicas(&self) -> u16 {
+        let leo = self.leo();
+        let followers = self.followers.read().await;
+        let caught_up = self.caught_up.read().await;
+        let in_sync_followers = followers
+            .iter()
+            .filter(|(id, info)| {
+                info.is_valid()
+                    && (info.leo == leo
+                        || caught_up
+                            .get(id)
+                            .map(|time| time.elapsed() <= self.config.max_follower_lag)
+                            .unwrap_or(false))
+            })
+            .count();
+        1 + in_sync_followers as u16
+    }
+
+    /// committed records must be stored by at least min in-sync replicas,
+    /// error if there are not enough of them to accept a write
+    pub async fn check_in_sync_replicas(&self) -> Result<(), ErrorCode> {
+        let in_sync = self.in_sync_replicas().await;
+        let min = self.min_in_sync_replicas();
+        if in_sync < min {
+            warn!(replica = %self.id(), in_sync, min, "not enough in-sync replicas");
+            return Err(ErrorCode::NotEnoughInSyncReplicas { in_sync, min });
+        }
+        Ok(())
+    }
+
+    /// record followers which are caught up with leader now
+    async fn touch_caught_up_followers(&self) {
+        let leo = self.leo();
+        let followers = self.followers.read().await;
+        let mut caught_up = self.caught_up.write().await;
+        let now = Instant::now();
+        for (id, _) in followers
+            .iter()
+            .filter(|(_, info)| info.is_valid() && info.leo == leo)
+        {
+            caught_up.insert(*id, now);
+        }
     }
 
     /// override in sync replica
@@ -205,6 +275,12 @@ where
         // get follower info
         let mut followers = self.followers.write().await;
         let update = if let Some(current_follow_info) = followers.get_mut(&follower_id) {
+            if follower_pos.is_valid() && follower_pos.leo == leader_pos.leo {
+                self.caught_up
+                    .write()
+                    .await
+                    .insert(follower_id, Instant::now());
+            }
             if current_follow_info.update(&follower_pos) {
                 // if our leo and hw is same there is no need to recompute hw
                 if !leader_pos.is_committed() {
@@ -326,7 +402,11 @@ where
             .unwrap_or(PartitionStatus::SIZE_ERROR);
         let base_offset = storage_reader.get_log_start_offset();
 
-        LrsRequest::new(self.id().to_owned(), leader, replicas, size, base_offset)
+        let mut lrs = LrsRequest::new(self.id().to_owned(), leader, replicas, size, base_offset);
+        lrs.corrupted_offset = storage_reader.get_corrupted_offset();
+        drop(storage_reader);
+        lrs.in_sync_replicas = self.in_sync_replicas().await as u32;
+        lrs
     }
 
     #[instrument(skip(self))]
@@ -354,6 +434,9 @@ where
             return Ok((self.hw(), self.leo(), 0));
         }
 
+        // followers which have all records so far are in sync until records are written
+        self.touch_caught_up_followers().await;
+
         let offsets = self
             .storage
             .write_record_set(records, self.in_sync_replica == 1)
@@ -366,7 +449,8 @@ where
     }
 
     async fn transform(&self, records: &mut RecordSet<RawRecords>) -> Result<()> {
-        if let Some(ref sm_ctx) = self.sm_ctx {
+        let sm_ctx = self.sm_ctx.read().await.clone();
+        if let Some(sm_ctx) = sm_ctx {
             let (sm_result, sm_error) =
                 process_record_set(sm_ctx.write().await.chain_mut(), records)?;
             if let Some(error) = sm_error {
@@ -385,7 +469,8 @@ where
         let leader_offset = self.as_offset();
         let followers = self.followers.read().await;
         debug!(?leader_offset);
-        for follower in &self.replica.replicas {
+        let replicas = self.read_replica().replicas.clone();
+        for follower in &replicas {
             if let Some(follower_info) = followers.get(follower) {
                 debug!(follower, ?follower_info);
                 if follower_info.is_valid() && !follower_info.is_same(&leader_offset) {
@@ -403,8 +488,7 @@ where
         self.followers.read().await.keys().cloned().collect()
     }
 
-    // get copy of followers_info for debugging
-    #[allow(unused)]
+    /// copy of offsets reported by followers
     pub async fn followers_info(&self) -> BTreeMap<SpuId, OffsetInfo> {
         self.followers.read().await.clone()
     }
@@ -469,28 +553,64 @@ where
     }
 }
 
-pub struct Uninit<S>(S);
-
-impl<S: ReplicaStorage + 'static> Uninit<LeaderReplicaState<S>>
+impl<S: ReplicaStorage + 'static> LeaderReplicaState<S>
 where
     S: Sync + Send,
 {
-    pub async fn init(self, ctx: &GlobalContext<FileReplica>) -> Result<LeaderReplicaState<S>> {
-        let mut state = self.0;
-        if let Some(dedup) = &state.replica.deduplication {
-            debug!(?state.replica.deduplication, "init leader smartmodule context");
+    /// apply config changes of replica while leader is running.
+    /// config is updated in place, so it is seen by every clone of this state
+    pub async fn update_config(
+        &self,
+        replica: Replica,
+        replica_config: S::ReplicaConfig,
+        ctx: &GlobalContext<FileReplica>,
+    ) -> Result<()> {
+        debug!(replica = %replica.id, "updating leader config");
+        self.storage.update_config(replica_config).await;
+        let dedup_changed = {
+            let mut current = self.replica.write().unwrap_or_else(|err| err.into_inner());
+            let dedup_changed = current.deduplication != replica.deduplication;
+            *current = replica;
+            dedup_changed
+        };
+        if dedup_changed {
+            self.init_sm_ctx(ctx).await?;
+        }
+        Ok(())
+    }
+
+    /// create SmartModule context for deduplication
+    async fn init_sm_ctx(&self, ctx: &GlobalContext<FileReplica>) -> Result<()> {
+        let deduplication = self.read_replica().deduplication.clone();
+        let mut sm_ctx_slot = self.sm_ctx.write().await;
+        *sm_ctx_slot = None;
+        if let Some(dedup) = &deduplication {
+            debug!(?deduplication, "init leader smartmodule context");
             let dedup_filter = dedup_to_invocation(dedup);
             let mut sm_ctx = SmartModuleContext::try_from(vec![dedup_filter], COMMON_VERSION, ctx)
                 .await?
                 .ok_or_else(|| anyhow::anyhow!("SmartModule context is required here"))?;
             sm_ctx
-                .look_back(&state)
+                .look_back(self)
                 .await
                 .context("leader smartmodule context lookback failed")?;
-            state.sm_ctx = Some(Arc::new(RwLock::new(sm_ctx)));
+            *sm_ctx_slot = Some(Arc::new(RwLock::new(sm_ctx)));
         };
+        Ok(())
+    }
+}
+
+pub struct Uninit<S>(S);
+
+impl<S: ReplicaStorage + 'static> Uninit<LeaderReplicaState<S>>
+where
+    S: Sync + Send,
+{
+    pub async fn init(self, ctx: &GlobalContext<FileReplica>) -> Result<LeaderReplicaState<S>> {
+        let mut state = self.0;
+        state.init_sm_ctx(ctx).await?;
         // start up mirror controller if mirror is source
-        if let Some(mirror) = &state.replica.mirror {
+        if let Some(mirror) = &state.get_replica().mirror {
             match mirror {
                 PartitionMirrorConfig::Remote(r) => {
                     debug!("found mirror remote, starting controller");
@@ -831,6 +951,8 @@ mod test_hw_updates {
 #[cfg(test)]
 mod test_leader {
 
+    use std::time::Duration;
+
     use async_trait::async_trait;
 
     use fluvio_controlplane_metadata::partition::ReplicaKey;
@@ -955,6 +1077,133 @@ mod test_leader {
         assert_eq!(state.in_sync_replica, 1);
     }
 
+    #[fluvio_future::test]
+    async fn test_leader_min_in_sync_replicas() {
+        let leader_config = SpuConfig {
+            id: 5000,
+            ..Default::default()
+        };
+        let notifier = FollowerNotifier::shared();
+
+        let mut replica = Replica::new(("test", 1), 5000, vec![5000, 5001, 5002]);
+        replica.min_in_sync_replicas = Some(2);
+        let mut state: LeaderReplicaState<MockStorage> =
+            LeaderReplicaState::create(replica, &leader_config, StatusLrsMessageSink::shared())
+                .await
+                .expect("state")
+                .0;
+        assert_eq!(state.min_in_sync_replicas(), 2);
+
+        // followers have not reported their offsets yet
+        assert_eq!(state.in_sync_replicas().await, 1);
+        assert_eq!(
+            state.check_in_sync_replicas().await,
+            Err(ErrorCode::NotEnoughInSyncReplicas { in_sync: 1, min: 2 })
+        );
+
+        state
+            .write_record_set(&mut create_raw_recordset(10), &notifier)
+            .await
+            .expect("write");
+        assert!(
+            state
+                .update_states_from_followers(5001, OffsetInfo { leo: 10, hw: 0 }, &notifier)
+                .await
+        );
+        assert!(
+            state
+                .update_states_from_followers(5002, OffsetInfo { leo: 5, hw: 0 }, &notifier)
+                .await
+        );
+        assert_eq!(state.in_sync_replicas().await, 2);
+        assert_eq!(state.check_in_sync_replicas().await, Ok(()));
+
+        // 5001 was caught up before write, it is in sync until it lags for too long
+        let mut records = create_raw_recordset(10);
+        records.batches[0].set_base_offset(10);
+        state
+            .write_record_set(&mut records, &notifier)
+            .await
+            .expect("write");
+        assert!(state.leo() > 10);
+        assert_eq!(state.in_sync_replicas().await, 2);
+        state.config.max_follower_lag = Duration::ZERO;
+        assert_eq!(state.in_sync_replicas().await, 1);
+
+        // SPU default is used if topic doesn't set it
+        state.replica.write().unwrap().min_in_sync_replicas = None;
+        assert_eq!(state.min_in_sync_replicas(), 1);
+        state.config.min_in_sync_replicas = 5;
+        assert_eq!(state.min_in_sync_replicas(), 3);
+    }
+
+    #[fluvio_future::test]
+    async fn test_fenced_leader_rejects_writes() {
+        let leader_config = SpuConfig {
+            id: 5000,
+            ..Default::default()
+        };
+        let notifier = FollowerNotifier::shared();
+
+        let state: LeaderReplicaState<MockStorage> = LeaderReplicaState::create(
+            Replica::new(("test", 1), 5000, vec![5000]),
+            &leader_config,
+            StatusLrsMessageSink::shared(),
+        )
+        .await
+        .expect("state")
+        .0;
+        let handler_state = state.clone();
+
+        state
+            .write_record_set(&mut create_raw_recordset(2), &notifier)
+            .await
+            .expect("write");
+        let leo = state.leo();
+
+        state.fence().await;
+
+        // clones held by handlers share the fence
+        let err = handler_state
+            .write_record_set(&mut create_raw_recordset(2), &notifier)
+            .await
+            .expect_err("fenced");
+        assert!(err
+            .downcast_ref::<crate::storage::ReplicaFenced>()
+            .is_some());
+        assert_eq!(state.leo(), leo);
+    }
+
+    #[fluvio_future::test]
+    async fn test_config_update_seen_by_clones() {
+        let leader_config = SpuConfig {
+            id: 5000,
+            ..Default::default()
+        };
+        let ctx = GlobalContext::new_shared_context(leader_config.clone());
+
+        let state: LeaderReplicaState<MockStorage> = LeaderReplicaState::create(
+            Replica::new(("test", 1), 5000, vec![5000, 5001, 5002]),
+            &leader_config,
+            StatusLrsMessageSink::shared(),
+        )
+        .await
+        .expect("state")
+        .0;
+        let handler_state = state.clone();
+        assert_eq!(handler_state.min_in_sync_replicas(), 1);
+
+        let mut replica = state.get_replica();
+        replica.min_in_sync_replicas = Some(2);
+        state
+            .update_config(replica, MockConfig::default(), &ctx)
+            .await
+            .expect("update");
+
+        assert_eq!(handler_state.min_in_sync_replicas(), 2);
+        assert_eq!(handler_state.get_replica().min_in_sync_replicas, Some(2));
+    }
+
     #[fluvio_future::test]
     async fn test_follower_update() {
         let leader_config = SpuConfig {
@@ -1,4 +1,5 @@
 pub use common::*;
+pub use tenant::*;
 
 mod common {
 
@@ -35,3 +36,83 @@ mod common {
         }
     }
 }
+
+mod tenant {
+
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use tracing::{debug, instrument};
+
+    use fluvio_auth::x509::X509Identity;
+    use fluvio_auth::{
+        AuthContext, AuthError, Authorization, InstanceAction, Tenant, TenantPolicy, TypeAction,
+    };
+    use fluvio_controlplane_metadata::extended::ObjectType;
+    use fluvio_socket::FluvioSocket;
+
+    /// Authorization of principals authenticated by TLS proxy,
+    /// principals of tenant can only access objects of the tenant
+    #[derive(Debug, Clone)]
+    pub struct TenantAuthorization {
+        tenants: Arc<TenantPolicy>,
+    }
+
+    impl TenantAuthorization {
+        pub fn new(tenants: TenantPolicy) -> Self {
+            Self {
+                tenants: Arc::new(tenants),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Authorization for TenantAuthorization {
+        type Context = TenantAuthContext;
+
+        #[instrument(level = "trace", skip(self, socket))]
+        async fn create_auth_context(
+            &self,
+            socket: &mut FluvioSocket,
+        ) -> Result<Self::Context, AuthError> {
+            let identity = X509Identity::create_from_connection(socket)
+                .await
+                .map_err(|err| {
+                    tracing::error!(%err, "failed to create x509 identity");
+                    err
+                })?;
+            let tenant = self.tenants.tenant_of(&identity.principal);
+            debug!(principal = %identity.principal, ?tenant, "authenticated");
+            Ok(TenantAuthContext { tenant })
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct TenantAuthContext {
+        tenant: Option<Tenant>,
+    }
+
+    #[async_trait]
+    impl AuthContext for TenantAuthContext {
+        async fn allow_type_action(
+            &self,
+            _ty: ObjectType,
+            _action: TypeAction,
+        ) -> Result<bool, AuthError> {
+            Ok(true)
+        }
+
+        async fn allow_instance_action(
+            &self,
+            ty: ObjectType,
+            _action: InstanceAction,
+            key: &str,
+        ) -> Result<bool, AuthError> {
+            Ok(self.allow_tenant_object(&ty, key))
+        }
+
+        fn tenant(&self) -> Option<&Tenant> {
+            self.tenant.as_ref()
+        }
+    }
+}
@@ -10,6 +10,8 @@ use fluvio_protocol::api::{RequestMessage, ApiMessage, RequestHeader};
 use super::fetch_consumer_offset_request::FetchConsumerOffsetRequest;
 use super::update_consumer_offset_request::UpdateConsumerOffsetRequest;
 use super::fetch_stream_request::FetchStreamRequest;
+use super::produce_dead_letter_request::ProduceDeadLetterRequest;
+use super::fetch_replica_offsets_request::FetchReplicaOffsetsRequest;
 
 #[repr(u16)]
 #[derive(Eq, PartialEq, Debug, Encoder, Decoder, Clone, Copy)]
@@ -18,6 +20,8 @@ pub enum SPUPeerApiEnum {
     FetchStream = 0,
     FetchConsumerOffset = 1,
     UpdateConsumerOffset = 2,
+    ProduceDeadLetter = 3,
+    FetchReplicaOffsets = 4,
 }
 
 impl Default for SPUPeerApiEnum {
@@ -34,6 +38,10 @@ pub enum SpuPeerRequest {
     FetchConsumerOffset(RequestMessage<FetchConsumerOffsetRequest>),
     #[fluvio(tag = 2)]
     UpdateConsumerOffset(RequestMessage<UpdateConsumerOffsetRequest>),
+    #[fluvio(tag = 3)]
+    ProduceDeadLetter(RequestMessage<ProduceDeadLetterRequest>),
+    #[fluvio(tag = 4)]
+    FetchReplicaOffsets(RequestMessage<FetchReplicaOffsetsRequest>),
 }
 
 impl Default for SpuPeerRequest {
@@ -70,6 +78,15 @@ impl ApiMessage for SpuPeerRequest {
                     UpdateConsumerOffsetRequest::decode_from(src, version)?,
                 )))
             }
+            SPUPeerApiEnum::ProduceDeadLetter => Ok(SpuPeerRequest::ProduceDeadLetter(
+                RequestMessage::new(header, ProduceDeadLetterRequest::decode_from(src, version)?),
+            )),
+            SPUPeerApiEnum::FetchReplicaOffsets => {
+                Ok(SpuPeerRequest::FetchReplicaOffsets(RequestMessage::new(
+                    header,
+                    FetchReplicaOffsetsRequest::decode_from(src, version)?,
+                )))
+            }
         }
     }
 }
@@ -0,0 +1,33 @@
+use std::io::Error as IoError;
+
+use fluvio_protocol::api::{RequestMessage, ResponseMessage};
+use fluvio_protocol::link::ErrorCode;
+use tracing::{instrument, debug};
+
+use crate::core::DefaultSharedGlobalContext;
+
+use super::fetch_replica_offsets_request::{FetchReplicaOffsetsRequest, FetchReplicaOffsetsResponse};
+
+#[instrument(skip(req_msg, ctx))]
+pub(crate) async fn handle_fetch_replica_offsets_request(
+    req_msg: RequestMessage<FetchReplicaOffsetsRequest>,
+    ctx: DefaultSharedGlobalContext,
+) -> Result<ResponseMessage<FetchReplicaOffsetsResponse>, IoError> {
+    let response = match ctx.leaders_state().get(&req_msg.request.replica_id).await {
+        Some(replica) => FetchReplicaOffsetsResponse {
+            error_code: ErrorCode::None,
+            leo: replica.leo(),
+        },
+        None => FetchReplicaOffsetsResponse {
+            error_code: ErrorCode::PartitionNotLeader,
+            ..Default::default()
+        },
+    };
+    debug!(%response, "replica offsets fetch result");
+    Ok(
+        RequestMessage::<FetchReplicaOffsetsRequest>::response_with_header(
+            &req_msg.header,
+            response,
+        ),
+    )
+}
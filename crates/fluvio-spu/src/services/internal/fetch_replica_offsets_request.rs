@@ -0,0 +1,39 @@
+use std::fmt;
+
+use fluvio_protocol::api::Request;
+use fluvio_protocol::link::ErrorCode;
+use fluvio_protocol::record::{Offset, ReplicaKey};
+use fluvio_protocol::{Encoder, Decoder};
+use fluvio_spu_schema::COMMON_VERSION;
+
+use super::SPUPeerApiEnum;
+
+/// Fetch offsets of a replica led by the peer
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct FetchReplicaOffsetsRequest {
+    pub replica_id: ReplicaKey,
+}
+
+impl Request for FetchReplicaOffsetsRequest {
+    const API_KEY: u16 = SPUPeerApiEnum::FetchReplicaOffsets as u16;
+    const DEFAULT_API_VERSION: i16 = COMMON_VERSION;
+    type Response = FetchReplicaOffsetsResponse;
+}
+
+impl FetchReplicaOffsetsRequest {
+    pub fn new(replica_id: ReplicaKey) -> Self {
+        Self { replica_id }
+    }
+}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct FetchReplicaOffsetsResponse {
+    pub error_code: ErrorCode,
+    pub leo: Offset,
+}
+
+impl fmt::Display for FetchReplicaOffsetsResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "error: {:#?}, leo: {}", self.error_code, self.leo)
+    }
+}
@@ -5,6 +5,10 @@ mod fetch_consumer_offset_request;
 mod fetch_consumer_offset_handler;
 mod update_consumer_offset_request;
 mod update_consumer_offset_handler;
+mod produce_dead_letter_request;
+mod produce_dead_letter_handler;
+mod fetch_replica_offsets_request;
+mod fetch_replica_offsets_handler;
 
 use tracing::info;
 
@@ -17,6 +21,8 @@ pub use self::fetch_stream_request::FetchStreamRequest;
 pub use self::fetch_stream_request::FetchStreamResponse;
 pub use self::fetch_consumer_offset_request::FetchConsumerOffsetRequest;
 pub use self::update_consumer_offset_request::UpdateConsumerOffsetRequest;
+pub use self::produce_dead_letter_request::ProduceDeadLetterRequest;
+pub use self::fetch_replica_offsets_request::FetchReplicaOffsetsRequest;
 pub use self::api::SPUPeerApiEnum;
 pub use self::api::SpuPeerRequest;
 
@@ -0,0 +1,25 @@
+use std::io::Error as IoError;
+
+use fluvio_protocol::api::{RequestMessage, ResponseMessage};
+use tracing::{instrument, trace};
+
+use crate::core::DefaultSharedGlobalContext;
+use crate::smartengine::dead_letter::write_local;
+
+use super::produce_dead_letter_request::{ProduceDeadLetterRequest, ProduceDeadLetterResponse};
+
+#[instrument(skip(req_msg, ctx))]
+pub(crate) async fn handle_produce_dead_letter_request(
+    req_msg: RequestMessage<ProduceDeadLetterRequest>,
+    ctx: DefaultSharedGlobalContext,
+) -> Result<ResponseMessage<ProduceDeadLetterResponse>, IoError> {
+    let ProduceDeadLetterRequest {
+        replica_id,
+        mut records,
+    } = req_msg.request;
+
+    let error_code = write_local(&ctx, &replica_id, &mut records).await;
+    trace!(%replica_id, ?error_code, "dead letter write result");
+    let response = ProduceDeadLetterResponse { error_code };
+    Ok(RequestMessage::<ProduceDeadLetterRequest>::response_with_header(&req_msg.header, response))
+}
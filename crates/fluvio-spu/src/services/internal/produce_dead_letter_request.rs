@@ -0,0 +1,42 @@
+use std::fmt;
+
+use fluvio_protocol::api::Request;
+use fluvio_protocol::link::ErrorCode;
+use fluvio_protocol::record::{RawRecords, RecordSet, ReplicaKey};
+use fluvio_protocol::{Encoder, Decoder};
+use fluvio_spu_schema::COMMON_VERSION;
+
+use super::SPUPeerApiEnum;
+
+/// Write records rejected by a SmartModule to the dead-letter partition led by the peer
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct ProduceDeadLetterRequest {
+    pub replica_id: ReplicaKey,
+    pub records: RecordSet<RawRecords>,
+}
+
+impl Request for ProduceDeadLetterRequest {
+    const API_KEY: u16 = SPUPeerApiEnum::ProduceDeadLetter as u16;
+    const DEFAULT_API_VERSION: i16 = COMMON_VERSION;
+    type Response = ProduceDeadLetterResponse;
+}
+
+impl ProduceDeadLetterRequest {
+    pub fn new(replica_id: ReplicaKey, records: RecordSet<RawRecords>) -> Self {
+        Self {
+            replica_id,
+            records,
+        }
+    }
+}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct ProduceDeadLetterResponse {
+    pub error_code: ErrorCode,
+}
+
+impl fmt::Display for ProduceDeadLetterResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "error: {:#?}", self.error_code)
+    }
+}
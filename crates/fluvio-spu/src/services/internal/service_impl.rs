@@ -12,6 +12,8 @@ use crate::core::DefaultSharedGlobalContext;
 use crate::replication::leader::FollowerHandler;
 use crate::services::internal::fetch_consumer_offset_handler::handle_fetch_consumer_offset_request;
 use crate::services::internal::update_consumer_offset_handler::handle_update_consumer_offset_request;
+use crate::services::internal::produce_dead_letter_handler::handle_produce_dead_letter_request;
+use crate::services::internal::fetch_replica_offsets_handler::handle_fetch_replica_offsets_request;
 use super::SpuPeerRequest;
 use super::SPUPeerApiEnum;
 use super::FetchStreamResponse;
@@ -96,6 +98,18 @@ impl FluvioService for InternalService {
                 let api_version = req_msg.header.api_version();
                 let response = handle_update_consumer_offset_request(req_msg, ctx).await?;
                 sink.send_response(&response, api_version).await?;
+            },
+            SpuPeerRequest::ProduceDeadLetter(req_msg) => {
+                trace!(replica = %req_msg.request.replica_id, "produce dead letter request");
+                let api_version = req_msg.header.api_version();
+                let response = handle_produce_dead_letter_request(req_msg, ctx).await?;
+                sink.send_response(&response, api_version).await?;
+            },
+            SpuPeerRequest::FetchReplicaOffsets(req_msg) => {
+                trace!(replica = %req_msg.request.replica_id, "fetch replica offsets request");
+                let api_version = req_msg.header.api_version();
+                let response = handle_fetch_replica_offsets_request(req_msg, ctx).await?;
+                sink.send_response(&response, api_version).await?;
             }
 
         );
@@ -0,0 +1,31 @@
+use tracing::{trace, instrument};
+
+use fluvio_protocol::Version;
+use fluvio_protocol::api::{Request, RequestMessage, ResponseMessage};
+use fluvio_spu_schema::kafka::error as kafka_error;
+use fluvio_spu_schema::kafka::api_versions::{
+    KafkaApiVersionsRequest, KafkaApiVersionsResponse, supported_api_versions,
+};
+
+/// Returns response with the version to encode it.
+/// Unsupported versions are answered in version 0 so the client can downgrade.
+#[instrument(skip(request))]
+pub fn handle_api_versions_request(
+    request: RequestMessage<KafkaApiVersionsRequest>,
+) -> (ResponseMessage<KafkaApiVersionsResponse>, Version) {
+    let version = request.header.api_version();
+    let (error_code, version) = if version > KafkaApiVersionsRequest::MAX_API_VERSION {
+        (kafka_error::UNSUPPORTED_VERSION, 0)
+    } else {
+        (kafka_error::NONE, version)
+    };
+
+    let response = KafkaApiVersionsResponse {
+        error_code,
+        api_keys: supported_api_versions(),
+        throttle_time_ms: 0,
+    };
+
+    trace!("Returning KafkaApiVersionsResponse: {:#?}", &response);
+    (request.new_response(response), version)
+}
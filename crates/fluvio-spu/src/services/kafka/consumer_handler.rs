@@ -0,0 +1,224 @@
+//! Kafka group offsets backed by the SPU consumer offset store.
+//!
+//! Group id is used as Fluvio consumer id. The coordinator of every group is the leader of the
+//! consumer offsets partition. Kafka commits the next offset to read while Fluvio stores
+//! the last consumed offset, so offsets are shifted by one in both directions,
+//! which lets Fluvio and Kafka consumers share the same consumer id.
+
+use tracing::{debug, trace, instrument};
+use anyhow::Result;
+
+use fluvio_controlplane_metadata::partition::ReplicaKey;
+use fluvio_protocol::api::{RequestMessage, ResponseMessage};
+use fluvio_spu_schema::kafka::error as kafka_error;
+use fluvio_spu_schema::kafka::find_coordinator::{FindCoordinatorRequest, FindCoordinatorResponse};
+use fluvio_spu_schema::kafka::offset_commit::{
+    OffsetCommitPartitionResponse, OffsetCommitRequest, OffsetCommitResponse,
+    OffsetCommitTopicResponse,
+};
+use fluvio_spu_schema::kafka::offset_fetch::{
+    OffsetFetchPartitionResponse, OffsetFetchRequest, OffsetFetchResponse, OffsetFetchTopicResponse,
+};
+use fluvio_types::{PartitionId, defaults::CONSUMER_STORAGE_TOPIC};
+
+use crate::core::DefaultSharedGlobalContext;
+use crate::kv::consumer::{ConsumerOffset, ConsumerOffsetKey, SharableConsumerOffsetStorage};
+
+use super::kafka_broker;
+
+const GROUP_KEY_TYPE: i8 = 0;
+
+fn consumers_replica_id() -> ReplicaKey {
+    ReplicaKey::new(CONSUMER_STORAGE_TOPIC, <PartitionId as Default>::default())
+}
+
+#[instrument(skip(request, ctx))]
+pub async fn handle_find_coordinator_request(
+    request: RequestMessage<FindCoordinatorRequest>,
+    ctx: DefaultSharedGlobalContext,
+) -> Result<ResponseMessage<FindCoordinatorResponse>> {
+    let mut response = FindCoordinatorResponse {
+        node_id: -1,
+        ..Default::default()
+    };
+
+    let leader = ctx
+        .replica_localstore()
+        .spec(&consumers_replica_id())
+        .and_then(|replica| ctx.spu_localstore().spec(&replica.leader));
+
+    match leader {
+        Some(spu) if request.request.key_type == GROUP_KEY_TYPE => {
+            let broker = kafka_broker(&ctx, &spu);
+            response.node_id = broker.node_id;
+            response.host = broker.host;
+            response.port = broker.port;
+        }
+        Some(_) => {
+            response.error_code = kafka_error::COORDINATOR_NOT_AVAILABLE;
+            response.error_message = "transactions are not supported".to_owned().into();
+        }
+        None => {
+            debug!("consumer offsets replica not found");
+            response.error_code = kafka_error::COORDINATOR_NOT_AVAILABLE;
+        }
+    }
+
+    trace!("Returning FindCoordinatorResponse: {:#?}", &response);
+    Ok(request.new_response(response))
+}
+
+#[instrument(skip(request, ctx), fields(group = %request.request.group_id))]
+pub async fn handle_offset_commit_request(
+    request: RequestMessage<OffsetCommitRequest>,
+    ctx: DefaultSharedGlobalContext,
+) -> Result<ResponseMessage<OffsetCommitResponse>> {
+    let commit_request = &request.request;
+    let consumers = local_consumers(&ctx).await;
+
+    let mut response = OffsetCommitResponse::default();
+    for topic_request in &commit_request.topics {
+        let mut topic_response = OffsetCommitTopicResponse {
+            name: topic_request.name.clone(),
+            partitions: vec![],
+        };
+        for partition_request in &topic_request.partitions {
+            let error_code = match &consumers {
+                Ok(consumers) if partition_request.partition_index >= 0 => {
+                    let key = ConsumerOffsetKey::new(
+                        ReplicaKey::new(
+                            topic_request.name.clone(),
+                            partition_request.partition_index as PartitionId,
+                        ),
+                        commit_request.group_id.clone(),
+                    );
+                    let offset = ConsumerOffset::new(partition_request.committed_offset - 1);
+                    match consumers.put(key, offset).await {
+                        Ok(_) => kafka_error::NONE,
+                        Err(err) => {
+                            debug!(%err, "unable to commit offset");
+                            kafka_error::UNKNOWN_SERVER_ERROR
+                        }
+                    }
+                }
+                Ok(_) => kafka_error::UNKNOWN_TOPIC_OR_PARTITION,
+                Err(error_code) => *error_code,
+            };
+            topic_response
+                .partitions
+                .push(OffsetCommitPartitionResponse {
+                    partition_index: partition_request.partition_index,
+                    error_code,
+                });
+        }
+        response.topics.push(topic_response);
+    }
+
+    trace!("Returning OffsetCommitResponse: {:#?}", &response);
+    Ok(request.new_response(response))
+}
+
+#[instrument(skip(request, ctx), fields(group = %request.request.group_id))]
+pub async fn handle_offset_fetch_request(
+    request: RequestMessage<OffsetFetchRequest>,
+    ctx: DefaultSharedGlobalContext,
+) -> Result<ResponseMessage<OffsetFetchResponse>> {
+    let fetch_request = &request.request;
+    let mut response = OffsetFetchResponse::default();
+
+    let consumers = match local_consumers(&ctx).await {
+        Ok(consumers) => consumers,
+        Err(error_code) => {
+            response.error_code = error_code;
+            return Ok(request.new_response(response));
+        }
+    };
+
+    let committed = match consumers.list().await {
+        Ok(committed) => committed,
+        Err(err) => {
+            debug!(%err, "unable to list consumer offsets");
+            response.error_code = kafka_error::UNKNOWN_SERVER_ERROR;
+            return Ok(request.new_response(response));
+        }
+    };
+    let committed_offset = |topic: &str, partition: i32| -> i64 {
+        committed
+            .iter()
+            .find(|(key, _)| {
+                key.consumer_id == fetch_request.group_id
+                    && key.replica_id.topic == topic
+                    && key.replica_id.partition as i32 == partition
+            })
+            .map(|(_, consumer)| consumer.offset + 1)
+            .unwrap_or(-1)
+    };
+
+    if fetch_request.topics.is_empty() {
+        for (key, _) in committed
+            .iter()
+            .filter(|(key, _)| key.consumer_id == fetch_request.group_id)
+        {
+            let topic = &key.replica_id.topic;
+            let partition = key.replica_id.partition as i32;
+            let partition_response =
+                offset_fetch_partition(partition, committed_offset(topic, partition));
+            match response.topics.iter_mut().find(|t| &t.name == topic) {
+                Some(topic_response) => topic_response.partitions.push(partition_response),
+                None => response.topics.push(OffsetFetchTopicResponse {
+                    name: topic.clone(),
+                    partitions: vec![partition_response],
+                }),
+            }
+        }
+    } else {
+        for topic_request in &fetch_request.topics {
+            response.topics.push(OffsetFetchTopicResponse {
+                name: topic_request.name.clone(),
+                partitions: topic_request
+                    .partition_indexes
+                    .iter()
+                    .map(|partition| {
+                        offset_fetch_partition(
+                            *partition,
+                            committed_offset(&topic_request.name, *partition),
+                        )
+                    })
+                    .collect(),
+            });
+        }
+    }
+
+    trace!("Returning OffsetFetchResponse: {:#?}", &response);
+    Ok(request.new_response(response))
+}
+
+fn offset_fetch_partition(
+    partition_index: i32,
+    committed_offset: i64,
+) -> OffsetFetchPartitionResponse {
+    OffsetFetchPartitionResponse {
+        partition_index,
+        committed_offset,
+        committed_leader_epoch: -1,
+        ..Default::default()
+    }
+}
+
+/// Consumer offset store, only available on the coordinator
+async fn local_consumers(
+    ctx: &DefaultSharedGlobalContext,
+) -> Result<SharableConsumerOffsetStorage, i16> {
+    let Some(ref replica) = ctx.leaders_state().get(&consumers_replica_id()).await else {
+        debug!("not coordinator");
+        return Err(kafka_error::NOT_COORDINATOR);
+    };
+
+    ctx.consumer_offset()
+        .get_or_insert(replica, ctx.follower_notifier())
+        .await
+        .map_err(|err| {
+            debug!(%err, "unable to open consumer offset store");
+            kafka_error::COORDINATOR_NOT_AVAILABLE
+        })
+}
@@ -0,0 +1,182 @@
+use std::time::Duration;
+
+use futures_util::future::{select_all, FutureExt};
+use tokio::select;
+use tracing::{debug, trace, instrument};
+use anyhow::Result;
+
+use fluvio_future::timer::sleep;
+use fluvio_protocol::api::{RequestMessage, ResponseMessage};
+use fluvio_protocol::link::ErrorCode;
+use fluvio_spu_schema::Isolation;
+use fluvio_spu_schema::kafka::error as kafka_error;
+use fluvio_spu_schema::kafka::fetch::{
+    KafkaFetchPartition, KafkaFetchPartitionResponse, KafkaFetchRequest, KafkaFetchResponse,
+    KafkaFetchTopicResponse,
+};
+use fluvio_storage::iterators::FileBatchIterator;
+
+use crate::core::DefaultSharedGlobalContext;
+use crate::core::metrics::IncreaseValue;
+
+use super::produce_handler::find_leader;
+use super::records::encode_kafka_batch;
+
+/// Records are read from storage and re-encoded as Kafka batches, zero copy is not possible.
+/// As in Kafka, consumers only read committed records regardless of isolation level.
+/// If there is nothing to return, waits up to `max_wait_ms` for new records on any of the partitions.
+#[instrument(
+    skip(request, ctx),
+    fields(
+        max_bytes = request.request.max_bytes,
+        max_wait_ms = request.request.max_wait_ms,
+    ),
+)]
+pub async fn handle_fetch_request(
+    request: RequestMessage<KafkaFetchRequest>,
+    ctx: DefaultSharedGlobalContext,
+) -> Result<ResponseMessage<KafkaFetchResponse>> {
+    let fetch_request = &request.request;
+    trace!("Handling KafkaFetchRequest: {:#?}", fetch_request);
+
+    let mut response = fetch_partitions(&ctx, fetch_request).await;
+    if response.records_len() == 0 && fetch_request.min_bytes > 0 && fetch_request.max_wait_ms > 0 {
+        wait_for_records(&ctx, fetch_request).await;
+        response = fetch_partitions(&ctx, fetch_request).await;
+    }
+
+    trace!(
+        records = response.records_len(),
+        "Returning KafkaFetchResponse"
+    );
+    Ok(request.new_response(response))
+}
+
+async fn fetch_partitions(
+    ctx: &DefaultSharedGlobalContext,
+    fetch_request: &KafkaFetchRequest,
+) -> KafkaFetchResponse {
+    let mut response = KafkaFetchResponse::default();
+    let mut remaining_bytes = fetch_request.max_bytes.max(0);
+
+    for topic_request in &fetch_request.topics {
+        let mut topic_response = KafkaFetchTopicResponse {
+            name: topic_request.name.clone(),
+            partitions: vec![],
+        };
+        for partition_request in &topic_request.partitions {
+            let max_bytes = partition_request.partition_max_bytes.min(remaining_bytes);
+            let partition_response =
+                fetch_partition(ctx, &topic_request.name, partition_request, max_bytes).await;
+            remaining_bytes -= partition_response.records.len() as i32;
+            topic_response.partitions.push(partition_response);
+        }
+        response.topics.push(topic_response);
+    }
+    response
+}
+
+#[instrument(skip(ctx, partition_request), fields(partition = partition_request.partition_index))]
+async fn fetch_partition(
+    ctx: &DefaultSharedGlobalContext,
+    topic: &str,
+    partition_request: &KafkaFetchPartition,
+    max_bytes: i32,
+) -> KafkaFetchPartitionResponse {
+    let mut response = KafkaFetchPartitionResponse {
+        partition_index: partition_request.partition_index,
+        high_watermark: -1,
+        last_stable_offset: -1,
+        log_start_offset: -1,
+        records: Vec::new().into(),
+        ..Default::default()
+    };
+
+    let (replica_id, leader_state) =
+        match find_leader(ctx, topic, partition_request.partition_index).await {
+            Ok(found) => found,
+            Err(error_code) => {
+                response.error_code = error_code;
+                return response;
+            }
+        };
+
+    let slice = match leader_state
+        .read_records(
+            partition_request.fetch_offset,
+            max_bytes.max(0) as u32,
+            Isolation::ReadCommitted,
+        )
+        .await
+    {
+        Ok(slice) => slice,
+        Err(err) => {
+            debug!(%replica_id, %err, "Failed to read records for partition");
+            response.error_code = match err {
+                ErrorCode::OffsetEvicted { .. } => kafka_error::OFFSET_OUT_OF_RANGE,
+                _ => kafka_error::UNKNOWN_SERVER_ERROR,
+            };
+            return response;
+        }
+    };
+
+    response.high_watermark = slice.end.hw;
+    response.last_stable_offset = slice.end.hw;
+    response.log_start_offset = slice.start;
+
+    if let Some(file_slice) = slice.file_slice {
+        let mut records = vec![];
+        for file_batch in FileBatchIterator::from_raw_slice(file_slice) {
+            let result = file_batch.and_then(|batch| encode_kafka_batch(&batch, &mut records));
+            if let Err(err) = result {
+                debug!(%replica_id, %err, "Failed to convert batch to kafka format");
+                if records.is_empty() {
+                    response.error_code = kafka_error::KAFKA_STORAGE_ERROR;
+                }
+                break;
+            }
+        }
+        let metrics = ctx.metrics();
+        let fetched = IncreaseValue::new((slice.end.hw - slice.start) as u64, records.len() as u64);
+        metrics.outbound().increase_by_value(false, fetched);
+        metrics
+            .partitions()
+            .partition(&replica_id)
+            .fetched()
+            .increase_by_value(fetched);
+        response.records = records.into();
+    }
+
+    response
+}
+
+/// wait until any of the local partitions has records after the fetch offset
+async fn wait_for_records(ctx: &DefaultSharedGlobalContext, fetch_request: &KafkaFetchRequest) {
+    let mut waits = vec![];
+    for topic_request in &fetch_request.topics {
+        for partition_request in &topic_request.partitions {
+            let Ok((_, leader_state)) =
+                find_leader(ctx, &topic_request.name, partition_request.partition_index).await
+            else {
+                continue;
+            };
+            let mut listener = leader_state.offset_listener(&Isolation::ReadCommitted);
+            let fetch_offset = partition_request.fetch_offset;
+            waits.push(async move { while listener.listen().await <= fetch_offset {} }.boxed());
+        }
+    }
+
+    if waits.is_empty() {
+        return;
+    }
+
+    let max_wait = Duration::from_millis(fetch_request.max_wait_ms as u64);
+    select! {
+        _ = select_all(waits) => {
+            trace!("new records available");
+        },
+        _ = sleep(max_wait) => {
+            trace!("fetch wait time exceeded");
+        },
+    }
+}
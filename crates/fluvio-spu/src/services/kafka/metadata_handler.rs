@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+
+use tracing::{trace, instrument};
+use anyhow::Result;
+
+use fluvio_controlplane::replica::Replica;
+use fluvio_protocol::api::{RequestMessage, ResponseMessage};
+use fluvio_spu_schema::kafka::error as kafka_error;
+use fluvio_spu_schema::kafka::metadata::{
+    MetadataRequest, MetadataResponse, MetadataResponsePartition, MetadataResponseTopic,
+};
+use fluvio_types::defaults::CONSUMER_STORAGE_TOPIC;
+
+use crate::core::DefaultSharedGlobalContext;
+
+use super::kafka_broker;
+
+/// Metadata is built from the replicas and SPUs received from SC.
+/// SPU doesn't track in-sync replicas of other leaders, so all replicas are reported as in-sync.
+#[instrument(skip(request, ctx))]
+pub async fn handle_metadata_request(
+    request: RequestMessage<MetadataRequest>,
+    ctx: DefaultSharedGlobalContext,
+) -> Result<ResponseMessage<MetadataResponse>> {
+    let mut topics: BTreeMap<String, Vec<Replica>> = BTreeMap::new();
+    for replica in ctx.replica_localstore().all_values() {
+        if replica.is_being_deleted {
+            continue;
+        }
+        topics
+            .entry(replica.id.topic.clone())
+            .or_default()
+            .push(replica);
+    }
+
+    let requested: Vec<String> = if request.request.topics.is_empty() {
+        topics
+            .keys()
+            .filter(|name| name.as_str() != CONSUMER_STORAGE_TOPIC)
+            .cloned()
+            .collect()
+    } else {
+        request
+            .request
+            .topics
+            .iter()
+            .map(|topic| topic.name.clone())
+            .collect()
+    };
+
+    let mut response = MetadataResponse {
+        brokers: ctx
+            .spu_localstore()
+            .all_values()
+            .iter()
+            .map(|spu| kafka_broker(&ctx, spu))
+            .collect(),
+        controller_id: -1,
+        ..Default::default()
+    };
+
+    for name in requested {
+        let topic = match topics.get_mut(&name) {
+            Some(replicas) => {
+                replicas.sort_by_key(|replica| replica.id.partition);
+                MetadataResponseTopic {
+                    name: name.clone(),
+                    is_internal: name == CONSUMER_STORAGE_TOPIC,
+                    partitions: replicas.iter().map(partition_metadata).collect(),
+                    ..Default::default()
+                }
+            }
+            None => MetadataResponseTopic {
+                error_code: kafka_error::UNKNOWN_TOPIC_OR_PARTITION,
+                name,
+                ..Default::default()
+            },
+        };
+        response.topics.push(topic);
+    }
+
+    trace!("Returning MetadataResponse: {:#?}", &response);
+    Ok(request.new_response(response))
+}
+
+fn partition_metadata(replica: &Replica) -> MetadataResponsePartition {
+    MetadataResponsePartition {
+        error_code: kafka_error::NONE,
+        partition_index: replica.id.partition as i32,
+        leader_id: replica.leader,
+        replica_nodes: replica.replicas.clone(),
+        isr_nodes: replica.replicas.clone(),
+        offline_replicas: vec![],
+    }
+}
@@ -0,0 +1,169 @@
+//!
+//! # Kafka compatible listener
+//!
+//! Optional listener serving a subset of the Kafka wire protocol on top of the SPU replicas:
+//! ApiVersions, Metadata, Produce, Fetch, ListOffsets, OffsetCommit, OffsetFetch and FindCoordinator.
+//!
+//! Every SPU is advertised as a Kafka broker with its public host and the port of the Kafka listener,
+//! so all SPUs must be started with the same Kafka port.
+//! Group membership, idempotent and transactional producers are not supported.
+//!
+mod api_versions;
+mod metadata_handler;
+mod produce_handler;
+mod fetch_handler;
+mod offset_request;
+mod consumer_handler;
+mod records;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use tracing::{debug, info, trace, instrument};
+use anyhow::Result;
+
+use fluvio_controlplane_metadata::spu::SpuSpec;
+use fluvio_service::{FluvioApiServer, FluvioService, ConnectInfo, call_service};
+use fluvio_socket::FluvioSocket;
+use fluvio_spu_schema::kafka::{KafkaApiKey, KafkaRequest};
+use fluvio_spu_schema::kafka::metadata::MetadataResponseBroker;
+
+use crate::core::DefaultSharedGlobalContext;
+use self::api_versions::handle_api_versions_request;
+use self::metadata_handler::handle_metadata_request;
+use self::produce_handler::handle_produce_request;
+use self::fetch_handler::handle_fetch_request;
+use self::offset_request::handle_list_offsets_request;
+use self::consumer_handler::{
+    handle_find_coordinator_request, handle_offset_commit_request, handle_offset_fetch_request,
+};
+
+pub(crate) type KafkaApiServer =
+    FluvioApiServer<KafkaRequest, KafkaApiKey, DefaultSharedGlobalContext, KafkaService>;
+
+pub fn create_kafka_server(addr: String, ctx: DefaultSharedGlobalContext) -> KafkaApiServer {
+    info!(
+        spu_id = ctx.local_spu_id(),
+        %addr,
+        "Starting SPU kafka service:",
+    );
+
+    FluvioApiServer::new(addr, ctx, KafkaService::new())
+}
+
+#[derive(Debug)]
+pub struct KafkaService {}
+
+impl KafkaService {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait]
+impl FluvioService for KafkaService {
+    type Context = DefaultSharedGlobalContext;
+    type Request = KafkaRequest;
+
+    #[instrument(skip(self, ctx))]
+    async fn respond(
+        self: Arc<Self>,
+        ctx: DefaultSharedGlobalContext,
+        socket: FluvioSocket,
+        _connection: ConnectInfo,
+    ) -> Result<()> {
+        let (sink, mut stream) = socket.split();
+        let mut shared_sink = sink.as_shared();
+        let mut api_stream = stream.api_stream::<KafkaRequest, KafkaApiKey>();
+
+        // requests are served one at time, kafka clients expect responses in request order
+        loop {
+            match api_stream.next().await {
+                Some(Ok(req_message)) => {
+                    debug!(%req_message, "received");
+                    trace!(
+                        "conn: {}, received request: {:#?}",
+                        shared_sink.id(),
+                        req_message
+                    );
+                    match req_message {
+                        KafkaRequest::ApiVersionsRequest(request) => {
+                            let (response, version) = handle_api_versions_request(request);
+                            shared_sink.send_response(&response, version).await?;
+                        }
+                        KafkaRequest::MetadataRequest(request) => call_service!(
+                            request,
+                            handle_metadata_request(request, ctx.clone()),
+                            shared_sink,
+                            "MetadataRequest"
+                        ),
+                        KafkaRequest::KafkaProduceRequest(request) => {
+                            let version = request.header.api_version();
+                            // acks = 0 doesn't expect any response
+                            if let Some(response) =
+                                handle_produce_request(request, ctx.clone()).await?
+                            {
+                                shared_sink.send_response(&response, version).await?;
+                            }
+                        }
+                        KafkaRequest::KafkaFetchRequest(request) => call_service!(
+                            request,
+                            handle_fetch_request(request, ctx.clone()),
+                            shared_sink,
+                            "KafkaFetchRequest"
+                        ),
+                        KafkaRequest::ListOffsetsRequest(request) => call_service!(
+                            request,
+                            handle_list_offsets_request(request, ctx.clone()),
+                            shared_sink,
+                            "ListOffsetsRequest"
+                        ),
+                        KafkaRequest::OffsetCommitRequest(request) => call_service!(
+                            request,
+                            handle_offset_commit_request(request, ctx.clone()),
+                            shared_sink,
+                            "OffsetCommitRequest"
+                        ),
+                        KafkaRequest::OffsetFetchRequest(request) => call_service!(
+                            request,
+                            handle_offset_fetch_request(request, ctx.clone()),
+                            shared_sink,
+                            "OffsetFetchRequest"
+                        ),
+                        KafkaRequest::FindCoordinatorRequest(request) => call_service!(
+                            request,
+                            handle_find_coordinator_request(request, ctx.clone()),
+                            shared_sink,
+                            "FindCoordinatorRequest"
+                        ),
+                    }
+                }
+                Some(Err(e)) => {
+                    debug!(
+                        sink_id = shared_sink.id(),
+                        "Error decoding message, ending connection: {}", e
+                    );
+                    break;
+                }
+                None => {
+                    debug!(sink_id = shared_sink.id(), "No content, end of connection",);
+                    break;
+                }
+            }
+        }
+
+        debug!("kafka service terminated");
+        Ok(())
+    }
+}
+
+/// SPU advertised as Kafka broker
+fn kafka_broker(ctx: &DefaultSharedGlobalContext, spu: &SpuSpec) -> MetadataResponseBroker {
+    MetadataResponseBroker {
+        node_id: spu.id,
+        host: spu.public_endpoint.host_string(),
+        port: ctx.config().kafka_port().unwrap_or_default() as i32,
+        rack: spu.rack.clone().into(),
+    }
+}
@@ -0,0 +1,70 @@
+use tracing::{trace, instrument};
+use anyhow::Result;
+
+use fluvio_protocol::api::{RequestMessage, ResponseMessage};
+use fluvio_spu_schema::kafka::error as kafka_error;
+use fluvio_spu_schema::kafka::list_offsets::{
+    ListOffsetsPartition, ListOffsetsPartitionResponse, ListOffsetsRequest, ListOffsetsResponse,
+    ListOffsetsTopicResponse, EARLIEST_TIMESTAMP, LATEST_TIMESTAMP,
+};
+
+use crate::core::DefaultSharedGlobalContext;
+
+use super::produce_handler::find_leader;
+
+/// Only latest and earliest offsets are supported, records are not indexed by timestamp.
+/// As in Kafka, latest offset is the high watermark for both isolation levels.
+#[instrument(skip(request, ctx))]
+pub async fn handle_list_offsets_request(
+    request: RequestMessage<ListOffsetsRequest>,
+    ctx: DefaultSharedGlobalContext,
+) -> Result<ResponseMessage<ListOffsetsResponse>> {
+    let list_request = &request.request;
+    let mut response = ListOffsetsResponse::default();
+
+    for topic_request in &list_request.topics {
+        let mut topic_response = ListOffsetsTopicResponse {
+            name: topic_request.name.clone(),
+            partitions: vec![],
+        };
+        for partition_request in &topic_request.partitions {
+            let partition_response =
+                list_partition_offset(&ctx, &topic_request.name, partition_request).await;
+            topic_response.partitions.push(partition_response);
+        }
+        response.topics.push(topic_response);
+    }
+
+    trace!("Returning ListOffsetsResponse: {:#?}", &response);
+    Ok(request.new_response(response))
+}
+
+async fn list_partition_offset(
+    ctx: &DefaultSharedGlobalContext,
+    topic: &str,
+    partition_request: &ListOffsetsPartition,
+) -> ListOffsetsPartitionResponse {
+    let mut response = ListOffsetsPartitionResponse {
+        partition_index: partition_request.partition_index,
+        timestamp: -1,
+        offset: -1,
+        leader_epoch: -1,
+        ..Default::default()
+    };
+
+    let leader_state = match find_leader(ctx, topic, partition_request.partition_index).await {
+        Ok((_, leader_state)) => leader_state,
+        Err(error_code) => {
+            response.error_code = error_code;
+            return response;
+        }
+    };
+
+    let (start_offset, hw) = leader_state.start_offset_info().await;
+    match partition_request.timestamp {
+        LATEST_TIMESTAMP => response.offset = hw,
+        EARLIEST_TIMESTAMP => response.offset = start_offset,
+        _ => response.error_code = kafka_error::UNSUPPORTED_FOR_MESSAGE_FORMAT,
+    }
+    response
+}
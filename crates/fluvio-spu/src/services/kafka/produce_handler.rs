@@ -0,0 +1,256 @@
+use std::time::Duration;
+
+use tokio::select;
+use tracing::{debug, error, trace, instrument};
+use anyhow::Result;
+
+use fluvio_compression::Compression;
+use fluvio_controlplane_metadata::partition::ReplicaKey;
+use fluvio_controlplane_metadata::topic::CompressionAlgorithm;
+use fluvio_future::timer::sleep;
+use fluvio_protocol::api::{RequestMessage, ResponseMessage};
+use fluvio_protocol::link::ErrorCode;
+use fluvio_protocol::record::{Batch, RawRecords, RecordSet};
+use fluvio_spu_schema::Isolation;
+use fluvio_spu_schema::kafka::error as kafka_error;
+use fluvio_spu_schema::kafka::produce::{
+    KafkaProducePartition, KafkaProducePartitionResponse, KafkaProduceRequest,
+    KafkaProduceTopicResponse, KafkaProduceResponse,
+};
+
+use crate::core::DefaultSharedGlobalContext;
+use crate::replication::leader::SharedFileLeaderState;
+use crate::services::public::write_partition;
+
+use super::records::decode_kafka_batches;
+
+const ACKS_NONE: i16 = 0;
+const ACKS_LEADER: i16 = 1;
+const ACKS_ALL: i16 = -1;
+
+/// Returns `None` when the producer doesn't expect a response (acks = 0)
+#[instrument(
+    skip(request, ctx),
+    fields(
+        id = request.header.correlation_id(),
+        client = %request.header.client_id()
+    )
+)]
+pub async fn handle_produce_request(
+    request: RequestMessage<KafkaProduceRequest>,
+    ctx: DefaultSharedGlobalContext,
+) -> Result<Option<ResponseMessage<KafkaProduceResponse>>> {
+    let (header, produce_request) = request.get_header_request();
+    trace!("Handling KafkaProduceRequest: {:#?}", produce_request);
+
+    let acks = produce_request.acks;
+    let rejected = if produce_request.transactional_id.as_deref().is_some() {
+        debug!("transactional producer is not supported");
+        Some(kafka_error::UNSUPPORTED_FOR_MESSAGE_FORMAT)
+    } else if ![ACKS_NONE, ACKS_LEADER, ACKS_ALL].contains(&acks) {
+        Some(kafka_error::INVALID_REQUIRED_ACKS)
+    } else {
+        None
+    };
+    let timeout = Duration::from_millis(produce_request.timeout_ms.max(0) as u64);
+
+    let mut response = KafkaProduceResponse::default();
+    for topic_request in produce_request.topics {
+        let mut topic_response = KafkaProduceTopicResponse {
+            name: topic_request.name,
+            partitions: vec![],
+        };
+        for partition_request in topic_request.partitions {
+            let partition_response = if let Some(error_code) = rejected {
+                KafkaProducePartitionResponse {
+                    partition_index: partition_request.partition_index,
+                    error_code,
+                    ..Default::default()
+                }
+            } else {
+                handle_produce_partition(
+                    &ctx,
+                    &topic_response.name,
+                    partition_request,
+                    acks,
+                    timeout,
+                )
+                .await
+            };
+            topic_response.partitions.push(partition_response);
+        }
+        response.topics.push(topic_response);
+    }
+
+    if acks == ACKS_NONE {
+        return Ok(None);
+    }
+
+    trace!("Returning KafkaProduceResponse: {:#?}", &response);
+    Ok(Some(
+        RequestMessage::<KafkaProduceRequest>::response_with_header(&header, response),
+    ))
+}
+
+#[instrument(skip(ctx, partition_request), fields(partition = partition_request.partition_index))]
+async fn handle_produce_partition(
+    ctx: &DefaultSharedGlobalContext,
+    topic: &str,
+    partition_request: KafkaProducePartition,
+    acks: i16,
+    timeout: Duration,
+) -> KafkaProducePartitionResponse {
+    let partition_index = partition_request.partition_index;
+    let mut response = KafkaProducePartitionResponse {
+        partition_index,
+        base_offset: -1,
+        log_append_time_ms: -1,
+        log_start_offset: -1,
+        ..Default::default()
+    };
+
+    let (replica_id, leader_state) = match find_leader(ctx, topic, partition_index).await {
+        Ok(found) => found,
+        Err(error_code) => {
+            response.error_code = error_code;
+            return response;
+        }
+    };
+
+    if let Some(mirror) = &leader_state.get_replica().mirror {
+        if mirror.is_home_mirror() {
+            debug!(%replica_id, "Mirror replica is not supported for produce");
+            response.error_code = kafka_error::POLICY_VIOLATION;
+            return response;
+        }
+    }
+
+    let Some(records) = partition_request.records.0 else {
+        response.error_code = kafka_error::CORRUPT_MESSAGE;
+        return response;
+    };
+    let batches = match decode_kafka_batches(records) {
+        Ok(batches) => batches,
+        Err(error_code) => {
+            response.error_code = error_code;
+            return response;
+        }
+    };
+
+    // kafka batches are uncompressed when decoded, compress them again as the topic expects
+    let compression = match leader_state.get_replica().compression_type {
+        CompressionAlgorithm::Any | CompressionAlgorithm::None => Compression::None,
+        CompressionAlgorithm::Gzip => Compression::Gzip,
+        CompressionAlgorithm::Snappy => Compression::Snappy,
+        CompressionAlgorithm::Lz4 => Compression::Lz4,
+        CompressionAlgorithm::Zstd => Compression::Zstd,
+    };
+    let batches: Result<Vec<Batch<RawRecords>>, _> = batches
+        .into_iter()
+        .map(|mut batch| {
+            batch.get_mut_header().set_compression(compression);
+            Batch::<RawRecords>::try_from(batch)
+        })
+        .collect();
+    let mut records = match batches {
+        Ok(batches) => RecordSet { batches },
+        Err(err) => {
+            error!(%replica_id, "Error compressing kafka batches: {:#?}", err);
+            response.error_code = kafka_error::UNSUPPORTED_COMPRESSION_TYPE;
+            return response;
+        }
+    };
+
+    let isolation = if acks == ACKS_ALL {
+        Isolation::ReadCommitted
+    } else {
+        Isolation::ReadUncommitted
+    };
+    let (base_offset, leo) = match write_partition(
+        ctx,
+        None, // kafka listener is not authenticated, there is no tenant
+        &replica_id,
+        &leader_state,
+        &mut records,
+        false,
+        false,
+        isolation,
+    )
+    .await
+    {
+        Ok(offsets) => offsets,
+        Err(error_code) => {
+            response.error_code = kafka_error_code(&error_code);
+            return response;
+        }
+    };
+
+    if acks == ACKS_ALL && !wait_for_hw(&leader_state, leo, timeout).await {
+        debug!(%replica_id, "response timeout exceeded");
+        response.error_code = kafka_error::REQUEST_TIMED_OUT;
+        return response;
+    }
+
+    response.base_offset = base_offset;
+    response.log_start_offset = leader_state.start_offset_info().await.0;
+    response
+}
+
+/// Kafka error code of error returned by write of records
+fn kafka_error_code(error_code: &ErrorCode) -> i16 {
+    match error_code {
+        ErrorCode::NotEnoughInSyncReplicas { .. } => kafka_error::NOT_ENOUGH_REPLICAS,
+        ErrorCode::TopicNotFound => kafka_error::UNKNOWN_TOPIC_OR_PARTITION,
+        ErrorCode::NotLeaderForPartition => kafka_error::NOT_LEADER_OR_FOLLOWER,
+        ErrorCode::CompressionError => kafka_error::UNSUPPORTED_COMPRESSION_TYPE,
+        ErrorCode::MessageTooLarge => kafka_error::MESSAGE_TOO_LARGE,
+        ErrorCode::StorageError => kafka_error::KAFKA_STORAGE_ERROR,
+        ErrorCode::SmartModuleRuntimeError(_) => kafka_error::INVALID_RECORD,
+        _ => kafka_error::UNKNOWN_SERVER_ERROR,
+    }
+}
+
+/// Leader of the partition if it's local, otherwise Kafka error code
+pub(super) async fn find_leader(
+    ctx: &DefaultSharedGlobalContext,
+    topic: &str,
+    partition_index: i32,
+) -> Result<(ReplicaKey, SharedFileLeaderState), i16> {
+    if partition_index < 0 {
+        return Err(kafka_error::UNKNOWN_TOPIC_OR_PARTITION);
+    }
+    let replica_id = ctx
+        .replica_localstore()
+        .resolve(ReplicaKey::new(topic, partition_index as u32));
+    match ctx.leaders_state().get(&replica_id).await {
+        Some(leader_state) => Ok((replica_id, leader_state)),
+        None if ctx.replica_localstore().contains_key(&replica_id) => {
+            debug!(%replica_id, "Not leader for partition");
+            Err(kafka_error::NOT_LEADER_OR_FOLLOWER)
+        }
+        None => {
+            debug!(%replica_id, "Replica not found");
+            Err(kafka_error::UNKNOWN_TOPIC_OR_PARTITION)
+        }
+    }
+}
+
+/// wait until hw includes `leo`, returns false on timeout
+async fn wait_for_hw(leader_state: &SharedFileLeaderState, leo: i64, timeout: Duration) -> bool {
+    if leader_state.hw() >= leo {
+        return true;
+    }
+    let mut listener = leader_state.offset_listener(&Isolation::ReadCommitted);
+    let wait_future = async {
+        loop {
+            let hw = listener.listen().await;
+            if hw >= leo {
+                break;
+            }
+        }
+    };
+    select! {
+        _ = wait_future => true,
+        _ = sleep(timeout) => false,
+    }
+}
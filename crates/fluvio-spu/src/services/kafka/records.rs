@@ -0,0 +1,457 @@
+//! Conversion between Kafka record batches (magic 2) and Fluvio batches.
+//!
+//! Batch headers have the same layout, but Fluvio records encode the key as an option,
+//! so records are always rewritten.
+//! Compressed Kafka batches are uncompressed here and compressed again with the topic compression,
+//! because Kafka snappy is xerial framed (or a raw block), unlike snappy frames of Fluvio batches.
+
+use std::io::Error as IoError;
+
+use bytes::{Buf, BufMut, Bytes};
+use tracing::debug;
+
+use fluvio_compression::Compression;
+use fluvio_protocol::{Decoder, DecoderVarInt, Encoder, EncoderVarInt};
+use fluvio_protocol::record::{Batch, Offset, Record, RecordData, RecordHeaderEntry};
+use fluvio_spu_schema::kafka::error as kafka_error;
+use fluvio_storage::iterators::FileBatch;
+
+const MAGIC: i8 = 2;
+const ATTR_COMPRESSION_CODEC_MASK: i16 = 0x07;
+const ATTR_TRANSACTIONAL: i16 = 0x10;
+const ATTR_CONTROL: i16 = 0x20;
+
+/// Header of xerial framed snappy: magic, version and compatible version
+const XERIAL_MAGIC: [u8; 8] = [0x82, b'S', b'N', b'A', b'P', b'P', b'Y', 0];
+const XERIAL_HEADER_LEN: usize = 16;
+
+/// Size of batch fields before `attributes`, which are not covered by crc
+const BATCH_CRC_OFFSET: usize = 8 + 4 + 4 + 1 + 4;
+
+/// Size of batch header including records count
+const BATCH_OVERHEAD: usize = BATCH_CRC_OFFSET + 2 + 4 + 8 + 8 + 8 + 2 + 4 + 4;
+
+/// Decode Kafka record batches into Fluvio batches.
+/// Errors are returned as Kafka error codes.
+pub(crate) fn decode_kafka_batches(mut src: Bytes) -> Result<Vec<Batch>, i16> {
+    let mut batches = vec![];
+    while src.has_remaining() {
+        if src.remaining() < BATCH_CRC_OFFSET {
+            debug!(remaining = src.remaining(), "truncated kafka batch");
+            return Err(kafka_error::CORRUPT_MESSAGE);
+        }
+        let batch_len = i32::from_be_bytes(src[8..12].try_into().unwrap_or_default());
+        let total_len = 12 + batch_len.max(0) as usize;
+        if total_len < BATCH_OVERHEAD || src.remaining() < total_len {
+            debug!(
+                batch_len,
+                remaining = src.remaining(),
+                "invalid kafka batch length"
+            );
+            return Err(kafka_error::CORRUPT_MESSAGE);
+        }
+        let batch_bytes = src.split_to(total_len);
+        batches.push(decode_kafka_batch(batch_bytes)?);
+    }
+    Ok(batches)
+}
+
+fn decode_kafka_batch(bytes: Bytes) -> Result<Batch, i16> {
+    let magic = bytes[16] as i8;
+    if magic != MAGIC {
+        debug!(magic, "unsupported kafka message format");
+        return Err(kafka_error::UNSUPPORTED_FOR_MESSAGE_FORMAT);
+    }
+    let crc = u32::from_be_bytes(bytes[17..21].try_into().unwrap_or_default());
+    if crc32c::crc32c(&bytes[BATCH_CRC_OFFSET..]) != crc {
+        debug!(crc, "kafka batch crc mismatch");
+        return Err(kafka_error::CORRUPT_MESSAGE);
+    }
+
+    let mut src = bytes.slice(BATCH_CRC_OFFSET..);
+    let mut decode = || -> Result<Batch, IoError> {
+        let mut batch = Batch::default();
+        let header = batch.get_mut_header();
+        header.attributes.decode(&mut src, 0)?;
+        header.last_offset_delta.decode(&mut src, 0)?;
+        header.first_timestamp.decode(&mut src, 0)?;
+        header.max_time_stamp.decode(&mut src, 0)?;
+        // producer id, epoch and base sequence are not used
+        src.advance(8 + 2 + 4);
+        Ok(batch)
+    };
+    let mut batch = decode().map_err(|_| kafka_error::CORRUPT_MESSAGE)?;
+
+    let attributes = batch.get_header().attributes;
+    if attributes & (ATTR_TRANSACTIONAL | ATTR_CONTROL) != 0 {
+        debug!(attributes, "transactional kafka batches are not supported");
+        return Err(kafka_error::UNSUPPORTED_FOR_MESSAGE_FORMAT);
+    }
+    let compression = Compression::try_from((attributes & ATTR_COMPRESSION_CODEC_MASK) as i8)
+        .map_err(|_| kafka_error::UNSUPPORTED_COMPRESSION_TYPE)?;
+
+    let mut count: i32 = 0;
+    count
+        .decode(&mut src, 0)
+        .map_err(|_| kafka_error::CORRUPT_MESSAGE)?;
+    let uncompressed = match compression {
+        Compression::Snappy => uncompress_kafka_snappy(&src).map(Some),
+        _ => compression
+            .uncompress(&src)
+            .map_err(|err| IoError::new(std::io::ErrorKind::InvalidData, err)),
+    };
+    let records = match uncompressed {
+        Ok(Some(records)) => Bytes::from(records),
+        Ok(None) => src,
+        Err(err) => {
+            debug!(%err, "unable to uncompress kafka batch");
+            return Err(kafka_error::CORRUPT_MESSAGE);
+        }
+    };
+
+    let mut records =
+        decode_kafka_records(records, count).map_err(|_| kafka_error::CORRUPT_MESSAGE)?;
+    batch.add_records(&mut records);
+    batch.get_mut_header().attributes = 0;
+    Ok(batch)
+}
+
+fn decode_kafka_records(mut src: Bytes, count: i32) -> Result<Vec<Record>, IoError> {
+    let mut records = Vec::with_capacity(count.max(0) as usize);
+    for _ in 0..count {
+        let mut len: i64 = 0;
+        len.decode_varint(&mut src)?;
+        let mut _attributes: i8 = 0;
+        _attributes.decode(&mut src, 0)?;
+        let mut timestamp_delta: i64 = 0;
+        timestamp_delta.decode_varint(&mut src)?;
+        // offsets are assigned sequentially when records are added to the batch
+        let mut _offset_delta: i64 = 0;
+        _offset_delta.decode_varint(&mut src)?;
+        let key = decode_varint_bytes(&mut src)?;
+        let value = decode_varint_bytes(&mut src)?.unwrap_or_default();
+
+        let mut record = Record::new(RecordData::from(value.to_vec()));
+        record.key = key.map(|key| RecordData::from(key.to_vec()));
+        record.preamble.set_timestamp_delta(timestamp_delta);
+
+        // header entries have the same encoding in Fluvio records
+        let mut headers: i64 = 0;
+        headers.decode_varint(&mut src)?;
+        for _ in 0..headers {
+            let mut header = RecordHeaderEntry::default();
+            header.decode(&mut src, 0)?;
+            record.headers.push(header);
+        }
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Kafka snappy is either xerial framed, a header followed by length prefixed raw blocks,
+/// or a single raw block
+fn uncompress_kafka_snappy(src: &[u8]) -> Result<Vec<u8>, IoError> {
+    let invalid = |err| IoError::new(std::io::ErrorKind::InvalidData, err);
+    let mut decoder = snap::raw::Decoder::new();
+    if !src.starts_with(&XERIAL_MAGIC) {
+        return decoder.decompress_vec(src).map_err(invalid);
+    }
+
+    let mut out = vec![];
+    let mut blocks = src.get(XERIAL_HEADER_LEN..).unwrap_or_default();
+    while !blocks.is_empty() {
+        let (len, rest) = blocks.split_at(4.min(blocks.len()));
+        let len = u32::from_be_bytes(len.try_into().map_err(|_| {
+            IoError::new(std::io::ErrorKind::UnexpectedEof, "truncated snappy block")
+        })?) as usize;
+        if rest.len() < len {
+            return Err(IoError::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "truncated snappy block",
+            ));
+        }
+        let (block, rest) = rest.split_at(len);
+        out.extend(decoder.decompress_vec(block).map_err(invalid)?);
+        blocks = rest;
+    }
+    Ok(out)
+}
+
+fn decode_varint_bytes(src: &mut Bytes) -> Result<Option<Bytes>, IoError> {
+    let mut len: i64 = 0;
+    len.decode_varint(src)?;
+    if len < 0 {
+        return Ok(None);
+    }
+    let len = len as usize;
+    if src.remaining() < len {
+        return Err(IoError::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "not enough bytes for kafka record",
+        ));
+    }
+    Ok(Some(src.split_to(len)))
+}
+
+/// Encode Fluvio batch read from storage as uncompressed Kafka record batch.
+/// Stored batches of compressed topics are decompressed first.
+pub(crate) fn encode_kafka_batch(
+    file_batch: &FileBatch,
+    dest: &mut Vec<u8>,
+) -> Result<(), IoError> {
+    let compression = file_batch
+        .batch
+        .get_compression()
+        .map_err(|err| IoError::new(std::io::ErrorKind::InvalidData, err))?;
+    let uncompressed = compression
+        .uncompress(&file_batch.records)
+        .map_err(|err| IoError::new(std::io::ErrorKind::InvalidData, err))?;
+    let raw_records = uncompressed.as_deref().unwrap_or(&file_batch.records);
+
+    let mut records: Vec<Record> = vec![];
+    records.decode(&mut std::io::Cursor::new(raw_records), 0)?;
+
+    let header = file_batch.batch.get_header();
+    let mut body: Vec<u8> = vec![];
+    0_i16.encode(&mut body, 0)?; // attributes, no compression
+    header.last_offset_delta.encode(&mut body, 0)?;
+    header.first_timestamp.encode(&mut body, 0)?;
+    header.max_time_stamp.encode(&mut body, 0)?;
+    (-1_i64).encode(&mut body, 0)?; // producer id
+    (-1_i16).encode(&mut body, 0)?; // producer epoch
+    (-1_i32).encode(&mut body, 0)?; // base sequence
+    (records.len() as i32).encode(&mut body, 0)?;
+    for record in &records {
+        encode_kafka_record(record, &mut body)?;
+    }
+
+    let base_offset: Offset = file_batch.batch.get_base_offset();
+    base_offset.encode(dest, 0)?;
+    let batch_len = (body.len() + BATCH_CRC_OFFSET - 12) as i32;
+    batch_len.encode(dest, 0)?;
+    header.partition_leader_epoch.encode(dest, 0)?;
+    MAGIC.encode(dest, 0)?;
+    crc32c::crc32c(&body).encode(dest, 0)?;
+    dest.put_slice(&body);
+    Ok(())
+}
+
+fn encode_kafka_record(record: &Record, dest: &mut Vec<u8>) -> Result<(), IoError> {
+    let mut out: Vec<u8> = vec![];
+    0_i8.encode(&mut out, 0)?; // attributes
+    record
+        .preamble
+        .get_timestamp_delta()
+        .encode_varint(&mut out)?;
+    record.preamble.offset_delta().encode_varint(&mut out)?;
+    match &record.key {
+        Some(key) => {
+            (key.len() as i64).encode_varint(&mut out)?;
+            out.put_slice(key.as_ref());
+        }
+        None => (-1_i64).encode_varint(&mut out)?,
+    }
+    (record.value.len() as i64).encode_varint(&mut out)?;
+    out.put_slice(record.value.as_ref());
+    (record.headers.len() as i64).encode_varint(&mut out)?;
+    for header in &record.headers {
+        header.encode(&mut out, 0)?;
+    }
+
+    (out.len() as i64).encode_varint(dest)?;
+    dest.put_slice(&out);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+
+    use fluvio_protocol::record::Batch;
+
+    use super::*;
+
+    fn to_file_batch(batch: Batch) -> FileBatch {
+        let mut records = vec![];
+        batch.records().encode(&mut records, 0).expect("encode");
+        FileBatch { batch, records }
+    }
+
+    /// compress records of uncompressed kafka batch with `codec`
+    fn compress_kafka_batch(
+        kafka_bytes: &[u8],
+        codec: i16,
+        compress: impl Fn(&[u8]) -> Vec<u8>,
+    ) -> Bytes {
+        let mut body = kafka_bytes[BATCH_CRC_OFFSET..BATCH_OVERHEAD].to_vec();
+        let attributes = i16::from_be_bytes(body[..2].try_into().unwrap()) | codec;
+        body[..2].copy_from_slice(&attributes.to_be_bytes());
+        body.extend(compress(&kafka_bytes[BATCH_OVERHEAD..]));
+
+        let mut out = kafka_bytes[..BATCH_CRC_OFFSET].to_vec();
+        let batch_len = (body.len() + BATCH_CRC_OFFSET - 12) as i32;
+        out[8..12].copy_from_slice(&batch_len.to_be_bytes());
+        out[17..21].copy_from_slice(&crc32c::crc32c(&body).to_be_bytes());
+        out.extend(body);
+        Bytes::from(out)
+    }
+
+    #[test]
+    fn test_kafka_batch_roundtrip() {
+        let mut batch = Batch::default();
+        batch.get_mut_header().first_timestamp = 1_000;
+        batch.get_mut_header().max_time_stamp = 1_010;
+        batch.add_record(Record::new("value1"));
+        batch.add_record(Record::new_key_value("key2", "value2"));
+        batch.mut_records()[1].preamble.set_timestamp_delta(10);
+        batch.mut_records()[1].headers = vec![
+            RecordHeaderEntry::new("trace-id", Some(Bytes::from_static(b"abc"))),
+            RecordHeaderEntry::new("empty", None),
+        ];
+        batch.set_base_offset(5);
+
+        let mut kafka_bytes = vec![];
+        encode_kafka_batch(&to_file_batch(batch), &mut kafka_bytes).expect("encode");
+
+        // header fields are kept at the kafka offsets
+        assert_eq!(kafka_bytes[16] as i8, MAGIC);
+        assert_eq!(i64::from_be_bytes(kafka_bytes[0..8].try_into().unwrap()), 5);
+
+        let batches = decode_kafka_batches(Bytes::from(kafka_bytes)).expect("decode");
+        assert_eq!(batches.len(), 1);
+        let decoded = &batches[0];
+        assert_eq!(decoded.get_header().first_timestamp, 1_000);
+        assert_eq!(decoded.get_header().max_time_stamp, 1_010);
+        assert_eq!(decoded.get_header().last_offset_delta, 1);
+        let records = decoded.records();
+        assert_eq!(records.len(), 2);
+        assert!(records[0].key.is_none());
+        assert_eq!(records[0].value.as_ref(), b"value1");
+        assert_eq!(
+            records[1].key.as_ref().map(|k| k.as_ref()),
+            Some(b"key2".as_ref())
+        );
+        assert_eq!(records[1].value.as_ref(), b"value2");
+        assert_eq!(records[1].preamble.get_timestamp_delta(), 10);
+        assert!(records[0].headers.is_empty());
+        assert_eq!(records[1].headers.len(), 2);
+        assert_eq!(records[1].headers[0].key, "trace-id");
+        assert_eq!(
+            records[1].headers[0].value.as_deref(),
+            Some(b"abc".as_ref())
+        );
+        assert!(records[1].headers[1].value.is_none());
+    }
+
+    #[test]
+    fn test_kafka_batch_snappy() {
+        let mut batch = Batch::default();
+        let mut record = Record::new_key_value("key1", "value1");
+        record.headers = vec![RecordHeaderEntry::new("h", Some(Bytes::from_static(b"v")))];
+        batch.add_record(record);
+        batch.add_record(Record::new("value2"));
+        let mut kafka_bytes = vec![];
+        encode_kafka_batch(&to_file_batch(batch), &mut kafka_bytes).expect("encode");
+
+        let xerial = compress_kafka_batch(&kafka_bytes, 2, |records| {
+            let mut out = XERIAL_MAGIC.to_vec();
+            out.extend(1_i32.to_be_bytes());
+            out.extend(1_i32.to_be_bytes());
+            let (first, second) = records.split_at(records.len() / 2);
+            for block in [first, second] {
+                let compressed = snap::raw::Encoder::new()
+                    .compress_vec(block)
+                    .expect("compress");
+                out.extend((compressed.len() as i32).to_be_bytes());
+                out.extend(compressed);
+            }
+            out
+        });
+        let raw_block = compress_kafka_batch(&kafka_bytes, 2, |records| {
+            snap::raw::Encoder::new()
+                .compress_vec(records)
+                .expect("compress")
+        });
+
+        for kafka_batch in [xerial, raw_block] {
+            let batches = decode_kafka_batches(kafka_batch).expect("decode");
+            assert_eq!(batches.len(), 1);
+            assert_eq!(batches[0].get_header().attributes, 0);
+            let records = batches[0].records();
+            assert_eq!(records.len(), 2);
+            assert_eq!(records[0].value.as_ref(), b"value1");
+            assert_eq!(records[0].headers.len(), 1);
+            assert_eq!(records[0].headers[0].key, "h");
+            assert_eq!(records[1].value.as_ref(), b"value2");
+        }
+    }
+
+    #[test]
+    fn test_kafka_batch_from_compressed_topic() {
+        let mut batch = Batch::default();
+        batch.add_record(Record::new("value1"));
+        batch.add_record(Record::new_key_value("key2", "value2"));
+        batch.set_base_offset(3);
+
+        let mut raw_records = vec![];
+        batch.records().encode(&mut raw_records, 0).expect("encode");
+        let compressed = Compression::Gzip.compress(&raw_records).expect("compress");
+        batch.get_mut_header().set_compression(Compression::Gzip);
+        let file_batch = FileBatch {
+            batch,
+            records: compressed.to_vec(),
+        };
+
+        let mut kafka_bytes = vec![];
+        encode_kafka_batch(&file_batch, &mut kafka_bytes).expect("encode");
+
+        // kafka batch is sent uncompressed
+        let attributes = i16::from_be_bytes(kafka_bytes[21..23].try_into().unwrap());
+        assert_eq!(attributes & ATTR_COMPRESSION_CODEC_MASK, 0);
+        assert_eq!(i64::from_be_bytes(kafka_bytes[0..8].try_into().unwrap()), 3);
+
+        let batches = decode_kafka_batches(Bytes::from(kafka_bytes)).expect("decode");
+        assert_eq!(batches.len(), 1);
+        let records = batches[0].records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].value.as_ref(), b"value1");
+        assert_eq!(
+            records[1].key.as_ref().map(|k| k.as_ref()),
+            Some(b"key2".as_ref())
+        );
+        assert_eq!(records[1].value.as_ref(), b"value2");
+    }
+
+    #[test]
+    fn test_kafka_batch_rejected() {
+        let mut batch = Batch::default();
+        batch.add_record(Record::new("value"));
+        let mut kafka_bytes = vec![];
+        encode_kafka_batch(&to_file_batch(batch), &mut kafka_bytes).expect("encode");
+
+        let mut corrupted = kafka_bytes.clone();
+        let last = corrupted.len() - 1;
+        corrupted[last] ^= 0xff;
+        assert_eq!(
+            decode_kafka_batches(Bytes::from(corrupted)).unwrap_err(),
+            kafka_error::CORRUPT_MESSAGE
+        );
+
+        assert_eq!(
+            decode_kafka_batches(compress_kafka_batch(&kafka_bytes, 5, |r| r.to_vec()))
+                .unwrap_err(),
+            kafka_error::UNSUPPORTED_COMPRESSION_TYPE
+        );
+
+        let mut old_format = kafka_bytes.clone();
+        old_format[16] = 1;
+        assert_eq!(
+            decode_kafka_batches(Bytes::from(old_format)).unwrap_err(),
+            kafka_error::UNSUPPORTED_FOR_MESSAGE_FORMAT
+        );
+
+        assert_eq!(
+            decode_kafka_batches(Bytes::from(kafka_bytes[..20].to_vec())).unwrap_err(),
+            kafka_error::CORRUPT_MESSAGE
+        );
+    }
+}
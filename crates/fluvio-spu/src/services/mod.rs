@@ -1,4 +1,5 @@
 pub(crate) mod public;
+pub(crate) mod kafka;
 
 pub mod auth;
 pub mod internal;
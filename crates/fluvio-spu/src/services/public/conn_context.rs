@@ -1,14 +1,18 @@
+use fluvio_auth::Tenant;
+
 use crate::services::public::StreamPublishers;
 
 #[derive(Debug)]
 pub(crate) struct ConnectionContext {
     stream_publishers: StreamPublishers,
+    tenant: Option<Tenant>,
 }
 
 impl ConnectionContext {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(tenant: Option<Tenant>) -> Self {
         Self {
             stream_publishers: StreamPublishers::new(),
+            tenant,
         }
     }
 
@@ -19,4 +23,16 @@ impl ConnectionContext {
     pub(crate) fn stream_publishers_mut(&mut self) -> &mut StreamPublishers {
         &mut self.stream_publishers
     }
+
+    /// tenant of authenticated principal, none if principal can access all topics
+    pub(crate) fn tenant(&self) -> Option<&Tenant> {
+        self.tenant.as_ref()
+    }
+
+    /// check if principal can access topic
+    pub(crate) fn allow_topic(&self, topic: &str) -> bool {
+        self.tenant
+            .as_ref()
+            .map_or(true, |tenant| tenant.owns(topic))
+    }
 }
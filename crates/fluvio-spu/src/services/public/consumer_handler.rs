@@ -7,6 +7,8 @@ use fluvio_spu_schema::server::consumer_offset::DeleteConsumerOffsetRequest;
 use fluvio_spu_schema::server::consumer_offset::DeleteConsumerOffsetResponse;
 use fluvio_spu_schema::server::consumer_offset::FetchConsumerOffsetsRequest;
 use fluvio_spu_schema::server::consumer_offset::FetchConsumerOffsetsResponse;
+use fluvio_spu_schema::server::consumer_offset::SetConsumerOffsetRequest;
+use fluvio_spu_schema::server::consumer_offset::SetConsumerOffsetResponse;
 use fluvio_spu_schema::server::consumer_offset::UpdateConsumerOffsetRequest;
 use fluvio_spu_schema::server::consumer_offset::UpdateConsumerOffsetResponse;
 use fluvio_spu_schema::server::consumer_offset::ConsumerOffset as ConsumerOffsetResponse;
@@ -19,6 +21,7 @@ use tracing::instrument;
 use fluvio_protocol::api::{RequestMessage, ResponseMessage};
 use fluvio_controlplane_metadata::partition::ReplicaKey;
 use fluvio_protocol::link::ErrorCode;
+use fluvio_protocol::record::Offset;
 use tracing::trace;
 use tracing::warn;
 
@@ -64,6 +67,7 @@ pub(crate) async fn handle_delete_consumer_offset_request(
         replica_id,
     } = req_msg.request;
 
+    let replica_id = ctx.replica_localstore().resolve(replica_id);
     let error_code = match handle_delete(ctx, replica_id, consumer_id).await {
         Ok(_) => ErrorCode::None,
         Err(error_code) => error_code,
@@ -80,6 +84,29 @@ pub(crate) async fn handle_delete_consumer_offset_request(
     )
 }
 
+#[instrument(skip(req_msg, ctx))]
+pub(crate) async fn handle_set_consumer_offset_request(
+    req_msg: RequestMessage<SetConsumerOffsetRequest>,
+    ctx: DefaultSharedGlobalContext,
+) -> Result<ResponseMessage<SetConsumerOffsetResponse>, IoError> {
+    let SetConsumerOffsetRequest {
+        replica_id,
+        consumer_id,
+        offset,
+    } = req_msg.request;
+
+    let replica_id = ctx.replica_localstore().resolve(replica_id);
+    let error_code = match handle_set(ctx, replica_id, consumer_id, offset).await {
+        Ok(_) => ErrorCode::None,
+        Err(error_code) => error_code,
+    };
+
+    debug!(?error_code, "set consumer offset result");
+
+    let response = SetConsumerOffsetResponse { error_code };
+    Ok(RequestMessage::<SetConsumerOffsetRequest>::response_with_header(&req_msg.header, response))
+}
+
 #[instrument(skip(req_msg, ctx))]
 pub(crate) async fn handle_fetch_consumer_offsets_request(
     req_msg: RequestMessage<FetchConsumerOffsetsRequest>,
@@ -183,6 +210,63 @@ async fn handle_delete(
         .map_err(|e| ErrorCode::Other(format!("unable to delete consumer: {e:?}")))
 }
 
+async fn handle_set(
+    ctx: DefaultSharedGlobalContext,
+    target_replica: ReplicaKey,
+    consumer_id: String,
+    offset: i64,
+) -> std::result::Result<(), ErrorCode> {
+    if offset < -1 {
+        return Err(ErrorCode::Other(format!(
+            "invalid consumer offset: {offset}"
+        )));
+    }
+    let leo = replica_leo(&ctx, &target_replica).await?;
+    // offset is the last consumed record
+    if offset >= leo {
+        return Err(ErrorCode::Other(format!(
+            "consumer offset: {offset} is beyond end of {target_replica}, log end offset: {leo}"
+        )));
+    }
+    let consumers_replica_id =
+        ReplicaKey::new(CONSUMER_STORAGE_TOPIC, <PartitionId as Default>::default());
+    let Some(ref replica) = ctx.leaders_state().get(&consumers_replica_id).await else {
+        return Err(ErrorCode::PartitionNotLeader);
+    };
+
+    update_offset_for_leader(
+        ctx,
+        replica,
+        target_replica.topic,
+        target_replica.partition,
+        consumer_id,
+        offset,
+    )
+    .await
+    .map_err(|e| ErrorCode::Other(format!("unable to set consumer offset: {e:?}")))
+}
+
+/// log end offset of the replica, fetched from its leader if it is led by another spu
+async fn replica_leo(
+    ctx: &DefaultSharedGlobalContext,
+    replica_id: &ReplicaKey,
+) -> std::result::Result<Offset, ErrorCode> {
+    if ctx.replica_localstore().spec(replica_id).is_none() {
+        return Err(ErrorCode::TopicNotFound);
+    }
+    if let Some(replica) = ctx.leaders_state().get(replica_id).await {
+        return Ok(replica.leo());
+    }
+
+    let request = crate::services::internal::FetchReplicaOffsetsRequest::new(replica_id.clone());
+    let response = send_private_request_to_leader(ctx, replica_id, request).await?;
+    if response.error_code != ErrorCode::None {
+        warn!(%response.error_code, "fetch replica offsets in peer");
+        return Err(response.error_code);
+    }
+    Ok(response.leo)
+}
+
 async fn handle_fetch_consumers(
     ctx: DefaultSharedGlobalContext,
 ) -> std::result::Result<Vec<ConsumerOffsetResponse>, ErrorCode> {
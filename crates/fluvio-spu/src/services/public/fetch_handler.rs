@@ -12,11 +12,13 @@ use fluvio_spu_schema::fetch::{
 use fluvio_controlplane_metadata::partition::ReplicaKey;
 
 use crate::core::DefaultSharedGlobalContext;
+use crate::services::public::conn_context::ConnectionContext;
+use crate::core::metrics::IncreaseValue;
 use crate::traffic::TrafficType;
 
 /// perform log fetch request using zero copy write
 #[instrument(
-    skip(request, ctx, sink),
+    skip(request, ctx, conn_ctx, sink),
     fields(
         max_bytes = request.request.max_bytes,
     ),
@@ -24,6 +26,7 @@ use crate::traffic::TrafficType;
 pub async fn handle_fetch_request(
     request: RequestMessage<FileFetchRequest>,
     ctx: DefaultSharedGlobalContext,
+    conn_ctx: &ConnectionContext,
     sink: ExclusiveFlvSink,
 ) -> Result<()> {
     let (header, fetch_request) = request.get_header_request();
@@ -31,8 +34,14 @@ pub async fn handle_fetch_request(
     let mut fetch_response = FileFetchResponse::default();
 
     for topic_request in &fetch_request.topics {
-        let topic_response =
-            handle_fetch_topic(&ctx, &fetch_request, topic_request, header.is_connector()).await?;
+        let topic_response = handle_fetch_topic(
+            &ctx,
+            conn_ctx,
+            &fetch_request,
+            topic_request,
+            header.is_connector(),
+        )
+        .await?;
         fetch_response.topics.push(topic_response);
     }
 
@@ -52,11 +61,12 @@ pub async fn handle_fetch_request(
 }
 
 #[instrument(
-    skip(ctx, fetch_request, topic_request),
+    skip(ctx, conn_ctx, fetch_request, topic_request),
     fields(topic = %topic_request.name),
 )]
 async fn handle_fetch_topic(
     ctx: &DefaultSharedGlobalContext,
+    conn_ctx: &ConnectionContext,
     fetch_request: &FileFetchRequest,
     topic_request: &FetchableTopic,
     is_connector: bool,
@@ -69,7 +79,19 @@ async fn handle_fetch_topic(
     };
 
     for partition_request in &topic_request.fetch_partitions {
-        let replica_id = ReplicaKey::new(topic.clone(), partition_request.partition_index);
+        let replica_id = ctx.replica_localstore().resolve(ReplicaKey::new(
+            topic.clone(),
+            partition_request.partition_index,
+        ));
+        if !conn_ctx.allow_topic(&replica_id.topic) {
+            debug!(%replica_id, "topic is not owned by tenant of principal");
+            topic_response.partitions.push(FilePartitionResponse {
+                partition_index: partition_request.partition_index,
+                error_code: ErrorCode::PermissionDenied,
+                ..Default::default()
+            });
+            continue;
+        }
         let partition_response = handle_fetch_partition(
             ctx,
             replica_id,
@@ -127,11 +149,14 @@ async fn handle_fetch_partition(
             partition_response.log_start_offset = slice.start;
 
             if let Some(file_slice) = slice.file_slice {
-                metrics.outbound().increase(
-                    is_connector,
-                    (slice.end.hw - slice.start) as u64,
-                    file_slice.len(),
-                );
+                let fetched =
+                    IncreaseValue::new((slice.end.hw - slice.start) as u64, file_slice.len());
+                metrics.outbound().increase_by_value(is_connector, fetched);
+                metrics
+                    .partitions()
+                    .partition(&replica_id)
+                    .fetched()
+                    .increase_by_value(fetched);
                 partition_response.records = file_slice.into();
             }
         }
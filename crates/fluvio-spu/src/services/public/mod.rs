@@ -3,6 +3,7 @@ mod produce_handler;
 mod fetch_handler;
 mod offset_request;
 mod offset_update;
+mod stream_update;
 mod stream_fetch;
 mod consumer_handler;
 
@@ -12,7 +13,7 @@ mod conn_context;
 
 use std::sync::Arc;
 use async_trait::async_trait;
-use fluvio_auth::Authorization;
+use fluvio_auth::{AuthContext, Authorization};
 use fluvio_protocol::api::Request;
 use fluvio_protocol::api::RequestMessage;
 use fluvio_protocol::link::ErrorCode;
@@ -34,12 +35,15 @@ use crate::services::auth::SpuAuthGlobalContext;
 use crate::services::auth::SpuAuthServiceContext;
 use crate::services::public::consumer_handler::handle_delete_consumer_offset_request;
 use crate::services::public::consumer_handler::handle_fetch_consumer_offsets_request;
+use crate::services::public::consumer_handler::handle_set_consumer_offset_request;
 use crate::services::public::consumer_handler::handle_update_consumer_offset_request;
 use self::api_versions::handle_api_version_request;
 use self::produce_handler::handle_produce_request;
+pub(crate) use self::produce_handler::write_partition;
 use self::fetch_handler::handle_fetch_request;
 use self::offset_request::handle_offset_request;
 use self::offset_update::handle_offset_update;
+use self::stream_update::handle_stream_update;
 use self::stream_fetch::{StreamFetchHandler, publishers::StreamPublishers};
 use self::conn_context::ConnectionContext;
 use std::fmt::Debug;
@@ -111,7 +115,7 @@ where
         {
             let api_stream = stream.api_stream::<SpuServerRequest, SpuServerApiKey>();
             let mut event_stream = api_stream.take_until(shutdown.listen_pinned());
-            let mut conn_ctx = ConnectionContext::new();
+            let mut conn_ctx = ConnectionContext::new(service_context.auth.tenant().cloned());
 
             let context = &context.global_ctx;
 
@@ -135,13 +139,18 @@ where
                             ),
                             SpuServerRequest::ProduceRequest(request) => call_service!(
                                 request,
-                                handle_produce_request(request, context.clone()),
+                                handle_produce_request(request, context.clone(), conn_ctx.tenant()),
                                 shared_sink,
                                 "ProduceRequest"
                             ),
                             SpuServerRequest::FileFetchRequest(request) => {
-                                handle_fetch_request(request, context.clone(), shared_sink.clone())
-                                    .await?
+                                handle_fetch_request(
+                                    request,
+                                    context.clone(),
+                                    &conn_ctx,
+                                    shared_sink.clone(),
+                                )
+                                .await?
                             }
                             SpuServerRequest::FetchOffsetsRequest(request) => call_service!(
                                 request,
@@ -165,6 +174,9 @@ where
                                 shared_sink,
                                 "UpdateOffsetsRequest"
                             ),
+                            SpuServerRequest::UpdateStreamFetchRequest(request) => {
+                                handle_stream_update(request, &conn_ctx, shared_sink.clone()).await
+                            }
                             SpuServerRequest::UpdateConsumerOffsetRequest(request) => {
                                 call_service!(
                                     request,
@@ -193,6 +205,14 @@ where
                                     "FetchConsumersRequest"
                                 )
                             }
+                            SpuServerRequest::SetConsumerOffsetRequest(request) => {
+                                call_service!(
+                                    request,
+                                    handle_set_consumer_offset_request(request, context.clone()),
+                                    shared_sink,
+                                    "SetConsumerRequest"
+                                )
+                            }
                             SpuServerRequest::StartMirrorRequest(request) => {
                                 // send mirror mode, afer that mirror cycle will be started
                                 mirror_request = Some(request);
@@ -225,7 +245,7 @@ where
     }
 }
 
-async fn send_private_request_to_leader<R: Request>(
+pub(crate) async fn send_private_request_to_leader<R: Request>(
     ctx: &DefaultSharedGlobalContext,
     replica_id: &ReplicaKey,
     req: R,
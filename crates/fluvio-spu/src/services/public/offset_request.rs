@@ -10,10 +10,14 @@ use fluvio_spu_schema::server::fetch_offset::FetchOffsetsResponse;
 use fluvio_spu_schema::server::fetch_offset::FetchOffsetPartitionResponse;
 use fluvio_controlplane_metadata::partition::ReplicaKey;
 use fluvio_protocol::link::ErrorCode;
-use fluvio_types::{PartitionId, defaults::CONSUMER_STORAGE_TOPIC};
+use fluvio_protocol::record::Offset;
+use fluvio_spu_schema::Isolation;
+use fluvio_storage::iterators::FileBatchIterator;
+use fluvio_types::{PartitionId, Timestamp, defaults::CONSUMER_STORAGE_TOPIC};
 
 use crate::core::DefaultSharedGlobalContext;
 use crate::kv::consumer::ConsumerOffsetKey;
+use crate::replication::leader::SharedFileLeaderState;
 use crate::services::internal::FetchConsumerOffsetRequest;
 use crate::services::public::send_private_request_to_leader;
 
@@ -41,7 +45,9 @@ pub async fn handle_offset_request(
                 partition_index: *partition,
                 ..Default::default()
             };
-            let rep_id = ReplicaKey::new(topic.clone(), *partition);
+            let rep_id = ctx
+                .replica_localstore()
+                .resolve(ReplicaKey::new(topic.clone(), *partition));
             if let Some(ref replica) = ctx.leaders_state().get(&rep_id).await {
                 trace!("offset fetch request for replica found: {}", rep_id);
                 let (start_offset, hw) = replica.start_offset_info().await;
@@ -49,6 +55,16 @@ pub async fn handle_offset_request(
                 partition_response.start_offset = start_offset;
                 partition_response.last_stable_offset = hw;
 
+                if let Some(timestamp) = partition_req.timestamp {
+                    match offset_for_timestamp(replica, timestamp, Isolation::ReadCommitted).await {
+                        Ok(offset) => partition_response.timestamp_offset = Some(offset),
+                        Err(e) => {
+                            error!(timestamp, "fetch offset for timestamp failed: {e:?}");
+                            partition_response.error_code = e;
+                        }
+                    }
+                }
+
                 if let Some(ref consumer_id) = request.consumer_id {
                     debug!(consumer_id, "fetch consumer offset");
                     match fetch_consumer_offset(&ctx, topic, *partition, consumer_id).await {
@@ -79,6 +95,35 @@ pub async fn handle_offset_request(
     Ok(req_msg.new_response(response))
 }
 
+/// Base offset of the oldest batch with records produced at or after `timestamp`.
+/// Batches are scanned backwards from the end of the log, so recent timestamps are found quickly.
+pub(crate) async fn offset_for_timestamp(
+    leader: &SharedFileLeaderState,
+    timestamp: Timestamp,
+    isolation: Isolation,
+) -> Result<Offset, ErrorCode> {
+    let (start_offset, end_offset) = leader.start_offset_info().await;
+    let mut offset = end_offset;
+    while offset > start_offset {
+        let slice = leader.read_records(offset - 1, u32::MAX, isolation).await?;
+        let Some(file_slice) = slice.file_slice else {
+            break;
+        };
+        let Some(batch) = FileBatchIterator::from_raw_slice(file_slice).next() else {
+            break;
+        };
+        let batch = batch
+            .map_err(|err| ErrorCode::Other(err.to_string()))?
+            .batch;
+        if batch.header.max_time_stamp < timestamp || batch.base_offset >= offset {
+            break;
+        }
+        offset = batch.base_offset;
+    }
+    debug!(timestamp, offset, "resolved timestamp");
+    Ok(offset)
+}
+
 async fn fetch_consumer_offset(
     ctx: &DefaultSharedGlobalContext,
     topic: &str,
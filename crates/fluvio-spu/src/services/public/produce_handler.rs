@@ -1,7 +1,7 @@
 use std::time::Duration;
 
 use tokio::select;
-use tracing::{debug, trace, error};
+use tracing::{debug, trace, error, warn, info_span, Span};
 use tracing::instrument;
 use anyhow::{anyhow, Result};
 
@@ -18,17 +18,22 @@ use fluvio_spu_schema::produce::{
 use fluvio_spu_schema::server::smartmodule::SmartModuleInvocation;
 use fluvio_protocol::{api::RequestMessage, link::ErrorCode};
 use fluvio_protocol::api::ResponseMessage;
+use fluvio_protocol::Encoder;
 use fluvio_protocol::record::RecordSet;
 use fluvio_controlplane_metadata::partition::ReplicaKey;
 
 use fluvio_future::timer::sleep;
+use fluvio_telemetry::{current_trace_context, set_parent};
+use fluvio_auth::Tenant;
 
 use crate::core::DefaultSharedGlobalContext;
 use crate::replication::leader::SharedFileLeaderState;
 use crate::smartengine::batch::process_batch;
 use crate::smartengine::context::SmartModuleContext;
+use crate::smartengine::dead_letter::send_dead_letters;
 use crate::smartengine::EngineError;
 use crate::smartengine::map_engine_error;
+use crate::storage::ReplicaFenced;
 use crate::smartengine::produce_batch::ProduceBatchIterator;
 
 use crate::traffic::TrafficType;
@@ -47,48 +52,60 @@ struct PartitionWriteResult {
 }
 
 #[instrument(
-    skip(request,ctx),
+    skip(request, ctx, tenant),
     fields(
         id = request.header.correlation_id(),
-        client = %request.header.client_id()
+        client = %request.header.client_id(),
+        otel.kind = "server"
     )
 )]
 pub async fn handle_produce_request(
     request: RequestMessage<DefaultProduceRequest>,
     ctx: DefaultSharedGlobalContext,
+    tenant: Option<&Tenant>,
 ) -> Result<ResponseMessage<ProduceResponse>> {
     let (header, produce_request) = request.get_header_request();
+    if let Some(trace_context) = header.trace_context() {
+        set_parent(&Span::current(), trace_context);
+    }
     trace!("Handling ProduceRequest: {:#?}", produce_request);
 
     let smartmodules = produce_request.smartmodules;
+    let wait_for_fsync = produce_request.wait_for_fsync;
+    let isolation = produce_request.isolation;
 
     let mut topic_results = Vec::with_capacity(produce_request.topics.len());
     for topic_request in produce_request.topics.into_iter() {
-        let topic_result =
-            handle_produce_topic(&ctx, topic_request, &smartmodules, &header).await?;
+        let topic_result = handle_produce_topic(
+            &ctx,
+            tenant,
+            topic_request,
+            &smartmodules,
+            &header,
+            wait_for_fsync,
+            isolation,
+        )
+        .await?;
         topic_results.push(topic_result);
     }
-    wait_for_acks(
-        produce_request.isolation,
-        produce_request.timeout,
-        &mut topic_results,
-        &ctx,
-    )
-    .await;
+    wait_for_acks(isolation, produce_request.timeout, &mut topic_results, &ctx).await;
     let response = into_response(topic_results);
     trace!("Returning ProduceResponse: {:#?}", &response);
     Ok(RequestMessage::<DefaultProduceRequest>::response_with_header(&header, response))
 }
 
 #[instrument(
-    skip(ctx, topic_request, smartmodules, header),
+    skip(ctx, tenant, topic_request, smartmodules, header),
     fields(topic = %topic_request.name),
 )]
 async fn handle_produce_topic(
     ctx: &DefaultSharedGlobalContext,
+    tenant: Option<&Tenant>,
     topic_request: DefaultTopicRequest,
     smartmodules: &[SmartModuleInvocation],
     header: &RequestHeader,
+    wait_for_fsync: bool,
+    isolation: Isolation,
 ) -> Result<TopicWriteResult> {
     let topic = &topic_request.name;
 
@@ -100,7 +117,19 @@ async fn handle_produce_topic(
     };
 
     for mut partition_request in topic_request.partitions.into_iter() {
-        let replica_id = ReplicaKey::new(topic.clone(), partition_request.partition_index);
+        let replica_id = ctx.replica_localstore().resolve(ReplicaKey::new(
+            topic.clone(),
+            partition_request.partition_index,
+        ));
+        if tenant.is_some_and(|tenant| !tenant.owns(&replica_id.topic)) {
+            debug!(%replica_id, "topic is not owned by tenant of principal");
+            topic_result.partitions.push(PartitionWriteResult::error(
+                replica_id,
+                ErrorCode::PermissionDenied,
+            ));
+            continue;
+        }
+
         let leader_state = match ctx.leaders_state().get(&replica_id).await {
             Some(leader_state) => leader_state,
             None => {
@@ -150,10 +179,13 @@ async fn handle_produce_topic(
         } else {
             handle_produce_partition(
                 ctx,
+                tenant,
                 replica_id,
                 leader_state,
                 partition_request,
                 header.is_connector(),
+                wait_for_fsync,
+                isolation,
             )
             .await
         };
@@ -164,66 +196,128 @@ async fn handle_produce_topic(
 }
 
 #[instrument(
-    skip(ctx, replica_id, partition_request, leader_state),
+    skip(ctx, tenant, replica_id, partition_request, leader_state),
     fields(%replica_id),
 )]
+#[allow(clippy::too_many_arguments)]
 async fn handle_produce_partition(
     ctx: &DefaultSharedGlobalContext,
+    tenant: Option<&Tenant>,
     replica_id: ReplicaKey,
     leader_state: SharedFileLeaderState,
     partition_request: PartitionProduceData<RecordSet<RawRecords>>,
     is_connector: bool,
+    wait_for_fsync: bool,
+    isolation: Isolation,
 ) -> PartitionWriteResult {
     trace!("Handling produce request for partition:");
 
-    let replica_metadata = match ctx.replica_localstore().spec(&replica_id) {
+    let mut records = partition_request.records;
+    match write_partition(
+        ctx,
+        tenant,
+        &replica_id,
+        &leader_state,
+        &mut records,
+        is_connector,
+        wait_for_fsync,
+        isolation,
+    )
+    .await
+    {
+        Ok((base_offset, leo)) => PartitionWriteResult::ok(replica_id, base_offset, leo),
+        Err(error_code) => PartitionWriteResult::error(replica_id, error_code),
+    }
+}
+
+/// Validate records and write them to the leader replica, returns base offset and leo.
+/// Kafka produce uses it too, so records of both protocols go through the same checks.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn write_partition(
+    ctx: &DefaultSharedGlobalContext,
+    tenant: Option<&Tenant>,
+    replica_id: &ReplicaKey,
+    leader_state: &SharedFileLeaderState,
+    records: &mut RecordSet<RawRecords>,
+    is_connector: bool,
+    wait_for_fsync: bool,
+    isolation: Isolation,
+) -> Result<(Offset, Offset), ErrorCode> {
+    if isolation == Isolation::ReadCommitted {
+        leader_state.check_in_sync_replicas().await?;
+    }
+
+    let replica_metadata = match ctx.replica_localstore().spec(replica_id) {
         Some(replica_metadata) => replica_metadata,
         None => {
             error!(%replica_id, "Replica not found");
-            return PartitionWriteResult::error(replica_id, ErrorCode::TopicNotFound);
+            return Err(ErrorCode::TopicNotFound);
         }
     };
 
-    let mut records = partition_request.records;
-
-    if validate_records(&records, replica_metadata.compression_type).is_err() {
+    if validate_records(records, replica_metadata.compression_type).is_err() {
         error!(%replica_id, "Compression in batch not supported by this topic");
-        return PartitionWriteResult::error(replica_id, ErrorCode::CompressionError);
+        return Err(ErrorCode::CompressionError);
+    }
+
+    if let Some(tenant) = tenant {
+        let bytes = records.write_size(0) as u64;
+        ctx.admit_tenant(tenant, bytes)?;
     }
 
     let write_result = leader_state
-        .write_record_set(&mut records, ctx.follower_notifier())
+        .write_record_set(records, ctx.follower_notifier())
         .await;
 
     let metrics = ctx.metrics();
     match write_result {
         Ok((base_offset, leo, bytes)) => {
+            if let Some(trace_context) = current_trace_context() {
+                ctx.produced_traces()
+                    .record(replica_id, base_offset, leo, trace_context);
+            }
             metrics
                 .inbound()
                 .increase(is_connector, (leo - base_offset) as u64, bytes as u64);
+            metrics
+                .partitions()
+                .partition(replica_id)
+                .produced()
+                .increase((leo - base_offset) as u64, bytes as u64);
+
+            if wait_for_fsync {
+                if let Err(err) = leader_state.sync().await {
+                    error!(%replica_id, "Error syncing replica: {:#?}", err);
+                    return Err(ErrorCode::StorageError);
+                }
+            }
 
-            PartitionWriteResult::ok(replica_id, base_offset, leo)
+            Ok((base_offset, leo))
         }
         Err(err) => {
             if let Some(engine_err) = err.downcast_ref::<EngineError>() {
                 error!(%replica_id, "Replica SmartEngine error: {:#?}", engine_err);
-                return PartitionWriteResult::error(replica_id, map_engine_error(engine_err));
+                return Err(map_engine_error(engine_err));
             };
+            if err.downcast_ref::<ReplicaFenced>().is_some() {
+                warn!(%replica_id, "replica is fenced, rejecting write");
+                return Err(ErrorCode::NotLeaderForPartition);
+            }
             match err.downcast_ref::<StorageError>() {
                 Some(StorageError::BatchTooBig(_)) => {
                     error!(%replica_id, "Batch is too big: {:#?}", err);
-                    PartitionWriteResult::error(replica_id, ErrorCode::MessageTooLarge)
+                    Err(ErrorCode::MessageTooLarge)
                 }
                 Some(StorageError::BatchExceededSegment {
                     batch_size,
                     max_segment_size,
                 }) => {
                     error!(%replica_id, batch_size, max_segment_size, "Batch size exceeded max segment size");
-                    PartitionWriteResult::error(replica_id, ErrorCode::MessageTooLarge)
+                    Err(ErrorCode::MessageTooLarge)
                 }
                 _ => {
                     error!(%replica_id, "Error writing to replica: {:#?}", err);
-                    PartitionWriteResult::error(replica_id, ErrorCode::StorageError)
+                    Err(ErrorCode::StorageError)
                 }
             }
         }
@@ -250,12 +344,16 @@ async fn apply_smartmodules(
 
     let mut batches = ProduceBatchIterator::new(batches);
 
-    let sm_result = match process_batch(
-        sm_ctx.chain_mut(),
-        &mut batches,
-        usize::MAX,
-        ctx.metrics().chain_metrics(),
-    ) {
+    let sm_span =
+        info_span!("smartmodule", replica = %leader_state.id(), smartmodules = smartmodules.len());
+    let sm_result = match sm_span.in_scope(|| {
+        process_batch(
+            sm_ctx.chain_mut(),
+            &mut batches,
+            usize::MAX,
+            ctx.metrics().chain_metrics(),
+        )
+    }) {
         Ok((result, sm_runtime_error)) => {
             if let Some(error) = sm_runtime_error {
                 return Err(ErrorCode::SmartModuleRuntimeError(error));
@@ -270,6 +368,13 @@ async fn apply_smartmodules(
         }
     };
 
+    send_dead_letters(
+        ctx,
+        leader_state.id(),
+        sm_ctx.chain_mut().take_dead_letters(),
+    )
+    .await;
+
     let smartmoduled_records = Batch::<RawRecords>::try_from(sm_result)
         .map_err(|e| ErrorCode::Other(format!("Compression Error: {:?}", e)))?;
 
@@ -309,6 +414,7 @@ fn validate_records<R: BatchRecords>(
 /// error code. The timeout is not shared between partitions.
 ///
 /// For isolation = ReadUncommitted - it's no op.
+#[instrument(skip(results, ctx))]
 async fn wait_for_acks(
     isolation: Isolation,
     timeout: Duration,
@@ -1,7 +1,8 @@
 use std::sync::Arc;
 use std::time::Instant;
 
-use tracing::{debug, error, instrument, trace, warn};
+use async_channel::Receiver;
+use tracing::{debug, error, info_span, instrument, trace, warn, Span};
 use tokio::select;
 
 use fluvio_compression::CompressionError;
@@ -11,6 +12,7 @@ use fluvio_types::event::{
     StickyEvent,
 };
 use fluvio_future::task::spawn;
+use fluvio_telemetry::set_parent;
 use fluvio_protocol::{
     api::{RequestMessage, RequestHeader},
     record::{RecordSet, Offset, RawRecords},
@@ -23,6 +25,7 @@ use fluvio_spu_schema::{
     server::stream_fetch::{
         DefaultStreamFetchRequest, FileStreamFetchRequest, StreamFetchRequest, StreamFetchResponse,
     },
+    server::update_stream_fetch::{StreamFetchAction, UpdateStreamFetchResponse},
     fetch::{FilePartitionResponse, FetchablePartitionResponse},
     Isolation,
     file::FileRecordSet,
@@ -32,11 +35,14 @@ use fluvio_types::event::offsets::OffsetChangeListener;
 use crate::core::{metrics::IncreaseValue, DefaultSharedGlobalContext};
 use crate::replication::leader::SharedFileLeaderState;
 use crate::services::public::conn_context::ConnectionContext;
+use crate::services::public::offset_request::offset_for_timestamp;
 use crate::smartengine::context::SmartModuleContext;
 use crate::smartengine::batch::process_batch;
 use crate::core::metrics::SpuMetrics;
 use crate::traffic::TrafficType;
 
+use self::publishers::StreamFetchUpdate;
+
 /// Fetch records as stream
 pub struct StreamFetchHandler {
     replica: ReplicaKey,
@@ -50,6 +56,9 @@ pub struct StreamFetchHandler {
     leader_state: SharedFileLeaderState,
     stream_id: u32,
     metrics: Arc<SpuMetrics>,
+    ctx: DefaultSharedGlobalContext,
+    update_receiver: Receiver<StreamFetchUpdate>,
+    epoch: u32,
 }
 
 impl StreamFetchHandler {
@@ -62,58 +71,79 @@ impl StreamFetchHandler {
         end_event: Arc<StickyEvent>,
     ) -> Result<(), SocketError> {
         let (header, msg) = request.get_header_request();
-        let replica = ReplicaKey::new(msg.topic.clone(), msg.partition);
-
-        if let Some(leader_state) = ctx.leaders_state().get(&replica).await {
-            let (stream_id, offset_publisher) = conn_ctx
-                .stream_publishers_mut()
-                .create_new_publisher(msg.topic.clone(), msg.partition, msg.consumer_id.clone())
-                .await;
-            let consumer_offset_listener = offset_publisher.offset_publisher.change_listener();
-
-            leader_state
-                .register_offset_publisher(&offset_publisher.offset_publisher)
-                .await;
-
-            spawn(async move {
-                if let Err(err) = StreamFetchHandler::fetch(
-                    ctx,
-                    sink,
-                    end_event.clone(),
-                    leader_state,
-                    stream_id,
-                    header,
-                    replica,
-                    consumer_offset_listener,
-                    msg,
-                )
+        let replica = ctx
+            .replica_localstore()
+            .resolve(ReplicaKey::new(msg.topic.clone(), msg.partition));
+
+        let leader_state = if conn_ctx.allow_topic(&replica.topic) {
+            ctx.leaders_state()
+                .get(&replica)
                 .await
-                {
-                    error!("error starting stream fetch handler: {:#?}", err);
-                    end_event.notify();
-                }
-            });
+                .ok_or(ErrorCode::NotLeaderForPartition)
         } else {
-            debug!(topic = %replica.topic," no leader found, returning");
-            let response = StreamFetchResponse {
-                topic: replica.topic,
-                stream_id: 0,
-                partition: FilePartitionResponse {
-                    partition_index: replica.partition,
-                    error_code: ErrorCode::NotLeaderForPartition,
-                    ..Default::default()
-                },
-            };
+            Err(ErrorCode::PermissionDenied)
+        };
 
-            let response_msg =
-                RequestMessage::<FileStreamFetchRequest>::response_with_header(&header, response);
+        match leader_state {
+            Ok(leader_state) => {
+                let (stream_id, offset_publisher, update_receiver) = conn_ctx
+                    .stream_publishers_mut()
+                    .create_new_publisher(
+                        replica.topic.clone(),
+                        msg.partition,
+                        msg.consumer_id.clone(),
+                    )
+                    .await;
+                let consumer_offset_listener = offset_publisher.offset_publisher.change_listener();
+
+                leader_state
+                    .register_offset_publisher(&offset_publisher.offset_publisher)
+                    .await;
+
+                spawn(async move {
+                    if let Err(err) = StreamFetchHandler::fetch(
+                        ctx,
+                        sink,
+                        end_event.clone(),
+                        leader_state,
+                        stream_id,
+                        header,
+                        replica,
+                        consumer_offset_listener,
+                        update_receiver,
+                        msg,
+                    )
+                    .await
+                    {
+                        error!("error starting stream fetch handler: {:#?}", err);
+                        end_event.notify();
+                    }
+                });
+            }
+            Err(error_code) => {
+                debug!(topic = %replica.topic, %error_code, "can't stream topic, returning");
+                let response = StreamFetchResponse {
+                    topic: replica.topic,
+                    stream_id: 0,
+                    partition: FilePartitionResponse {
+                        partition_index: replica.partition,
+                        error_code,
+                        ..Default::default()
+                    },
+                    epoch: 0,
+                };
 
-            trace!("sending back file fetch response msg: {:#?}", response_msg);
+                let response_msg = RequestMessage::<FileStreamFetchRequest>::response_with_header(
+                    &header, response,
+                );
+
+                trace!("sending back file fetch response msg: {:#?}", response_msg);
 
-            let mut inner_sink = sink.lock().await;
-            inner_sink
-                .send_response(&response_msg, header.api_version())
-                .await?;
+                let mut inner_sink = sink.lock().await;
+                inner_sink
+                    .send_response(&response_msg, header.api_version())
+                    .await?;
+            }
         }
 
         Ok(())
@@ -121,10 +151,11 @@ impl StreamFetchHandler {
 
     #[allow(clippy::too_many_arguments)]
     #[instrument(
-        skip(ctx,replica,end_event,leader_state,header,msg,consumer_offset_listener),
+        skip(ctx,replica,end_event,leader_state,header,msg,consumer_offset_listener,update_receiver),
         fields(
             replica = %replica,
-            sink = sink.id()
+            sink = sink.id(),
+            otel.kind = "server"
         ))
     ]
     pub async fn fetch(
@@ -136,11 +167,34 @@ impl StreamFetchHandler {
         header: RequestHeader,
         replica: ReplicaKey,
         consumer_offset_listener: OffsetChangeListener,
+        update_receiver: Receiver<StreamFetchUpdate>,
         msg: StreamFetchRequest<FileRecordSet>,
     ) -> Result<(), SocketError> {
         debug!("request: {:#?}", msg);
+        if let Some(trace_context) = header.trace_context() {
+            set_parent(&Span::current(), trace_context);
+        }
         let version = header.api_version();
 
+        // dead letters are written once when records are produced,
+        // re-reading the partition would duplicate them
+        if msg
+            .smartmodules
+            .iter()
+            .any(|sm| sm.on_error.dead_letter_topic().is_some())
+        {
+            warn!("dead-letter error policy is not supported on stream fetch");
+            send_back_error(
+                &sink,
+                &replica,
+                &header,
+                stream_id,
+                ErrorCode::SmartModuleDeadLetterNotSupported,
+            )
+            .await?;
+            return Ok(());
+        }
+
         let sm_ctx = match SmartModuleContext::try_from(msg.smartmodules, version, &ctx).await {
             Ok(Some(mut ctx)) => {
                 if let Err(error_code) = ctx.look_back(&leader_state).await {
@@ -190,6 +244,9 @@ impl StreamFetchHandler {
             leader_state,
             max_fetch_bytes,
             metrics: ctx.metrics(),
+            ctx: ctx.clone(),
+            update_receiver,
+            epoch: 0,
         };
 
         if let Err(err) = handler.process(starting_offset, sm_ctx).await {
@@ -231,6 +288,8 @@ impl StreamFetchHandler {
         // since we don't need to wait for consumer, can move consumer to same offset as last read
         let mut last_known_consumer_offset: Option<Offset> =
             (!consumer_wait).then_some(last_partition_offset);
+        // while paused, offsets are tracked but no records are sent
+        let mut paused = false;
 
         loop {
             counter += 1;
@@ -258,6 +317,12 @@ impl StreamFetchHandler {
                         return Err(StreamFetchError::Fetch(ErrorCode::TopicDeleted))
                     }
 
+                    if paused {
+                        debug!(consumer_offset_update, "Consumer offset updated while paused");
+                        last_known_consumer_offset = Some(consumer_offset_update);
+                        continue;
+                    }
+
                     // If the consumer offset is not behind, there is no need to send records
                     if consumer_offset_update >= last_partition_offset {
                         debug!(
@@ -294,6 +359,11 @@ impl StreamFetchHandler {
                 partition_offset_update = leader_offset_receiver.listen() => {
                     debug!(partition_offset_update, "Received leader update:");
 
+                    if paused {
+                        last_partition_offset = partition_offset_update;
+                        continue;
+                    }
+
                     let last_consumer_offset = match last_known_consumer_offset {
                         Some(last_consumer_offset) => last_consumer_offset,
                         None => {
@@ -332,6 +402,70 @@ impl StreamFetchHandler {
                     }
                 },
 
+                // Received pause, resume or seek from consumer
+                update = self.update_receiver.recv() => {
+                    let Ok(StreamFetchUpdate { action, reply }) = update else {
+                        debug!("stream update channel closed, terminating");
+                        break;
+                    };
+                    debug!(?action, "Received stream update");
+
+                    let position = match action {
+                        StreamFetchAction::Pause => {
+                            paused = true;
+                            None
+                        }
+                        StreamFetchAction::Resume => {
+                            paused = false;
+                            // if consumer has not acknowledged last records, it will be resumed by the acknowledgment
+                            last_known_consumer_offset
+                        }
+                        StreamFetchAction::Seek(offset) => {
+                            // records sent before the seek are discarded by consumer
+                            self.epoch += 1;
+                            Some(offset)
+                        }
+                        StreamFetchAction::SeekTimestamp(timestamp) => {
+                            let offset =
+                                offset_for_timestamp(&self.leader_state, timestamp, self.isolation)
+                                    .await;
+                            match offset {
+                                Ok(offset) => {
+                                    self.epoch += 1;
+                                    Some(offset)
+                                }
+                                Err(error_code) => {
+                                    let _ = reply
+                                        .send(UpdateStreamFetchResponse {
+                                            error_code,
+                                            epoch: self.epoch,
+                                            ..Default::default()
+                                        })
+                                        .await;
+                                    continue;
+                                }
+                            }
+                        }
+                    };
+
+                    // reply before records of the new epoch are sent, so consumer learns the epoch first
+                    let _ = reply
+                        .send(UpdateStreamFetchResponse {
+                            error_code: ErrorCode::None,
+                            offset: position.unwrap_or(last_partition_offset),
+                            epoch: self.epoch,
+                        })
+                        .await;
+
+                    if let Some(offset) = position {
+                        last_known_consumer_offset = Some(offset);
+                        if !paused && offset < last_partition_offset {
+                            let (next_offset, wait) = self.send_back_records(offset, sm_ctx.as_mut()).await?;
+                            last_partition_offset = next_offset;
+                            last_known_consumer_offset = (!wait).then_some(next_offset);
+                        }
+                    }
+                },
             }
         }
 
@@ -439,6 +573,7 @@ impl StreamFetchHandler {
                     topic: self.replica.topic.clone(),
                     stream_id: self.stream_id,
                     partition: file_partition_response,
+                    epoch: self.epoch,
                 };
 
                 let response_msg = RequestMessage::<FileStreamFetchRequest>::response_with_header(
@@ -467,9 +602,35 @@ impl StreamFetchHandler {
         self.metrics
             .outbound()
             .increase_by_value(self.header.is_connector(), metrics_update);
+        self.metrics
+            .partitions()
+            .partition(&self.replica)
+            .fetched()
+            .increase_by_value(metrics_update);
+        if wait {
+            self.record_deliveries(starting_offset, offset);
+        }
         Ok((offset, wait))
     }
 
+    /// record delivery of traced records in trace of their producer
+    fn record_deliveries(&self, start_offset: Offset, end_offset: Offset) {
+        for (base_offset, trace_context) in
+            self.ctx
+                .produced_traces()
+                .lookup(&self.replica, start_offset, end_offset)
+        {
+            let span = info_span!(
+                "deliver",
+                replica = %self.replica,
+                base_offset,
+                stream_id = self.stream_id,
+                otel.kind = "consumer"
+            );
+            set_parent(&span, &trace_context);
+        }
+    }
+
     #[instrument(skip(self, file_partition_response, batch, smartmodule_error))]
     async fn send_processed_response(
         &self,
@@ -525,6 +686,7 @@ impl StreamFetchHandler {
             topic: self.replica.topic.clone(),
             stream_id: self.stream_id,
             partition: partition_response,
+            epoch: self.epoch,
         };
 
         let response_msg = RequestMessage::<DefaultStreamFetchRequest>::response_with_header(
@@ -557,10 +719,12 @@ async fn send_back_error(
         ..Default::default()
     };
 
+    // clients never discard errors, no need to track epoch
     let stream_response = StreamFetchResponse {
         topic: replica.topic.clone(),
         stream_id,
         partition: partition_response,
+        epoch: 0,
     };
 
     let response_msg =
@@ -605,6 +769,11 @@ pub mod publishers {
     use std::fmt::Debug;
     use std::ops::AddAssign;
 
+    use async_channel::{Receiver, Sender};
+    use tracing::error;
+
+    use fluvio_protocol::link::ErrorCode;
+    use fluvio_spu_schema::server::update_stream_fetch::{StreamFetchAction, UpdateStreamFetchResponse};
     use fluvio_types::PartitionId;
     use fluvio_types::event::offsets::INIT_OFFSET;
 
@@ -621,6 +790,46 @@ pub mod publishers {
         pub topic: String,
         pub partition: PartitionId,
         pub consumer: Option<Consumer>,
+        update_sender: Sender<StreamFetchUpdate>,
+    }
+
+    /// Pause, resume or seek sent to the stream fetch handler, it replies once the update is applied
+    pub struct StreamFetchUpdate {
+        pub action: StreamFetchAction,
+        pub reply: Sender<UpdateStreamFetchResponse>,
+    }
+
+    impl StreamPublisher {
+        /// apply update to the stream and wait for the handler to reply
+        pub async fn update(&self, action: StreamFetchAction) -> UpdateStreamFetchResponse {
+            if matches!(
+                action,
+                StreamFetchAction::Seek(_) | StreamFetchAction::SeekTimestamp(_)
+            ) {
+                // acknowledgments after the seek may repeat offsets acknowledged before it,
+                // reset the value so they are not ignored by the change listener
+                self.offset_publisher.update(INIT_OFFSET);
+            }
+            let (reply, response) = async_channel::bounded(1);
+            if self
+                .update_sender
+                .send(StreamFetchUpdate { action, reply })
+                .await
+                .is_err()
+            {
+                return UpdateStreamFetchResponse {
+                    error_code: ErrorCode::FetchSessionNotFoud,
+                    ..Default::default()
+                };
+            }
+            response.recv().await.unwrap_or_else(|err| {
+                error!(%err, "stream fetch ended before applying update");
+                UpdateStreamFetchResponse {
+                    error_code: ErrorCode::FetchSessionNotFoud,
+                    ..Default::default()
+                }
+            })
+        }
     }
 
     #[derive(Clone)]
@@ -653,18 +862,20 @@ pub mod publishers {
             topic: String,
             partition: PartitionId,
             consumer_id: Option<String>,
-        ) -> (u32, StreamPublisher) {
+        ) -> (u32, StreamPublisher, Receiver<StreamFetchUpdate>) {
             let stream_id = self.next_stream_id();
             let offset_publisher = OffsetPublisher::shared(INIT_OFFSET);
             let consumer = consumer_id.map(|id| Consumer { consumer_id: id });
+            let (update_sender, update_receiver) = async_channel::bounded(1);
             let publisher = StreamPublisher {
                 offset_publisher,
                 topic,
                 partition,
                 consumer,
+                update_sender,
             };
             self.publishers.insert(stream_id, publisher.clone());
-            (stream_id, publisher)
+            (stream_id, publisher, update_receiver)
         }
 
         /// get publisher with stream id
@@ -0,0 +1,52 @@
+use tracing::{debug, error, instrument, warn};
+use fluvio_future::task::spawn;
+use fluvio_socket::ExclusiveFlvSink;
+use fluvio_spu_schema::server::update_stream_fetch::{
+    UpdateStreamFetchRequest, UpdateStreamFetchResponse,
+};
+use fluvio_protocol::link::ErrorCode;
+use fluvio_protocol::api::RequestMessage;
+use crate::services::public::conn_context::ConnectionContext;
+
+/// Apply update to the stream fetch session.
+/// Stream may be busy sending records, so the update is awaited in background
+/// and requests of the connection are not blocked.
+#[instrument(skip(conn_ctx, request, sink))]
+pub(crate) async fn handle_stream_update(
+    request: RequestMessage<UpdateStreamFetchRequest>,
+    conn_ctx: &ConnectionContext,
+    sink: ExclusiveFlvSink,
+) {
+    let (header, update) = request.get_header_request();
+    debug!(session_id = update.session_id, action = ?update.action, "received stream update");
+
+    let publisher = conn_ctx
+        .stream_publishers()
+        .get_publisher(update.session_id)
+        .await;
+
+    spawn(async move {
+        let response = match publisher {
+            Some(publisher) => publisher.update(update.action).await,
+            None => {
+                error!(session_id = update.session_id, "invalid stream update");
+                UpdateStreamFetchResponse {
+                    error_code: ErrorCode::FetchSessionNotFoud,
+                    ..Default::default()
+                }
+            }
+        };
+        let response_msg =
+            RequestMessage::<UpdateStreamFetchRequest>::response_with_header(&header, response);
+        let mut inner_sink = sink.lock().await;
+        if let Err(err) = inner_sink
+            .send_response(&response_msg, header.api_version())
+            .await
+        {
+            warn!(
+                "sending stream update response failed: {}. Client could gave up waiting for the response",
+                err
+            );
+        }
+    });
+}
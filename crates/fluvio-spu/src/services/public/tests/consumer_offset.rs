@@ -0,0 +1,89 @@
+use std::{env::temp_dir, time::Duration};
+
+use fluvio_controlplane::replica::Replica;
+use fluvio_future::timer::sleep;
+use fluvio_protocol::{api::RequestMessage, fixture::create_raw_recordset, link::ErrorCode};
+use fluvio_socket::{FluvioSocket, MultiplexerSocket};
+use fluvio_spu_schema::server::consumer_offset::SetConsumerOffsetRequest;
+use fluvio_types::defaults::CONSUMER_STORAGE_TOPIC;
+use flv_util::fixture::ensure_clean_dir;
+
+use crate::config::SpuConfig;
+use crate::core::GlobalContext;
+use crate::replication::leader::LeaderReplicaState;
+use crate::services::public::tests::create_public_server_with_root_auth;
+
+#[fluvio_future::test]
+async fn test_set_consumer_offset_within_log() {
+    let test_path = temp_dir().join("test_set_consumer_offset_within_log");
+    ensure_clean_dir(&test_path);
+    let mut spu_config = SpuConfig::default();
+    spu_config.log.base_dir.clone_from(&test_path);
+    let ctx = GlobalContext::new_shared_context(spu_config);
+
+    let port = portpicker::pick_unused_port().expect("No free ports left");
+    let addr = format!("127.0.0.1:{port}");
+    let server_end_event = create_public_server_with_root_auth(addr.to_owned(), ctx.clone()).run();
+
+    // wait for stream controller async to start
+    sleep(Duration::from_millis(100)).await;
+
+    let client_socket =
+        MultiplexerSocket::new(FluvioSocket::connect(&addr).await.expect("connect"));
+
+    let topic = "test_set_consumer_offset";
+    let test = Replica::new((topic, 0), 5001, vec![5001]);
+    let consumers = Replica::new((CONSUMER_STORAGE_TOPIC, 0), 5001, vec![5001]);
+    ctx.replica_localstore()
+        .sync_all(vec![test.clone(), consumers.clone()]);
+    for replica in [test.clone(), consumers] {
+        let id = replica.id.clone();
+        let leader = LeaderReplicaState::create(replica, ctx.config(), ctx.status_update_owned())
+            .await
+            .expect("replica")
+            .init(&ctx)
+            .await
+            .expect("init succeeded");
+        ctx.leaders_state().insert(id, leader).await;
+    }
+    let leader = ctx.leaders_state().get(&test.id).await.expect("leader");
+    leader
+        .write_record_set(&mut create_raw_recordset(2), ctx.follower_notifier())
+        .await
+        .expect("write");
+    assert_eq!(leader.leo(), 2);
+
+    // last record
+    let response = client_socket
+        .send_and_receive(RequestMessage::new_request(SetConsumerOffsetRequest::new(
+            test.id.clone(),
+            "consumer",
+            1,
+        )))
+        .await
+        .expect("set offset");
+    assert_eq!(response.error_code, ErrorCode::None);
+
+    // there is no record after log end offset
+    let response = client_socket
+        .send_and_receive(RequestMessage::new_request(SetConsumerOffsetRequest::new(
+            test.id.clone(),
+            "consumer",
+            2,
+        )))
+        .await
+        .expect("set offset");
+    assert!(matches!(response.error_code, ErrorCode::Other(_)));
+
+    let response = client_socket
+        .send_and_receive(RequestMessage::new_request(SetConsumerOffsetRequest::new(
+            (format!("{topic}-unknown"), 0),
+            "consumer",
+            0,
+        )))
+        .await
+        .expect("set offset");
+    assert_eq!(response.error_code, ErrorCode::TopicNotFound);
+
+    server_end_event.notify();
+}
@@ -345,6 +345,146 @@ async fn test_produce_not_waiting_replication() {
     debug!("terminated controller");
 }
 
+#[fluvio_future::test(ignore)]
+async fn test_produce_wait_for_fsync() {
+    let test_path = temp_dir().join("produce_wait_for_fsync");
+    ensure_clean_dir(&test_path);
+    let port = portpicker::pick_unused_port().expect("No free ports left");
+
+    let addr = format!("127.0.0.1:{port}");
+    let mut spu_config = SpuConfig::default();
+    spu_config.log.base_dir = test_path;
+    let ctx = GlobalContext::new_shared_context(spu_config);
+
+    let server_end_event = create_public_server_with_root_auth(addr.to_owned(), ctx.clone()).run();
+
+    // wait for stream controller async to start
+    sleep(Duration::from_millis(100)).await;
+
+    let client_socket =
+        MultiplexerSocket::new(FluvioSocket::connect(&addr).await.expect("connect"));
+    let topic = "test_produce_fsync";
+    let test = Replica::new((topic, 0), 5001, vec![5001]);
+    let test_id = test.id.clone();
+    ctx.replica_localstore().sync_all(vec![test.clone()]);
+
+    let replica = LeaderReplicaState::create(test, ctx.config(), ctx.status_update_owned())
+        .await
+        .expect("replica")
+        .init(&ctx)
+        .await
+        .expect("init succeeded");
+    ctx.leaders_state().insert(test_id, replica.clone()).await;
+
+    let records_per_request = 5;
+    let mut produce_request = DefaultProduceRequest {
+        wait_for_fsync: true,
+        ..Default::default()
+    };
+    let partition_produce = DefaultPartitionRequest {
+        partition_index: 0,
+        records: create_filter_records(records_per_request)
+            .try_into()
+            .expect("filter records"),
+    };
+    produce_request.topics.push(TopicProduceData {
+        name: topic.to_owned(),
+        partitions: vec![partition_produce],
+        ..Default::default()
+    });
+
+    let produce_response = client_socket
+        .send_and_receive(RequestMessage::new_request(produce_request))
+        .await
+        .expect("send offset");
+
+    assert_eq!(produce_response.responses.len(), 1);
+    assert_eq!(produce_response.responses[0].partitions.len(), 1);
+    assert_eq!(
+        produce_response.responses[0].partitions[0].error_code,
+        ErrorCode::None
+    );
+    assert_eq!(replica.leo(), records_per_request as i64);
+
+    server_end_event.notify();
+    debug!("terminated controller");
+}
+
+#[fluvio_future::test]
+async fn test_produce_not_enough_in_sync_replicas() {
+    let test_path = temp_dir().join("produce_not_enough_in_sync_replicas");
+    ensure_clean_dir(&test_path);
+    let port = portpicker::pick_unused_port().expect("No free ports left");
+
+    let addr = format!("127.0.0.1:{port}");
+    let mut spu_config = SpuConfig::default();
+    spu_config.log.base_dir = test_path;
+    let ctx = GlobalContext::new_shared_context(spu_config);
+
+    let server_end_event = create_public_server_with_root_auth(addr.to_owned(), ctx.clone()).run();
+
+    // wait for stream controller async to start
+    sleep(Duration::from_millis(100)).await;
+
+    let client_socket =
+        MultiplexerSocket::new(FluvioSocket::connect(&addr).await.expect("connect"));
+    let topic = "test_produce_min_isr";
+    // follower 5002 is never connected
+    let mut test = Replica::new((topic, 0), 5001, vec![5001, 5002]);
+    test.min_in_sync_replicas = Some(2);
+    let test_id = test.id.clone();
+    ctx.replica_localstore().sync_all(vec![test.clone()]);
+
+    let replica = LeaderReplicaState::create(test, ctx.config(), ctx.status_update_owned())
+        .await
+        .expect("replica")
+        .init(&ctx)
+        .await
+        .expect("init succeeded");
+    ctx.leaders_state().insert(test_id, replica.clone()).await;
+
+    let records_per_request = 5;
+    for isolation in [Isolation::ReadCommitted, Isolation::ReadUncommitted] {
+        let mut produce_request = DefaultProduceRequest {
+            isolation,
+            timeout: Duration::from_millis(300),
+            ..Default::default()
+        };
+        produce_request.topics.push(TopicProduceData {
+            name: topic.to_owned(),
+            partitions: vec![DefaultPartitionRequest {
+                partition_index: 0,
+                records: create_filter_records(records_per_request)
+                    .try_into()
+                    .expect("filter records"),
+            }],
+            ..Default::default()
+        });
+
+        let produce_response = client_socket
+            .send_and_receive(RequestMessage::new_request(produce_request))
+            .await
+            .expect("send offset");
+
+        assert_eq!(produce_response.responses.len(), 1);
+        assert_eq!(produce_response.responses[0].partitions.len(), 1);
+        let expected = match isolation {
+            Isolation::ReadCommitted => ErrorCode::NotEnoughInSyncReplicas { in_sync: 1, min: 2 },
+            Isolation::ReadUncommitted => ErrorCode::None,
+        };
+        assert_eq!(
+            produce_response.responses[0].partitions[0].error_code,
+            expected
+        );
+    }
+
+    // only uncommitted write is stored
+    assert_eq!(replica.leo(), records_per_request as i64);
+
+    server_end_event.notify();
+    debug!("terminated controller");
+}
+
 #[fluvio_future::test(ignore)]
 async fn test_produce_waiting_replication() {
     let config = TestConfig::builder()
@@ -534,6 +674,7 @@ async fn test_produce_basic_with_smartmodule_with_lookback() {
         wasm: SmartModuleInvocationWasm::Predefined(FLUVIO_WASM_FILTER_WITH_LOOKBACK.to_owned()),
         kind: SmartModuleKind::Filter,
         params: Default::default(),
+        ..Default::default()
     };
     smartmodule.params.set_lookback(Some(Lookback::last(1)));
     let mut smartmodules = vec![smartmodule];
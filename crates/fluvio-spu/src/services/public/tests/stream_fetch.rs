@@ -12,13 +12,14 @@ use fluvio_controlplane_metadata::smartmodule::{
 };
 use fluvio_storage::FileReplica;
 use flv_util::fixture::ensure_clean_dir;
-use futures_util::{Future, StreamExt};
+use futures_util::{Future, FutureExt, StreamExt};
 
 use fluvio_future::timer::sleep;
 use fluvio_socket::{FluvioSocket, MultiplexerSocket, AsyncResponse};
 use fluvio_spu_schema::server::{
     smartmodule::{
         SmartModuleKind, SmartModuleInvocation, SmartModuleInvocationWasm, SmartModuleContextData,
+        SmartModuleErrorPolicy,
     },
     stream_fetch::StreamFetchRequest,
 };
@@ -31,6 +32,7 @@ use fluvio_protocol::{
 use fluvio_protocol::fixture::{TEST_RECORD, create_raw_recordset};
 use fluvio_spu_schema::{
     server::update_offset::{UpdateOffsetsRequest, OffsetUpdate},
+    server::update_stream_fetch::{StreamFetchAction, UpdateStreamFetchRequest},
     fetch::DefaultFetchRequest,
 };
 use fluvio_spu_schema::server::stream_fetch::DefaultStreamFetchRequest;
@@ -2306,6 +2308,67 @@ async fn test_stream_fetch_invalid_smartmodule(
     debug!("terminated controller");
 }
 
+#[fluvio_future::test]
+async fn test_stream_fetch_rejects_dead_letter_policy() {
+    let test_path = temp_dir().join("test_stream_fetch_rejects_dead_letter_policy");
+    ensure_clean_dir(&test_path);
+    let mut spu_config = SpuConfig::default();
+    spu_config.log.base_dir.clone_from(&test_path);
+    let ctx = GlobalContext::new_shared_context(spu_config);
+
+    let port = portpicker::pick_unused_port().expect("No free ports left");
+    let addr = format!("127.0.0.1:{port}");
+    let server_end_event = create_public_server_with_root_auth(addr.to_owned(), ctx.clone()).run();
+
+    // wait for stream controller async to start
+    sleep(Duration::from_millis(100)).await;
+
+    let client_socket =
+        MultiplexerSocket::shared(FluvioSocket::connect(&addr).await.expect("connect"));
+
+    let topic = "test_dead_letter_policy";
+    let test = Replica::new((topic.to_owned(), 0), 5001, vec![5001]);
+    let test_id = test.id.clone();
+    let replica = LeaderReplicaState::create(test, ctx.config(), ctx.status_update_owned())
+        .await
+        .expect("replica")
+        .init(&ctx)
+        .await
+        .expect("init succeeded");
+    ctx.leaders_state().insert(test_id, replica.clone()).await;
+
+    // policy is rejected before SmartModule is loaded
+    let smartmodule = SmartModuleInvocation {
+        wasm: SmartModuleInvocationWasm::Predefined("filter".to_owned()),
+        kind: SmartModuleKind::Filter,
+        on_error: SmartModuleErrorPolicy::DeadLetter("dlq".to_owned()),
+        ..Default::default()
+    };
+    let stream_request = DefaultStreamFetchRequest::builder()
+        .topic(topic.to_owned())
+        .max_bytes(10000)
+        .smartmodules(vec![smartmodule])
+        .build()
+        .expect("stream request");
+
+    let mut stream = client_socket
+        .create_stream(RequestMessage::new_request(stream_request), 11)
+        .await
+        .expect("create stream");
+
+    let response = stream
+        .next()
+        .await
+        .expect("should get response")
+        .expect("response should be Ok");
+    assert_eq!(
+        response.partition.error_code,
+        ErrorCode::SmartModuleDeadLetterNotSupported
+    );
+
+    server_end_event.notify();
+}
+
 #[fluvio_future::test(ignore)]
 async fn test_stream_metrics() {
     let test_path = temp_dir().join("test_stream_metrics");
@@ -3091,3 +3154,150 @@ async fn test_stream_fetch_sends_topic_delete_error_on_topic_delete() {
     server_end_event.notify();
     debug!("terminated controller");
 }
+
+#[fluvio_future::test(ignore)]
+async fn test_stream_fetch_pause_resume_seek() {
+    let test_path = temp_dir().join("test_stream_fetch_pause_resume_seek");
+    ensure_clean_dir(&test_path);
+    let port = portpicker::pick_unused_port().expect("No free ports left");
+
+    let addr = format!("127.0.0.1:{port}");
+    let mut spu_config = SpuConfig::default();
+    spu_config.log.base_dir = test_path;
+    let ctx = GlobalContext::new_shared_context(spu_config);
+
+    let server_end_event = create_public_server_with_root_auth(addr.to_owned(), ctx.clone()).run();
+
+    // wait for stream controller async to start
+    sleep(Duration::from_millis(100)).await;
+
+    let client_socket =
+        MultiplexerSocket::new(FluvioSocket::connect(&addr).await.expect("connect"));
+
+    let topic = "test_stream_update".to_string();
+    let test = Replica::new((topic.clone(), 0), 5001, vec![5001]);
+    let test_id = test.id.clone();
+    let replica = LeaderReplicaState::create(test, ctx.config(), ctx.status_update_owned())
+        .await
+        .expect("replica")
+        .init(&ctx)
+        .await
+        .expect("init succeeded");
+
+    ctx.leaders_state().insert(test_id, replica.clone()).await;
+
+    let stream_request = DefaultStreamFetchRequest::builder()
+        .topic(topic.clone())
+        .max_bytes(1000)
+        .build()
+        .expect("request");
+
+    let mut stream = client_socket
+        .create_stream(
+            RequestMessage::new_request(stream_request),
+            DefaultStreamFetchRequest::DEFAULT_API_VERSION,
+        )
+        .await
+        .expect("create stream");
+
+    replica
+        .write_record_set(&mut create_raw_recordset(2), ctx.follower_notifier())
+        .await
+        .expect("write");
+
+    let response = stream.next().await.expect("first").expect("response");
+    let stream_id = response.stream_id;
+    assert_eq!(response.epoch, 0);
+    assert_eq!(response.partition.next_offset_for_fetch(), Some(2));
+
+    // paused stream doesn't send new records
+    let update = client_socket
+        .send_and_receive(RequestMessage::new_request(UpdateStreamFetchRequest::new(
+            stream_id,
+            StreamFetchAction::Pause,
+        )))
+        .await
+        .expect("pause");
+    assert_eq!(update.error_code, ErrorCode::None);
+
+    client_socket
+        .send_and_receive(RequestMessage::new_request(UpdateOffsetsRequest {
+            offsets: vec![OffsetUpdate {
+                offset: 2,
+                session_id: stream_id,
+            }],
+        }))
+        .await
+        .expect("send offset");
+    replica
+        .write_record_set(&mut create_raw_recordset(2), ctx.follower_notifier())
+        .await
+        .expect("write");
+    sleep(Duration::from_millis(100)).await;
+    assert!(stream.next().now_or_never().is_none());
+
+    // resume sends records produced while paused
+    let update = client_socket
+        .send_and_receive(RequestMessage::new_request(UpdateStreamFetchRequest::new(
+            stream_id,
+            StreamFetchAction::Resume,
+        )))
+        .await
+        .expect("resume");
+    assert_eq!(update.error_code, ErrorCode::None);
+
+    let response = stream.next().await.expect("2nd").expect("response");
+    assert_eq!(response.epoch, 0);
+    assert_eq!(response.partition.records.batches[0].base_offset, 2);
+    assert_eq!(response.partition.next_offset_for_fetch(), Some(4));
+
+    // seek back to the beginning, without acknowledging the last records
+    let update = client_socket
+        .send_and_receive(RequestMessage::new_request(UpdateStreamFetchRequest::new(
+            stream_id,
+            StreamFetchAction::Seek(0),
+        )))
+        .await
+        .expect("seek");
+    assert_eq!(update.error_code, ErrorCode::None);
+    assert_eq!(update.offset, 0);
+    assert_eq!(update.epoch, 1);
+
+    let response = stream.next().await.expect("3rd").expect("response");
+    assert_eq!(response.epoch, 1);
+    assert_eq!(response.partition.records.batches[0].base_offset, 0);
+    assert_eq!(response.partition.next_offset_for_fetch(), Some(4));
+
+    // acknowledgment after the seek repeats offset acknowledged before, it must not be ignored
+    client_socket
+        .send_and_receive(RequestMessage::new_request(UpdateOffsetsRequest {
+            offsets: vec![OffsetUpdate {
+                offset: 4,
+                session_id: stream_id,
+            }],
+        }))
+        .await
+        .expect("send offset");
+    replica
+        .write_record_set(&mut create_raw_recordset(2), ctx.follower_notifier())
+        .await
+        .expect("write");
+
+    let response = stream.next().await.expect("4th").expect("response");
+    assert_eq!(response.partition.records.batches[0].base_offset, 4);
+
+    // timestamp older than all records moves to the start of the log
+    let update = client_socket
+        .send_and_receive(RequestMessage::new_request(UpdateStreamFetchRequest::new(
+            stream_id,
+            StreamFetchAction::SeekTimestamp(0),
+        )))
+        .await
+        .expect("seek timestamp");
+    assert_eq!(update.error_code, ErrorCode::None);
+    assert_eq!(update.offset, 0);
+    assert_eq!(update.epoch, 2);
+
+    server_end_event.notify();
+    debug!("terminated controller");
+}
@@ -4,10 +4,12 @@ use fluvio_protocol::link::ErrorCode;
 use fluvio_spu_schema::server::smartmodule::SmartModuleInvocation;
 
 #[cfg(feature = "smartengine")]
-use fluvio_smartengine::{EngineError, SmartModuleConfig, SmartModuleInitialData};
+use fluvio_smartengine::{EngineError, ErrorPolicy, SmartModuleConfig, SmartModuleInitialData};
 
 #[cfg(feature = "smartengine")]
-use fluvio_spu_schema::server::smartmodule::{SmartModuleContextData, SmartModuleKind};
+use fluvio_spu_schema::server::smartmodule::{
+    SmartModuleContextData, SmartModuleErrorPolicy, SmartModuleKind,
+};
 
 use crate::smartengine::SmartModuleChainBuilder;
 use crate::smartengine::SmartEngine;
@@ -54,12 +56,19 @@ pub(crate) fn build_chain(
 
         let lookback = invocation.params.lookback().map(Into::into);
 
+        let error_policy = match invocation.on_error {
+            SmartModuleErrorPolicy::Fail => ErrorPolicy::Fail,
+            SmartModuleErrorPolicy::Skip => ErrorPolicy::Skip,
+            SmartModuleErrorPolicy::DeadLetter(topic) => ErrorPolicy::DeadLetter(topic),
+        };
+
         debug!("param: {:#?}", invocation.params);
         chain_builder.add_smart_module(
             SmartModuleConfig::builder()
                 .params(invocation.params)
                 .version(version)
                 .lookback(lookback)
+                .error_policy(error_policy)
                 .initial_data(initial_data)
                 .build()
                 .map_err(|err| ErrorCode::SmartModuleInvalid {
@@ -0,0 +1,185 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use tracing::{debug, error};
+
+use fluvio_protocol::link::ErrorCode;
+use fluvio_protocol::record::{Batch, RawRecords, Record, RecordSet, ReplicaKey};
+use fluvio_types::PartitionId;
+
+use crate::core::DefaultSharedGlobalContext;
+use crate::services::internal::ProduceDeadLetterRequest;
+use crate::services::public::send_private_request_to_leader;
+use crate::smartengine::DeadLetterRecord;
+
+/// Value of the record written to the dead-letter topic.
+/// The key of the record is the key of the offending record.
+#[derive(Debug, Serialize)]
+struct DeadLetterEnvelope<'a> {
+    topic: &'a str,
+    partition: PartitionId,
+    offset: i64,
+    smartmodule_kind: String,
+    error: &'a str,
+    value: String,
+}
+
+/// Write records dropped by SmartModules to their dead-letter topics.
+/// Failures are logged, they don't affect processing of the source partition.
+pub(crate) async fn send_dead_letters(
+    ctx: &DefaultSharedGlobalContext,
+    source: &ReplicaKey,
+    dead_letters: Vec<DeadLetterRecord>,
+) {
+    if dead_letters.is_empty() {
+        return;
+    }
+
+    let mut by_topic: BTreeMap<String, Vec<Record>> = BTreeMap::new();
+    for dead_letter in dead_letters {
+        match dead_letter_record(source, &dead_letter) {
+            Ok(record) => by_topic.entry(dead_letter.topic).or_default().push(record),
+            Err(err) => error!(%err, "unable to encode dead letter"),
+        }
+    }
+
+    for (topic, records) in by_topic {
+        let Some(partition) = dead_letter_partition(ctx, &topic, source.partition) else {
+            error!(topic, count = records.len(), "dead-letter topic not found");
+            continue;
+        };
+        let replica_id = ReplicaKey::new(topic, partition);
+        let count = records.len();
+        let mut batch = Batch::new();
+        for record in records {
+            batch.add_record(record);
+        }
+        let mut record_set = match Batch::<RawRecords>::try_from(batch) {
+            Ok(batch) => RecordSet::default().add(batch),
+            Err(err) => {
+                error!(%replica_id, %err, "unable to encode dead letter batch");
+                continue;
+            }
+        };
+
+        let error_code = if ctx.leaders_state().get(&replica_id).await.is_some() {
+            write_local(ctx, &replica_id, &mut record_set).await
+        } else {
+            let request = ProduceDeadLetterRequest::new(replica_id.clone(), record_set);
+            match send_private_request_to_leader(ctx, &replica_id, request).await {
+                Ok(response) => response.error_code,
+                Err(error_code) => error_code,
+            }
+        };
+
+        if error_code.is_ok() {
+            debug!(%replica_id, count, "dead letters written");
+        } else {
+            error!(%replica_id, count, ?error_code, "unable to write dead letters");
+        }
+    }
+}
+
+/// Write dead letters to a partition led by this SPU
+pub(crate) async fn write_local(
+    ctx: &DefaultSharedGlobalContext,
+    replica_id: &ReplicaKey,
+    records: &mut RecordSet<RawRecords>,
+) -> ErrorCode {
+    let Some(leader) = ctx.leaders_state().get(replica_id).await else {
+        return ErrorCode::PartitionNotLeader;
+    };
+    match leader
+        .write_record_set(records, ctx.follower_notifier())
+        .await
+    {
+        Ok(_) => ErrorCode::None,
+        Err(err) => ErrorCode::Other(err.to_string()),
+    }
+}
+
+/// Dead letters of a source partition always go to the same partition of the dead-letter topic,
+/// so they keep the order of the source partition.
+fn dead_letter_partition(
+    ctx: &DefaultSharedGlobalContext,
+    topic: &str,
+    source_partition: PartitionId,
+) -> Option<PartitionId> {
+    let partitions = ctx
+        .replica_localstore()
+        .all_keys()
+        .into_iter()
+        .filter(|key| key.topic == topic)
+        .count();
+    map_partition(source_partition, partitions as PartitionId)
+}
+
+fn map_partition(source_partition: PartitionId, partitions: PartitionId) -> Option<PartitionId> {
+    if partitions == 0 {
+        None
+    } else {
+        Some(source_partition % partitions)
+    }
+}
+
+fn dead_letter_record(
+    source: &ReplicaKey,
+    dead_letter: &DeadLetterRecord,
+) -> Result<Record, serde_json::Error> {
+    let error = &dead_letter.error;
+    let envelope = DeadLetterEnvelope {
+        topic: &source.topic,
+        partition: source.partition,
+        offset: error.offset,
+        smartmodule_kind: error.kind.to_string(),
+        error: &error.hint,
+        value: String::from_utf8_lossy(error.record_value.as_ref()).into_owned(),
+    };
+    let value = serde_json::to_vec(&envelope)?;
+    let record = match &error.record_key {
+        Some(key) => Record::new_key_value(key.as_ref().to_vec(), value),
+        None => Record::new(value),
+    };
+    Ok(record)
+}
+
+#[cfg(test)]
+mod tests {
+    use fluvio_protocol::link::smartmodule::{SmartModuleKind, SmartModuleTransformRuntimeError};
+
+    use super::*;
+
+    #[test]
+    fn test_map_partition() {
+        assert_eq!(map_partition(0, 0), None);
+        assert_eq!(map_partition(2, 1), Some(0));
+        assert_eq!(map_partition(2, 3), Some(2));
+        assert_eq!(map_partition(5, 3), Some(2));
+    }
+
+    #[test]
+    fn test_dead_letter_record() {
+        let source = ReplicaKey::new("orders", 2u32);
+        let dead_letter = DeadLetterRecord {
+            topic: "orders-dlq".to_string(),
+            error: SmartModuleTransformRuntimeError {
+                hint: "invalid json".to_string(),
+                offset: 10,
+                kind: SmartModuleKind::Map,
+                record_key: Some("k1".into()),
+                record_value: "{bad".into(),
+            },
+        };
+
+        let record = dead_letter_record(&source, &dead_letter).expect("record");
+
+        assert_eq!(record.key().map(|key| key.as_ref()), Some(b"k1".as_ref()));
+        let envelope: serde_json::Value =
+            serde_json::from_slice(record.value().as_ref()).expect("json");
+        assert_eq!(envelope["topic"], "orders");
+        assert_eq!(envelope["partition"], 2);
+        assert_eq!(envelope["offset"], 10);
+        assert_eq!(envelope["error"], "invalid json");
+        assert_eq!(envelope["value"], "{bad");
+    }
+}
@@ -10,12 +10,13 @@ pub(crate) mod batch;
 pub(crate) mod file_batch;
 pub(crate) mod produce_batch;
 pub(crate) mod context;
+pub(crate) mod dead_letter;
 mod chain;
 
 #[cfg(feature = "smartengine")]
 pub(crate) use fluvio_smartengine::{
     EngineError, Lookback, SmartModuleChainBuilder, metrics::SmartModuleChainMetrics, SmartEngine,
-    SmartModuleChainInstance, Version,
+    SmartModuleChainInstance, DeadLetterRecord, Version,
 };
 
 // Stub structures to support a null smartengine config
@@ -31,6 +32,7 @@ mod null_smartengine {
     use fluvio_smartmodule::dataplane::smartmodule::SmartModuleInput;
     use fluvio_smartmodule::dataplane::smartmodule::SmartModuleOutput;
     use fluvio_smartmodule::Record;
+    use fluvio_protocol::link::smartmodule::SmartModuleTransformRuntimeError;
 
     // refactor to use more widely as a "flow" metric?
     // hack copy of smartmodule chain metrics
@@ -40,6 +42,7 @@ mod null_smartengine {
         records_out: AtomicU64,
         invocation_count: AtomicU64,
         fuel_used: AtomicU64,
+        records_skipped: AtomicU64,
     }
 
     #[allow(dead_code)]
@@ -57,6 +60,10 @@ mod null_smartengine {
             self.fuel_used.fetch_add(value, Ordering::SeqCst);
         }
 
+        pub fn add_records_skipped(&self, value: u64) {
+            self.records_skipped.fetch_add(value, Ordering::SeqCst);
+        }
+
         pub fn bytes_in(&self) -> u64 {
             self.bytes_in.load(Ordering::SeqCst)
         }
@@ -71,6 +78,10 @@ mod null_smartengine {
         pub fn invocation_count(&self) -> u64 {
             self.invocation_count.load(Ordering::SeqCst)
         }
+
+        pub fn records_skipped(&self) -> u64 {
+            self.records_skipped.load(Ordering::SeqCst)
+        }
     }
 
     #[derive(Clone, Debug, Default)]
@@ -92,6 +103,13 @@ mod null_smartengine {
     #[derive(Debug)]
     pub struct SmartModuleChainInstance;
 
+    #[allow(dead_code)]
+    #[derive(Debug, Clone)]
+    pub struct DeadLetterRecord {
+        pub topic: String,
+        pub error: SmartModuleTransformRuntimeError,
+    }
+
     impl SmartModuleChainInstance {
         pub async fn look_back<F, R>(
             &mut self,
@@ -118,6 +136,10 @@ mod null_smartengine {
             let out = SmartModuleOutput::new(records);
             Ok(out)
         }
+
+        pub fn take_dead_letters(&mut self) -> Vec<DeadLetterRecord> {
+            Vec::new()
+        }
     }
 
     pub type Version = i16;
@@ -168,6 +190,7 @@ pub(crate) fn dedup_to_invocation(dedup: &Deduplication) -> SmartModuleInvocatio
         wasm: SmartModuleInvocationWasm::Predefined(dedup.filter.transform.uses.clone()),
         kind: SmartModuleKind::Filter,
         params: SmartModuleExtraParams::new(params, Some(lookback)),
+        ..Default::default()
     }
 }
 
@@ -1,15 +1,21 @@
 use std::sync::Arc;
 
+use tracing::warn;
+
 use fluvio_auth::root::RootAuthorization;
+use fluvio_future::net::TcpListener;
+use fluvio_service::ServiceTasks;
 use fluvio_storage::FileReplica;
+use fluvio_types::event::StickyEvent;
 
 use crate::config::{SpuConfig, SpuOpt};
-use crate::services::auth::SpuAuthGlobalContext;
+use crate::services::auth::{SpuAuthGlobalContext, TenantAuthorization};
 use crate::services::create_internal_server;
 use crate::services::public::create_public_server;
+use crate::services::kafka::create_kafka_server;
 use crate::core::DefaultSharedGlobalContext;
 use crate::core::GlobalContext;
-use crate::control_plane::ScDispatcher;
+use crate::control_plane::{ScDispatcher, graceful_shutdown};
 
 type FileReplicaContext = GlobalContext<FileReplica>;
 
@@ -43,10 +49,12 @@ pub fn main_loop(opt: SpuOpt) {
     info!(available_memory = sys.available_memory(), "System");
     info!(uptime = System::uptime(), "Uptime in secs");
 
+    let shutdown = shutdown_signal();
+
     run_block_on(async move {
         let ctx = create_services(spu_config.clone(), true, true);
 
-        init_monitoring(ctx);
+        init_monitoring(ctx.clone());
 
         if let Some(tls_config) = tls_acceptor_option {
             proxy::start_proxy(spu_config, tls_config).await;
@@ -54,11 +62,72 @@ pub fn main_loop(opt: SpuOpt) {
 
         println!("SPU Version: {VERSION} started successfully");
 
-        // infinite loop
-        loop {
-            sleep(Duration::from_secs(60)).await;
+        if shutdown.recv().await.is_ok() {
+            graceful_shutdown(&ctx).await;
+        } else {
+            // signal handler is not installed, run until killed
+            loop {
+                sleep(Duration::from_secs(60)).await;
+            }
+        }
+
+        println!("SPU stopped");
+    });
+}
+
+/// receives termination signal, second signal exits immediately
+fn shutdown_signal() -> async_channel::Receiver<()> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use tracing::error;
+
+    let (sender, receiver) = async_channel::bounded(1);
+    let invoked = AtomicBool::new(false);
+    let result = ctrlc::set_handler(move || {
+        if invoked.swap(true, Ordering::SeqCst) {
+            std::process::exit(1);
         }
+        let _ = sender.try_send(());
     });
+    if let Err(err) = result {
+        error!(%err, "shutdown signal handler can't be initialized");
+    }
+    receiver
+}
+
+/// start services of SPU in current runtime, without blocking.
+/// Servers run on listeners bound by caller, returned events stop them.
+/// Returned tasks are dispatcher and replica controllers, which are stopped after servers
+pub fn start_services(
+    spu_config: SpuConfig,
+    public_listener: TcpListener,
+    private_listener: TcpListener,
+) -> (Arc<ServiceTasks>, Vec<Arc<StickyEvent>>) {
+    let ctx = FileReplicaContext::new_shared_context(spu_config);
+
+    let public_addr = ctx.config().public_socket_addr().to_owned();
+    let private_addr = ctx.config().private_socket_addr().to_owned();
+    let public_server = match &ctx.config().tenant_policy {
+        Some(tenants) => {
+            let authorization = Arc::new(TenantAuthorization::new(tenants.clone()));
+            let auth_global_ctx = SpuAuthGlobalContext::new(ctx.clone(), authorization);
+            create_public_server(public_addr, auth_global_ctx).run_on(public_listener)
+        }
+        None => {
+            let authorization = Arc::new(RootAuthorization::new());
+            let auth_global_ctx = SpuAuthGlobalContext::new(ctx.clone(), authorization);
+            create_public_server(public_addr, auth_global_ctx).run_on(public_listener)
+        }
+    };
+    let servers = vec![
+        public_server,
+        create_internal_server(private_addr, ctx.clone()).run_on(private_listener),
+    ];
+
+    let tasks = ctx.tasks().clone();
+    ScDispatcher::new(ctx).run();
+
+    (tasks, servers)
 }
 
 /// create server and spin up services, but don't run server
@@ -73,10 +142,24 @@ pub fn create_services(
     let private_ep_addr = ctx.config().private_socket_addr().to_owned();
 
     if public {
-        let authorization = Arc::new(RootAuthorization::new());
-        let auth_global_ctx = SpuAuthGlobalContext::new(ctx.clone(), authorization);
-        let pub_server = create_public_server(public_ep_addr, auth_global_ctx);
-        pub_server.run();
+        // principals of tenants are authenticated by TLS proxy
+        if let Some(tenants) = &ctx.config().tenant_policy {
+            let authorization = Arc::new(TenantAuthorization::new(tenants.clone()));
+            let auth_global_ctx = SpuAuthGlobalContext::new(ctx.clone(), authorization);
+            create_public_server(public_ep_addr, auth_global_ctx).run();
+        } else {
+            let authorization = Arc::new(RootAuthorization::new());
+            let auth_global_ctx = SpuAuthGlobalContext::new(ctx.clone(), authorization);
+            create_public_server(public_ep_addr, auth_global_ctx).run();
+        }
+
+        if ctx.config().tenant_policy.is_some() && ctx.config().kafka_socket_addr().is_some() {
+            // kafka clients are not authenticated, so they can't be isolated by tenant
+            warn!("kafka listener is disabled with tenant policy");
+        } else if let Some(kafka_ep_addr) = ctx.config().kafka_socket_addr() {
+            let kafka_server = create_kafka_server(kafka_ep_addr.to_owned(), ctx.clone());
+            kafka_server.run();
+        }
     };
 
     if internal {
@@ -97,7 +180,10 @@ mod proxy {
 
     use flv_util::print_cli_err;
     use fluvio_future::openssl::TlsAcceptor;
-    use flv_tls_proxy::start as proxy_start;
+    use fluvio_auth::x509::X509Authenticator;
+    use flv_tls_proxy::{
+        start as proxy_start, start_with_authenticator as proxy_start_with_authenticator,
+    };
 
     use crate::config::SpuConfig;
 
@@ -106,8 +192,14 @@ mod proxy {
         let target = config.public_endpoint;
         info!("starting TLS proxy: {}", proxy_addr);
 
-        //TODO: add X509Authenticator
-        if let Err(err) = proxy_start(&proxy_addr, tls_acceptor, target).await {
+        // principal is passed to public service, which maps it to tenant
+        let result = if config.tenant_policy.is_some() {
+            let authenticator = Box::new(X509Authenticator::without_scopes());
+            proxy_start_with_authenticator(&proxy_addr, tls_acceptor, target, authenticator).await
+        } else {
+            proxy_start(&proxy_addr, tls_acceptor, target).await
+        };
+        if let Err(err) = result {
             print_cli_err!(err);
             process::exit(-1);
         } else {
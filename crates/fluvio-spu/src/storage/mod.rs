@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::fmt::Debug;
 use std::time::Instant;
 
@@ -19,6 +20,11 @@ use fluvio_types::event::offsets::OffsetPublisher;
 pub const REMOVAL_START: Offset = -1000; // indicate that storage about to be removed
 pub const REMOVAL_END: Offset = -1001; // indicate the storage has been removed
 
+/// write to replica which no longer accepts records
+#[derive(Debug, thiserror::Error)]
+#[error("replica {0} is fenced")]
+pub struct ReplicaFenced(pub ReplicaKey);
+
 /// Thread safe storage for replicas
 #[derive(Debug)]
 pub struct SharableReplicaStorage<S> {
@@ -26,6 +32,7 @@ pub struct SharableReplicaStorage<S> {
     inner: Arc<RwLock<S>>,
     leo: Arc<OffsetPublisher>,
     hw: Arc<OffsetPublisher>,
+    fenced: Arc<AtomicBool>,
 }
 
 impl<S> Clone for SharableReplicaStorage<S> {
@@ -35,6 +42,7 @@ impl<S> Clone for SharableReplicaStorage<S> {
             inner: self.inner.clone(),
             leo: self.leo.clone(),
             hw: self.hw.clone(),
+            fenced: self.fenced.clone(),
         }
     }
 }
@@ -54,6 +62,7 @@ where
             inner: Arc::new(RwLock::new(storage)),
             leo,
             hw,
+            fenced: Arc::new(AtomicBool::new(false)),
         })
     }
 
@@ -96,6 +105,22 @@ where
         self.inner.write().await
     }
 
+    /// stop accepting records, shared by all clones of this storage.
+    /// returns once writes in progress are done
+    pub async fn fence(&self) {
+        self.fenced.store(true, Ordering::SeqCst);
+        drop(self.write().await);
+    }
+
+    pub fn is_fenced(&self) -> bool {
+        self.fenced.load(Ordering::SeqCst)
+    }
+
+    /// apply config changes to storage
+    pub async fn update_config(&self, config: S::ReplicaConfig) {
+        self.read().await.update_config(config);
+    }
+
     /// get start offset and hw
     pub async fn start_offset_info(&self) -> (Offset, Offset) {
         let reader = self.read().await;
@@ -144,6 +169,9 @@ where
         );
 
         let mut writer = self.write().await;
+        if self.is_fenced() {
+            return Err(ReplicaFenced(self.id.clone()).into());
+        }
 
         let base_offset = writer.get_leo();
 
@@ -163,6 +191,31 @@ where
         Ok((base_offset, leo, bytes_written))
     }
 
+    /// offset of first corrupted batch if replica is fenced
+    pub async fn corrupted_offset(&self) -> Option<Offset> {
+        self.read().await.get_corrupted_offset()
+    }
+
+    /// discard corrupted records and publish new offsets.
+    /// return new leo if records were discarded
+    #[instrument(skip(self))]
+    pub async fn discard_corrupted(&self) -> Result<Option<Offset>> {
+        let mut writer = self.write().await;
+        let leo = writer.discard_corrupted().await?;
+        if leo.is_some() {
+            self.leo.update(writer.get_leo());
+            self.hw.update(writer.get_hw());
+        }
+        Ok(leo)
+    }
+
+    /// sync written records to disk
+    #[instrument(skip(self))]
+    pub async fn sync(&self) -> Result<()> {
+        let mut writer = self.write().await;
+        writer.sync().await
+    }
+
     /// perform permanent remove
     pub async fn remove(&self) -> Result<(), StorageError> {
         self.leo.update(REMOVAL_START);
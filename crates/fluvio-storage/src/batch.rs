@@ -4,6 +4,7 @@ use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::path::Path;
 
+use fluvio_protocol::Decoder;
 use fluvio_protocol::record::BatchHeader;
 use fluvio_protocol::record::Offset;
 use tracing::error;
@@ -41,6 +42,17 @@ pub enum BatchHeaderError {
     },
 }
 
+/// Batch content doesn't match crc stored in the batch header
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[error("Checksum mismatch at offset {base_offset}: expected {expected:#x}, found {computed:#x}")]
+pub struct BatchChecksumError {
+    pub base_offset: Offset,
+    /// position of the batch in the segment log
+    pub pos: Size,
+    pub expected: u32,
+    pub computed: u32,
+}
+
 /// hold information about position of batch in the file
 pub struct FileBatchPos<R>
 where
@@ -146,6 +158,9 @@ where
         }
 
         let mut cursor = Cursor::new(bytes);
+        if batch.header.has_schema() {
+            batch.schema_id.decode(&mut cursor, 0)?;
+        }
         batch.mut_records().decode(&mut cursor, 0)?;
 
         Ok(Some(FileBatchPos { inner: batch, pos }))
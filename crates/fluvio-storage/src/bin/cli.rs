@@ -14,11 +14,17 @@ use fluvio_storage::{
     FileReplica, ReplicaStorage,
 };
 use fluvio_storage::records::FileRecords;
+use fluvio_storage::batch::FileBatchStream;
+use fluvio_storage::repair::{self, ReplicaIssue};
+use fluvio_protocol::record::RawRecords;
+use serde_json::json;
 
 ///
 /// Bunch of storage utilities:
 ///
 /// validation: `cargo run --bin storage-cli --features=cli --release validate ~/.fluvio/data/spu-logs-5001/longevity-0 --skip-errors=false `
+///
+/// repair: `cargo run --bin storage-cli --features=cli --release repair ~/.fluvio/data/spu-logs-5001/longevity-0 --dry-run`
 #[derive(Debug, Parser)]
 #[clap(name = "storage", about = "Flavio Storage CLI")]
 enum Main {
@@ -35,6 +41,18 @@ enum Main {
     /// show information about replica
     #[clap(name = "replica")]
     Replica(ReplicaOpt),
+
+    /// dump batches and records of segment as JSON
+    #[clap(name = "dump")]
+    Dump(DumpOpt),
+
+    /// verify replica directory against checkpoint
+    #[clap(name = "verify")]
+    Verify(VerifyOpt),
+
+    /// repair corrupt segments and indexes of replica directory
+    #[clap(name = "repair")]
+    Repair(RepairOpt),
 }
 
 fn main() {
@@ -48,6 +66,9 @@ fn main() {
             Main::Index(opt) => dump_index(opt).await,
             Main::ValidateSegment(opt) => validate_segment(opt).await,
             Main::Replica(opt) => replica_info(opt).await,
+            Main::Dump(opt) => dump_records(opt).await,
+            Main::Verify(opt) => verify_replica(opt).await,
+            Main::Repair(opt) => repair_replica(opt).await,
         }
     });
     if let Err(err) = result {
@@ -248,3 +269,206 @@ pub(crate) async fn replica_info(opt: ReplicaOpt) -> Result<()> {
 
     Ok(())
 }
+
+#[derive(Debug, Parser)]
+pub(crate) struct DumpOpt {
+    /// segment log file
+    #[clap(value_parser)]
+    file_name: PathBuf,
+
+    /// first offset to dump
+    #[clap(long)]
+    start: Option<Offset>,
+
+    /// last offset to dump (inclusive)
+    #[clap(long)]
+    end: Option<Offset>,
+
+    /// only dump batch headers
+    #[clap(long)]
+    headers_only: bool,
+}
+
+async fn dump_records(opt: DumpOpt) -> Result<()> {
+    if let (Some(start), Some(end)) = (opt.start, opt.end) {
+        if start > end {
+            return Err(anyhow!("start > end"));
+        }
+    }
+
+    let mut batch_stream: FileBatchStream<RawRecords> =
+        FileBatchStream::open(&opt.file_name).await?;
+
+    while let Some(batch_pos) = batch_stream.try_next().await? {
+        let pos = batch_pos.get_pos();
+        let batch = batch_pos.inner();
+
+        if let Some(start) = opt.start {
+            if batch.get_last_offset() < start {
+                continue;
+            }
+        }
+        if let Some(end) = opt.end {
+            if batch.get_base_offset() > end {
+                break;
+            }
+        }
+
+        let header = batch.get_header();
+        let compression = batch
+            .get_compression()
+            .map(|compression| compression.to_string())
+            .unwrap_or_else(|err| format!("invalid: {err}"));
+        println!(
+            "{}",
+            json!({
+                "type": "batch",
+                "pos": pos,
+                "base_offset": batch.get_base_offset(),
+                "last_offset": batch.get_last_offset(),
+                "batch_len": batch.batch_len,
+                "crc": header.crc,
+                "compression": compression,
+                "first_timestamp": header.first_timestamp,
+                "max_timestamp": header.max_time_stamp,
+                "producer_id": header.producer_id,
+            })
+        );
+
+        if opt.headers_only {
+            continue;
+        }
+
+        let records = batch.memory_records()?;
+        for record in records {
+            let offset = batch.get_base_offset() + record.offset_delta();
+            if opt.start.map(|start| offset < start).unwrap_or(false)
+                || opt.end.map(|end| offset > end).unwrap_or(false)
+            {
+                continue;
+            }
+            println!(
+                "{}",
+                json!({
+                    "type": "record",
+                    "offset": offset,
+                    "timestamp": header.first_timestamp + record.timestamp_delta(),
+                    "key": record.key().map(|key| key.as_utf8_lossy_string().to_string()),
+                    "value": record.value().as_utf8_lossy_string().to_string(),
+                })
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Parser)]
+pub(crate) struct VerifyOpt {
+    /// replica directory, ex: ~/.fluvio/data/spu-logs-5001/longevity-0
+    #[clap(value_parser)]
+    replica_dir: PathBuf,
+}
+
+async fn verify_replica(opt: VerifyOpt) -> Result<()> {
+    let report = repair::verify_replica(&opt.replica_dir).await?;
+
+    println!("segments: {}", report.segments.len());
+    println!("hw: {:?}", report.hw);
+    println!("leo: {:?}", report.leo);
+    if report.is_healthy() {
+        println!("replica is healthy");
+    } else {
+        for issue in &report.issues {
+            println!("issue: {issue:?}");
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Parser)]
+pub(crate) struct RepairOpt {
+    /// replica directory, ex: ~/.fluvio/data/spu-logs-5001/longevity-0
+    #[clap(value_parser)]
+    replica_dir: PathBuf,
+
+    /// rebuild index of every segment, not only invalid ones
+    #[clap(long)]
+    rebuild_index: bool,
+
+    /// show what would be repaired without modifying files
+    #[clap(long)]
+    dry_run: bool,
+}
+
+async fn repair_replica(opt: RepairOpt) -> Result<()> {
+    let option = ReplicaConfig::builder()
+        .base_dir(opt.replica_dir.clone())
+        .build()
+        .shared();
+
+    let report = repair::verify_replica(&opt.replica_dir).await?;
+    let active_segment = report.segments.last();
+
+    for log_path in &report.segments {
+        let base_offset = repair::segment_base_offset(log_path)?;
+        // only active segment can have partially written tail, older segments were complete when rolled over
+        let truncated = if Some(log_path) == active_segment {
+            let seg_report = repair::truncate_corrupt_tail(log_path, opt.dry_run).await?;
+            if seg_report.is_truncated() {
+                println!(
+                    "{}: truncating from {} to {} bytes, leo: {}",
+                    log_path.display(),
+                    seg_report.original_len,
+                    seg_report.repaired_len,
+                    seg_report.leo
+                );
+            }
+            seg_report.is_truncated()
+        } else {
+            let errors = report.segment_corruption(base_offset);
+            if !errors.is_empty() {
+                for error in errors {
+                    println!(
+                        "{}: corrupt segment: {error}, manual recovery needed",
+                        log_path.display()
+                    );
+                }
+                continue;
+            }
+            false
+        };
+
+        if opt.rebuild_index || report.has_invalid_index(base_offset) || truncated {
+            println!("{}: rebuilding index", log_path.display());
+            if !opt.dry_run {
+                let batches = repair::rebuild_index(log_path, option.clone()).await?;
+                println!(
+                    "{}: index rebuilt from {batches} batches",
+                    log_path.display()
+                );
+            }
+        }
+    }
+
+    for issue in &report.issues {
+        match issue {
+            ReplicaIssue::HwBeyondLeo { hw, leo } => {
+                println!("hw: {hw} is beyond leo: {leo}, SPU will reset hw to leo on start")
+            }
+            ReplicaIssue::SegmentGap { expected, found } => {
+                println!(
+                    "gap between segments, expected: {expected} found: {found}, \
+                    manual recovery needed"
+                )
+            }
+            ReplicaIssue::MissingCheckpoint | ReplicaIssue::InvalidCheckpoint(_) => {
+                println!("{issue:?}, SPU will recreate checkpoint on start")
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
@@ -21,6 +21,9 @@ use fluvio_future::fs::util;
 
 use crate::config::SharedReplicaConfig;
 
+/// name of the checkpoint file storing replica high watermark
+pub(crate) const REPLICATION_CHECKPOINT: &str = "replication.chk";
+
 pub trait ReadToBuf: Sized {
     fn read_from<B>(buf: &mut B) -> Self
     where
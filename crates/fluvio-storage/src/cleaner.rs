@@ -4,24 +4,33 @@ use std::ops::Div;
 use std::ops::Rem;
 
 use tracing::{debug, info, instrument};
+#[cfg(feature = "tiered")]
+use tracing::error;
 
 use fluvio_future::task::spawn;
 use fluvio_future::timer::sleep;
+#[cfg(feature = "tiered")]
+use fluvio_protocol::record::Offset;
 use fluvio_types::event::StickyEvent;
 
 use crate::config::{SharedReplicaConfig, StorageConfig};
 use crate::replica::ReplicaSize;
 use crate::segments::SharedSegments;
+#[cfg(feature = "tiered")]
+use crate::tiered::RemoteTier;
 
 /// Replica cleaner. This is a background task that periodically checks for expired segments and
 /// removes them. It also enforces max partition size by removing first segments if replica size is
 /// exceeded. In the future, this may be done by a central cleaner pool instead of per a replica.
+/// If replica has a remote tier, old segments are offloaded to it before other policies apply.
 #[derive(Debug)]
 pub(crate) struct Cleaner {
     config: Arc<StorageConfig>,
     replica_config: Arc<SharedReplicaConfig>,
     segments: Arc<SharedSegments>,
     replica_size: Arc<ReplicaSize>,
+    #[cfg(feature = "tiered")]
+    remote: Option<Arc<RemoteTier>>,
     end_event: Arc<StickyEvent>,
 }
 
@@ -31,6 +40,7 @@ impl Cleaner {
         replica_config: Arc<SharedReplicaConfig>,
         segments: Arc<SharedSegments>,
         replica_size: Arc<ReplicaSize>,
+        #[cfg(feature = "tiered")] remote: Option<Arc<RemoteTier>>,
     ) -> Arc<Self> {
         let end_event = StickyEvent::shared();
         let cleaner = Arc::new(Cleaner {
@@ -38,6 +48,8 @@ impl Cleaner {
             replica_config,
             segments,
             replica_size,
+            #[cfg(feature = "tiered")]
+            remote,
             end_event,
         });
 
@@ -72,6 +84,8 @@ impl Cleaner {
                     break;
                 },
                 _ = sleep(sleep_period) => {
+                    #[cfg(feature = "tiered")]
+                    self.enforce_offload().await;
                     self.enforce_size().await;
                     self.enforce_ttl().await;
                 }
@@ -81,10 +95,58 @@ impl Cleaner {
         info!("cleaner end");
     }
 
+    /// move all but most recent local segments to remote tier
+    #[cfg(feature = "tiered")]
+    #[instrument(skip(self))]
+    async fn enforce_offload(&self) {
+        let Some(remote) = &self.remote else {
+            return;
+        };
+
+        // segments are uploaded without holding the lock, so writes and reads are not blocked
+        let candidates: Vec<(Offset, Offset)> = {
+            let read = self.segments.read().await;
+            let count_to_offload = read.len().saturating_sub(remote.local_segments());
+            read.find_first(count_to_offload)
+                .into_iter()
+                .filter_map(|base_offset| read.get_segment(base_offset))
+                .map(|segment| (segment.get_base_offset(), segment.get_end_offset()))
+                .collect()
+        };
+        if candidates.is_empty() {
+            return;
+        }
+
+        let mut offloaded = vec![];
+        for (base_offset, end_offset) in candidates {
+            match remote.offload(base_offset, end_offset).await {
+                Ok(_) => offloaded.push(base_offset),
+                Err(err) => {
+                    // segments must be offloaded in order, retry on next cycle
+                    error!(?err, base_offset, "failed to offload segment");
+                    break;
+                }
+            }
+        }
+
+        if !offloaded.is_empty() {
+            self.segments.remove_segments(&offloaded).await;
+            let read = self.segments.read().await;
+            self.replica_size.store_prev(read.occupied_memory());
+        }
+    }
+
     #[instrument(skip(self))]
     async fn enforce_size(&self) {
         let replica_size = self.replica_size.get();
         let max_partition_size = self.replica_config.max_partition_size.get();
+        #[cfg(feature = "tiered")]
+        let Some(max_partition_size) = self
+            .enforce_remote_size(replica_size, max_partition_size)
+            .await
+        else {
+            return;
+        };
         let excess = replica_size.saturating_sub(max_partition_size);
         if excess > 0 {
             let segment_size = self.replica_config.segment_max_bytes.get() as u64;
@@ -110,6 +172,29 @@ impl Cleaner {
         }
     }
 
+    /// remove oldest remote segments while replica, including remote tier, exceeds max partition size.
+    /// Remote segments are older than local ones, so they are removed first.
+    /// Return size left for local segments, None if remote segments couldn't be removed
+    #[cfg(feature = "tiered")]
+    async fn enforce_remote_size(&self, local_size: u64, max_partition_size: u64) -> Option<u64> {
+        let Some(remote) = &self.remote else {
+            return Some(max_partition_size);
+        };
+        let remote_size = remote.size().await;
+        let excess = (local_size + remote_size).saturating_sub(max_partition_size);
+        let mut freed = 0;
+        if excess > 0 {
+            match remote.remove_oldest(excess).await {
+                Ok(bytes) => freed = bytes,
+                Err(err) => {
+                    error!(?err, "failed to remove remote segments");
+                    return None;
+                }
+            }
+        }
+        Some(max_partition_size.saturating_sub(remote_size.saturating_sub(freed)))
+    }
+
     #[instrument(skip(self))]
     async fn enforce_ttl(&self) {
         let retention_secs =
@@ -129,6 +214,12 @@ impl Cleaner {
             let read = self.segments.read().await;
             self.replica_size.store_prev(read.occupied_memory());
         }
+        #[cfg(feature = "tiered")]
+        if let Some(remote) = &self.remote {
+            if let Err(err) = remote.enforce_ttl(&retention_secs).await {
+                error!(?err, "failed to remove expired remote segments");
+            }
+        }
     }
 }
 
@@ -286,6 +377,8 @@ mod tests {
             replica_config: replica_config.shared(),
             segments,
             replica_size,
+            #[cfg(feature = "tiered")]
+            remote: None,
             end_event: StickyEvent::shared(),
         }
     }
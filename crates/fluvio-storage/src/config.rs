@@ -2,13 +2,13 @@ use std::default::Default;
 use std::path::PathBuf;
 use std::fmt;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU32, AtomicU64};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64};
 
 use derive_builder::Builder;
 use fluvio_controlplane::replica::Replica;
 use serde::Deserialize;
 
-use fluvio_controlplane_metadata::topic::CleanupPolicy;
+use fluvio_controlplane_metadata::topic::{CleanupPolicy, FlushPolicy};
 use fluvio_types::defaults::{
     SPU_LOG_INDEX_MAX_BYTES, SPU_LOG_BASE_DIR, STORAGE_FLUSH_WRITE_COUNT, STORAGE_FLUSH_IDLE_MSEC,
     STORAGE_MAX_BATCH_SIZE, STORAGE_RETENTION_SECONDS, SPU_PARTITION_MAX_BYTES,
@@ -18,6 +18,7 @@ use fluvio_types::defaults::SPU_LOG_SEGMENT_MAX_BYTES;
 use fluvio_protocol::record::{Size, Size64};
 
 use crate::ReplicaStorageConfig;
+use crate::tiered::TieredStorageConfig;
 
 // Replica specific config
 #[derive(Builder, Clone, Debug, Eq, PartialEq, Deserialize)]
@@ -41,6 +42,9 @@ pub struct ReplicaConfig {
     #[builder(default = "default_flush_idle_msec()")]
     #[serde(default = "default_flush_idle_msec")]
     pub flush_idle_msec: Size,
+    #[builder(default)]
+    #[serde(default)]
+    pub flush_sync: bool, // if true, flush syncs records to disk, set by flush policy of topic
     #[builder(default = "default_max_batch_size()")]
     #[serde(default = "default_max_batch_size")]
     pub max_batch_size: Size,
@@ -53,6 +57,15 @@ pub struct ReplicaConfig {
     #[builder(default = "default_max_partition_size()")]
     #[serde(default = "default_max_partition_size")]
     pub max_partition_size: Size64,
+    #[builder(default)]
+    #[serde(default)]
+    pub tiered_storage: Option<TieredStorageConfig>,
+    #[builder(default)]
+    #[serde(default)]
+    pub verify_checksum_on_fetch: bool, // if true, verify crc of batches before they are sent
+    #[builder(default = "default_verify_checksum_on_load()")]
+    #[serde(default = "default_verify_checksum_on_load")]
+    pub verify_checksum_on_load: bool, // if true, verify crc of all batches when replica is loaded
 }
 
 impl fmt::Display for ReplicaConfig {
@@ -85,6 +98,21 @@ impl ReplicaStorageConfig for ReplicaConfig {
         {
             self.max_partition_size = max_partition_size;
         }
+        if let Some(flush_policy) = replica
+            .storage
+            .as_ref()
+            .and_then(|storage| storage.flush_policy)
+        {
+            let (flush_write_count, flush_idle_msec) = match flush_policy {
+                FlushPolicy::Os => (0, 0),
+                FlushPolicy::EveryWrite => (1, 0),
+                FlushPolicy::WriteCount(count) => (count, 0),
+                FlushPolicy::Interval(msec) => (0, msec),
+            };
+            self.flush_write_count = flush_write_count;
+            self.flush_idle_msec = flush_idle_msec;
+            self.flush_sync = true;
+        }
     }
 }
 
@@ -96,6 +124,10 @@ const fn default_update_hw() -> bool {
     true
 }
 
+const fn default_verify_checksum_on_load() -> bool {
+    true
+}
+
 const fn default_index_max_bytes() -> Size {
     SPU_LOG_INDEX_MAX_BYTES
 }
@@ -148,10 +180,14 @@ impl Default for ReplicaConfig {
             segment_max_bytes: default_segment_max_bytes(),
             flush_write_count: default_flush_write_count(),
             flush_idle_msec: default_flush_idle_msec(),
+            flush_sync: false,
             max_batch_size: default_max_batch_size(),
             retention_seconds: default_retention_seconds(),
             max_partition_size: default_max_partition_size(),
             update_hw: true,
+            tiered_storage: None,
+            verify_checksum_on_fetch: false,
+            verify_checksum_on_load: default_verify_checksum_on_load(),
         }
     }
 }
@@ -212,8 +248,25 @@ impl SharedConfigValue<AtomicU64> {
     }
 }
 
+impl SharedConfigValue<AtomicBool> {
+    pub fn new(value: bool) -> Self {
+        SharedConfigValue(AtomicBool::new(value))
+    }
+
+    #[inline(always)]
+    pub fn get(&self) -> bool {
+        self.0.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    #[inline(always)]
+    pub fn set(&self, value: bool) {
+        self.0.store(value, std::sync::atomic::Ordering::Relaxed)
+    }
+}
+
 pub type SharedConfigU32Value = SharedConfigValue<AtomicU32>;
 pub type SharedConfigU64Value = SharedConfigValue<AtomicU64>;
+pub type SharedConfigBoolValue = SharedConfigValue<AtomicBool>;
 
 /// Config that can be shared updated
 #[derive(Debug)]
@@ -224,10 +277,14 @@ pub struct SharedReplicaConfig {
     pub segment_max_bytes: SharedConfigU32Value,
     pub flush_write_count: SharedConfigU32Value,
     pub flush_idle_msec: SharedConfigU32Value,
+    pub flush_sync: SharedConfigBoolValue,
     pub max_batch_size: SharedConfigU32Value,
     pub update_hw: bool, // if true, enable hw update
     pub retention_seconds: SharedConfigU32Value,
     pub max_partition_size: SharedConfigU64Value,
+    pub tiered_storage: Option<TieredStorageConfig>,
+    pub verify_checksum_on_fetch: bool,
+    pub verify_checksum_on_load: bool,
 }
 
 impl From<ReplicaConfig> for SharedReplicaConfig {
@@ -239,14 +296,31 @@ impl From<ReplicaConfig> for SharedReplicaConfig {
             segment_max_bytes: SharedConfigU32Value::new(config.segment_max_bytes),
             flush_write_count: SharedConfigU32Value::new(config.flush_write_count),
             flush_idle_msec: SharedConfigU32Value::new(config.flush_idle_msec),
+            flush_sync: SharedConfigBoolValue::new(config.flush_sync),
             max_batch_size: SharedConfigU32Value::new(config.max_batch_size),
             update_hw: config.update_hw,
             retention_seconds: SharedConfigU32Value::new(config.retention_seconds),
             max_partition_size: SharedConfigU64Value::new(config.max_partition_size),
+            tiered_storage: config.tiered_storage,
+            verify_checksum_on_fetch: config.verify_checksum_on_fetch,
+            verify_checksum_on_load: config.verify_checksum_on_load,
         }
     }
 }
 
+impl SharedReplicaConfig {
+    /// update values which can be changed while replica is running
+    pub fn update(&self, config: &ReplicaConfig) {
+        self.segment_max_bytes.set(config.segment_max_bytes);
+        self.flush_write_count.set(config.flush_write_count);
+        self.flush_idle_msec.set(config.flush_idle_msec);
+        self.flush_sync.set(config.flush_sync);
+        self.max_batch_size.set(config.max_batch_size);
+        self.retention_seconds.set(config.retention_seconds);
+        self.max_partition_size.set(config.max_partition_size);
+    }
+}
+
 /// Storage wide configuration independent of replica
 #[derive(Builder, Debug, Clone)]
 pub struct StorageConfig {
@@ -270,4 +344,50 @@ mod tests {
 
         assert_eq!(ReplicaConfig::default(), config);
     }
+
+    #[test]
+    fn test_flush_policy_from_replica() {
+        use fluvio_controlplane_metadata::topic::TopicStorageConfig;
+
+        let mut replica = Replica::new(("topic", 0), 5000, vec![5000]);
+        let mut config = ReplicaConfig::default();
+        config.update_from_replica(&replica);
+        assert_eq!(config.flush_write_count, default_flush_write_count());
+        assert_eq!(config.flush_idle_msec, default_flush_idle_msec());
+        assert!(!config.flush_sync);
+
+        replica.storage = Some(TopicStorageConfig {
+            flush_policy: Some(FlushPolicy::EveryWrite),
+            ..Default::default()
+        });
+        config.update_from_replica(&replica);
+        assert_eq!(config.flush_write_count, 1);
+        assert_eq!(config.flush_idle_msec, 0);
+        assert!(config.flush_sync);
+
+        replica.storage = Some(TopicStorageConfig {
+            flush_policy: Some(FlushPolicy::Interval(200)),
+            ..Default::default()
+        });
+        config.update_from_replica(&replica);
+        assert_eq!(config.flush_write_count, 0);
+        assert_eq!(config.flush_idle_msec, 200);
+    }
+
+    #[test]
+    fn test_shared_config_update() {
+        let shared: SharedReplicaConfig = ReplicaConfig::default().into();
+        let mut replica = Replica::new(("topic", 0), 5000, vec![5000]);
+        replica.cleanup_policy = Some(CleanupPolicy::Segment(
+            fluvio_controlplane_metadata::topic::SegmentBasedPolicy {
+                time_in_seconds: 600,
+            },
+        ));
+        let mut config = ReplicaConfig::default();
+        config.update_from_replica(&replica);
+
+        shared.update(&config);
+        assert_eq!(shared.retention_seconds.get(), 600);
+        assert_eq!(shared.segment_max_bytes.get(), default_segment_max_bytes());
+    }
 }
@@ -13,6 +13,7 @@ use nix::Result as NixResult;
 use tracing::{debug, instrument, trace};
 
 use fluvio_future::fs::File;
+use fluvio_future::file_slice::AsyncFileSlice;
 
 use crate::batch::StorageBytesIterator;
 
@@ -78,6 +79,20 @@ impl StorageBytesIterator for FileBytesIterator {
     }
 }
 
+/// read content of file slice
+pub(crate) async fn read_file_slice(slice: &AsyncFileSlice) -> Result<Bytes, IoError> {
+    let fd = slice.fd();
+    let pos = slice.position() as i64;
+    let len = slice.len() as usize;
+    match unblock(move || pread(fd, pos, len))
+        .await
+        .map_err(|e| IoError::new(ErrorKind::Other, format!("pread error: {e:#?}")))?
+    {
+        ReadOutput::Some { buffer, .. } => Ok(buffer),
+        ReadOutput::Empty => Ok(Bytes::new()),
+    }
+}
+
 enum ReadOutput {
     Empty, // EOF, no bytes read
     Some { buffer: Bytes, eof: bool },
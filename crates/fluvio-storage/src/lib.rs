@@ -11,6 +11,8 @@ mod replica;
 pub mod segment;
 mod util;
 mod validator;
+pub mod repair;
+pub mod tiered;
 mod file;
 pub mod config;
 #[cfg(feature = "iterators")]
@@ -167,6 +169,25 @@ mod inner {
 
         /// permanently remove
         async fn remove(&self) -> Result<(), StorageError>;
+
+        /// offset of first corrupted batch, replica is fenced while it is set
+        fn get_corrupted_offset(&self) -> Option<Offset> {
+            None
+        }
+
+        /// discard corrupted records so they can be replicated again from leader.
+        /// return new leo if records were discarded
+        async fn discard_corrupted(&mut self) -> Result<Option<Offset>> {
+            Ok(None)
+        }
+
+        /// sync written records to disk regardless of flush policy
+        async fn sync(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        /// apply config changes while replica is running
+        fn update_config(&self, _replica_config: Self::ReplicaConfig) {}
     }
 
     #[cfg(test)]
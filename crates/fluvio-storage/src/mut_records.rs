@@ -1,19 +1,19 @@
 use std::io::Error as IoError;
 use std::io::Write;
-use std::os::fd::OwnedFd;
+use std::os::fd::{BorrowedFd, OwnedFd};
 use std::os::unix::prelude::AsRawFd;
 use std::os::unix::prelude::FromRawFd;
 use std::path::PathBuf;
 use std::path::Path;
 use std::fmt;
-use std::time::Instant;
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
 
 use tracing::instrument;
-use tracing::{debug, trace};
+use tracing::{debug, trace, warn};
 use futures_lite::io::AsyncWriteExt;
-use async_channel::Sender;
+use async_channel::{Receiver, Sender};
 use anyhow::Result;
 
 use fluvio_protocol::record::BatchRecords;
@@ -33,24 +33,22 @@ use crate::validator::LogValidator;
 
 pub const MESSAGE_LOG_EXTENSION: &str = "log";
 
-// Delay flush scheduling interrival time constant
-//   not something that a user should change
-// this is a delay put in because the scheduler switch
-// to the flush task is fast enough that it might beat
-// out waiting writes which should be preferred
-//const DELAY_FLUSH_SIA_MSEC: u64 = 3;
-
 /// Can append new batch to file
 pub struct MutFileRecords {
     base_offset: Offset,
     file: File,
     len: u32,
     max_len: u32,
-    _flush_policy: FlushPolicy,
-    write_count: u64,
+    option: Arc<SharedReplicaConfig>,
+    /// writes since policy last flushed
+    unflushed_writes: u32,
+    write_count: Arc<AtomicU64>,
+    /// write count which is known to be on disk
+    synced_count: Arc<AtomicU64>,
     flush_count: Arc<AtomicU32>,
     path: PathBuf,
-    _flush_time_tx: Option<Sender<Instant>>,
+    /// wakes up delayed flush task, task exits when this is dropped
+    flush_timer_tx: Option<Sender<()>>,
 }
 
 impl fmt::Debug for MutFileRecords {
@@ -73,7 +71,6 @@ fn get_flush_policy_from_config(option: &SharedReplicaConfig) -> FlushPolicy {
     } else {
         FlushPolicy::CountWrites {
             n_writes: option.flush_write_count.get(),
-            write_tracking: 0,
         }
     }
 }
@@ -95,11 +92,13 @@ impl MutFileRecords {
             file,
             len,
             max_len,
-            _flush_policy: get_flush_policy_from_config(&option),
-            write_count: 0,
+            option,
+            unflushed_writes: 0,
+            write_count: Arc::new(AtomicU64::new(0)),
+            synced_count: Arc::new(AtomicU64::new(0)),
             flush_count: Arc::new(AtomicU32::new(0)),
             path: log_path.to_owned(),
-            _flush_time_tx: None,
+            flush_timer_tx: None,
         })
     }
 
@@ -115,7 +114,11 @@ impl MutFileRecords {
     }
 
     pub(crate) async fn validate(&mut self, index: &MutLogIndex) -> Result<LogValidator> {
-        LogValidator::default_validate(&self.path, Some(index)).await
+        if self.option.verify_checksum_on_load {
+            LogValidator::checksum_validate(&self.path, Some(index)).await
+        } else {
+            LogValidator::default_validate(&self.path, Some(index)).await
+        }
     }
 
     /// get current file position
@@ -154,34 +157,34 @@ impl MutFileRecords {
 
             self.len += batch_len as u32;
             debug!(pos = self.get_pos(), "update pos",);
-            self.write_count = self.write_count.saturating_add(1);
+            self.write_count.fetch_add(1, Ordering::Relaxed);
+            self.unflushed_writes = self.unflushed_writes.saturating_add(1);
 
-            self.flush().await?;
-            debug!(
-                flush_count = self.flush_count(),
-                write_count = self.write_count,
-                "Flushing Now"
-            );
-
-            /*
-            match self.flush_policy.should_flush() {
+            // policy is read on every write, so topic changes apply to active segment
+            let flush_policy = get_flush_policy_from_config(&self.option);
+            match flush_policy.should_flush(self.unflushed_writes) {
                 FlushAction::NoFlush => {}
 
                 FlushAction::Now => {
-                    self.flush().await?;
+                    self.unflushed_writes = 0;
+                    // records are only synced to disk if topic opted in with flush policy
+                    if self.option.flush_sync.get() {
+                        self.sync().await?;
+                    } else {
+                        self.flush().await?;
+                    }
                     debug!(
                         flush_count = self.flush_count(),
-                        write_count = self.write_count,
+                        write_count = self.write_count(),
                         "Flushing Now"
                     );
                 }
 
                 FlushAction::Delay(delay_millis) => {
-                    debug!("  delay flush start {:?}", self.flush_policy);
-                    self.delay_flush(delay_millis).await?;
+                    self.unflushed_writes = 0;
+                    self.delay_flush(delay_millis)?;
                 }
             }
-            */
 
             Ok((true, batch_len, self.len))
         } else {
@@ -196,96 +199,81 @@ impl MutFileRecords {
         Ok(())
     }
 
+    /// sync written batches to disk, no op if everything is already synced
+    pub async fn sync(&mut self) -> Result<(), IoError> {
+        let write_count = self.write_count();
+        if self.synced_count.load(Ordering::Relaxed) >= write_count {
+            return Ok(());
+        }
+        self.file.sync_data().await?;
+        self.flush_count.fetch_add(1, Ordering::Relaxed);
+        self.synced_count.fetch_max(write_count, Ordering::Relaxed);
+        Ok(())
+    }
+
     pub fn flush_count(&self) -> u32 {
         self.flush_count.load(Ordering::Relaxed)
     }
 
-    /*
-    async fn delay_flush(&mut self, _delay_millis: u32) -> Result<(), IoError> {
-        //let delay_tgt = delay_millis as u64;
-
-        if self.flush_time_tx.is_none() {
-            // no task running so start one
-            let (tx, rx) = async_channel::bounded(100);
-            self.flush_time_tx = Some(tx);
-           // let delay_tgt = Duration::from_millis(delay_tgt);
-            let flush_count = self.flush_count.clone();
-
-            if let Err(e) = self.file.flush().await {
-                warn!("flush error {}", e);
-            } else {
-                let fc = flush_count.fetch_add(1, Ordering::Relaxed);
-                debug!(fc,"flush");
-            }
-
-
-            fluvio_future::task::spawn(async move {
-                let mut delay_dur = delay_tgt;
-                let mut write_time = Instant::now();
-
-                // when the mut_record struct is dropped self.flush_time_tx
-                // will also be closed and dropped, causing this while loop
-                // to end then exit the task
-                while !rx.is_closed() {
-                    if let Ok(wt) = rx.recv().await {
-                        // always grab initial write time, but also
-                        // guarantee a wait on a write time for flush
-                        // for wait times after the first delay runs
-                        write_time = wt;
-                    }
-                    //  A short fixed wait was added here to give a little
-                    //  breathing space to accumulate clusters of writes which
-                    //  arrive closely but not fast enough for this task loop
-                    //  between the first and following check for write times
-                    //  It prevents extra flushes and the time is still accounted for
-                    //  in the delay_dur calculation.
-                    timer::after(Duration::from_millis(DELAY_FLUSH_SIA_MSEC)).await;
-
-                    // Clear out any accumulated write times but don't wait
-                    // Update to latest accumulated write time
-
-                    // could use a mutex for last write time instead?
-                    // could bundle write time with f_sink inside a struct
-                    // held by the existing mutex, but that's more invasive
-                    while let Ok(wt) = rx.try_recv() {
-                        write_time = wt;
-                        debug!("update write time for delayed flush {:?}", write_time);
-                    }
-                    if write_time.elapsed() < delay_tgt {
-                        // calculate remaining delay time
-                        delay_dur = delay_tgt - write_time.elapsed();
-                    }
-                    debug!(
-                        "flush delay wait start delay:tgt {:?}:{:?}",
-                        delay_dur, delay_tgt
-                    );
-                    timer::after(delay_dur).await;
-
-                    debug!("delay flush: get lock");
-                    if let Err(e) = self.file.flush().await {
-                        warn!("flush error {}", e);
-                    } else {
-                        let fc = flush_count.fetch_add(1, Ordering::Relaxed);
-                        debug!(" - flushed: delay task flush cnt: {}", fc);
-                    }
-                }
-                debug!("delay_flush task exited");
-            });
+    fn write_count(&self) -> u64 {
+        self.write_count.load(Ordering::Relaxed)
+    }
 
+    /// make sure the write is synced within delay.
+    /// flush task is started on first delayed write, it syncs all writes made until it fires
+    fn delay_flush(&mut self, delay_millis: u32) -> Result<(), IoError> {
+        if self.flush_timer_tx.is_none() {
+            // task syncs through its own descriptor of the same file
+            let fd = unsafe { BorrowedFd::borrow_raw(self.file.as_raw_fd()) };
+            let file = File::from(std::fs::File::from(fd.try_clone_to_owned()?));
+            let (tx, rx) = async_channel::bounded(1);
+            fluvio_future::task::spawn(delay_flush_loop(
+                file,
+                rx,
+                Duration::from_millis(delay_millis as u64),
+                self.write_count.clone(),
+                self.synced_count.clone(),
+                self.flush_count.clone(),
+            ));
+            self.flush_timer_tx = Some(tx);
         }
-
-
-        // update running flush
-        if let Some(flush_tx) = &self.flush_time_tx {
-            if let Err(serr) = flush_tx.send(Instant::now()).await {
-                use std::io::{Error, ErrorKind};
-                warn!("Flush send error {}", serr);
-                return Err(Error::new(ErrorKind::Other, "flush send error"));
-            }
+        // flush is already pending if channel is full
+        if let Some(tx) = &self.flush_timer_tx {
+            let _ = tx.try_send(());
         }
         Ok(())
     }
-    */
+}
+
+/// sync file after delay whenever woken up by writes.
+/// ends when records are dropped
+async fn delay_flush_loop(
+    file: File,
+    rx: Receiver<()>,
+    delay: Duration,
+    write_count: Arc<AtomicU64>,
+    synced_count: Arc<AtomicU64>,
+    flush_count: Arc<AtomicU32>,
+) {
+    while rx.recv().await.is_ok() {
+        fluvio_future::timer::sleep(delay).await;
+        let written = write_count.load(Ordering::Relaxed);
+        if synced_count.load(Ordering::Relaxed) >= written {
+            continue;
+        }
+        if let Err(err) = file.sync_data().await {
+            warn!(%err, "delayed flush failed");
+            continue;
+        }
+        let count = flush_count.fetch_add(1, Ordering::Relaxed);
+        synced_count.fetch_max(written, Ordering::Relaxed);
+        debug!(
+            flush_count = count + 1,
+            write_count = written,
+            "Flushing after delay"
+        );
+    }
+    debug!("delay flush task exited");
 }
 
 impl FileRecords for MutFileRecords {
@@ -341,11 +329,10 @@ impl FileRecords for MutFileRecords {
 pub enum FlushPolicy {
     NoFlush,
     EveryWrite,
-    CountWrites { n_writes: u32, write_tracking: u32 },
+    CountWrites { n_writes: u32 },
     IdleFlush { delay_millis: u32 },
 }
 
-#[allow(unused)]
 enum FlushAction {
     NoFlush,
     Now,
@@ -355,21 +342,15 @@ enum FlushAction {
 impl FlushPolicy {
     /// Evaluates the flush policy and returns a flush action
     // to take
-    #[allow(unused)]
-    fn should_flush(&mut self) -> FlushAction {
+    fn should_flush(&self, unflushed_writes: u32) -> FlushAction {
         use FlushPolicy::{NoFlush, EveryWrite, CountWrites, IdleFlush};
         match self {
             NoFlush => FlushAction::NoFlush,
 
             EveryWrite => FlushAction::Now,
 
-            CountWrites {
-                n_writes: n_max,
-                write_tracking: wcount,
-            } => {
-                *wcount += 1;
-                if *wcount >= *n_max {
-                    *wcount = 0;
+            CountWrites { n_writes } => {
+                if unflushed_writes >= *n_writes {
                     return FlushAction::Now;
                 }
                 FlushAction::NoFlush
@@ -502,8 +483,7 @@ mod tests {
     // This Test configures policy to flush after every NUM_WRITES
     // and checks to see when the flush occurs relative to the write count
 
-    //#[fluvio_future::test]
-    #[allow(unused)]
+    #[fluvio_future::test]
     async fn test_write_records_count() {
         let test_dir = temp_dir().join("mut_records_word_count");
         ensure_new_dir(&test_dir).expect("new");
@@ -570,6 +550,85 @@ mod tests {
         assert_eq!(old_msg_sink.get_base_offset(), OFFSET);
     }
 
+    #[fluvio_future::test]
+    async fn test_sync_with_os_flush_policy() {
+        let test_dir = temp_dir().join("mut_records_sync_os_policy");
+        ensure_new_dir(&test_dir).expect("new");
+
+        const OFFSET: i64 = 100;
+
+        let options = ReplicaConfig {
+            base_dir: test_dir,
+            segment_max_bytes: 1000,
+            flush_write_count: 0,
+            flush_idle_msec: 0,
+            ..Default::default()
+        }
+        .shared();
+        let mut msg_sink = MutFileRecords::create(OFFSET, options)
+            .await
+            .expect("create");
+        let mut builder = BatchProducer::builder()
+            .base_offset(OFFSET)
+            .build()
+            .expect("build");
+
+        // writes are not synced by the policy
+        msg_sink.write_batch(&builder.batch()).await.expect("write");
+        msg_sink.write_batch(&builder.batch()).await.expect("write");
+        assert_eq!(msg_sink.flush_count(), 0);
+
+        // explicit sync covers all pending writes
+        msg_sink.sync().await.expect("sync");
+        assert_eq!(msg_sink.flush_count(), 1);
+
+        // nothing to sync
+        msg_sink.sync().await.expect("sync");
+        assert_eq!(msg_sink.flush_count(), 1);
+    }
+
+    #[fluvio_future::test]
+    async fn test_flush_policy_updated() {
+        let test_dir = temp_dir().join("mut_records_flush_policy_updated");
+        ensure_new_dir(&test_dir).expect("new");
+
+        const OFFSET: i64 = 100;
+
+        let mut config = ReplicaConfig {
+            base_dir: test_dir,
+            segment_max_bytes: 1000,
+            ..Default::default()
+        };
+        let options = config.clone().shared();
+        let mut msg_sink = MutFileRecords::create(OFFSET, options.clone())
+            .await
+            .expect("create");
+        let mut builder = BatchProducer::builder()
+            .base_offset(OFFSET)
+            .build()
+            .expect("build");
+
+        // default flushes every write
+        msg_sink.write_batch(&builder.batch()).await.expect("write");
+        assert_eq!(msg_sink.flush_count(), 1);
+
+        // policy of topic applies to next write
+        config.flush_write_count = 0;
+        config.flush_sync = true;
+        options.update(&config);
+        msg_sink.write_batch(&builder.batch()).await.expect("write");
+        assert_eq!(msg_sink.flush_count(), 1);
+
+        config.flush_write_count = 1;
+        options.update(&config);
+        msg_sink.write_batch(&builder.batch()).await.expect("write");
+        assert_eq!(msg_sink.flush_count(), 2);
+
+        // synced by policy, nothing left to sync
+        msg_sink.sync().await.expect("sync");
+        assert_eq!(msg_sink.flush_count(), 2);
+    }
+
     // This test configures policy to flush after some write idle time
     // and checks to see when the flush occurs relative to the write count
 
@@ -580,8 +639,7 @@ mod tests {
     // The test still verifies that flushes on writes have occurred within the
     // expected timeframe
     #[cfg(not(target_os = "macos"))]
-    #[allow(unused)]
-    //#[fluvio_future::test]
+    #[fluvio_future::test]
     async fn test_write_records_idle_delay() {
         use std::time::Duration;
         use fluvio_future::timer;
@@ -89,8 +89,13 @@ impl FileRecordsSlice {
         self.base_offset
     }
 
-    pub async fn validate(&self, index: &LogIndex) -> Result<LogValidator> {
-        LogValidator::default_validate(&self.path, Some(index)).await
+    /// validate log, crc of batches is verified only if `verify_checksum` is set
+    pub async fn validate(&self, index: &LogIndex, verify_checksum: bool) -> Result<LogValidator> {
+        if verify_checksum {
+            LogValidator::checksum_validate(&self.path, Some(index)).await
+        } else {
+            LogValidator::default_validate(&self.path, Some(index)).await
+        }
     }
 
     pub fn modified_time_elapsed(&self) -> Result<Duration, SystemTimeError> {
@@ -0,0 +1,539 @@
+//! Offline inspection and repair of replica directories.
+//!
+//! These operations are meant to be run against a replica directory while the owning SPU is
+//! stopped. They are built on top of the [LogValidator] and never remove a segment; at worst
+//! they truncate the unreadable tail of the active segment.
+
+use std::ffi::OsStr;
+use std::io::{Cursor, Error as IoError, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow};
+use bytes::Buf;
+use futures_lite::io::AsyncReadExt;
+use tracing::{debug, info, warn};
+
+use fluvio_future::fs::{metadata, remove_file, util as file_util};
+use fluvio_protocol::record::{Offset, Size};
+
+use crate::batch_header::BatchHeaderStream;
+use crate::checkpoint::REPLICATION_CHECKPOINT;
+use crate::config::SharedReplicaConfig;
+use crate::index::{LogIndex, EXTENSION as INDEX_EXTENSION};
+use crate::mut_index::MutLogIndex;
+use crate::mut_records::MESSAGE_LOG_EXTENSION;
+use crate::util::{generate_file_name, log_path_get_offset};
+use crate::validator::{LogValidationError, LogValidator};
+
+/// Outcome of repairing a single segment log
+#[derive(Debug, Default)]
+pub struct SegmentRepairReport {
+    pub base_offset: Offset,
+    /// log end offset after repair
+    pub leo: Offset,
+    pub batches: u32,
+    pub original_len: u64,
+    pub repaired_len: u64,
+}
+
+impl SegmentRepairReport {
+    /// true if corrupt bytes were removed from the segment
+    pub fn is_truncated(&self) -> bool {
+        self.repaired_len < self.original_len
+    }
+}
+
+/// Truncate segment log to the end of last valid batch.
+///
+/// Only batch decoding errors are recoverable, which is the case for a partially written tail.
+/// If `dry_run` is set, the log is validated but not modified.
+pub async fn truncate_corrupt_tail(
+    log_path: impl AsRef<Path>,
+    dry_run: bool,
+) -> Result<SegmentRepairReport> {
+    let log_path = log_path.as_ref();
+    let original_len = metadata(log_path).await?.len();
+
+    let validator = LogValidator::default_validate::<LogIndex>(log_path, None).await?;
+
+    let mut report = SegmentRepairReport {
+        base_offset: validator.base_offset,
+        leo: validator.leo(),
+        batches: validator.batches,
+        original_len,
+        repaired_len: original_len,
+    };
+
+    match validator.error {
+        None => {
+            debug!(?log_path, "segment is valid, nothing to repair");
+        }
+        Some(LogValidationError::BatchDecoding(err)) => {
+            let valid_len = validator.last_valid_file_pos as u64;
+            warn!(?log_path, %err, valid_len, original_len, "found corrupt tail");
+            report.repaired_len = valid_len;
+            if !dry_run {
+                let file = file_util::open_read_write(log_path).await?;
+                file.set_len(valid_len).await?;
+                file.sync_all().await?;
+                info!(?log_path, valid_len, "segment truncated");
+            }
+        }
+        Some(err) => return Err(err.into()),
+    }
+
+    Ok(report)
+}
+
+/// Rebuild segment index from contents of the log.
+///
+/// Existing index is discarded. Entries are written with same interval as the SPU would
+/// have written them, given `option`. Returns number of batches scanned.
+pub async fn rebuild_index(
+    log_path: impl AsRef<Path>,
+    option: Arc<SharedReplicaConfig>,
+) -> Result<u32> {
+    let log_path = log_path.as_ref();
+    let base_offset = log_path_get_offset(log_path)?;
+
+    let index_path = generate_file_name(&option.base_dir, base_offset, INDEX_EXTENSION);
+    if let Err(err) = remove_file(&index_path).await {
+        if err.kind() != ErrorKind::NotFound {
+            return Err(err.into());
+        }
+    }
+
+    let mut index = MutLogIndex::create(base_offset, option).await?;
+    let mut stream = BatchHeaderStream::open(log_path).await?;
+    let mut batches: u32 = 0;
+    let mut last_relative_offset: Option<Size> = None;
+
+    while let Some(batch_pos) = stream.try_next().await? {
+        let pos = batch_pos.get_pos();
+        let batch_len = stream.get_pos() - pos;
+        let batch = batch_pos.inner();
+
+        let relative_offset = batch.get_base_offset() - base_offset;
+        if relative_offset < 0 {
+            return Err(anyhow!(
+                "batch offset: {} is less than segment base offset: {}",
+                batch.get_base_offset(),
+                base_offset
+            ));
+        }
+        let relative_offset = relative_offset as Size;
+        if let Some(last) = last_relative_offset {
+            if relative_offset <= last {
+                return Err(anyhow!(
+                    "batch offsets are not increasing at file pos: {pos}, repair the log first"
+                ));
+            }
+        }
+
+        index.write_index(relative_offset, pos, batch_len).await?;
+        last_relative_offset = Some(relative_offset);
+        batches += 1;
+    }
+
+    index.shrink().await?;
+    info!(?index_path, batches, "index rebuilt");
+    Ok(batches)
+}
+
+/// Discrepancy found when comparing checkpoint against segments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplicaIssue {
+    /// high watermark checkpoint does not exist
+    MissingCheckpoint,
+    /// checkpoint file doesn't contain a valid offset
+    InvalidCheckpoint(String),
+    /// high watermark is beyond what is stored in the segments
+    HwBeyondLeo { hw: Offset, leo: Offset },
+    /// there are missing offsets between two segments
+    SegmentGap { expected: Offset, found: Offset },
+    /// segment log contains corrupt bytes
+    CorruptSegment { base_offset: Offset, error: String },
+    /// index doesn't match batch positions in the log
+    InvalidIndex { base_offset: Offset, error: String },
+}
+
+/// Result of verifying a replica directory
+#[derive(Debug, Default)]
+pub struct ReplicaVerifyReport {
+    pub segments: Vec<PathBuf>,
+    pub hw: Option<Offset>,
+    pub leo: Option<Offset>,
+    pub issues: Vec<ReplicaIssue>,
+}
+
+impl ReplicaVerifyReport {
+    pub fn is_healthy(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// corruption errors of segment starting at `base_offset`
+    pub fn segment_corruption(&self, base_offset: Offset) -> Vec<&str> {
+        self.issues
+            .iter()
+            .filter_map(|issue| match issue {
+                ReplicaIssue::CorruptSegment {
+                    base_offset: offset,
+                    error,
+                } if *offset == base_offset => Some(error.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// check if index of segment starting at `base_offset` needs to be rebuilt
+    pub fn has_invalid_index(&self, base_offset: Offset) -> bool {
+        self.issues.iter().any(|issue| {
+            matches!(
+                issue,
+                ReplicaIssue::InvalidIndex { base_offset: offset, .. } if *offset == base_offset
+            )
+        })
+    }
+}
+
+/// base offset of segment from name of its log
+pub fn segment_base_offset(log_path: impl AsRef<Path>) -> Result<Offset> {
+    Ok(log_path_get_offset(log_path)?)
+}
+
+/// find all segment logs in the replica directory sorted by base offset
+pub fn list_segment_logs(replica_dir: impl AsRef<Path>) -> Result<Vec<PathBuf>, IoError> {
+    let mut logs: Vec<(Offset, PathBuf)> = replica_dir
+        .as_ref()
+        .read_dir()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file() && path.extension() == Some(OsStr::new(MESSAGE_LOG_EXTENSION))
+        })
+        .filter_map(|path| log_path_get_offset(&path).ok().map(|offset| (offset, path)))
+        .collect();
+    logs.sort_unstable_by_key(|(offset, _)| *offset);
+    Ok(logs.into_iter().map(|(_, path)| path).collect())
+}
+
+/// Verify that segments are contiguous and high watermark checkpoint is consistent with them
+pub async fn verify_replica(replica_dir: impl AsRef<Path>) -> Result<ReplicaVerifyReport> {
+    let replica_dir = replica_dir.as_ref();
+    let mut report = ReplicaVerifyReport {
+        segments: list_segment_logs(replica_dir)?,
+        ..Default::default()
+    };
+
+    let segments = report.segments.clone();
+    let mut expected_base: Option<Offset> = None;
+    for (i, log_path) in segments.iter().enumerate() {
+        let base_offset = log_path_get_offset(log_path)?;
+        // index of active segment is preallocated, it can only be checked by the SPU
+        let is_active = i + 1 == segments.len();
+        let index = if is_active {
+            None
+        } else {
+            match LogIndex::open_from_path(log_path.with_extension(INDEX_EXTENSION)).await {
+                Ok(index) => Some(index),
+                Err(err) => {
+                    report.issues.push(ReplicaIssue::InvalidIndex {
+                        base_offset,
+                        error: err.to_string(),
+                    });
+                    None
+                }
+            }
+        };
+        let validator = LogValidator::checksum_validate(log_path, index.as_ref()).await?;
+
+        if let Some(expected) = expected_base {
+            if expected != base_offset {
+                report.issues.push(ReplicaIssue::SegmentGap {
+                    expected,
+                    found: base_offset,
+                });
+            }
+        }
+        if let Some(err) = &validator.error {
+            report.issues.push(ReplicaIssue::CorruptSegment {
+                base_offset,
+                error: err.to_string(),
+            });
+        }
+        if let Some(err) = &validator.checksum_error {
+            report.issues.push(ReplicaIssue::CorruptSegment {
+                base_offset,
+                error: err.to_string(),
+            });
+        }
+        if let Some(err) = &validator.index_error {
+            report.issues.push(ReplicaIssue::InvalidIndex {
+                base_offset,
+                error: err.to_string(),
+            });
+        }
+
+        expected_base = Some(validator.leo());
+        report.leo = Some(validator.leo());
+    }
+
+    match read_checkpoint(replica_dir.join(REPLICATION_CHECKPOINT)).await {
+        Ok(hw) => {
+            report.hw = Some(hw);
+            if let Some(leo) = report.leo {
+                if hw > leo {
+                    report.issues.push(ReplicaIssue::HwBeyondLeo { hw, leo });
+                }
+            }
+        }
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            report.issues.push(ReplicaIssue::MissingCheckpoint);
+        }
+        Err(err) => {
+            report
+                .issues
+                .push(ReplicaIssue::InvalidCheckpoint(err.to_string()));
+        }
+    }
+
+    Ok(report)
+}
+
+/// read checkpoint without creating it
+async fn read_checkpoint(path: impl AsRef<Path>) -> Result<Offset, IoError> {
+    let mut file = file_util::open(path).await?;
+    let mut contents = Vec::new();
+    file.read_to_end(&mut contents).await?;
+    if contents.len() != 8 {
+        return Err(IoError::new(
+            ErrorKind::InvalidData,
+            format!("expected 8 bytes but found {}", contents.len()),
+        ));
+    }
+    Ok(Cursor::new(contents).get_i64())
+}
+
+#[cfg(test)]
+#[cfg(feature = "fixture")]
+mod tests {
+
+    use std::env::temp_dir;
+
+    use flv_util::fixture::ensure_new_dir;
+    use futures_lite::io::AsyncWriteExt;
+
+    use crate::config::ReplicaConfig;
+    use crate::fixture::BatchProducer;
+    use crate::index::{Index, INDEX_ENTRY_SIZE};
+    use crate::mut_records::MutFileRecords;
+    use crate::records::FileRecords;
+
+    use super::*;
+
+    /// write segment with `batches` of 2 records each, returns path of the log
+    async fn write_segment(
+        base_offset: Offset,
+        batches: u16,
+        option: Arc<SharedReplicaConfig>,
+    ) -> PathBuf {
+        let mut msg_sink = MutFileRecords::create(base_offset, option)
+            .await
+            .expect("create");
+        let mut builder = BatchProducer::builder()
+            .base_offset(base_offset)
+            .build()
+            .expect("build");
+        for _ in 0..batches {
+            msg_sink
+                .write_batch(&builder.batch_records(2))
+                .await
+                .expect("write");
+        }
+        msg_sink.flush().await.expect("flush");
+        msg_sink.get_path().to_owned()
+    }
+
+    #[fluvio_future::test]
+    async fn test_truncate_corrupt_tail() {
+        const BASE_OFFSET: Offset = 100;
+
+        let test_dir = temp_dir().join("repair_truncate_tail");
+        ensure_new_dir(&test_dir).expect("new");
+
+        let option = ReplicaConfig {
+            base_dir: test_dir,
+            segment_max_bytes: 1000,
+            ..Default::default()
+        }
+        .shared();
+
+        let mut msg_sink = MutFileRecords::create(BASE_OFFSET, option)
+            .await
+            .expect("create");
+        let mut builder = BatchProducer::builder()
+            .base_offset(BASE_OFFSET)
+            .build()
+            .expect("build");
+        msg_sink
+            .write_batch(&builder.batch_records(3))
+            .await
+            .expect("write");
+        msg_sink.flush().await.expect("flush");
+        let log_path = msg_sink.get_path().to_owned();
+        drop(msg_sink);
+
+        let valid_len = std::fs::metadata(&log_path).expect("metadata").len();
+
+        let mut file = fluvio_future::fs::util::open_read_append(&log_path)
+            .await
+            .expect("open");
+        file.write_all(&[0x01, 0x02, 0x03])
+            .await
+            .expect("write junk");
+        file.flush().await.expect("flush");
+        drop(file);
+
+        let report = truncate_corrupt_tail(&log_path, true)
+            .await
+            .expect("dry run");
+        assert!(report.is_truncated());
+        assert_eq!(
+            std::fs::metadata(&log_path).expect("metadata").len(),
+            valid_len + 3
+        );
+
+        let report = truncate_corrupt_tail(&log_path, false)
+            .await
+            .expect("repair");
+        assert_eq!(report.leo, BASE_OFFSET + 3);
+        assert_eq!(report.repaired_len, valid_len);
+        assert_eq!(
+            std::fs::metadata(&log_path).expect("metadata").len(),
+            valid_len
+        );
+    }
+
+    #[fluvio_future::test]
+    async fn test_rebuild_index() {
+        const BASE_OFFSET: Offset = 100;
+
+        let test_dir = temp_dir().join("repair_rebuild_index");
+        ensure_new_dir(&test_dir).expect("new");
+
+        let option = ReplicaConfig {
+            base_dir: test_dir,
+            index_max_interval_bytes: 0,
+            ..Default::default()
+        }
+        .shared();
+
+        let log_path = write_segment(BASE_OFFSET, 3, option.clone()).await;
+
+        let mut positions = vec![];
+        let mut stream = BatchHeaderStream::open(&log_path).await.expect("open");
+        while let Some(batch_pos) = stream.try_next().await.expect("next") {
+            positions.push(batch_pos.get_pos());
+        }
+        assert_eq!(positions.len(), 3);
+
+        let batches = rebuild_index(&log_path, option.clone())
+            .await
+            .expect("rebuild");
+        assert_eq!(batches, 3);
+
+        let index = LogIndex::open_from_path(log_path.with_extension(INDEX_EXTENSION))
+            .await
+            .expect("open index");
+        assert_eq!(index.len(), 3 * INDEX_ENTRY_SIZE);
+        for (i, pos) in positions.into_iter().enumerate() {
+            let relative_offset = i as Size * 2;
+            assert_eq!(
+                index.find_offset(relative_offset),
+                Some((relative_offset, pos))
+            );
+        }
+        drop(index);
+
+        // rebuilding again replaces existing index
+        let batches = rebuild_index(&log_path, option).await.expect("rebuild");
+        assert_eq!(batches, 3);
+        let index = LogIndex::open_from_path(log_path.with_extension(INDEX_EXTENSION))
+            .await
+            .expect("open index");
+        assert_eq!(index.len(), 3 * INDEX_ENTRY_SIZE);
+    }
+
+    #[fluvio_future::test]
+    async fn test_verify_replica() {
+        let test_dir = temp_dir().join("repair_verify_replica");
+        ensure_new_dir(&test_dir).expect("new");
+
+        let option = ReplicaConfig {
+            base_dir: test_dir.clone(),
+            index_max_interval_bytes: 0,
+            ..Default::default()
+        }
+        .shared();
+
+        let first = write_segment(0, 2, option.clone()).await;
+        rebuild_index(&first, option.clone())
+            .await
+            .expect("rebuild");
+        write_segment(4, 1, option.clone()).await;
+
+        let report = verify_replica(&test_dir).await.expect("verify");
+        assert_eq!(report.segments.len(), 2);
+        assert_eq!(report.leo, Some(6));
+        assert_eq!(report.hw, None);
+        assert_eq!(report.issues, vec![ReplicaIssue::MissingCheckpoint]);
+
+        let checkpoint = test_dir.join(REPLICATION_CHECKPOINT);
+        std::fs::write(&checkpoint, 6_i64.to_be_bytes()).expect("write checkpoint");
+        let report = verify_replica(&test_dir).await.expect("verify");
+        assert_eq!(report.hw, Some(6));
+        assert!(report.issues.is_empty(), "{:?}", report.issues);
+
+        std::fs::write(&checkpoint, 10_i64.to_be_bytes()).expect("write checkpoint");
+        let report = verify_replica(&test_dir).await.expect("verify");
+        assert_eq!(
+            report.issues,
+            vec![ReplicaIssue::HwBeyondLeo { hw: 10, leo: 6 }]
+        );
+
+        std::fs::write(&checkpoint, [0x01, 0x02]).expect("write checkpoint");
+        let report = verify_replica(&test_dir).await.expect("verify");
+        assert!(matches!(
+            report.issues.as_slice(),
+            [ReplicaIssue::InvalidCheckpoint(_)]
+        ));
+
+        // segment after the gap becomes active, index of previous segment is checked
+        std::fs::write(&checkpoint, 6_i64.to_be_bytes()).expect("write checkpoint");
+        let second = write_segment(10, 1, option.clone()).await;
+        let report = verify_replica(&test_dir).await.expect("verify");
+        assert_eq!(report.leo, Some(12));
+        assert!(report.issues.contains(&ReplicaIssue::SegmentGap {
+            expected: 6,
+            found: 10
+        }));
+        assert!(report.has_invalid_index(4));
+        assert!(!report.has_invalid_index(0));
+        assert!(report.segment_corruption(4).is_empty());
+
+        // corrupt bytes in older segment are reported
+        let mut file = fluvio_future::fs::util::open_read_append(&first)
+            .await
+            .expect("open");
+        file.write_all(&[0x01, 0x02, 0x03])
+            .await
+            .expect("write junk");
+        file.flush().await.expect("flush");
+        drop(file);
+        let report = verify_replica(&test_dir).await.expect("verify");
+        assert!(!report.segment_corruption(0).is_empty());
+        assert!(report.segment_corruption(10).is_empty());
+        assert_eq!(report.segments.last(), Some(&second));
+    }
+}
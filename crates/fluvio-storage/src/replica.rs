@@ -1,15 +1,16 @@
 use std::cmp::min;
 use std::mem;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock as StdRwLock};
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
 
-use tracing::{debug, trace, warn, instrument, info};
+use tracing::{debug, trace, warn, instrument, info, error};
 use async_trait::async_trait;
 use anyhow::Result;
+use blocking::unblock;
 
 use fluvio_future::file_slice::AsyncFileSlice;
 use fluvio_protocol::Encoder;
-use fluvio_future::fs::{create_dir_all, remove_dir_all};
+use fluvio_future::fs::{create_dir_all, metadata, remove_dir_all};
 use fluvio_protocol::link::ErrorCode;
 use fluvio_spu_schema::Isolation;
 use fluvio_protocol::record::{Offset, ReplicaKey, Size, Size64};
@@ -17,12 +18,17 @@ use fluvio_protocol::record::{Batch, BatchRecords};
 use fluvio_protocol::record::RecordSet;
 
 use crate::{OffsetInfo, checkpoint::CheckPoint};
+use crate::batch::BatchChecksumError;
+use crate::checkpoint::REPLICATION_CHECKPOINT;
 use crate::segments::SharedSegments;
 use crate::segment::MutableSegment;
 use crate::config::{ReplicaConfig, SharedReplicaConfig, StorageConfig};
 use crate::ReplicaSlice;
 use crate::{StorageError, ReplicaStorage};
 use crate::cleaner::Cleaner;
+#[cfg(feature = "tiered")]
+use crate::tiered::RemoteTier;
+use crate::validator::verify_slice_checksum;
 
 /// Replica is public abstraction for commit log which are distributed.
 /// Internally it is stored as list of segments.  Each segment contains finite sets of record batches.
@@ -39,6 +45,12 @@ pub struct FileReplica {
     commit_checkpoint: CheckPoint<Offset>,
     cleaner: Arc<Cleaner>,
     size: Arc<ReplicaSize>,
+    #[cfg(feature = "tiered")]
+    remote: Option<Arc<RemoteTier>>,
+    /// first corrupted batch found, replica is fenced while this is set
+    checksum_error: StdRwLock<Option<BatchChecksumError>>,
+    /// local batches before this offset had crc verified, they are not verified again on fetch
+    verified_offset: AtomicI64,
 }
 
 #[derive(Debug, Default)]
@@ -82,8 +94,15 @@ impl ReplicaStorage for FileReplica {
         self.active_segment.get_end_offset()
     }
 
-    /// earliest offset
+    /// earliest offset, including segments offloaded to remote tier
     fn get_log_start_offset(&self) -> Offset {
+        #[cfg(feature = "tiered")]
+        if let Some(remote) = &self.remote {
+            let remote_min_offset = remote.min_offset();
+            if remote_min_offset >= 0 {
+                return remote_min_offset;
+            }
+        }
         let min_base_offset = self.prev_segments.min_offset();
         if min_base_offset < 0 {
             self.active_segment.get_base_offset()
@@ -185,6 +204,13 @@ impl ReplicaStorage for FileReplica {
 
     #[instrument(skip(self))]
     async fn remove(&self) -> Result<(), StorageError> {
+        #[cfg(feature = "tiered")]
+        if let Some(remote) = &self.remote {
+            remote
+                .remove_all()
+                .await
+                .map_err(|err| StorageError::Other(format!("remote tier removal: {err}")))?;
+        }
         remove_dir_all(&self.option.base_dir)
             .await
             .map_err(StorageError::Io)?;
@@ -192,6 +218,26 @@ impl ReplicaStorage for FileReplica {
         self.cleaner.shutdown();
         Ok(())
     }
+
+    fn get_corrupted_offset(&self) -> Option<Offset> {
+        self.checksum_error()
+            .map(|checksum_error| checksum_error.base_offset)
+    }
+
+    async fn discard_corrupted(&mut self) -> Result<Option<Offset>> {
+        self.discard_corrupted_segments().await
+    }
+
+    #[instrument(skip(self))]
+    async fn sync(&mut self) -> Result<()> {
+        self.active_segment.sync().await?;
+        Ok(())
+    }
+
+    fn update_config(&self, replica_config: Self::ReplicaConfig) {
+        debug!(path = %self.option.base_dir.display(), "updating replica config");
+        self.option.update(&replica_config);
+    }
 }
 
 impl FileReplica {
@@ -222,9 +268,8 @@ impl FileReplica {
     where
         S: AsRef<str> + Send + 'static,
     {
-        let replica_dir = replica_config
-            .base_dir
-            .join(replica_dir_name(topic, partition));
+        let dir_name = replica_dir_name(topic, partition);
+        let replica_dir = replica_config.base_dir.join(&dir_name);
 
         info!("creating rep dir: {}", replica_dir.display());
         debug!("replica config: {:?}", replica_config);
@@ -252,10 +297,30 @@ impl FileReplica {
             MutableSegment::create(base_offset, shared_config.clone()).await?
         };
 
+        let checksum_error = match segments.read().await.first_checksum_error() {
+            Some(checksum_error) => Some(checksum_error),
+            None => active_segment.checksum_error().cloned(),
+        };
+        if let Some(checksum_error) = &checksum_error {
+            error!(%checksum_error, "replica contains corrupted batch, fencing");
+        }
+        let verified_offset = if shared_config.verify_checksum_on_load {
+            active_segment.get_end_offset()
+        } else {
+            match segments.min_offset() {
+                min_offset if min_offset < 0 => active_segment.get_base_offset(),
+                min_offset => min_offset,
+            }
+        };
+
         let last_base_offset = active_segment.get_base_offset();
 
-        let mut commit_checkpoint: CheckPoint<Offset> =
-            CheckPoint::create(shared_config.clone(), "replication.chk", last_base_offset).await?;
+        let mut commit_checkpoint: CheckPoint<Offset> = CheckPoint::create(
+            shared_config.clone(),
+            REPLICATION_CHECKPOINT,
+            last_base_offset,
+        )
+        .await?;
 
         // ensure checkpoint is valid
         let hw = *commit_checkpoint.get_offset();
@@ -271,11 +336,27 @@ impl FileReplica {
         let size = Arc::new(ReplicaSize::default());
         size.store_active(active_segment.occupied_memory());
 
+        #[cfg(feature = "tiered")]
+        let remote = match &shared_config.tiered_storage {
+            Some(tiered_config) => Some(Arc::new(
+                RemoteTier::load(tiered_config, dir_name, shared_config.clone()).await?,
+            )),
+            None => None,
+        };
+        #[cfg(not(feature = "tiered"))]
+        if shared_config.tiered_storage.is_some() {
+            return Err(anyhow::anyhow!(
+                "tiered storage of replica: {dir_name} requires `tiered` feature"
+            ));
+        }
+
         let cleaner = Cleaner::start_new(
             storage_config,
             shared_config.clone(),
             segments.clone(),
             size.clone(),
+            #[cfg(feature = "tiered")]
+            remote.clone(),
         );
 
         Ok(Self {
@@ -287,9 +368,99 @@ impl FileReplica {
             commit_checkpoint,
             cleaner,
             size,
+            #[cfg(feature = "tiered")]
+            remote,
+            checksum_error: StdRwLock::new(checksum_error),
+            verified_offset: AtomicI64::new(verified_offset),
         })
     }
 
+    /// first corrupted batch found either when replica was loaded or on fetch.
+    /// While it is set, records from the corrupted batch on are not read
+    pub fn checksum_error(&self) -> Option<BatchChecksumError> {
+        self.checksum_error
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
+    pub fn is_fenced(&self) -> bool {
+        self.checksum_error().is_some()
+    }
+
+    fn fence(&self, checksum_error: BatchChecksumError) {
+        error!(%checksum_error, path = %self.option.base_dir.display(), "fencing replica");
+        let mut guard = self
+            .checksum_error
+            .write()
+            .unwrap_or_else(|err| err.into_inner());
+        match guard.as_ref() {
+            Some(existing) if existing.base_offset <= checksum_error.base_offset => {}
+            _ => *guard = Some(checksum_error),
+        }
+    }
+
+    /// Discard records starting from the segment containing corrupted batch,
+    /// so they can be fetched again from the leader.
+    /// Only followers should do this. Returns new leo if replica was fenced.
+    #[instrument(skip(self))]
+    async fn discard_corrupted_segments(&mut self) -> Result<Option<Offset>> {
+        let checksum_error = match self.checksum_error() {
+            Some(checksum_error) => checksum_error,
+            None => return Ok(None),
+        };
+        let corrupted_offset = checksum_error.base_offset;
+
+        let active_base_offset = self.active_segment.get_base_offset();
+        let segment_base_offset = if corrupted_offset >= active_base_offset {
+            active_base_offset
+        } else {
+            let segments = self.prev_segments.read().await;
+            match segments.find_segment(corrupted_offset) {
+                Some((base_offset, _)) => *base_offset,
+                None => {
+                    return Err(StorageError::Other(format!(
+                        "corrupted offset: {corrupted_offset} is not in local segments"
+                    ))
+                    .into())
+                }
+            }
+        };
+        info!(
+            corrupted_offset,
+            segment_base_offset, "discarding records from corrupted segment"
+        );
+
+        let later_segments = self
+            .prev_segments
+            .read()
+            .await
+            .find_from(segment_base_offset);
+        self.prev_segments.remove_segments(&later_segments).await;
+
+        // active segment is either the corrupted segment or after it
+        self.active_segment.remove_files().await?;
+        self.active_segment =
+            MutableSegment::create(segment_base_offset, self.option.clone()).await?;
+
+        let leo = self.get_leo();
+        if self.get_hw() > leo {
+            self.commit_checkpoint.write(leo).await?;
+        }
+        // records fetched again are verified
+        self.verified_offset.fetch_min(leo, Ordering::SeqCst);
+        self.size
+            .store_prev(self.prev_segments.read().await.occupied_memory());
+        self.size
+            .store_active(self.active_segment.occupied_memory());
+        *self
+            .checksum_error
+            .write()
+            .unwrap_or_else(|err| err.into_inner()) = None;
+        info!(leo, "corrupted records discarded");
+        Ok(Some(leo))
+    }
+
     /// clear the any holding directory for replica
     #[instrument(skip(replica, option))]
     pub async fn clear(replica: &ReplicaKey, option: &SharedReplicaConfig) {
@@ -303,6 +474,41 @@ impl FileReplica {
         }
     }
 
+    /// move directory and remote segments of a replica to another replica of the same SPU.
+    /// Nothing is moved if source doesn't exist or target already exists, return true if moved
+    #[instrument(skip(option))]
+    pub async fn rename(
+        from: &ReplicaKey,
+        to: &ReplicaKey,
+        option: &ReplicaConfig,
+    ) -> Result<bool> {
+        let from_name = replica_dir_name(&from.topic, from.partition);
+        let to_name = replica_dir_name(&to.topic, to.partition);
+        let from_dir = option.base_dir.join(&from_name);
+        let to_dir = option.base_dir.join(&to_name);
+        if metadata(&from_dir).await.is_err() || metadata(&to_dir).await.is_ok() {
+            debug!(
+                from = %from_dir.display(),
+                to = %to_dir.display(),
+                "replica dir not moved"
+            );
+            return Ok(false);
+        }
+
+        #[cfg(feature = "tiered")]
+        if let Some(tiered_config) = &option.tiered_storage {
+            RemoteTier::move_prefix(tiered_config, &from_name, &to_name).await?;
+        }
+        let (source, target) = (from_dir.clone(), to_dir.clone());
+        unblock(move || std::fs::rename(source, target)).await?;
+        info!(
+            from = %from_dir.display(),
+            to = %to_dir.display(),
+            "replica dir moved"
+        );
+        Ok(true)
+    }
+
     /// update high watermark to end
     #[instrument(skip(self))]
     pub async fn update_high_watermark_to_end(&mut self) -> Result<bool, StorageError> {
@@ -336,6 +542,20 @@ impl FileReplica {
         let leo = self.get_leo();
         debug!(hw, leo, "starting read records",);
 
+        // fenced replica doesn't return corrupted batch or anything after it
+        let max_offset = match self.checksum_error() {
+            Some(checksum_error) => {
+                let corrupted_offset = checksum_error.base_offset;
+                if start_offset >= corrupted_offset {
+                    return Err(ErrorCode::Other(format!(
+                        "replica is fenced, batch at offset: {corrupted_offset} is corrupted"
+                    )));
+                }
+                Some(max_offset.map_or(corrupted_offset, |max| min(max, corrupted_offset)))
+            }
+            None => max_offset,
+        };
+
         let mut slice = ReplicaSlice {
             end: OffsetInfo { hw, leo },
             start: self.get_log_start_offset(),
@@ -343,7 +563,8 @@ impl FileReplica {
         };
 
         let active_base_offset = self.active_segment.get_base_offset();
-        let file_slice = if start_offset >= active_base_offset {
+        // remote segments are not covered by verified offset
+        let (file_slice, local) = if start_offset >= active_base_offset {
             debug!(start_offset, active_base_offset, "is in active segment");
             if start_offset == leo {
                 trace!("start offset is same as end offset, skipping");
@@ -357,7 +578,7 @@ impl FileReplica {
                 .records_slice(start_offset, max_offset)
                 .await?
             {
-                slice
+                (slice, true)
             } else {
                 return Err(ErrorCode::Other(format!(
                     "no records found in active replica, start: {}, max: {:#?}, active: {:#?}",
@@ -366,21 +587,66 @@ impl FileReplica {
             }
         } else {
             debug!(start_offset, active_base_offset, "not in active sgments");
-            self.prev_segments
+            let local_slice = self
+                .prev_segments
                 .find_slice(start_offset, max_offset)
-                .await?
-                .ok_or_else(|| ErrorCode::OffsetEvicted {
-                    offset: start_offset,
-                    next_available: self.get_log_start_offset(),
-                })?
+                .await?;
+            #[cfg(feature = "tiered")]
+            let slice = match (local_slice, &self.remote) {
+                (Some(slice), _) => Some((slice, true)),
+                (None, Some(remote)) => {
+                    debug!(
+                        start_offset,
+                        "not in local segments, reading from remote tier"
+                    );
+                    remote
+                        .find_slice(start_offset, max_offset)
+                        .await?
+                        .map(|slice| (slice, false))
+                }
+                (None, None) => None,
+            };
+            #[cfg(not(feature = "tiered"))]
+            let slice = local_slice.map(|slice| (slice, true));
+            slice.ok_or_else(|| ErrorCode::OffsetEvicted {
+                offset: start_offset,
+                next_available: self.get_log_start_offset(),
+            })?
         };
 
-        let limited_slice = AsyncFileSlice::new(
+        let mut limited_slice = AsyncFileSlice::new(
             file_slice.fd(),
             file_slice.position(),
             min(file_slice.len(), max_len as u64),
         );
 
+        if self.option.verify_checksum_on_fetch {
+            let verified_offset = if local {
+                self.verified_offset.load(Ordering::SeqCst)
+            } else {
+                -1
+            };
+            let verification = verify_slice_checksum(&limited_slice, verified_offset)
+                .await
+                .map_err(|err| ErrorCode::Other(format!("checksum verification: {err}")))?;
+            if let Some(verified_offset) = verification.verified_offset.filter(|_| local) {
+                self.verified_offset
+                    .fetch_max(verified_offset, Ordering::SeqCst);
+            }
+            if let Some(checksum_error) = verification.checksum_error {
+                let valid_len = checksum_error.pos as u64 - limited_slice.position();
+                let corrupted_offset = checksum_error.base_offset;
+                self.fence(checksum_error);
+                if valid_len == 0 {
+                    return Err(ErrorCode::Other(format!(
+                        "replica is fenced, batch at offset: {corrupted_offset} is corrupted"
+                    )));
+                }
+                limited_slice =
+                    AsyncFileSlice::new(limited_slice.fd(), limited_slice.position(), valid_len);
+            }
+        }
+
         debug!(
             fd = limited_slice.fd(),
             pos = limited_slice.position(),
@@ -468,6 +734,7 @@ mod tests {
     use fluvio_protocol::link::ErrorCode;
     use fluvio_protocol::record::Batch;
     use fluvio_protocol::record::Offset;
+    use fluvio_protocol::record::ReplicaKey;
     use fluvio_protocol::{Decoder, Encoder};
     use fluvio_protocol::record::{Record, RecordSet};
     use fluvio_protocol::record::MemoryRecords;
@@ -861,6 +1128,34 @@ mod tests {
         assert!(!test_file.exists());
     }
 
+    #[fluvio_future::test]
+    async fn test_replica_rename() {
+        let option = base_option("test_rename");
+
+        let mut replica = create_replica("old", START_OFFSET, option.clone()).await;
+        replica
+            .write_batch(&mut create_batch())
+            .await
+            .expect("write");
+        drop(replica);
+
+        let from = ReplicaKey::new("old", 0u32);
+        let to = ReplicaKey::new("new", 0u32);
+        assert!(FileReplica::rename(&from, &to, &option)
+            .await
+            .expect("rename"));
+        assert!(!option.base_dir.join("old-0").exists());
+        assert!(option.base_dir.join("new-0").join(TEST_SEG_NAME).exists());
+
+        // already moved
+        assert!(!FileReplica::rename(&from, &to, &option)
+            .await
+            .expect("rename"));
+
+        let replica = create_replica("new", 0, option).await;
+        assert_eq!(replica.get_leo(), START_OFFSET + 2);
+    }
+
     #[fluvio_future::test]
     async fn test_replica_limit_batch() {
         let mut option = base_option("test_batch_limit");
@@ -1183,4 +1478,145 @@ mod tests {
             }
         ));
     }
+
+    /// flip last byte of the log, which is in the records of last batch
+    fn corrupt_last_byte(log_path: &std::path::Path) {
+        let mut bytes = fs::read(log_path).expect("read log");
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        fs::write(log_path, bytes).expect("write log");
+    }
+
+    #[fluvio_future::test]
+    async fn test_replica_fenced_on_load() {
+        let option = base_option("test_replica_fenced_on_load");
+
+        let mut replica = create_replica("test", START_OFFSET, option.clone()).await;
+        replica
+            .write_batch(&mut create_batch())
+            .await
+            .expect("write");
+        replica
+            .write_batch(&mut create_batch())
+            .await
+            .expect("write");
+        let batch_len = replica
+            .read_all_uncommitted_records(FileReplica::PREFER_MAX_LEN)
+            .await
+            .expect("read")
+            .file_slice
+            .expect("slice")
+            .len()
+            / 2;
+        drop(replica);
+
+        corrupt_last_byte(&option.base_dir.join("test-0").join(TEST_SEG_NAME));
+
+        let mut replica = create_replica("test", START_OFFSET, option.clone()).await;
+        assert!(replica.is_fenced());
+        assert_eq!(replica.get_corrupted_offset(), Some(START_OFFSET + 2));
+        assert_eq!(replica.get_leo(), START_OFFSET + 4);
+
+        // only first batch can be read
+        let slice = replica
+            .read_all_uncommitted_records(FileReplica::PREFER_MAX_LEN)
+            .await
+            .expect("read");
+        assert_eq!(slice.file_slice.expect("slice").len(), batch_len);
+        assert!(replica
+            .read_partition_slice(START_OFFSET + 2, 1000, Isolation::ReadUncommitted)
+            .await
+            .is_err());
+
+        // discarded records are fetched again from the start of segment
+        assert_eq!(
+            replica.discard_corrupted().await.expect("discard"),
+            Some(START_OFFSET)
+        );
+        assert!(!replica.is_fenced());
+        assert_eq!(replica.get_leo(), START_OFFSET);
+        replica
+            .write_batch(&mut create_batch())
+            .await
+            .expect("write");
+        assert_eq!(replica.get_leo(), START_OFFSET + 2);
+        drop(replica);
+
+        let replica = create_replica("test", START_OFFSET, option).await;
+        assert!(!replica.is_fenced());
+        assert_eq!(replica.get_leo(), START_OFFSET + 2);
+    }
+
+    #[fluvio_future::test]
+    async fn test_replica_fenced_on_fetch() {
+        let mut option = base_option("test_replica_fenced_on_fetch");
+        option.verify_checksum_on_fetch = true;
+
+        let mut replica = create_replica("test", START_OFFSET, option.clone()).await;
+        replica
+            .write_batch(&mut create_batch())
+            .await
+            .expect("write");
+        let batch_len = replica
+            .read_all_uncommitted_records(FileReplica::PREFER_MAX_LEN)
+            .await
+            .expect("read")
+            .file_slice
+            .expect("slice")
+            .len();
+        assert!(!replica.is_fenced());
+
+        // only second batch is verified on next fetch
+        replica
+            .write_batch(&mut create_batch())
+            .await
+            .expect("write");
+        corrupt_last_byte(&option.base_dir.join("test-0").join(TEST_SEG_NAME));
+
+        let slice = replica
+            .read_all_uncommitted_records(FileReplica::PREFER_MAX_LEN)
+            .await
+            .expect("read");
+        assert_eq!(slice.file_slice.expect("slice").len(), batch_len);
+        assert_eq!(replica.get_corrupted_offset(), Some(START_OFFSET + 2));
+    }
+
+    #[fluvio_future::test]
+    async fn test_replica_skip_checksum_on_load() {
+        let mut option = base_option("test_replica_skip_checksum_on_load");
+        option.verify_checksum_on_fetch = true;
+        option.verify_checksum_on_load = false;
+
+        let mut replica = create_replica("test", START_OFFSET, option.clone()).await;
+        replica
+            .write_batch(&mut create_batch())
+            .await
+            .expect("write");
+        replica
+            .write_batch(&mut create_batch())
+            .await
+            .expect("write");
+        drop(replica);
+
+        corrupt_last_byte(&option.base_dir.join("test-0").join(TEST_SEG_NAME));
+
+        // corruption is not found until corrupted batch is fetched
+        let replica = create_replica("test", START_OFFSET, option).await;
+        assert!(!replica.is_fenced());
+        assert_eq!(replica.get_leo(), START_OFFSET + 4);
+        let batch_len = create_batch().write_size(0) as u64;
+        let slice = replica
+            .read_partition_slice(START_OFFSET, batch_len as u32, Isolation::ReadUncommitted)
+            .await
+            .expect("read");
+        assert_eq!(slice.file_slice.expect("slice").len(), batch_len);
+        assert!(!replica.is_fenced());
+
+        let slice = replica
+            .read_all_uncommitted_records(FileReplica::PREFER_MAX_LEN)
+            .await
+            .expect("read");
+        assert_eq!(slice.file_slice.expect("slice").len(), batch_len);
+        assert_eq!(replica.get_corrupted_offset(), Some(START_OFFSET + 2));
+    }
 }
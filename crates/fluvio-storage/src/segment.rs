@@ -15,16 +15,17 @@ use fluvio_protocol::link::ErrorCode;
 
 use crate::batch_header::{BatchHeaderStream, FileEmptyRecords};
 use crate::mut_index::MutLogIndex;
-use crate::index::LogIndex;
+use crate::index::{LogIndex, EXTENSION as INDEX_EXTENSION};
 use crate::index::Index;
 use crate::records::FileRecords;
 use crate::mut_records::MutFileRecords;
 use crate::records::FileRecordsSlice;
 use crate::config::{SharedReplicaConfig};
 use crate::StorageError;
-use crate::batch::{FileBatchStream};
+use crate::batch::{BatchChecksumError, FileBatchStream};
 use crate::index::OffsetPosition;
 use crate::validator::LogValidationError;
+use crate::util::generate_file_name;
 
 pub type MutableSegment = Segment<MutLogIndex, MutFileRecords>;
 pub type ReadSegment = Segment<LogIndex, FileRecordsSlice>;
@@ -41,6 +42,7 @@ pub struct Segment<I, L> {
     index: I,
     base_offset: Offset,
     end_offset: Offset,
+    checksum_error: Option<BatchChecksumError>,
 }
 
 impl<I, L> fmt::Debug for Segment<I, L> {
@@ -69,6 +71,11 @@ impl<I, L> Segment<I, L> {
     pub fn get_base_offset(&self) -> Offset {
         self.base_offset
     }
+
+    /// first batch with crc mismatch found when segment was loaded
+    pub fn checksum_error(&self) -> Option<&BatchChecksumError> {
+        self.checksum_error.as_ref()
+    }
 }
 
 impl<I, L> Segment<I, L>
@@ -250,6 +257,7 @@ impl Segment<LogIndex, FileRecordsSlice> {
             option,
             base_offset,
             end_offset,
+            checksum_error: None,
         })
     }
 
@@ -262,7 +270,10 @@ impl Segment<LogIndex, FileRecordsSlice> {
         let msg_log = FileRecordsSlice::open(base_offset, option.clone()).await?;
         let index = LogIndex::open_from_offset(base_offset, option.clone()).await?;
         let base_offset = msg_log.get_base_offset();
-        match msg_log.validate(&index).await {
+        match msg_log
+            .validate(&index, option.verify_checksum_on_load)
+            .await
+        {
             Ok(val) => {
                 // check if validation is successful
                 if let Some(err) = val.error {
@@ -270,6 +281,10 @@ impl Segment<LogIndex, FileRecordsSlice> {
                     return Err(err.into());
                 }
 
+                if let Some(checksum_error) = &val.checksum_error {
+                    error!(%checksum_error, base_offset, "segment contains corrupted batch");
+                }
+
                 info!(end_offset = val.leo(), base_offset = val.base_offset, time_ms = %val.duration.as_millis(), "segment validated");
                 Ok(Segment {
                     msg_log,
@@ -277,6 +292,7 @@ impl Segment<LogIndex, FileRecordsSlice> {
                     option,
                     base_offset,
                     end_offset: val.leo(),
+                    checksum_error: val.checksum_error,
                 })
             }
             Err(err) => {
@@ -318,6 +334,7 @@ impl Segment<MutLogIndex, MutFileRecords> {
             index,
             base_offset,
             end_offset: base_offset,
+            checksum_error: None,
         })
     }
 
@@ -341,6 +358,7 @@ impl Segment<MutLogIndex, MutFileRecords> {
             index,
             base_offset,
             end_offset: base_offset,
+            checksum_error: None,
         })
     }
 
@@ -375,10 +393,23 @@ impl Segment<MutLogIndex, MutFileRecords> {
                 }
             }
         }
+        if let Some(checksum_error) = &validation.checksum_error {
+            error!(%checksum_error, "active segment contains corrupted batch");
+        }
+        self.checksum_error = validation.checksum_error;
         self.end_offset = leo;
         Ok(self.end_offset)
     }
 
+    /// remove log and index files, segment must not be used afterward
+    pub(crate) async fn remove_files(&self) -> Result<(), IoError> {
+        let index_path =
+            generate_file_name(&self.option.base_dir, self.base_offset, INDEX_EXTENSION);
+        info!(log_path = %self.msg_log.get_path().display(), "removing active segment");
+        remove_file(self.msg_log.get_path()).await?;
+        remove_file(&index_path).await
+    }
+
     // shrink index
     #[cfg(test)]
     async fn shrink_index(&mut self) -> Result<(), IoError> {
@@ -387,6 +418,7 @@ impl Segment<MutLogIndex, MutFileRecords> {
 
     // perform any action during roll over
     pub async fn roll_over(&mut self) -> Result<(), IoError> {
+        self.msg_log.sync().await?;
         self.index.shrink().await
     }
 
@@ -453,6 +485,11 @@ impl Segment<MutLogIndex, MutFileRecords> {
         }
     }
 
+    /// sync written batches to disk
+    pub async fn sync(&mut self) -> Result<(), StorageError> {
+        self.msg_log.sync().await.map_err(|err| err.into())
+    }
+
     #[allow(unused)]
     pub async fn flush(&mut self) -> Result<(), StorageError> {
         self.msg_log.flush().await.map_err(|err| err.into())
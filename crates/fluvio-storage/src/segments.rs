@@ -14,6 +14,7 @@ use fluvio_protocol::record::Size64;
 use fluvio_protocol::record::Offset;
 use fluvio_future::file_slice::AsyncFileSlice;
 
+use crate::batch::BatchChecksumError;
 use crate::config::SharedReplicaConfig;
 use crate::segment::ReadSegment;
 use crate::util::log_path_get_offset;
@@ -220,8 +221,6 @@ impl SegmentList {
         }
     }
 
-    #[cfg(test)]
-    #[cfg(feature = "fixture")]
     pub fn get_segment(&self, offset: Offset) -> Option<&ReadSegment> {
         self.segments.get(&offset)
     }
@@ -262,6 +261,21 @@ impl SegmentList {
     pub(crate) fn find_first(&self, count: usize) -> Vec<Offset> {
         self.segments.keys().take(count).copied().collect()
     }
+
+    /// base offsets of segments starting at or after offset
+    pub(crate) fn find_from(&self, offset: Offset) -> Vec<Offset> {
+        self.segments
+            .range(offset..)
+            .map(|(base, _)| *base)
+            .collect()
+    }
+
+    /// first corrupted batch found when segments were loaded
+    pub(crate) fn first_checksum_error(&self) -> Option<BatchChecksumError> {
+        self.segments
+            .values()
+            .find_map(|segment| segment.checksum_error().cloned())
+    }
 }
 
 #[cfg(test)]
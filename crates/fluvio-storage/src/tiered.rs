@@ -0,0 +1,77 @@
+//! Tiered storage.
+//!
+//! Closed segments can be offloaded to an object store. Once a segment is uploaded, it is
+//! recorded in the remote manifest of the replica and removed from local disk. Reads of offsets
+//! which are no longer available locally are served by downloading the segment into a small
+//! local cache.
+//!
+//! Objects can be stored in a local directory or in a bucket of S3 compatible service.
+//! Object stores and remote tier are only built with `tiered` feature.
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+#[cfg(feature = "tiered")]
+mod remote;
+#[cfg(feature = "tiered")]
+mod s3;
+
+#[cfg(feature = "tiered")]
+pub use remote::{LocalObjectStore, ObjectStore, RemoteManifest, RemoteSegment};
+#[cfg(feature = "tiered")]
+pub(crate) use remote::RemoteTier;
+#[cfg(feature = "tiered")]
+pub use s3::S3ObjectStore;
+
+/// Tiered storage configuration
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+pub struct TieredStorageConfig {
+    pub store: ObjectStoreConfig,
+    /// number of closed segments kept on local disk, older segments are offloaded
+    #[serde(default = "default_local_segments")]
+    pub local_segments: u32,
+}
+
+const fn default_local_segments() -> u32 {
+    1
+}
+
+impl TieredStorageConfig {
+    pub fn local(path: impl Into<PathBuf>) -> Self {
+        Self {
+            store: ObjectStoreConfig::Local { path: path.into() },
+            local_segments: default_local_segments(),
+        }
+    }
+
+    pub fn s3(
+        endpoint: impl Into<String>,
+        bucket: impl Into<String>,
+        region: impl Into<String>,
+    ) -> Self {
+        Self {
+            store: ObjectStoreConfig::S3 {
+                endpoint: endpoint.into(),
+                bucket: bucket.into(),
+                region: region.into(),
+            },
+            local_segments: default_local_segments(),
+        }
+    }
+}
+
+/// Object store backend
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectStoreConfig {
+    /// store objects as files under directory
+    Local { path: PathBuf },
+    /// store objects in bucket of S3 compatible service, using path style urls
+    S3 {
+        /// url of the service, for example `https://s3.us-east-1.amazonaws.com`
+        endpoint: String,
+        bucket: String,
+        region: String,
+    },
+}
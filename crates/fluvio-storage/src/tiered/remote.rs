@@ -0,0 +1,782 @@
+//! Object stores and remote tier of replica, enabled by `tiered` feature.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::io::{Error as IoError, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Result, anyhow};
+use async_lock::RwLock;
+use async_trait::async_trait;
+use blocking::unblock;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument, error};
+
+use fluvio_future::file_slice::AsyncFileSlice;
+use fluvio_future::fs::{create_dir_all, metadata, remove_file};
+use fluvio_protocol::link::ErrorCode;
+use fluvio_protocol::record::Offset;
+
+use crate::config::{ReplicaConfig, SharedReplicaConfig};
+use crate::index::EXTENSION as INDEX_EXTENSION;
+use crate::mut_records::MESSAGE_LOG_EXTENSION;
+use crate::segment::ReadSegment;
+use crate::util::generate_file_name;
+
+use super::s3::S3ObjectStore;
+use super::{ObjectStoreConfig, TieredStorageConfig};
+
+/// name of the remote manifest object
+const MANIFEST_NAME: &str = "manifest.json";
+
+/// name of the directory in the replica which holds downloaded segments
+const REMOTE_CACHE_DIR: &str = "remote-cache";
+
+/// max number of downloaded segments kept in the cache
+const REMOTE_CACHE_SEGMENTS: usize = 4;
+
+/// cached segments read within this period are not evicted, even if cache is full,
+/// since slices of them may still be sent to consumers
+const REMOTE_CACHE_READ_GRACE: Duration = Duration::from_secs(60);
+
+impl ObjectStoreConfig {
+    pub fn open(&self) -> Result<Arc<dyn ObjectStore>> {
+        Ok(match self {
+            Self::Local { path } => Arc::new(LocalObjectStore::new(path.clone())),
+            Self::S3 {
+                endpoint,
+                bucket,
+                region,
+            } => Arc::new(S3ObjectStore::new(endpoint, bucket, region)?),
+        })
+    }
+}
+
+/// Minimal object store abstraction needed to offload segments.
+/// Keys are `/` separated paths.
+#[async_trait]
+pub trait ObjectStore: Debug + Send + Sync {
+    /// upload file as object
+    async fn upload(&self, key: &str, file: &Path) -> Result<()>;
+
+    /// download object into file
+    async fn download(&self, key: &str, file: &Path) -> Result<()>;
+
+    async fn put(&self, key: &str, bytes: Vec<u8>) -> Result<()>;
+
+    /// get object, return None if object doesn't exist
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>>;
+
+    /// copy object within the store, without transferring its content
+    async fn copy(&self, from: &str, to: &str) -> Result<()>;
+
+    async fn delete(&self, key: &str) -> Result<()>;
+}
+
+/// Object store backed by local file system, mostly useful for testing
+#[derive(Debug)]
+pub struct LocalObjectStore {
+    root: PathBuf,
+}
+
+impl LocalObjectStore {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    fn object_path(&self, key: &str) -> PathBuf {
+        self.root.join(key)
+    }
+
+    async fn ensure_parent(path: &Path) -> Result<(), IoError> {
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ObjectStore for LocalObjectStore {
+    async fn upload(&self, key: &str, file: &Path) -> Result<()> {
+        let target = self.object_path(key);
+        Self::ensure_parent(&target).await?;
+        let source = file.to_owned();
+        unblock(move || std::fs::copy(source, target)).await?;
+        Ok(())
+    }
+
+    async fn download(&self, key: &str, file: &Path) -> Result<()> {
+        let source = self.object_path(key);
+        Self::ensure_parent(file).await?;
+        let target = file.to_owned();
+        unblock(move || std::fs::copy(source, target)).await?;
+        Ok(())
+    }
+
+    async fn put(&self, key: &str, bytes: Vec<u8>) -> Result<()> {
+        let target = self.object_path(key);
+        Self::ensure_parent(&target).await?;
+        unblock(move || std::fs::write(target, bytes)).await?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let source = self.object_path(key);
+        match unblock(move || std::fs::read(source)).await {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn copy(&self, from: &str, to: &str) -> Result<()> {
+        let source = self.object_path(from);
+        let target = self.object_path(to);
+        Self::ensure_parent(&target).await?;
+        unblock(move || std::fs::copy(source, target)).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        match remove_file(self.object_path(key)).await {
+            Ok(_) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+/// Segment stored in the object store
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RemoteSegment {
+    pub base_offset: Offset,
+    pub end_offset: Offset,
+    pub size: u64,
+    /// last modification time of the segment, in seconds since epoch
+    pub last_modified: u64,
+}
+
+impl RemoteSegment {
+    fn is_expired(&self, expired_duration: &Duration) -> bool {
+        let expire_before = SystemTime::now()
+            .checked_sub(*expired_duration)
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        self.last_modified <= expire_before
+    }
+}
+
+/// List of segments of a replica which are stored in the object store
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RemoteManifest {
+    pub segments: BTreeMap<Offset, RemoteSegment>,
+}
+
+impl RemoteManifest {
+    pub fn min_offset(&self) -> Option<Offset> {
+        self.segments.keys().next().copied()
+    }
+
+    pub fn find_segment(&self, offset: Offset) -> Option<&RemoteSegment> {
+        self.segments
+            .range(..=offset)
+            .next_back()
+            .map(|(_, segment)| segment)
+            .filter(|segment| offset < segment.end_offset)
+    }
+}
+
+/// Downloaded segment
+#[derive(Debug)]
+struct CachedSegment {
+    segment: ReadSegment,
+    last_read: StdMutex<Instant>,
+}
+
+impl CachedSegment {
+    fn new(segment: ReadSegment, now: Instant) -> Self {
+        Self {
+            segment,
+            last_read: StdMutex::new(now),
+        }
+    }
+
+    fn last_read(&self) -> Instant {
+        *self.last_read.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    async fn records_slice(
+        &self,
+        start_offset: Offset,
+        max_offset: Option<Offset>,
+    ) -> Result<Option<AsyncFileSlice>, ErrorCode> {
+        *self.last_read.lock().unwrap_or_else(|err| err.into_inner()) = Instant::now();
+        self.segment.records_slice(start_offset, max_offset).await
+    }
+}
+
+/// cached segments to evict. Segments no longer in manifest are evicted first, then least
+/// recently read ones while cache is over capacity. Segments read within grace period are kept.
+fn find_evicted(
+    last_reads: &BTreeMap<Offset, Instant>,
+    manifest: &RemoteManifest,
+    now: Instant,
+) -> Vec<Offset> {
+    let mut idle: Vec<(Instant, Offset)> = last_reads
+        .iter()
+        .map(|(base_offset, last_read)| (*last_read, *base_offset))
+        .filter(|(last_read, _)| {
+            now.saturating_duration_since(*last_read) >= REMOTE_CACHE_READ_GRACE
+        })
+        .collect();
+    idle.sort();
+
+    let (mut evicted, live): (Vec<Offset>, Vec<Offset>) = idle
+        .into_iter()
+        .map(|(_, base_offset)| base_offset)
+        .partition(|base_offset| !manifest.segments.contains_key(base_offset));
+    let excess = (last_reads.len() - evicted.len()).saturating_sub(REMOTE_CACHE_SEGMENTS);
+    evicted.extend(live.into_iter().take(excess));
+    evicted
+}
+
+/// Remote tier of a single replica
+#[derive(Debug)]
+pub(crate) struct RemoteTier {
+    store: Arc<dyn ObjectStore>,
+    prefix: String,
+    local_segments: u32,
+    option: Arc<SharedReplicaConfig>,
+    cache_option: Arc<SharedReplicaConfig>,
+    manifest: RwLock<RemoteManifest>,
+    min_offset: AtomicI64,
+    cache: RwLock<BTreeMap<Offset, CachedSegment>>,
+}
+
+impl RemoteTier {
+    /// load remote tier for replica, `prefix` must be unique for each replica
+    pub(crate) async fn load(
+        config: &TieredStorageConfig,
+        prefix: String,
+        option: Arc<SharedReplicaConfig>,
+    ) -> Result<Self> {
+        let store = config.store.open()?;
+        let manifest = match store.get(&format!("{prefix}/{MANIFEST_NAME}")).await? {
+            Some(bytes) => serde_json::from_slice(&bytes)?,
+            None => RemoteManifest::default(),
+        };
+        debug!(
+            prefix,
+            segments = manifest.segments.len(),
+            "loaded remote manifest"
+        );
+
+        let cache_dir = option.base_dir.join(REMOTE_CACHE_DIR);
+        create_dir_all(&cache_dir).await?;
+        let cache_option = ReplicaConfig {
+            base_dir: cache_dir,
+            ..Default::default()
+        }
+        .shared();
+
+        Ok(Self {
+            store,
+            prefix,
+            local_segments: config.local_segments,
+            option,
+            cache_option,
+            min_offset: AtomicI64::new(manifest.min_offset().unwrap_or(-1)),
+            manifest: RwLock::new(manifest),
+            cache: RwLock::new(BTreeMap::new()),
+        })
+    }
+
+    pub(crate) fn local_segments(&self) -> usize {
+        self.local_segments as usize
+    }
+
+    /// earliest offset stored in remote tier, -1 if there is none
+    pub(crate) fn min_offset(&self) -> Offset {
+        self.min_offset.load(Ordering::SeqCst)
+    }
+
+    fn segment_key(&self, base_offset: Offset, extension: &str) -> String {
+        format!("{}/{base_offset:020}.{extension}", self.prefix)
+    }
+
+    async fn save_manifest(&self, manifest: &RemoteManifest) -> Result<()> {
+        let bytes = serde_json::to_vec(manifest)?;
+        self.store
+            .put(&format!("{}/{MANIFEST_NAME}", self.prefix), bytes)
+            .await?;
+        self.min_offset
+            .store(manifest.min_offset().unwrap_or(-1), Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// upload closed segment to object store and record it in the manifest.
+    /// caller is responsible for removing local segment afterward
+    #[instrument(skip(self))]
+    pub(crate) async fn offload(&self, base_offset: Offset, end_offset: Offset) -> Result<()> {
+        let log_path =
+            generate_file_name(&self.option.base_dir, base_offset, MESSAGE_LOG_EXTENSION);
+        let index_path = generate_file_name(&self.option.base_dir, base_offset, INDEX_EXTENSION);
+
+        let log_metadata = metadata(&log_path).await?;
+        let last_modified = log_metadata
+            .modified()?
+            .duration_since(UNIX_EPOCH)
+            .map_err(|err| anyhow!("invalid modified time: {err}"))?
+            .as_secs();
+
+        self.store
+            .upload(
+                &self.segment_key(base_offset, MESSAGE_LOG_EXTENSION),
+                &log_path,
+            )
+            .await?;
+        self.store
+            .upload(&self.segment_key(base_offset, INDEX_EXTENSION), &index_path)
+            .await?;
+
+        let mut manifest = self.manifest.write().await;
+        manifest.segments.insert(
+            base_offset,
+            RemoteSegment {
+                base_offset,
+                end_offset,
+                size: log_metadata.len(),
+                last_modified,
+            },
+        );
+        self.save_manifest(&manifest).await?;
+        info!(base_offset, prefix = self.prefix, "segment offloaded");
+        Ok(())
+    }
+
+    /// total size of segments in object store
+    pub(crate) async fn size(&self) -> u64 {
+        self.manifest
+            .read()
+            .await
+            .segments
+            .values()
+            .map(|segment| segment.size)
+            .sum()
+    }
+
+    /// remove segments from object store which are older than retention
+    #[instrument(skip(self))]
+    pub(crate) async fn enforce_ttl(&self, retention: &Duration) -> Result<()> {
+        let mut manifest = self.manifest.write().await;
+        let expired: Vec<Offset> = manifest
+            .segments
+            .values()
+            .filter(|segment| segment.is_expired(retention))
+            .map(|segment| segment.base_offset)
+            .collect();
+        self.remove_segments(&mut manifest, &expired).await
+    }
+
+    /// remove oldest segments from object store until at least `bytes` are freed.
+    /// return number of bytes freed, which is less than requested if all segments are removed
+    #[instrument(skip(self))]
+    pub(crate) async fn remove_oldest(&self, bytes: u64) -> Result<u64> {
+        let mut manifest = self.manifest.write().await;
+        let mut freed = 0;
+        let mut oldest = vec![];
+        for segment in manifest.segments.values() {
+            if freed >= bytes {
+                break;
+            }
+            freed += segment.size;
+            oldest.push(segment.base_offset);
+        }
+        self.remove_segments(&mut manifest, &oldest).await?;
+        Ok(freed)
+    }
+
+    async fn remove_segments(
+        &self,
+        manifest: &mut RemoteManifest,
+        base_offsets: &[Offset],
+    ) -> Result<()> {
+        if base_offsets.is_empty() {
+            return Ok(());
+        }
+
+        for base_offset in base_offsets {
+            manifest.segments.remove(base_offset);
+        }
+        // manifest is updated first so readers never see a missing object
+        self.save_manifest(manifest).await?;
+        self.evict(manifest).await;
+
+        for base_offset in base_offsets {
+            info!(base_offset, "removing remote segment");
+            self.store
+                .delete(&self.segment_key(*base_offset, MESSAGE_LOG_EXTENSION))
+                .await?;
+            self.store
+                .delete(&self.segment_key(*base_offset, INDEX_EXTENSION))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// remove all remote segments and manifest of the replica
+    pub(crate) async fn remove_all(&self) -> Result<()> {
+        let mut manifest = self.manifest.write().await;
+        for base_offset in manifest.segments.keys() {
+            self.store
+                .delete(&self.segment_key(*base_offset, MESSAGE_LOG_EXTENSION))
+                .await?;
+            self.store
+                .delete(&self.segment_key(*base_offset, INDEX_EXTENSION))
+                .await?;
+        }
+        manifest.segments.clear();
+        self.store
+            .delete(&format!("{}/{MANIFEST_NAME}", self.prefix))
+            .await?;
+        self.min_offset.store(-1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// move remote segments and manifest of a replica to a new prefix
+    #[instrument(skip(config))]
+    pub(crate) async fn move_prefix(
+        config: &TieredStorageConfig,
+        from: &str,
+        to: &str,
+    ) -> Result<()> {
+        let store = config.store.open()?;
+        let Some(bytes) = store.get(&format!("{from}/{MANIFEST_NAME}")).await? else {
+            return Ok(());
+        };
+        let manifest: RemoteManifest = serde_json::from_slice(&bytes)?;
+        for base_offset in manifest.segments.keys() {
+            for extension in [MESSAGE_LOG_EXTENSION, INDEX_EXTENSION] {
+                store
+                    .copy(
+                        &format!("{from}/{base_offset:020}.{extension}"),
+                        &format!("{to}/{base_offset:020}.{extension}"),
+                    )
+                    .await?;
+            }
+        }
+        // new manifest is written last, so it only refers to copied segments
+        store.put(&format!("{to}/{MANIFEST_NAME}"), bytes).await?;
+
+        store.delete(&format!("{from}/{MANIFEST_NAME}")).await?;
+        for base_offset in manifest.segments.keys() {
+            for extension in [MESSAGE_LOG_EXTENSION, INDEX_EXTENSION] {
+                store
+                    .delete(&format!("{from}/{base_offset:020}.{extension}"))
+                    .await?;
+            }
+        }
+        info!(
+            from,
+            to,
+            segments = manifest.segments.len(),
+            "remote segments moved"
+        );
+        Ok(())
+    }
+
+    /// find slice in remote segments, downloading segment if needed
+    #[instrument(skip(self))]
+    pub(crate) async fn find_slice(
+        &self,
+        start_offset: Offset,
+        max_offset: Option<Offset>,
+    ) -> Result<Option<AsyncFileSlice>, ErrorCode> {
+        let remote_segment = match self.manifest.read().await.find_segment(start_offset) {
+            Some(segment) => segment.clone(),
+            None => return Ok(None),
+        };
+        let base_offset = remote_segment.base_offset;
+
+        {
+            let cache = self.cache.read().await;
+            if let Some(cached) = cache.get(&base_offset) {
+                return cached.records_slice(start_offset, max_offset).await;
+            }
+        }
+
+        let downloaded = {
+            let mut cache = self.cache.write().await;
+            if !cache.contains_key(&base_offset) {
+                let segment = self
+                    .download(&remote_segment)
+                    .await
+                    .map_err(|err| ErrorCode::Other(format!("remote segment download: {err}")))?;
+                cache.insert(base_offset, CachedSegment::new(segment, Instant::now()));
+            }
+            let cached = cache.get(&base_offset).expect("segment was just cached");
+            cached.records_slice(start_offset, max_offset).await
+        };
+        self.evict(&*self.manifest.read().await).await;
+        downloaded
+    }
+
+    /// close and remove evicted segments from cache
+    async fn evict(&self, manifest: &RemoteManifest) {
+        let mut cache = self.cache.write().await;
+        let last_reads = cache
+            .iter()
+            .map(|(base_offset, cached)| (*base_offset, cached.last_read()))
+            .collect();
+        for base_offset in find_evicted(&last_reads, manifest, Instant::now()) {
+            if let Some(evicted) = cache.remove(&base_offset) {
+                debug!(base_offset, "evicting cached segment");
+                if let Err(err) = evicted.segment.remove().await {
+                    error!(?err, base_offset, "failed to evict cached segment");
+                }
+            }
+        }
+    }
+
+    async fn download(&self, remote_segment: &RemoteSegment) -> Result<ReadSegment> {
+        let base_offset = remote_segment.base_offset;
+        let cache_dir = &self.cache_option.base_dir;
+        debug!(base_offset, "downloading remote segment");
+        self.store
+            .download(
+                &self.segment_key(base_offset, MESSAGE_LOG_EXTENSION),
+                &generate_file_name(cache_dir, base_offset, MESSAGE_LOG_EXTENSION),
+            )
+            .await?;
+        self.store
+            .download(
+                &self.segment_key(base_offset, INDEX_EXTENSION),
+                &generate_file_name(cache_dir, base_offset, INDEX_EXTENSION),
+            )
+            .await?;
+        ReadSegment::open_for_read(
+            base_offset,
+            remote_segment.end_offset,
+            self.cache_option.clone(),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::env::temp_dir;
+
+    use flv_util::fixture::ensure_new_dir;
+    use fluvio_protocol::fixture::create_batch;
+
+    use crate::segment::MutableSegment;
+
+    use super::*;
+
+    #[fluvio_future::test]
+    async fn test_offload_and_read_remote_segment() {
+        let test_dir = temp_dir().join("tiered-offload");
+        ensure_new_dir(&test_dir).expect("new");
+        let replica_dir = test_dir.join("replica");
+        let store_dir = test_dir.join("store");
+        ensure_new_dir(&replica_dir).expect("new");
+
+        let option = ReplicaConfig {
+            base_dir: replica_dir,
+            segment_max_bytes: 1000,
+            index_max_bytes: 1000,
+            ..Default::default()
+        }
+        .shared();
+
+        let mut active = MutableSegment::create(10, option.clone())
+            .await
+            .expect("create");
+        active
+            .append_batch(&mut create_batch())
+            .await
+            .expect("append");
+        active.roll_over().await.expect("roll over");
+        let segment = active.as_segment().await.expect("segment");
+
+        let config = TieredStorageConfig::local(store_dir);
+        let tier = RemoteTier::load(&config, "test-0".to_owned(), option.clone())
+            .await
+            .expect("load");
+        tier.offload(segment.get_base_offset(), segment.get_end_offset())
+            .await
+            .expect("offload");
+        segment.remove().await.expect("remove");
+
+        assert_eq!(tier.min_offset(), 10);
+        let slice = tier
+            .find_slice(10, None)
+            .await
+            .expect("find")
+            .expect("slice");
+        assert_eq!(slice.position(), 0);
+        assert!(slice.len() > 0);
+        assert!(tier.find_slice(12, None).await.expect("find").is_none());
+
+        // manifest survives reload
+        let tier = RemoteTier::load(&config, "test-0".to_owned(), option)
+            .await
+            .expect("reload");
+        assert_eq!(tier.min_offset(), 10);
+
+        tier.enforce_ttl(&Duration::ZERO).await.expect("ttl");
+        assert_eq!(tier.min_offset(), -1);
+    }
+
+    #[fluvio_future::test]
+    async fn test_remove_oldest() {
+        let test_dir = temp_dir().join("tiered-remove-oldest");
+        ensure_new_dir(&test_dir).expect("new");
+        let replica_dir = test_dir.join("replica");
+        let store_dir = test_dir.join("store");
+        ensure_new_dir(&replica_dir).expect("new");
+
+        let option = ReplicaConfig {
+            base_dir: replica_dir,
+            segment_max_bytes: 1000,
+            index_max_bytes: 1000,
+            ..Default::default()
+        }
+        .shared();
+
+        let config = TieredStorageConfig::local(store_dir);
+        let tier = RemoteTier::load(&config, "test-0".to_owned(), option.clone())
+            .await
+            .expect("load");
+        for base_offset in [10, 20] {
+            let mut active = MutableSegment::create(base_offset, option.clone())
+                .await
+                .expect("create");
+            active
+                .append_batch(&mut create_batch())
+                .await
+                .expect("append");
+            active.roll_over().await.expect("roll over");
+            let segment = active.as_segment().await.expect("segment");
+            tier.offload(segment.get_base_offset(), segment.get_end_offset())
+                .await
+                .expect("offload");
+            segment.remove().await.expect("remove");
+        }
+        let total = tier.size().await;
+        assert!(total > 0);
+
+        // removing single byte removes whole oldest segment
+        let freed = tier.remove_oldest(1).await.expect("remove");
+        assert_eq!(freed * 2, total);
+        assert_eq!(tier.size().await, total - freed);
+        assert_eq!(tier.min_offset(), 20);
+
+        assert_eq!(tier.remove_oldest(total).await.expect("remove"), freed);
+        assert_eq!(tier.size().await, 0);
+        assert_eq!(tier.min_offset(), -1);
+    }
+
+    #[test]
+    fn test_find_evicted() {
+        let now = Instant::now();
+        let idle = now - REMOTE_CACHE_READ_GRACE;
+        let manifest = RemoteManifest {
+            segments: (1..=6)
+                .map(|base_offset| {
+                    (
+                        base_offset,
+                        RemoteSegment {
+                            base_offset,
+                            end_offset: base_offset + 1,
+                            size: 100,
+                            last_modified: 0,
+                        },
+                    )
+                })
+                .collect(),
+        };
+
+        // under capacity, nothing is evicted
+        let last_reads = BTreeMap::from([(1, idle), (2, idle)]);
+        assert!(find_evicted(&last_reads, &manifest, now).is_empty());
+
+        // over capacity, least recently read are evicted first
+        let last_reads = BTreeMap::from([
+            (1, idle),
+            (2, idle - Duration::from_secs(1)),
+            (3, idle),
+            (4, idle),
+            (5, idle),
+            (6, idle),
+        ]);
+        assert_eq!(find_evicted(&last_reads, &manifest, now), vec![2, 1]);
+
+        // segments which are being read are kept even if cache is over capacity
+        let last_reads =
+            BTreeMap::from([(1, now), (2, now), (3, now), (4, now), (5, now), (6, idle)]);
+        assert_eq!(find_evicted(&last_reads, &manifest, now), vec![6]);
+
+        // segment removed from manifest is evicted once it is not read
+        let last_reads = BTreeMap::from([(7, idle), (8, now)]);
+        assert_eq!(find_evicted(&last_reads, &manifest, now), vec![7]);
+    }
+
+    #[fluvio_future::test]
+    async fn test_move_prefix() {
+        let test_dir = temp_dir().join("tiered-move-prefix");
+        ensure_new_dir(&test_dir).expect("new");
+        let replica_dir = test_dir.join("replica");
+        let store_dir = test_dir.join("store");
+        ensure_new_dir(&replica_dir).expect("new");
+
+        let option = ReplicaConfig {
+            base_dir: replica_dir,
+            segment_max_bytes: 1000,
+            index_max_bytes: 1000,
+            ..Default::default()
+        }
+        .shared();
+
+        let mut active = MutableSegment::create(10, option.clone())
+            .await
+            .expect("create");
+        active
+            .append_batch(&mut create_batch())
+            .await
+            .expect("append");
+        active.roll_over().await.expect("roll over");
+        let segment = active.as_segment().await.expect("segment");
+
+        let config = TieredStorageConfig::local(store_dir);
+        let tier = RemoteTier::load(&config, "old-0".to_owned(), option.clone())
+            .await
+            .expect("load");
+        tier.offload(segment.get_base_offset(), segment.get_end_offset())
+            .await
+            .expect("offload");
+        segment.remove().await.expect("remove");
+
+        RemoteTier::move_prefix(&config, "old-0", "new-0")
+            .await
+            .expect("move");
+
+        let tier = RemoteTier::load(&config, "new-0".to_owned(), option.clone())
+            .await
+            .expect("load new");
+        assert_eq!(tier.min_offset(), 10);
+        assert!(tier.find_slice(10, None).await.expect("find").is_some());
+
+        let old = RemoteTier::load(&config, "old-0".to_owned(), option)
+            .await
+            .expect("load old");
+        assert_eq!(old.min_offset(), -1);
+    }
+}
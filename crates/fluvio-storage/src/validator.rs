@@ -1,9 +1,14 @@
 use std::io::ErrorKind;
+use std::mem::size_of;
 use std::path::Path;
 use std::path::PathBuf;
 use std::time::Duration;
 
-use fluvio_protocol::record::BatchRecords;
+use fluvio_future::file_slice::AsyncFileSlice;
+use fluvio_protocol::Decoder;
+use fluvio_protocol::record::{
+    Batch, BatchRecords, RawRecords, BATCH_FILE_HEADER_SIZE, BATCH_PREAMBLE_SIZE,
+};
 use tracing::error;
 use tracing::info;
 use tracing::instrument;
@@ -13,11 +18,12 @@ use anyhow::{Result, anyhow};
 
 use fluvio_protocol::record::Offset;
 
+use crate::batch::BatchChecksumError;
 use crate::batch::BatchHeaderError;
 use crate::batch::FileBatchStream;
 use crate::batch::StorageBytesIterator;
 use crate::batch_header::FileEmptyRecords;
-use crate::file::FileBytesIterator;
+use crate::file::{FileBytesIterator, read_file_slice};
 use crate::index::Index;
 use crate::util::log_path_get_offset;
 
@@ -52,10 +58,16 @@ pub struct LogValidator {
     pub duration: Duration,
     pub error: Option<LogValidationError>,
     pub index_error: Option<InvalidIndexError>,
+    /// first batch with crc mismatch, only checked when validating with checksum
+    pub checksum_error: Option<BatchChecksumError>,
 }
 
 impl LogValidator {
-    async fn validate_core<I, S, R>(path: impl AsRef<Path>, index: Option<&I>) -> Result<Self>
+    async fn validate_core<I, S, R>(
+        path: impl AsRef<Path>,
+        index: Option<&I>,
+        verify_checksum: bool,
+    ) -> Result<Self>
     where
         I: Index,
         S: StorageBytesIterator,
@@ -89,7 +101,10 @@ impl LogValidator {
 
         // find recoverable error
 
-        if let Err(err) = val.validate_with_stream(batch_stream, index).await {
+        if let Err(err) = val
+            .validate_with_stream(batch_stream, index, verify_checksum)
+            .await
+        {
             error!(%err, "found error from stream");
             error!("{:#?} debug", err);
             val.duration = start_time.elapsed();
@@ -113,6 +128,7 @@ impl LogValidator {
         &mut self,
         mut batch_stream: FileBatchStream<R, S>,
         index: Option<&I>,
+        verify_checksum: bool,
     ) -> Result<()>
     where
         I: Index,
@@ -145,6 +161,26 @@ impl LogValidator {
                 return Ok(());
             }
 
+            // corrupted batch doesn't stop validation, rest of the log is still scanned
+            if verify_checksum && self.checksum_error.is_none() {
+                let computed = current_batch.compute_crc()?;
+                if computed != header.crc {
+                    error!(
+                        current_batch_offset,
+                        current_batch_pos,
+                        expected = header.crc,
+                        computed,
+                        "batch checksum mismatch"
+                    );
+                    self.checksum_error = Some(BatchChecksumError {
+                        base_offset: current_batch_offset,
+                        pos: current_batch_pos,
+                        expected: header.crc,
+                        computed,
+                    });
+                }
+            }
+
             // set high watermark for validating batches
             self.last_valid_offset = current_batch_offset + offset_delta as Offset;
             self.last_valid_batch_pos = current_batch_pos;
@@ -245,7 +281,19 @@ impl LogValidator {
         I: Index,
         S: StorageBytesIterator,
     {
-        Self::validate_core::<I, S, FileEmptyRecords>(path, index).await
+        Self::validate_core::<I, S, FileEmptyRecords>(path, index, false).await
+    }
+
+    /// validate log file and verify crc of every batch
+    #[instrument(skip(index, path))]
+    pub(crate) async fn checksum_validate<I>(
+        path: impl AsRef<Path>,
+        index: Option<&I>,
+    ) -> Result<Self>
+    where
+        I: Index,
+    {
+        Self::validate_core::<I, FileBytesIterator, RawRecords>(path, index, true).await
     }
 
     #[instrument(skip(index, path))]
@@ -260,6 +308,81 @@ impl LogValidator {
     }
 }
 
+/// outcome of verifying crc of batches in a slice
+#[derive(Debug, Default)]
+pub(crate) struct SliceChecksum {
+    /// first batch which doesn't match
+    pub checksum_error: Option<BatchChecksumError>,
+    /// end offset of batches verified without gap from the verified offset passed in
+    pub verified_offset: Option<Offset>,
+}
+
+/// verify crc of batches in the slice which end at or after `verified_offset`.
+/// Only headers of already verified batches are read, rest of the slice is read at once.
+/// batch cut by the end of the slice is not checked.
+pub(crate) async fn verify_slice_checksum(
+    slice: &AsyncFileSlice,
+    verified_offset: Offset,
+) -> Result<SliceChecksum> {
+    let end = slice.position() + slice.len();
+    let mut pos = slice.position();
+    let first_base_offset = loop {
+        if pos + BATCH_FILE_HEADER_SIZE as u64 > end {
+            return Ok(SliceChecksum::default());
+        }
+        let header_slice = AsyncFileSlice::new(slice.fd(), pos, BATCH_FILE_HEADER_SIZE as u64);
+        let bytes = read_file_slice(&header_slice).await?;
+        if bytes.len() < BATCH_FILE_HEADER_SIZE {
+            return Ok(SliceChecksum::default());
+        }
+        let mut header: Batch<FileEmptyRecords> = Batch::default();
+        header.decode_from_file_buf(&mut &bytes[..], 0)?;
+        if header.get_last_offset() >= verified_offset {
+            break header.get_base_offset();
+        }
+        pos += (BATCH_PREAMBLE_SIZE + header.batch_len().max(0) as usize) as u64;
+    };
+    trace!(
+        pos,
+        first_base_offset,
+        verified_offset,
+        "verifying from batch"
+    );
+
+    let bytes = read_file_slice(&AsyncFileSlice::new(slice.fd(), pos, end - pos)).await?;
+    let mut relative_pos = 0;
+    let mut next_offset = None;
+    while bytes.len() - relative_pos >= BATCH_PREAMBLE_SIZE {
+        let mut batch_len: i32 = 0;
+        batch_len.decode(&mut &bytes[relative_pos + size_of::<Offset>()..], 0)?;
+        let batch_size = BATCH_PREAMBLE_SIZE + batch_len.max(0) as usize;
+        if relative_pos + batch_size > bytes.len() {
+            break;
+        }
+
+        let mut batch: Batch<RawRecords> = Batch::default();
+        batch.decode(&mut &bytes[relative_pos..relative_pos + batch_size], 0)?;
+        let computed = batch.compute_crc()?;
+        if computed != batch.header.crc {
+            return Ok(SliceChecksum {
+                checksum_error: Some(BatchChecksumError {
+                    base_offset: batch.get_base_offset(),
+                    pos: (pos + relative_pos as u64) as u32,
+                    expected: batch.header.crc,
+                    computed,
+                }),
+                verified_offset: None,
+            });
+        }
+        next_offset = Some(batch.get_last_offset() + 1);
+        relative_pos += batch_size;
+    }
+    Ok(SliceChecksum {
+        checksum_error: None,
+        verified_offset: next_offset.filter(|_| first_base_offset <= verified_offset),
+    })
+}
+
 /// validate the file and find last offset
 /// if file is not valid then return error
 
@@ -431,6 +554,64 @@ mod tests {
         let err = validator.error.expect("error");
         assert!(matches!(err, LogValidationError::BatchDecoding(_)));
     }
+
+    #[fluvio_future::test]
+    async fn test_validate_checksum_mismatch() {
+        const OFFSET: i64 = 701;
+
+        let test_dir = temp_dir().join("validate_checksum_mismatch");
+        ensure_new_dir(&test_dir).expect("new");
+
+        let options = ReplicaConfig {
+            base_dir: test_dir,
+            segment_max_bytes: 1000,
+            ..Default::default()
+        }
+        .shared();
+
+        let mut msg_sink = MutFileRecords::create(OFFSET, options)
+            .await
+            .expect("create");
+
+        let mut builder = BatchProducer::builder()
+            .base_offset(OFFSET)
+            .build()
+            .expect("build");
+
+        // write 3 batches with 3 records
+        for _ in 0..3 {
+            msg_sink
+                .write_batch(&builder.batch_records(3))
+                .await
+                .expect("write");
+        }
+        msg_sink.flush().await.expect("flush");
+        let test_fs_path = msg_sink.get_path().to_owned();
+        drop(msg_sink);
+
+        // flip last byte of second batch
+        let mut bytes = std::fs::read(&test_fs_path).expect("read");
+        let batch_len = bytes.len() / 3;
+        bytes[batch_len * 2 - 1] ^= 0xFF;
+        std::fs::write(&test_fs_path, bytes).expect("write");
+
+        // header only validation doesn't check crc
+        let validator = LogValidator::default_validate::<LogIndex>(&test_fs_path, None)
+            .await
+            .expect("validate");
+        assert!(validator.checksum_error.is_none());
+
+        let validator = LogValidator::checksum_validate::<LogIndex>(&test_fs_path, None)
+            .await
+            .expect("validate");
+        assert!(validator.error.is_none());
+        assert_eq!(validator.leo(), OFFSET + 9);
+        assert_eq!(validator.batches, 3);
+        let checksum_error = validator.checksum_error.expect("checksum error");
+        assert_eq!(checksum_error.base_offset, OFFSET + 3);
+        assert_eq!(checksum_error.pos as usize, batch_len);
+        assert_ne!(checksum_error.expected, checksum_error.computed);
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,106 @@
+//! Conversion between OpenTelemetry span context and trace context carried in request header.
+
+use opentelemetry::trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState};
+use opentelemetry::Context;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use fluvio_protocol::api::TraceContext;
+
+/// span field which starts new trace when span has no sampled parent
+pub const ROOT_FIELD: &str = "otel.root";
+
+/// span field with kind of span: `client`, `server`, `producer` or `consumer`
+pub const KIND_FIELD: &str = "otel.kind";
+
+/// trace context of current span, None if current span is not part of sampled trace
+pub fn current_trace_context() -> Option<TraceContext> {
+    let context = Span::current().context();
+    let span = context.span();
+    let span_context = span.span_context();
+    if !span_context.is_valid() || !span_context.is_sampled() {
+        return None;
+    }
+    Some(TraceContext::new(
+        u128::from_be_bytes(span_context.trace_id().to_bytes()),
+        u64::from_be_bytes(span_context.span_id().to_bytes()),
+        span_context.is_sampled(),
+    ))
+}
+
+/// continue remote trace in `span`, typically with context received in request header
+pub fn set_parent(span: &Span, parent: &TraceContext) {
+    if !parent.is_valid() {
+        return;
+    }
+    let span_context = SpanContext::new(
+        TraceId::from(parent.trace_id()),
+        SpanId::from(parent.span_id()),
+        TraceFlags::new(parent.flags()),
+        true,
+        TraceState::default(),
+    );
+    span.set_parent(Context::new().with_remote_span_context(span_context));
+}
+
+#[cfg(test)]
+mod tests {
+
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry_sdk::trace::{Config, TracerProvider};
+    use tracing::info_span;
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::Registry;
+
+    use crate::otlp::RootSampler;
+
+    use super::*;
+
+    fn subscriber() -> impl tracing::Subscriber + Send + Sync {
+        let provider = TracerProvider::builder()
+            .with_config(Config::default().with_sampler(RootSampler::new(1.0)))
+            .build();
+        Registry::default()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")))
+    }
+
+    #[test]
+    fn test_trace_context_propagation() {
+        tracing::subscriber::with_default(subscriber(), || {
+            info_span!("untraced").in_scope(|| {
+                assert!(current_trace_context().is_none());
+            });
+
+            info_span!("produce", otel.root = true, otel.kind = "client").in_scope(|| {
+                let root_context = current_trace_context().expect("root context");
+                assert!(root_context.is_sampled());
+                info_span!("send", partition = 1).in_scope(|| {
+                    let child_context = current_trace_context().expect("child context");
+                    assert_eq!(child_context.trace_id(), root_context.trace_id());
+                    assert_ne!(child_context.span_id(), root_context.span_id());
+                });
+            });
+        });
+    }
+
+    #[test]
+    fn test_trace_context_remote_parent() {
+        tracing::subscriber::with_default(subscriber(), || {
+            let remote = TraceContext::new(0xabc, 0x123, true);
+            let span = info_span!("handle_produce_request");
+            set_parent(&span, &remote);
+            span.in_scope(|| {
+                let context = current_trace_context().expect("context");
+                assert_eq!(context.trace_id(), 0xabc);
+                assert_ne!(context.span_id(), 0x123);
+            });
+
+            let remote = TraceContext::new(0xabc, 0x123, false);
+            let span = info_span!("handle_produce_request", otel.root = true);
+            set_parent(&span, &remote);
+            span.in_scope(|| {
+                assert!(current_trace_context().is_none());
+            });
+        });
+    }
+}
@@ -0,0 +1,104 @@
+//! Distributed tracing for Fluvio components.
+//!
+//! When `OTEL_EXPORTER_OTLP_ENDPOINT` (or `OTEL_EXPORTER_OTLP_TRACES_ENDPOINT`) is set,
+//! spans which are part of a sampled trace are exported with OpenTelemetry OTLP exporter and
+//! trace context of the current span is attached to outgoing requests which accept it.
+//!
+//! A span starts a new trace when it has `otel.root = true` field and no sampled parent.
+//! Spans handling a request continue the remote trace with [`set_parent`].
+
+mod context;
+mod otlp;
+
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_sdk::trace::TracerProvider;
+use tracing::error;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer, Registry};
+
+pub use fluvio_protocol::api::TraceContext;
+
+pub use self::context::{current_trace_context, set_parent, KIND_FIELD, ROOT_FIELD};
+
+pub const OTLP_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+pub const OTLP_TRACES_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT";
+pub const SERVICE_NAME_ENV: &str = "OTEL_SERVICE_NAME";
+/// ratio of new traces which are sampled, between 0 and 1
+pub const SAMPLER_ARG_ENV: &str = "OTEL_TRACES_SAMPLER_ARG";
+
+const SCOPE_NAME: &str = env!("CARGO_PKG_NAME");
+
+/// Flushes pending spans when dropped
+#[must_use = "pending spans are flushed when guard is dropped"]
+pub struct TelemetryGuard {
+    provider: Option<TracerProvider>,
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        if let Some(provider) = self.provider.take() {
+            if let Err(err) = provider.shutdown() {
+                error!(%err, "unable to flush pending spans");
+            }
+        }
+    }
+}
+
+/// Install global tracing subscriber.
+/// Without OTLP endpoint configured, this is same as `fluvio_future::subscriber::init_tracer`.
+pub fn init_tracer(service_name: &str) -> TelemetryGuard {
+    if !is_export_configured() {
+        fluvio_future::subscriber::init_tracer(None);
+        return TelemetryGuard { provider: None };
+    }
+
+    let service_name = std::env::var(SERVICE_NAME_ENV)
+        .ok()
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| service_name.to_owned());
+    let provider = match otlp::tracer_provider(service_name, sample_ratio()) {
+        Ok(provider) => provider,
+        Err(err) => {
+            fluvio_future::subscriber::init_tracer(None);
+            error!(%err, "unable to start trace exporter");
+            return TelemetryGuard { provider: None };
+        }
+    };
+
+    let installed = Registry::default()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(std::io::stderr)
+                .with_filter(EnvFilter::from_default_env()),
+        )
+        .with(
+            tracing_opentelemetry::layer()
+                .with_tracer(provider.tracer(SCOPE_NAME))
+                .with_filter(LevelFilter::INFO),
+        )
+        .try_init()
+        .is_ok();
+    if installed {
+        fluvio_socket::set_trace_context_provider(current_trace_context);
+    }
+
+    TelemetryGuard {
+        provider: Some(provider),
+    }
+}
+
+fn is_export_configured() -> bool {
+    [OTLP_TRACES_ENDPOINT_ENV, OTLP_ENDPOINT_ENV]
+        .iter()
+        .any(|name| std::env::var(name).is_ok_and(|value| !value.is_empty()))
+}
+
+fn sample_ratio() -> f64 {
+    std::env::var(SAMPLER_ARG_ENV)
+        .ok()
+        .and_then(|ratio| ratio.parse::<f64>().ok())
+        .map(|ratio| ratio.clamp(0.0, 1.0))
+        .unwrap_or(1.0)
+}
@@ -0,0 +1,176 @@
+//! Export of finished spans with OTLP over HTTP.
+
+use opentelemetry::trace::{
+    Link, SamplingDecision, SamplingResult, SpanKind, TraceContextExt, TraceError, TraceId,
+};
+use opentelemetry::{Context, KeyValue, Value};
+use opentelemetry_sdk::runtime::AsyncStd;
+use opentelemetry_sdk::trace::{Config, Sampler, ShouldSample, TracerProvider};
+use opentelemetry_sdk::Resource;
+
+use crate::ROOT_FIELD;
+
+const SERVICE_NAME_KEY: &str = "service.name";
+
+/// create provider exporting sampled spans in batches,
+/// endpoint is resolved from standard `OTEL_EXPORTER_OTLP_*` variables
+pub(crate) fn tracer_provider(
+    service_name: String,
+    sample_ratio: f64,
+) -> Result<TracerProvider, TraceError> {
+    opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(opentelemetry_otlp::new_exporter().http())
+        .with_trace_config(
+            Config::default()
+                .with_sampler(RootSampler::new(sample_ratio))
+                .with_resource(Resource::new([KeyValue::new(
+                    SERVICE_NAME_KEY,
+                    service_name,
+                )])),
+        )
+        .install_batch(AsyncStd)
+}
+
+/// Samples spans which continue a sampled trace.
+///
+/// New trace is started only by span with `otel.root = true` field and sampled with given ratio.
+/// Other spans without sampled parent are dropped.
+#[derive(Debug, Clone)]
+pub(crate) struct RootSampler {
+    ratio: Sampler,
+}
+
+impl RootSampler {
+    pub(crate) fn new(sample_ratio: f64) -> Self {
+        Self {
+            ratio: Sampler::TraceIdRatioBased(sample_ratio),
+        }
+    }
+}
+
+impl ShouldSample for RootSampler {
+    fn should_sample(
+        &self,
+        parent_context: Option<&Context>,
+        trace_id: TraceId,
+        name: &str,
+        span_kind: &SpanKind,
+        attributes: &[KeyValue],
+        links: &[Link],
+    ) -> SamplingResult {
+        let parent = parent_context
+            .map(|cx| cx.span().span_context().clone())
+            .filter(|parent| parent.is_valid());
+
+        let sampled = match &parent {
+            // decision of remote parent is always respected
+            Some(parent) if parent.is_sampled() || parent.is_remote() => parent.is_sampled(),
+            _ if is_root(attributes) => {
+                return self.ratio.should_sample(
+                    parent_context,
+                    trace_id,
+                    name,
+                    span_kind,
+                    attributes,
+                    links,
+                )
+            }
+            _ => false,
+        };
+
+        SamplingResult {
+            decision: if sampled {
+                SamplingDecision::RecordAndSample
+            } else {
+                SamplingDecision::Drop
+            },
+            attributes: vec![],
+            trace_state: parent
+                .map(|parent| parent.trace_state().clone())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+fn is_root(attributes: &[KeyValue]) -> bool {
+    attributes.iter().any(|attribute| {
+        attribute.key.as_str() == ROOT_FIELD && attribute.value == Value::Bool(true)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use opentelemetry::trace::{SpanContext, SpanId, TraceFlags, TraceState};
+
+    use super::*;
+
+    fn sample(sampler: &RootSampler, parent: Option<SpanContext>, root: bool) -> SamplingDecision {
+        let parent = parent.map(|parent| Context::new().with_remote_span_context(parent));
+        let attributes = if root {
+            vec![KeyValue::new(ROOT_FIELD, true)]
+        } else {
+            vec![]
+        };
+        sampler
+            .should_sample(
+                parent.as_ref(),
+                TraceId::from(0xabc_u128),
+                "span",
+                &SpanKind::Internal,
+                &attributes,
+                &[],
+            )
+            .decision
+    }
+
+    fn parent(sampled: bool, remote: bool) -> SpanContext {
+        let flags = if sampled {
+            TraceFlags::SAMPLED
+        } else {
+            TraceFlags::NOT_SAMPLED
+        };
+        SpanContext::new(
+            TraceId::from(0xabc_u128),
+            SpanId::from(0x123_u64),
+            flags,
+            remote,
+            TraceState::default(),
+        )
+    }
+
+    #[test]
+    fn test_root_sampler() {
+        let sampler = RootSampler::new(1.0);
+        assert_eq!(sample(&sampler, None, false), SamplingDecision::Drop);
+        assert_eq!(
+            sample(&sampler, None, true),
+            SamplingDecision::RecordAndSample
+        );
+        assert_eq!(
+            sample(&sampler, Some(parent(true, false)), false),
+            SamplingDecision::RecordAndSample
+        );
+        assert_eq!(
+            sample(&sampler, Some(parent(false, false)), false),
+            SamplingDecision::Drop
+        );
+        // local untraced parent doesn't prevent new trace
+        assert_eq!(
+            sample(&sampler, Some(parent(false, false)), true),
+            SamplingDecision::RecordAndSample
+        );
+        assert_eq!(
+            sample(&sampler, Some(parent(true, true)), false),
+            SamplingDecision::RecordAndSample
+        );
+        assert_eq!(
+            sample(&sampler, Some(parent(false, true)), true),
+            SamplingDecision::Drop
+        );
+
+        let sampler = RootSampler::new(0.0);
+        assert_eq!(sample(&sampler, None, true), SamplingDecision::Drop);
+    }
+}
@@ -18,6 +18,7 @@ pub const SC_DEFAULT_ID: i32 = 1;
 pub const SC_CONFIG_FILE: &str = "sc_server";
 pub const SC_PUBLIC_PORT: u16 = 9003;
 pub const SC_PRIVATE_PORT: u16 = 9004;
+pub const SC_RAFT_PORT: u16 = 9002;
 pub const SC_HOSTNAME: &str = "localhost";
 pub const SC_RECONCILIATION_INTERVAL_SEC: u64 = 60; // 5 min
 
@@ -31,8 +32,14 @@ pub const SPU_PUBLIC_HOSTNAME: &str = "0.0.0.0";
 pub const SPU_PRIVATE_HOSTNAME: &str = "0.0.0.0";
 pub const SPU_CREDENTIALS_FILE: &str = "/etc/fluvio/.credentials/token_secret";
 pub const SPU_RETRY_SC_TIMEOUT_MS: u16 = 3000;
+/// time to hand off leadership of partitions to followers on shutdown
+pub const SPU_SHUTDOWN_TIMEOUT_SEC: u64 = 20;
 pub const SPU_MIN_IN_SYNC_REPLICAS: u16 = 1;
+/// follower which has not caught up with leader for longer than this is out of sync
+pub const SPU_REPLICA_MAX_LAG_SEC: u64 = 10;
 pub const SPU_LOG_BASE_DIR: &str = "/var/lib/fluvio/data";
+/// replicas of a SPU are stored in `{base_dir}/{SPU_LOG_DIR_PREFIX}{id}`
+pub const SPU_LOG_DIR_PREFIX: &str = "spu-logs-";
 pub const SPU_LOG_SIZE: &str = "10Gi";
 pub const SPU_LOG_INDEX_MAX_BYTES: u32 = 10485760;
 pub const SPU_LOG_INDEX_MAX_INTERVAL_BYTES: u32 = 4096;
@@ -2,6 +2,7 @@ use std::convert::TryFrom;
 use std::fmt::Debug;
 use std::io::Error as IoError;
 use std::io::ErrorKind;
+use std::sync::Arc;
 
 use futures_util::{Stream, StreamExt};
 use tracing::{debug, trace, instrument};
@@ -10,6 +11,7 @@ use anyhow::{Result, anyhow};
 use fluvio_sc_schema::objects::ObjectApiUpdateRequest;
 use fluvio_sc_schema::objects::UpdateRequest;
 use fluvio_sc_schema::UpdatableAdminSpec;
+use fluvio_sc_schema::limits::{TopicLimitsRequest, TopicLimitsResponse};
 use fluvio_protocol::{Decoder, Encoder};
 use fluvio_protocol::api::{Request, RequestMessage};
 use fluvio_future::net::DomainConnector;
@@ -19,12 +21,13 @@ use fluvio_sc_schema::objects::{
     CommonCreateRequest,
 };
 use fluvio_sc_schema::{AdminSpec, DeletableAdminSpec, CreatableAdminSpec, TryEncodableFrom};
-use fluvio_socket::{ClientConfig, VersionedSerialSocket, SerialFrame, MultiplexerSocket};
+use fluvio_socket::{ClientConfig, Versions, VersionedSerialSocket, SerialFrame, MultiplexerSocket};
 
 use crate::FluvioConfig;
 use crate::metadata::objects::{ListResponse, ListRequest};
 use crate::config::ConfigFile;
-use crate::sync::MetadataStores;
+use crate::sync::{MetadataStores, ScConnection};
+use crate::fluvio::connect_to_sc;
 
 /// An interface for managing a Fluvio cluster
 ///
@@ -59,14 +62,51 @@ use crate::sync::MetadataStores;
 /// [`connect`]: ./struct.FluvioAdmin.html#method.connect
 /// [`connect_with_config`]: ./struct.FluvioAdmin.html#method.connect_with_config
 pub struct FluvioAdmin {
-    socket: VersionedSerialSocket,
+    connection: Arc<ScConnection>,
+    config: Arc<ClientConfig>,
+    versions: Versions,
     #[allow(dead_code)]
     metadata: MetadataStores,
+    tenant: Option<String>,
 }
 
 impl FluvioAdmin {
-    pub(crate) fn new(socket: VersionedSerialSocket, metadata: MetadataStores) -> Self {
-        Self { socket, metadata }
+    pub(crate) fn new(
+        connection: Arc<ScConnection>,
+        config: Arc<ClientConfig>,
+        versions: Versions,
+        metadata: MetadataStores,
+    ) -> Self {
+        Self {
+            connection,
+            config,
+            versions,
+            metadata,
+            tenant: None,
+        }
+    }
+
+    /// socket to SC, re-established if SC connection was lost
+    async fn socket(&self) -> Result<VersionedSerialSocket> {
+        let socket = self.connection.socket().await?;
+        Ok(VersionedSerialSocket::new(
+            socket,
+            self.config.clone(),
+            self.versions.clone(),
+        ))
+    }
+
+    pub(crate) fn with_tenant(mut self, tenant: Option<String>) -> Self {
+        self.tenant = tenant;
+        self
+    }
+
+    /// Name of object `name` of spec `S` in the tenant of this client
+    pub fn qualified_name<S: CreatableAdminSpec>(&self, name: String) -> String {
+        match &self.tenant {
+            Some(tenant) => S::qualify_name(name, tenant),
+            None => name,
+        }
     }
 
     /// Creates a new admin connection using the current profile from `~/.fluvio/config`
@@ -119,20 +159,28 @@ impl FluvioAdmin {
     #[instrument(skip(config))]
     pub async fn connect_with_config(config: &FluvioConfig) -> Result<Self> {
         let connector = DomainConnector::try_from(config.tls.clone())?;
-        let client_config =
-            ClientConfig::new(&config.endpoint, connector, config.use_spu_local_address);
-        let inner_client = client_config.connect().await?;
+        let reconnector = connector.new_domain(connector.domain().to_owned());
+        let inner_client = connect_to_sc(config, connector, None).await?;
         debug!(addr = %inner_client.config().addr(), "connected to cluster");
+        let tenant = config.tenant.clone();
+        let cluster_config = config.clone();
 
         let (socket, config, versions) = inner_client.split();
         if let Some(watch_version) = versions.lookup_version::<ObjectApiWatchRequest>() {
-            let socket = MultiplexerSocket::shared(socket);
-            let metadata = MetadataStores::start(socket.clone(), watch_version).await?;
-            let versioned_socket = VersionedSerialSocket::new(socket, config, versions);
+            let connection = ScConnection::new(
+                MultiplexerSocket::shared(socket),
+                cluster_config,
+                reconnector,
+                None,
+            );
+            let metadata = MetadataStores::start(connection.clone(), watch_version).await?;
 
             Ok(Self {
-                socket: versioned_socket,
+                connection,
+                config,
+                versions,
                 metadata,
+                tenant,
             })
         } else {
             let platform_version = versions.platform_version();
@@ -147,13 +195,13 @@ impl FluvioAdmin {
         R: Request + Send + Sync,
         R: TryEncodableFrom<I>,
     {
-        let version = self
-            .socket
+        let socket = self.socket().await?;
+        let version = socket
             .lookup_version::<R>()
             .ok_or(anyhow!("no version found for: {}", R::API_KEY))?;
         let request = R::try_encode_from(request, version)?;
-        let req_msg = self.socket.new_request(request, Some(version));
-        self.socket
+        let req_msg = socket.new_request(request, Some(version));
+        socket
             .send_and_receive(req_msg)
             .await
             .map_err(|err| err.into())
@@ -175,10 +223,15 @@ impl FluvioAdmin {
     }
 
     #[instrument(skip(self, config, spec))]
-    pub async fn create_with_config<S>(&self, config: CommonCreateRequest, spec: S) -> Result<()>
+    pub async fn create_with_config<S>(
+        &self,
+        mut config: CommonCreateRequest,
+        spec: S,
+    ) -> Result<()>
     where
         S: CreatableAdminSpec + Sync + Send,
     {
+        config.name = self.qualified_name::<S>(config.name);
         let create_request = CreateRequest::new(config, spec);
         debug!("sending create request: {:#?}", create_request);
 
@@ -210,7 +263,7 @@ impl FluvioAdmin {
     where
         S: DeletableAdminSpec + Sync + Send,
     {
-        let delete_request: DeleteRequest<S> = DeleteRequest::new(key.into());
+        let delete_request: DeleteRequest<S> = DeleteRequest::new(self.delete_key::<S>(key));
         debug!("sending delete request: {:#?}", delete_request);
 
         self.send_receive_admin::<ObjectApiDeleteRequest, _>(delete_request)
@@ -242,7 +295,7 @@ impl FluvioAdmin {
     where
         S: DeletableAdminSpec + Sync + Send,
     {
-        let delete_request: DeleteRequest<S> = DeleteRequest::with(key.into(), true);
+        let delete_request: DeleteRequest<S> = DeleteRequest::with(self.delete_key::<S>(key), true);
         debug!("sending force delete request: {:#?}", delete_request);
 
         self.send_receive_admin::<ObjectApiDeleteRequest, _>(delete_request)
@@ -262,7 +315,11 @@ impl FluvioAdmin {
     where
         S: UpdatableAdminSpec + Sync + Send,
     {
-        let update_request: UpdateRequest<S> = UpdateRequest::new(key.into(), action);
+        let key = match &self.tenant {
+            Some(tenant) => S::qualify_update_key(key.into(), tenant),
+            None => key.into(),
+        };
+        let update_request: UpdateRequest<S> = UpdateRequest::new(key, action);
         debug!("sending update request: {:#?}", update_request);
 
         self.send_receive_admin::<ObjectApiUpdateRequest, _>(update_request)
@@ -271,6 +328,13 @@ impl FluvioAdmin {
         Ok(())
     }
 
+    fn delete_key<S: DeletableAdminSpec>(&self, key: impl Into<S::DeleteKey>) -> S::DeleteKey {
+        match &self.tenant {
+            Some(tenant) => S::qualify_delete_key(key.into(), tenant),
+            None => key.into(),
+        }
+    }
+
     /// return all instance of this spec
     #[instrument(skip(self))]
     pub async fn all<S>(&self) -> Result<Vec<Metadata<S>>>
@@ -326,6 +390,20 @@ impl FluvioAdmin {
             .map(|out: ListResponse<S>| out.inner())
     }
 
+    /// Cluster limits of topics, with highest usage of each limit
+    #[instrument(skip(self))]
+    pub async fn topic_limits(&self) -> Result<TopicLimitsResponse> {
+        let socket = self.socket().await?;
+        let version = socket
+            .lookup_version::<TopicLimitsRequest>()
+            .ok_or(anyhow!("topic limits are not supported by the cluster"))?;
+        let req_msg = socket.new_request(TopicLimitsRequest, Some(version));
+        socket
+            .send_and_receive(req_msg)
+            .await
+            .map_err(|err| err.into())
+    }
+
     /// Watch stream of changes for metadata
     /// There is caching, this is just pass through
     #[instrument(skip(self))]
@@ -336,8 +414,8 @@ impl FluvioAdmin {
     {
         // only summary for watch
         let watch_request: WatchRequest<S> = WatchRequest::summary();
-        let version = self
-            .socket
+        let socket = self.socket().await?;
+        let version = socket
             .lookup_version::<ObjectApiWatchRequest>()
             .ok_or(anyhow!(
                 "no version found watch request {}",
@@ -347,7 +425,7 @@ impl FluvioAdmin {
         let watch_req = ObjectApiWatchRequest::try_encode_from(watch_request, version)?;
         let req_msg = RequestMessage::new_request(watch_req);
         debug!(api_version = req_msg.header.api_version(), obj = %S::LABEL, "create watch stream");
-        let inner_socket = self.socket.new_socket();
+        let inner_socket = socket.new_socket();
         let stream = inner_socket.create_stream(req_msg, 10).await?;
         Ok(stream.map(|respons_result| match respons_result {
             Ok(response) => {
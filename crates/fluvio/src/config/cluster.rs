@@ -15,7 +15,8 @@ use super::ConfigFile;
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct FluvioConfig {
-    /// The address to connect to the Fluvio cluster
+    /// The address to connect to the Fluvio cluster,
+    /// instances of replicated SC are listed separated by comma
     // TODO use a validated address type.
     // We don't want to have a "" address.
     #[serde(alias = "addr")]
@@ -34,6 +35,11 @@ pub struct FluvioConfig {
     #[serde(default = "Metadata::new", skip_serializing_if = "Metadata::is_empty")]
     metadata: Metadata,
 
+    /// Tenant of the client principal. Plain names of topics, SmartModules and table formats
+    /// are qualified with it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+
     /// This is not part of profile and doesn't persist.
     /// It is purely to override client id when creating ClientConfig
     #[serde(skip)]
@@ -55,6 +61,7 @@ impl FluvioConfig {
             use_spu_local_address: false,
             tls: TlsPolicy::Disabled,
             metadata: Metadata::new(),
+            tenant: None,
             client_id: None,
         }
     }
@@ -65,6 +72,12 @@ impl FluvioConfig {
         self
     }
 
+    /// Set tenant of objects addressed by this cluster config.
+    pub fn with_tenant(mut self, tenant: impl Into<String>) -> Self {
+        self.tenant = Some(tenant.into());
+        self
+    }
+
     pub fn query_metadata_by_name<'de, T>(&self, name: &str) -> Option<T>
     where
         T: Deserialize<'de>,
@@ -146,6 +159,21 @@ name = "foo"
         );
     }
 
+    #[test]
+    fn test_tenant() {
+        let toml = r#"version = "2"
+[profile.local]
+cluster = "local"
+
+[cluster.local]
+endpoint = "127.0.0.1:9003"
+tenant = "team-a"
+"#;
+        let profile = Config::load_str(toml).unwrap();
+        let config = profile.cluster("local").unwrap();
+        assert_eq!(config.tenant.as_deref(), Some("team-a"));
+    }
+
     #[test]
     fn test_create_metadata() {
         let toml = r#"version = "2"
@@ -54,9 +54,16 @@ pub enum OffsetManagementStrategy {
 }
 
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ConsumerConfigExt {
-    #[builder(setter(into))]
+    #[builder(default, setter(into))]
     pub topic: String,
+    /// Additional topics to consume from, together with `topic`
+    #[builder(default, setter(custom))]
+    pub topics: Vec<String>,
+    /// Consume from all topics whose whole name matches the regex, including topics created later
+    #[builder(default, setter(strip_option, into))]
+    pub topic_pattern: Option<String>,
     #[builder(default, setter(custom))]
     pub partition: Vec<PartitionId>,
     #[builder(default, setter(strip_option, into))]
@@ -64,12 +71,15 @@ pub struct ConsumerConfigExt {
     #[builder(default, setter(strip_option, into))]
     pub offset_consumer: Option<String>,
     pub offset_start: Offset,
+    /// Where to start consuming partitions which are created after the consumer started
+    #[builder(default = "Offset::beginning()")]
+    pub offset_new_partitions: Offset,
     #[builder(default)]
     pub offset_strategy: OffsetManagementStrategy,
     #[builder(default = "DEFAULT_OFFSET_FLUSH_PERIOD")]
     pub offset_flush: Duration,
     #[builder(default)]
-    disable_continuous: bool,
+    pub(crate) disable_continuous: bool,
     #[builder(default = "*MAX_FETCH_BYTES")]
     pub max_bytes: i32,
     #[builder(default)]
@@ -83,6 +93,11 @@ impl ConsumerConfigExt {
         ConsumerConfigExtBuilder::default()
     }
 
+    /// true if records may come from more than one topic
+    pub fn is_multi_topic(&self) -> bool {
+        !self.topics.is_empty() || self.topic_pattern.is_some()
+    }
+
     pub fn into_parts(
         self,
     ) -> (
@@ -94,10 +109,13 @@ impl ConsumerConfigExt {
     ) {
         let Self {
             topic: _,
+            topics: _,
+            topic_pattern: _,
             partition: _,
             mirror: _,
             offset_consumer,
             offset_start,
+            offset_new_partitions: _,
             disable_continuous,
             max_bytes,
             isolation,
@@ -128,16 +146,58 @@ impl ConsumerConfigExtBuilder {
         self.partition.get_or_insert(Vec::new()).push(value);
         self
     }
+
+    pub fn topics<I, T>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.topics
+            .get_or_insert(Vec::new())
+            .extend(values.into_iter().map(Into::into));
+        self
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let has_topic = self.topic.as_ref().is_some_and(|topic| !topic.is_empty());
+        let has_topics = self
+            .topics
+            .as_ref()
+            .is_some_and(|topics| !topics.is_empty());
+        let topic_pattern = self
+            .topic_pattern
+            .as_ref()
+            .and_then(|pattern| pattern.as_ref());
+        if !has_topic && !has_topics && topic_pattern.is_none() {
+            return Err("one of `topic`, `topics` or `topic_pattern` must be set".to_string());
+        }
+        if let Some(pattern) = topic_pattern {
+            regex::Regex::new(pattern).map_err(|err| format!("invalid topic pattern: {err}"))?;
+        }
+
+        let has_partition = self
+            .partition
+            .as_ref()
+            .is_some_and(|partition| !partition.is_empty());
+        let has_mirror = self.mirror.as_ref().is_some_and(|mirror| mirror.is_some());
+        if (has_topics || topic_pattern.is_some()) && (has_partition || has_mirror) {
+            return Err("`partition` and `mirror` require a single topic".to_string());
+        }
+        Ok(())
+    }
 }
 
 impl From<ConsumerConfigExt> for ConsumerConfig {
     fn from(value: ConsumerConfigExt) -> Self {
         let ConsumerConfigExt {
             topic: _,
+            topics: _,
+            topic_pattern: _,
             partition: _,
             mirror: _,
             offset_consumer: _,
             offset_start: _,
+            offset_new_partitions: _,
             offset_strategy: _,
             offset_flush: _,
             disable_continuous,
@@ -154,3 +214,36 @@ impl From<ConsumerConfigExt> for ConsumerConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_consumer_config_ext_topics_validation() {
+        let mut builder = ConsumerConfigExt::builder();
+        builder.offset_start(Offset::beginning());
+        assert!(builder.build().is_err(), "topic is required");
+
+        let config = builder
+            .clone()
+            .topics(["a", "b"])
+            .topics(["c"])
+            .build()
+            .expect("topics");
+        assert_eq!(config.topics, ["a", "b", "c"]);
+        assert!(config.is_multi_topic());
+
+        assert!(builder.clone().topic_pattern("(unclosed").build().is_err());
+        assert!(builder
+            .clone()
+            .topic_pattern("events-.*")
+            .partition(0)
+            .build()
+            .is_err());
+
+        let config = builder.topic("a").partition(0).build().expect("topic");
+        assert!(!config.is_multi_topic());
+    }
+}
@@ -0,0 +1,307 @@
+use std::sync::atomic::{AtomicI64, AtomicU32};
+use std::sync::Arc;
+
+use async_channel::{Sender, bounded};
+use tracing::debug;
+
+use fluvio_protocol::link::ErrorCode;
+use fluvio_protocol::record::ReplicaKey;
+use fluvio_socket::VersionedSerialSocket;
+use fluvio_spu_schema::server::stream_fetch::DefaultStreamFetchResponse;
+use fluvio_spu_schema::server::update_stream_fetch::{StreamFetchAction, UpdateStreamFetchRequest};
+use fluvio_types::{PartitionId, Timestamp};
+
+use crate::offset::{Offset, fetch_offsets};
+
+use super::{StreamToServer, StreamToServerCallback};
+
+const DEFAULT_ORDERING: std::sync::atomic::Ordering = std::sync::atomic::Ordering::SeqCst;
+/// start offset of pending seek is resolved by the SPU
+const UNKNOWN_OFFSET: i64 = -1;
+
+/// Position of a partition stream, shared between the stream and its [`PartitionControl`]
+#[derive(Debug)]
+pub(crate) struct StreamPosition {
+    /// responses with lower epoch were sent by the SPU before the last seek
+    epoch: AtomicU32,
+    /// records before this offset are skipped
+    start_offset: AtomicI64,
+    /// start offset of seek sent to the SPU, applied once epoch of the seek is seen
+    pending_start_offset: AtomicI64,
+    /// offset of the next record yielded by the stream
+    next_offset: AtomicI64,
+    high_watermark: AtomicI64,
+}
+
+impl StreamPosition {
+    pub(crate) fn new(start_offset: i64) -> Self {
+        Self {
+            epoch: AtomicU32::new(0),
+            start_offset: AtomicI64::new(start_offset),
+            pending_start_offset: AtomicI64::new(UNKNOWN_OFFSET),
+            next_offset: AtomicI64::new(start_offset),
+            high_watermark: AtomicI64::new(start_offset),
+        }
+    }
+
+    pub(crate) fn epoch(&self) -> u32 {
+        self.epoch.load(DEFAULT_ORDERING)
+    }
+
+    pub(crate) fn is_stale(&self, epoch: u32) -> bool {
+        epoch < self.epoch()
+    }
+
+    /// Response was sent before the last seek, errors are never stale.
+    /// Records of the seek may arrive before its reply, newer epoch of response applies the pending seek.
+    pub(crate) fn is_stale_response(&self, response: &DefaultStreamFetchResponse) -> bool {
+        if !response.partition.error_code.is_ok() {
+            return false;
+        }
+        let pending_start_offset = self.pending_start_offset.load(DEFAULT_ORDERING);
+        self.advance(response.epoch, pending_start_offset);
+        self.is_stale(response.epoch)
+    }
+
+    pub(crate) fn start_offset(&self) -> i64 {
+        self.start_offset.load(DEFAULT_ORDERING)
+    }
+
+    pub(crate) fn update_high_watermark(&self, high_watermark: i64) {
+        self.high_watermark.store(high_watermark, DEFAULT_ORDERING);
+    }
+
+    pub(crate) fn record_consumed(&self, offset: i64) {
+        self.next_offset.store(offset + 1, DEFAULT_ORDERING);
+    }
+
+    /// remember start offset of seek before it is sent, `None` if it is resolved by the SPU
+    fn begin_seek(&self, start_offset: Option<i64>) {
+        self.pending_start_offset
+            .store(start_offset.unwrap_or(UNKNOWN_OFFSET), DEFAULT_ORDERING);
+    }
+
+    /// apply seek acknowledged by the SPU
+    fn seek(&self, epoch: u32, offset: i64) {
+        if !self.advance(epoch, offset) && epoch == self.epoch() {
+            // epoch was already seen in responses, offset of timestamp seek is known only now
+            self.start_offset.fetch_max(offset, DEFAULT_ORDERING);
+            self.next_offset.fetch_max(offset, DEFAULT_ORDERING);
+        }
+    }
+
+    /// move to newer epoch, returns false if epoch is not newer
+    fn advance(&self, epoch: u32, start_offset: i64) -> bool {
+        if self.epoch.fetch_max(epoch, DEFAULT_ORDERING) >= epoch {
+            return false;
+        }
+        // records of the new epoch start at the resolved offset, nothing to skip if unknown
+        let start_offset = start_offset.max(0);
+        self.start_offset.store(start_offset, DEFAULT_ORDERING);
+        self.next_offset.store(start_offset, DEFAULT_ORDERING);
+        true
+    }
+
+    fn lag(&self) -> i64 {
+        let high_watermark = self.high_watermark.load(DEFAULT_ORDERING);
+        (high_watermark - self.next_offset.load(DEFAULT_ORDERING)).max(0)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum StreamUpdate {
+    Pause,
+    Resume,
+    Seek(Offset),
+    SeekTimestamp(Timestamp),
+}
+
+/// Handle to pause, resume and seek the stream of a single partition.
+///
+/// Updates are sent to the SPU over the connection of the stream. Records sent
+/// before a seek is applied by the SPU are discarded.
+#[derive(Debug, Clone)]
+pub struct PartitionControl {
+    replica: ReplicaKey,
+    stream_to_server: Sender<StreamToServer>,
+    position: Arc<StreamPosition>,
+}
+
+impl PartitionControl {
+    pub(crate) fn new(
+        replica: ReplicaKey,
+        stream_to_server: Sender<StreamToServer>,
+        position: Arc<StreamPosition>,
+    ) -> Self {
+        Self {
+            replica,
+            stream_to_server,
+            position,
+        }
+    }
+
+    pub fn topic(&self) -> &str {
+        &self.replica.topic
+    }
+
+    pub fn partition(&self) -> PartitionId {
+        self.replica.partition
+    }
+
+    /// Stop receiving records from the partition. Records already received are still yielded.
+    pub async fn pause(&self) -> Result<(), ErrorCode> {
+        self.update(StreamUpdate::Pause).await.map(|_| ())
+    }
+
+    /// Continue receiving records from the current position
+    pub async fn resume(&self) -> Result<(), ErrorCode> {
+        self.update(StreamUpdate::Resume).await.map(|_| ())
+    }
+
+    /// Continue the stream from the given offset, returns the resolved absolute offset
+    pub async fn seek(&self, offset: Offset) -> Result<i64, ErrorCode> {
+        self.update(StreamUpdate::Seek(offset)).await
+    }
+
+    /// Continue the stream from the first batch with records produced at or after the timestamp,
+    /// in milliseconds since the UNIX epoch. Returns the absolute offset of that batch.
+    pub async fn seek_to_timestamp(&self, timestamp: Timestamp) -> Result<i64, ErrorCode> {
+        self.update(StreamUpdate::SeekTimestamp(timestamp)).await
+    }
+
+    /// Number of records in the partition after the last record yielded by the stream.
+    /// It is updated every time records are received from the SPU.
+    pub fn lag(&self) -> i64 {
+        self.position.lag()
+    }
+
+    pub(crate) fn position(&self) -> &Arc<StreamPosition> {
+        &self.position
+    }
+
+    pub(crate) fn stream_to_server(&self) -> &Sender<StreamToServer> {
+        &self.stream_to_server
+    }
+
+    async fn update(&self, update: StreamUpdate) -> Result<i64, ErrorCode> {
+        let (s, r) = bounded(1);
+        self.stream_to_server
+            .send(StreamToServer::UpdateStream {
+                update,
+                callback: StreamToServerCallback::Channel(s),
+            })
+            .await
+            .map_err(|e| ErrorCode::Other(e.to_string()))?;
+        r.recv()
+            .await
+            .map_err(|e| ErrorCode::Other(e.to_string()))?
+    }
+}
+
+/// Send update of the stream fetch session to the SPU.
+/// Relative offsets are resolved before they are sent.
+pub(crate) async fn send_stream_update(
+    socket: &mut VersionedSerialSocket,
+    replica: &ReplicaKey,
+    stream_id: u32,
+    update: StreamUpdate,
+    position: &StreamPosition,
+) -> Result<i64, ErrorCode> {
+    let action = match update {
+        StreamUpdate::Pause => StreamFetchAction::Pause,
+        StreamUpdate::Resume => StreamFetchAction::Resume,
+        StreamUpdate::Seek(offset) => {
+            let offsets = fetch_offsets(socket, replica, None)
+                .await
+                .map_err(|e| ErrorCode::Other(e.to_string()))?;
+            let offset = offset
+                .resolve(&offsets)
+                .await
+                .map_err(|e| ErrorCode::Other(e.to_string()))?;
+            StreamFetchAction::Seek(offset)
+        }
+        StreamUpdate::SeekTimestamp(timestamp) => StreamFetchAction::SeekTimestamp(timestamp),
+    };
+    let is_seek = match action {
+        StreamFetchAction::Seek(offset) => {
+            position.begin_seek(Some(offset));
+            true
+        }
+        StreamFetchAction::SeekTimestamp(_) => {
+            position.begin_seek(None);
+            true
+        }
+        _ => false,
+    };
+
+    debug!(?action, stream_id, "sending stream update");
+    let response = socket
+        .send_receive(UpdateStreamFetchRequest::new(stream_id, action))
+        .await
+        .map_err(|e| ErrorCode::Other(e.to_string()))?;
+    if !response.error_code.is_ok() {
+        return Err(response.error_code);
+    }
+    if is_seek {
+        position.seek(response.epoch, response.offset);
+    }
+    Ok(response.offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_position() {
+        let position = StreamPosition::new(10);
+        position.update_high_watermark(15);
+        position.record_consumed(11);
+        assert_eq!(position.lag(), 3);
+        assert!(!position.is_stale(0));
+
+        position.seek(1, 5);
+        assert_eq!(position.start_offset(), 5);
+        assert_eq!(position.lag(), 10);
+        assert!(position.is_stale(0));
+        assert!(!position.is_stale(1));
+
+        position.update_high_watermark(4);
+        assert_eq!(position.lag(), 0);
+    }
+
+    #[test]
+    fn test_stream_position_seek_before_reply() {
+        let position = StreamPosition::new(10);
+        position.record_consumed(11);
+
+        // records of the seek arrive before the reply
+        position.begin_seek(Some(3));
+        let mut response = DefaultStreamFetchResponse::default();
+        assert!(!position.is_stale_response(&response));
+        response.epoch = 1;
+        assert!(!position.is_stale_response(&response));
+        assert_eq!(position.epoch(), 1);
+        assert_eq!(position.start_offset(), 3);
+        position.record_consumed(4);
+
+        // late reply doesn't rewind the stream
+        position.seek(1, 3);
+        assert_eq!(position.next_offset.load(DEFAULT_ORDERING), 5);
+
+        response.epoch = 0;
+        assert!(position.is_stale_response(&response));
+        response.partition.error_code = ErrorCode::OffsetOutOfRange;
+        assert!(!position.is_stale_response(&response));
+
+        // offset of timestamp seek is known from the reply only
+        position.begin_seek(None);
+        response.epoch = 2;
+        response.partition.error_code = ErrorCode::None;
+        assert!(!position.is_stale_response(&response));
+        assert_eq!(position.start_offset(), 0);
+        position.seek(2, 7);
+        assert_eq!(position.start_offset(), 7);
+        assert_eq!(position.next_offset.load(DEFAULT_ORDERING), 7);
+    }
+}
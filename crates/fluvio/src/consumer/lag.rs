@@ -0,0 +1,241 @@
+use std::time::{Duration, SystemTime};
+
+use std::sync::Arc;
+
+use futures_util::{stream, StreamExt};
+use serde::Serialize;
+use tracing::debug;
+
+use fluvio_sc_schema::partition::ReplicaStatus;
+use fluvio_types::PartitionId;
+
+use crate::Offset;
+use crate::metrics::ClientMetrics;
+use crate::spu::SpuSocketPool;
+
+use super::{ConsumerConfigExt, ConsumerOffset, PartitionConsumer};
+
+/// Max number of partitions read at the same time to compute time lag
+const TIME_LAG_CONCURRENCY: usize = 16;
+
+/// Progress of a consumer on a partition, compared to the partition leader
+#[derive(Debug, Serialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ConsumerLag {
+    pub consumer_id: String,
+    pub topic: String,
+    pub partition: PartitionId,
+    /// last offset committed by the consumer
+    pub offset: i64,
+    pub high_watermark: i64,
+    pub end_offset: i64,
+    /// number of committed records after the consumer offset
+    pub lag: i64,
+    /// age in milliseconds of the first record not yet consumed, if there is one
+    pub time_lag_ms: Option<u64>,
+    /// seconds since the UNIX epoch of the last offset commit
+    pub modified_time: u64,
+}
+
+impl ConsumerLag {
+    pub fn new(consumer: ConsumerOffset, leader: &ReplicaStatus) -> Self {
+        let ConsumerOffset {
+            consumer_id,
+            topic,
+            partition,
+            offset,
+            modified_time,
+        } = consumer;
+
+        Self {
+            consumer_id,
+            topic,
+            partition,
+            offset,
+            high_watermark: leader.hw,
+            end_offset: leader.leo,
+            lag: (leader.hw - offset - 1).max(0),
+            time_lag_ms: None,
+            modified_time,
+        }
+    }
+
+    /// Offset of the next record for the consumer
+    pub fn next_offset(&self) -> i64 {
+        self.offset + 1
+    }
+
+    /// Consumer didn't commit an offset within `ttl`
+    pub fn is_stale(&self, ttl: Duration, now: SystemTime) -> bool {
+        let now = now
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        now.saturating_sub(self.modified_time) > ttl.as_secs()
+    }
+
+    /// Consumer is stale and has records to consume
+    pub fn is_stuck(&self, ttl: Duration, now: SystemTime) -> bool {
+        self.lag > 0 && self.is_stale(ttl, now)
+    }
+}
+
+/// Selects lags of consumers, time lag is computed only for selected lags
+#[derive(Debug, Clone, Default)]
+pub struct ConsumerLagFilter {
+    pub consumer_id: Option<String>,
+    pub topic: Option<String>,
+    /// only consumers which didn't commit an offset within this time
+    pub stale_after: Option<Duration>,
+}
+
+impl ConsumerLagFilter {
+    pub fn consumer_id(mut self, consumer_id: impl Into<String>) -> Self {
+        self.consumer_id = Some(consumer_id.into());
+        self
+    }
+
+    pub fn topic(mut self, topic: impl Into<String>) -> Self {
+        self.topic = Some(topic.into());
+        self
+    }
+
+    pub fn stale_after(mut self, stale_after: Duration) -> Self {
+        self.stale_after = Some(stale_after);
+        self
+    }
+
+    pub fn matches(&self, lag: &ConsumerLag, now: SystemTime) -> bool {
+        self.selects(&lag.consumer_id, &lag.topic)
+            && self
+                .stale_after
+                .map_or(true, |stale_after| lag.is_stale(stale_after, now))
+    }
+
+    /// consumer on topic is selected, regardless of its lag
+    pub(crate) fn selects(&self, consumer_id: &str, topic: &str) -> bool {
+        self.consumer_id
+            .as_ref()
+            .map_or(true, |selected| selected == consumer_id)
+            && self
+                .topic
+                .as_ref()
+                .map_or(true, |selected| selected == topic)
+    }
+}
+
+/// Set time lag of lagging consumers, reading partitions concurrently
+pub(crate) async fn fill_time_lags(
+    lags: &mut [ConsumerLag],
+    spu_pool: Arc<SpuSocketPool>,
+    metrics: Arc<ClientMetrics>,
+    now_ms: i64,
+) {
+    let time_lags: Vec<Option<u64>> = stream::iter(lags.iter())
+        .map(|lag| {
+            let consumer = PartitionConsumer::new(
+                lag.topic.clone(),
+                lag.partition,
+                spu_pool.clone(),
+                metrics.clone(),
+            );
+            let lagging = lag.lag > 0;
+            let offset = lag.next_offset();
+            async move {
+                if !lagging {
+                    return None;
+                }
+                record_timestamp(consumer, offset)
+                    .await
+                    .map(|timestamp| (now_ms - timestamp).max(0) as u64)
+            }
+        })
+        .buffered(TIME_LAG_CONCURRENCY)
+        .collect()
+        .await;
+    for (lag, time_lag) in lags.iter_mut().zip(time_lags) {
+        lag.time_lag_ms = time_lag;
+    }
+}
+
+/// Timestamp in milliseconds of the record at `offset`.
+/// Returns `None` if the record is not available or was produced without timestamp.
+pub(crate) async fn record_timestamp(consumer: PartitionConsumer, offset: i64) -> Option<i64> {
+    let config = ConsumerConfigExt::builder()
+        .topic(consumer.topic())
+        .partition(consumer.partition())
+        .offset_start(Offset::absolute(offset).ok()?)
+        .disable_continuous(true)
+        .build()
+        .ok()?;
+    let mut stream = match consumer.consumer_stream_with_config(config).await {
+        Ok(stream) => stream,
+        Err(err) => {
+            debug!(%err, offset, "unable to read record timestamp");
+            return None;
+        }
+    };
+    let record = stream.next().await?.ok()?;
+    debug!(offset, "read record timestamp");
+    Some(record.timestamp()).filter(|timestamp| *timestamp >= 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consumer_lag() {
+        let consumer = ConsumerOffset {
+            consumer_id: "c1".to_string(),
+            topic: "t1".to_string(),
+            partition: 0,
+            offset: 9,
+            modified_time: 1000,
+        };
+        let lag = ConsumerLag::new(consumer.clone(), &ReplicaStatus::new(5001, 15, 17));
+        assert_eq!(lag.lag, 5);
+        assert_eq!(lag.next_offset(), 10);
+        assert_eq!(lag.end_offset, 17);
+
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1100);
+        assert!(!lag.is_stale(Duration::from_secs(100), now));
+        assert!(lag.is_stale(Duration::from_secs(99), now));
+        assert!(lag.is_stuck(Duration::from_secs(99), now));
+
+        let caught_up = ConsumerLag::new(consumer, &ReplicaStatus::new(5001, 10, 10));
+        assert_eq!(caught_up.lag, 0);
+        assert!(!caught_up.is_stuck(Duration::from_secs(99), now));
+    }
+    #[test]
+    fn test_consumer_lag_filter() {
+        let consumer = |consumer_id: &str, topic: &str, modified_time| ConsumerOffset {
+            consumer_id: consumer_id.to_string(),
+            topic: topic.to_string(),
+            partition: 0,
+            offset: 9,
+            modified_time,
+        };
+        let leader = ReplicaStatus::new(5001, 15, 15);
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1100);
+
+        let active = ConsumerLag::new(consumer("c1", "t1", 1000), &leader);
+        let stale = ConsumerLag::new(consumer("c2", "t2", 500), &leader);
+
+        let all = ConsumerLagFilter::default();
+        assert!(all.matches(&active, now));
+        assert!(all.matches(&stale, now));
+
+        let by_consumer = ConsumerLagFilter::default().consumer_id("c1");
+        assert!(by_consumer.matches(&active, now));
+        assert!(!by_consumer.matches(&stale, now));
+        assert!(!by_consumer.selects("c2", "t1"));
+
+        let by_topic = ConsumerLagFilter::default().topic("t2");
+        assert!(!by_topic.matches(&active, now));
+        assert!(by_topic.matches(&stale, now));
+
+        let only_stale = ConsumerLagFilter::default().stale_after(Duration::from_secs(300));
+        assert!(!only_stale.matches(&active, now));
+        assert!(only_stale.matches(&stale, now));
+    }
+}
@@ -1,8 +1,11 @@
 #![allow(dead_code)]
 
 mod config;
+mod control;
+mod lag;
 mod stream;
 mod offset;
+mod subscription;
 
 use std::sync::Arc;
 
@@ -12,7 +15,7 @@ use fluvio_spu_schema::server::consumer_offset::UpdateConsumerOffsetRequest;
 use tracing::{debug, error, trace, instrument, info, warn};
 use futures_util::stream::{Stream, select_all};
 use once_cell::sync::Lazy;
-use futures_util::future::{Either, err, join_all};
+use futures_util::future::{Either, err, join_all, ready};
 use futures_util::stream::{StreamExt, once, iter};
 use futures_util::FutureExt;
 
@@ -20,7 +23,7 @@ use fluvio_types::PartitionId;
 use fluvio_types::defaults::{FLUVIO_CLIENT_MAX_FETCH_BYTES, FLUVIO_MAX_SIZE_TOPIC_NAME};
 use fluvio_spu_schema::server::stream_fetch::{
     DefaultStreamFetchRequest, DefaultStreamFetchResponse, CHAIN_SMARTMODULE_API,
-    OFFSET_MANAGEMENT_API,
+    OFFSET_MANAGEMENT_API, STREAM_FETCH_UPDATE_API,
 };
 use fluvio_protocol::record::ReplicaKey;
 use fluvio_protocol::link::ErrorCode;
@@ -31,16 +34,24 @@ use crate::metrics::ClientMetrics;
 use crate::offset::{Offset, fetch_offsets};
 use crate::spu::{SpuDirectory, SpuSocketPool};
 
+pub(crate) use self::lag::fill_time_lags;
+use self::control::{StreamPosition, StreamUpdate, send_stream_update};
+
 pub use config::{ConsumerConfig, ConsumerConfigBuilder};
 pub use config::{ConsumerConfigExt, ConsumerConfigExtBuilder, OffsetManagementStrategy};
+pub use control::PartitionControl;
 pub use stream::{ConsumerStream, MultiplePartitionConsumerStream, SinglePartitionConsumerStream};
-pub use offset::ConsumerOffset;
+pub use offset::{ConsumerOffset, ConsumerOffsetReset};
+pub use lag::{ConsumerLag, ConsumerLagFilter};
+
+pub(crate) use subscription::{PartitionDiscovery, TopicSubscription};
 
 pub use fluvio_protocol::record::ConsumerRecord as Record;
 pub use fluvio_spu_schema::server::smartmodule::SmartModuleInvocation;
 pub use fluvio_spu_schema::server::smartmodule::SmartModuleInvocationWasm;
 pub use fluvio_spu_schema::server::smartmodule::SmartModuleKind;
 pub use fluvio_spu_schema::server::smartmodule::SmartModuleContextData;
+pub use fluvio_spu_schema::server::smartmodule::SmartModuleErrorPolicy;
 pub use fluvio_smartmodule::dataplane::smartmodule::SmartModuleExtraParams;
 
 const STREAM_TO_SERVER_CHANNEL_SIZE: usize = 100;
@@ -209,9 +220,9 @@ where
             .inner_stream_batches_with_config(offset, config, None)
             .await?;
         let partition = self.partition;
-        let flattened = stream.flat_map(move |result: Result<Batch, _>| match result {
+        let flattened = stream.flat_map(move |result: Result<(Batch, u32), _>| match result {
             Err(e) => Either::Right(once(err(e))),
-            Ok(batch) => {
+            Ok((batch, _)) => {
                 let records =
                     batch
                         .into_consumer_records_iter(partition)
@@ -267,6 +278,7 @@ where
         let (stream, _start_offset, _) = self
             .inner_stream_batches_with_config(offset, config, None)
             .await?;
+        let stream = stream.map(|result| result.map(|(batch, _)| batch));
         Ok(stream)
     }
 
@@ -279,19 +291,22 @@ where
         config: ConsumerConfig,
         consumer_id: Option<String>,
     ) -> Result<(
-        impl Stream<Item = Result<Batch, ErrorCode>>,
+        impl Stream<Item = Result<(Batch, u32), ErrorCode>>,
         fluvio_protocol::record::Offset,
-        Sender<StreamToServer>,
+        PartitionControl,
     )> {
-        let (stream, start_offset, stream_to_server) =
+        let (stream, start_offset, control) =
             self.request_stream(offset, config, consumer_id).await?;
         let metrics = self.metrics.clone();
+        let position = control.position().clone();
         let flattened =
             stream.flat_map(move |batch_result: Result<DefaultStreamFetchResponse, _>| {
                 let response = match batch_result {
                     Ok(response) => response,
                     Err(e) => return Either::Right(once(err(e))),
                 };
+                let epoch = response.epoch;
+                let position = position.clone();
 
                 // If we ever get an error_code AND batches of records, we want to first send
                 // the records down the consumer stream, THEN an Err with the error inside.
@@ -299,29 +314,30 @@ where
                 // processed before hitting an error, so that the error does not obscure those records.
 
                 let inner_metrics = metrics.clone();
-                let batches =
-                    response
-                        .partition
-                        .records
-                        .batches
-                        .into_iter()
-                        .map(move |raw_batch| {
-                            inner_metrics
-                                .consumer()
-                                .add_records(raw_batch.records_len() as u64);
-                            inner_metrics
-                                .consumer()
-                                .add_bytes(raw_batch.batch_len() as u64);
-
-                            let batch: Result<Batch, _> = raw_batch.try_into();
-                            match batch {
-                                Ok(batch) => Ok(batch),
-                                Err(err) => {
-                                    tracing::error!("{err:?}");
-                                    Err(ErrorCode::Other(err.to_string()))
-                                }
+                let batches = response
+                    .partition
+                    .records
+                    .batches
+                    .into_iter()
+                    // skip rest of the response if a seek completed while it was consumed
+                    .filter(move |_| !position.is_stale(epoch))
+                    .map(move |raw_batch| {
+                        inner_metrics
+                            .consumer()
+                            .add_records(raw_batch.records_len() as u64);
+                        inner_metrics
+                            .consumer()
+                            .add_bytes(raw_batch.batch_len() as u64);
+
+                        let batch: Result<Batch, _> = raw_batch.try_into();
+                        match batch {
+                            Ok(batch) => Ok((batch, epoch)),
+                            Err(err) => {
+                                tracing::error!("{err:?}");
+                                Err(ErrorCode::Other(err.to_string()))
                             }
-                        });
+                        }
+                    });
                 let error = {
                     let code = response.partition.error_code;
                     match code {
@@ -334,14 +350,14 @@ where
                 Either::Left(iter(items))
             });
 
-        Ok((flattened, start_offset, stream_to_server))
+        Ok((flattened, start_offset, control))
     }
 
     /// Creates a stream of `DefaultStreamFetchResponse` for older consumers who rely
     /// on the internal structure of the fetch response. New clients should use the
     /// `stream` and `stream_with_config` methods.
     /// Returns both the stream and the start offset of the stream.
-    #[instrument(skip(self, config))]
+    #[instrument(skip(self, config), fields(otel.root = true, otel.kind = "client"))]
     async fn request_stream(
         &self,
         offset: Offset,
@@ -350,7 +366,7 @@ where
     ) -> Result<(
         impl Stream<Item = Result<DefaultStreamFetchResponse, ErrorCode>>,
         fluvio_protocol::record::Offset,
-        Sender<StreamToServer>,
+        PartitionControl,
     )> {
         use fluvio_future::task::spawn;
         use futures_util::stream::empty;
@@ -387,6 +403,7 @@ where
         if with_consumer_id && stream_fetch_version < OFFSET_MANAGEMENT_API {
             warn!("SPU does not support Offset Management API");
         }
+        let stream_update_supported = stream_fetch_version >= STREAM_FETCH_UPDATE_API;
 
         let mut stream = self
             .pool
@@ -397,6 +414,8 @@ where
             async_channel::bounded::<StreamToServer>(STREAM_TO_SERVER_CHANNEL_SIZE);
 
         let server_sender_clone = server_sender.clone();
+        let position = Arc::new(StreamPosition::new(start_absolute_offset));
+        let control = PartitionControl::new(replica.clone(), server_sender, position.clone());
 
         let ft_stream = async move {
             if let Some(Ok(raw_response)) = stream.next().await {
@@ -411,13 +430,26 @@ where
                     "first stream response"
                 );
 
+                position.update_high_watermark(response.partition.high_watermark);
+
                 // update stream with received offsets
+                let loop_position = position.clone();
                 spawn(async move {
                     use fluvio_spu_schema::server::update_offset::{UpdateOffsetsRequest, OffsetUpdate};
 
                     loop {
                         match server_recv.recv().await {
-                            Ok(StreamToServer::UpdateOffset(fetch_last_value)) => {
+                            Ok(StreamToServer::UpdateOffset {
+                                offset: fetch_last_value,
+                                epoch,
+                            }) => {
+                                if loop_position.is_stale(epoch) {
+                                    debug!(
+                                        fetch_last_value,
+                                        epoch, "skipping offset received before seek"
+                                    );
+                                    continue;
+                                }
                                 debug!(fetch_last_value, stream_id, "received end fetch");
                                 debug!(
                                     offset = fetch_last_value,
@@ -461,6 +493,25 @@ where
                                     }
                                 };
                             }
+                            Ok(StreamToServer::UpdateStream { update, callback }) => {
+                                if !stream_update_supported {
+                                    callback
+                                        .send(Err(ErrorCode::Other(
+                                            "SPU does not support stream updates".to_string(),
+                                        )))
+                                        .await;
+                                    continue;
+                                }
+                                let result = send_stream_update(
+                                    &mut serial_socket,
+                                    &replica,
+                                    stream_id,
+                                    update,
+                                    &loop_position,
+                                )
+                                .await;
+                                callback.send(result).await;
+                            }
                             Err(err) => {
                                 debug!("stream to server channel closed: {err:?}");
                                 break;
@@ -474,24 +525,41 @@ where
                 if let Some(last_offset) = response.partition.next_offset_for_fetch() {
                     debug!(last_offset, "notify new last offset");
                     let _ = server_sender_clone
-                        .send(StreamToServer::UpdateOffset(last_offset))
+                        .send(StreamToServer::UpdateOffset {
+                            offset: last_offset,
+                            epoch: response.epoch,
+                        })
                         .await;
                 }
 
                 let server_sender_clone2 = server_sender_clone.clone();
-                let update_stream = StreamExt::map(stream, move |item| {
-                    item.map(|response| {
-                        if let Some(last_offset) = response.partition.next_offset_for_fetch() {
-                            debug!(last_offset, stream_id, "received last offset from spu");
-                            let _ = server_sender_clone
-                                .try_send(StreamToServer::UpdateOffset(last_offset));
+                let update_stream = StreamExt::filter_map(stream, move |item| {
+                    let item = match item {
+                        Ok(response) if position.is_stale_response(&response) => {
+                            debug!(
+                                epoch = response.epoch,
+                                stream_id, "discarding response sent before seek"
+                            );
+                            None
+                        }
+                        Ok(response) => {
+                            position.update_high_watermark(response.partition.high_watermark);
+                            if let Some(last_offset) = response.partition.next_offset_for_fetch() {
+                                debug!(last_offset, stream_id, "received last offset from spu");
+                                let _ =
+                                    server_sender_clone.try_send(StreamToServer::UpdateOffset {
+                                        offset: last_offset,
+                                        epoch: response.epoch,
+                                    });
+                            }
+                            Some(Ok(response))
+                        }
+                        Err(e) => {
+                            error!(?e, "error in stream");
+                            Some(Err(ErrorCode::Other(e.to_string())))
                         }
-                        response
-                    })
-                    .map_err(|e| {
-                        error!(?e, "error in stream");
-                        ErrorCode::Other(e.to_string())
-                    })
+                    };
+                    ready(item)
                 });
                 Either::Left(
                     iter(vec![Ok(response)]).chain(publish_stream::EndPublishSt::new(
@@ -511,7 +579,7 @@ where
             ft_stream.flatten_stream().boxed()
         };
 
-        Ok((stream, start_absolute_offset, server_sender))
+        Ok((stream, start_absolute_offset, control))
     }
 
     #[instrument(skip(self, config))]
@@ -520,19 +588,25 @@ where
         config: ConsumerConfigExt,
     ) -> Result<SinglePartitionConsumerStream<impl Stream<Item = Result<Record, ErrorCode>>>> {
         let (offset, config, consumer_id, strategy, flush_period) = config.into_parts();
-        let (stream, start_offset, stream_to_server) = self
+        let (stream, _start_offset, control) = self
             .inner_stream_batches_with_config(offset, config, consumer_id)
             .await?;
         let partition = self.partition;
-        let flattened = stream.flat_map(move |result: Result<Batch, _>| match result {
+        let topic: Arc<str> = self.topic.as_str().into();
+        let position = control.position().clone();
+        let flattened = stream.flat_map(move |result: Result<(Batch, u32), _>| match result {
             Err(e) => Either::Right(once(err(e))),
-            Ok(batch) => {
+            Ok((batch, epoch)) => {
+                // start offset changes on seek
+                let start_offset = position.start_offset();
+                let position = position.clone();
+                let topic = topic.clone();
                 let records =
                     batch
                         .into_consumer_records_iter(partition)
                         .filter_map(move |record| {
-                            if record.offset >= start_offset {
-                                Some(Ok(record))
+                            if record.offset >= start_offset && !position.is_stale(epoch) {
+                                Some(Ok(record.with_topic(topic.clone())))
                             } else {
                                 None
                             }
@@ -544,8 +618,9 @@ where
             flattened,
             strategy,
             flush_period,
-            stream_to_server,
-        ))
+            control.stream_to_server().clone(),
+        )
+        .with_control(control))
     }
 }
 
@@ -852,11 +927,19 @@ impl MultiplePartitionConsumer {
 
 #[derive(Debug, Clone)]
 pub(crate) enum StreamToServer {
-    UpdateOffset(i64),
+    /// offset acknowledged by the consumer, ignored if sent before the last seek
+    UpdateOffset {
+        offset: i64,
+        epoch: u32,
+    },
     FlushManagedOffset {
         offset: i64,
         callback: StreamToServerCallback<ErrorCode>,
     },
+    UpdateStream {
+        update: StreamUpdate,
+        callback: StreamToServerCallback<Result<i64, ErrorCode>>,
+    },
     Close,
 }
 
@@ -2,11 +2,14 @@ use std::sync::atomic::AtomicI64;
 
 use async_channel::{Sender, bounded};
 use anyhow::Result;
-use fluvio_types::PartitionId;
+use fluvio_types::{PartitionId, Timestamp};
 use serde::Serialize;
 
 use fluvio_protocol::link::ErrorCode;
 use fluvio_spu_schema::server::consumer_offset::ConsumerOffset as ConsumerOffsetRequest;
+use fluvio_spu_schema::server::fetch_offset::FetchOffsetPartitionResponse;
+
+use crate::FluvioError;
 
 use super::{StreamToServer, StreamToServerCallback};
 
@@ -117,6 +120,50 @@ impl From<ConsumerOffsetRequest> for ConsumerOffset {
     }
 }
 
+/// New position of a stored consumer offset
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsumerOffsetReset {
+    /// Consume from the first record of the partition
+    Earliest,
+    /// Consume only records produced after the reset
+    Latest,
+    /// Consume from the record at the offset
+    Absolute(i64),
+    /// Consume from the first batch with records produced at or after the timestamp in milliseconds
+    Timestamp(Timestamp),
+    /// Move the position of the consumer by a number of records, backwards if negative
+    Shift(i64),
+}
+
+impl ConsumerOffsetReset {
+    /// Returns the offset to store for the consumer, that is the offset of the last consumed record.
+    /// The position is kept within the readable records of the partition.
+    pub(crate) fn resolve(
+        &self,
+        offsets: &FetchOffsetPartitionResponse,
+        current: Option<i64>,
+    ) -> Result<i64, FluvioError> {
+        let next = match self {
+            Self::Earliest => offsets.start_offset,
+            Self::Latest => offsets.last_stable_offset,
+            Self::Absolute(offset) => *offset,
+            Self::Timestamp(timestamp) => offsets.timestamp_offset.ok_or_else(|| {
+                FluvioError::Other(format!("no offset found for timestamp: {timestamp}"))
+            })?,
+            Self::Shift(records) => {
+                let current = current.ok_or_else(|| {
+                    FluvioError::Other("consumer has no offset to shift".to_string())
+                })?;
+                current.saturating_add(1).saturating_add(*records)
+            }
+        };
+        let next = next
+            .min(offsets.last_stable_offset)
+            .max(offsets.start_offset);
+        Ok(next - 1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use async_channel::TryRecvError;
@@ -320,4 +367,37 @@ mod tests {
         assert!(res.is_err());
         assert_eq!(res.unwrap_err().to_string(), "an error occurred on the SPU");
     }
+
+    #[test]
+    fn test_consumer_offset_reset() {
+        let offsets = FetchOffsetPartitionResponse {
+            start_offset: 5,
+            last_stable_offset: 20,
+            timestamp_offset: Some(12),
+            ..Default::default()
+        };
+
+        let resolve = |reset: ConsumerOffsetReset, current| {
+            reset.resolve(&offsets, current).expect("resolved")
+        };
+        assert_eq!(resolve(ConsumerOffsetReset::Earliest, None), 4);
+        assert_eq!(resolve(ConsumerOffsetReset::Latest, None), 19);
+        assert_eq!(resolve(ConsumerOffsetReset::Absolute(10), None), 9);
+        assert_eq!(resolve(ConsumerOffsetReset::Absolute(100), None), 19);
+        assert_eq!(resolve(ConsumerOffsetReset::Timestamp(1000), None), 11);
+        assert_eq!(resolve(ConsumerOffsetReset::Shift(-3), Some(10)), 7);
+        assert_eq!(resolve(ConsumerOffsetReset::Shift(2), Some(10)), 12);
+        assert_eq!(resolve(ConsumerOffsetReset::Shift(-30), Some(10)), 4);
+
+        assert!(ConsumerOffsetReset::Shift(1)
+            .resolve(&offsets, None)
+            .is_err());
+        let no_timestamp = FetchOffsetPartitionResponse {
+            timestamp_offset: None,
+            ..offsets
+        };
+        assert!(ConsumerOffsetReset::Timestamp(1000)
+            .resolve(&no_timestamp, None)
+            .is_err());
+    }
 }
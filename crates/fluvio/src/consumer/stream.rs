@@ -4,12 +4,13 @@ use std::time::{Duration, SystemTime};
 
 use async_channel::Sender;
 use fluvio_protocol::{link::ErrorCode, record::ConsumerRecord as Record};
-use futures_util::stream::select_all;
+use futures_util::stream::{select_all, BoxStream};
 use futures_util::{future::try_join_all, ready, Future, FutureExt};
-use futures_util::Stream;
+use futures_util::{Stream, StreamExt};
 use tracing::warn;
 
 use super::config::OffsetManagementStrategy;
+use super::control::PartitionControl;
 use super::{offset::OffsetLocalStore, StreamToServer};
 
 /// Extension of [`Stream`] trait with offset management capabilities.
@@ -20,15 +21,22 @@ pub trait ConsumerStream: Stream<Item = Result<Record, ErrorCode>> + Unpin {
 
     /// Send the committed offset to the server. The method waits for the server's acknowledgment before it finishes.
     fn offset_flush(&mut self) -> impl Future<Output = Result<(), ErrorCode>> + Send;
+
+    /// Handles to pause, resume, seek and get the lag of each streamed partition.
+    fn partition_controls(&self) -> Vec<PartitionControl>;
 }
 
 pub struct MultiplePartitionConsumerStream<T> {
     partition_streams: futures_util::stream::SelectAll<SinglePartitionConsumerStream<T>>,
     offset_mgnts: Vec<Arc<OffsetManagement>>,
+    controls: Vec<PartitionControl>,
+    /// streams of partitions which appear while consuming
+    discovered: Option<BoxStream<'static, SinglePartitionConsumerStream<T>>>,
 }
 
 pub struct SinglePartitionConsumerStream<T> {
     offset_mngt: Arc<OffsetManagement>,
+    control: Option<PartitionControl>,
     inner: T,
 }
 
@@ -51,16 +59,36 @@ impl<T: Stream<Item = Result<Record, ErrorCode>> + Unpin> MultiplePartitionConsu
     {
         let mut partition_streams = Vec::new();
         let mut offset_mgnts = Vec::new();
+        let mut controls = Vec::new();
         for partition_stream in streams.into_iter() {
             offset_mgnts.push(partition_stream.offset_mngt.clone());
+            controls.extend(partition_stream.control.clone());
             partition_streams.push(partition_stream);
         }
         let partition_streams = select_all(partition_streams);
         Self {
             partition_streams,
             offset_mgnts,
+            controls,
+            discovered: None,
         }
     }
+
+    /// Merge partition streams yielded by `discovered` as they appear.
+    /// The stream does not end while `discovered` is active.
+    pub(crate) fn with_discovery(
+        mut self,
+        discovered: BoxStream<'static, SinglePartitionConsumerStream<T>>,
+    ) -> Self {
+        self.discovered = Some(discovered);
+        self
+    }
+
+    fn push(&mut self, partition_stream: SinglePartitionConsumerStream<T>) {
+        self.offset_mgnts.push(partition_stream.offset_mngt.clone());
+        self.controls.extend(partition_stream.control.clone());
+        self.partition_streams.push(partition_stream);
+    }
 }
 
 impl<T> SinglePartitionConsumerStream<T> {
@@ -83,9 +111,15 @@ impl<T> SinglePartitionConsumerStream<T> {
         };
         Self {
             offset_mngt: Arc::new(offset_mngt),
+            control: None,
             inner,
         }
     }
+
+    pub(super) fn with_control(mut self, control: PartitionControl) -> Self {
+        self.control = Some(control);
+        self
+    }
 }
 
 impl<T: Stream<Item = Result<Record, ErrorCode>> + Unpin> Stream
@@ -102,6 +136,9 @@ impl<T: Stream<Item = Result<Record, ErrorCode>> + Unpin> Stream
         match ready!(pinned.poll_next(cx)) {
             Some(Ok(last)) => {
                 self_mut.offset_mngt.update(last.offset);
+                if let Some(control) = &self_mut.control {
+                    control.position().record_consumed(last.offset);
+                }
                 std::task::Poll::Ready(Some(Ok(last)))
             }
             other => std::task::Poll::Ready(other),
@@ -119,6 +156,10 @@ impl<T: Stream<Item = Result<Record, ErrorCode>> + Unpin> ConsumerStream
     fn offset_flush(&mut self) -> impl Future<Output = Result<(), ErrorCode>> + Send {
         self.offset_mngt.flush()
     }
+
+    fn partition_controls(&self) -> Vec<PartitionControl> {
+        self.control.iter().cloned().collect()
+    }
 }
 
 impl<T: Stream<Item = Result<Record, ErrorCode>> + Unpin> ConsumerStream
@@ -135,6 +176,10 @@ impl<T: Stream<Item = Result<Record, ErrorCode>> + Unpin> ConsumerStream
         let futures: Vec<_> = self.offset_mgnts.iter().map(|p| p.flush()).collect();
         try_join_all(futures).map(|r| r.map(|_| ()))
     }
+
+    fn partition_controls(&self) -> Vec<PartitionControl> {
+        self.controls.clone()
+    }
 }
 
 impl<T: Stream<Item = Result<Record, ErrorCode>> + Unpin> Stream
@@ -146,9 +191,21 @@ impl<T: Stream<Item = Result<Record, ErrorCode>> + Unpin> Stream
         self: std::pin::Pin<&mut Self>,
         cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Option<Self::Item>> {
+        use std::task::Poll;
+
         let self_mut = self.get_mut();
+        while let Some(discovered) = &mut self_mut.discovered {
+            match discovered.poll_next_unpin(cx) {
+                Poll::Ready(Some(partition_stream)) => self_mut.push(partition_stream),
+                Poll::Ready(None) => self_mut.discovered = None,
+                Poll::Pending => break,
+            }
+        }
         let pinned = std::pin::pin!(&mut self_mut.partition_streams);
-        pinned.poll_next(cx)
+        match ready!(pinned.poll_next(cx)) {
+            None if self_mut.discovered.is_some() => Poll::Pending,
+            next => Poll::Ready(next),
+        }
     }
 }
 
@@ -323,6 +380,49 @@ mod tests {
         assert_eq!(result, ["1", "2", "3", "4", "5", "6"]);
     }
 
+    #[fluvio_future::test]
+    async fn test_multi_partition_stream_merges_discovered() {
+        //given
+        let (tx, _rx) = async_channel::unbounded();
+        let partition_stream = SinglePartitionConsumerStream::new(
+            records_stream(0, ["1", "3"]),
+            Default::default(),
+            Default::default(),
+            tx,
+        );
+        let (tx, _rx) = async_channel::unbounded();
+        let discovered_stream = SinglePartitionConsumerStream::new(
+            records_stream(1, ["2"]),
+            Default::default(),
+            Default::default(),
+            tx,
+        );
+        let (discovered_tx, discovered_rx) = async_channel::unbounded();
+        let mut multi_stream = MultiplePartitionConsumerStream::new([partition_stream])
+            .with_discovery(discovered_rx.boxed());
+
+        //when
+        let first = multi_stream.next().await;
+        discovered_tx
+            .send(discovered_stream)
+            .await
+            .expect("discovered");
+        drop(discovered_tx);
+        let rest: Vec<_> = multi_stream.collect::<Vec<_>>().await;
+
+        //then
+        let result: Vec<_> = first
+            .into_iter()
+            .chain(rest)
+            .collect::<Result<Vec<_>, _>>()
+            .expect("no error")
+            .into_iter()
+            .map(|r| String::from_utf8_lossy(r.as_ref()).to_string())
+            .collect();
+        assert_eq!(result.len(), 3);
+        assert!(result.contains(&"2".to_string()));
+    }
+
     #[fluvio_future::test]
     async fn test_none_offset_strategy_raise_error_on_commit() {
         //given
@@ -0,0 +1,268 @@
+//! Consumption from several topics, selected by name or by pattern.
+
+use std::collections::{HashSet, VecDeque};
+use std::future::Future;
+
+use anyhow::Result;
+use fluvio_stream_dispatcher::metadata::local::LocalMetadataItem;
+use futures_util::Stream;
+use regex::Regex;
+use tracing::{debug, warn};
+
+use fluvio_protocol::record::ReplicaKey;
+
+use crate::metadata::partition::PartitionSpec;
+use crate::metadata::store::ChangeListener;
+use crate::sync::StoreContext;
+
+use super::ConsumerConfigExt;
+
+/// Topics a consumer is subscribed to
+#[derive(Debug, Clone)]
+pub(crate) struct TopicSubscription {
+    topics: HashSet<String>,
+    pattern: Option<Regex>,
+}
+
+impl TopicSubscription {
+    pub(crate) fn new(config: &ConsumerConfigExt) -> Result<Self> {
+        let mut topics: HashSet<String> = config.topics.iter().cloned().collect();
+        if !config.topic.is_empty() {
+            topics.insert(config.topic.clone());
+        }
+        // pattern must match whole topic name
+        let pattern = config
+            .topic_pattern
+            .as_ref()
+            .map(|pattern| Regex::new(&format!("^(?:{pattern})$")))
+            .transpose()?;
+        Ok(Self { topics, pattern })
+    }
+
+    pub(crate) fn matches(&self, topic: &str) -> bool {
+        self.topics.contains(topic)
+            || self
+                .pattern
+                .as_ref()
+                .is_some_and(|pattern| pattern.is_match(topic))
+    }
+
+    /// subscribed partitions in the store, system topics are never subscribed
+    async fn partitions(&self, store: &StoreContext<PartitionSpec>) -> Vec<ReplicaKey> {
+        let partitions = store.store().read().await;
+        let mut replicas: Vec<ReplicaKey> = partitions
+            .values()
+            .map(|partition| partition.inner())
+            .filter(|partition| !partition.spec.system && self.matches(&partition.key.topic))
+            .map(|partition| partition.key.clone())
+            .collect();
+        replicas.sort();
+        replicas
+    }
+}
+
+/// Finds partitions of subscribed topics which are created after the consumer is started
+pub(crate) struct PartitionDiscovery {
+    store: StoreContext<PartitionSpec>,
+    listener: ChangeListener<PartitionSpec, LocalMetadataItem>,
+    subscription: TopicSubscription,
+    known: HashSet<ReplicaKey>,
+    pending: VecDeque<ReplicaKey>,
+}
+
+impl PartitionDiscovery {
+    /// Wait for partition metadata and return the currently subscribed partitions
+    pub(crate) async fn start(
+        store: &StoreContext<PartitionSpec>,
+        subscription: TopicSubscription,
+    ) -> Result<(Self, Vec<ReplicaKey>)> {
+        store.wait_for_sync().await?;
+        // listen before reading the store so no partition is missed
+        let listener = store.store().change_listener();
+        let replicas = subscription.partitions(store).await;
+        debug!(?replicas, "subscribed partitions");
+        let discovery = Self {
+            store: store.clone(),
+            listener,
+            subscription,
+            known: replicas.iter().cloned().collect(),
+            pending: VecDeque::new(),
+        };
+        Ok((discovery, replicas))
+    }
+
+    /// Discovery of subscribed partitions other than `known`, which are already consumed
+    pub(crate) fn new(
+        store: &StoreContext<PartitionSpec>,
+        subscription: TopicSubscription,
+        known: &[ReplicaKey],
+    ) -> Self {
+        Self {
+            store: store.clone(),
+            listener: store.store().change_listener(),
+            subscription,
+            known: known.iter().cloned().collect(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Stream of values created by `start` for each new subscribed partition.
+    /// Partitions which fail to start are retried on the next metadata change.
+    pub(crate) fn into_stream<T, F, Fut>(self, start: F) -> impl Stream<Item = T>
+    where
+        F: Fn(ReplicaKey) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        futures_util::stream::unfold((self, start), |(mut discovery, start)| async move {
+            loop {
+                while let Some(replica) = discovery.pending.pop_front() {
+                    match start(replica.clone()).await {
+                        Ok(value) => {
+                            debug!(%replica, "consuming from new partition");
+                            discovery.known.insert(replica);
+                            return Some((value, (discovery, start)));
+                        }
+                        Err(err) => warn!(%replica, %err, "unable to consume from new partition"),
+                    }
+                }
+                discovery.listener.listen().await;
+                discovery.listener.load_last();
+                let replicas = discovery.subscription.partitions(&discovery.store).await;
+                discovery.pending = replicas
+                    .into_iter()
+                    .filter(|replica| !discovery.known.contains(replica))
+                    .collect();
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use futures_util::StreamExt;
+
+    use crate::metadata::store::MetadataStoreObject;
+    use crate::consumer::ConsumerConfigExtBuilder;
+
+    use super::*;
+
+    fn partition(
+        topic: &str,
+        partition: u32,
+        system: bool,
+    ) -> MetadataStoreObject<PartitionSpec, LocalMetadataItem> {
+        let spec = PartitionSpec {
+            system,
+            ..Default::default()
+        };
+        MetadataStoreObject::with_spec(ReplicaKey::new(topic, partition), spec)
+    }
+
+    #[test]
+    fn test_subscription_matches() {
+        let config = ConsumerConfigExtBuilder::default()
+            .topic("orders")
+            .topics(["payments"])
+            .topic_pattern("tenant-.*")
+            .offset_start(crate::Offset::beginning())
+            .build()
+            .expect("config");
+        let subscription = TopicSubscription::new(&config).expect("subscription");
+
+        assert!(subscription.matches("orders"));
+        assert!(subscription.matches("payments"));
+        assert!(subscription.matches("tenant-a"));
+        assert!(!subscription.matches("my-tenant-a"));
+        assert!(!subscription.matches("shipments"));
+    }
+
+    #[fluvio_future::test]
+    async fn test_partition_discovery() {
+        let store = StoreContext::<PartitionSpec>::new();
+        store
+            .store()
+            .sync_all(vec![
+                partition("tenant-a", 0, false),
+                partition("tenant-a", 1, false),
+                partition("other", 0, false),
+            ])
+            .await;
+
+        let config = ConsumerConfigExtBuilder::default()
+            .topic_pattern("tenant-.*")
+            .offset_start(crate::Offset::beginning())
+            .build()
+            .expect("config");
+        let subscription = TopicSubscription::new(&config).expect("subscription");
+        let (discovery, replicas) = PartitionDiscovery::start(&store, subscription)
+            .await
+            .expect("start");
+        assert_eq!(
+            replicas,
+            vec![
+                ReplicaKey::new("tenant-a", 0u32),
+                ReplicaKey::new("tenant-a", 1u32)
+            ]
+        );
+
+        let mut discovered = Box::pin(discovery.into_stream(|replica| async move { Ok(replica) }));
+
+        store
+            .store()
+            .sync_all(vec![
+                partition("tenant-a", 0, false),
+                partition("tenant-a", 1, false),
+                partition("other", 0, false),
+                partition("tenant-b", 0, false),
+                partition("tenant-system", 0, true),
+            ])
+            .await;
+
+        assert_eq!(
+            discovered.next().await,
+            Some(ReplicaKey::new("tenant-b", 0u32))
+        );
+    }
+
+    #[fluvio_future::test]
+    async fn test_partition_discovery_of_added_partitions() {
+        let store = StoreContext::<PartitionSpec>::new();
+        store
+            .store()
+            .sync_all(vec![
+                partition("orders", 0, false),
+                partition("orders", 1, false),
+            ])
+            .await;
+
+        let config = ConsumerConfigExtBuilder::default()
+            .topic("orders")
+            .offset_start(crate::Offset::beginning())
+            .build()
+            .expect("config");
+        let subscription = TopicSubscription::new(&config).expect("subscription");
+        // partition 1 is in metadata but not yet in topic spec when consumer starts
+        let discovery =
+            PartitionDiscovery::new(&store, subscription, &[ReplicaKey::new("orders", 0u32)]);
+        let mut discovered = Box::pin(discovery.into_stream(|replica| async move { Ok(replica) }));
+
+        assert_eq!(
+            discovered.next().await,
+            Some(ReplicaKey::new("orders", 1u32))
+        );
+
+        store
+            .store()
+            .sync_all(vec![
+                partition("orders", 0, false),
+                partition("orders", 1, false),
+                partition("orders", 2, false),
+            ])
+            .await;
+        assert_eq!(
+            discovered.next().await,
+            Some(ReplicaKey::new("orders", 2u32))
+        );
+    }
+}
@@ -1,19 +1,21 @@
 use std::convert::TryFrom;
 use std::sync::Arc;
+use std::time::{Duration, UNIX_EPOCH};
 
 use fluvio_sc_schema::partition::PartitionMirrorConfig;
 use fluvio_sc_schema::topic::MirrorConfig;
 use fluvio_sc_schema::topic::PartitionMap;
 use fluvio_sc_schema::topic::ReplicaSpec;
+use fluvio_sc_schema::tenant::qualify;
 use tracing::{debug, info};
 use tokio::sync::OnceCell;
 use anyhow::{anyhow, Result};
 
 use fluvio_sc_schema::objects::ObjectApiWatchRequest;
 use fluvio_types::PartitionId;
-use fluvio_socket::{
-    ClientConfig, Versions, VersionedSerialSocket, SharedMultiplexerSocket, MultiplexerSocket,
-};
+use fluvio_protocol::record::ReplicaKey;
+use futures_util::StreamExt;
+use fluvio_socket::{ClientConfig, Versions, VersionedSocket, MultiplexerSocket};
 use fluvio_future::net::DomainConnector;
 use semver::Version;
 
@@ -25,24 +27,28 @@ use crate::PartitionConsumer;
 
 use crate::FluvioError;
 use crate::FluvioConfig;
+use crate::Offset;
 use crate::consumer::{MultiplePartitionConsumer, PartitionSelectionStrategy};
 use crate::consumer::{
     ConsumerStream, MultiplePartitionConsumerStream, Record, ConsumerConfigExt, ConsumerOffset,
+    ConsumerLag, ConsumerLagFilter, ConsumerOffsetReset, fill_time_lags,
 };
+use crate::consumer::{PartitionDiscovery, TopicSubscription};
 use crate::metrics::ClientMetrics;
 use crate::producer::TopicProducerConfig;
 use crate::spu::SpuSocketPool;
-use crate::sync::MetadataStores;
+use crate::sync::{MetadataStores, ScConnection};
 
 /// An interface for interacting with Fluvio streaming
 pub struct Fluvio {
-    socket: SharedMultiplexerSocket,
+    connection: Arc<ScConnection>,
     config: Arc<ClientConfig>,
     versions: Versions,
     spu_pool: OnceCell<Arc<SpuSocketPool>>,
     metadata: MetadataStores,
     watch_version: i16,
     metric: Arc<ClientMetrics>,
+    tenant: Option<String>,
 }
 
 impl Fluvio {
@@ -93,13 +99,11 @@ impl Fluvio {
         connector: DomainConnector,
         config: &FluvioConfig,
     ) -> Result<Self> {
-        let mut client_config =
-            ClientConfig::new(&config.endpoint, connector, config.use_spu_local_address);
-        if let Some(client_id) = &config.client_id {
-            client_config.set_client_id(client_id.to_owned());
-        }
-        let inner_client = client_config.connect().await?;
+        let reconnector = connector.new_domain(connector.domain().to_owned());
+        let inner_client = connect_to_sc(config, connector, config.client_id.as_ref()).await?;
         debug!("connected to cluster");
+        let tenant = config.tenant.clone();
+        let cluster_config = config.clone();
 
         let (socket, config, versions) = inner_client.split();
 
@@ -108,18 +112,25 @@ impl Fluvio {
             debug!(platform = %versions.platform_version(),"checking platform version");
             check_platform_compatible(versions.platform_version())?;
 
-            let socket = MultiplexerSocket::shared(socket);
-            let metadata = MetadataStores::start(socket.clone(), watch_version).await?;
+            let client_id = cluster_config.client_id.clone();
+            let connection = ScConnection::new(
+                MultiplexerSocket::shared(socket),
+                cluster_config,
+                reconnector,
+                client_id,
+            );
+            let metadata = MetadataStores::start(connection.clone(), watch_version).await?;
 
             let spu_pool = OnceCell::new();
             Ok(Self {
-                socket,
+                connection,
                 config,
                 versions,
                 spu_pool,
                 metadata,
                 watch_version,
                 metric: Arc::new(ClientMetrics::new()),
+                tenant,
             })
         } else {
             let platform_version = versions.platform_version();
@@ -133,7 +144,7 @@ impl Fluvio {
         self.spu_pool
             .get_or_try_init(|| async {
                 let metadata =
-                    MetadataStores::start(self.socket.clone(), self.watch_version).await?;
+                    MetadataStores::start(self.connection.clone(), self.watch_version).await?;
                 let pool = SpuSocketPool::start(self.config.clone(), metadata);
                 Ok(Arc::new(pool?))
             })
@@ -188,6 +199,7 @@ impl Fluvio {
         debug!(topic = &*topic, "Creating producer");
 
         let spu_pool = self.spu_pool().await?;
+        let topic = resolve_topic(&spu_pool, topic, self.tenant.as_deref()).await?;
         if !spu_pool.topic_exists(topic.clone()).await? {
             return Err(FluvioError::TopicNotFound(topic).into());
         }
@@ -252,6 +264,10 @@ impl Fluvio {
     /// Creates a new [ConsumerStream] instance.
     ///
     /// The stream can read data from one topic partition or all partitions. Records across different partitions are not guaranteed to be ordered.
+    /// With `topics` or `topic_pattern`, records of several topics are merged.
+    ///
+    /// Unless `disable_continuous` is set, a stream of all partitions also consumes partitions added later,
+    /// and partitions of matching topics created later, starting at `offset_new_partitions`.
     ///
     /// The [ConsumerStream] provides the offset management capabilities. If configured, it allows
     /// to store consumed offsets in the Fluvio cluster, and use it later to continue reading from
@@ -324,6 +340,67 @@ impl Fluvio {
         impl ConsumerStream<Item = std::result::Result<Record, fluvio_protocol::link::ErrorCode>>,
     > {
         let spu_pool = self.spu_pool().await?;
+        let mut config = config;
+        let tenant = self.tenant.as_deref();
+        config.topic = resolve_topic(&spu_pool, config.topic, tenant).await?;
+        for topic in config.topics.iter_mut() {
+            *topic = resolve_topic(&spu_pool, std::mem::take(topic), tenant).await?;
+        }
+        let partitions = spu_pool.metadata.partitions();
+        let (replicas, discovery) = if config.is_multi_topic() {
+            let subscription = TopicSubscription::new(&config)?;
+            let (discovery, replicas) = PartitionDiscovery::start(partitions, subscription).await?;
+            (replicas, Some(discovery))
+        } else {
+            let replicas = self.config_replicas(&spu_pool, &config).await?;
+            // only consumers of all partitions follow partitions added to the topic
+            let discovery = (config.partition.is_empty() && config.mirror.is_none())
+                .then(|| TopicSubscription::new(&config))
+                .transpose()?
+                .map(|subscription| PartitionDiscovery::new(partitions, subscription, &replicas));
+            (replicas, discovery)
+        };
+        // stream ends once available records are read, so no need to wait for new partitions
+        let discovery = discovery.filter(|_| !config.disable_continuous);
+
+        let offset_start = config.offset_start.clone();
+        let offset_new_partitions = config.offset_new_partitions.clone();
+        let metrics = self.metrics();
+        let start_stream = move |replica: ReplicaKey, offset_start: Offset| {
+            let consumer = PartitionConsumer::new(
+                replica.topic,
+                replica.partition,
+                spu_pool.clone(),
+                metrics.clone(),
+            );
+            let mut config = config.clone();
+            config.offset_start = offset_start;
+            async move { consumer.consumer_stream_with_config(config).await }
+        };
+
+        let mut partition_streams = Vec::with_capacity(replicas.len());
+        for replica in replicas {
+            partition_streams.push(start_stream(replica, offset_start.clone()).await?);
+        }
+        let stream = MultiplePartitionConsumerStream::new(partition_streams);
+        match discovery {
+            Some(discovery) => Ok(stream.with_discovery(
+                discovery
+                    .into_stream(move |replica| {
+                        start_stream(replica, offset_new_partitions.clone())
+                    })
+                    .boxed(),
+            )),
+            None => Ok(stream),
+        }
+    }
+
+    /// partitions of single topic consumer
+    async fn config_replicas(
+        &self,
+        spu_pool: &SpuSocketPool,
+        config: &ConsumerConfigExt,
+    ) -> Result<Vec<ReplicaKey>> {
         let topic = &config.topic;
         let topics = spu_pool.metadata.topics();
         let topic_spec = topics
@@ -352,25 +429,22 @@ impl Fluvio {
             None
         };
 
-        let partitions = if let Some(partition) = mirror_partition {
+        let partitions: Vec<PartitionId> = if let Some(partition) = mirror_partition {
             vec![partition]
         } else if config.partition.is_empty() {
             (0..topic_spec.partitions()).collect()
         } else {
             config.partition.clone()
         };
-        let mut partition_streams = Vec::with_capacity(partitions.len());
-        for partition in partitions {
-            let consumer =
-                PartitionConsumer::new(topic.clone(), partition, spu_pool.clone(), self.metrics());
-            partition_streams.push(consumer.consumer_stream_with_config(config.clone()).await?);
-        }
-        Ok(MultiplePartitionConsumerStream::new(partition_streams))
+        Ok(partitions
+            .into_iter()
+            .map(|partition| ReplicaKey::new(topic.clone(), partition))
+            .collect())
     }
 
     /// Returns all consumers offsets that currently available in the cluster.
     pub async fn consumer_offsets(&self) -> Result<Vec<ConsumerOffset>> {
-        use fluvio_protocol::{link::ErrorCode, record::ReplicaKey};
+        use fluvio_protocol::link::ErrorCode;
         use crate::spu::SpuDirectory;
 
         let spu_pool = self.spu_pool().await?;
@@ -395,6 +469,49 @@ impl Fluvio {
             .collect())
     }
 
+    /// Returns lag of all consumers, joining stored consumer offsets with the status of partition leaders.
+    ///
+    /// The time lag is the age of the first record not yet consumed, it requires reading
+    /// that record from every lagging partition.
+    pub async fn consumer_lags(&self) -> Result<Vec<ConsumerLag>> {
+        self.consumer_lags_with_filter(&ConsumerLagFilter::default())
+            .await
+    }
+
+    /// Returns lag of consumers selected by `filter`.
+    /// Time lag is read only from lagging partitions of selected consumers, several partitions at once.
+    pub async fn consumer_lags_with_filter(
+        &self,
+        filter: &ConsumerLagFilter,
+    ) -> Result<Vec<ConsumerLag>> {
+        let spu_pool = self.spu_pool().await?;
+        // clock of chrono is available in wasm as well
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        let now = UNIX_EPOCH + Duration::from_millis(now_ms.max(0) as u64);
+        let mut lags = vec![];
+        for consumer in self.consumer_offsets().await? {
+            if !filter.selects(&consumer.consumer_id, &consumer.topic) {
+                continue;
+            }
+            let replica = ReplicaKey::new(consumer.topic.clone(), consumer.partition);
+            let Some(partition) = spu_pool
+                .metadata
+                .partitions()
+                .lookup_by_key(&replica)
+                .await?
+            else {
+                debug!(%replica, consumer_id = %consumer.consumer_id, "partition not found");
+                continue;
+            };
+            let lag = ConsumerLag::new(consumer, &partition.status.leader);
+            if filter.matches(&lag, now) {
+                lags.push(lag);
+            }
+        }
+        fill_time_lags(&mut lags, spu_pool, self.metrics(), now_ms).await;
+        Ok(lags)
+    }
+
     /// Delete a consumer offset for the given name and the replica.
     pub async fn delete_consumer_offset(
         &self,
@@ -428,6 +545,69 @@ impl Fluvio {
         Ok(())
     }
 
+    /// Overwrite the stored offset of a consumer, creating the consumer if it doesn't exist.
+    /// The offset is the last record consumed, the consumer continues from the next one.
+    pub async fn set_consumer_offset(
+        &self,
+        consumer_id: impl Into<String>,
+        replica_id: impl Into<fluvio_protocol::record::ReplicaKey>,
+        offset: i64,
+    ) -> Result<()> {
+        use fluvio_protocol::{link::ErrorCode, record::ReplicaKey};
+        use fluvio_spu_schema::server::consumer_offset::SetConsumerOffsetRequest;
+
+        use crate::spu::SpuDirectory;
+
+        let spu_pool = self.spu_pool().await?;
+        let consumers_replica_id = ReplicaKey::new(
+            fluvio_types::defaults::CONSUMER_STORAGE_TOPIC,
+            <PartitionId as Default>::default(),
+        );
+        let socket = spu_pool.create_serial_socket(&consumers_replica_id).await?;
+        let response = socket
+            .send_receive(SetConsumerOffsetRequest::new(
+                replica_id,
+                consumer_id,
+                offset,
+            ))
+            .await?;
+        if response.error_code != ErrorCode::None {
+            anyhow::bail!("set consumer offset failed with: {}", response.error_code);
+        }
+        Ok(())
+    }
+
+    /// Returns the offset to store for the consumer to continue from the position given by `reset`.
+    /// `current` is the stored offset of the consumer, it is only required to shift it.
+    pub async fn resolve_consumer_offset(
+        &self,
+        replica_id: impl Into<fluvio_protocol::record::ReplicaKey>,
+        reset: ConsumerOffsetReset,
+        current: Option<i64>,
+    ) -> Result<i64> {
+        use fluvio_protocol::link::ErrorCode;
+
+        use crate::spu::SpuDirectory;
+        use crate::offset::{fetch_offsets, fetch_timestamp_offsets};
+
+        let replica = replica_id.into();
+        let spu_pool = self.spu_pool().await?;
+        let mut socket = spu_pool.create_serial_socket(&replica).await?;
+        let offsets = match reset {
+            ConsumerOffsetReset::Timestamp(timestamp) => {
+                fetch_timestamp_offsets(&mut socket, &replica, timestamp).await?
+            }
+            _ => fetch_offsets(&mut socket, &replica, None).await?,
+        };
+        if offsets.error_code != ErrorCode::None {
+            anyhow::bail!(
+                "fetch offsets of {replica} failed with: {}",
+                offsets.error_code
+            );
+        }
+        Ok(reset.resolve(&offsets, current)?)
+    }
+
     /// Provides an interface for managing a Fluvio cluster
     ///
     /// # Example
@@ -440,9 +620,14 @@ impl Fluvio {
     /// # }
     /// ```
     pub async fn admin(&self) -> FluvioAdmin {
-        let socket = self.create_serial_client().await;
         let metadata = self.metadata.clone();
-        FluvioAdmin::new(socket, metadata)
+        FluvioAdmin::new(
+            self.connection.clone(),
+            self.config.clone(),
+            self.versions.clone(),
+            metadata,
+        )
+        .with_tenant(self.tenant.clone())
     }
 
     /// Reports the Platform Version of the connected cluster.
@@ -455,20 +640,72 @@ impl Fluvio {
         self.versions.platform_version()
     }
 
-    /// create serial connection
-    async fn create_serial_client(&self) -> VersionedSerialSocket {
-        VersionedSerialSocket::new(
-            self.socket.clone(),
-            self.config.clone(),
-            self.versions.clone(),
-        )
-    }
-
     pub fn metrics(&self) -> Arc<ClientMetrics> {
         self.metric.clone()
     }
 }
 
+/// Name of topic which has `topic` as name or alias, unknown topics are returned unchanged.
+/// Plain names are qualified with the tenant of the client
+async fn resolve_topic(
+    spu_pool: &SpuSocketPool,
+    topic: String,
+    tenant: Option<&str>,
+) -> Result<String> {
+    if topic.is_empty() {
+        return Ok(topic);
+    }
+    let topic = match tenant {
+        Some(tenant) => qualify(tenant, &topic),
+        None => topic,
+    };
+    match spu_pool
+        .metadata
+        .topics()
+        .lookup_by_name_or_alias(&topic)
+        .await?
+    {
+        Some(found) if found.key != topic => {
+            debug!(alias = %topic, topic = %found.key, "resolved topic alias");
+            Ok(found.key)
+        }
+        _ => Ok(topic),
+    }
+}
+
+/// Connect to SC, endpoint of replicated SC is a comma separated list of instances
+/// which are tried in order
+pub(crate) async fn connect_to_sc(
+    config: &FluvioConfig,
+    connector: DomainConnector,
+    client_id: Option<&String>,
+) -> Result<VersionedSocket> {
+    let endpoints: Vec<&str> = config
+        .endpoint
+        .split(',')
+        .map(str::trim)
+        .filter(|endpoint| !endpoint.is_empty())
+        .collect();
+
+    for (index, endpoint) in endpoints.iter().enumerate() {
+        let connector = connector.new_domain(connector.domain().to_owned());
+        let mut client_config =
+            ClientConfig::new(*endpoint, connector, config.use_spu_local_address);
+        if let Some(client_id) = client_id {
+            client_config.set_client_id(client_id.to_owned());
+        }
+        match client_config.connect().await {
+            Ok(socket) => return Ok(socket),
+            Err(err) if index + 1 < endpoints.len() => {
+                debug!(endpoint, %err, "unable to connect to SC, trying next endpoint");
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    Err(anyhow!("no SC endpoint configured"))
+}
+
 /// The remote cluster is compatible with this client if its
 /// platform version is greater than this crate's
 /// `MINIMUM_PLATFORM_VERSION`.
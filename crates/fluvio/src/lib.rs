@@ -32,7 +32,7 @@ pub use fluvio_spu_schema::Isolation;
 pub use consumer::{
     PartitionConsumer, ConsumerConfig, MultiplePartitionConsumer, PartitionSelectionStrategy,
     SmartModuleInvocation, SmartModuleInvocationWasm, SmartModuleKind, SmartModuleContextData,
-    SmartModuleExtraParams,
+    SmartModuleExtraParams, SmartModuleErrorPolicy,
 };
 pub use offset::Offset;
 
@@ -3,8 +3,9 @@ use std::io::ErrorKind;
 
 use tracing::{debug, trace};
 use fluvio_protocol::record::ReplicaKey;
-use fluvio_spu_schema::server::fetch_offset::FetchOffsetsRequest;
+use fluvio_spu_schema::server::fetch_offset::{FetchOffsetsRequest, FETCH_OFFSET_TIMESTAMP_API};
 use fluvio_spu_schema::server::fetch_offset::FetchOffsetPartitionResponse;
+use fluvio_types::Timestamp;
 
 use crate::FluvioError;
 use fluvio_socket::VersionedSerialSocket;
@@ -307,13 +308,47 @@ pub(crate) async fn fetch_offsets(
 ) -> Result<FetchOffsetPartitionResponse, FluvioError> {
     debug!("fetching offset for replica: {}", replica);
 
-    let response = client
-        .send_receive(FetchOffsetsRequest::new(
-            replica.topic.to_owned(),
-            replica.partition,
-            consumer_id,
-        ))
-        .await?;
+    let request =
+        FetchOffsetsRequest::new(replica.topic.to_owned(), replica.partition, consumer_id);
+    send_fetch_offsets(client, replica, request).await
+}
+
+/// fetch offsets, including the offset of the first batch produced at or after `timestamp`
+pub(crate) async fn fetch_timestamp_offsets(
+    client: &mut VersionedSerialSocket,
+    replica: &ReplicaKey,
+    timestamp: Timestamp,
+) -> Result<FetchOffsetPartitionResponse, FluvioError> {
+    debug!(
+        timestamp,
+        "fetching timestamp offset for replica: {}", replica
+    );
+
+    let version = client
+        .versions()
+        .lookup_version::<FetchOffsetsRequest>()
+        .unwrap_or_default();
+    if version < FETCH_OFFSET_TIMESTAMP_API {
+        return Err(FluvioError::Other(
+            "SPU does not support offsets of timestamps".to_string(),
+        ));
+    }
+
+    let mut request = FetchOffsetsRequest::new(replica.topic.to_owned(), replica.partition, None);
+    for topic in request.topics.iter_mut() {
+        for partition in topic.partitions.iter_mut() {
+            partition.timestamp = Some(timestamp);
+        }
+    }
+    send_fetch_offsets(client, replica, request).await
+}
+
+async fn send_fetch_offsets(
+    client: &mut VersionedSerialSocket,
+    replica: &ReplicaKey,
+    request: FetchOffsetsRequest,
+) -> Result<FetchOffsetPartitionResponse, FluvioError> {
+    let response = client.send_receive(request).await?;
 
     trace!(
         "receive fetch response replica: {}, {:#?}",
@@ -345,6 +380,7 @@ mod tests {
             partition_index: 0,
             start_offset: 0,
             last_stable_offset: 10,
+            timestamp_offset: None,
         };
 
         let offset_inner = OffsetInner::FromBeginning(3);
@@ -359,6 +395,7 @@ mod tests {
             partition_index: 0,
             start_offset: 5,
             last_stable_offset: 10,
+            timestamp_offset: None,
         };
 
         let offset_inner = OffsetInner::FromBeginning(3);
@@ -373,6 +410,7 @@ mod tests {
             partition_index: 0,
             start_offset: 0,
             last_stable_offset: 10,
+            timestamp_offset: None,
         };
 
         let offset_inner = OffsetInner::FromBeginning(15);
@@ -387,6 +425,7 @@ mod tests {
             partition_index: 0,
             start_offset: 5,
             last_stable_offset: 10,
+            timestamp_offset: None,
         };
 
         let offset_inner = OffsetInner::FromBeginning(15);
@@ -401,6 +440,7 @@ mod tests {
             partition_index: 0,
             start_offset: 0,
             last_stable_offset: 10,
+            timestamp_offset: None,
         };
 
         let offset_inner = OffsetInner::FromEnd(3);
@@ -415,6 +455,7 @@ mod tests {
             partition_index: 0,
             start_offset: 6,
             last_stable_offset: 10,
+            timestamp_offset: None,
         };
 
         let offset_inner = OffsetInner::FromEnd(6);
@@ -429,6 +470,7 @@ mod tests {
             partition_index: 0,
             start_offset: 0,
             last_stable_offset: 10,
+            timestamp_offset: None,
         };
 
         let offset_inner = OffsetInner::FromEnd(100);
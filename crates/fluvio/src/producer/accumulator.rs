@@ -93,10 +93,12 @@ impl RecordAccumulator {
     }
 
     /// Add a record to the accumulator.
+    /// If `timestamp` is not set, the record is timestamped with the current time.
     pub(crate) async fn push_record(
         &self,
         record: Record,
         partition_id: PartitionId,
+        timestamp: Option<Timestamp>,
     ) -> Result<PushRecord, ProducerError> {
         let batches_lock = self.batches.read().await;
         let (batch_events, batches_lock) = batches_lock
@@ -117,7 +119,7 @@ impl RecordAccumulator {
             batches = guard;
         }
         if let Some(batch) = batches.back_mut() {
-            if let Some(push_record) = batch.push_record(record.clone()) {
+            if let Some(push_record) = batch.push_record(record.clone(), timestamp) {
                 if batch.is_full() {
                     batch_events.notify_batch_full().await;
                 }
@@ -136,7 +138,7 @@ impl RecordAccumulator {
 
         let mut batch = ProducerBatch::new(self.batch_size, self.compression);
 
-        match batch.push_record(record) {
+        match batch.push_record(record, timestamp) {
             Some(push_record) => {
                 batch_events.notify_new_batch().await;
 
@@ -191,8 +193,12 @@ impl ProducerBatch {
     /// Add a record to the batch.
     /// Return ProducerError::BatchFull if record does not fit in the batch, so
     /// the RecordAccumulator can create more batches if needed.
-    fn push_record(&mut self, record: Record) -> Option<PartialFutureRecordMetadata> {
-        match self.batch.push_record(record) {
+    fn push_record(
+        &mut self,
+        record: Record,
+        timestamp: Option<Timestamp>,
+    ) -> Option<PartialFutureRecordMetadata> {
+        match self.batch.push_record(record, timestamp) {
             None => None,
             Some(relative_offset) => Some(PartialFutureRecordMetadata::new(
                 relative_offset,
@@ -334,13 +340,13 @@ mod test {
             Compression::None,
         );
 
-        assert!(pb.push_record(record.clone()).is_some());
-        assert!(pb.push_record(record.clone()).is_some());
-        assert!(pb.push_record(record.clone()).is_some());
+        assert!(pb.push_record(record.clone(), None).is_some());
+        assert!(pb.push_record(record.clone(), None).is_some());
+        assert!(pb.push_record(record.clone(), None).is_some());
 
         assert!(!pb.is_full());
 
-        assert!(pb.push_record(record).is_none());
+        assert!(pb.push_record(record, None).is_none());
     }
 
     #[test]
@@ -356,13 +362,13 @@ mod test {
             Compression::None,
         );
 
-        assert!(pb.push_record(record.clone()).is_some());
-        assert!(pb.push_record(record.clone()).is_some());
-        assert!(pb.push_record(record.clone()).is_some());
+        assert!(pb.push_record(record.clone(), None).is_some());
+        assert!(pb.push_record(record.clone(), None).is_some());
+        assert!(pb.push_record(record.clone(), None).is_some());
 
         assert!(pb.is_full());
 
-        assert!(pb.push_record(record).is_none());
+        assert!(pb.push_record(record, None).is_none());
     }
 
     #[fluvio_future::test]
@@ -389,7 +395,7 @@ mod test {
             .clone();
 
         accumulator
-            .push_record(record.clone(), 0)
+            .push_record(record.clone(), 0, None)
             .await
             .expect("failed push");
         assert!(
@@ -404,7 +410,7 @@ mod test {
                 .is_err()
         );
         accumulator
-            .push_record(record.clone(), 0)
+            .push_record(record.clone(), 0, None)
             .await
             .expect("failed push");
 
@@ -414,7 +420,7 @@ mod test {
                 .is_err()
         );
         accumulator
-            .push_record(record, 0)
+            .push_record(record, 0, None)
             .await
             .expect("failed push");
 
@@ -431,7 +437,7 @@ mod test {
             .add_partition(1, (batch_events.clone(), batches_deque.clone()))
             .await;
         accumulator
-            .push_record(record_2.clone(), 1)
+            .push_record(record_2.clone(), 1, None)
             .await
             .expect("failed push");
 
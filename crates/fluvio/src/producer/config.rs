@@ -105,6 +105,11 @@ pub struct TopicProducerConfig {
     #[builder(default = "default_delivery()")]
     pub(crate) delivery_semantic: DeliverySemantic,
 
+    /// Wait for the partition leader to sync records to disk before acknowledging them,
+    /// regardless of the topic flush policy.
+    #[builder(default)]
+    pub(crate) wait_for_fsync: bool,
+
     #[builder(default)]
     pub(crate) smartmodules: Vec<SmartModuleInvocation>,
 }
@@ -138,6 +143,10 @@ impl TopicProducerConfig {
         self.delivery_semantic
     }
 
+    pub fn wait_for_fsync(&self) -> bool {
+        self.wait_for_fsync
+    }
+
     pub fn smartmodules(&self) -> &Vec<SmartModuleInvocation> {
         &self.smartmodules
     }
@@ -154,6 +163,7 @@ impl Default for TopicProducerConfig {
             timeout: default_timeout(),
             isolation: default_isolation(),
             delivery_semantic: default_delivery(),
+            wait_for_fsync: false,
             smartmodules: vec![],
         }
     }
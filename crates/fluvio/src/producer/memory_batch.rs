@@ -14,6 +14,8 @@ pub struct MemoryBatch {
     current_size_uncompressed: usize,
     is_full: bool,
     create_time: Timestamp,
+    /// timestamp of first record when set by the caller
+    first_timestamp: Option<Timestamp>,
     records: Vec<Record>,
 }
 impl MemoryBatch {
@@ -24,6 +26,7 @@ impl MemoryBatch {
             is_full: false,
             write_limit,
             create_time: now,
+            first_timestamp: None,
             current_size_uncompressed: Vec::<RawRecords>::default().write_size(0),
             records: vec![],
         }
@@ -35,14 +38,26 @@ impl MemoryBatch {
 
     /// Add a record to the batch.
     /// The value of `Offset` is relative to the `MemoryBatch` instance.
-    pub fn push_record(&mut self, mut record: Record) -> Option<Offset> {
+    /// If `timestamp` is not set, the current time is used. The first record of the batch
+    /// determines the batch base timestamp, records older than it have negative timestamp delta.
+    pub fn push_record(
+        &mut self,
+        mut record: Record,
+        timestamp: Option<Timestamp>,
+    ) -> Option<Offset> {
         let current_offset = self.offset() as i64;
         record
             .get_mut_header()
             .set_offset_delta(current_offset as Offset);
 
-        let timestamp_delta = self.elapsed();
-        record.get_mut_header().set_timestamp_delta(timestamp_delta);
+        let base_timestamp = match timestamp {
+            Some(timestamp) if self.records.is_empty() => timestamp,
+            _ => self.base_timestamp(),
+        };
+        let timestamp = timestamp.unwrap_or_else(|| Utc::now().timestamp_millis());
+        record
+            .get_mut_header()
+            .set_timestamp_delta(timestamp - base_timestamp);
 
         let record_size = record.write_size(0);
 
@@ -57,11 +72,18 @@ impl MemoryBatch {
 
         self.current_size_uncompressed += record_size;
 
+        if self.records.is_empty() && base_timestamp != self.create_time {
+            self.first_timestamp = Some(base_timestamp);
+        }
         self.records.push(record);
 
         Some(current_offset)
     }
 
+    fn base_timestamp(&self) -> Timestamp {
+        self.first_timestamp.unwrap_or(self.create_time)
+    }
+
     pub fn is_full(&self) -> bool {
         self.is_full || self.write_limit <= self.estimated_size()
     }
@@ -112,6 +134,7 @@ impl From<MemoryBatch> for Batch<MemoryRecords> {
             Self::new_with_len((BATCH_HEADER_SIZE + p_batch.records.write_size(0)) as i32);
 
         let compression = p_batch.compression();
+        let first_timestamp = p_batch.base_timestamp();
         let records = p_batch.records;
 
         let len = records.len() as i32;
@@ -120,11 +143,10 @@ impl From<MemoryBatch> for Batch<MemoryRecords> {
         let header = batch.get_mut_header();
         header.last_offset_delta = if len > 0 { len - 1 } else { len };
 
-        let first_timestamp = p_batch.create_time;
-
         let max_time_stamp = records
-            .last()
+            .iter()
             .map(|r| first_timestamp + r.timestamp_delta())
+            .max()
             .unwrap_or(0);
 
         header.set_first_timestamp(first_timestamp);
@@ -157,13 +179,13 @@ mod test {
             Compression::None,
         );
 
-        assert!(mb.push_record(record).is_some());
+        assert!(mb.push_record(record, None).is_some());
         std::thread::sleep(std::time::Duration::from_millis(100));
         let record = Record::from(("key", "value"));
-        assert!(mb.push_record(record).is_some());
+        assert!(mb.push_record(record, None).is_some());
         std::thread::sleep(std::time::Duration::from_millis(100));
         let record = Record::from(("key", "value"));
-        assert!(mb.push_record(record).is_some());
+        assert!(mb.push_record(record, None).is_some());
 
         let batch: Batch<MemoryRecords> = mb.into();
         assert!(
@@ -196,6 +218,54 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_memory_batch_with_timestamp() {
+        let mut mb = MemoryBatch::new(1024, Compression::None);
+
+        let first_timestamp: Timestamp = 1_600_000_000_000;
+        assert!(mb
+            .push_record(Record::from(("key", "value")), Some(first_timestamp))
+            .is_some());
+        assert!(mb
+            .push_record(Record::from(("key", "value")), Some(first_timestamp + 500))
+            .is_some());
+
+        let batch: Batch<MemoryRecords> = mb.into();
+        assert_eq!(batch.header.first_timestamp, first_timestamp);
+        assert_eq!(batch.header.max_time_stamp, first_timestamp + 500);
+
+        let records_delta: Vec<_> = batch
+            .records()
+            .iter()
+            .map(|record| record.timestamp_delta())
+            .collect();
+        assert_eq!(records_delta, vec![0, 500]);
+    }
+
+    #[test]
+    fn test_memory_batch_with_older_timestamp() {
+        let mut mb = MemoryBatch::new(1024, Compression::None);
+
+        let first_timestamp: Timestamp = 1_600_000_000_000;
+        assert!(mb
+            .push_record(Record::from(("key", "value")), Some(first_timestamp))
+            .is_some());
+        assert!(mb
+            .push_record(Record::from(("key", "value")), Some(first_timestamp - 300))
+            .is_some());
+
+        let batch: Batch<MemoryRecords> = mb.into();
+        assert_eq!(batch.header.first_timestamp, first_timestamp);
+        assert_eq!(batch.header.max_time_stamp, first_timestamp);
+
+        let records_delta: Vec<_> = batch
+            .records()
+            .iter()
+            .map(|record| record.timestamp_delta())
+            .collect();
+        assert_eq!(records_delta, vec![0, -300]);
+    }
+
     #[test]
     fn test_convert_memory_batch_to_batch() {
         let num_records = 10;
@@ -210,10 +280,13 @@ mod test {
 
         for _ in 0..num_records {
             offset = memory_batch
-                .push_record(Record {
-                    value: RecordData::from(record_data.clone()),
-                    ..Default::default()
-                })
+                .push_record(
+                    Record {
+                        value: RecordData::from(record_data.clone()),
+                        ..Default::default()
+                    },
+                    None,
+                )
                 .expect("Offset should exist");
         }
 
@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use tracing::instrument;
 use async_lock::RwLock;
@@ -10,7 +10,9 @@ use fluvio_protocol::record::Record;
 use fluvio_compression::Compression;
 #[cfg(feature = "compress")]
 use fluvio_sc_schema::topic::CompressionAlgorithm;
-use fluvio_types::PartitionId;
+use fluvio_sc_schema::partition::PartitionSpec;
+use fluvio_stream_dispatcher::metadata::local::LocalMetadataItem;
+use fluvio_types::{PartitionCount, PartitionId, Timestamp};
 use fluvio_types::event::StickyEvent;
 
 mod accumulator;
@@ -30,6 +32,7 @@ use crate::spu::SpuPool;
 use crate::spu::SpuSocketPool;
 use crate::FluvioError;
 use crate::metrics::ClientMetrics;
+use crate::metadata::store::ChangeListener;
 use crate::producer::accumulator::{RecordAccumulator, PushRecord};
 
 pub use crate::producer::partitioning::{Partitioner, PartitionerConfig};
@@ -220,6 +223,40 @@ where
     record_accumulator: Arc<RecordAccumulator>,
     producer_pool: Arc<RwLock<ProducerPool>>,
     metrics: Arc<ClientMetrics>,
+    available_partitions: AvailablePartitions,
+}
+
+/// Count of available partitions, computed again only when partition metadata changes
+struct AvailablePartitions {
+    listener: ChangeListener<PartitionSpec, LocalMetadataItem>,
+    /// partition metadata change, partitions of topic and available partitions
+    cached: Mutex<Option<(i64, PartitionCount, PartitionCount)>>,
+}
+
+impl AvailablePartitions {
+    fn new(listener: ChangeListener<PartitionSpec, LocalMetadataItem>) -> Self {
+        Self {
+            listener,
+            cached: Mutex::new(None),
+        }
+    }
+
+    fn get(&self, change: i64, partition_count: PartitionCount) -> Option<PartitionCount> {
+        let cached = self.cached.lock().unwrap_or_else(|err| err.into_inner());
+        match *cached {
+            Some((cached_change, count, available))
+                if cached_change == change && count == partition_count =>
+            {
+                Some(available)
+            }
+            _ => None,
+        }
+    }
+
+    fn set(&self, change: i64, partition_count: PartitionCount, available: PartitionCount) {
+        *self.cached.lock().unwrap_or_else(|err| err.into_inner()) =
+            Some((change, partition_count, available));
+    }
 }
 
 impl<S> InnerTopicProducer<S>
@@ -232,7 +269,11 @@ where
         Ok(())
     }
 
-    async fn push_record(self: Arc<Self>, record: Record) -> Result<PushRecord> {
+    async fn push_record(
+        self: Arc<Self>,
+        record: Record,
+        timestamp: Option<Timestamp>,
+    ) -> Result<PushRecord> {
         let topics = self.spu_pool.topics();
 
         let topic_spec = topics
@@ -240,7 +281,7 @@ where
             .await?
             .ok_or_else(|| FluvioError::TopicNotFound(self.topic.to_string()))?
             .spec;
-        let partition_count = topic_spec.partitions();
+        let partition_count = self.available_partitions(topic_spec.partitions()).await;
         let partition_config = PartitionerConfig { partition_count };
 
         let key = record.key.as_ref().map(|k| k.as_ref());
@@ -275,7 +316,7 @@ where
 
         let push_record = self
             .record_accumulator
-            .push_record(record, partition)
+            .push_record(record, partition, timestamp)
             .await?;
 
         Ok(push_record)
@@ -284,6 +325,36 @@ where
     async fn clear_errors(&self) {
         self.producer_pool.read().await.clear_errors().await;
     }
+
+    /// Number of partitions which can receive records.
+    /// Partitions added to the topic are used once they appear in partition metadata,
+    /// so records are not routed to partitions which are not provisioned yet.
+    async fn available_partitions(&self, partition_count: PartitionCount) -> PartitionCount {
+        // read change before the store, so changes made while counting are not missed
+        let change = self.available_partitions.listener.current_change();
+        if let Some(available) = self.available_partitions.get(change, partition_count) {
+            return available;
+        }
+
+        let partitions = self.spu_pool.partitions().store().read().await;
+        let available = (0..partition_count)
+            .take_while(|partition| {
+                partitions
+                    .get(&ReplicaKey::new(self.topic.clone(), *partition))
+                    .is_some()
+            })
+            .count() as PartitionCount;
+        drop(partitions);
+        // partition metadata may not be synced yet
+        let available = if available == 0 {
+            partition_count
+        } else {
+            available
+        };
+        self.available_partitions
+            .set(change, partition_count, available);
+        available
+    }
 }
 
 cfg_if::cfg_if! {
@@ -425,6 +496,9 @@ where
             metrics.clone(),
         );
 
+        let available_partitions =
+            AvailablePartitions::new(spu_pool.partitions().store().change_listener());
+
         Ok(Self {
             inner: Arc::new(InnerTopicProducer {
                 config,
@@ -433,6 +507,7 @@ where
                 producer_pool: Arc::new(RwLock::new(producer_pool)),
                 record_accumulator: Arc::new(record_accumulator),
                 metrics: metrics.clone(),
+                available_partitions,
             }),
             #[cfg(feature = "smartengine")]
             sm_chain: Default::default(),
@@ -490,6 +565,33 @@ where
         &self,
         key: impl Into<RecordKey>,
         value: impl Into<RecordData>,
+    ) -> Result<ProduceOutput> {
+        self.send_record(key, value, None).await
+    }
+
+    /// Sends a key/value record with the given timestamp, in milliseconds since the Unix epoch,
+    /// instead of the time it was sent.
+    ///
+    /// This is used to reproduce records that were produced earlier, for example when importing
+    /// a topic archive.
+    #[instrument(
+        skip(self, key, value),
+        fields(topic = %self.inner.topic),
+    )]
+    pub async fn send_with_timestamp(
+        &self,
+        key: impl Into<RecordKey>,
+        value: impl Into<RecordData>,
+        timestamp: Timestamp,
+    ) -> Result<ProduceOutput> {
+        self.send_record(key, value, Some(timestamp)).await
+    }
+
+    async fn send_record(
+        &self,
+        key: impl Into<RecordKey>,
+        value: impl Into<RecordData>,
+        timestamp: Option<Timestamp>,
     ) -> Result<ProduceOutput> {
         let record_key = key.into();
         let record_value = value.into();
@@ -512,7 +614,7 @@ where
                 ) = &self.sm_chain {
                     let mut sm_chain = smart_chain_ref.write().await;
                     let mut sm_input = SmartModuleInput::try_from_records(entries, DEFAULT_SMARTENGINE_VERSION)?;
-                    let current_time = Utc::now().timestamp_millis();
+                    let current_time = timestamp.unwrap_or_else(|| Utc::now().timestamp_millis());
 
                     sm_input.set_base_timestamp(current_time);
 
@@ -526,7 +628,7 @@ where
 
         let mut results = ProduceOutput::default();
         for record in entries {
-            let push_record = self.inner.clone().push_record(record).await?;
+            let push_record = self.inner.clone().push_record(record, timestamp).await?;
             results.add(push_record.future);
         }
         Ok(results)
@@ -609,7 +711,7 @@ mod tests {
     use std::sync::Arc;
 
     use async_trait::async_trait;
-    use fluvio_protocol::record::RecordKey;
+    use fluvio_protocol::record::{RecordKey, ReplicaKey};
     use fluvio_sc_schema::{partition::PartitionSpec, store::MetadataStoreObject, topic::TopicSpec};
     use fluvio_socket::{ClientConfig, SocketError, StreamSocket, VersionedSerialSocket};
     use fluvio_stream_dispatcher::metadata::local::LocalMetadataItem;
@@ -624,6 +726,7 @@ mod tests {
 
     struct SpuPoolMock {
         topics: StoreContext<TopicSpec>,
+        partitions: StoreContext<PartitionSpec>,
     }
 
     #[async_trait]
@@ -659,7 +762,7 @@ mod tests {
         }
 
         fn partitions(&self) -> &StoreContext<PartitionSpec> {
-            todo!()
+            &self.partitions
         }
     }
 
@@ -678,7 +781,10 @@ mod tests {
         ];
 
         let topics = StoreContext::<TopicSpec>::new();
-        let spu_pool = Arc::new(SpuPoolMock { topics });
+        let spu_pool = Arc::new(SpuPoolMock {
+            topics,
+            partitions: StoreContext::new(),
+        });
         spu_pool.topics().store().sync_all(topic_2_partitions).await;
         let producer = TopicProducer::new(topic.clone(), spu_pool.clone(), config, metrics)
             .await
@@ -737,4 +843,76 @@ mod tests {
         );
         drop(producer_pool);
     }
+
+    #[fluvio_future::test]
+    async fn test_topic_producer_should_wait_for_provisioned_partitions() {
+        let topic = "test".to_string();
+        let config = Arc::new(TopicProducerConfig::default());
+        let metrics = Arc::new(ClientMetrics::default());
+
+        let spu_pool = Arc::new(SpuPoolMock {
+            topics: StoreContext::new(),
+            partitions: StoreContext::new(),
+        });
+        // partition 2 was added to topic but is not provisioned yet
+        spu_pool
+            .topics
+            .store()
+            .sync_all(vec![
+                MetadataStoreObject::<TopicSpec, LocalMetadataItem>::with_spec(
+                    "test",
+                    (3, 1, false).into(),
+                ),
+            ])
+            .await;
+        let partition = |partition: u32| {
+            MetadataStoreObject::<PartitionSpec, LocalMetadataItem>::with_spec(
+                ReplicaKey::new("test", partition),
+                PartitionSpec::default(),
+            )
+        };
+        spu_pool
+            .partitions
+            .store()
+            .sync_all(vec![partition(0), partition(1)])
+            .await;
+        let producer = TopicProducer::new(topic, spu_pool.clone(), config, metrics)
+            .await
+            .expect("producer");
+
+        for value in ["1", "2", "3"] {
+            let _ = producer
+                .send(RecordKey::NULL, value.to_string())
+                .await
+                .expect("send");
+        }
+        let producer_pool = producer.inner.producer_pool.read().await;
+        assert_eq!(producer_pool.errors.len(), 2);
+        assert!(producer_pool.errors.get(&2).is_none());
+        drop(producer_pool);
+        // count is cached until partition metadata changes
+        let available = &producer.inner.available_partitions;
+        let change = available.listener.current_change();
+        assert_eq!(available.get(change, 3), Some(2));
+
+        spu_pool
+            .partitions
+            .store()
+            .sync_all(vec![partition(0), partition(1), partition(2)])
+            .await;
+        for value in ["4", "5", "6"] {
+            let _ = producer
+                .send(RecordKey::NULL, value.to_string())
+                .await
+                .expect("send");
+        }
+        let producer_pool = producer.inner.producer_pool.read().await;
+        assert_eq!(producer_pool.errors.len(), 3);
+        drop(producer_pool);
+        assert!(available.listener.current_change() > change);
+        assert_eq!(
+            available.get(available.listener.current_change(), 3),
+            Some(3)
+        );
+    }
 }
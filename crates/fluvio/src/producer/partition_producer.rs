@@ -212,6 +212,7 @@ where
 
         request.isolation = self.config.isolation;
         request.timeout = self.config.timeout;
+        request.wait_for_fsync = self.config.wait_for_fsync;
         request.smartmodules.clone_from(&self.config.smartmodules);
         request.topics.push(topic_request);
 
@@ -228,6 +229,15 @@ where
         Ok(())
     }
 
+    #[instrument(
+        name = "produce",
+        skip(self, socket, request),
+        fields(
+            replica = %self.replica,
+            otel.root = true,
+            otel.kind = "client"
+        )
+    )]
     async fn send_to_socket(
         &self,
         socket: VersionedSerialSocket,
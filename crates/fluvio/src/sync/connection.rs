@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use async_lock::RwLock;
+use tracing::{debug, info};
+use anyhow::Result;
+
+use fluvio_future::net::DomainConnector;
+use fluvio_socket::{MultiplexerSocket, SharedMultiplexerSocket};
+
+use crate::FluvioConfig;
+use crate::fluvio::connect_to_sc;
+
+/// how to reach SC again after the connection is lost
+struct ScEndpoint {
+    config: FluvioConfig,
+    connector: DomainConnector,
+    client_id: Option<String>,
+}
+
+/// Connection to SC which is re-established when it becomes stale.
+/// With replicated SC, reconnect tries every configured endpoint so
+/// clients follow the instance which is still alive.
+pub(crate) struct ScConnection {
+    socket: RwLock<SharedMultiplexerSocket>,
+    endpoint: ScEndpoint,
+}
+
+impl ScConnection {
+    pub(crate) fn new(
+        socket: SharedMultiplexerSocket,
+        config: FluvioConfig,
+        connector: DomainConnector,
+        client_id: Option<String>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            socket: RwLock::new(socket),
+            endpoint: ScEndpoint {
+                config,
+                connector,
+                client_id,
+            },
+        })
+    }
+
+    /// current socket, reconnecting first if it has been closed
+    pub(crate) async fn socket(&self) -> Result<SharedMultiplexerSocket> {
+        let socket = self.socket.read().await.clone();
+        if socket.is_stale() {
+            self.reconnect(&socket).await
+        } else {
+            Ok(socket)
+        }
+    }
+
+    /// replace `failed` with new connection.
+    /// if other task has already replaced it, the current socket is returned
+    pub(crate) async fn reconnect(
+        &self,
+        failed: &SharedMultiplexerSocket,
+    ) -> Result<SharedMultiplexerSocket> {
+        let mut current = self.socket.write().await;
+        if !Arc::ptr_eq(&current, failed) {
+            return Ok(current.clone());
+        }
+
+        let endpoint = &self.endpoint;
+        debug!(endpoint = %endpoint.config.endpoint, "reconnecting to SC");
+        let connector = endpoint
+            .connector
+            .new_domain(endpoint.connector.domain().to_owned());
+        let inner_client =
+            connect_to_sc(&endpoint.config, connector, endpoint.client_id.as_ref()).await?;
+        info!(addr = %inner_client.config().addr(), "reconnected to SC");
+        let (socket, _config, _versions) = inner_client.split();
+        *current = MultiplexerSocket::shared(socket);
+        Ok(current.clone())
+    }
+}
@@ -42,6 +42,7 @@ impl SimpleEvent {
     }
 
     pub(crate) fn notify(&self) {
+        self.flag.store(true, Ordering::SeqCst);
         self.event.notify(usize::MAX);
     }
 }
@@ -62,17 +63,16 @@ where
     CacheMetadataStoreObject<S>: TryFrom<Metadata<S>>,
     <Metadata<S> as TryInto<CacheMetadataStoreObject<S>>>::Error: Display,
 {
-    pub(crate) fn start(
+    /// synchronize store until watch stream ends or shutdown is requested
+    pub(crate) async fn run(
         store: StoreContext<S>,
         watch_response: AsyncResponse<ObjectApiWatchRequest>,
         shutdown: Arc<SimpleEvent>,
     ) {
-        use fluvio_future::task::spawn;
-
         let controller = Self { store, shutdown };
 
-        debug!(spec = %S::LABEL, "spawning sync controller");
-        spawn(controller.dispatch_loop(watch_response));
+        debug!(spec = %S::LABEL, "starting sync controller");
+        controller.dispatch_loop(watch_response).await;
     }
 
     #[instrument(
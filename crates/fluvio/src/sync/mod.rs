@@ -1,7 +1,9 @@
+mod connection;
 mod controller;
 mod store;
 
 pub(crate) use store::*;
+pub(crate) use connection::ScConnection;
 pub(crate) use context::*;
 
 mod context {
@@ -21,6 +23,7 @@ mod context {
     use crate::metadata::store::DualEpochMap;
     use crate::metadata::store::MetadataStoreObject;
     use crate::metadata::spu::SpuSpec;
+    use crate::metadata::topic::TopicSpec;
     use crate::metadata::core::MetadataItem;
 
     pub(crate) type CacheMetadataStoreObject<S> = MetadataStoreObject<S, LocalMetadataItem>;
@@ -91,6 +94,16 @@ mod context {
                 .await
         }
 
+        /// Wait until metadata has been filled
+        /// This will generate timeout if metadata has not been filled
+        pub(crate) async fn wait_for_sync(&self) -> Result<(), IoError>
+        where
+            S: 'static,
+            S::IndexKey: Display,
+        {
+            self.lookup_and_wait(|_| None).await.map(|_| ())
+        }
+
         #[instrument(skip(self, search))]
         async fn lookup_and_wait<'a, F>(
             &'a self,
@@ -164,6 +177,21 @@ mod context {
         }
     }
 
+    impl StoreContext<TopicSpec> {
+        /// Look up topic by its name or one of its aliases
+        pub(crate) async fn lookup_by_name_or_alias(
+            &self,
+            name: &str,
+        ) -> Result<Option<CacheMetadataStoreObject<TopicSpec>>, IoError> {
+            self.lookup_and_wait(|g| {
+                g.get(name)
+                    .or_else(|| g.values().find(|topic| topic.spec.has_alias(name)))
+                    .map(|topic| topic.inner().clone())
+            })
+            .await
+        }
+    }
+
     #[cfg(feature = "unstable")]
     mod unstable {
         use super::*;
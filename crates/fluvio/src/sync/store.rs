@@ -1,10 +1,11 @@
 use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::fmt::Display;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
 
 use fluvio_sc_schema::TryEncodableFrom;
-use tracing::{debug, instrument};
+use tracing::{debug, info, instrument};
 use anyhow::Result;
 
 use fluvio_protocol::Decoder;
@@ -13,7 +14,7 @@ use fluvio_sc_schema::AdminSpec;
 use fluvio_sc_schema::objects::Metadata;
 use fluvio_sc_schema::objects::ObjectApiWatchRequest;
 use fluvio_socket::AsyncResponse;
-use fluvio_socket::SharedMultiplexerSocket;
+use fluvio_socket::{MultiplexerSocket, SharedMultiplexerSocket};
 
 use crate::metadata::topic::TopicSpec;
 use crate::metadata::spu::SpuSpec;
@@ -22,6 +23,10 @@ use crate::metadata::partition::PartitionSpec;
 use super::CacheMetadataStoreObject;
 use super::controller::{MetadataSyncController, SimpleEvent};
 use super::StoreContext;
+use super::ScConnection;
+
+/// delay between attempts to re-establish metadata watch
+const WATCH_RETRY_DELAY: Duration = Duration::from_secs(1);
 
 #[derive(Clone)]
 /// global cached stores necessary for consumer and producers
@@ -30,22 +35,22 @@ pub struct MetadataStores {
     spus: StoreContext<SpuSpec>,
     partitions: StoreContext<PartitionSpec>,
     topics: StoreContext<TopicSpec>,
-    socket: SharedMultiplexerSocket,
+    connection: Arc<ScConnection>,
     watch_version: i16,
 }
 
 impl MetadataStores {
     /// start synchronization
 
-    #[instrument(skip(socket))]
-    pub(crate) async fn start(socket: SharedMultiplexerSocket, watch_version: i16) -> Result<Self> {
+    #[instrument(skip(connection))]
+    pub(crate) async fn start(connection: Arc<ScConnection>, watch_version: i16) -> Result<Self> {
         debug!(watch_version, "starting metadata store");
         let store = Self {
             shutdown: SimpleEvent::shared(),
             spus: StoreContext::new(),
             partitions: StoreContext::new(),
             topics: StoreContext::new(),
-            socket,
+            connection,
             watch_version,
         };
 
@@ -107,19 +112,100 @@ impl MetadataStores {
         CacheMetadataStoreObject<S>: TryFrom<Metadata<S>>,
         <Metadata<S> as TryInto<CacheMetadataStoreObject<S>>>::Error: Display,
     {
-        use fluvio_protocol::api::RequestMessage;
-        use fluvio_sc_schema::objects::WatchRequest;
-
-        let watch_request: WatchRequest<S> = WatchRequest::default();
-        let watch_req = ObjectApiWatchRequest::try_encode_from(watch_request, self.watch_version)?;
-        let mut req_msg = RequestMessage::new_request(watch_req);
-        req_msg.get_mut_header().set_api_version(self.watch_version);
+        let socket = self.connection.socket().await?;
+        let async_response = create_watch_stream::<S>(&socket, self.watch_version).await?;
+
+        fluvio_future::task::spawn(watch_loop::<S>(
+            Arc::downgrade(&self.connection),
+            Arc::downgrade(&socket),
+            async_response,
+            store,
+            self.shutdown.clone(),
+            self.watch_version,
+        ));
 
-        debug!(watch_version = self.watch_version, obj = %S::LABEL, "create metadata stream");
-        let async_response = self.socket.create_stream(req_msg, 10).await?;
+        Ok(())
+    }
+}
 
-        MetadataSyncController::<S>::start(store, async_response, self.shutdown.clone());
+async fn create_watch_stream<S>(
+    socket: &SharedMultiplexerSocket,
+    watch_version: i16,
+) -> Result<AsyncResponse<ObjectApiWatchRequest>>
+where
+    S: AdminSpec,
+    S::Status: Encoder + Decoder,
+{
+    use fluvio_protocol::api::RequestMessage;
+    use fluvio_sc_schema::objects::WatchRequest;
+
+    let watch_request: WatchRequest<S> = WatchRequest::default();
+    let watch_req = ObjectApiWatchRequest::try_encode_from(watch_request, watch_version)?;
+    let mut req_msg = RequestMessage::new_request(watch_req);
+    req_msg.get_mut_header().set_api_version(watch_version);
+
+    debug!(watch_version, obj = %S::LABEL, "create metadata stream");
+    Ok(socket.create_stream(req_msg, 10).await?)
+}
 
-        Ok(())
+/// Keep store synchronized. When the watch stream ends because SC went away,
+/// reconnect and watch again, the new stream starts with full sync.
+/// Only weak references are held so dropping the client ends the loop.
+#[instrument(skip_all, fields(spec = S::LABEL))]
+async fn watch_loop<S>(
+    connection: Weak<ScConnection>,
+    mut socket: Weak<MultiplexerSocket>,
+    mut response: AsyncResponse<ObjectApiWatchRequest>,
+    store: StoreContext<S>,
+    shutdown: Arc<SimpleEvent>,
+    watch_version: i16,
+) where
+    S: AdminSpec + 'static + Sync + Send,
+    AsyncResponse<ObjectApiWatchRequest>: Send,
+    S: Encoder + Decoder + Send + Sync,
+    S::Status: Sync + Send + Encoder + Decoder,
+    S::IndexKey: Display + Sync + Send,
+    CacheMetadataStoreObject<S>: TryFrom<Metadata<S>>,
+    <Metadata<S> as TryInto<CacheMetadataStoreObject<S>>>::Error: Display,
+{
+    loop {
+        MetadataSyncController::<S>::run(store.clone(), response, shutdown.clone()).await;
+
+        loop {
+            if shutdown.is_set() {
+                return;
+            }
+            let Some(current) = connection.upgrade() else {
+                debug!("client dropped, ending metadata watch");
+                return;
+            };
+            let reconnected = match socket.upgrade() {
+                Some(failed) if failed.is_stale() => current.reconnect(&failed).await,
+                _ => current.socket().await,
+            };
+            drop(current);
+
+            match reconnected {
+                Ok(new_socket) => {
+                    match create_watch_stream::<S>(&new_socket, watch_version).await {
+                        Ok(new_response) => {
+                            info!("metadata watch re-established");
+                            socket = Arc::downgrade(&new_socket);
+                            response = new_response;
+                            break;
+                        }
+                        Err(err) => {
+                            debug!(%err, "unable to re-create metadata watch");
+                            new_socket.set_stale();
+                            socket = Arc::downgrade(&new_socket);
+                        }
+                    }
+                }
+                Err(err) => {
+                    debug!(%err, "unable to reconnect to SC");
+                }
+            }
+            fluvio_future::timer::sleep(WATCH_RETRY_DELAY).await;
+        }
     }
 }
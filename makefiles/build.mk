@@ -8,6 +8,9 @@ build-smdk: install_rustup_target
 build-cdk: install_rustup_target
 	$(CARGO_BUILDER) build --bin cdk -p cdk $(RELEASE_FLAG) $(TARGET_FLAG) $(VERBOSE_FLAG) $(SMARTENGINE_FLAG)
 
+build-http-gateway: install_rustup_target
+	$(CARGO_BUILDER) build --bin fluvio-http-gateway -p fluvio-http-gateway $(RELEASE_FLAG) $(TARGET_FLAG) $(VERBOSE_FLAG)
+
 build-fbm: install_rustup_target
 	$(CARGO_BUILDER) build --bin fbm -p fluvio-benchmark $(RELEASE_FLAG) $(TARGET_FLAG) $(VERBOSE_FLAG) $(SMARTENGINE_FLAG)
 
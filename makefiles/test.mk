@@ -227,6 +227,9 @@ cli-smdk-smoke:
 cli-cdk-smoke:
 	bats $(shell ls -1 ./tests/cli/cdk_smoke_tests/*.bats | sort -R)
 
+cli-http-gateway-smoke:
+	HTTP_GATEWAY_BIN=$(shell readlink -f $(HTTP_GATEWAY_BIN)) bats ./tests/cli/http_gateway_smoke_tests/http-gateway-basic.bats
+
 cli-fvm-smoke:
 	bats $(shell ls -1 ./tests/cli/fvm_smoke_tests/*.bats | sort -R)
 
@@ -123,10 +123,10 @@ setup_file() {
     debug_msg "Check if the new partition received the message"
     run bash -c 'timeout 15s "$FLUVIO_BIN" partition list | grep "$TOPIC_NAME"'
     assert_success
-    assert_line --partial --index 0 "2   2    2    0"
-    assert_line --partial --index 1 "1   1    1    0"
-    assert_line --partial --index 2 "1   1    1    0"
-    assert_line --partial --index 3 "1   1    1    0"
+    assert_line --partial --index 0 "2   2    0    1"
+    assert_line --partial --index 1 "1   1    0    1"
+    assert_line --partial --index 2 "1   1    0    1"
+    assert_line --partial --index 3 "1   1    0    1"
     assert [ ${#lines[@]} -eq 4 ]
 }
 
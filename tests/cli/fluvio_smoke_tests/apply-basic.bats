@@ -0,0 +1,78 @@
+#!/usr/bin/env bats
+
+TEST_HELPER_DIR="$BATS_TEST_DIRNAME/../test_helper"
+export TEST_HELPER_DIR
+
+load "$TEST_HELPER_DIR"/tools_check.bash
+load "$TEST_HELPER_DIR"/fluvio_dev.bash
+load "$TEST_HELPER_DIR"/bats-support/load.bash
+load "$TEST_HELPER_DIR"/bats-assert/load.bash
+
+setup_file() {
+    TOPIC_NAME=$(random_string)
+    export TOPIC_NAME
+    MANIFEST_DIR=$(mktemp -d)
+    export MANIFEST_DIR
+    EXPORT_DIR="$(mktemp -d)/export"
+    export EXPORT_DIR
+    debug_msg "Topic name: $TOPIC_NAME"
+
+    cat > "$MANIFEST_DIR/topic.yaml" <<EOF
+kind: topic
+name: $TOPIC_NAME
+spec:
+  replicas:
+    computed:
+      partitions: 1
+      replicationFactor: 1
+EOF
+}
+
+teardown_file() {
+    run timeout 15s "$FLUVIO_BIN" topic delete "$TOPIC_NAME"
+    rm -rf "$MANIFEST_DIR" "$(dirname "$EXPORT_DIR")"
+}
+
+@test "Dry run does not create the topic" {
+    run timeout 15s "$FLUVIO_BIN" apply -f "$MANIFEST_DIR" --dry-run
+    assert_success
+    assert_output --partial "topic \"$TOPIC_NAME\" will be created"
+
+    run timeout 15s "$FLUVIO_BIN" topic describe "$TOPIC_NAME"
+    assert_failure
+}
+
+@test "Apply creates the topic" {
+    run timeout 15s "$FLUVIO_BIN" apply -f "$MANIFEST_DIR"
+    assert_success
+    assert_output --partial "topic \"$TOPIC_NAME\" created"
+
+    run timeout 15s "$FLUVIO_BIN" apply -f "$MANIFEST_DIR"
+    assert_success
+    assert_output --partial "no changes"
+}
+
+@test "Apply adds partitions to the topic" {
+    sed -i 's/partitions: 1/partitions: 2/' "$MANIFEST_DIR/topic.yaml"
+    run timeout 15s "$FLUVIO_BIN" apply -f "$MANIFEST_DIR"
+    assert_success
+    assert_output --partial "topic \"$TOPIC_NAME\" updated"
+}
+
+@test "Apply updates config of the topic in place" {
+    echo "  compressionType: Gzip" >> "$MANIFEST_DIR/topic.yaml"
+    run timeout 15s "$FLUVIO_BIN" apply -f "$MANIFEST_DIR" --dry-run
+    assert_success
+    assert_output --partial "topic \"$TOPIC_NAME\" will be updated, config changed"
+
+    run timeout 15s "$FLUVIO_BIN" apply -f "$MANIFEST_DIR"
+    assert_success
+    assert_output --partial "topic \"$TOPIC_NAME\" updated"
+}
+
+@test "Export writes the topic manifest" {
+    run timeout 15s "$FLUVIO_BIN" export -o "$EXPORT_DIR"
+    assert_success
+    run cat "$EXPORT_DIR/topic-$TOPIC_NAME.yaml"
+    assert_output --partial "partitions: 2"
+}
@@ -0,0 +1,57 @@
+#!/usr/bin/env bats
+
+TEST_HELPER_DIR="$BATS_TEST_DIRNAME/../test_helper"
+export TEST_HELPER_DIR
+
+load "$TEST_HELPER_DIR"/tools_check.bash
+load "$TEST_HELPER_DIR"/fluvio_dev.bash
+load "$TEST_HELPER_DIR"/bats-support/load.bash
+load "$TEST_HELPER_DIR"/bats-assert/load.bash
+
+setup_file() {
+    TOPIC_PREFIX=$(random_string)
+    export TOPIC_PREFIX
+    TOPIC_NAME_1="$TOPIC_PREFIX-one"
+    export TOPIC_NAME_1
+    TOPIC_NAME_2="$TOPIC_PREFIX-two"
+    export TOPIC_NAME_2
+    debug_msg "Topic prefix: $TOPIC_PREFIX"
+}
+
+teardown_file() {
+    run timeout 15s "$FLUVIO_BIN" topic delete "$TOPIC_NAME_1"
+    run timeout 15s "$FLUVIO_BIN" topic delete "$TOPIC_NAME_2"
+}
+
+@test "Create topics for test" {
+    run timeout 15s "$FLUVIO_BIN" topic create "$TOPIC_NAME_1"
+    assert_success
+    run timeout 15s "$FLUVIO_BIN" topic create "$TOPIC_NAME_2" -p 2
+    assert_success
+}
+
+@test "Produce to topics" {
+    run bash -c 'echo first | timeout 15s "$FLUVIO_BIN" produce "$TOPIC_NAME_1"'
+    assert_success
+    run bash -c 'echo second | timeout 15s "$FLUVIO_BIN" produce "$TOPIC_NAME_2"'
+    assert_success
+}
+
+@test "Consume from several topics" {
+    run timeout 15s "$FLUVIO_BIN" consume "$TOPIC_NAME_1" "$TOPIC_NAME_2" -B -d
+    assert_success
+    assert_output --partial "$TOPIC_NAME_1: first"
+    assert_output --partial "$TOPIC_NAME_2: second"
+}
+
+@test "Consume from topics matching pattern" {
+    run timeout 15s "$FLUVIO_BIN" consume --topic-pattern "$TOPIC_PREFIX-.*" -B -d --format "{{topic}} {{value}}"
+    assert_success
+    assert_output --partial "$TOPIC_NAME_1 first"
+    assert_output --partial "$TOPIC_NAME_2 second"
+}
+
+@test "Pattern consumer rejects partition" {
+    run timeout 15s "$FLUVIO_BIN" consume --topic-pattern "$TOPIC_PREFIX-.*" -p 0 -B -d
+    assert_failure
+}
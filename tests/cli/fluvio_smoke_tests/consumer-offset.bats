@@ -58,6 +58,11 @@ setup_file() {
     OFFSET=$("$FLUVIO_BIN" consumer list -O json | jq ".[] | select(.consumer_id == \"$CONSUMER_NAME\") | .offset")
     assert [ $OFFSET == "1" ]
 
+    LAG=$("$FLUVIO_BIN" consumer lag "$CONSUMER_NAME" -O json | jq ".[] | select(.partition == 1) | .lag")
+    assert [ $LAG == "0" ]
+    STATUS=$("$FLUVIO_BIN" consumer lag "$CONSUMER_NAME" -O json | jq -r ".[] | select(.partition == 1) | .status")
+    assert [ $STATUS == "active" ]
+
     run timeout 15s "$FLUVIO_BIN" consumer delete "$CONSUMER_NAME"
     assert_output --partial "consumer \"$CONSUMER_NAME\" on topic \"$TOPIC_NAME\" and partition \"1\" deleted"
 }
@@ -88,6 +93,45 @@ setup_file() {
     assert_output --partial "consumer \"$CONSUMER_NAME\" on topic \"$TOPIC_NAME\" and partition \"1\" deleted"
 }
 
+@test "Reset and copy consumer offsets" {
+    if [ "$FLUVIO_CLI_RELEASE_CHANNEL" == "stable" ]; then
+        skip "don't run on fluvio cli stable version"
+    fi
+    if [ "$FLUVIO_CLUSTER_RELEASE_CHANNEL" == "stable" ]; then
+        skip "don't run on cluster stable version"
+    fi
+
+    CONSUMER_NAME=$(random_string)
+    COPY_NAME=$(random_string)
+    run timeout 15s "$FLUVIO_BIN" consume "$TOPIC_NAME" --consumer $CONSUMER_NAME -p 1 -B -d
+    assert_success
+
+    run timeout 15s "$FLUVIO_BIN" consumer reset "$CONSUMER_NAME" --topic "$TOPIC_NAME" -p 1 --earliest --dry-run
+    assert_success
+    assert_output --partial "dry run"
+    OFFSET=$("$FLUVIO_BIN" consumer list -O json | jq ".[] | select(.consumer_id == \"$CONSUMER_NAME\") | .offset")
+    assert [ $OFFSET == "1" ]
+
+    run timeout 15s "$FLUVIO_BIN" consumer reset "$CONSUMER_NAME" --topic "$TOPIC_NAME" -p 1 --earliest
+    assert_success
+    run timeout 15s "$FLUVIO_BIN" consume "$TOPIC_NAME" --consumer $CONSUMER_NAME -p 1 -B -d
+    assert_success
+    assert_line "4"
+    assert_line "8"
+
+    run timeout 15s "$FLUVIO_BIN" consumer reset "$CONSUMER_NAME" --topic "$TOPIC_NAME" -p 1 --shift -1
+    assert_success
+    run timeout 15s "$FLUVIO_BIN" consumer copy "$CONSUMER_NAME" "$COPY_NAME"
+    assert_success
+    run timeout 15s "$FLUVIO_BIN" consume "$TOPIC_NAME" --consumer $COPY_NAME -p 1 -B -d
+    assert_success
+    refute_line "4"
+    assert_line "8"
+
+    run timeout 15s "$FLUVIO_BIN" consumer delete "$CONSUMER_NAME"
+    run timeout 15s "$FLUVIO_BIN" consumer delete "$COPY_NAME"
+}
+
 teardown_file() {
     run timeout 15s "$FLUVIO_BIN" topic delete "$TOPIC_NAME"
 }
@@ -165,6 +165,23 @@ EOF
     assert_output --partial "max_partition_size 2048 is less than segment size 3072"
 }
 
+# Create topic with min in-sync replicas (dry run)
+@test "Attempt to create topic with min in-sync replicas" {
+    run timeout 15s "$FLUVIO_BIN" topic create "$(random_string)" --replication 1 --min-in-sync-replicas 1 --dry-run
+    debug_msg "status: $status"
+    debug_msg "output: ${lines[0]}"
+    assert_success
+}
+
+# Create topic with min in-sync replicas greater than replication (dry run) - Negative test
+@test "Attempt to create topic with min in-sync replicas greater than replication" {
+    run timeout 15s "$FLUVIO_BIN" topic create "$(random_string)" --replication 1 --min-in-sync-replicas 2 --dry-run
+    debug_msg "status: $status"
+    debug_msg "output: ${lines[0]}"
+    assert_failure
+    assert_output --partial "min_in_sync_replicas 2 is greater than replication factor 1"
+}
+
 # Create topic with empty name - Negative test
 @test "Attempt to create topic with empty name" {
     run timeout 15s "$FLUVIO_BIN" topic create " " --dry-run
@@ -0,0 +1,35 @@
+#!/usr/bin/env bats
+
+TEST_HELPER_DIR="$BATS_TEST_DIRNAME/../test_helper"
+export TEST_HELPER_DIR
+
+load "$TEST_HELPER_DIR"/tools_check.bash
+load "$TEST_HELPER_DIR"/fluvio_dev.bash
+load "$TEST_HELPER_DIR"/bats-support/load.bash
+load "$TEST_HELPER_DIR"/bats-assert/load.bash
+
+setup_file() {
+    TOPIC_NAME=$(random_string)
+    export TOPIC_NAME
+    debug_msg "Topic name: $TOPIC_NAME"
+
+    run timeout 15s "$FLUVIO_BIN" topic create "$TOPIC_NAME" --partitions 2
+    assert_success
+}
+
+teardown_file() {
+    run timeout 15s "$FLUVIO_BIN" topic delete "$TOPIC_NAME"
+}
+
+@test "List topic limits usage" {
+    run timeout 15s "$FLUVIO_BIN" topic limits
+    assert_success
+    assert_output --partial "partitions per topic"
+    assert_output --partial "partitions per spu"
+}
+
+@test "List topic limits usage as json" {
+    run timeout 15s "$FLUVIO_BIN" topic limits -O json
+    assert_success
+    assert_output --partial "PartitionsPerTopic"
+}
@@ -0,0 +1,91 @@
+#!/usr/bin/env bats
+
+TEST_HELPER_DIR="$BATS_TEST_DIRNAME/../test_helper"
+export TEST_HELPER_DIR
+
+load "$TEST_HELPER_DIR"/tools_check.bash
+load "$TEST_HELPER_DIR"/fluvio_dev.bash
+load "$TEST_HELPER_DIR"/bats-support/load.bash
+load "$TEST_HELPER_DIR"/bats-assert/load.bash
+
+setup_file() {
+    TOPIC_NAME=$(random_string)
+    export TOPIC_NAME
+    debug_msg "Topic name: $TOPIC_NAME"
+
+    TOPIC_ALIAS=$(random_string)
+    export TOPIC_ALIAS
+    debug_msg "Topic alias: $TOPIC_ALIAS"
+
+    NEW_TOPIC_NAME=$(random_string)
+    export NEW_TOPIC_NAME
+    debug_msg "New topic name: $NEW_TOPIC_NAME"
+
+    run timeout 15s "$FLUVIO_BIN" topic create "$TOPIC_NAME" --alias "$TOPIC_ALIAS"
+    assert_success
+}
+
+teardown_file() {
+    run timeout 15s "$FLUVIO_BIN" topic delete "$NEW_TOPIC_NAME"
+    run timeout 15s "$FLUVIO_BIN" topic delete "$TOPIC_NAME"
+}
+
+@test "Produce and consume through alias" {
+    run bash -c 'echo "via-alias" | timeout 15s "$FLUVIO_BIN" produce "$TOPIC_ALIAS"'
+    assert_success
+
+    run timeout 15s "$FLUVIO_BIN" consume "$TOPIC_NAME" -B -d
+    assert_success
+    assert_output --partial "via-alias"
+}
+
+@test "Add and remove alias" {
+    EXTRA_ALIAS=$(random_string)
+
+    run timeout 15s "$FLUVIO_BIN" topic add-alias "$TOPIC_NAME" "$EXTRA_ALIAS"
+    assert_success
+    assert_output --partial "added alias \"$EXTRA_ALIAS\""
+
+    run timeout 15s "$FLUVIO_BIN" topic describe "$TOPIC_NAME"
+    assert_success
+    assert_output --partial "Aliases"
+    assert_output --partial "$EXTRA_ALIAS"
+
+    run timeout 15s "$FLUVIO_BIN" topic remove-alias "$TOPIC_NAME" "$EXTRA_ALIAS"
+    assert_success
+    assert_output --partial "removed alias \"$EXTRA_ALIAS\""
+
+    run timeout 15s "$FLUVIO_BIN" topic remove-alias "$TOPIC_NAME" "$EXTRA_ALIAS"
+    assert_failure
+}
+
+@test "Reject topic named as existing alias" {
+    run timeout 15s "$FLUVIO_BIN" topic create "$TOPIC_ALIAS"
+    assert_failure
+}
+
+@test "Rename topic keeping old name as alias" {
+    run timeout 60s "$FLUVIO_BIN" topic rename "$TOPIC_NAME" "$NEW_TOPIC_NAME" --keep-alias
+    assert_success
+    assert_output --partial "renamed to \"$NEW_TOPIC_NAME\""
+
+    run timeout 15s "$FLUVIO_BIN" topic describe "$NEW_TOPIC_NAME"
+    assert_success
+    assert_output --partial "$TOPIC_NAME"
+    assert_output --partial "$TOPIC_ALIAS"
+}
+
+@test "Consume after rename" {
+    run timeout 15s "$FLUVIO_BIN" consume "$NEW_TOPIC_NAME" -B -d
+    assert_success
+    assert_output --partial "via-alias"
+
+    run timeout 15s "$FLUVIO_BIN" consume "$TOPIC_NAME" -B -d
+    assert_success
+    assert_output --partial "via-alias"
+}
+
+@test "Reject rename of missing topic" {
+    run timeout 15s "$FLUVIO_BIN" topic rename "$(random_string)" "$(random_string)"
+    assert_failure
+}
@@ -0,0 +1,57 @@
+#!/usr/bin/env bats
+
+TEST_HELPER_DIR="$BATS_TEST_DIRNAME/../test_helper"
+export TEST_HELPER_DIR
+
+load "$TEST_HELPER_DIR"/tools_check.bash
+load "$TEST_HELPER_DIR"/fluvio_dev.bash
+load "$TEST_HELPER_DIR"/bats-support/load.bash
+load "$TEST_HELPER_DIR"/bats-assert/load.bash
+
+setup_file() {
+    TOPIC_NAME=$(random_string)
+    export TOPIC_NAME
+    debug_msg "Topic name: $TOPIC_NAME"
+
+    run timeout 15s "$FLUVIO_BIN" topic create "$TOPIC_NAME"
+    assert_success
+}
+
+teardown_file() {
+    run timeout 15s "$FLUVIO_BIN" topic delete "$TOPIC_NAME"
+}
+
+@test "Update topic config" {
+    run timeout 15s "$FLUVIO_BIN" topic update "$TOPIC_NAME" --retention-time 1h --compression-type lz4 --segment-size "2 KiB"
+    assert_success
+    assert_output --partial "topic \"$TOPIC_NAME\" updated"
+
+    run timeout 15s "$FLUVIO_BIN" topic describe "$TOPIC_NAME" -O json
+    assert_success
+    assert_output --partial "Lz4"
+    assert_output --partial "3600"
+}
+
+@test "Produce and consume after topic update" {
+    run bash -c 'echo "after-update" | timeout 15s "$FLUVIO_BIN" produce "$TOPIC_NAME"'
+    assert_success
+
+    run timeout 15s "$FLUVIO_BIN" consume "$TOPIC_NAME" -B -d
+    assert_success
+    assert_output --partial "after-update"
+}
+
+@test "Reject invalid topic config" {
+    run timeout 15s "$FLUVIO_BIN" topic update "$TOPIC_NAME" --retention-time 1s
+    assert_failure
+}
+
+@test "Reject update without settings" {
+    run timeout 15s "$FLUVIO_BIN" topic update "$TOPIC_NAME"
+    assert_failure
+}
+
+@test "Reject update of missing topic" {
+    run timeout 15s "$FLUVIO_BIN" topic update "$(random_string)" --retention-time 1h
+    assert_failure
+}
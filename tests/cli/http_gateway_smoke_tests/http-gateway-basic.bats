@@ -0,0 +1,69 @@
+#!/usr/bin/env bats
+
+TEST_HELPER_DIR="$BATS_TEST_DIRNAME/../test_helper"
+export TEST_HELPER_DIR
+
+load "$TEST_HELPER_DIR"/tools_check.bash
+load "$TEST_HELPER_DIR"/fluvio_dev.bash
+load "$TEST_HELPER_DIR"/bats-support/load.bash
+load "$TEST_HELPER_DIR"/bats-assert/load.bash
+
+setup_file() {
+    TOPIC_NAME=$(random_string)
+    export TOPIC_NAME
+    debug_msg "Topic name: $TOPIC_NAME"
+
+    CONSUMER_NAME=$(random_string)
+    export CONSUMER_NAME
+    debug_msg "Consumer name: $CONSUMER_NAME"
+
+    GATEWAY_ADDR="127.0.0.1:18080"
+    export GATEWAY_ADDR
+
+    run timeout 15s "$FLUVIO_BIN" topic create "$TOPIC_NAME"
+    assert_success
+
+    "$HTTP_GATEWAY_BIN" --bind "$GATEWAY_ADDR" 3>&- &
+    GATEWAY_PID=$!
+    export GATEWAY_PID
+
+    # wait for gateway to connect to cluster and listen
+    for _ in $(seq 1 30); do
+        curl -s -o /dev/null "http://$GATEWAY_ADDR/consumers" && break
+        sleep 1
+    done
+}
+
+teardown_file() {
+    kill "$GATEWAY_PID"
+    run timeout 15s "$FLUVIO_BIN" topic delete "$TOPIC_NAME"
+}
+
+@test "Produce JSON records through gateway" {
+    run curl -s -f -X POST -H 'Content-Type: application/json' \
+        -d '[{"key":"a","value":"one"},{"value":"two"}]' \
+        "http://$GATEWAY_ADDR/topics/$TOPIC_NAME/records"
+    assert_success
+    assert_output --partial '"offset":1'
+}
+
+@test "Consume produced records through gateway" {
+    run curl -s -f "http://$GATEWAY_ADDR/topics/$TOPIC_NAME/records?offset=beginning&consumer=$CONSUMER_NAME&count=2&timeout_ms=5000"
+    assert_success
+    assert_output --partial '"key":"a","value":"one"'
+    assert_output --partial '"value":"two"'
+
+    run timeout 15s "$FLUVIO_BIN" consume "$TOPIC_NAME" -B -d
+    assert_success
+    assert_output --partial "one"
+    assert_output --partial "two"
+}
+
+@test "Consumer offset is committed by gateway" {
+    run curl -s -f "http://$GATEWAY_ADDR/consumers"
+    assert_success
+    assert_output --partial "$CONSUMER_NAME"
+
+    run curl -s -f -X DELETE "http://$GATEWAY_ADDR/consumers/$CONSUMER_NAME/topics/$TOPIC_NAME/partitions/0"
+    assert_success
+}